@@ -1,4 +1,5 @@
 use clap::Parser;
+use poke_engine::evaluator::DefaultEvaluator;
 use poke_engine::mcts::perform_mcts;
 use poke_engine::mcts_threaded::perform_mcts_shared_tree;
 use poke_engine::state::State;
@@ -48,6 +49,7 @@ fn main() {
                 std::time::Duration::from_millis(0),
                 args.iterations,
                 args.threads,
+                &DefaultEvaluator,
             );
         } else {
             perform_mcts(
@@ -56,6 +58,7 @@ fn main() {
                 side_two_options,
                 std::time::Duration::from_millis(0),
                 args.iterations,
+                &DefaultEvaluator,
             );
         }
         println!("{}", i);
@@ -0,0 +1,53 @@
+use clap::Parser;
+use poke_engine::engine::randbats::SetDatabase;
+use poke_engine::state::{Generation, State};
+use std::process::exit;
+
+// Prints the random battle sets seen in a file of states, e.g.
+// cargo run --release --bin randbats_sets -- -f gen9randombattle.txt > data/gen9randombattle_sets.txt
+#[derive(Parser)]
+struct Args {
+    #[clap(short, long)]
+    file_name: String,
+}
+
+fn main() {
+    let args = Args::parse();
+    if args.file_name.is_empty() {
+        eprintln!("File name is required");
+        exit(1);
+    }
+
+    let file_path = {
+        let this_file = std::path::Path::new(file!());
+        let this_dir = this_file.parent().unwrap();
+        this_dir.join(&args.file_name)
+    };
+    let contents = std::fs::read_to_string(file_path).expect("Failed to read the file");
+
+    let mut states = vec![];
+    for line in contents.lines().filter(|line| !line.is_empty()) {
+        match State::try_deserialize(line) {
+            Ok(state) => states.push(state),
+            Err(e) => {
+                eprintln!("Invalid state: {}", e);
+                exit(1);
+            }
+        }
+    }
+    let pokemon = states.iter().flat_map(|state| {
+        state
+            .side_one
+            .pokemon
+            .into_iter()
+            .chain(&state.side_two.pokemon)
+    });
+    let database = SetDatabase::from_pokemon(pokemon, Generation::GEN9);
+
+    println!(
+        "# Random battle sets seen in data/{}, in the format described in src/genx/randbats.rs",
+        args.file_name
+    );
+    println!("# species|level|abilities|items|moves|tera types");
+    print!("{}", database.serialize());
+}
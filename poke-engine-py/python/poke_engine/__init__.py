@@ -1,5 +1,6 @@
 from dataclasses import dataclass
 from enum import StrEnum
+from typing import Optional
 
 from .poke_engine import *
 
@@ -98,11 +99,14 @@ class MctsSideResult:
     :type total_score: float
     :param visits: The number of times the move was chosen
     :type visits: int
+    :param policy: The probability of playing the move in the side's mixed strategy
+    :type policy: float
     """
 
     move_choice: str
     total_score: float
     visits: int
+    policy: float
 
 
 @dataclass
@@ -130,6 +134,7 @@ class MctsResult:
                     move_choice=i.move_choice,
                     total_score=i.total_score,
                     visits=i.visits,
+                    policy=i.policy,
                 )
                 for i in rust_result.s1
             ],
@@ -138,6 +143,7 @@ class MctsResult:
                     move_choice=i.move_choice,
                     total_score=i.total_score,
                     visits=i.visits,
+                    policy=i.policy,
                 )
                 for i in rust_result.s2
             ],
@@ -146,7 +152,12 @@ class MctsResult:
 
 
 def monte_carlo_tree_search(
-    state: State, duration_ms: int = 1000, iterations: int = 0, threads: int = 1
+    state: State,
+    duration_ms: int = 1000,
+    iterations: int = 0,
+    threads: int = 1,
+    seed: Optional[int] = None,
+    prune_below: float = 0.0,
 ) -> MctsResult:
     """
     Perform monte-carlo-tree-search on the given state and for the given duration
@@ -159,14 +170,20 @@ def monte_carlo_tree_search(
     :type iterations: int
     :param threads: number of threads to use for the search
     :type threads: int
+    :param seed: seed for the search's random choices. a search limited by iterations with a seed is reproducible
+    :type seed: int, optional
+    :param prune_below: outcomes of a turn less likely than this percentage are left out of the search
+    :type prune_below: float
     :return: the result of the search
     :rtype: MctsResult
     """
-    return MctsResult._from_rust(mcts(state, duration_ms, iterations, threads))
+    return MctsResult._from_rust(
+        mcts(state, duration_ms, iterations, threads, seed, prune_below)
+    )
 
 
 def iterative_deepening_expectiminimax(
-    state: State, duration_ms: int = 1000
+    state: State, duration_ms: int = 1000, prune_below: float = 0.0
 ) -> IterativeDeepeningResult:
     """
     Perform an iterative-deepening expectiminimax search on the given state and for the given duration
@@ -175,7 +192,9 @@ def iterative_deepening_expectiminimax(
     :type state: State
     :param duration_ms: time in milliseconds to run the search
     :type duration_ms: int
+    :param prune_below: outcomes of a turn less likely than this percentage are left out of the search
+    :type prune_below: float
     :return: the result of the search
     :rtype: IterativeDeepeningResult
     """
-    return IterativeDeepeningResult._from_rust(id(state, duration_ms))
+    return IterativeDeepeningResult._from_rust(id(state, duration_ms, prune_below))
@@ -173,6 +173,8 @@ class Pokemon:
     :type nature: str
     :param evs: Effort values
     :type evs: tuple[int, int, int, int, int, int]
+    :param ivs: Individual values
+    :type ivs: tuple[int, int, int, int, int, int]
     :param attack: Attack stat
     :type attack: int
     :param defense: Defense stat
@@ -214,6 +216,7 @@ class Pokemon:
     item: str
     nature: str
     evs: Tuple[int, int, int, int, int, int]
+    ivs: Tuple[int, int, int, int, int, int]
     attack: int
     defense: int
     special_attack: int
@@ -242,6 +245,7 @@ class Pokemon:
         item: str = "none",
         nature: str = "serious",
         evs: Tuple[int, int, int, int, int, int] = (85, 85, 85, 85, 85, 85),
+        ivs: Tuple[int, int, int, int, int, int] = (31, 31, 31, 31, 31, 31),
         attack: int = 100,
         defense: int = 100,
         special_attack: int = 100,
@@ -385,6 +389,10 @@ class State:
     :type terrain_turns_remaining: int
     :param trick_room: Whether Trick Room is active
     :type trick_room: bool
+    :param generation: The generation whose mechanics are used, e.g. "gen5". Defaults to "gen4"
+    :type generation: str
+    :param terastallization: Whether terastallization is allowed
+    :type terastallization: bool
     """
 
     side_one: Side
@@ -396,6 +404,8 @@ class State:
     trick_room: bool
     trick_room_turns_remaining: int
     team_preview: bool
+    generation: str
+    terastallization: bool
 
     def __init__(
         self,
@@ -408,6 +418,8 @@ class State:
         trick_room: bool = False,
         trick_room_turns_remaining: int = 0,
         team_preview: bool = False,
+        generation: str = ...,
+        terastallization: bool = False,
     ) -> None: ...
     def apply_instructions(self, instructions: StateInstructions) -> State: ...
     def reverse_instructions(self, instructions: StateInstructions) -> State: ...
@@ -421,6 +433,7 @@ class MctsSideResult:
     move_choice: Tuple[str, str]
     total_score: float
     visits: int
+    policy: float
 
 class MctsResult:
     """Result from MCTS algorithm."""
@@ -429,8 +442,44 @@ class MctsResult:
     side_two: List[MctsSideResult]
     iteration_count: int
 
+class EvaluationTerm:
+    """One term of an evaluation and how much it added to side one's score."""
+
+    side: str
+    name: str
+    value: float
+
+class EvaluationBreakdown:
+    """Every non-zero term of an evaluation."""
+
+    terms: List[EvaluationTerm]
+    total: float
+
+def evaluate(py_state: State) -> float:
+    """
+    Evaluate the given state. Positive is good for side_one.
+
+    :param py_state: The game state to evaluate
+    :return: The evaluation
+    """
+    ...
+
+def evaluate_explained(py_state: State) -> EvaluationBreakdown:
+    """
+    Evaluate the given state and list every term that went into the evaluation.
+
+    :param py_state: The game state to evaluate
+    :return: The terms of the evaluation and their total
+    """
+    ...
+
 def mcts(
-    py_state: State, duration_ms: int, iterations: int, threads: int
+    py_state: State,
+    duration_ms: int,
+    iterations: int,
+    threads: int,
+    seed: Optional[int] = None,
+    prune_below: float = 0.0,
 ) -> MctsResult:
     """
     Perform Monte Carlo Tree Search on the given state.
@@ -439,6 +488,8 @@ def mcts(
     :param duration_ms: Duration in milliseconds to run MCTS. ignored if iterations > 0.
     :param iterations: Exact number of monte-carlo iterations to run
     :param threads: Number of threads to use for MCTS
+    :param seed: Seed for the search's random choices, making a search limited by iterations reproducible
+    :param prune_below: Outcomes of a turn less likely than this percentage are left out of the search
     :return: MCTS results for both sides
     """
     ...
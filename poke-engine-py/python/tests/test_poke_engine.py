@@ -8,6 +8,8 @@ from poke_engine import (
     monte_carlo_tree_search,
     generate_instructions,
     calculate_damage,
+    evaluate,
+    evaluate_explained,
     iterative_deepening_expectiminimax,
     Weather,
     Terrain,
@@ -96,3 +98,19 @@ def test_calculate_damage():
 def test_generate_instructions_errors_when_move_does_not_exist():
     with pytest.raises(ValueError):
         generate_instructions(state, "not_a_move", "ember")
+
+
+def test_evaluate_explained_adds_up_to_the_evaluation():
+    breakdown = evaluate_explained(state)
+    assert breakdown.total == pytest.approx(evaluate(state), abs=1e-3)
+    assert sum(t.value for t in breakdown.terms) == pytest.approx(breakdown.total, abs=1e-3)
+
+
+def test_generate_instructions_errors_when_weather_does_not_exist():
+    bad_state = State(
+        side_one=state.side_one,
+        side_two=state.side_two,
+        weather="not_a_weather",
+    )
+    with pytest.raises(ValueError):
+        generate_instructions(bad_state, "watergun", "ember")
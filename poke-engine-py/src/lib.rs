@@ -3,26 +3,38 @@ use pyo3::types::PyType;
 use pyo3::{pyfunction, pymethods, pymodule, wrap_pyfunction, Bound, PyResult};
 use std::collections::HashSet;
 
-use poke_engine::choices::{Choices, MoveCategory, MOVES};
-use poke_engine::engine::abilities::Abilities;
+use poke_engine::choices::{moves, MoveCategory, MOVES};
+use poke_engine::engine::evaluate::{evaluate, evaluate_explained};
 use poke_engine::engine::generate_instructions::{
     calculate_both_damage_rolls, generate_instructions_from_move_pair,
 };
-use poke_engine::engine::items::Items;
-use poke_engine::engine::state::{MoveChoice, PokemonVolatileStatus, Terrain, Weather};
-use poke_engine::instruction::{Instruction, StateInstructions};
-use poke_engine::mcts::{perform_mcts, MctsResult, MctsSideResult};
-use poke_engine::mcts_threaded::perform_mcts_shared_tree;
-use poke_engine::pokemon::PokemonName;
-use poke_engine::search::iterative_deepen_expectiminimax;
+use poke_engine::engine::state::{MoveChoice, PokemonVolatileStatus};
+use poke_engine::evaluator::{DefaultEvaluator, EvaluationBreakdown};
+use poke_engine::instruction::{BranchPruning, Instruction, StateInstructions};
+use poke_engine::mcts::{MctsConfig, MctsResult, MctsSideResult, MctsTree};
+use poke_engine::mcts_threaded::SharedMctsTree;
+use poke_engine::search::{iterative_deepen_expectiminimax, ExpectiminimaxOptions};
 use poke_engine::state::{
-    LastUsedMove, Move, Pokemon, PokemonIndex, PokemonMoves, PokemonNature, PokemonStatus,
-    PokemonType, Side, SideConditions, SidePokemon, State, StateTerrain, StateTrickRoom,
-    StateWeather, VolatileStatusBitset, VolatileStatusDurations,
+    Generation, LastUsedMove, Move, Pokemon, PokemonIndex, PokemonMoves, Side, SideConditions,
+    SidePokemon, SideReference, State, StateParseError, StateTerrain, StateTrickRoom, StateWeather,
+    VolatileStatusBitset, VolatileStatusDurations,
 };
 use std::str::FromStr;
 use std::time::Duration;
 
+// parses a name given from python, raising a ValueError instead of panicking on a bad one
+fn parse<T>(name: &str) -> PyResult<T>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    T::from_str(name).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+fn to_py_err(e: StateParseError) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string())
+}
+
 fn movechoice_to_string(side: &Side, move_choice: &MoveChoice) -> String {
     match move_choice {
         MoveChoice::Switch(_) => {
@@ -44,6 +56,8 @@ pub struct PyState {
     pub trick_room: bool,
     pub trick_room_turns_remaining: i8,
     pub team_preview: bool,
+    pub generation: String,
+    pub terastallization: bool,
 }
 
 impl From<State> for PyState {
@@ -58,33 +72,46 @@ impl From<State> for PyState {
             trick_room: other.trick_room.active,
             trick_room_turns_remaining: other.trick_room.turns_remaining,
             team_preview: other.team_preview,
+            generation: other.generation.to_string(),
+            terastallization: other.terastallization,
         }
     }
 }
 
-impl Into<State> for PyState {
-    fn into(self) -> State {
+impl TryFrom<PyState> for State {
+    type Error = PyErr;
+
+    fn try_from(py_state: PyState) -> PyResult<State> {
+        let generation = Generation::from_str(&py_state.generation).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid generation: {}", e))
+        })?;
         let mut state = State {
-            side_one: self.side_one.into(),
-            side_two: self.side_two.into(),
+            side_one: Side::try_from(py_state.side_one)?,
+            side_two: Side::try_from(py_state.side_two)?,
             weather: StateWeather {
-                weather_type: Weather::from_str(&self.weather).unwrap(),
-                turns_remaining: self.weather_turns_remaining,
+                weather_type: parse(&py_state.weather)?,
+                turns_remaining: py_state.weather_turns_remaining,
             },
             terrain: StateTerrain {
-                terrain_type: Terrain::from_str(&self.terrain).unwrap(),
-                turns_remaining: self.terrain_turns_remaining,
+                terrain_type: parse(&py_state.terrain)?,
+                turns_remaining: py_state.terrain_turns_remaining,
             },
             trick_room: StateTrickRoom {
-                active: self.trick_room,
-                turns_remaining: self.trick_room_turns_remaining,
+                active: py_state.trick_room,
+                turns_remaining: py_state.trick_room_turns_remaining,
             },
-            team_preview: self.team_preview,
+            team_preview: py_state.team_preview,
             use_last_used_move: false,
             use_damage_dealt: false,
+            generation: Generation::default(),
+            terastallization: py_state.terastallization,
+            zobrist_hash: 0,
         };
         state.set_conditional_mechanics();
-        state
+        state.set_generation(generation);
+        state.terastallization = py_state.terastallization;
+        state.update_zobrist_hash();
+        Ok(state)
     }
 }
 
@@ -101,6 +128,8 @@ impl PyState {
         trick_room=false,
         trick_room_turns_remaining=0,
         team_preview=false,
+        generation=Generation::default().to_string(),
+        terastallization=false,
     ))]
     fn new(
         side_one: PySide,
@@ -112,6 +141,8 @@ impl PyState {
         trick_room: bool,
         trick_room_turns_remaining: i8,
         team_preview: bool,
+        generation: String,
+        terastallization: bool,
     ) -> Self {
         PyState {
             side_one,
@@ -123,28 +154,35 @@ impl PyState {
             trick_room,
             trick_room_turns_remaining,
             team_preview,
+            generation,
+            terastallization,
         }
     }
-    fn apply_instructions(&self, instructions: PyStateInstructions) -> PyState {
-        let mut state: State = self.clone().into();
+    fn apply_instructions(&self, instructions: PyStateInstructions) -> PyResult<PyState> {
+        let mut state = State::try_from(self.clone())?;
         let instructions: StateInstructions = instructions.into();
         state.apply_instructions(&instructions.instruction_list);
-        state.into()
+        Ok(state.into())
     }
-    fn reverse_instructions(&self, instructions: PyStateInstructions) -> PyState {
-        let mut state: State = self.clone().into();
+    fn reverse_instructions(&self, instructions: PyStateInstructions) -> PyResult<PyState> {
+        let mut state = State::try_from(self.clone())?;
         let instructions: StateInstructions = instructions.into();
         state.reverse_instructions(&instructions.instruction_list);
-        state.into()
+        Ok(state.into())
     }
     #[classmethod]
     fn from_string(_cls: &Bound<'_, PyType>, state_str: String) -> PyResult<Self> {
-        let state: State = State::deserialize(&state_str);
-        Ok(PyState::from(state))
+        match State::try_deserialize(&state_str) {
+            Ok(state) => Ok(PyState::from(state)),
+            Err(e) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid state: {}",
+                e
+            ))),
+        }
     }
-    fn to_string(&self) -> String {
-        let state: State = self.clone().into();
-        state.serialize()
+    fn to_string(&self) -> PyResult<String> {
+        let state = State::try_from(self.clone())?;
+        Ok(state.serialize())
     }
 }
 
@@ -216,56 +254,57 @@ impl From<Side> for PySide {
     }
 }
 
-impl Into<Side> for PySide {
-    fn into(self) -> Side {
+impl TryFrom<PySide> for Side {
+    type Error = PyErr;
+
+    fn try_from(py_side: PySide) -> PyResult<Side> {
         let mut volatile_statuses = VolatileStatusBitset::default();
-        for s in &self.volatile_statuses {
+        for s in &py_side.volatile_statuses {
             if let Ok(vs) = PokemonVolatileStatus::from_str(s) {
                 volatile_statuses.insert(vs);
             }
         }
-        Side {
-            active_index: PokemonIndex::deserialize(&self.active_index),
-            baton_passing: self.baton_passing,
-            shed_tailing: self.shed_tailing,
+        Ok(Side {
+            active_index: PokemonIndex::try_deserialize(&py_side.active_index)
+                .map_err(to_py_err)?,
+            baton_passing: py_side.baton_passing,
+            shed_tailing: py_side.shed_tailing,
             pokemon: SidePokemon {
                 pkmn: [
-                    self.pokemon[0].clone().into(),
-                    self.pokemon[1].clone().into(),
-                    self.pokemon[2].clone().into(),
-                    self.pokemon[3].clone().into(),
-                    self.pokemon[4].clone().into(),
-                    self.pokemon[5].clone().into(),
+                    Pokemon::try_from(py_side.pokemon[0].clone())?,
+                    Pokemon::try_from(py_side.pokemon[1].clone())?,
+                    Pokemon::try_from(py_side.pokemon[2].clone())?,
+                    Pokemon::try_from(py_side.pokemon[3].clone())?,
+                    Pokemon::try_from(py_side.pokemon[4].clone())?,
+                    Pokemon::try_from(py_side.pokemon[5].clone())?,
                 ],
             },
-            side_conditions: self.side_conditions.into(),
+            side_conditions: py_side.side_conditions.into(),
             volatile_status_durations: VolatileStatusDurations::from(
-                self.volatile_status_durations.into(),
+                py_side.volatile_status_durations.into(),
             ),
-            wish: self.wish,
+            wish: py_side.wish,
             future_sight: (
-                self.future_sight.0,
-                PokemonIndex::deserialize(&self.future_sight.1),
+                py_side.future_sight.0,
+                PokemonIndex::try_deserialize(&py_side.future_sight.1).map_err(to_py_err)?,
             ),
-            force_switch: self.force_switch,
-            force_trapped: self.force_trapped,
-            slow_uturn_move: self.slow_uturn_move,
+            force_switch: py_side.force_switch,
+            force_trapped: py_side.force_trapped,
+            slow_uturn_move: py_side.slow_uturn_move,
             volatile_statuses,
-            substitute_health: self.substitute_health,
-            attack_boost: self.attack_boost,
-            defense_boost: self.defense_boost,
-            special_attack_boost: self.special_attack_boost,
-            special_defense_boost: self.special_defense_boost,
-            speed_boost: self.speed_boost,
-            accuracy_boost: self.accuracy_boost,
-            evasion_boost: self.evasion_boost,
-            last_used_move: LastUsedMove::deserialize(&self.last_used_move),
+            substitute_health: py_side.substitute_health,
+            attack_boost: py_side.attack_boost,
+            defense_boost: py_side.defense_boost,
+            special_attack_boost: py_side.special_attack_boost,
+            special_defense_boost: py_side.special_defense_boost,
+            speed_boost: py_side.speed_boost,
+            accuracy_boost: py_side.accuracy_boost,
+            evasion_boost: py_side.evasion_boost,
+            last_used_move: LastUsedMove::try_deserialize(&py_side.last_used_move)
+                .map_err(to_py_err)?,
             damage_dealt: Default::default(),
-            switch_out_move_second_saved_move: Choices::from_str(
-                &self.switch_out_move_second_saved_move,
-            )
-            .unwrap(),
-        }
+            switch_out_move_second_saved_move: parse(&py_side.switch_out_move_second_saved_move)?,
+        })
     }
 }
 
@@ -594,6 +633,7 @@ pub struct PyPokemon {
     pub item: String,
     pub nature: String,
     pub evs: (u8, u8, u8, u8, u8, u8),
+    pub ivs: (u8, u8, u8, u8, u8, u8),
     pub attack: i16,
     pub defense: i16,
     pub special_attack: i16,
@@ -632,6 +672,14 @@ impl From<Pokemon> for PyPokemon {
                 other.evs.4,
                 other.evs.5,
             ),
+            ivs: (
+                other.ivs.0,
+                other.ivs.1,
+                other.ivs.2,
+                other.ivs.3,
+                other.ivs.4,
+                other.ivs.5,
+            ),
             attack: other.attack,
             defense: other.defense,
             special_attack: other.special_attack,
@@ -652,50 +700,62 @@ impl From<Pokemon> for PyPokemon {
     }
 }
 
-impl Into<Pokemon> for PyPokemon {
-    fn into(self) -> Pokemon {
-        let mut moves_vec = self.moves.clone();
+impl TryFrom<PyPokemon> for Pokemon {
+    type Error = PyErr;
+
+    fn try_from(py_pokemon: PyPokemon) -> PyResult<Pokemon> {
+        let mut moves_vec = py_pokemon.moves.clone();
         while moves_vec.len() < 4 {
             moves_vec.push(PyMove::create_empty_move());
         }
-        Pokemon {
-            id: PokemonName::from_str(&self.id).unwrap(),
-            level: self.level,
-            types: (
-                PokemonType::from_str(&self.types.0).unwrap(),
-                PokemonType::from_str(&self.types.1).unwrap(),
-            ),
+        Ok(Pokemon {
+            id: parse(&py_pokemon.id)?,
+            level: py_pokemon.level,
+            types: (parse(&py_pokemon.types.0)?, parse(&py_pokemon.types.1)?),
             base_types: (
-                PokemonType::from_str(&self.base_types.0).unwrap(),
-                PokemonType::from_str(&self.base_types.1).unwrap(),
+                parse(&py_pokemon.base_types.0)?,
+                parse(&py_pokemon.base_types.1)?,
             ),
-            hp: self.hp,
-            maxhp: self.maxhp,
-            ability: Abilities::from_str(&self.ability).unwrap(),
-            base_ability: Abilities::from_str(&self.base_ability).unwrap(),
-            item: Items::from_str(&self.item).unwrap(),
-            nature: PokemonNature::from_str(&self.nature).unwrap(),
+            hp: py_pokemon.hp,
+            maxhp: py_pokemon.maxhp,
+            ability: parse(&py_pokemon.ability)?,
+            base_ability: parse(&py_pokemon.base_ability)?,
+            item: parse(&py_pokemon.item)?,
+            nature: parse(&py_pokemon.nature)?,
             evs: (
-                self.evs.0, self.evs.1, self.evs.2, self.evs.3, self.evs.4, self.evs.5,
+                py_pokemon.evs.0,
+                py_pokemon.evs.1,
+                py_pokemon.evs.2,
+                py_pokemon.evs.3,
+                py_pokemon.evs.4,
+                py_pokemon.evs.5,
             ),
-            attack: self.attack,
-            defense: self.defense,
-            special_attack: self.special_attack,
-            special_defense: self.special_defense,
-            speed: self.speed,
-            status: PokemonStatus::from_str(&self.status).unwrap(),
-            rest_turns: self.rest_turns,
-            sleep_turns: self.sleep_turns,
-            weight_kg: self.weight_kg,
-            terastallized: self.terastallized,
-            tera_type: PokemonType::from_str(&self.tera_type).unwrap(),
+            ivs: (
+                py_pokemon.ivs.0,
+                py_pokemon.ivs.1,
+                py_pokemon.ivs.2,
+                py_pokemon.ivs.3,
+                py_pokemon.ivs.4,
+                py_pokemon.ivs.5,
+            ),
+            attack: py_pokemon.attack,
+            defense: py_pokemon.defense,
+            special_attack: py_pokemon.special_attack,
+            special_defense: py_pokemon.special_defense,
+            speed: py_pokemon.speed,
+            status: parse(&py_pokemon.status)?,
+            rest_turns: py_pokemon.rest_turns,
+            sleep_turns: py_pokemon.sleep_turns,
+            weight_kg: py_pokemon.weight_kg,
+            terastallized: py_pokemon.terastallized,
+            tera_type: parse(&py_pokemon.tera_type)?,
             moves: PokemonMoves {
-                m0: moves_vec[0].clone().into(),
-                m1: moves_vec[1].clone().into(),
-                m2: moves_vec[2].clone().into(),
-                m3: moves_vec[3].clone().into(),
+                m0: Move::try_from(moves_vec[0].clone())?,
+                m1: Move::try_from(moves_vec[1].clone())?,
+                m2: Move::try_from(moves_vec[2].clone())?,
+                m3: Move::try_from(moves_vec[3].clone())?,
             },
-        }
+        })
     }
 }
 
@@ -714,6 +774,7 @@ impl PyPokemon {
         item="none".to_string(),
         nature="serious".to_string(),
         evs=(85, 85, 85, 85, 85, 85),
+        ivs=(31, 31, 31, 31, 31, 31),
         attack=100,
         defense=100,
         special_attack=100,
@@ -739,6 +800,7 @@ impl PyPokemon {
         item: String,
         nature: String,
         evs: (u8, u8, u8, u8, u8, u8),
+        ivs: (u8, u8, u8, u8, u8, u8),
         attack: i16,
         defense: i16,
         special_attack: i16,
@@ -767,6 +829,7 @@ impl PyPokemon {
             item,
             nature,
             evs,
+            ivs,
             attack,
             defense,
             special_attack,
@@ -808,17 +871,26 @@ impl From<Move> for PyMove {
     }
 }
 
-impl Into<Move> for PyMove {
-    fn into(self) -> Move {
-        Move {
-            id: Choices::from_str(&self.id).unwrap(),
-            disabled: self.disabled,
-            pp: self.pp,
-            choice: MOVES
-                .get(&Choices::from_str(&self.id).unwrap())
-                .unwrap()
-                .clone(),
-        }
+impl TryFrom<PyMove> for Move {
+    type Error = PyErr;
+
+    fn try_from(py_move: PyMove) -> PyResult<Move> {
+        let id = parse(&py_move.id)?;
+        let choice = match MOVES.get(&id) {
+            Some(choice) => choice.clone(),
+            None => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Invalid move: {}",
+                    py_move.id
+                )))
+            }
+        };
+        Ok(Move {
+            id,
+            disabled: py_move.disabled,
+            pp: py_move.pp,
+            choice,
+        })
     }
 }
 
@@ -851,6 +923,7 @@ struct PyMctsSideResult {
     pub move_choice: String,
     pub total_score: f32,
     pub visits: u32,
+    pub policy: f32,
 }
 
 impl PyMctsSideResult {
@@ -859,6 +932,7 @@ impl PyMctsSideResult {
             move_choice: movechoice_to_string(side, &result.move_choice),
             total_score: result.total_score,
             visits: result.visits,
+            policy: result.policy,
         }
     }
 }
@@ -921,21 +995,45 @@ impl PyIterativeDeepeningResult {
 }
 
 #[pyfunction]
+#[pyo3(signature = (py_state, duration_ms, iterations, threads, seed=None, prune_below=0.0))]
 fn mcts(
     py_state: PyState,
     duration_ms: u64,
     iterations: u32,
     threads: usize,
+    seed: Option<u64>,
+    prune_below: f32,
 ) -> PyResult<PyMctsResult> {
-    let mut state: State = py_state.into();
+    let mut state = State::try_from(py_state)?;
+    let config = MctsConfig {
+        pruning: BranchPruning {
+            min_percentage: prune_below,
+            ..BranchPruning::default()
+        },
+        threads,
+        seed,
+        ..MctsConfig::default()
+    };
     let duration = Duration::from_millis(duration_ms);
     let (s1_options, s2_options) = state.root_get_all_options();
     let mcts_result = if threads > 1 {
-        perform_mcts_shared_tree(
-            &mut state, s1_options, s2_options, duration, iterations, threads,
+        SharedMctsTree::with_config(config).search(
+            &mut state,
+            s1_options,
+            s2_options,
+            duration,
+            iterations,
+            &DefaultEvaluator,
         )
     } else {
-        perform_mcts(&mut state, s1_options, s2_options, duration, iterations)
+        MctsTree::with_config(config).search(
+            &mut state,
+            s1_options,
+            s2_options,
+            duration,
+            iterations,
+            &DefaultEvaluator,
+        )
     };
 
     let py_mcts_result = PyMctsResult::from_mcts_result(mcts_result, &state);
@@ -943,17 +1041,81 @@ fn mcts(
 }
 
 #[pyfunction]
-fn id(py_state: PyState, duration_ms: u64) -> PyResult<PyIterativeDeepeningResult> {
-    let mut state: State = py_state.into();
+#[pyo3(signature = (py_state, duration_ms, prune_below=0.0))]
+fn id(
+    py_state: PyState,
+    duration_ms: u64,
+    prune_below: f32,
+) -> PyResult<PyIterativeDeepeningResult> {
+    let mut state = State::try_from(py_state)?;
     let duration = Duration::from_millis(duration_ms);
     let (s1_options, s2_options) = state.root_get_all_options();
-    let id_result = iterative_deepen_expectiminimax(&mut state, s1_options, s2_options, duration);
+    let id_result = iterative_deepen_expectiminimax(
+        &mut state,
+        s1_options,
+        s2_options,
+        duration,
+        ExpectiminimaxOptions {
+            pruning: BranchPruning {
+                min_percentage: prune_below,
+                ..BranchPruning::default()
+            },
+            ..ExpectiminimaxOptions::default()
+        },
+    );
 
     let py_id_result =
         PyIterativeDeepeningResult::from_iterative_deepening_result(id_result, &state);
     Ok(py_id_result)
 }
 
+#[derive(Clone)]
+#[pyclass(get_all, from_py_object)]
+struct PyEvaluationTerm {
+    side: String,
+    name: String,
+    value: f32,
+}
+
+#[derive(Clone)]
+#[pyclass(get_all, from_py_object)]
+struct PyEvaluationBreakdown {
+    terms: Vec<PyEvaluationTerm>,
+    total: f32,
+}
+
+impl From<EvaluationBreakdown> for PyEvaluationBreakdown {
+    fn from(other: EvaluationBreakdown) -> Self {
+        PyEvaluationBreakdown {
+            terms: other
+                .terms
+                .into_iter()
+                .map(|t| PyEvaluationTerm {
+                    side: match t.side {
+                        SideReference::SideOne => "side_one".to_string(),
+                        SideReference::SideTwo => "side_two".to_string(),
+                    },
+                    name: t.name,
+                    value: t.value,
+                })
+                .collect(),
+            total: other.total,
+        }
+    }
+}
+
+#[pyfunction(name = "evaluate")]
+fn py_evaluate(py_state: PyState) -> PyResult<f32> {
+    let state = State::try_from(py_state)?;
+    Ok(evaluate(&state))
+}
+
+#[pyfunction(name = "evaluate_explained")]
+fn py_evaluate_explained(py_state: PyState) -> PyResult<PyEvaluationBreakdown> {
+    let state = State::try_from(py_state)?;
+    Ok(evaluate_explained(&state).into())
+}
+
 #[derive(Clone)]
 #[pyclass(
     name = "StateInstructions",
@@ -1039,7 +1201,7 @@ fn generate_instructions(
     side_two_move: String,
 ) -> PyResult<Vec<PyStateInstructions>> {
     let (s1_move, s2_move);
-    let mut state: State = py_state.into();
+    let mut state = State::try_from(py_state)?;
     match MoveChoice::from_string(&side_one_move, &state.side_one) {
         Some(m) => s1_move = m,
         None => {
@@ -1071,9 +1233,9 @@ fn calculate_damage(
     side_two_move: String,
     side_one_moves_first: bool,
 ) -> PyResult<(Vec<i16>, Vec<i16>)> {
-    let state: State = py_state.into();
+    let state = State::try_from(py_state)?;
     let (mut s1_choice, mut s2_choice);
-    match MOVES.get(&Choices::from_str(side_one_move.as_str()).unwrap()) {
+    match moves(state.generation).get(&parse(side_one_move.as_str())?) {
         Some(m) => s1_choice = m.to_owned(),
         None => {
             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
@@ -1082,12 +1244,12 @@ fn calculate_damage(
             )))
         }
     }
-    match MOVES.get(&Choices::from_str(side_two_move.as_str()).unwrap()) {
+    match moves(state.generation).get(&parse(side_two_move.as_str())?) {
         Some(m) => s2_choice = m.to_owned(),
         None => {
             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
                 "Invalid move for s2: {}",
-                side_one_move
+                side_two_move
             )))
         }
     }
@@ -1121,6 +1283,8 @@ fn py_poke_engine(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(generate_instructions, m)?)?;
     m.add_function(wrap_pyfunction!(id, m)?)?;
     m.add_function(wrap_pyfunction!(mcts, m)?)?;
+    m.add_function(wrap_pyfunction!(py_evaluate, m)?)?;
+    m.add_function(wrap_pyfunction!(py_evaluate_explained, m)?)?;
     m.add_class::<PyState>()?;
     m.add_class::<PySide>()?;
     m.add_class::<PySideConditions>()?;
@@ -0,0 +1,291 @@
+use crate::control::SearchControl;
+use crate::engine::state::MoveChoice;
+use crate::evaluator::Evaluator;
+use crate::instruction::BranchPruning;
+use crate::mcts::{MctsConfig, MctsTree};
+use crate::nash::solve_nash_equilibrium;
+use crate::search::{
+    expectiminimax_search, iterative_deepen_expectiminimax, pick_safest, ExpectiminimaxOptions,
+    SubGameSolver, TranspositionTable, TRANSPOSITION_TABLE_SIZE,
+};
+use crate::state::State;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The search that is run on every state
+#[derive(Debug, Clone, Copy)]
+pub enum AnalysisSearch {
+    Expectiminimax {
+        depth: i8,
+        solver: SubGameSolver,
+        pruning: BranchPruning,
+    },
+    IterativeDeepening {
+        max_time: Duration,
+        solver: SubGameSolver,
+        pruning: BranchPruning,
+    },
+    MonteCarloTreeSearch {
+        max_time: Duration,
+        max_iterations: u32,
+        config: MctsConfig,
+    },
+}
+
+/// Side one's result of searching one state
+#[derive(Debug, Clone)]
+pub struct Analysis {
+    pub best_move: MoveChoice,
+
+    /// Each of side one's options with the probability of playing it: the policy of monte-carlo
+    /// tree search, the equilibrium strategy with `SubGameSolver::Nash`, or only the best move
+    /// with `SubGameSolver::Safest`
+    pub policy: Vec<(MoveChoice, f32)>,
+
+    /// The value of the state for side one. For monte-carlo tree search this is the best
+    /// move's average score (0 to 1), otherwise the value of the payoff matrix
+    pub evaluation: f32,
+
+    /// Iterations of monte-carlo tree search, 0 for the other searches
+    pub iterations: u32,
+
+    /// The depth expectiminimax searched to, 0 for monte-carlo tree search
+    pub depth: i8,
+
+    pub elapsed: Duration,
+}
+
+// side one's best move, the value and side one's strategy for a payoff matrix
+fn solve_matrix(
+    scores: &[f32],
+    side_one_options: &[MoveChoice],
+    side_two_options: &[MoveChoice],
+    solver: SubGameSolver,
+) -> (usize, f32, Vec<f32>) {
+    match solver {
+        SubGameSolver::Safest => {
+            let (best_index, value) =
+                pick_safest(scores, side_one_options.len(), side_two_options.len());
+            let strategy = (0..side_one_options.len())
+                .map(|i| if i == best_index { 1.0 } else { 0.0 })
+                .collect();
+            (best_index, value, strategy)
+        }
+        SubGameSolver::Nash => {
+            let equilibrium =
+                solve_nash_equilibrium(scores, side_one_options.len(), side_two_options.len());
+            (
+                equilibrium.s1_best_index(),
+                equilibrium.value,
+                equilibrium.s1_strategy,
+            )
+        }
+    }
+}
+
+/// Runs `search` on `state` from side one's perspective
+pub fn analyze(state: &mut State, search: &AnalysisSearch, evaluator: &dyn Evaluator) -> Analysis {
+    let start_time = Instant::now();
+    let (side_one_options, side_two_options) = state.root_get_all_options();
+    let (scores, side_one_options, side_two_options, solver, depth) = match *search {
+        AnalysisSearch::Expectiminimax {
+            depth,
+            solver,
+            pruning,
+        } => {
+            let scores = expectiminimax_search(
+                state,
+                depth,
+                side_one_options.clone(),
+                side_two_options.clone(),
+                ExpectiminimaxOptions {
+                    solver,
+                    pruning,
+                    transposition_table: Some(&mut TranspositionTable::new(
+                        TRANSPOSITION_TABLE_SIZE,
+                    )),
+                    evaluator,
+                    ..Default::default()
+                },
+                &SearchControl::default(),
+            );
+            (scores, side_one_options, side_two_options, solver, depth)
+        }
+        AnalysisSearch::IterativeDeepening {
+            max_time,
+            solver,
+            pruning,
+        } => {
+            let (side_one_options, side_two_options, scores, depth) =
+                iterative_deepen_expectiminimax(
+                    state,
+                    side_one_options,
+                    side_two_options,
+                    max_time,
+                    ExpectiminimaxOptions {
+                        solver,
+                        pruning,
+                        evaluator,
+                        ..Default::default()
+                    },
+                );
+            (scores, side_one_options, side_two_options, solver, depth)
+        }
+        AnalysisSearch::MonteCarloTreeSearch {
+            max_time,
+            max_iterations,
+            config,
+        } => {
+            let result = MctsTree::with_config(config).search(
+                state,
+                side_one_options,
+                side_two_options,
+                max_time,
+                max_iterations,
+                evaluator,
+            );
+            let best = result.s1.iter().max_by_key(|r| r.visits);
+            return Analysis {
+                best_move: best.map(|r| r.move_choice).unwrap_or(MoveChoice::None),
+                policy: result
+                    .s1
+                    .iter()
+                    .map(|r| (r.move_choice, r.policy))
+                    .collect(),
+                evaluation: best
+                    .map(|r| r.total_score / r.visits.max(1) as f32)
+                    .unwrap_or(0.0),
+                iterations: result.iteration_count,
+                depth: 0,
+                elapsed: start_time.elapsed(),
+            };
+        }
+    };
+
+    let (best_index, evaluation, strategy) =
+        solve_matrix(&scores, &side_one_options, &side_two_options, solver);
+    Analysis {
+        best_move: side_one_options[best_index],
+        policy: side_one_options.into_iter().zip(strategy).collect(),
+        evaluation,
+        iterations: 0,
+        depth,
+        elapsed: start_time.elapsed(),
+    }
+}
+
+/// Runs `search` on every state, analyzing up to `threads` states at the same time.
+/// `on_result` is called with the index of each state and its analysis, in the order of `states`
+pub fn analyze_all(
+    states: &[State],
+    search: &AnalysisSearch,
+    threads: usize,
+    evaluator: &dyn Evaluator,
+    mut on_result: impl FnMut(usize, Analysis),
+) {
+    let next_state = AtomicUsize::new(0);
+    let (sender, receiver) = channel();
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            let sender = sender.clone();
+            let next_state = &next_state;
+            scope.spawn(move || loop {
+                let index = next_state.fetch_add(1, Ordering::Relaxed);
+                if index >= states.len() {
+                    break;
+                }
+                let mut state = states[index].clone();
+                let analysis = analyze(&mut state, search, evaluator);
+                if sender.send((index, analysis)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // results that finish early wait here until every state before them is done
+        let mut finished = BTreeMap::new();
+        let mut next_result = 0;
+        for (index, analysis) in receiver {
+            finished.insert(index, analysis);
+            while let Some(analysis) = finished.remove(&next_result) {
+                on_result(next_result, analysis);
+                next_result += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::Choices;
+    use crate::evaluator::DefaultEvaluator;
+    use crate::state::PokemonMoveIndex;
+
+    fn states() -> Vec<State> {
+        let mut state = State::default();
+        state.side_one.get_active().replace_move(
+            PokemonMoveIndex::M0,
+            Choices::TACKLE,
+            state.generation,
+        );
+        state.side_one.get_active().replace_move(
+            PokemonMoveIndex::M1,
+            Choices::SPLASH,
+            state.generation,
+        );
+        vec![state.clone(), State::default(), state]
+    }
+
+    #[test]
+    fn test_every_state_is_analyzed_in_order() {
+        let states = states();
+        let search = AnalysisSearch::Expectiminimax {
+            depth: 1,
+            solver: SubGameSolver::Safest,
+            pruning: BranchPruning::default(),
+        };
+        let mut indices = vec![];
+        analyze_all(&states, &search, 2, &DefaultEvaluator, |index, analysis| {
+            indices.push(index);
+            assert_eq!(1, analysis.depth);
+        });
+        assert_eq!(vec![0, 1, 2], indices);
+    }
+
+    #[test]
+    fn test_safest_policy_is_the_best_move() {
+        let mut state = states().remove(0);
+        let search = AnalysisSearch::Expectiminimax {
+            depth: 1,
+            solver: SubGameSolver::Safest,
+            pruning: BranchPruning::default(),
+        };
+        let analysis = analyze(&mut state, &search, &DefaultEvaluator);
+
+        assert_eq!(MoveChoice::Move(PokemonMoveIndex::M0), analysis.best_move);
+        assert!(analysis
+            .policy
+            .iter()
+            .all(|(m, p)| (*m == analysis.best_move) == (*p == 1.0)));
+    }
+
+    #[test]
+    fn test_mcts_analysis_counts_iterations() {
+        let mut state = states().remove(0);
+        let search = AnalysisSearch::MonteCarloTreeSearch {
+            max_time: Duration::ZERO,
+            max_iterations: 2000,
+            config: MctsConfig::default(),
+        };
+        let analysis = analyze(&mut state, &search, &DefaultEvaluator);
+
+        assert_eq!(2000, analysis.iterations);
+        let policy_total: f32 = analysis.policy.iter().map(|(_, p)| p).sum();
+        assert!((policy_total - 1.0).abs() < 1e-5);
+    }
+}
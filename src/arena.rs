@@ -0,0 +1,530 @@
+use crate::control::SearchControl;
+use crate::engine::state::MoveChoice;
+use crate::evaluator::Evaluator;
+use crate::instruction::BranchPruning;
+use crate::mcts::{MctsConfig, MctsResult, MctsTree};
+use crate::mcts_threaded::SharedMctsTree;
+use crate::search::{
+    expectiminimax_search, iterative_deepen_expectiminimax, pick_safest, ExpectiminimaxOptions,
+    TranspositionTable, TRANSPOSITION_TABLE_SIZE,
+};
+use crate::simulate::{simulate, Policy};
+use crate::state::{SideReference, State};
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+// z-score of a 95% confidence interval
+const CONFIDENCE_Z: f32 = 1.96;
+
+/// A way of picking moves that can play in the arena
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Agent {
+    Random,
+    Expectiminimax {
+        depth: i8,
+    },
+    IterativeDeepening {
+        max_time: Duration,
+    },
+    MonteCarloTreeSearch {
+        max_time: Duration,
+        max_iterations: u32,
+        threads: usize,
+    },
+}
+
+// the value of `key` in an agent's comma separated settings, which must fit in `T`
+fn agent_setting<T: FromStr>(agent: &str, settings: &str, key: &str) -> Result<Option<T>, String> {
+    for pair in settings.split(',').filter(|pair| !pair.is_empty()) {
+        match pair.split_once('=') {
+            Some((k, value)) if k == key => {
+                return value
+                    .parse::<T>()
+                    .map(Some)
+                    .map_err(|_| format!("invalid {} in agent {}", key, agent))
+            }
+            Some(_) => {}
+            None => {
+                return Err(format!(
+                    "expected key=value, got {} in agent {}",
+                    pair, agent
+                ))
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// `random`, `expectiminimax:depth=2`, `id:time=100` or `mcts:time=100,iterations=0,threads=1`.
+/// Settings that are not given keep these defaults
+impl FromStr for Agent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, settings) = s.split_once(':').unwrap_or((s, ""));
+        match name {
+            "random" => Ok(Agent::Random),
+            "expectiminimax" | "e" => Ok(Agent::Expectiminimax {
+                depth: agent_setting(s, settings, "depth")?.unwrap_or(2),
+            }),
+            "iterative-deepening" | "id" => Ok(Agent::IterativeDeepening {
+                max_time: Duration::from_millis(agent_setting(s, settings, "time")?.unwrap_or(100)),
+            }),
+            "monte-carlo-tree-search" | "mcts" => Ok(Agent::MonteCarloTreeSearch {
+                max_time: Duration::from_millis(agent_setting(s, settings, "time")?.unwrap_or(100)),
+                max_iterations: agent_setting(s, settings, "iterations")?.unwrap_or(0),
+                threads: agent_setting(s, settings, "threads")?.unwrap_or(1),
+            }),
+            _ => Err(format!("unknown agent {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Agent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Agent::Random => write!(f, "random"),
+            Agent::Expectiminimax { depth } => write!(f, "expectiminimax:depth={}", depth),
+            Agent::IterativeDeepening { max_time } => write!(f, "id:time={}", max_time.as_millis()),
+            Agent::MonteCarloTreeSearch {
+                max_time,
+                max_iterations,
+                threads,
+            } => write!(
+                f,
+                "mcts:time={},iterations={},threads={}",
+                max_time.as_millis(),
+                max_iterations,
+                threads
+            ),
+        }
+    }
+}
+
+fn most_visited(result: &MctsResult) -> MoveChoice {
+    result
+        .s1
+        .iter()
+        .max_by_key(|r| r.visits)
+        .map(|r| r.move_choice)
+        .unwrap_or(MoveChoice::None)
+}
+
+impl Agent {
+    /// `side_ref`'s move in `state`. Every search is from side one's perspective,
+    /// so side two searches a copy of the state with the sides swapped
+    pub fn choose(
+        &self,
+        state: &State,
+        side_ref: &SideReference,
+        options: &[MoveChoice],
+        pruning: BranchPruning,
+        evaluator: &dyn Evaluator,
+        rng: &mut StdRng,
+    ) -> MoveChoice {
+        if *self == Agent::Random {
+            return options[rng.random_range(0..options.len())];
+        }
+        let mut state = state.clone();
+        if *side_ref == SideReference::SideTwo {
+            std::mem::swap(&mut state.side_one, &mut state.side_two);
+        }
+        state.update_zobrist_hash();
+        let (side_one_options, side_two_options) = state.root_get_all_options();
+        match *self {
+            Agent::Random => unreachable!(),
+            Agent::Expectiminimax { depth } => {
+                let result = expectiminimax_search(
+                    &mut state,
+                    depth,
+                    side_one_options.clone(),
+                    side_two_options.clone(),
+                    ExpectiminimaxOptions {
+                        pruning,
+                        transposition_table: Some(&mut TranspositionTable::new(
+                            TRANSPOSITION_TABLE_SIZE,
+                        )),
+                        evaluator,
+                        ..Default::default()
+                    },
+                    &SearchControl::default(),
+                );
+                let (index, _) =
+                    pick_safest(&result, side_one_options.len(), side_two_options.len());
+                side_one_options[index]
+            }
+            Agent::IterativeDeepening { max_time } => {
+                let (side_one_options, side_two_options, result, _) =
+                    iterative_deepen_expectiminimax(
+                        &mut state,
+                        side_one_options,
+                        side_two_options,
+                        max_time,
+                        ExpectiminimaxOptions {
+                            pruning,
+                            evaluator,
+                            ..Default::default()
+                        },
+                    );
+                let (index, _) =
+                    pick_safest(&result, side_one_options.len(), side_two_options.len());
+                side_one_options[index]
+            }
+            Agent::MonteCarloTreeSearch {
+                max_time,
+                max_iterations,
+                threads,
+            } => {
+                let config = MctsConfig {
+                    pruning,
+                    threads,
+                    seed: Some(rng.random()),
+                    ..Default::default()
+                };
+                let result = if threads > 1 {
+                    SharedMctsTree::with_config(config).search(
+                        &mut state,
+                        side_one_options,
+                        side_two_options,
+                        max_time,
+                        max_iterations,
+                        evaluator,
+                    )
+                } else {
+                    MctsTree::with_config(config).search(
+                        &mut state,
+                        side_one_options,
+                        side_two_options,
+                        max_time,
+                        max_iterations,
+                        evaluator,
+                    )
+                };
+                most_visited(&result)
+            }
+        }
+    }
+}
+
+struct AgentPolicy<'a> {
+    agent: &'a Agent,
+    pruning: BranchPruning,
+    evaluator: &'a dyn Evaluator,
+}
+
+impl Policy for AgentPolicy<'_> {
+    fn choose(
+        &mut self,
+        state: &State,
+        side_ref: &SideReference,
+        options: &[MoveChoice],
+        rng: &mut StdRng,
+    ) -> MoveChoice {
+        self.agent
+            .choose(state, side_ref, options, self.pruning, self.evaluator, rng)
+    }
+}
+
+/// One agent's games against another
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Record {
+    pub wins: u32,
+    pub losses: u32,
+    /// Games that reached `max_turns` without a winner
+    pub draws: u32,
+}
+
+impl Record {
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+
+    /// Wins plus half of the draws, out of the games played
+    pub fn score(&self) -> f32 {
+        if self.games() == 0 {
+            return 0.5;
+        }
+        (self.wins as f32 + 0.5 * self.draws as f32) / self.games() as f32
+    }
+
+    /// The 95% Wilson score interval of `score`
+    pub fn score_interval(&self) -> (f32, f32) {
+        if self.games() == 0 {
+            return (0.0, 1.0);
+        }
+        let n = self.games() as f32;
+        let p = self.score();
+        let z2 = CONFIDENCE_Z * CONFIDENCE_Z;
+        let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+        let half_width =
+            CONFIDENCE_Z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / (1.0 + z2 / n);
+        (
+            (center - half_width).max(0.0),
+            (center + half_width).min(1.0),
+        )
+    }
+
+    fn add(&mut self, other: &Record) {
+        self.wins += other.wins;
+        self.losses += other.losses;
+        self.draws += other.draws;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ArenaResult {
+    pub agents: Vec<Agent>,
+
+    /// `records[i][j]` is agent i's record against agent j
+    pub records: Vec<Vec<Record>>,
+
+    /// Each agent's Elo rating. The ratings average to 0, so only their differences mean anything
+    pub elo: Vec<f32>,
+}
+
+impl ArenaResult {
+    /// An agent's record against every other agent
+    pub fn total(&self, agent: usize) -> Record {
+        let mut total = Record::default();
+        for record in self.records[agent].iter() {
+            total.add(record);
+        }
+        total
+    }
+}
+
+/// Elo ratings that best explain the records (a Bradley-Terry model fit by minorization-maximization).
+/// Each pair of agents that played gets one extra drawn game so that an agent that won or lost
+/// every game still has a finite rating. The ratings average to 0
+pub fn elo_ratings(records: &[Vec<Record>]) -> Vec<f32> {
+    let agent_count = records.len();
+    let mut scores = vec![0.0; agent_count];
+    let mut games = vec![vec![0.0; agent_count]; agent_count];
+    for i in 0..agent_count {
+        for j in 0..agent_count {
+            let record = &records[i][j];
+            if i == j || record.games() == 0 {
+                continue;
+            }
+            scores[i] += record.wins as f32 + 0.5 * record.draws as f32 + 0.5;
+            games[i][j] = record.games() as f32 + 1.0;
+        }
+    }
+
+    let mut strengths = vec![1.0f32; agent_count];
+    for _ in 0..1000 {
+        let mut next: Vec<f32> = (0..agent_count)
+            .map(|i| {
+                let denominator: f32 = (0..agent_count)
+                    .filter(|j| *j != i)
+                    .map(|j| games[i][j] / (strengths[i] + strengths[j]))
+                    .sum();
+                if denominator > 0.0 {
+                    scores[i] / denominator
+                } else {
+                    strengths[i]
+                }
+            })
+            .collect();
+        let log_mean = next.iter().map(|s| s.ln()).sum::<f32>() / agent_count as f32;
+        for strength in next.iter_mut() {
+            *strength /= log_mean.exp();
+        }
+        let converged = next
+            .iter()
+            .zip(strengths.iter())
+            .all(|(a, b)| (a - b).abs() < 1e-6);
+        strengths = next;
+        if converged {
+            break;
+        }
+    }
+    strengths.iter().map(|s| 400.0 * s.log10()).collect()
+}
+
+/// Plays every pair of `agents` against each other from each of `starting_states`, twice:
+/// once with each agent as side one, using the same seed both times. Games are played on
+/// `threads` threads and a game that reaches `max_turns` is a draw.
+/// Every agent's search uses `pruning`
+pub fn play_arena(
+    agents: &[Agent],
+    starting_states: &[State],
+    max_turns: usize,
+    seed: u64,
+    threads: usize,
+    pruning: BranchPruning,
+    evaluator: &dyn Evaluator,
+) -> ArenaResult {
+    let mut games = vec![];
+    for first in 0..agents.len() {
+        for second in first + 1..agents.len() {
+            for state_index in 0..starting_states.len() {
+                games.push((first, second, state_index));
+                games.push((second, first, state_index));
+            }
+        }
+    }
+
+    let records = Mutex::new(vec![vec![Record::default(); agents.len()]; agents.len()]);
+    let next_game = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| loop {
+                let game = next_game.fetch_add(1, Ordering::Relaxed);
+                if game >= games.len() {
+                    break;
+                }
+                let (side_one, side_two, state_index) = games[game];
+                let mut state = starting_states[state_index].clone();
+                let result = simulate(
+                    &mut state,
+                    &mut AgentPolicy {
+                        agent: &agents[side_one],
+                        pruning,
+                        evaluator,
+                    },
+                    &mut AgentPolicy {
+                        agent: &agents[side_two],
+                        pruning,
+                        evaluator,
+                    },
+                    seed.wrapping_add(state_index as u64),
+                    max_turns,
+                );
+
+                let mut records = records.lock().unwrap();
+                match result.winner {
+                    Some(SideReference::SideOne) => {
+                        records[side_one][side_two].wins += 1;
+                        records[side_two][side_one].losses += 1;
+                    }
+                    Some(SideReference::SideTwo) => {
+                        records[side_one][side_two].losses += 1;
+                        records[side_two][side_one].wins += 1;
+                    }
+                    None => {
+                        records[side_one][side_two].draws += 1;
+                        records[side_two][side_one].draws += 1;
+                    }
+                }
+            });
+        }
+    });
+
+    let records = records.into_inner().unwrap();
+    ArenaResult {
+        agents: agents.to_vec(),
+        elo: elo_ratings(&records),
+        records,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::Choices;
+    use crate::evaluator::DefaultEvaluator;
+    use crate::state::{pokemon_index_iter, PokemonMoveIndex};
+
+    fn record(wins: u32, losses: u32, draws: u32) -> Record {
+        Record {
+            wins,
+            losses,
+            draws,
+        }
+    }
+
+    #[test]
+    fn test_agents_parse_from_their_display() {
+        for agent in [
+            Agent::Random,
+            Agent::Expectiminimax { depth: 3 },
+            Agent::IterativeDeepening {
+                max_time: Duration::from_millis(250),
+            },
+            Agent::MonteCarloTreeSearch {
+                max_time: Duration::from_millis(50),
+                max_iterations: 1000,
+                threads: 2,
+            },
+        ] {
+            assert_eq!(Ok(agent), Agent::from_str(&agent.to_string()));
+        }
+        assert_eq!(
+            Ok(Agent::MonteCarloTreeSearch {
+                max_time: Duration::from_millis(100),
+                max_iterations: 0,
+                threads: 4,
+            }),
+            Agent::from_str("mcts:threads=4")
+        );
+        assert!(Agent::from_str("mcts:fast").is_err());
+        // values that do not fit the setting are errors rather than being truncated
+        assert!(Agent::from_str("expectiminimax:depth=300").is_err());
+        assert!(Agent::from_str("mcts:iterations=5000000000").is_err());
+    }
+
+    #[test]
+    fn test_equal_records_give_equal_ratings() {
+        let records = vec![
+            vec![record(0, 0, 0), record(5, 5, 0)],
+            vec![record(5, 5, 0), record(0, 0, 0)],
+        ];
+        let elo = elo_ratings(&records);
+        assert!(elo[0].abs() < 0.01);
+        assert!(elo[1].abs() < 0.01);
+    }
+
+    #[test]
+    fn test_elo_difference_matches_the_score() {
+        // a 75% score is an Elo difference of 400 * log10(3)
+        let records = vec![
+            vec![record(0, 0, 0), record(299, 99, 0)],
+            vec![record(99, 299, 0), record(0, 0, 0)],
+        ];
+        let elo = elo_ratings(&records);
+        assert!((elo[0] - elo[1] - 400.0 * 3f32.log10()).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_score_interval_contains_the_score() {
+        let record = record(30, 10, 0);
+        let (low, high) = record.score_interval();
+        assert!(low < 0.75 && 0.75 < high);
+        assert!(high - low < 0.3);
+    }
+
+    #[test]
+    fn test_each_pair_plays_both_sides() {
+        let mut state = State::default();
+        for side in [&mut state.side_one, &mut state.side_two] {
+            for index in pokemon_index_iter() {
+                side.pokemon[index].replace_move(
+                    PokemonMoveIndex::M0,
+                    Choices::TACKLE,
+                    state.generation,
+                );
+            }
+        }
+        let agents = [Agent::Random, Agent::Expectiminimax { depth: 1 }];
+        let result = play_arena(
+            &agents,
+            &[state],
+            100,
+            1,
+            2,
+            BranchPruning::default(),
+            &DefaultEvaluator,
+        );
+
+        assert_eq!(2, result.total(0).games());
+        assert_eq!(result.records[0][1].wins, result.records[1][0].losses);
+        assert!((result.elo[0] + result.elo[1]).abs() < 0.01);
+    }
+}
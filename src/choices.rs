@@ -1,14 +1,34 @@
 use crate::define_enum_with_from_str;
 use crate::engine::state::PokemonVolatileStatus;
 use crate::state::{
-    PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, PokemonSideCondition, PokemonStatus,
-    PokemonType,
+    Generation, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, PokemonSideCondition,
+    PokemonStatus, PokemonType,
 };
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::LazyLock;
 
-pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
+/// The moves of the default generation. See `moves` for other generations
+pub static MOVES: LazyLock<&'static HashMap<Choices, Choice>> =
+    LazyLock::new(|| moves(Generation::default()));
+
+static MOVES_BY_GENERATION: [LazyLock<HashMap<Choices, Choice>>; 9] = [
+    LazyLock::new(|| build_moves(Generation::GEN1)),
+    LazyLock::new(|| build_moves(Generation::GEN2)),
+    LazyLock::new(|| build_moves(Generation::GEN3)),
+    LazyLock::new(|| build_moves(Generation::GEN4)),
+    LazyLock::new(|| build_moves(Generation::GEN5)),
+    LazyLock::new(|| build_moves(Generation::GEN6)),
+    LazyLock::new(|| build_moves(Generation::GEN7)),
+    LazyLock::new(|| build_moves(Generation::GEN8)),
+    LazyLock::new(|| build_moves(Generation::GEN9)),
+];
+
+pub fn moves(generation: Generation) -> &'static HashMap<Choices, Choice> {
+    &MOVES_BY_GENERATION[generation as usize]
+}
+
+fn build_moves(generation: Generation) -> HashMap<Choices, Choice> {
     let mut moves: HashMap<Choices, Choice> = HashMap::new();
     moves.insert(
         Choices::NONE,
@@ -49,7 +69,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::ACID,
             Choice {
@@ -76,7 +96,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen2") || cfg!(feature = "gen3") {
+    } else if generation == Generation::GEN2 || generation == Generation::GEN3 {
         moves.insert(
             Choices::ACID,
             Choice {
@@ -274,11 +294,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::AIRCUTTER,
@@ -363,7 +383,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::AMNESIA,
             Choice {
@@ -645,11 +665,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::ASSURANCE,
@@ -748,11 +768,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::AURASPHERE,
@@ -814,7 +834,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::AURORABEAM,
             Choice {
@@ -1090,10 +1110,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::BEATUP,
@@ -1213,10 +1233,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::BIND,
@@ -1260,7 +1280,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::BITE,
             Choice {
@@ -1433,7 +1453,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::BLIZZARD,
             Choice {
@@ -1455,10 +1475,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    } else if generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::BLIZZARD,
@@ -1655,10 +1675,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::BONERUSH,
@@ -1847,7 +1867,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::BUBBLE,
             Choice {
@@ -1874,10 +1894,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    } else if generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::BUBBLE,
@@ -1933,7 +1953,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::BUBBLEBEAM,
             Choice {
@@ -2096,10 +2116,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::BULLETSEED,
@@ -2367,11 +2387,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::CHATTER,
@@ -2505,10 +2525,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::CLAMP,
@@ -2802,7 +2822,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::CONSTRICT,
             Choice {
@@ -2967,10 +2987,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::COTTONSPORE,
@@ -3051,10 +3071,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::COVET,
@@ -3088,11 +3108,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::CRABHAMMER,
@@ -3182,7 +3202,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") {
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+    {
         moves.insert(
             Choices::CRUNCH,
             Choice {
@@ -3361,12 +3384,12 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
-        || cfg!(feature = "gen6")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
+        || generation == Generation::GEN6
     {
         moves.insert(
             Choices::DARKVOID,
@@ -3550,12 +3573,12 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
         },
     );
 
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
-        || cfg!(feature = "gen6")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
+        || generation == Generation::GEN6
     {
         moves.insert(
             Choices::DIAMONDSTORM,
@@ -3613,7 +3636,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::DIG,
             Choice {
@@ -3630,7 +3653,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen2") || cfg!(feature = "gen3") {
+    } else if generation == Generation::GEN2 || generation == Generation::GEN3 {
         moves.insert(
             Choices::DIG,
             Choice {
@@ -3680,10 +3703,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::DISABLE,
@@ -3756,7 +3779,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") {
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+    {
         moves.insert(
             Choices::DIVE,
             Choice {
@@ -3791,7 +3817,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::DIZZYPUNCH,
             Choice {
@@ -3842,10 +3868,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::DOOMDESIRE,
@@ -3876,7 +3902,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::DOUBLEEDGE,
             Choice {
@@ -4017,11 +4043,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::DRACOMETEOR,
@@ -4217,11 +4243,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::DRAGONPULSE,
@@ -4324,10 +4350,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::DRAINPUNCH,
@@ -4800,11 +4826,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::ENERGYBALL,
@@ -4945,7 +4971,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::EXPLOSION,
             Choice {
@@ -5151,10 +5177,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::FEINT,
@@ -5201,12 +5227,12 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
-        || cfg!(feature = "gen6")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
+        || generation == Generation::GEN6
     {
         moves.insert(
             Choices::FELLSTINGER,
@@ -5336,7 +5362,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::FIREBLAST,
             Choice {
@@ -5357,10 +5383,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    } else if generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::FIREBLAST,
@@ -5460,11 +5486,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::FIREPLEDGE,
@@ -5517,10 +5543,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::FIRESPIN,
@@ -5663,11 +5689,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::FLAMETHROWER,
@@ -5960,12 +5986,12 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
-        || cfg!(feature = "gen6")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
+        || generation == Generation::GEN6
     {
         moves.insert(
             Choices::FLYINGPRESS,
@@ -6234,11 +6260,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::FROSTBREATH,
@@ -6302,10 +6328,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::FURYCUTTER,
@@ -6324,7 +6350,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen5") {
+    } else if generation == Generation::GEN5 {
         moves.insert(
             Choices::FURYCUTTER,
             Choice {
@@ -6403,10 +6429,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::FUTURESIGHT,
@@ -6422,7 +6448,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen5") {
+    } else if generation == Generation::GEN5 {
         moves.insert(
             Choices::FUTURESIGHT,
             Choice {
@@ -6520,10 +6546,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::GIGADRAIN,
@@ -6591,7 +6617,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
         },
     );
 
-    if cfg!(feature = "gen9") {
+    if generation == Generation::GEN9 {
         moves.insert(
             Choices::GLACIALLANCE,
             Choice {
@@ -6664,10 +6690,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::GLARE,
@@ -6687,7 +6713,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen5") {
+    } else if generation == Generation::GEN5 {
         moves.insert(
             Choices::GLARE,
             Choice {
@@ -6754,11 +6780,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::GRASSPLEDGE,
@@ -6810,7 +6836,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
         },
     );
 
-    if cfg!(feature = "gen9") {
+    if generation == Generation::GEN9 {
         moves.insert(
             Choices::GRASSYGLIDE,
             Choice {
@@ -6916,10 +6942,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::GROWTH,
@@ -7024,11 +7050,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::GUNKSHOT,
@@ -7072,7 +7098,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::GUST,
             Choice {
@@ -7428,11 +7454,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::HEATWAVE,
@@ -7509,11 +7535,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::HEX,
@@ -8023,7 +8049,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") {
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+    {
         moves.insert(
             Choices::HIGHJUMPKICK,
             Choice {
@@ -8041,7 +8070,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen4") {
+    } else if generation == Generation::GEN4 {
         moves.insert(
             Choices::HIGHJUMPKICK,
             Choice {
@@ -8199,11 +8228,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::HURRICANE,
@@ -8265,11 +8294,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::HYDROPUMP,
@@ -8456,11 +8485,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::ICEBEAM,
@@ -8651,10 +8680,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::ICICLESPEAR,
@@ -8730,11 +8759,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::INCINERATE,
@@ -8996,7 +9025,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") {
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+    {
         moves.insert(
             Choices::JUMPKICK,
             Choice {
@@ -9014,7 +9046,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen4") {
+    } else if generation == Generation::GEN4 {
         moves.insert(
             Choices::JUMPKICK,
             Choice {
@@ -9068,7 +9100,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::KARATECHOP,
             Choice {
@@ -9143,11 +9175,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::KNOCKOFF,
@@ -9242,10 +9274,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::LASTRESORT,
@@ -9326,7 +9358,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") {
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+    {
         moves.insert(
             Choices::LEAFBLADE,
             Choice {
@@ -9361,11 +9396,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::LEAFSTORM,
@@ -9448,12 +9483,12 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
-        || cfg!(feature = "gen6")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
+        || generation == Generation::GEN6
     {
         moves.insert(
             Choices::LEECHLIFE,
@@ -9534,11 +9569,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::LICK,
@@ -9615,7 +9650,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::LIGHTSCREEN,
             Choice {
@@ -9707,7 +9742,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") || cfg!(feature = "gen2") {
+    if generation == Generation::GEN1 || generation == Generation::GEN2 {
         moves.insert(
             Choices::LOWKICK,
             Choice {
@@ -9745,11 +9780,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::LOWSWEEP,
@@ -9906,7 +9941,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen9") {
+    if generation == Generation::GEN9 {
         moves.insert(
             Choices::LUSTERPURGE,
             Choice {
@@ -10053,10 +10088,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::MAGMASTORM,
@@ -10077,7 +10112,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen5") {
+    } else if generation == Generation::GEN5 {
         moves.insert(
             Choices::MAGMASTORM,
             Choice {
@@ -10493,11 +10528,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::METEORMASH,
@@ -10753,7 +10788,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen9") {
+    if generation == Generation::GEN9 {
         moves.insert(
             Choices::MISTBALL,
             Choice {
@@ -10977,11 +11012,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::MUDDYWATER,
@@ -11103,7 +11138,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen9") || cfg!(feature = "gen8") {
+    if generation == Generation::GEN9 || generation == Generation::GEN8 {
         moves.insert(
             Choices::MULTIATTACK,
             Choice {
@@ -11136,12 +11171,12 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
-        || cfg!(feature = "gen6")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
+        || generation == Generation::GEN6
     {
         moves.insert(
             Choices::MYSTICALFIRE,
@@ -11630,7 +11665,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") {
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+    {
         moves.insert(
             Choices::OUTRAGE,
             Choice {
@@ -11686,11 +11724,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::OVERHEAT,
@@ -11784,12 +11822,12 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
-        || cfg!(feature = "gen6")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
+        || generation == Generation::GEN6
     {
         moves.insert(
             Choices::PARABOLICCHARGE,
@@ -11922,7 +11960,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") {
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+    {
         moves.insert(
             Choices::PETALDANCE,
             Choice {
@@ -11943,7 +11984,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen4") {
+    if generation == Generation::GEN4 {
         moves.insert(
             Choices::PETALDANCE,
             Choice {
@@ -12026,11 +12067,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::PINMISSILE,
@@ -12146,11 +12187,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::POISONFANG,
@@ -12196,10 +12237,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::POISONGAS,
@@ -12219,7 +12260,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen5") {
+    } else if generation == Generation::GEN5 {
         moves.insert(
             Choices::POISONGAS,
             Choice {
@@ -12297,7 +12338,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::POISONSTING,
             Choice {
@@ -12485,11 +12526,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::POWERGEM,
@@ -12732,7 +12773,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::PSYCHIC,
             Choice {
@@ -12874,11 +12915,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::PSYCHOSHIFT,
@@ -12974,11 +13015,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::PSYWAVE,
@@ -13235,7 +13276,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
         },
     );
 
-    if cfg!(feature = "gen9") || cfg!(feature = "gen8") {
+    if generation == Generation::GEN9 || generation == Generation::GEN8 {
         moves.insert(
             Choices::RAPIDSPIN,
             Choice {
@@ -13382,7 +13423,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::REFLECT,
             Choice {
@@ -13623,10 +13664,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::ROCKBLAST,
@@ -13706,7 +13747,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::ROCKSLIDE,
             Choice {
@@ -13744,7 +13785,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") {
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+    {
         moves.insert(
             Choices::ROCKSMASH,
             Choice {
@@ -13801,7 +13845,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::ROCKTHROW,
             Choice {
@@ -13834,11 +13878,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::ROCKTOMB,
@@ -14092,7 +14136,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::SANDATTACK,
             Choice {
@@ -14176,10 +14220,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::SANDTOMB,
@@ -14282,10 +14326,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::SCARYFACE,
@@ -14508,7 +14552,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::SELFDESTRUCT,
             Choice {
@@ -15032,11 +15076,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::SKULLBASH,
@@ -15206,7 +15250,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::SLUDGE,
             Choice {
@@ -15319,11 +15363,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::SMELLINGSALTS,
@@ -15357,11 +15401,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::SMOG,
@@ -15507,11 +15551,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::SNORE,
@@ -16246,11 +16290,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::STORMTHROW,
@@ -16373,11 +16417,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::STRUGGLEBUG,
@@ -16497,12 +16541,12 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
-        || cfg!(feature = "gen6")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
+        || generation == Generation::GEN6
     {
         moves.insert(
             Choices::SUCKERPUNCH,
@@ -16641,11 +16685,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::SURF,
@@ -16694,12 +16738,12 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
         },
     );
 
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
-        || cfg!(feature = "gen6")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
+        || generation == Generation::GEN6
     {
         moves.insert(
             Choices::SWAGGER,
@@ -16865,11 +16909,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::SYNCHRONOISE,
@@ -16969,10 +17013,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::TAILGLOW,
@@ -17187,11 +17231,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::TECHNOBLAST,
@@ -17257,7 +17301,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
         },
     );
 
-    if cfg!(feature = "gen9") || cfg!(feature = "gen8") {
+    if generation == Generation::GEN9 || generation == Generation::GEN8 {
         moves.insert(
             Choices::TELEPORT,
             Choice {
@@ -17345,11 +17389,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::THIEF,
@@ -17415,10 +17459,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::THRASH,
@@ -17479,7 +17523,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::THUNDER,
             Choice {
@@ -17500,10 +17544,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
                 ..Default::default()
             },
         );
-    } else if cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    } else if generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::THUNDER,
@@ -17547,11 +17591,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::THUNDERBOLT,
@@ -17723,13 +17767,13 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
-        || cfg!(feature = "gen6")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
+        || generation == Generation::GEN6
     {
         moves.insert(
             Choices::THUNDERWAVE,
@@ -17873,10 +17917,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::TOXIC,
@@ -17998,7 +18042,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::TRIATTACK,
             Choice {
@@ -18281,10 +18325,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::UPROAR,
@@ -18440,11 +18484,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::VINEWHIP,
@@ -18545,11 +18589,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::WAKEUPSLAP,
@@ -18617,11 +18661,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::WATERPLEDGE,
@@ -18674,12 +18718,12 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
         },
     );
 
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
-        || cfg!(feature = "gen6")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
+        || generation == Generation::GEN6
     {
         moves.insert(
             Choices::WATERSHURIKEN,
@@ -18770,10 +18814,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::WHIRLPOOL,
@@ -18831,7 +18875,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
         },
     );
 
-    if cfg!(feature = "gen9") {
+    if generation == Generation::GEN9 {
         moves.insert(
             Choices::WICKEDBLOW,
             Choice {
@@ -18940,11 +18984,11 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
-        || cfg!(feature = "gen5")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
+        || generation == Generation::GEN5
     {
         moves.insert(
             Choices::WILLOWISP,
@@ -18984,7 +19028,7 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             },
         );
     }
-    if cfg!(feature = "gen1") {
+    if generation == Generation::GEN1 {
         moves.insert(
             Choices::WINGATTACK,
             Choice {
@@ -19116,10 +19160,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1")
-        || cfg!(feature = "gen2")
-        || cfg!(feature = "gen3")
-        || cfg!(feature = "gen4")
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+        || generation == Generation::GEN4
     {
         moves.insert(
             Choices::WRAP,
@@ -19210,7 +19254,10 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
             ..Default::default()
         },
     );
-    if cfg!(feature = "gen1") || cfg!(feature = "gen2") || cfg!(feature = "gen3") {
+    if generation == Generation::GEN1
+        || generation == Generation::GEN2
+        || generation == Generation::GEN3
+    {
         moves.insert(
             Choices::ZAPCANNON,
             Choice {
@@ -19325,13 +19372,15 @@ pub static MOVES: LazyLock<HashMap<Choices, Choice>> = LazyLock::new(|| {
         },
     );
 
-    #[cfg(any(feature = "gen1", feature = "gen2", feature = "gen3"))]
-    undo_physical_special_split(&mut moves);
+    if generation <= Generation::GEN3 {
+        undo_physical_special_split(&mut moves);
+    }
 
     moves
-});
+}
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MoveCategory {
     Physical,
     Special,
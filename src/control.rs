@@ -0,0 +1,93 @@
+use crate::engine::state::MoveChoice;
+use crate::mcts::MctsResult;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Stops a search early. Clones share the same flag, so a clone can be kept by whatever
+/// decides when to stop (e.g. the thread that receives the opponent's move)
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// How a running search is doing, reported to `SearchControl::on_progress`
+pub enum SearchProgress<'a> {
+    /// Iterative deepening finished searching to `depth`
+    Depth {
+        depth: i8,
+        best_move: MoveChoice,
+        side_one_options: &'a [MoveChoice],
+        side_two_options: &'a [MoveChoice],
+        scores: &'a [f32],
+    },
+
+    /// An expectiminimax search to `depth` finished the first `finished_rows` rows of the root,
+    /// i.e. every reply to that many of side one's moves. `scores` holds those rows
+    Row {
+        depth: i8,
+        finished_rows: usize,
+        best_move: MoveChoice,
+        side_one_options: &'a [MoveChoice],
+        side_two_options: &'a [MoveChoice],
+        scores: &'a [f32],
+    },
+
+    /// The root of a monte-carlo tree search so far
+    Iterations {
+        best_move: MoveChoice,
+        result: &'a MctsResult,
+    },
+}
+
+impl SearchProgress<'_> {
+    /// Side one's best move so far
+    pub fn best_move(&self) -> MoveChoice {
+        match self {
+            SearchProgress::Depth { best_move, .. } => *best_move,
+            SearchProgress::Row { best_move, .. } => *best_move,
+            SearchProgress::Iterations { best_move, .. } => *best_move,
+        }
+    }
+}
+
+/// Lets the caller of a search stop it early and follow its progress
+pub struct SearchControl<'a> {
+    pub cancellation: CancellationToken,
+
+    /// Called with the search's progress. Iterative deepening reports every finished depth,
+    /// a single expectiminimax search reports every finished row of the root and
+    /// monte-carlo tree search reports every `progress_interval`
+    pub on_progress: Option<&'a dyn Fn(&SearchProgress)>,
+    pub progress_interval: Duration,
+}
+
+impl Default for SearchControl<'_> {
+    fn default() -> Self {
+        SearchControl {
+            cancellation: CancellationToken::new(),
+            on_progress: None,
+            progress_interval: Duration::from_millis(100),
+        }
+    }
+}
+
+impl SearchControl<'_> {
+    pub(crate) fn report(&self, progress: SearchProgress) {
+        if let Some(on_progress) = self.on_progress {
+            on_progress(&progress);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::evaluator::Evaluator;
+use crate::mcts::{sample_probabilities, set_policies, MctsConfig, MctsSideResult, MctsTree};
+use crate::state::{Pokemon, Side, State};
+use rand::Rng;
+use std::time::Duration;
+
+/// What side one knows about side two's hidden information.
+/// Weights are relative and do not need to add up to 1
+#[derive(Debug, Clone)]
+pub enum OpponentCandidates {
+    /// Complete sides that side two could be
+    Sides(Vec<(Side, f32)>),
+
+    /// Candidates for each of side two's pokemon, in the order of `Side::pokemon`.
+    /// A pokemon without candidates is kept as it is in the state
+    Pokemon(Vec<Vec<(Pokemon, f32)>>),
+}
+
+pub(crate) fn sample_weighted<'a, T>(
+    candidates: &'a [(T, f32)],
+    rng: &mut impl Rng,
+) -> Result<&'a T, String> {
+    let weights: Vec<f32> = candidates
+        .iter()
+        .map(|(_, weight)| weight.max(0.0))
+        .collect();
+    if weights.iter().sum::<f32>() <= 0.0 {
+        return Err("Candidates must have a positive total weight".to_string());
+    }
+    Ok(&candidates[sample_probabilities(&weights, rng)].0)
+}
+
+impl OpponentCandidates {
+    /// A copy of `state` with side two's hidden information filled in by a sample of the candidates
+    pub fn determinize(&self, state: &State, rng: &mut impl Rng) -> Result<State, String> {
+        let mut determinized = state.clone();
+        match self {
+            OpponentCandidates::Sides(sides) => {
+                determinized.side_two = sample_weighted(sides, rng)?.clone();
+            }
+            OpponentCandidates::Pokemon(candidates) => {
+                if candidates.len() > determinized.side_two.pokemon.pkmn.len() {
+                    return Err(format!(
+                        "Got candidates for {} pokemon but a side has {}",
+                        candidates.len(),
+                        determinized.side_two.pokemon.pkmn.len()
+                    ));
+                }
+                for (pokemon, pokemon_candidates) in determinized
+                    .side_two
+                    .pokemon
+                    .pkmn
+                    .iter_mut()
+                    .zip(candidates.iter())
+                {
+                    if !pokemon_candidates.is_empty() {
+                        *pokemon = sample_weighted(pokemon_candidates, rng)?.clone();
+                    }
+                }
+            }
+        }
+        determinized.update_zobrist_hash();
+        Ok(determinized)
+    }
+}
+
+/// Side one's root moves from a determinized search, totalled over every sample
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeterminizedResult {
+    pub s1: Vec<MctsSideResult>,
+    pub samples: u32,
+    pub iteration_count: u32,
+}
+
+/// Determinized monte-carlo tree search: searches `samples` states with side two sampled from
+/// `candidates` and adds up side one's root statistics. Side two's moves are not aggregated
+/// because they refer to different pokemon in each sample.
+///
+/// `max_time` and `max_iterations` are shared equally between the samples
+pub fn determinized_mcts(
+    state: &State,
+    candidates: &OpponentCandidates,
+    samples: u32,
+    max_time: Duration,
+    max_iterations: u32,
+    evaluator: &dyn Evaluator,
+    config: MctsConfig,
+) -> Result<DeterminizedResult, String> {
+    if samples == 0 {
+        return Err("A determinized search needs at least one sample".to_string());
+    }
+    let mut rng = config.rng(0);
+    let mut result = DeterminizedResult {
+        s1: Vec::new(),
+        samples,
+        iteration_count: 0,
+    };
+    for sample in 0..samples {
+        let mut determinized = candidates.determinize(state, &mut rng)?;
+        let (side_one_options, side_two_options) = determinized.root_get_all_options();
+        let sample_config = MctsConfig {
+            seed: config.seed.map(|seed| seed.wrapping_add(sample as u64 + 1)),
+            ..config
+        };
+        let sample_result = MctsTree::with_config(sample_config).search(
+            &mut determinized,
+            side_one_options,
+            side_two_options,
+            max_time / samples,
+            if max_iterations > 0 {
+                (max_iterations / samples).max(1)
+            } else {
+                0
+            },
+            evaluator,
+        );
+
+        result.iteration_count += sample_result.iteration_count;
+        for side_result in sample_result.s1 {
+            match result
+                .s1
+                .iter_mut()
+                .find(|r| r.move_choice == side_result.move_choice)
+            {
+                Some(total) => {
+                    total.total_score += side_result.total_score;
+                    total.visits += side_result.visits;
+                }
+                None => result.s1.push(side_result),
+            }
+        }
+    }
+    set_policies(&mut result.s1, &config);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::DefaultEvaluator;
+    use crate::pokemon::PokemonName;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn pokemon(id: PokemonName) -> Pokemon {
+        Pokemon {
+            id,
+            ..Pokemon::default()
+        }
+    }
+
+    #[test]
+    fn test_determinizing_only_replaces_pokemon_with_candidates() {
+        let state = State::default();
+        let candidates = OpponentCandidates::Pokemon(vec![
+            vec![],
+            vec![
+                (pokemon(PokemonName::PIKACHU), 0.0),
+                (pokemon(PokemonName::CHARMANDER), 1.0),
+            ],
+        ]);
+
+        let determinized = candidates
+            .determinize(&state, &mut StdRng::seed_from_u64(1))
+            .unwrap();
+        assert_eq!(
+            state.side_two.pokemon.pkmn[0].id,
+            determinized.side_two.pokemon.pkmn[0].id
+        );
+        assert_eq!(
+            PokemonName::CHARMANDER,
+            determinized.side_two.pokemon.pkmn[1].id
+        );
+        assert_eq!(
+            state.side_one.serialize(),
+            determinized.side_one.serialize()
+        );
+    }
+
+    #[test]
+    fn test_candidates_without_weight_cannot_be_sampled() {
+        let candidates = OpponentCandidates::Sides(vec![(Side::default(), 0.0)]);
+        assert!(candidates
+            .determinize(&State::default(), &mut StdRng::seed_from_u64(1))
+            .is_err());
+    }
+
+    #[test]
+    fn test_determinized_search_adds_up_every_sample() {
+        let state = State::default();
+        let mut other_side = state.side_two.clone();
+        other_side.pokemon.pkmn[0].id = PokemonName::CHARMANDER;
+        let candidates =
+            OpponentCandidates::Sides(vec![(state.side_two.clone(), 1.0), (other_side, 1.0)]);
+
+        let result = determinized_mcts(
+            &state,
+            &candidates,
+            4,
+            Duration::from_millis(0),
+            4000,
+            &DefaultEvaluator,
+            MctsConfig::default(),
+        )
+        .unwrap();
+
+        let (side_one_options, _) = state.root_get_all_options();
+        assert_eq!(side_one_options.len(), result.s1.len());
+        assert_eq!(
+            result.iteration_count,
+            result.s1.iter().map(|r| r.visits).sum::<u32>()
+        );
+        let policy_total: f32 = result.s1.iter().map(|r| r.policy).sum();
+        assert!((policy_total - 1.0).abs() < 1e-5);
+    }
+}
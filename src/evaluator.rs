@@ -0,0 +1,332 @@
+use crate::engine::evaluate::{evaluate, evaluate_explained_with_weights, evaluate_with_weights};
+use crate::state::{Pokemon, SideReference, State};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Scores a state from side one's perspective: positive is good for side one.
+///
+/// Searches can run on several threads at once so an evaluator must be `Sync`
+pub trait Evaluator: Sync {
+    fn evaluate(&self, state: &State) -> f32;
+}
+
+impl<F> Evaluator for F
+where
+    F: Fn(&State) -> f32 + Sync,
+{
+    fn evaluate(&self, state: &State) -> f32 {
+        self(state)
+    }
+}
+
+/// The engine's built-in evaluation with its default weights
+pub struct DefaultEvaluator;
+
+impl Evaluator for DefaultEvaluator {
+    fn evaluate(&self, state: &State) -> f32 {
+        evaluate(state)
+    }
+}
+
+/// One term of an evaluation, e.g. a pokemon's hp or the stealth rock on a side
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EvaluationTerm {
+    pub side: SideReference,
+    pub name: String,
+    /// How much the term added to the evaluation. Terms for side two count against side one,
+    /// so a side two term that is good for side two has a negative value
+    pub value: f32,
+}
+
+/// Every term of an evaluation that did not score zero
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EvaluationBreakdown {
+    pub terms: Vec<EvaluationTerm>,
+    /// The sum of every term, the same score the evaluation returns
+    pub total: f32,
+}
+
+impl fmt::Display for EvaluationBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for term in self.terms.iter() {
+            let side = match term.side {
+                SideReference::SideOne => "side one",
+                SideReference::SideTwo => "side two",
+            };
+            writeln!(f, "{:<10}{:<32}{:>10.2}", side, term.name, term.value)?;
+        }
+        write!(f, "{:<42}{:>10.2}", "total", self.total)
+    }
+}
+
+/// Where an evaluation adds up its terms.
+///
+/// Searches add them to a plain `f32`, where the name of a term is never built
+pub(crate) trait EvaluationTerms {
+    /// `value` is from `side`'s point of view
+    fn add(&mut self, side: SideReference, name: impl FnOnce() -> String, value: f32);
+}
+
+impl EvaluationTerms for f32 {
+    #[inline]
+    fn add(&mut self, side: SideReference, _name: impl FnOnce() -> String, value: f32) {
+        match side {
+            SideReference::SideOne => *self += value,
+            SideReference::SideTwo => *self -= value,
+        }
+    }
+}
+
+impl EvaluationTerms for EvaluationBreakdown {
+    fn add(&mut self, side: SideReference, name: impl FnOnce() -> String, value: f32) {
+        if value == 0.0 {
+            return;
+        }
+        let value = match side {
+            SideReference::SideOne => value,
+            SideReference::SideTwo => -value,
+        };
+        self.total += value;
+        self.terms.push(EvaluationTerm {
+            side,
+            name: name(),
+            value,
+        });
+    }
+}
+
+/// The name of a term that belongs to one pokemon, e.g. `garchomp hp`
+pub(crate) fn pokemon_term(pokemon: &Pokemon, term: &str) -> String {
+    format!("{} {}", pokemon.id.to_string().to_lowercase(), term)
+}
+
+macro_rules! define_evaluation_weights {
+    ($($name:ident: $default:expr),+ $(,)?) => {
+        /// The weights used by the built-in evaluation.
+        ///
+        /// Not every generation uses every weight, e.g. gen1 has no special defense boosts
+        #[derive(Debug, Clone, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub struct EvaluationWeights {
+            $(pub $name: f32),+
+        }
+
+        impl EvaluationWeights {
+            pub const DEFAULT: EvaluationWeights = EvaluationWeights {
+                $($name: $default),+
+            };
+
+            fn get_mut(&mut self, name: &str) -> Option<&mut f32> {
+                match name {
+                    $(stringify!($name) => Some(&mut self.$name),)+
+                    _ => None,
+                }
+            }
+
+            /// Writes every weight in the format read by `EvaluationWeights::parse`
+            pub fn serialize(&self) -> String {
+                let mut lines = vec![];
+                $(lines.push(format!("{} = {}", stringify!($name), self.$name));)+
+                lines.join("\n")
+            }
+        }
+    };
+}
+
+define_evaluation_weights! {
+    pokemon_alive: 30.0,
+    pokemon_hp: 100.0,
+    pokemon_item: 10.0,
+    used_tera: -75.0,
+
+    pokemon_attack_boost: 30.0,
+    pokemon_defense_boost: 15.0,
+    pokemon_special_attack_boost: 30.0,
+    pokemon_special_defense_boost: 15.0,
+    pokemon_speed_boost: 30.0,
+
+    pokemon_boost_multiplier_6: 3.3,
+    pokemon_boost_multiplier_5: 3.15,
+    pokemon_boost_multiplier_4: 3.0,
+    pokemon_boost_multiplier_3: 2.5,
+    pokemon_boost_multiplier_2: 2.0,
+    pokemon_boost_multiplier_1: 1.0,
+    pokemon_boost_multiplier_0: 0.0,
+    pokemon_boost_multiplier_neg_1: -1.0,
+    pokemon_boost_multiplier_neg_2: -2.0,
+    pokemon_boost_multiplier_neg_3: -2.5,
+    pokemon_boost_multiplier_neg_4: -3.0,
+    pokemon_boost_multiplier_neg_5: -3.15,
+    pokemon_boost_multiplier_neg_6: -3.3,
+
+    pokemon_frozen: -40.0,
+    pokemon_asleep: -25.0,
+    pokemon_paralyzed: -25.0,
+    pokemon_toxic: -30.0,
+    pokemon_poisoned: -10.0,
+    pokemon_burned: -25.0,
+
+    leech_seed: -30.0,
+    substitute: 40.0,
+    confusion: -20.0,
+
+    reflect: 20.0,
+    light_screen: 20.0,
+    aurora_veil: 40.0,
+    safe_guard: 5.0,
+    tailwind: 7.0,
+    healing_wish: 30.0,
+
+    stealth_rock: -10.0,
+    spikes: -7.0,
+    toxic_spikes: -7.0,
+    sticky_web: -25.0,
+
+    // gen3 values spikes by the number of layers
+    spikes_one_layer: -12.0,
+    spikes_two_layer: -16.0,
+    spikes_three_layer: -25.0,
+}
+
+impl Default for EvaluationWeights {
+    fn default() -> EvaluationWeights {
+        EvaluationWeights::DEFAULT
+    }
+}
+
+impl EvaluationWeights {
+    pub fn boost_multiplier(&self, boost: i8) -> f32 {
+        match boost {
+            6 => self.pokemon_boost_multiplier_6,
+            5 => self.pokemon_boost_multiplier_5,
+            4 => self.pokemon_boost_multiplier_4,
+            3 => self.pokemon_boost_multiplier_3,
+            2 => self.pokemon_boost_multiplier_2,
+            1 => self.pokemon_boost_multiplier_1,
+            0 => self.pokemon_boost_multiplier_0,
+            -1 => self.pokemon_boost_multiplier_neg_1,
+            -2 => self.pokemon_boost_multiplier_neg_2,
+            -3 => self.pokemon_boost_multiplier_neg_3,
+            -4 => self.pokemon_boost_multiplier_neg_4,
+            -5 => self.pokemon_boost_multiplier_neg_5,
+            -6 => self.pokemon_boost_multiplier_neg_6,
+            _ => panic!("Invalid boost value: {}", boost),
+        }
+    }
+
+    /// Parses one `name = value` pair per line. Blank lines and lines starting with `#`
+    /// are ignored, and any weight that is not given keeps its default value
+    ///
+    /// ```
+    /// use poke_engine::evaluator::EvaluationWeights;
+    ///
+    /// let weights = EvaluationWeights::parse("# fainting matters more\npokemon_alive = 50").unwrap();
+    /// assert_eq!(weights.pokemon_alive, 50.0);
+    /// assert_eq!(weights.pokemon_hp, EvaluationWeights::DEFAULT.pokemon_hp);
+    /// ```
+    pub fn parse(text: &str) -> Result<EvaluationWeights, String> {
+        let mut weights = EvaluationWeights::DEFAULT;
+        for (line_number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (name, value) = match line.split_once('=') {
+                Some((name, value)) => (name.trim(), value.trim()),
+                None => return Err(format!("line {}: expected name = value", line_number + 1)),
+            };
+            let value = match value.parse::<f32>() {
+                Ok(value) => value,
+                Err(_) => return Err(format!("line {}: invalid value {}", line_number + 1, value)),
+            };
+            match weights.get_mut(name) {
+                Some(weight) => *weight = value,
+                None => return Err(format!("line {}: unknown weight {}", line_number + 1, name)),
+            }
+        }
+        Ok(weights)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<EvaluationWeights, String> {
+        match fs::read_to_string(&path) {
+            Ok(text) => EvaluationWeights::parse(&text),
+            Err(e) => Err(format!("{}: {}", path.as_ref().display(), e)),
+        }
+    }
+}
+
+impl Evaluator for EvaluationWeights {
+    fn evaluate(&self, state: &State) -> f32 {
+        evaluate_with_weights(state, self)
+    }
+}
+
+impl EvaluationWeights {
+    /// Every term that went into `evaluate` with these weights
+    pub fn explain(&self, state: &State) -> EvaluationBreakdown {
+        evaluate_explained_with_weights(state, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialized_weights_parse_to_the_same_weights() {
+        let mut weights = EvaluationWeights::DEFAULT;
+        weights.stealth_rock = -12.5;
+        weights.pokemon_boost_multiplier_neg_6 = -4.0;
+        assert_eq!(
+            weights,
+            EvaluationWeights::parse(&weights.serialize()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unknown_weight_is_an_error() {
+        assert!(EvaluationWeights::parse("not_a_weight = 1.0").is_err());
+        assert!(EvaluationWeights::parse("pokemon_hp = lots").is_err());
+    }
+
+    #[test]
+    fn test_explained_terms_add_up_to_the_evaluation() {
+        let mut state = State::default();
+        state.side_one.attack_boost = 2;
+        state.side_two.speed_boost = -1;
+        state.side_two.get_active().hp = 50;
+
+        let breakdown = EvaluationWeights::DEFAULT.explain(&state);
+        let sum: f32 = breakdown.terms.iter().map(|t| t.value).sum();
+        assert!((breakdown.total - DefaultEvaluator.evaluate(&state)).abs() < 0.001);
+        assert!((breakdown.total - sum).abs() < 0.001);
+
+        let attack_boost = breakdown
+            .terms
+            .iter()
+            .find(|t| t.side == SideReference::SideOne && t.name == "attack boost")
+            .unwrap();
+        assert_eq!(2.0 * 30.0, attack_boost.value);
+        let speed_drop = breakdown
+            .terms
+            .iter()
+            .find(|t| t.side == SideReference::SideTwo && t.name == "speed boost")
+            .unwrap();
+        assert_eq!(30.0, speed_drop.value);
+    }
+
+    #[test]
+    fn test_default_weights_match_the_default_evaluator() {
+        let mut state = State::default();
+        state.side_one.attack_boost = 2;
+        state.side_two.side_conditions.stealth_rock = 1;
+        assert_eq!(
+            DefaultEvaluator.evaluate(&state),
+            EvaluationWeights::default().evaluate(&state)
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use super::items::get_choice_move_disable_instructions;
-use super::state::{PokemonVolatileStatus, Weather};
 use crate::choices::{Choice, Choices, MoveTarget};
+use crate::engine::state::{PokemonVolatileStatus, Weather};
 use crate::instruction::{
     ApplyVolatileStatusInstruction, ChangeStatusInstruction, ChangeSubsituteHealthInstruction,
     DamageInstruction, HealInstruction, Instruction, SetSleepTurnsInstruction, StateInstructions,
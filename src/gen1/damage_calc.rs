@@ -1,5 +1,6 @@
-use super::state::PokemonVolatileStatus;
+use super::state::calculate_boosted_stat;
 use crate::choices::{Choice, MoveCategory};
+use crate::engine::state::PokemonVolatileStatus;
 use crate::state::{
     Pokemon, PokemonBoostableStat, PokemonStatus, PokemonType, Side, SideReference, State,
 };
@@ -175,18 +176,18 @@ pub fn calculate_damage(
     if choice.category == MoveCategory::Physical {
         attacking_stat = attacker.attack;
         boosted_attacking_stat =
-            attacking_side.calculate_boosted_stat(PokemonBoostableStat::Attack);
+            calculate_boosted_stat(attacking_side, PokemonBoostableStat::Attack);
         defending_stat = defender.defense;
         boosted_defending_stat =
-            defending_side.calculate_boosted_stat(PokemonBoostableStat::Defense);
+            calculate_boosted_stat(defending_side, PokemonBoostableStat::Defense);
     } else {
         // gen1 uses spa as spc
         attacking_stat = attacker.special_attack;
         boosted_attacking_stat =
-            attacking_side.calculate_boosted_stat(PokemonBoostableStat::SpecialAttack);
+            calculate_boosted_stat(attacking_side, PokemonBoostableStat::SpecialAttack);
         defending_stat = defender.special_attack;
         boosted_defending_stat =
-            defending_side.calculate_boosted_stat(PokemonBoostableStat::SpecialAttack);
+            calculate_boosted_stat(defending_side, PokemonBoostableStat::SpecialAttack);
     }
 
     let mut damage = common_pkmn_damage_calc(
@@ -1,44 +1,9 @@
-use super::state::PokemonVolatileStatus;
 use crate::choices::MoveCategory;
-use crate::state::{Pokemon, PokemonStatus, State};
+use crate::engine::state::PokemonVolatileStatus;
+use crate::evaluator::{pokemon_term, EvaluationBreakdown, EvaluationTerms, EvaluationWeights};
+use crate::state::{Pokemon, PokemonStatus, Side, SideReference, State};
 
-const POKEMON_ALIVE: f32 = 30.0;
-const POKEMON_HP: f32 = 100.0;
-
-const POKEMON_ATTACK_BOOST: f32 = 30.0;
-const POKEMON_DEFENSE_BOOST: f32 = 15.0;
-const POKEMON_SPECIAL_ATTACK_BOOST: f32 = 30.0;
-const POKEMON_SPEED_BOOST: f32 = 30.0;
-
-const POKEMON_BOOST_MULTIPLIER_6: f32 = 3.3;
-const POKEMON_BOOST_MULTIPLIER_5: f32 = 3.15;
-const POKEMON_BOOST_MULTIPLIER_4: f32 = 3.0;
-const POKEMON_BOOST_MULTIPLIER_3: f32 = 2.5;
-const POKEMON_BOOST_MULTIPLIER_2: f32 = 2.0;
-const POKEMON_BOOST_MULTIPLIER_1: f32 = 1.0;
-const POKEMON_BOOST_MULTIPLIER_0: f32 = 0.0;
-const POKEMON_BOOST_MULTIPLIER_NEG_1: f32 = -1.0;
-const POKEMON_BOOST_MULTIPLIER_NEG_2: f32 = -2.0;
-const POKEMON_BOOST_MULTIPLIER_NEG_3: f32 = -2.5;
-const POKEMON_BOOST_MULTIPLIER_NEG_4: f32 = -3.0;
-const POKEMON_BOOST_MULTIPLIER_NEG_5: f32 = -3.15;
-const POKEMON_BOOST_MULTIPLIER_NEG_6: f32 = -3.3;
-
-const POKEMON_FROZEN: f32 = -40.0;
-const POKEMON_ASLEEP: f32 = -25.0;
-const POKEMON_PARALYZED: f32 = -25.0;
-const POKEMON_TOXIC: f32 = -30.0;
-const POKEMON_POISONED: f32 = -10.0;
-const POKEMON_BURNED: f32 = -25.0;
-
-const LEECH_SEED: f32 = -30.0;
-const SUBSTITUTE: f32 = 40.0;
-const CONFUSION: f32 = -20.0;
-
-const REFLECT: f32 = 20.0;
-const LIGHT_SCREEN: f32 = 20.0;
-
-fn evaluate_burned(pokemon: &Pokemon) -> f32 {
+fn evaluate_burned(pokemon: &Pokemon, weights: &EvaluationWeights) -> f32 {
     // burn is not as punishing in certain situations
 
     let mut multiplier = 0.0;
@@ -53,150 +18,142 @@ fn evaluate_burned(pokemon: &Pokemon) -> f32 {
         multiplier /= 2.0;
     }
 
-    multiplier * POKEMON_BURNED
-}
-
-fn get_boost_multiplier(boost: i8) -> f32 {
-    match boost {
-        6 => POKEMON_BOOST_MULTIPLIER_6,
-        5 => POKEMON_BOOST_MULTIPLIER_5,
-        4 => POKEMON_BOOST_MULTIPLIER_4,
-        3 => POKEMON_BOOST_MULTIPLIER_3,
-        2 => POKEMON_BOOST_MULTIPLIER_2,
-        1 => POKEMON_BOOST_MULTIPLIER_1,
-        0 => POKEMON_BOOST_MULTIPLIER_0,
-        -1 => POKEMON_BOOST_MULTIPLIER_NEG_1,
-        -2 => POKEMON_BOOST_MULTIPLIER_NEG_2,
-        -3 => POKEMON_BOOST_MULTIPLIER_NEG_3,
-        -4 => POKEMON_BOOST_MULTIPLIER_NEG_4,
-        -5 => POKEMON_BOOST_MULTIPLIER_NEG_5,
-        -6 => POKEMON_BOOST_MULTIPLIER_NEG_6,
-        _ => panic!("Invalid boost value: {}", boost),
-    }
+    multiplier * weights.pokemon_burned
 }
 
-fn evaluate_pokemon(pokemon: &Pokemon) -> f32 {
-    let mut score = 0.0;
-    score += POKEMON_HP * pokemon.hp as f32 / pokemon.maxhp as f32;
-
-    match pokemon.status {
-        PokemonStatus::BURN => score += evaluate_burned(pokemon),
-        PokemonStatus::FREEZE => score += POKEMON_FROZEN,
-        PokemonStatus::SLEEP => score += POKEMON_ASLEEP,
-        PokemonStatus::PARALYZE => score += POKEMON_PARALYZED,
-        PokemonStatus::TOXIC => score += POKEMON_TOXIC,
-        PokemonStatus::POISON => score += POKEMON_POISONED,
-        PokemonStatus::NONE => {}
-    }
-
+fn evaluate_pokemon(
+    pokemon: &Pokemon,
+    side_ref: SideReference,
+    weights: &EvaluationWeights,
+    terms: &mut impl EvaluationTerms,
+) {
+    let hp = weights.pokemon_hp * pokemon.hp as f32 / pokemon.maxhp as f32;
+    let status = match pokemon.status {
+        PokemonStatus::BURN => evaluate_burned(pokemon, weights),
+        PokemonStatus::FREEZE => weights.pokemon_frozen,
+        PokemonStatus::SLEEP => weights.pokemon_asleep,
+        PokemonStatus::PARALYZE => weights.pokemon_paralyzed,
+        PokemonStatus::TOXIC => weights.pokemon_toxic,
+        PokemonStatus::POISON => weights.pokemon_poisoned,
+        PokemonStatus::NONE => 0.0,
+    };
+    terms.add(side_ref, || pokemon_term(pokemon, "hp"), hp);
+    terms.add(
+        side_ref,
+        || pokemon_term(pokemon, &pokemon.status.to_string().to_lowercase()),
+        status,
+    );
+
+    // without this a low hp pokemon could get a negative score and incentivize the other side
+    // to keep it alive
+    let score = hp + status;
     if score < 0.0 {
-        score = 0.0;
+        terms.add(side_ref, || pokemon_term(pokemon, "score floor"), -score);
     }
 
-    score += POKEMON_ALIVE;
-
-    score
+    terms.add(
+        side_ref,
+        || pokemon_term(pokemon, "alive"),
+        weights.pokemon_alive,
+    );
 }
 
-pub fn evaluate(state: &State) -> f32 {
-    let mut score = 0.0;
-
-    let mut iter = state.side_one.pokemon.into_iter();
-    while let Some(pkmn) = iter.next() {
-        if pkmn.hp > 0 {
-            score += evaluate_pokemon(pkmn);
-            if iter.pokemon_index == state.side_one.active_index {
-                if state
-                    .side_one
-                    .volatile_statuses
-                    .contains(&PokemonVolatileStatus::LEECHSEED)
-                {
-                    score += LEECH_SEED;
-                }
-                if state
-                    .side_one
-                    .volatile_statuses
-                    .contains(&PokemonVolatileStatus::SUBSTITUTE)
-                {
-                    score += SUBSTITUTE;
-                }
-                if state
-                    .side_one
-                    .volatile_statuses
-                    .contains(&PokemonVolatileStatus::CONFUSION)
-                {
-                    score += CONFUSION;
-                }
-                if state
-                    .side_one
-                    .volatile_statuses
-                    .contains(&PokemonVolatileStatus::REFLECT)
-                {
-                    score += REFLECT;
-                }
-                if state
-                    .side_one
-                    .volatile_statuses
-                    .contains(&PokemonVolatileStatus::LIGHTSCREEN)
-                {
-                    score += LIGHT_SCREEN;
-                }
-                score += get_boost_multiplier(state.side_one.attack_boost) * POKEMON_ATTACK_BOOST;
-                score += get_boost_multiplier(state.side_one.defense_boost) * POKEMON_DEFENSE_BOOST;
-                score += get_boost_multiplier(state.side_one.special_attack_boost)
-                    * POKEMON_SPECIAL_ATTACK_BOOST;
-                score += get_boost_multiplier(state.side_one.speed_boost) * POKEMON_SPEED_BOOST;
-            }
-        }
-    }
-    let mut iter = state.side_two.pokemon.into_iter();
+fn evaluate_side(
+    side: &Side,
+    side_ref: SideReference,
+    weights: &EvaluationWeights,
+    terms: &mut impl EvaluationTerms,
+) {
+    let mut iter = side.pokemon.into_iter();
     while let Some(pkmn) = iter.next() {
         if pkmn.hp > 0 {
-            score -= evaluate_pokemon(pkmn);
-            if iter.pokemon_index == state.side_two.active_index {
-                if state
-                    .side_two
+            evaluate_pokemon(pkmn, side_ref, weights, terms);
+            if iter.pokemon_index == side.active_index {
+                if side
                     .volatile_statuses
                     .contains(&PokemonVolatileStatus::LEECHSEED)
                 {
-                    score -= LEECH_SEED;
+                    terms.add(side_ref, || "leech seed".to_string(), weights.leech_seed);
                 }
-                if state
-                    .side_two
+                if side
                     .volatile_statuses
                     .contains(&PokemonVolatileStatus::SUBSTITUTE)
                 {
-                    score -= SUBSTITUTE;
+                    terms.add(side_ref, || "substitute".to_string(), weights.substitute);
                 }
-                if state
-                    .side_two
+                if side
                     .volatile_statuses
                     .contains(&PokemonVolatileStatus::CONFUSION)
                 {
-                    score -= CONFUSION;
+                    terms.add(side_ref, || "confusion".to_string(), weights.confusion);
                 }
-                if state
-                    .side_two
+                if side
                     .volatile_statuses
                     .contains(&PokemonVolatileStatus::REFLECT)
                 {
-                    score -= REFLECT;
+                    terms.add(side_ref, || "reflect".to_string(), weights.reflect);
                 }
-                if state
-                    .side_two
+                if side
                     .volatile_statuses
                     .contains(&PokemonVolatileStatus::LIGHTSCREEN)
                 {
-                    score -= LIGHT_SCREEN;
+                    terms.add(
+                        side_ref,
+                        || "light screen".to_string(),
+                        weights.light_screen,
+                    );
                 }
-                score -= get_boost_multiplier(state.side_two.attack_boost) * POKEMON_ATTACK_BOOST;
-                score -= get_boost_multiplier(state.side_two.defense_boost) * POKEMON_DEFENSE_BOOST;
-                score -= get_boost_multiplier(state.side_two.special_attack_boost)
-                    * POKEMON_SPECIAL_ATTACK_BOOST;
-                score -= get_boost_multiplier(state.side_two.speed_boost) * POKEMON_SPEED_BOOST;
+                terms.add(
+                    side_ref,
+                    || "attack boost".to_string(),
+                    weights.boost_multiplier(side.attack_boost) * weights.pokemon_attack_boost,
+                );
+                terms.add(
+                    side_ref,
+                    || "defense boost".to_string(),
+                    weights.boost_multiplier(side.defense_boost) * weights.pokemon_defense_boost,
+                );
+                terms.add(
+                    side_ref,
+                    || "special attack boost".to_string(),
+                    weights.boost_multiplier(side.special_attack_boost)
+                        * weights.pokemon_special_attack_boost,
+                );
+                terms.add(
+                    side_ref,
+                    || "speed boost".to_string(),
+                    weights.boost_multiplier(side.speed_boost) * weights.pokemon_speed_boost,
+                );
             }
         }
     }
+}
 
+fn evaluate_terms(state: &State, weights: &EvaluationWeights, terms: &mut impl EvaluationTerms) {
+    evaluate_side(&state.side_one, SideReference::SideOne, weights, terms);
+    evaluate_side(&state.side_two, SideReference::SideTwo, weights, terms);
+}
+
+pub fn evaluate(state: &State) -> f32 {
+    evaluate_with_weights(state, &EvaluationWeights::DEFAULT)
+}
+
+pub fn evaluate_with_weights(state: &State, weights: &EvaluationWeights) -> f32 {
+    let mut score = 0.0;
+    evaluate_terms(state, weights, &mut score);
     score
 }
+
+/// Every term that went into `evaluate`, useful for finding out why a state scored the way it did
+pub fn evaluate_explained(state: &State) -> EvaluationBreakdown {
+    evaluate_explained_with_weights(state, &EvaluationWeights::DEFAULT)
+}
+
+pub fn evaluate_explained_with_weights(
+    state: &State,
+    weights: &EvaluationWeights,
+) -> EvaluationBreakdown {
+    let mut breakdown = EvaluationBreakdown::default();
+    evaluate_terms(state, weights, &mut breakdown);
+    breakdown
+}
@@ -3,11 +3,15 @@ use super::choice_effects::{
     modify_choice,
 };
 use super::damage_calc::{calculate_damage, DamageRolls};
-use super::state::{MoveChoice, PokemonVolatileStatus};
+use super::state::{
+    calculate_boosted_stat, crit_rate, has_alive_frozen_pokemon,
+    remove_volatile_statuses_on_switch, reset_toxic, volatile_status_can_be_applied,
+};
 use crate::choices::{
     Boost, Choice, Choices, Effect, Heal, MoveCategory, MoveTarget, MultiHitMove, Secondary,
     Status, VolatileStatus,
 };
+use crate::engine::state::{MoveChoice, PokemonVolatileStatus};
 use crate::instruction::{
     ApplyVolatileStatusInstruction, BoostInstruction, ChangeDamageDealtDamageInstruction,
     ChangeDamageDealtMoveCategoryInstruction, ChangeSideConditionInstruction,
@@ -146,11 +150,13 @@ pub fn generate_instructions_from_switch(
         &switching_side_ref,
         &mut incoming_instructions.instruction_list,
     );
-    state.remove_volatile_statuses_on_switch(
+    remove_volatile_statuses_on_switch(
+        state,
         &switching_side_ref,
         &mut incoming_instructions.instruction_list,
     );
-    state.reset_toxic(
+    reset_toxic(
+        state,
         &switching_side_ref,
         &mut incoming_instructions.instruction_list,
     );
@@ -189,7 +195,8 @@ fn get_instructions_from_volatile_statuses(
 
     let side = state.get_side(&target_side);
     let affected_pkmn = side.get_active_immutable();
-    if affected_pkmn.volatile_status_can_be_applied(
+    if volatile_status_can_be_applied(
+        affected_pkmn,
         &volatile_status.volatile_status,
         &side.volatile_statuses,
         attacker_choice.first_move,
@@ -292,7 +299,7 @@ pub fn immune_to_status(
         match status {
             PokemonStatus::BURN => target_pkmn.has_type(&PokemonType::FIRE),
             PokemonStatus::FREEZE => {
-                target_pkmn.has_type(&PokemonType::ICE) || target_side.has_alive_frozen_pokemon()
+                target_pkmn.has_type(&PokemonType::ICE) || has_alive_frozen_pokemon(target_side)
             }
             PokemonStatus::SLEEP => {
                 // sleep clause
@@ -991,8 +998,8 @@ fn generate_instructions_from_existing_status_conditions(
         let mut hit_yourself_instruction = incoming_instructions.clone();
         hit_yourself_instruction.update_percentage(0.50);
 
-        let attacking_stat = attacking_side.calculate_boosted_stat(PokemonBoostableStat::Attack);
-        let defending_stat = attacking_side.calculate_boosted_stat(PokemonBoostableStat::Defense);
+        let attacking_stat = calculate_boosted_stat(attacking_side, PokemonBoostableStat::Attack);
+        let defending_stat = calculate_boosted_stat(attacking_side, PokemonBoostableStat::Defense);
 
         let attacker_active = attacking_side.get_active();
         let mut damage_dealt = 2.0 * attacker_active.level as f32;
@@ -1212,7 +1219,7 @@ pub fn generate_instructions_from_move(
 
     // Only calculate crit if regular damage doesn't KO
     if average_damage < defender_side.get_active().hp {
-        let crit_rate = attacker_side.get_active().crit_rate(&choice.move_id);
+        let crit_rate = crit_rate(attacker_side.get_active(), &choice.move_id);
         let mut crit = incoming_instructions.clone();
         crit.update_percentage(crit_rate);
         crit_instructions = Some(crit);
@@ -1375,7 +1382,7 @@ fn get_effective_speed(state: &State, side_reference: &SideReference) -> i16 {
     let side = state.get_side_immutable(side_reference);
     let active_pkmn = side.get_active_immutable();
 
-    let mut boosted_speed = side.calculate_boosted_stat(PokemonBoostableStat::Speed) as f32;
+    let mut boosted_speed = calculate_boosted_stat(side, PokemonBoostableStat::Speed) as f32;
 
     if active_pkmn.status == PokemonStatus::PARALYZE
         && !side
@@ -1626,6 +1633,9 @@ pub fn generate_instructions_from_move_pair(
     side_two_move: &MoveChoice,
     branch_on_damage: bool,
 ) -> Vec<StateInstructions> {
+    // the state is modified directly while generating instructions, which does not keep the
+    // incremental hash in sync. Every modification is undone by the end so it can be restored
+    let zobrist_hash = state.zobrist_hash;
     let mut side_one_choice;
     match side_one_move {
         MoveChoice::Switch(switch_id) => {
@@ -1633,7 +1643,9 @@ pub fn generate_instructions_from_move_pair(
             side_one_choice.switch_id = *switch_id;
             side_one_choice.category = MoveCategory::Switch;
         }
-        MoveChoice::Move(move_index) => {
+        MoveChoice::Move(move_index)
+        | MoveChoice::MoveTera(move_index)
+        | MoveChoice::MoveMega(move_index) => {
             side_one_choice = state.side_one.get_active().moves[move_index].choice.clone();
             side_one_choice.move_index = *move_index;
         }
@@ -1649,7 +1661,9 @@ pub fn generate_instructions_from_move_pair(
             side_two_choice.switch_id = *switch_id;
             side_two_choice.category = MoveCategory::Switch;
         }
-        MoveChoice::Move(move_index) => {
+        MoveChoice::Move(move_index)
+        | MoveChoice::MoveTera(move_index)
+        | MoveChoice::MoveMega(move_index) => {
             side_two_choice = state.side_two.get_active().moves[move_index].choice.clone();
             side_two_choice.move_index = *move_index;
         }
@@ -1733,9 +1747,12 @@ pub fn generate_instructions_from_move_pair(
             }
 
             // combine both vectors into the final vector
+            // either side moving first can end in the same state, e.g. when neither move does anything
             state_instructions_vec.extend(side_two_moves_first_si);
+            combine_duplicate_instructions(&mut state_instructions_vec);
         }
     }
+    state.zobrist_hash = zobrist_hash;
     state_instructions_vec
 }
 
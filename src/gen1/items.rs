@@ -1,7 +1,6 @@
 #![allow(unused_variables)]
 
 use crate::choices::Choices;
-use crate::define_enum_with_from_str;
 use crate::instruction::{DisableMoveInstruction, Instruction};
 use crate::state::{Pokemon, SideReference};
 
@@ -22,13 +21,3 @@ pub fn get_choice_move_disable_instructions(
     }
     moves_to_disable
 }
-
-define_enum_with_from_str! {
-    #[repr(u8)]
-    #[derive(Debug, PartialEq, Clone, Copy)]
-    Items {
-        NONE,
-        UNKNOWNITEM,
-    },
-    default = NONE
-}
@@ -1,8 +1,9 @@
 use super::damage_calc::type_effectiveness_modifier;
 use super::generate_instructions::{add_remove_status_instructions, get_boost_amount};
-use super::items::{get_choice_move_disable_instructions, Items};
-use super::state::{PokemonVolatileStatus, Weather};
+use super::items::get_choice_move_disable_instructions;
 use crate::choices::{Choice, Choices, Heal, MoveCategory, MoveTarget};
+use crate::engine::items::Items;
+use crate::engine::state::{PokemonVolatileStatus, Weather};
 use crate::instruction::{
     ApplyVolatileStatusInstruction, BoostInstruction, ChangeItemInstruction,
     ChangeSideConditionInstruction, ChangeStatusInstruction, ChangeSubsituteHealthInstruction,
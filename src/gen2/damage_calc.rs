@@ -1,9 +1,10 @@
-use super::state::{PokemonVolatileStatus, Weather};
+use super::state::calculate_boosted_stat;
+use crate::choices::{moves, Choices};
 use crate::choices::{Choice, MoveCategory};
-use crate::choices::{Choices, MOVES};
+use crate::engine::state::{PokemonVolatileStatus, Weather};
 use crate::state::{
-    Pokemon, PokemonBoostableStat, PokemonIndex, PokemonStatus, PokemonType, Side, SideReference,
-    State,
+    Generation, Pokemon, PokemonBoostableStat, PokemonIndex, PokemonStatus, PokemonType, Side,
+    SideReference, State,
 };
 
 #[rustfmt::skip]
@@ -199,9 +200,9 @@ pub fn calculate_damage(
     let mut gen2_crit_ignore_effects = false;
     if choice.category == MoveCategory::Physical {
         boosted_attacking_stat =
-            attacking_side.calculate_boosted_stat(PokemonBoostableStat::Attack);
+            calculate_boosted_stat(attacking_side, PokemonBoostableStat::Attack);
         boosted_defending_stat =
-            defending_side.calculate_boosted_stat(PokemonBoostableStat::Defense);
+            calculate_boosted_stat(defending_side, PokemonBoostableStat::Defense);
         if defending_side.defense_boost >= attacking_side.attack_boost {
             gen2_crit_ignore_effects = true;
             crit_attacking_stat = boosted_attacking_stat;
@@ -212,9 +213,9 @@ pub fn calculate_damage(
         }
     } else {
         boosted_attacking_stat =
-            attacking_side.calculate_boosted_stat(PokemonBoostableStat::SpecialAttack);
+            calculate_boosted_stat(attacking_side, PokemonBoostableStat::SpecialAttack);
         boosted_defending_stat =
-            defending_side.calculate_boosted_stat(PokemonBoostableStat::SpecialDefense);
+            calculate_boosted_stat(defending_side, PokemonBoostableStat::SpecialDefense);
         if defending_side.special_defense_boost >= attacking_side.special_attack_boost {
             gen2_crit_ignore_effects = true;
             crit_attacking_stat = boosted_attacking_stat;
@@ -298,7 +299,7 @@ pub fn calculate_futuresight_damage(
         defending_side.get_active_immutable(),
         defending_stat,
         &Weather::NONE,
-        MOVES.get(&Choices::FUTURESIGHT).unwrap(),
+        moves(Generation::GEN2).get(&Choices::FUTURESIGHT).unwrap(),
     );
 
     (damage * 0.925) as i16
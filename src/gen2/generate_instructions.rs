@@ -2,9 +2,13 @@ use super::choice_effects::{
     charge_choice_to_volatile, choice_after_damage_hit, choice_before_move, choice_hazard_clear,
     choice_special_effect, modify_choice,
 };
+use super::state::{
+    calculate_boosted_stat, has_alive_frozen_pokemon, remove_volatile_statuses_on_switch,
+    reset_toxic, volatile_status_can_be_applied,
+};
 use crate::choices::{
-    Boost, Choices, Effect, Heal, MoveTarget, MultiHitMove, Secondary, SideCondition, Status,
-    VolatileStatus, MOVES,
+    moves, Boost, Choices, Effect, Heal, MoveTarget, MultiHitMove, Secondary, SideCondition,
+    Status, VolatileStatus,
 };
 use crate::instruction::DecrementFutureSightInstruction;
 use crate::instruction::{
@@ -23,12 +27,12 @@ use super::damage_calc::calculate_futuresight_damage;
 use super::damage_calc::{calculate_damage, type_effectiveness_modifier, DamageRolls};
 use super::items::{
     item_before_move, item_end_of_turn, item_modify_attack_against, item_modify_attack_being_used,
-    Items,
 };
-use super::state::{MoveChoice, PokemonVolatileStatus, Weather};
+use crate::engine::items::Items;
+use crate::engine::state::{MoveChoice, PokemonVolatileStatus, Weather};
 use crate::state::{
-    LastUsedMove, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, PokemonSideCondition,
-    PokemonStatus, PokemonType, Side, SideMovesFirst, SideReference, State,
+    Generation, LastUsedMove, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex,
+    PokemonSideCondition, PokemonStatus, PokemonType, Side, SideMovesFirst, SideReference, State,
 };
 use crate::{
     choices::{Choice, MoveCategory},
@@ -171,12 +175,14 @@ pub fn generate_instructions_from_switch(
         &switching_side_ref,
         &mut incoming_instructions.instruction_list,
     );
-    state.remove_volatile_statuses_on_switch(
+    remove_volatile_statuses_on_switch(
+        state,
         &switching_side_ref,
         &mut incoming_instructions.instruction_list,
         baton_passing,
     );
-    state.reset_toxic(
+    reset_toxic(
+        state,
         &switching_side_ref,
         &mut incoming_instructions.instruction_list,
     );
@@ -266,7 +272,8 @@ fn get_instructions_from_volatile_statuses(
 
     let side = state.get_side(&target_side);
     let affected_pkmn = side.get_active_immutable();
-    if affected_pkmn.volatile_status_can_be_applied(
+    if volatile_status_can_be_applied(
+        affected_pkmn,
         &volatile_status.volatile_status,
         &side.volatile_statuses,
         attacker_choice.first_move,
@@ -367,7 +374,7 @@ pub fn immune_to_status(
         match status {
             PokemonStatus::BURN => target_pkmn.has_type(&PokemonType::FIRE),
             PokemonStatus::FREEZE => {
-                target_pkmn.has_type(&PokemonType::ICE) || target_side.has_alive_frozen_pokemon()
+                target_pkmn.has_type(&PokemonType::ICE) || has_alive_frozen_pokemon(target_side)
             }
             PokemonStatus::SLEEP => {
                 // sleep clause
@@ -1290,8 +1297,8 @@ fn generate_instructions_from_existing_status_conditions(
         let mut hit_yourself_instruction = incoming_instructions.clone();
         hit_yourself_instruction.update_percentage(0.50);
 
-        let attacking_stat = attacking_side.calculate_boosted_stat(PokemonBoostableStat::Attack);
-        let defending_stat = attacking_side.calculate_boosted_stat(PokemonBoostableStat::Defense);
+        let attacking_stat = calculate_boosted_stat(attacking_side, PokemonBoostableStat::Attack);
+        let defending_stat = calculate_boosted_stat(attacking_side, PokemonBoostableStat::Defense);
 
         let attacker_active = attacking_side.get_active();
         let mut damage_dealt = 2.0 * attacker_active.level as f32;
@@ -1383,7 +1390,7 @@ pub fn generate_instructions_from_move(
         match side.last_used_move {
             LastUsedMove::Move(last_used_move) => {
                 if choice.move_index != last_used_move {
-                    *choice = MOVES
+                    *choice = moves(Generation::GEN2)
                         .get(&side.get_active_immutable().moves[&last_used_move].id)
                         .unwrap()
                         .clone();
@@ -1668,7 +1675,7 @@ fn get_effective_speed(state: &State, side_reference: &SideReference) -> i16 {
     let side = state.get_side_immutable(side_reference);
     let active_pkmn = side.get_active_immutable();
 
-    let mut boosted_speed = side.calculate_boosted_stat(PokemonBoostableStat::Speed) as f32;
+    let mut boosted_speed = calculate_boosted_stat(side, PokemonBoostableStat::Speed) as f32;
     if active_pkmn.status == PokemonStatus::PARALYZE {
         boosted_speed *= 0.25;
     }
@@ -2338,6 +2345,9 @@ pub fn generate_instructions_from_move_pair(
     side_two_move: &MoveChoice,
     branch_on_damage: bool,
 ) -> Vec<StateInstructions> {
+    // the state is modified directly while generating instructions, which does not keep the
+    // incremental hash in sync. Every modification is undone by the end so it can be restored
+    let zobrist_hash = state.zobrist_hash;
     let mut side_one_choice;
     match side_one_move {
         MoveChoice::Switch(switch_id) => {
@@ -2345,7 +2355,9 @@ pub fn generate_instructions_from_move_pair(
             side_one_choice.switch_id = *switch_id;
             side_one_choice.category = MoveCategory::Switch;
         }
-        MoveChoice::Move(move_index) => {
+        MoveChoice::Move(move_index)
+        | MoveChoice::MoveTera(move_index)
+        | MoveChoice::MoveMega(move_index) => {
             side_one_choice = state.side_one.get_active().moves[move_index].choice.clone();
             side_one_choice.move_index = *move_index;
         }
@@ -2361,7 +2373,9 @@ pub fn generate_instructions_from_move_pair(
             side_two_choice.switch_id = *switch_id;
             side_two_choice.category = MoveCategory::Switch;
         }
-        MoveChoice::Move(move_index) => {
+        MoveChoice::Move(move_index)
+        | MoveChoice::MoveTera(move_index)
+        | MoveChoice::MoveMega(move_index) => {
             side_two_choice = state.side_two.get_active().moves[move_index].choice.clone();
             side_two_choice.move_index = *move_index;
         }
@@ -2445,9 +2459,12 @@ pub fn generate_instructions_from_move_pair(
             }
 
             // combine both vectors into the final vector
+            // either side moving first can end in the same state, e.g. when neither move does anything
             state_instructions_vec.extend(side_two_moves_first_si);
+            combine_duplicate_instructions(&mut state_instructions_vec);
         }
     }
+    state.zobrist_hash = zobrist_hash;
     state_instructions_vec
 }
 
@@ -2518,7 +2535,7 @@ pub fn calculate_damage_rolls(
             return Some(vec![defender_active.hp / 2]);
         }
         Choices::SUCKERPUNCH | Choices::THUNDERCLAP => {
-            defending_choice = MOVES.get(&Choices::TACKLE).unwrap();
+            defending_choice = moves(Generation::GEN2).get(&Choices::TACKLE).unwrap();
         }
 
         _ => {}
@@ -2533,7 +2550,7 @@ pub fn calculate_damage_rolls(
     );
 
     if choice.move_id == Choices::FUTURESIGHT {
-        choice = MOVES.get(&Choices::FUTURESIGHT)?.clone();
+        choice = moves(Generation::GEN2).get(&Choices::FUTURESIGHT)?.clone();
     }
 
     let mut return_vec = Vec::with_capacity(4);
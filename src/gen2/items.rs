@@ -1,6 +1,6 @@
 #![allow(unused_variables)]
 use crate::choices::{Choice, Choices};
-use crate::define_enum_with_from_str;
+use crate::engine::items::Items;
 use crate::instruction::{
     ChangeItemInstruction, ChangeStatusInstruction, DisableMoveInstruction, HealInstruction,
     Instruction, StateInstructions,
@@ -9,41 +9,6 @@ use crate::pokemon::PokemonName;
 use crate::state::{Pokemon, PokemonStatus, PokemonType, Side, SideReference, State};
 use std::cmp;
 
-define_enum_with_from_str! {
-    #[repr(u8)]
-    #[derive(Debug, PartialEq, Clone, Copy)]
-    Items {
-        NONE,
-        UNKNOWNITEM,
-        LEFTOVERS,
-        METALPOWDER,
-        BLACKBELT,
-        BLACKGLASSES,
-        CHARCOAL,
-        DRAGONFANG,
-        DRAGONSCALE,
-        METALCOAT,
-        MYSTICWATER,
-        NEVERMELTICE,
-        PINKBOW,
-        POLKADOTBOW,
-        POISONBARB,
-        SHARPBEAK,
-        SILVERPOWDER,
-        SOFTSAND,
-        MIRACLESEED,
-        SPELLTAG,
-        THICKCLUB,
-        TWISTEDSPOON,
-        HARDSTONE,
-        MAGNET,
-        LIGHTBALL,
-        MIRACLEBERRY,
-        MINTBERRY,
-    },
-    default = UNKNOWNITEM
-}
-
 fn miracle_berry(
     side_ref: &SideReference,
     attacking_side: &mut Side,
@@ -1,5 +1,3 @@
-pub mod abilities;
-pub mod base_stats;
 pub mod choice_effects;
 pub mod damage_calc;
 pub mod evaluate;
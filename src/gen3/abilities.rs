@@ -1,12 +1,12 @@
 #![allow(unused_variables)]
 use super::damage_calc::type_effectiveness_modifier;
 use super::generate_instructions::{add_remove_status_instructions, apply_boost_instruction};
-use super::state::{PokemonVolatileStatus, Weather};
 use crate::choices::{
     Boost, Choice, Choices, Effect, Heal, MoveCategory, MoveTarget, Secondary, StatBoosts,
     VolatileStatus,
 };
-use crate::define_enum_with_from_str;
+use crate::engine::abilities::Abilities;
+use crate::engine::state::{PokemonVolatileStatus, Weather};
 use crate::instruction::{
     BoostInstruction, ChangeAbilityInstruction, ChangeStatusInstruction, ChangeType, ChangeWeather,
     DamageInstruction, HealInstruction, Instruction, StateInstructions,
@@ -16,93 +16,6 @@ use std::cmp;
 
 pub const WEATHER_ABILITY_TURNS: i8 = -1;
 
-define_enum_with_from_str! {
-    #[repr(i16)]
-    #[derive(PartialEq, Debug, Clone, Copy)]
-    Abilities {
-        NONE,
-        AIRLOCK,
-        ARENATRAP,
-        BATTLEARMOR,
-        BLAZE,
-        CACOPHONY,
-        CHLOROPHYLL,
-        CLEARBODY,
-        CLOUDNINE,
-        COLORCHANGE,
-        COMPOUNDEYES,
-        CUTECHARM,
-        DAMP,
-        DRIZZLE,
-        DROUGHT,
-        DRYSKIN,
-        EARLYBIRD,
-        EFFECTSPORE,
-        FLAMEBODY,
-        FLASHFIRE,
-        FORECAST,
-        GUTS,
-        HUGEPOWER,
-        HUSTLE,
-        HYPERCUTTER,
-        ILLUMINATE,
-        IMMUNITY,
-        INNERFOCUS,
-        INSOMNIA,
-        INTIMIDATE,
-        KEENEYE,
-        LEVITATE,
-        LIGHTNINGROD,
-        LIMBER,
-        LIQUIDOOZE,
-        MAGMAARMOR,
-        MAGNETPULL,
-        MARVELSCALE,
-        MINUS,
-        NATURALCURE,
-        OBLIVIOUS,
-        OVERGROW,
-        OWNTEMPO,
-        PICKUP,
-        PLUS,
-        POISONPOINT,
-        PRESSURE,
-        PUREPOWER,
-        RAINDISH,
-        ROCKHEAD,
-        ROUGHSKIN,
-        RUNAWAY,
-        SANDSTREAM,
-        SANDVEIL,
-        SERENEGRACE,
-        SHADOWTAG,
-        SHEDSKIN,
-        SHELLARMOR,
-        SHIELDDUST,
-        SOUNDPROOF,
-        SPEEDBOOST,
-        STATIC,
-        STENCH,
-        STICKYHOLD,
-        STURDY,
-        SUCTIONCUPS,
-        SWARM,
-        SWIFTSWIM,
-        SYNCHRONIZE,
-        THICKFAT,
-        TORRENT,
-        TRACE,
-        TRUANT,
-        VITALSPIRIT,
-        VOLTABSORB,
-        WATERABSORB,
-        WATERVEIL,
-        WHITESMOKE,
-        WONDERGUARD,
-    },
-    default = NONE
-}
-
 pub fn ability_after_damage_hit(
     state: &mut State,
     choice: &mut Choice,
@@ -604,13 +517,9 @@ pub fn ability_modify_attack_against(
             }
         }
         Abilities::VOLTABSORB => {
-            #[cfg(feature = "gen3")]
             let activate = attacker_choice.move_type == PokemonType::ELECTRIC
                 && attacker_choice.category != MoveCategory::Status;
 
-            #[cfg(not(feature = "gen3"))]
-            let activate = attacker_choice.move_type == PokemonType::ELECTRIC;
-
             if activate {
                 attacker_choice.remove_all_effects();
                 attacker_choice.accuracy = 100.0;
@@ -1,9 +1,10 @@
-use super::abilities::Abilities;
 use super::damage_calc::type_effectiveness_modifier;
 use super::generate_instructions::add_remove_status_instructions;
-use super::items::{get_choice_move_disable_instructions, Items};
-use super::state::{PokemonVolatileStatus, Weather};
+use super::items::get_choice_move_disable_instructions;
 use crate::choices::{Boost, Choice, Choices, Heal, MoveCategory, MoveTarget, StatBoosts};
+use crate::engine::abilities::Abilities;
+use crate::engine::items::Items;
+use crate::engine::state::{PokemonVolatileStatus, Weather};
 use crate::instruction::{
     ApplyVolatileStatusInstruction, BoostInstruction, ChangeItemInstruction,
     ChangeSideConditionInstruction, ChangeStatusInstruction, ChangeSubsituteHealthInstruction,
@@ -111,7 +112,7 @@ pub fn modify_choice(
                 attacker_choice.base_power = 100.0;
                 attacker_choice.move_type = PokemonType::ICE;
             }
-            Weather::NONE => {}
+            _ => {}
         },
         Choices::SOLARBEAM => {
             if state.weather_is_active(&Weather::SUN) {
@@ -397,6 +398,7 @@ pub fn choice_hazard_clear(
 pub fn choice_special_effect(
     state: &mut State,
     choice: &mut Choice,
+    defender_choice: &Choice,
     attacking_side_ref: &SideReference,
     instructions: &mut StateInstructions,
 ) {
@@ -424,7 +426,8 @@ pub fn choice_special_effect(
             }
         }
         Choices::COUNTER => {
-            if defending_side.damage_dealt.move_category == MoveCategory::Physical
+            if (defending_side.damage_dealt.move_category == MoveCategory::Physical
+                || defender_choice.move_id.is_hiddenpower())
                 && !defending_side
                     .get_active_immutable()
                     .has_type(&PokemonType::GHOST)
@@ -449,6 +452,7 @@ pub fn choice_special_effect(
                 && !defending_side
                     .get_active_immutable()
                     .has_type(&PokemonType::DARK)
+                && !defender_choice.move_id.is_hiddenpower()
             {
                 let damage_amount = cmp::min(
                     defending_side.damage_dealt.damage * 2,
@@ -1,49 +1,9 @@
-use super::abilities::Abilities;
-use super::items::Items;
-use super::state::PokemonVolatileStatus;
 use crate::choices::MoveCategory;
-use crate::state::{Pokemon, PokemonStatus, Side, State};
-
-const POKEMON_ALIVE: f32 = 30.0;
-const POKEMON_HP: f32 = 100.0;
-
-const POKEMON_ATTACK_BOOST: f32 = 30.0;
-const POKEMON_DEFENSE_BOOST: f32 = 15.0;
-const POKEMON_SPECIAL_ATTACK_BOOST: f32 = 30.0;
-const POKEMON_SPECIAL_DEFENSE_BOOST: f32 = 15.0;
-const POKEMON_SPEED_BOOST: f32 = 30.0;
-
-const POKEMON_BOOST_MULTIPLIER_6: f32 = 3.3;
-const POKEMON_BOOST_MULTIPLIER_5: f32 = 3.15;
-const POKEMON_BOOST_MULTIPLIER_4: f32 = 3.0;
-const POKEMON_BOOST_MULTIPLIER_3: f32 = 2.5;
-const POKEMON_BOOST_MULTIPLIER_2: f32 = 2.0;
-const POKEMON_BOOST_MULTIPLIER_1: f32 = 1.0;
-const POKEMON_BOOST_MULTIPLIER_0: f32 = 0.0;
-const POKEMON_BOOST_MULTIPLIER_NEG_1: f32 = -1.0;
-const POKEMON_BOOST_MULTIPLIER_NEG_2: f32 = -2.0;
-const POKEMON_BOOST_MULTIPLIER_NEG_3: f32 = -2.5;
-const POKEMON_BOOST_MULTIPLIER_NEG_4: f32 = -3.0;
-const POKEMON_BOOST_MULTIPLIER_NEG_5: f32 = -3.15;
-const POKEMON_BOOST_MULTIPLIER_NEG_6: f32 = -3.3;
-
-const POKEMON_FROZEN: f32 = -40.0;
-const POKEMON_ASLEEP: f32 = -25.0;
-const POKEMON_PARALYZED: f32 = -25.0;
-const POKEMON_TOXIC: f32 = -30.0;
-const POKEMON_POISONED: f32 = -10.0;
-const POKEMON_BURNED: f32 = -25.0;
-
-const LEECH_SEED: f32 = -30.0;
-const SUBSTITUTE: f32 = 40.0;
-const CONFUSION: f32 = -20.0;
-
-const REFLECT: f32 = 20.0;
-const LIGHT_SCREEN: f32 = 20.0;
-
-const SPIKES_ONE_LAYER: f32 = -12.0;
-const SPIKES_TWO_LAYER: f32 = -16.0;
-const SPIKES_THREE_LAYER: f32 = -25.0;
+use crate::engine::abilities::Abilities;
+use crate::engine::items::Items;
+use crate::engine::state::PokemonVolatileStatus;
+use crate::evaluator::{pokemon_term, EvaluationBreakdown, EvaluationTerms, EvaluationWeights};
+use crate::state::{Pokemon, PokemonStatus, Side, SideReference, State};
 
 fn evaluate_poison(pokemon: &Pokemon, base_score: f32) -> f32 {
     match pokemon.ability {
@@ -52,12 +12,12 @@ fn evaluate_poison(pokemon: &Pokemon, base_score: f32) -> f32 {
     }
 }
 
-fn evaluate_burned(pokemon: &Pokemon) -> f32 {
+fn evaluate_burned(pokemon: &Pokemon, weights: &EvaluationWeights) -> f32 {
     // burn is not as punishing in certain situations
 
     // guts, marvel scale, quick feet will result in a positive evaluation
     match pokemon.ability {
-        Abilities::GUTS | Abilities::MARVELSCALE => return -2.0 * POKEMON_BURNED,
+        Abilities::GUTS | Abilities::MARVELSCALE => return -2.0 * weights.pokemon_burned,
         _ => {}
     }
 
@@ -73,35 +33,16 @@ fn evaluate_burned(pokemon: &Pokemon) -> f32 {
         multiplier /= 2.0;
     }
 
-    multiplier * POKEMON_BURNED
+    multiplier * weights.pokemon_burned
 }
 
-fn get_boost_multiplier(boost: i8) -> f32 {
-    match boost {
-        6 => POKEMON_BOOST_MULTIPLIER_6,
-        5 => POKEMON_BOOST_MULTIPLIER_5,
-        4 => POKEMON_BOOST_MULTIPLIER_4,
-        3 => POKEMON_BOOST_MULTIPLIER_3,
-        2 => POKEMON_BOOST_MULTIPLIER_2,
-        1 => POKEMON_BOOST_MULTIPLIER_1,
-        0 => POKEMON_BOOST_MULTIPLIER_0,
-        -1 => POKEMON_BOOST_MULTIPLIER_NEG_1,
-        -2 => POKEMON_BOOST_MULTIPLIER_NEG_2,
-        -3 => POKEMON_BOOST_MULTIPLIER_NEG_3,
-        -4 => POKEMON_BOOST_MULTIPLIER_NEG_4,
-        -5 => POKEMON_BOOST_MULTIPLIER_NEG_5,
-        -6 => POKEMON_BOOST_MULTIPLIER_NEG_6,
-        _ => panic!("Invalid boost value: {}", boost),
-    }
-}
-
-fn evaluate_hazards(pokemon: &Pokemon, side: &Side) -> f32 {
+fn evaluate_hazards(pokemon: &Pokemon, side: &Side, weights: &EvaluationWeights) -> f32 {
     let mut score = 0.0;
     if pokemon.is_grounded() {
         match side.side_conditions.spikes {
-            1 => score += SPIKES_ONE_LAYER,
-            2 => score += SPIKES_TWO_LAYER,
-            3 => score += SPIKES_THREE_LAYER,
+            1 => score += weights.spikes_one_layer,
+            2 => score += weights.spikes_two_layer,
+            3 => score += weights.spikes_three_layer,
             _ => {}
         }
     }
@@ -109,130 +50,163 @@ fn evaluate_hazards(pokemon: &Pokemon, side: &Side) -> f32 {
     score
 }
 
-fn evaluate_pokemon(pokemon: &Pokemon) -> f32 {
-    let mut score = 0.0;
-    score += POKEMON_HP * pokemon.hp as f32 / pokemon.maxhp as f32;
-
-    match pokemon.status {
-        PokemonStatus::BURN => score += evaluate_burned(pokemon),
-        PokemonStatus::FREEZE => score += POKEMON_FROZEN,
-        PokemonStatus::SLEEP => score += POKEMON_ASLEEP,
-        PokemonStatus::PARALYZE => score += POKEMON_PARALYZED,
-        PokemonStatus::TOXIC => score += evaluate_poison(pokemon, POKEMON_TOXIC),
-        PokemonStatus::POISON => score += evaluate_poison(pokemon, POKEMON_POISONED),
-        PokemonStatus::NONE => {}
-    }
-
-    if pokemon.item != Items::NONE {
-        score += 10.0;
-    }
-
+fn evaluate_pokemon(
+    pokemon: &Pokemon,
+    side_ref: SideReference,
+    weights: &EvaluationWeights,
+    terms: &mut impl EvaluationTerms,
+) {
+    let hp = weights.pokemon_hp * pokemon.hp as f32 / pokemon.maxhp as f32;
+    let status = match pokemon.status {
+        PokemonStatus::BURN => evaluate_burned(pokemon, weights),
+        PokemonStatus::FREEZE => weights.pokemon_frozen,
+        PokemonStatus::SLEEP => weights.pokemon_asleep,
+        PokemonStatus::PARALYZE => weights.pokemon_paralyzed,
+        PokemonStatus::TOXIC => evaluate_poison(pokemon, weights.pokemon_toxic),
+        PokemonStatus::POISON => evaluate_poison(pokemon, weights.pokemon_poisoned),
+        PokemonStatus::NONE => 0.0,
+    };
+    let item = if pokemon.item != Items::NONE {
+        weights.pokemon_item
+    } else {
+        0.0
+    };
+
+    let mut low_pp = 0.0;
     for mv in pokemon.moves.into_iter() {
         // at 10 PP: (10*3) - 30 = 0  -> no penalty
         // at 1 pp: (1*3) - 30 = -27  -> -27 penalty
         if mv.pp <= 10 {
-            let penalty = (mv.pp * 3) as f32 - 30.0;
-            score += penalty;
+            low_pp += (mv.pp * 3) as f32 - 30.0;
         }
     }
 
+    terms.add(side_ref, || pokemon_term(pokemon, "hp"), hp);
+    terms.add(
+        side_ref,
+        || pokemon_term(pokemon, &pokemon.status.to_string().to_lowercase()),
+        status,
+    );
+    terms.add(side_ref, || pokemon_term(pokemon, "item"), item);
+    terms.add(side_ref, || pokemon_term(pokemon, "low pp"), low_pp);
+
     // without this a low hp pokemon could get a negative score and incentivize the other side
     // to keep it alive
+    let score = hp + status + item + low_pp;
     if score < 0.0 {
-        score = 0.0;
+        terms.add(side_ref, || pokemon_term(pokemon, "score floor"), -score);
     }
 
-    score += POKEMON_ALIVE;
-
-    score
+    terms.add(
+        side_ref,
+        || pokemon_term(pokemon, "alive"),
+        weights.pokemon_alive,
+    );
 }
 
-pub fn evaluate(state: &State) -> f32 {
-    let mut score = 0.0;
-
-    let mut iter = state.side_one.pokemon.into_iter();
+fn evaluate_side(
+    side: &Side,
+    side_ref: SideReference,
+    weights: &EvaluationWeights,
+    terms: &mut impl EvaluationTerms,
+) {
+    let mut iter = side.pokemon.into_iter();
     while let Some(pkmn) = iter.next() {
         if pkmn.hp > 0 {
-            score += evaluate_pokemon(pkmn);
-            score += evaluate_hazards(pkmn, &state.side_one);
-            if iter.pokemon_index == state.side_one.active_index {
-                if state
-                    .side_one
+            evaluate_pokemon(pkmn, side_ref, weights, terms);
+            terms.add(
+                side_ref,
+                || pokemon_term(pkmn, "hazards"),
+                evaluate_hazards(pkmn, side, weights),
+            );
+            if iter.pokemon_index == side.active_index {
+                if side
                     .volatile_statuses
                     .contains(&PokemonVolatileStatus::LEECHSEED)
                 {
-                    score += LEECH_SEED;
+                    terms.add(side_ref, || "leech seed".to_string(), weights.leech_seed);
                 }
-                if state
-                    .side_one
+                if side
                     .volatile_statuses
                     .contains(&PokemonVolatileStatus::SUBSTITUTE)
                 {
-                    score += SUBSTITUTE;
+                    terms.add(side_ref, || "substitute".to_string(), weights.substitute);
                 }
-                if state
-                    .side_one
+                if side
                     .volatile_statuses
                     .contains(&PokemonVolatileStatus::CONFUSION)
                 {
-                    score += CONFUSION;
+                    terms.add(side_ref, || "confusion".to_string(), weights.confusion);
                 }
-
-                score += get_boost_multiplier(state.side_one.attack_boost) * POKEMON_ATTACK_BOOST;
-                score += get_boost_multiplier(state.side_one.defense_boost) * POKEMON_DEFENSE_BOOST;
-                score += get_boost_multiplier(state.side_one.special_attack_boost)
-                    * POKEMON_SPECIAL_ATTACK_BOOST;
-                score += get_boost_multiplier(state.side_one.special_defense_boost)
-                    * POKEMON_SPECIAL_DEFENSE_BOOST;
-                score += get_boost_multiplier(state.side_one.speed_boost) * POKEMON_SPEED_BOOST;
+                terms.add(
+                    side_ref,
+                    || "attack boost".to_string(),
+                    weights.boost_multiplier(side.attack_boost) * weights.pokemon_attack_boost,
+                );
+                terms.add(
+                    side_ref,
+                    || "defense boost".to_string(),
+                    weights.boost_multiplier(side.defense_boost) * weights.pokemon_defense_boost,
+                );
+                terms.add(
+                    side_ref,
+                    || "special attack boost".to_string(),
+                    weights.boost_multiplier(side.special_attack_boost)
+                        * weights.pokemon_special_attack_boost,
+                );
+                terms.add(
+                    side_ref,
+                    || "special defense boost".to_string(),
+                    weights.boost_multiplier(side.special_defense_boost)
+                        * weights.pokemon_special_defense_boost,
+                );
+                terms.add(
+                    side_ref,
+                    || "speed boost".to_string(),
+                    weights.boost_multiplier(side.speed_boost) * weights.pokemon_speed_boost,
+                );
             }
         }
     }
-    let mut iter = state.side_two.pokemon.into_iter();
-    while let Some(pkmn) = iter.next() {
-        if pkmn.hp > 0 {
-            score -= evaluate_pokemon(pkmn);
-            score -= evaluate_hazards(pkmn, &state.side_two);
-
-            if iter.pokemon_index == state.side_two.active_index {
-                if state
-                    .side_two
-                    .volatile_statuses
-                    .contains(&PokemonVolatileStatus::LEECHSEED)
-                {
-                    score -= LEECH_SEED;
-                }
-                if state
-                    .side_two
-                    .volatile_statuses
-                    .contains(&PokemonVolatileStatus::SUBSTITUTE)
-                {
-                    score -= SUBSTITUTE;
-                }
-                if state
-                    .side_two
-                    .volatile_statuses
-                    .contains(&PokemonVolatileStatus::CONFUSION)
-                {
-                    score -= CONFUSION;
-                }
 
-                score -= get_boost_multiplier(state.side_two.attack_boost) * POKEMON_ATTACK_BOOST;
-                score -= get_boost_multiplier(state.side_two.defense_boost) * POKEMON_DEFENSE_BOOST;
-                score -= get_boost_multiplier(state.side_two.special_attack_boost)
-                    * POKEMON_SPECIAL_ATTACK_BOOST;
-                score -= get_boost_multiplier(state.side_two.special_defense_boost)
-                    * POKEMON_SPECIAL_DEFENSE_BOOST;
-                score -= get_boost_multiplier(state.side_two.speed_boost) * POKEMON_SPEED_BOOST;
-            }
-        }
-    }
+    let side_conditions = &side.side_conditions;
+    terms.add(
+        side_ref,
+        || "reflect".to_string(),
+        side_conditions.reflect as f32 * weights.reflect,
+    );
+    terms.add(
+        side_ref,
+        || "light screen".to_string(),
+        side_conditions.light_screen as f32 * weights.light_screen,
+    );
+}
 
-    score += state.side_one.side_conditions.reflect as f32 * REFLECT;
-    score += state.side_one.side_conditions.light_screen as f32 * LIGHT_SCREEN;
+fn evaluate_terms(state: &State, weights: &EvaluationWeights, terms: &mut impl EvaluationTerms) {
+    evaluate_side(&state.side_one, SideReference::SideOne, weights, terms);
+    evaluate_side(&state.side_two, SideReference::SideTwo, weights, terms);
+}
 
-    score -= state.side_two.side_conditions.reflect as f32 * REFLECT;
-    score -= state.side_two.side_conditions.light_screen as f32 * LIGHT_SCREEN;
+pub fn evaluate(state: &State) -> f32 {
+    evaluate_with_weights(state, &EvaluationWeights::DEFAULT)
+}
 
+pub fn evaluate_with_weights(state: &State, weights: &EvaluationWeights) -> f32 {
+    let mut score = 0.0;
+    evaluate_terms(state, weights, &mut score);
     score
 }
+
+/// Every term that went into `evaluate`, useful for finding out why a state scored the way it did
+pub fn evaluate_explained(state: &State) -> EvaluationBreakdown {
+    evaluate_explained_with_weights(state, &EvaluationWeights::DEFAULT)
+}
+
+pub fn evaluate_explained_with_weights(
+    state: &State,
+    weights: &EvaluationWeights,
+) -> EvaluationBreakdown {
+    let mut breakdown = EvaluationBreakdown::default();
+    evaluate_terms(state, weights, &mut breakdown);
+    breakdown
+}
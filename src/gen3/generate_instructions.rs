@@ -1,15 +1,17 @@
 use super::abilities::{
     ability_after_damage_hit, ability_end_of_turn, ability_modify_attack_against,
-    ability_modify_attack_being_used, ability_on_switch_in, ability_on_switch_out, Abilities,
+    ability_modify_attack_being_used, ability_on_switch_in, ability_on_switch_out,
 };
 use super::choice_effects::{
     charge_choice_to_volatile, choice_after_damage_hit, choice_before_move, choice_hazard_clear,
     choice_special_effect, modify_choice,
 };
+use super::state::remove_volatile_statuses_on_switch;
 use crate::choices::{
-    Boost, Choices, Effect, Heal, MoveTarget, MultiHitMove, Secondary, SideCondition, Status,
-    VolatileStatus, MOVES,
+    moves, Boost, Choices, Effect, Heal, MoveTarget, MultiHitMove, Secondary, SideCondition,
+    Status, VolatileStatus,
 };
+use crate::engine::abilities::Abilities;
 use crate::instruction::DecrementFutureSightInstruction;
 use crate::instruction::{
     ApplyVolatileStatusInstruction, BoostInstruction, ChangeDamageDealtDamageInstruction,
@@ -26,16 +28,16 @@ use super::damage_calc::calculate_futuresight_damage;
 use super::damage_calc::{calculate_damage, type_effectiveness_modifier, DamageRolls};
 use super::items::{
     item_before_move, item_end_of_turn, item_modify_attack_against, item_modify_attack_being_used,
-    Items,
 };
-use super::state::{MoveChoice, PokemonVolatileStatus, Weather};
 use crate::choices::{Choice, MoveCategory};
+use crate::engine::items::Items;
+use crate::engine::state::{MoveChoice, PokemonVolatileStatus, Weather};
 use crate::instruction::{
     ChangeStatusInstruction, DamageInstruction, Instruction, StateInstructions, SwitchInstruction,
 };
 use crate::state::{
-    LastUsedMove, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, PokemonSideCondition,
-    PokemonStatus, PokemonType, Side, SideMovesFirst, SideReference, State,
+    Generation, LastUsedMove, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex,
+    PokemonSideCondition, PokemonStatus, PokemonType, Side, SideMovesFirst, SideReference, State,
 };
 use std::cmp;
 
@@ -177,7 +179,8 @@ fn generate_instructions_from_switch(
         &switching_side_ref,
         &mut incoming_instructions.instruction_list,
     );
-    state.remove_volatile_statuses_on_switch(
+    remove_volatile_statuses_on_switch(
+        state,
         &switching_side_ref,
         &mut incoming_instructions.instruction_list,
         baton_passing,
@@ -1426,8 +1429,10 @@ fn generate_instructions_from_existing_status_conditions(
         let mut hit_yourself_instruction = incoming_instructions.clone();
         hit_yourself_instruction.update_percentage(HIT_SELF_IN_CONFUSION_CHANCE);
 
-        let attacking_stat = attacking_side.calculate_boosted_stat(PokemonBoostableStat::Attack);
-        let defending_stat = attacking_side.calculate_boosted_stat(PokemonBoostableStat::Defense);
+        let attacking_stat =
+            attacking_side.calculate_boosted_stat(Generation::GEN3, PokemonBoostableStat::Attack);
+        let defending_stat =
+            attacking_side.calculate_boosted_stat(Generation::GEN3, PokemonBoostableStat::Defense);
 
         let attacker_active = attacking_side.get_active();
         let mut damage_dealt = 2.0 * attacker_active.level as f32;
@@ -1549,7 +1554,7 @@ pub fn generate_instructions_from_move(
         match side.last_used_move {
             LastUsedMove::Move(last_used_move) => {
                 if choice.move_index != last_used_move {
-                    *choice = MOVES
+                    *choice = moves(Generation::GEN3)
                         .get(&side.get_active_immutable().moves[&last_used_move].id)
                         .unwrap()
                         .clone();
@@ -1693,7 +1698,13 @@ pub fn generate_instructions_from_move(
         final_instructions.push(incoming_instructions);
         return;
     }
-    choice_special_effect(state, choice, &attacking_side, &mut incoming_instructions);
+    choice_special_effect(
+        state,
+        choice,
+        defender_choice,
+        &attacking_side,
+        &mut incoming_instructions,
+    );
     let damage = calculate_damage(state, &attacking_side, &choice, DamageRolls::Max);
     check_move_hit_or_miss(
         state,
@@ -1858,7 +1869,8 @@ fn get_effective_speed(state: &State, side_reference: &SideReference) -> i16 {
     let side = state.get_side_immutable(side_reference);
     let active_pkmn = side.get_active_immutable();
 
-    let mut boosted_speed = side.calculate_boosted_stat(PokemonBoostableStat::Speed) as f32;
+    let mut boosted_speed =
+        side.calculate_boosted_stat(Generation::GEN3, PokemonBoostableStat::Speed) as f32;
 
     match state.weather.weather_type {
         Weather::SUN if active_pkmn.ability == Abilities::CHLOROPHYLL => boosted_speed *= 2.0,
@@ -2820,6 +2832,9 @@ pub fn generate_instructions_from_move_pair(
     side_two_move: &MoveChoice,
     branch_on_damage: bool,
 ) -> Vec<StateInstructions> {
+    // the state is modified directly while generating instructions, which does not keep the
+    // incremental hash in sync. Every modification is undone by the end so it can be restored
+    let zobrist_hash = state.zobrist_hash;
     let mut side_one_choice;
     match side_one_move {
         MoveChoice::Switch(switch_id) => {
@@ -2827,7 +2842,9 @@ pub fn generate_instructions_from_move_pair(
             side_one_choice.switch_id = *switch_id;
             side_one_choice.category = MoveCategory::Switch;
         }
-        MoveChoice::Move(move_index) => {
+        MoveChoice::Move(move_index)
+        | MoveChoice::MoveTera(move_index)
+        | MoveChoice::MoveMega(move_index) => {
             side_one_choice = state.side_one.get_active().moves[move_index].choice.clone();
             side_one_choice.move_index = *move_index;
         }
@@ -2843,7 +2860,9 @@ pub fn generate_instructions_from_move_pair(
             side_two_choice.switch_id = *switch_id;
             side_two_choice.category = MoveCategory::Switch;
         }
-        MoveChoice::Move(move_index) => {
+        MoveChoice::Move(move_index)
+        | MoveChoice::MoveTera(move_index)
+        | MoveChoice::MoveMega(move_index) => {
             side_two_choice = state.side_two.get_active().moves[move_index].choice.clone();
             side_two_choice.move_index = *move_index;
         }
@@ -2926,9 +2945,12 @@ pub fn generate_instructions_from_move_pair(
             }
 
             // combine both vectors into the final vector
+            // either side moving first can end in the same state, e.g. when neither move does anything
             state_instructions_vec.extend(side_two_moves_first_si);
+            combine_duplicate_instructions(&mut state_instructions_vec);
         }
     }
+    state.zobrist_hash = zobrist_hash;
     state_instructions_vec
 }
 
@@ -3005,7 +3027,7 @@ pub fn calculate_damage_rolls(
     );
 
     if choice.move_id == Choices::FUTURESIGHT {
-        choice = MOVES.get(&Choices::FUTURESIGHT)?.clone();
+        choice = moves(Generation::GEN3).get(&Choices::FUTURESIGHT)?.clone();
     }
 
     let mut return_vec = Vec::with_capacity(4);
@@ -1,7 +1,7 @@
 #![allow(unused_variables)]
 use super::generate_instructions::{add_remove_status_instructions, apply_boost_instruction};
 use crate::choices::{Choice, Choices, MoveCategory};
-use crate::define_enum_with_from_str;
+use crate::engine::items::Items;
 use crate::instruction::{
     ChangeItemInstruction, ChangeStatusInstruction, DisableMoveInstruction, HealInstruction,
     Instruction, StateInstructions,
@@ -12,170 +12,6 @@ use crate::state::{
 };
 use std::cmp;
 
-define_enum_with_from_str! {
-    #[repr(u8)]
-    #[derive(Debug, PartialEq, Clone, Copy)]
-    Items {
-        NONE,
-        UNKNOWNITEM,
-        ABSORBBULB,
-        ADRENALINEORB,
-        ADAMANTORB,
-        ADAMANTCRYSTAL,
-        AIRBALLOON,
-        ASSAULTVEST,
-        BABIRIBERRY,
-        BLACKBELT,
-        BLACKSLUDGE,
-        BLACKGLASSES,
-        BLANKPLATE,
-        BOOSTERENERGY,
-        CELLBATTERY,
-        CHARCOAL,
-        CHARTIBERRY,
-        CHILANBERRY,
-        CHOICEBAND,
-        CHOICESPECS,
-        CHOICESCARF,
-        CHOPLEBERRY,
-        COBABERRY,
-        COLBURBERRY,
-        CUSTAPBERRY,
-        DRAGONFANG,
-        DRAGONSCALE,
-        DREADPLATE,
-        EARTHPLATE,
-        ELECTRICSEED,
-        EXPERTBELT,
-        EVIOLITE,
-        FAIRYFEATHER,
-        FISTPLATE,
-        FLAMEORB,
-        GRASSYSEED,
-        HABANBERRY,
-        KASIBBERRY,
-        KEBIABERRY,
-        LEFTOVERS,
-        LIFEORB,
-        LUSTROUSORB,
-        LUSTROUSGLOBE,
-        METALCOAT,
-        MISTYSEED,
-        MUSCLEBAND,
-        MYSTICWATER,
-        NEVERMELTICE,
-        PINKBOW,
-        POLKADOTBOW,
-        OCCABERRY,
-        ODDINCENSE,
-        PASSHOBERRY,
-        PAYAPABERRY,
-        POISONBARB,
-        POWERHERB,
-        PSYCHICSEED,
-        PUNCHINGGLOVE,
-        RINDOBERRY,
-        ROSELIBERRY,
-        ROCKYHELMET,
-        SEAINCENSE,
-        SHARPBEAK,
-        SPELLTAG,
-        MIRACLESEED,
-        SHELLBELL,
-        SHUCABERRY,
-        SILKSCARF,
-        SILVERPOWDER,
-        SKYPLATE,
-        SOFTSAND,
-        SOULDEW,
-        GRISEOUSORB,
-        GRISEOUSCORE,
-        TANGABERRY,
-        THROATSPRAY,
-        THICKCLUB,
-        TOXICORB,
-        TOXICPLATE,
-        TWISTEDSPOON,
-        HARDSTONE,
-        METALPOWDER,
-        WACANBERRY,
-        WAVEINCENSE,
-        MAGNET,
-        WEAKNESSPOLICY,
-        WISEGLASSES,
-        BLUNDERPOLICY,
-        HEAVYDUTYBOOTS,
-        CLEARAMULET,
-        PROTECTIVEPADS,
-        SHEDSHELL,
-        YACHEBERRY,
-        STONEPLATE,
-        INSECTPLATE,
-        SPOOKYPLATE,
-        IRONBALL,
-        IRONPLATE,
-        FLAMEPLATE,
-        SPLASHPLATE,
-        MEADOWPLATE,
-        ZAPPLATE,
-        MINDPLATE,
-        ICICLEPLATE,
-        DRACOPLATE,
-        PIXIEPLATE,
-        LIGHTBALL,
-        FOCUSSASH,
-        CHESTOBERRY,
-        LUMBERRY,
-        SITRUSBERRY,
-        PETAYABERRY,
-        SALACBERRY,
-        LIECHIBERRY,
-        NORMALGEM,
-        BUGGEM,
-        ELECTRICGEM,
-        FIGHTINGGEM,
-        GHOSTGEM,
-        PSYCHICGEM,
-        FLYINGGEM,
-        STEELGEM,
-        ICEGEM,
-        POISONGEM,
-        FIREGEM,
-        DRAGONGEM,
-        GROUNDGEM,
-        WATERGEM,
-        DARKGEM,
-        ROCKGEM,
-        GRASSGEM,
-        FAIRYGEM,
-        BUGMEMORY,
-        FIGHTINGMEMORY,
-        GHOSTMEMORY,
-        PSYCHICMEMORY,
-        FLYINGMEMORY,
-        STEELMEMORY,
-        ICEMEMORY,
-        POISONMEMORY,
-        FIREMEMORY,
-        DRAGONMEMORY,
-        GROUNDMEMORY,
-        WATERMEMORY,
-        DARKMEMORY,
-        ROCKMEMORY,
-        GRASSMEMORY,
-        FAIRYMEMORY,
-        ELECTRICMEMORY,
-        WELLSPRINGMASK,
-        HEARTHFLAMEMASK,
-        CORNERSTONEMASK,
-        WIDELENS,
-        LOADEDDICE,
-        RUSTEDSWORD,
-        RUSTEDSHIELD,
-    },
-    default = UNKNOWNITEM
-}
-
 pub fn get_choice_move_disable_instructions(
     pkmn: &Pokemon,
     side_ref: &SideReference,
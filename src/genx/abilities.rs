@@ -16,16 +16,18 @@ use crate::instruction::{
 };
 use crate::pokemon::PokemonName;
 use crate::state::{
-    PokemonBoostableStat, PokemonSideCondition, PokemonStatus, PokemonType, Side, SideReference,
-    State,
+    Generation, PokemonBoostableStat, PokemonSideCondition, PokemonStatus, PokemonType, Side,
+    SideReference, State,
 };
 use std::cmp;
 
-#[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5"))]
-pub const WEATHER_ABILITY_TURNS: i8 = -1;
-
-#[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
-pub const WEATHER_ABILITY_TURNS: i8 = 5;
+pub fn weather_ability_turns(generation: Generation) -> i8 {
+    if generation >= Generation::GEN6 {
+        5
+    } else {
+        -1
+    }
+}
 
 define_enum_with_from_str! {
     #[repr(i16)]
@@ -351,6 +353,7 @@ define_enum_with_from_str! {
         DRAGONIZE,
         SPICYSPRAY,
         PIERCINGDRILL,
+        CACOPHONY,
     },
     default = NONE
 }
@@ -485,8 +488,8 @@ fn protosynthesus_or_quarkdrive_on_switch_in(
     }
 }
 
-fn protosynthesis_volatile_from_side(side: &Side) -> PokemonVolatileStatus {
-    match side.calculate_highest_stat() {
+fn protosynthesis_volatile_from_side(generation: Generation, side: &Side) -> PokemonVolatileStatus {
+    match side.calculate_highest_stat(generation) {
         PokemonBoostableStat::Attack => PokemonVolatileStatus::PROTOSYNTHESISATK,
         PokemonBoostableStat::Defense => PokemonVolatileStatus::PROTOSYNTHESISDEF,
         PokemonBoostableStat::SpecialAttack => PokemonVolatileStatus::PROTOSYNTHESISSPA,
@@ -496,8 +499,8 @@ fn protosynthesis_volatile_from_side(side: &Side) -> PokemonVolatileStatus {
     }
 }
 
-fn quarkdrive_volatile_from_side(side: &Side) -> PokemonVolatileStatus {
-    match side.calculate_highest_stat() {
+fn quarkdrive_volatile_from_side(generation: Generation, side: &Side) -> PokemonVolatileStatus {
+    match side.calculate_highest_stat(generation) {
         PokemonBoostableStat::Attack => PokemonVolatileStatus::QUARKDRIVEATK,
         PokemonBoostableStat::Defense => PokemonVolatileStatus::QUARKDRIVEDEF,
         PokemonBoostableStat::SpecialAttack => PokemonVolatileStatus::QUARKDRIVESPA,
@@ -513,6 +516,7 @@ pub fn ability_before_move(
     side_ref: &SideReference,
     instructions: &mut StateInstructions,
 ) {
+    let generation = state.generation;
     let (attacking_side, defending_side) = state.get_both_sides(side_ref);
     let active_pkmn = attacking_side.get_active();
     let defending_pkmn = defending_side.get_active();
@@ -542,10 +546,10 @@ pub fn ability_before_move(
         }
         // Technically incorrect
         // A move missing should not trigger this formechange
-        #[cfg(not(any(feature = "gen8", feature = "gen9")))]
         Abilities::DISGUISE
-            if (choice.category == MoveCategory::Physical
-                || choice.category == MoveCategory::Special)
+            if generation <= Generation::GEN7
+                && (choice.category == MoveCategory::Physical
+                    || choice.category == MoveCategory::Special)
                 && (defending_pkmn.id == PokemonName::MIMIKYU
                     || defending_pkmn.id == PokemonName::MIMIKYUTOTEM) =>
         {
@@ -558,10 +562,10 @@ pub fn ability_before_move(
                 }));
             defending_pkmn.id = PokemonName::MIMIKYUBUSTED;
         }
-        #[cfg(any(feature = "gen8", feature = "gen9"))]
         Abilities::DISGUISE
-            if (choice.category == MoveCategory::Physical
-                || choice.category == MoveCategory::Special)
+            if generation >= Generation::GEN8
+                && (choice.category == MoveCategory::Physical
+                    || choice.category == MoveCategory::Special)
                 && (defending_pkmn.id == PokemonName::MIMIKYU
                     || defending_pkmn.id == PokemonName::MIMIKYUTOTEM) =>
         {
@@ -603,8 +607,7 @@ pub fn ability_before_move(
                 active_pkmn.id = new_forme;
             }
         }
-        #[cfg(feature = "gen9")]
-        Abilities::PROTEAN | Abilities::LIBERO => {
+        Abilities::PROTEAN | Abilities::LIBERO if generation == Generation::GEN9 => {
             if !attacking_side
                 .volatile_statuses
                 .contains(&PokemonVolatileStatus::TYPECHANGE)
@@ -634,8 +637,7 @@ pub fn ability_before_move(
                 }
             }
         }
-        #[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8"))]
-        Abilities::PROTEAN | Abilities::LIBERO => {
+        Abilities::PROTEAN | Abilities::LIBERO if generation >= Generation::GEN6 => {
             if !active_pkmn.has_type(&choice.move_type) && !active_pkmn.terastallized {
                 instructions
                     .instruction_list
@@ -681,6 +683,7 @@ pub fn ability_after_damage_hit(
     damage_dealt: i16,
     instructions: &mut StateInstructions,
 ) {
+    let generation = state.generation;
     let (attacking_side, defending_side) = state.get_both_sides(side_ref);
     let active_pkmn = attacking_side.get_active();
     if defending_side.get_active_immutable().ability == Abilities::NEUTRALIZINGGAS
@@ -767,7 +770,7 @@ pub fn ability_after_damage_hit(
         }
         Abilities::BEASTBOOST => {
             if damage_dealt > 0 && defending_side.get_active_immutable().hp == 0 {
-                let highest_stat = &attacking_side.calculate_highest_stat();
+                let highest_stat = &attacking_side.calculate_highest_stat(generation);
                 apply_boost_instruction(
                     attacking_side,
                     highest_stat,
@@ -880,12 +883,12 @@ pub fn ability_after_damage_hit(
                     .instruction_list
                     .push(Instruction::ChangeWeather(ChangeWeather {
                         new_weather: Weather::SAND,
-                        new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                        new_weather_turns_remaining: weather_ability_turns(generation),
                         previous_weather: state.weather.weather_type,
                         previous_weather_turns_remaining: state.weather.turns_remaining,
                     }));
                 state.weather.weather_type = Weather::SAND;
-                state.weather.turns_remaining = WEATHER_ABILITY_TURNS;
+                state.weather.turns_remaining = weather_ability_turns(generation);
             }
         }
         Abilities::SEEDSOWER => {
@@ -937,10 +940,6 @@ pub fn ability_after_damage_hit(
         }
         Abilities::ROUGHSKIN | Abilities::IRONBARBS => {
             if damage_dealt > 0 && choice.flags.contact {
-                #[cfg(feature = "gen3")]
-                let damage_dealt = cmp::min(attacking_pkmn.maxhp / 16, attacking_pkmn.hp);
-
-                #[cfg(not(feature = "gen3"))]
                 let damage_dealt = cmp::min(attacking_pkmn.maxhp / 8, attacking_pkmn.hp);
 
                 instructions
@@ -1359,6 +1358,7 @@ pub fn ability_on_switch_in(
     side_ref: &SideReference,
     instructions: &mut StateInstructions,
 ) {
+    let generation = state.generation;
     let (attacking_side, defending_side) = state.get_both_sides(side_ref);
     let active_pkmn = attacking_side.get_active();
     let defending_pkmn = defending_side.get_active_immutable();
@@ -1397,7 +1397,7 @@ pub fn ability_on_switch_in(
         Abilities::PROTOSYNTHESIS => {
             let sun_is_active = state.weather_is_active(&Weather::SUN);
             let attacking_side = state.get_side(side_ref);
-            let volatile = protosynthesis_volatile_from_side(&attacking_side);
+            let volatile = protosynthesis_volatile_from_side(generation, &attacking_side);
             protosynthesus_or_quarkdrive_on_switch_in(
                 sun_is_active,
                 volatile,
@@ -1409,7 +1409,7 @@ pub fn ability_on_switch_in(
         Abilities::QUARKDRIVE => {
             let electric_terrain_is_active = state.terrain_is_active(&Terrain::ELECTRICTERRAIN);
             let attacking_side = state.get_side(side_ref);
-            let volatile = quarkdrive_volatile_from_side(&attacking_side);
+            let volatile = quarkdrive_volatile_from_side(generation, &attacking_side);
             protosynthesus_or_quarkdrive_on_switch_in(
                 electric_terrain_is_active,
                 volatile,
@@ -1498,12 +1498,12 @@ pub fn ability_on_switch_in(
                     .instruction_list
                     .push(Instruction::ChangeWeather(ChangeWeather {
                         new_weather: Weather::SUN,
-                        new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                        new_weather_turns_remaining: weather_ability_turns(generation),
                         previous_weather: state.weather.weather_type,
                         previous_weather_turns_remaining: state.weather.turns_remaining,
                     }));
                 state.weather.weather_type = Weather::SUN;
-                state.weather.turns_remaining = WEATHER_ABILITY_TURNS;
+                state.weather.turns_remaining = weather_ability_turns(generation);
             }
         }
         Abilities::DESOLATELAND => {
@@ -1540,12 +1540,12 @@ pub fn ability_on_switch_in(
                     .instruction_list
                     .push(Instruction::ChangeWeather(ChangeWeather {
                         new_weather: Weather::SAND,
-                        new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                        new_weather_turns_remaining: weather_ability_turns(generation),
                         previous_weather: state.weather.weather_type,
                         previous_weather_turns_remaining: state.weather.turns_remaining,
                     }));
                 state.weather.weather_type = Weather::SAND;
-                state.weather.turns_remaining = WEATHER_ABILITY_TURNS;
+                state.weather.turns_remaining = weather_ability_turns(generation);
             }
         }
         Abilities::INTIMIDATE => {
@@ -1631,8 +1631,9 @@ pub fn ability_on_switch_in(
             }
         }
         Abilities::DOWNLOAD => {
-            if defending_side.calculate_boosted_stat(PokemonBoostableStat::Defense)
-                < defending_side.calculate_boosted_stat(PokemonBoostableStat::SpecialDefense)
+            if defending_side.calculate_boosted_stat(generation, PokemonBoostableStat::Defense)
+                < defending_side
+                    .calculate_boosted_stat(generation, PokemonBoostableStat::SpecialDefense)
             {
                 apply_boost_instruction(
                     attacking_side,
@@ -1742,22 +1743,23 @@ pub fn ability_on_switch_in(
             }
         }
         Abilities::SNOWWARNING => {
-            #[cfg(feature = "gen9")]
-            let weather_type = Weather::SNOW;
-            #[cfg(not(feature = "gen9"))]
-            let weather_type = Weather::HAIL;
+            let weather_type = if generation == Generation::GEN9 {
+                Weather::SNOW
+            } else {
+                Weather::HAIL
+            };
 
             if state.weather.weather_type != weather_type {
                 instructions
                     .instruction_list
                     .push(Instruction::ChangeWeather(ChangeWeather {
                         new_weather: weather_type,
-                        new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                        new_weather_turns_remaining: weather_ability_turns(generation),
                         previous_weather: state.weather.weather_type,
                         previous_weather_turns_remaining: state.weather.turns_remaining,
                     }));
                 state.weather.weather_type = weather_type;
-                state.weather.turns_remaining = WEATHER_ABILITY_TURNS;
+                state.weather.turns_remaining = weather_ability_turns(generation);
             }
         }
         Abilities::PSYCHICSURGE => {
@@ -1780,12 +1782,12 @@ pub fn ability_on_switch_in(
                     .instruction_list
                     .push(Instruction::ChangeWeather(ChangeWeather {
                         new_weather: Weather::RAIN,
-                        new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                        new_weather_turns_remaining: weather_ability_turns(generation),
                         previous_weather: state.weather.weather_type,
                         previous_weather_turns_remaining: state.weather.turns_remaining,
                     }));
                 state.weather.weather_type = Weather::RAIN;
-                state.weather.turns_remaining = WEATHER_ABILITY_TURNS;
+                state.weather.turns_remaining = weather_ability_turns(generation);
             }
         }
         _ => {}
@@ -1804,8 +1806,7 @@ pub fn ability_modify_attack_being_used(
         return;
     }
     match attacking_pkmn.ability {
-        #[cfg(any(feature = "gen9", feature = "gen8", feature = "gen7"))]
-        Abilities::PRANKSTER => {
+        Abilities::PRANKSTER if state.generation >= Generation::GEN7 => {
             if attacker_choice.category == MoveCategory::Status
                 && defending_side
                     .get_active_immutable()
@@ -1859,15 +1860,13 @@ pub fn ability_modify_attack_being_used(
                 attacker_choice.base_power *= 1.2;
             }
         }
-        #[cfg(any(feature = "gen9", feature = "gen8", feature = "gen7"))]
-        Abilities::AERILATE => {
+        Abilities::AERILATE if state.generation >= Generation::GEN7 => {
             if attacker_choice.move_type == PokemonType::NORMAL {
                 attacker_choice.move_type = PokemonType::FLYING;
                 attacker_choice.base_power *= 1.2;
             }
         }
-        #[cfg(any(feature = "gen6", feature = "gen5", feature = "gen4"))]
-        Abilities::AERILATE => {
+        Abilities::AERILATE if state.generation <= Generation::GEN6 => {
             if attacker_choice.move_type == PokemonType::NORMAL {
                 attacker_choice.move_type = PokemonType::FLYING;
                 attacker_choice.base_power *= 1.3;
@@ -1875,6 +1874,7 @@ pub fn ability_modify_attack_being_used(
         }
         Abilities::NEUROFORCE => {
             if type_effectiveness_modifier(
+                state.generation,
                 &attacker_choice.move_type,
                 &defending_side.get_active_immutable(),
             ) > 1.0
@@ -1892,15 +1892,13 @@ pub fn ability_modify_attack_being_used(
                 attacker_choice.base_power *= 1.5;
             }
         }
-        #[cfg(any(feature = "gen9", feature = "gen8", feature = "gen7"))]
-        Abilities::REFRIGERATE => {
+        Abilities::REFRIGERATE if state.generation >= Generation::GEN7 => {
             if attacker_choice.move_type == PokemonType::NORMAL {
                 attacker_choice.move_type = PokemonType::ICE;
                 attacker_choice.base_power *= 1.2;
             }
         }
-        #[cfg(any(feature = "gen6", feature = "gen5", feature = "gen4"))]
-        Abilities::REFRIGERATE => {
+        Abilities::REFRIGERATE if state.generation <= Generation::GEN6 => {
             if attacker_choice.move_type == PokemonType::NORMAL {
                 attacker_choice.move_type = PokemonType::ICE;
                 attacker_choice.base_power *= 1.3;
@@ -1934,6 +1932,7 @@ pub fn ability_modify_attack_being_used(
         }
         Abilities::TINTEDLENS => {
             if type_effectiveness_modifier(
+                state.generation,
                 &attacker_choice.move_type,
                 &defending_side.get_active_immutable(),
             ) < 1.0
@@ -2017,20 +2016,12 @@ pub fn ability_modify_attack_being_used(
                 attacker_choice.base_power *= 1.5;
             }
         }
-        #[cfg(any(
-            feature = "gen8",
-            feature = "gen7",
-            feature = "gen6",
-            feature = "gen5",
-            feature = "gen4"
-        ))]
-        Abilities::TRANSISTOR => {
+        Abilities::TRANSISTOR if state.generation <= Generation::GEN8 => {
             if attacker_choice.move_type == PokemonType::ELECTRIC {
                 attacker_choice.base_power *= 1.5;
             }
         }
-        #[cfg(any(feature = "gen9"))]
-        Abilities::TRANSISTOR => {
+        Abilities::TRANSISTOR if state.generation == Generation::GEN9 => {
             if attacker_choice.move_type == PokemonType::ELECTRIC {
                 attacker_choice.base_power *= 1.3;
             }
@@ -2088,15 +2079,13 @@ pub fn ability_modify_attack_being_used(
                 attacker_choice.base_power *= 1.5;
             };
         }
-        #[cfg(any(feature = "gen9", feature = "gen8", feature = "gen7"))]
-        Abilities::PIXILATE => {
+        Abilities::PIXILATE if state.generation >= Generation::GEN7 => {
             if attacker_choice.move_type == PokemonType::NORMAL {
                 attacker_choice.move_type = PokemonType::FAIRY;
                 attacker_choice.base_power *= 1.2;
             }
         }
-        #[cfg(any(feature = "gen6", feature = "gen5", feature = "gen4"))]
-        Abilities::PIXILATE => {
+        Abilities::PIXILATE if state.generation <= Generation::GEN6 => {
             if attacker_choice.move_type == PokemonType::NORMAL {
                 attacker_choice.move_type = PokemonType::FAIRY;
                 attacker_choice.base_power *= 1.3;
@@ -2343,8 +2332,7 @@ pub fn ability_modify_attack_against(
                 });
             }
         }
-        #[cfg(any(feature = "gen9", feature = "gen8", feature = "gen7"))]
-        Abilities::WEAKARMOR => {
+        Abilities::WEAKARMOR if state.generation >= Generation::GEN7 => {
             if attacker_choice.category == MoveCategory::Physical {
                 attacker_choice.add_or_create_secondaries(Secondary {
                     chance: 100.0,
@@ -2360,8 +2348,7 @@ pub fn ability_modify_attack_against(
                 });
             }
         }
-        #[cfg(any(feature = "gen6", feature = "gen5", feature = "gen4"))]
-        Abilities::WEAKARMOR => {
+        Abilities::WEAKARMOR if state.generation <= Generation::GEN6 => {
             if attacker_choice.category == MoveCategory::Physical {
                 attacker_choice.add_or_create_secondaries(Secondary {
                     chance: 100.0,
@@ -2417,28 +2404,7 @@ pub fn ability_modify_attack_against(
                 attacker_choice.base_power /= 1.5;
             }
         }
-        #[cfg(feature = "gen3")]
-        Abilities::EFFECTSPORE => {
-            if attacker_choice.flags.contact {
-                attacker_choice.add_or_create_secondaries(Secondary {
-                    chance: 3.30,
-                    target: MoveTarget::User,
-                    effect: Effect::Status(PokemonStatus::POISON),
-                });
-                attacker_choice.add_or_create_secondaries(Secondary {
-                    chance: 3.30,
-                    target: MoveTarget::User,
-                    effect: Effect::Status(PokemonStatus::PARALYZE),
-                });
-                attacker_choice.add_or_create_secondaries(Secondary {
-                    chance: 3.30,
-                    target: MoveTarget::User,
-                    effect: Effect::Status(PokemonStatus::SLEEP),
-                });
-            }
-        }
 
-        #[cfg(not(feature = "gen3"))]
         Abilities::EFFECTSPORE => {
             if attacker_choice.flags.contact {
                 attacker_choice.add_or_create_secondaries(Secondary {
@@ -2526,7 +2492,11 @@ pub fn ability_modify_attack_against(
         }
         Abilities::WONDERGUARD => {
             if attacker_choice.category != MoveCategory::Status
-                && type_effectiveness_modifier(&attacker_choice.move_type, &target_pkmn) <= 1.0
+                && type_effectiveness_modifier(
+                    state.generation,
+                    &attacker_choice.move_type,
+                    &target_pkmn,
+                ) <= 1.0
             {
                 attacker_choice.remove_all_effects();
                 attacker_choice.base_power = 0.0;
@@ -2602,7 +2572,12 @@ pub fn ability_modify_attack_against(
             }
         }
         Abilities::PRISMARMOR => {
-            if type_effectiveness_modifier(&attacker_choice.move_type, &target_pkmn) > 1.0 {
+            if type_effectiveness_modifier(
+                state.generation,
+                &attacker_choice.move_type,
+                &target_pkmn,
+            ) > 1.0
+            {
                 attacker_choice.base_power *= 0.75;
             }
         }
@@ -2628,7 +2603,12 @@ pub fn ability_modify_attack_against(
             }
         }
         Abilities::FILTER => {
-            if type_effectiveness_modifier(&attacker_choice.move_type, &target_pkmn) > 1.0 {
+            if type_effectiveness_modifier(
+                state.generation,
+                &attacker_choice.move_type,
+                &target_pkmn,
+            ) > 1.0
+            {
                 attacker_choice.base_power *= 0.75;
             }
         }
@@ -2780,11 +2760,6 @@ pub fn ability_modify_attack_against(
             }
         }
         Abilities::VOLTABSORB => {
-            #[cfg(feature = "gen3")]
-            let activate = attacker_choice.move_type == PokemonType::ELECTRIC
-                && attacker_choice.category != MoveCategory::Status;
-
-            #[cfg(not(feature = "gen3"))]
             let activate = attacker_choice.move_type == PokemonType::ELECTRIC;
 
             if activate {
@@ -2799,7 +2774,12 @@ pub fn ability_modify_attack_against(
             }
         }
         Abilities::SOLIDROCK => {
-            if type_effectiveness_modifier(&attacker_choice.move_type, &target_pkmn) > 1.0 {
+            if type_effectiveness_modifier(
+                state.generation,
+                &attacker_choice.move_type,
+                &target_pkmn,
+            ) > 1.0
+            {
                 attacker_choice.base_power *= 0.75;
             }
         }
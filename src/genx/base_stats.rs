@@ -11,96 +11,8 @@ pub struct MegaEvolveData {
 }
 
 impl PokemonName {
-    /*
-    Base Stats are only required to re-calculate stats when a pokemon changes forme
-    so not every pokemon will be here
-    */
     pub fn base_stats(&self) -> (i16, i16, i16, i16, i16, i16) {
-        match self {
-            PokemonName::MINIOR => (60, 100, 60, 100, 60, 120),
-            PokemonName::MINIORMETEOR => (60, 60, 100, 60, 100, 60),
-            PokemonName::WISHIWASHI => (45, 20, 20, 25, 25, 40),
-            PokemonName::WISHIWASHISCHOOL => (45, 140, 130, 140, 135, 30),
-            PokemonName::PALAFIN => (100, 70, 72, 53, 62, 100),
-            PokemonName::PALAFINHERO => (100, 160, 97, 106, 87, 100),
-            PokemonName::EISCUE => (75, 80, 110, 65, 90, 50),
-            PokemonName::EISCUENOICE => (75, 80, 70, 65, 50, 130),
-
-            // megas
-            PokemonName::VENUSAURMEGA => (80, 100, 123, 122, 120, 80),
-            PokemonName::CHARIZARDMEGAX => (78, 130, 111, 130, 85, 100),
-            PokemonName::CHARIZARDMEGAY => (78, 104, 78, 159, 115, 100),
-            PokemonName::BLASTOISEMEGA => (79, 103, 120, 135, 115, 78),
-            PokemonName::BEEDRILLMEGA => (65, 150, 40, 15, 80, 145),
-            PokemonName::PIDGEOTMEGA => (83, 80, 80, 135, 80, 121),
-            PokemonName::ALAKAZAMMEGA => (55, 50, 65, 175, 105, 150),
-            PokemonName::SLOWBROMEGA => (95, 75, 180, 130, 80, 30),
-            PokemonName::GENGARMEGA => (60, 65, 80, 170, 95, 130),
-            PokemonName::KANGASKHANMEGA => (105, 125, 100, 60, 100, 100),
-            PokemonName::PINSIRMEGA => (65, 155, 120, 65, 90, 105),
-            PokemonName::GYARADOSMEGA => (95, 155, 109, 70, 130, 81),
-            PokemonName::AERODACTYLMEGA => (80, 135, 85, 70, 95, 150),
-            PokemonName::MEWTWOMEGAX => (106, 190, 100, 154, 100, 130),
-            PokemonName::MEWTWOMEGAY => (106, 150, 70, 194, 120, 140),
-            PokemonName::AMPHAROSMEGA => (90, 95, 105, 165, 110, 45),
-            PokemonName::STEELIXMEGA => (75, 125, 230, 55, 95, 30),
-            PokemonName::SCIZORMEGA => (70, 150, 140, 65, 100, 75),
-            PokemonName::HERACROSSMEGA => (80, 185, 115, 40, 105, 75),
-            PokemonName::HOUNDOOMMEGA => (75, 90, 90, 140, 90, 115),
-            PokemonName::TYRANITARMEGA => (100, 164, 150, 95, 120, 71),
-            PokemonName::SCEPTILEMEGA => (70, 110, 75, 145, 85, 145),
-            PokemonName::BLAZIKENMEGA => (80, 160, 80, 130, 80, 100),
-            PokemonName::SWAMPERTMEGA => (100, 150, 110, 95, 110, 70),
-            PokemonName::GARDEVOIRMEGA => (68, 85, 65, 165, 135, 100),
-            PokemonName::SABLEYEMEGA => (50, 85, 125, 85, 115, 20),
-            PokemonName::MAWILEMEGA => (50, 105, 125, 55, 95, 50),
-            PokemonName::AGGRONMEGA => (70, 140, 230, 60, 80, 50),
-            PokemonName::MEDICHAMMEGA => (60, 100, 85, 80, 85, 100),
-            PokemonName::MANECTRICMEGA => (70, 75, 80, 135, 80, 135),
-            PokemonName::SHARPEDOMEGA => (70, 140, 70, 110, 65, 105),
-            PokemonName::CAMERUPTMEGA => (70, 120, 100, 145, 105, 20),
-            PokemonName::ALTARIAMEGA => (75, 110, 110, 110, 105, 80),
-            PokemonName::BANETTEMEGA => (64, 165, 75, 93, 83, 75),
-            PokemonName::ABSOLMEGA => (65, 150, 60, 115, 60, 115),
-            PokemonName::GLALIEMEGA => (80, 120, 80, 120, 80, 100),
-            PokemonName::SALAMENCEMEGA => (95, 145, 130, 120, 90, 120),
-            PokemonName::METAGROSSMEGA => (80, 145, 150, 105, 110, 110),
-            PokemonName::LATIASMEGA => (80, 100, 120, 140, 150, 110),
-            PokemonName::LATIOSMEGA => (80, 130, 100, 160, 120, 110),
-            PokemonName::RAYQUAZAMEGA => (105, 180, 100, 180, 100, 115),
-            PokemonName::LOPUNNYMEGA => (65, 136, 94, 54, 96, 135),
-            PokemonName::GARCHOMPMEGA => (108, 170, 115, 120, 95, 92),
-            PokemonName::LUCARIOMEGA => (70, 145, 88, 140, 70, 112),
-            PokemonName::ABOMASNOWMEGA => (90, 132, 105, 132, 105, 30),
-            PokemonName::GALLADEMEGA => (68, 165, 95, 65, 115, 110),
-            PokemonName::AUDINOMEGA => (103, 60, 126, 80, 126, 50),
-            PokemonName::DIANCIEMEGA => (50, 160, 110, 160, 110, 110),
-
-            PokemonName::DRAGONITEMEGA => (91, 124, 115, 145, 125, 100),
-            PokemonName::CLEFABLEMEGA => (95, 80, 93, 135, 110, 70),
-            PokemonName::MEGANIUMMEGA => (80, 92, 115, 143, 115, 80),
-            PokemonName::FERALIGATRMEGA => (85, 160, 125, 89, 93, 78),
-            PokemonName::EMBOARMEGA => (110, 148, 75, 110, 110, 75),
-            PokemonName::CHESNAUGHTMEGA => (88, 137, 172, 74, 115, 44),
-            PokemonName::DELPHOXMEGA => (75, 69, 72, 159, 125, 134),
-            PokemonName::GRENINJAMEGA => (72, 125, 77, 133, 81, 142),
-            PokemonName::CRABOMINABLEMEGA => (97, 157, 122, 62, 107, 33),
-            PokemonName::GOLURKMEGA => (89, 159, 105, 70, 105, 55),
-            PokemonName::SCOVILLAINMEGA => (65, 138, 85, 138, 85, 75),
-            PokemonName::GLIMMORAMEGA => (83, 90, 105, 150, 96, 101),
-            PokemonName::FLOETTEMEGA => (74, 85, 87, 155, 148, 102),
-            PokemonName::VICTREEBELMEGA => (80, 125, 85, 135, 95, 70),
-            PokemonName::STARMIEMEGA => (60, 140, 105, 130, 105, 120),
-            PokemonName::HAWLUCHAMEGA => (78, 137, 100, 74, 93, 118),
-            PokemonName::SKARMORYMEGA => (65, 140, 110, 40, 100, 110),
-            PokemonName::MEOWSTICMEGA => (74, 48, 76, 143, 101, 124),
-            PokemonName::FROSLASSMEGA => (70, 80, 70, 140, 100, 120),
-            PokemonName::EXCADRILLMEGA => (110, 165, 100, 65, 65, 103),
-            PokemonName::DRAMPAMEGA => (78, 85, 110, 160, 114, 36),
-            PokemonName::CHIMECHOMEGA => (75, 50, 110, 135, 120, 65),
-            PokemonName::CHANDELUREMEGA => (60, 75, 110, 175, 110, 90),
-            _ => panic!("Base stats not implemented for {}", self),
-        }
+        self.pokedex_entry().base_stats
     }
 
     pub fn mega_evolve_target(&self, item: Items) -> Option<MegaEvolveData> {
@@ -528,4 +440,3497 @@ impl PokemonName {
             _ => None,
         }
     }
+
+    /// Every ability the species can legally have, including its hidden ability.
+    /// The first one is used when an ability is not given
+    pub fn abilities(&self) -> &'static [Abilities] {
+        match self {
+            PokemonName::NONE => &[],
+            PokemonName::BULBASAUR => &[Abilities::OVERGROW, Abilities::CHLOROPHYLL],
+            PokemonName::IVYSAUR => &[Abilities::OVERGROW, Abilities::CHLOROPHYLL],
+            PokemonName::VENUSAUR => &[Abilities::OVERGROW, Abilities::CHLOROPHYLL],
+            PokemonName::VENUSAURMEGA => &[Abilities::THICKFAT],
+            PokemonName::VENUSAURGMAX => &[Abilities::OVERGROW, Abilities::CHLOROPHYLL],
+            PokemonName::CHARMANDER => &[Abilities::BLAZE, Abilities::SOLARPOWER],
+            PokemonName::CHARMELEON => &[Abilities::BLAZE, Abilities::SOLARPOWER],
+            PokemonName::CHARIZARD => &[Abilities::BLAZE, Abilities::SOLARPOWER],
+            PokemonName::CHARIZARDMEGAX => &[Abilities::TOUGHCLAWS],
+            PokemonName::CHARIZARDMEGAY => &[Abilities::DROUGHT],
+            PokemonName::CHARIZARDGMAX => &[Abilities::BLAZE, Abilities::SOLARPOWER],
+            PokemonName::SQUIRTLE => &[Abilities::TORRENT, Abilities::RAINDISH],
+            PokemonName::WARTORTLE => &[Abilities::TORRENT, Abilities::RAINDISH],
+            PokemonName::BLASTOISE => &[Abilities::TORRENT, Abilities::RAINDISH],
+            PokemonName::BLASTOISEMEGA => &[Abilities::MEGALAUNCHER],
+            PokemonName::BLASTOISEGMAX => &[Abilities::TORRENT, Abilities::RAINDISH],
+            PokemonName::CATERPIE => &[Abilities::SHIELDDUST, Abilities::RUNAWAY],
+            PokemonName::METAPOD => &[Abilities::SHEDSKIN],
+            PokemonName::BUTTERFREE => &[Abilities::COMPOUNDEYES, Abilities::TINTEDLENS],
+            PokemonName::BUTTERFREEGMAX => &[Abilities::COMPOUNDEYES, Abilities::TINTEDLENS],
+            PokemonName::WEEDLE => &[Abilities::SHIELDDUST, Abilities::RUNAWAY],
+            PokemonName::KAKUNA => &[Abilities::SHEDSKIN],
+            PokemonName::BEEDRILL => &[Abilities::SWARM, Abilities::SNIPER],
+            PokemonName::BEEDRILLMEGA => &[Abilities::ADAPTABILITY],
+            PokemonName::PIDGEY => &[
+                Abilities::KEENEYE,
+                Abilities::TANGLEDFEET,
+                Abilities::BIGPECKS,
+            ],
+            PokemonName::PIDGEOTTO => &[
+                Abilities::KEENEYE,
+                Abilities::TANGLEDFEET,
+                Abilities::BIGPECKS,
+            ],
+            PokemonName::PIDGEOT => &[
+                Abilities::KEENEYE,
+                Abilities::TANGLEDFEET,
+                Abilities::BIGPECKS,
+            ],
+            PokemonName::PIDGEOTMEGA => &[Abilities::NOGUARD],
+            PokemonName::RATTATA => &[Abilities::RUNAWAY, Abilities::GUTS, Abilities::HUSTLE],
+            PokemonName::RATTATAALOLA => {
+                &[Abilities::GLUTTONY, Abilities::HUSTLE, Abilities::THICKFAT]
+            }
+            PokemonName::RATICATE => &[Abilities::RUNAWAY, Abilities::GUTS, Abilities::HUSTLE],
+            PokemonName::RATICATEALOLA => {
+                &[Abilities::GLUTTONY, Abilities::HUSTLE, Abilities::THICKFAT]
+            }
+            PokemonName::RATICATEALOLATOTEM => &[Abilities::THICKFAT],
+            PokemonName::SPEAROW => &[Abilities::KEENEYE, Abilities::SNIPER],
+            PokemonName::FEAROW => &[Abilities::KEENEYE, Abilities::SNIPER],
+            PokemonName::EKANS => &[
+                Abilities::INTIMIDATE,
+                Abilities::SHEDSKIN,
+                Abilities::UNNERVE,
+            ],
+            PokemonName::ARBOK => &[
+                Abilities::INTIMIDATE,
+                Abilities::SHEDSKIN,
+                Abilities::UNNERVE,
+            ],
+            PokemonName::PIKACHU => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUCOSPLAY => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUROCKSTAR => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUBELLE => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUPOPSTAR => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUPHD => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHULIBRE => &[Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUORIGINAL => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUHOENN => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUSINNOH => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUUNOVA => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUKALOS => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUALOLA => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUPARTNER => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUSTARTER => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUGMAX => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::PIKACHUWORLD => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::RAICHU => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::RAICHUALOLA => &[Abilities::SURGESURFER],
+            PokemonName::SANDSHREW => &[Abilities::SANDVEIL, Abilities::SANDRUSH],
+            PokemonName::SANDSHREWALOLA => &[Abilities::SNOWCLOAK, Abilities::SLUSHRUSH],
+            PokemonName::SANDSLASH => &[Abilities::SANDVEIL, Abilities::SANDRUSH],
+            PokemonName::SANDSLASHALOLA => &[Abilities::SNOWCLOAK, Abilities::SLUSHRUSH],
+            PokemonName::NIDORANF => &[
+                Abilities::POISONPOINT,
+                Abilities::RIVALRY,
+                Abilities::HUSTLE,
+            ],
+            PokemonName::NIDORINA => &[
+                Abilities::POISONPOINT,
+                Abilities::RIVALRY,
+                Abilities::HUSTLE,
+            ],
+            PokemonName::NIDOQUEEN => &[
+                Abilities::POISONPOINT,
+                Abilities::RIVALRY,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::NIDORANM => &[
+                Abilities::POISONPOINT,
+                Abilities::RIVALRY,
+                Abilities::HUSTLE,
+            ],
+            PokemonName::NIDORINO => &[
+                Abilities::POISONPOINT,
+                Abilities::RIVALRY,
+                Abilities::HUSTLE,
+            ],
+            PokemonName::NIDOKING => &[
+                Abilities::POISONPOINT,
+                Abilities::RIVALRY,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::CLEFAIRY => &[
+                Abilities::CUTECHARM,
+                Abilities::MAGICGUARD,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::CLEFABLE => &[
+                Abilities::CUTECHARM,
+                Abilities::MAGICGUARD,
+                Abilities::UNAWARE,
+            ],
+            PokemonName::VULPIX => &[Abilities::FLASHFIRE, Abilities::DROUGHT],
+            PokemonName::VULPIXALOLA => &[Abilities::SNOWCLOAK, Abilities::SNOWWARNING],
+            PokemonName::NINETALES => &[Abilities::FLASHFIRE, Abilities::DROUGHT],
+            PokemonName::NINETALESALOLA => &[Abilities::SNOWCLOAK, Abilities::SNOWWARNING],
+            PokemonName::JIGGLYPUFF => &[
+                Abilities::CUTECHARM,
+                Abilities::COMPETITIVE,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::WIGGLYTUFF => &[
+                Abilities::CUTECHARM,
+                Abilities::COMPETITIVE,
+                Abilities::FRISK,
+            ],
+            PokemonName::ZUBAT => &[Abilities::INNERFOCUS, Abilities::INFILTRATOR],
+            PokemonName::GOLBAT => &[Abilities::INNERFOCUS, Abilities::INFILTRATOR],
+            PokemonName::ODDISH => &[Abilities::CHLOROPHYLL, Abilities::RUNAWAY],
+            PokemonName::GLOOM => &[Abilities::CHLOROPHYLL, Abilities::STENCH],
+            PokemonName::VILEPLUME => &[Abilities::CHLOROPHYLL, Abilities::EFFECTSPORE],
+            PokemonName::PARAS => &[Abilities::EFFECTSPORE, Abilities::DRYSKIN, Abilities::DAMP],
+            PokemonName::PARASECT => &[Abilities::EFFECTSPORE, Abilities::DRYSKIN, Abilities::DAMP],
+            PokemonName::VENONAT => &[
+                Abilities::COMPOUNDEYES,
+                Abilities::TINTEDLENS,
+                Abilities::RUNAWAY,
+            ],
+            PokemonName::VENOMOTH => &[
+                Abilities::SHIELDDUST,
+                Abilities::TINTEDLENS,
+                Abilities::WONDERSKIN,
+            ],
+            PokemonName::DIGLETT => &[
+                Abilities::SANDVEIL,
+                Abilities::ARENATRAP,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::DIGLETTALOLA => &[
+                Abilities::SANDVEIL,
+                Abilities::TANGLINGHAIR,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::DUGTRIO => &[
+                Abilities::SANDVEIL,
+                Abilities::ARENATRAP,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::DUGTRIOALOLA => &[
+                Abilities::SANDVEIL,
+                Abilities::TANGLINGHAIR,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::MEOWTH => &[Abilities::PICKUP, Abilities::TECHNICIAN, Abilities::UNNERVE],
+            PokemonName::MEOWTHALOLA => {
+                &[Abilities::PICKUP, Abilities::TECHNICIAN, Abilities::RATTLED]
+            }
+            PokemonName::MEOWTHGALAR => {
+                &[Abilities::PICKUP, Abilities::TOUGHCLAWS, Abilities::UNNERVE]
+            }
+            PokemonName::MEOWTHGMAX => {
+                &[Abilities::PICKUP, Abilities::TECHNICIAN, Abilities::UNNERVE]
+            }
+            PokemonName::PERSIAN => &[Abilities::LIMBER, Abilities::TECHNICIAN, Abilities::UNNERVE],
+            PokemonName::PERSIANALOLA => &[
+                Abilities::FURCOAT,
+                Abilities::TECHNICIAN,
+                Abilities::RATTLED,
+            ],
+            PokemonName::PSYDUCK => &[Abilities::DAMP, Abilities::CLOUDNINE, Abilities::SWIFTSWIM],
+            PokemonName::GOLDUCK => &[Abilities::DAMP, Abilities::CLOUDNINE, Abilities::SWIFTSWIM],
+            PokemonName::MANKEY => &[
+                Abilities::VITALSPIRIT,
+                Abilities::ANGERPOINT,
+                Abilities::DEFIANT,
+            ],
+            PokemonName::PRIMEAPE => &[
+                Abilities::VITALSPIRIT,
+                Abilities::ANGERPOINT,
+                Abilities::DEFIANT,
+            ],
+            PokemonName::GROWLITHE => &[
+                Abilities::INTIMIDATE,
+                Abilities::FLASHFIRE,
+                Abilities::JUSTIFIED,
+            ],
+            PokemonName::GROWLITHEHISUI => &[
+                Abilities::INTIMIDATE,
+                Abilities::FLASHFIRE,
+                Abilities::ROCKHEAD,
+            ],
+            PokemonName::ARCANINE => &[
+                Abilities::INTIMIDATE,
+                Abilities::FLASHFIRE,
+                Abilities::JUSTIFIED,
+            ],
+            PokemonName::ARCANINEHISUI => &[
+                Abilities::INTIMIDATE,
+                Abilities::FLASHFIRE,
+                Abilities::ROCKHEAD,
+            ],
+            PokemonName::POLIWAG => &[
+                Abilities::WATERABSORB,
+                Abilities::DAMP,
+                Abilities::SWIFTSWIM,
+            ],
+            PokemonName::POLIWHIRL => &[
+                Abilities::WATERABSORB,
+                Abilities::DAMP,
+                Abilities::SWIFTSWIM,
+            ],
+            PokemonName::POLIWRATH => &[
+                Abilities::WATERABSORB,
+                Abilities::DAMP,
+                Abilities::SWIFTSWIM,
+            ],
+            PokemonName::ABRA => &[
+                Abilities::SYNCHRONIZE,
+                Abilities::INNERFOCUS,
+                Abilities::MAGICGUARD,
+            ],
+            PokemonName::KADABRA => &[
+                Abilities::SYNCHRONIZE,
+                Abilities::INNERFOCUS,
+                Abilities::MAGICGUARD,
+            ],
+            PokemonName::ALAKAZAM => &[
+                Abilities::SYNCHRONIZE,
+                Abilities::INNERFOCUS,
+                Abilities::MAGICGUARD,
+            ],
+            PokemonName::ALAKAZAMMEGA => &[Abilities::TRACE],
+            PokemonName::MACHOP => &[Abilities::GUTS, Abilities::NOGUARD, Abilities::STEADFAST],
+            PokemonName::MACHOKE => &[Abilities::GUTS, Abilities::NOGUARD, Abilities::STEADFAST],
+            PokemonName::MACHAMP => &[Abilities::GUTS, Abilities::NOGUARD, Abilities::STEADFAST],
+            PokemonName::MACHAMPGMAX => {
+                &[Abilities::GUTS, Abilities::NOGUARD, Abilities::STEADFAST]
+            }
+            PokemonName::BELLSPROUT => &[Abilities::CHLOROPHYLL, Abilities::GLUTTONY],
+            PokemonName::WEEPINBELL => &[Abilities::CHLOROPHYLL, Abilities::GLUTTONY],
+            PokemonName::VICTREEBEL => &[Abilities::CHLOROPHYLL, Abilities::GLUTTONY],
+            PokemonName::TENTACOOL => &[
+                Abilities::CLEARBODY,
+                Abilities::LIQUIDOOZE,
+                Abilities::RAINDISH,
+            ],
+            PokemonName::TENTACRUEL => &[
+                Abilities::CLEARBODY,
+                Abilities::LIQUIDOOZE,
+                Abilities::RAINDISH,
+            ],
+            PokemonName::GEODUDE => &[Abilities::ROCKHEAD, Abilities::STURDY, Abilities::SANDVEIL],
+            PokemonName::GEODUDEALOLA => &[
+                Abilities::MAGNETPULL,
+                Abilities::STURDY,
+                Abilities::GALVANIZE,
+            ],
+            PokemonName::GRAVELER => &[Abilities::ROCKHEAD, Abilities::STURDY, Abilities::SANDVEIL],
+            PokemonName::GRAVELERALOLA => &[
+                Abilities::MAGNETPULL,
+                Abilities::STURDY,
+                Abilities::GALVANIZE,
+            ],
+            PokemonName::GOLEM => &[Abilities::ROCKHEAD, Abilities::STURDY, Abilities::SANDVEIL],
+            PokemonName::GOLEMALOLA => &[
+                Abilities::MAGNETPULL,
+                Abilities::STURDY,
+                Abilities::GALVANIZE,
+            ],
+            PokemonName::PONYTA => &[
+                Abilities::RUNAWAY,
+                Abilities::FLASHFIRE,
+                Abilities::FLAMEBODY,
+            ],
+            PokemonName::PONYTAGALAR => &[
+                Abilities::RUNAWAY,
+                Abilities::PASTELVEIL,
+                Abilities::ANTICIPATION,
+            ],
+            PokemonName::RAPIDASH => &[
+                Abilities::RUNAWAY,
+                Abilities::FLASHFIRE,
+                Abilities::FLAMEBODY,
+            ],
+            PokemonName::RAPIDASHGALAR => &[
+                Abilities::RUNAWAY,
+                Abilities::PASTELVEIL,
+                Abilities::ANTICIPATION,
+            ],
+            PokemonName::SLOWPOKE => &[
+                Abilities::OBLIVIOUS,
+                Abilities::OWNTEMPO,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::SLOWPOKEGALAR => &[
+                Abilities::GLUTTONY,
+                Abilities::OWNTEMPO,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::SLOWBRO => &[
+                Abilities::OBLIVIOUS,
+                Abilities::OWNTEMPO,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::SLOWBROMEGA => &[Abilities::SHELLARMOR],
+            PokemonName::SLOWBROGALAR => &[
+                Abilities::QUICKDRAW,
+                Abilities::OWNTEMPO,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::MAGNEMITE => &[
+                Abilities::MAGNETPULL,
+                Abilities::STURDY,
+                Abilities::ANALYTIC,
+            ],
+            PokemonName::MAGNETON => &[
+                Abilities::MAGNETPULL,
+                Abilities::STURDY,
+                Abilities::ANALYTIC,
+            ],
+            PokemonName::FARFETCHD => &[
+                Abilities::KEENEYE,
+                Abilities::INNERFOCUS,
+                Abilities::DEFIANT,
+            ],
+            PokemonName::FARFETCHDGALAR => &[Abilities::STEADFAST, Abilities::SCRAPPY],
+            PokemonName::DODUO => &[
+                Abilities::RUNAWAY,
+                Abilities::EARLYBIRD,
+                Abilities::TANGLEDFEET,
+            ],
+            PokemonName::DODRIO => &[
+                Abilities::RUNAWAY,
+                Abilities::EARLYBIRD,
+                Abilities::TANGLEDFEET,
+            ],
+            PokemonName::SEEL => &[
+                Abilities::THICKFAT,
+                Abilities::HYDRATION,
+                Abilities::ICEBODY,
+            ],
+            PokemonName::DEWGONG => &[
+                Abilities::THICKFAT,
+                Abilities::HYDRATION,
+                Abilities::ICEBODY,
+            ],
+            PokemonName::GRIMER => &[
+                Abilities::STENCH,
+                Abilities::STICKYHOLD,
+                Abilities::POISONTOUCH,
+            ],
+            PokemonName::GRIMERALOLA => &[
+                Abilities::POISONTOUCH,
+                Abilities::GLUTTONY,
+                Abilities::POWEROFALCHEMY,
+            ],
+            PokemonName::MUK => &[
+                Abilities::STENCH,
+                Abilities::STICKYHOLD,
+                Abilities::POISONTOUCH,
+            ],
+            PokemonName::MUKALOLA => &[
+                Abilities::POISONTOUCH,
+                Abilities::GLUTTONY,
+                Abilities::POWEROFALCHEMY,
+            ],
+            PokemonName::SHELLDER => &[
+                Abilities::SHELLARMOR,
+                Abilities::SKILLLINK,
+                Abilities::OVERCOAT,
+            ],
+            PokemonName::CLOYSTER => &[
+                Abilities::SHELLARMOR,
+                Abilities::SKILLLINK,
+                Abilities::OVERCOAT,
+            ],
+            PokemonName::GASTLY => &[Abilities::LEVITATE],
+            PokemonName::HAUNTER => &[Abilities::LEVITATE],
+            PokemonName::GENGAR => &[Abilities::CURSEDBODY],
+            PokemonName::GENGARMEGA => &[Abilities::SHADOWTAG],
+            PokemonName::GENGARGMAX => &[Abilities::CURSEDBODY],
+            PokemonName::ONIX => &[Abilities::ROCKHEAD, Abilities::STURDY, Abilities::WEAKARMOR],
+            PokemonName::DROWZEE => &[
+                Abilities::INSOMNIA,
+                Abilities::FOREWARN,
+                Abilities::INNERFOCUS,
+            ],
+            PokemonName::HYPNO => &[
+                Abilities::INSOMNIA,
+                Abilities::FOREWARN,
+                Abilities::INNERFOCUS,
+            ],
+            PokemonName::KRABBY => &[
+                Abilities::HYPERCUTTER,
+                Abilities::SHELLARMOR,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::KINGLER => &[
+                Abilities::HYPERCUTTER,
+                Abilities::SHELLARMOR,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::KINGLERGMAX => &[
+                Abilities::HYPERCUTTER,
+                Abilities::SHELLARMOR,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::VOLTORB => &[
+                Abilities::SOUNDPROOF,
+                Abilities::STATIC,
+                Abilities::AFTERMATH,
+            ],
+            PokemonName::VOLTORBHISUI => &[
+                Abilities::SOUNDPROOF,
+                Abilities::STATIC,
+                Abilities::AFTERMATH,
+            ],
+            PokemonName::ELECTRODE => &[
+                Abilities::SOUNDPROOF,
+                Abilities::STATIC,
+                Abilities::AFTERMATH,
+            ],
+            PokemonName::ELECTRODEHISUI => &[
+                Abilities::SOUNDPROOF,
+                Abilities::STATIC,
+                Abilities::AFTERMATH,
+            ],
+            PokemonName::EXEGGCUTE => &[Abilities::CHLOROPHYLL, Abilities::HARVEST],
+            PokemonName::EXEGGUTOR => &[Abilities::CHLOROPHYLL, Abilities::HARVEST],
+            PokemonName::EXEGGUTORALOLA => &[Abilities::FRISK, Abilities::HARVEST],
+            PokemonName::CUBONE => &[
+                Abilities::ROCKHEAD,
+                Abilities::LIGHTNINGROD,
+                Abilities::BATTLEARMOR,
+            ],
+            PokemonName::MAROWAK => &[
+                Abilities::ROCKHEAD,
+                Abilities::LIGHTNINGROD,
+                Abilities::BATTLEARMOR,
+            ],
+            PokemonName::MAROWAKALOLA => &[
+                Abilities::CURSEDBODY,
+                Abilities::LIGHTNINGROD,
+                Abilities::ROCKHEAD,
+            ],
+            PokemonName::MAROWAKALOLATOTEM => &[Abilities::ROCKHEAD],
+            PokemonName::HITMONLEE => {
+                &[Abilities::LIMBER, Abilities::RECKLESS, Abilities::UNBURDEN]
+            }
+            PokemonName::HITMONCHAN => &[
+                Abilities::KEENEYE,
+                Abilities::IRONFIST,
+                Abilities::INNERFOCUS,
+            ],
+            PokemonName::LICKITUNG => &[
+                Abilities::OWNTEMPO,
+                Abilities::OBLIVIOUS,
+                Abilities::CLOUDNINE,
+            ],
+            PokemonName::KOFFING => &[
+                Abilities::LEVITATE,
+                Abilities::NEUTRALIZINGGAS,
+                Abilities::STENCH,
+            ],
+            PokemonName::WEEZING => &[
+                Abilities::LEVITATE,
+                Abilities::NEUTRALIZINGGAS,
+                Abilities::STENCH,
+            ],
+            PokemonName::WEEZINGGALAR => &[
+                Abilities::LEVITATE,
+                Abilities::NEUTRALIZINGGAS,
+                Abilities::MISTYSURGE,
+            ],
+            PokemonName::RHYHORN => &[
+                Abilities::LIGHTNINGROD,
+                Abilities::ROCKHEAD,
+                Abilities::RECKLESS,
+            ],
+            PokemonName::RHYDON => &[
+                Abilities::LIGHTNINGROD,
+                Abilities::ROCKHEAD,
+                Abilities::RECKLESS,
+            ],
+            PokemonName::CHANSEY => &[
+                Abilities::NATURALCURE,
+                Abilities::SERENEGRACE,
+                Abilities::HEALER,
+            ],
+            PokemonName::TANGELA => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::LEAFGUARD,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::KANGASKHAN => &[
+                Abilities::EARLYBIRD,
+                Abilities::SCRAPPY,
+                Abilities::INNERFOCUS,
+            ],
+            PokemonName::KANGASKHANMEGA => &[Abilities::PARENTALBOND],
+            PokemonName::HORSEA => &[Abilities::SWIFTSWIM, Abilities::SNIPER, Abilities::DAMP],
+            PokemonName::SEADRA => &[Abilities::POISONPOINT, Abilities::SNIPER, Abilities::DAMP],
+            PokemonName::GOLDEEN => &[
+                Abilities::SWIFTSWIM,
+                Abilities::WATERVEIL,
+                Abilities::LIGHTNINGROD,
+            ],
+            PokemonName::SEAKING => &[
+                Abilities::SWIFTSWIM,
+                Abilities::WATERVEIL,
+                Abilities::LIGHTNINGROD,
+            ],
+            PokemonName::STARYU => &[
+                Abilities::ILLUMINATE,
+                Abilities::NATURALCURE,
+                Abilities::ANALYTIC,
+            ],
+            PokemonName::STARMIE => &[
+                Abilities::ILLUMINATE,
+                Abilities::NATURALCURE,
+                Abilities::ANALYTIC,
+            ],
+            PokemonName::MRMIME => &[
+                Abilities::SOUNDPROOF,
+                Abilities::FILTER,
+                Abilities::TECHNICIAN,
+            ],
+            PokemonName::MRMIMEGALAR => &[
+                Abilities::VITALSPIRIT,
+                Abilities::SCREENCLEANER,
+                Abilities::ICEBODY,
+            ],
+            PokemonName::SCYTHER => &[
+                Abilities::SWARM,
+                Abilities::TECHNICIAN,
+                Abilities::STEADFAST,
+            ],
+            PokemonName::JYNX => &[
+                Abilities::OBLIVIOUS,
+                Abilities::FOREWARN,
+                Abilities::DRYSKIN,
+            ],
+            PokemonName::ELECTABUZZ => &[Abilities::STATIC, Abilities::VITALSPIRIT],
+            PokemonName::MAGMAR => &[Abilities::FLAMEBODY, Abilities::VITALSPIRIT],
+            PokemonName::PINSIR => &[
+                Abilities::HYPERCUTTER,
+                Abilities::MOLDBREAKER,
+                Abilities::MOXIE,
+            ],
+            PokemonName::PINSIRMEGA => &[Abilities::AERILATE],
+            PokemonName::TAUROS => &[
+                Abilities::INTIMIDATE,
+                Abilities::ANGERPOINT,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::TAUROSPALDEACOMBAT => &[
+                Abilities::INTIMIDATE,
+                Abilities::ANGERPOINT,
+                Abilities::CUDCHEW,
+            ],
+            PokemonName::TAUROSPALDEABLAZE => &[
+                Abilities::INTIMIDATE,
+                Abilities::ANGERPOINT,
+                Abilities::CUDCHEW,
+            ],
+            PokemonName::TAUROSPALDEAAQUA => &[
+                Abilities::INTIMIDATE,
+                Abilities::ANGERPOINT,
+                Abilities::CUDCHEW,
+            ],
+            PokemonName::MAGIKARP => &[Abilities::SWIFTSWIM, Abilities::RATTLED],
+            PokemonName::GYARADOS => &[Abilities::INTIMIDATE, Abilities::MOXIE],
+            PokemonName::GYARADOSMEGA => &[Abilities::MOLDBREAKER],
+            PokemonName::LAPRAS => &[
+                Abilities::WATERABSORB,
+                Abilities::SHELLARMOR,
+                Abilities::HYDRATION,
+            ],
+            PokemonName::LAPRASGMAX => &[
+                Abilities::WATERABSORB,
+                Abilities::SHELLARMOR,
+                Abilities::HYDRATION,
+            ],
+            PokemonName::DITTO => &[Abilities::LIMBER, Abilities::IMPOSTER],
+            PokemonName::EEVEE => &[
+                Abilities::RUNAWAY,
+                Abilities::ADAPTABILITY,
+                Abilities::ANTICIPATION,
+            ],
+            PokemonName::EEVEESTARTER => &[
+                Abilities::RUNAWAY,
+                Abilities::ADAPTABILITY,
+                Abilities::ANTICIPATION,
+            ],
+            PokemonName::EEVEEGMAX => &[
+                Abilities::RUNAWAY,
+                Abilities::ADAPTABILITY,
+                Abilities::ANTICIPATION,
+            ],
+            PokemonName::VAPOREON => &[Abilities::WATERABSORB, Abilities::HYDRATION],
+            PokemonName::JOLTEON => &[Abilities::VOLTABSORB, Abilities::QUICKFEET],
+            PokemonName::FLAREON => &[Abilities::FLASHFIRE, Abilities::GUTS],
+            PokemonName::PORYGON => &[Abilities::TRACE, Abilities::DOWNLOAD, Abilities::ANALYTIC],
+            PokemonName::OMANYTE => &[
+                Abilities::SWIFTSWIM,
+                Abilities::SHELLARMOR,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::OMASTAR => &[
+                Abilities::SWIFTSWIM,
+                Abilities::SHELLARMOR,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::KABUTO => &[
+                Abilities::SWIFTSWIM,
+                Abilities::BATTLEARMOR,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::KABUTOPS => &[
+                Abilities::SWIFTSWIM,
+                Abilities::BATTLEARMOR,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::AERODACTYL => {
+                &[Abilities::ROCKHEAD, Abilities::PRESSURE, Abilities::UNNERVE]
+            }
+            PokemonName::AERODACTYLMEGA => &[Abilities::TOUGHCLAWS],
+            PokemonName::SNORLAX => &[
+                Abilities::IMMUNITY,
+                Abilities::THICKFAT,
+                Abilities::GLUTTONY,
+            ],
+            PokemonName::SNORLAXGMAX => &[
+                Abilities::IMMUNITY,
+                Abilities::THICKFAT,
+                Abilities::GLUTTONY,
+            ],
+            PokemonName::ARTICUNO => &[Abilities::PRESSURE, Abilities::SNOWCLOAK],
+            PokemonName::ARTICUNOGALAR => &[Abilities::COMPETITIVE],
+            PokemonName::ZAPDOS => &[Abilities::PRESSURE, Abilities::STATIC],
+            PokemonName::ZAPDOSGALAR => &[Abilities::DEFIANT],
+            PokemonName::MOLTRES => &[Abilities::PRESSURE, Abilities::FLAMEBODY],
+            PokemonName::MOLTRESGALAR => &[Abilities::BERSERK],
+            PokemonName::DRATINI => &[Abilities::SHEDSKIN, Abilities::MARVELSCALE],
+            PokemonName::DRAGONAIR => &[Abilities::SHEDSKIN, Abilities::MARVELSCALE],
+            PokemonName::DRAGONITE => &[Abilities::INNERFOCUS, Abilities::MULTISCALE],
+            PokemonName::MEWTWO => &[Abilities::PRESSURE, Abilities::UNNERVE],
+            PokemonName::MEWTWOMEGAX => &[Abilities::STEADFAST],
+            PokemonName::MEWTWOMEGAY => &[Abilities::INSOMNIA],
+            PokemonName::MEW => &[Abilities::SYNCHRONIZE],
+            PokemonName::CHIKORITA => &[Abilities::OVERGROW, Abilities::LEAFGUARD],
+            PokemonName::BAYLEEF => &[Abilities::OVERGROW, Abilities::LEAFGUARD],
+            PokemonName::MEGANIUM => &[Abilities::OVERGROW, Abilities::LEAFGUARD],
+            PokemonName::CYNDAQUIL => &[Abilities::BLAZE, Abilities::FLASHFIRE],
+            PokemonName::QUILAVA => &[Abilities::BLAZE, Abilities::FLASHFIRE],
+            PokemonName::TYPHLOSION => &[Abilities::BLAZE, Abilities::FLASHFIRE],
+            PokemonName::TYPHLOSIONHISUI => &[Abilities::BLAZE, Abilities::FRISK],
+            PokemonName::TOTODILE => &[Abilities::TORRENT, Abilities::SHEERFORCE],
+            PokemonName::CROCONAW => &[Abilities::TORRENT, Abilities::SHEERFORCE],
+            PokemonName::FERALIGATR => &[Abilities::TORRENT, Abilities::SHEERFORCE],
+            PokemonName::SENTRET => &[Abilities::RUNAWAY, Abilities::KEENEYE, Abilities::FRISK],
+            PokemonName::FURRET => &[Abilities::RUNAWAY, Abilities::KEENEYE, Abilities::FRISK],
+            PokemonName::HOOTHOOT => &[
+                Abilities::INSOMNIA,
+                Abilities::KEENEYE,
+                Abilities::TINTEDLENS,
+            ],
+            PokemonName::NOCTOWL => &[
+                Abilities::INSOMNIA,
+                Abilities::KEENEYE,
+                Abilities::TINTEDLENS,
+            ],
+            PokemonName::LEDYBA => &[Abilities::SWARM, Abilities::EARLYBIRD, Abilities::RATTLED],
+            PokemonName::LEDIAN => &[Abilities::SWARM, Abilities::EARLYBIRD, Abilities::IRONFIST],
+            PokemonName::SPINARAK => &[Abilities::SWARM, Abilities::INSOMNIA, Abilities::SNIPER],
+            PokemonName::ARIADOS => &[Abilities::SWARM, Abilities::INSOMNIA, Abilities::SNIPER],
+            PokemonName::CROBAT => &[Abilities::INNERFOCUS, Abilities::INFILTRATOR],
+            PokemonName::CHINCHOU => &[
+                Abilities::VOLTABSORB,
+                Abilities::ILLUMINATE,
+                Abilities::WATERABSORB,
+            ],
+            PokemonName::LANTURN => &[
+                Abilities::VOLTABSORB,
+                Abilities::ILLUMINATE,
+                Abilities::WATERABSORB,
+            ],
+            PokemonName::PICHU => &[Abilities::STATIC, Abilities::LIGHTNINGROD],
+            PokemonName::PICHUSPIKYEARED => &[Abilities::STATIC],
+            PokemonName::CLEFFA => &[
+                Abilities::CUTECHARM,
+                Abilities::MAGICGUARD,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::IGGLYBUFF => &[
+                Abilities::CUTECHARM,
+                Abilities::COMPETITIVE,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::TOGEPI => &[
+                Abilities::HUSTLE,
+                Abilities::SERENEGRACE,
+                Abilities::SUPERLUCK,
+            ],
+            PokemonName::TOGETIC => &[
+                Abilities::HUSTLE,
+                Abilities::SERENEGRACE,
+                Abilities::SUPERLUCK,
+            ],
+            PokemonName::NATU => &[
+                Abilities::SYNCHRONIZE,
+                Abilities::EARLYBIRD,
+                Abilities::MAGICBOUNCE,
+            ],
+            PokemonName::XATU => &[
+                Abilities::SYNCHRONIZE,
+                Abilities::EARLYBIRD,
+                Abilities::MAGICBOUNCE,
+            ],
+            PokemonName::MAREEP => &[Abilities::STATIC, Abilities::PLUS],
+            PokemonName::FLAAFFY => &[Abilities::STATIC, Abilities::PLUS],
+            PokemonName::AMPHAROS => &[Abilities::STATIC, Abilities::PLUS],
+            PokemonName::AMPHAROSMEGA => &[Abilities::MOLDBREAKER],
+            PokemonName::BELLOSSOM => &[Abilities::CHLOROPHYLL, Abilities::HEALER],
+            PokemonName::MARILL => &[
+                Abilities::THICKFAT,
+                Abilities::HUGEPOWER,
+                Abilities::SAPSIPPER,
+            ],
+            PokemonName::AZUMARILL => &[
+                Abilities::THICKFAT,
+                Abilities::HUGEPOWER,
+                Abilities::SAPSIPPER,
+            ],
+            PokemonName::SUDOWOODO => &[Abilities::STURDY, Abilities::ROCKHEAD, Abilities::RATTLED],
+            PokemonName::POLITOED => &[Abilities::WATERABSORB, Abilities::DAMP, Abilities::DRIZZLE],
+            PokemonName::HOPPIP => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::LEAFGUARD,
+                Abilities::INFILTRATOR,
+            ],
+            PokemonName::SKIPLOOM => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::LEAFGUARD,
+                Abilities::INFILTRATOR,
+            ],
+            PokemonName::JUMPLUFF => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::LEAFGUARD,
+                Abilities::INFILTRATOR,
+            ],
+            PokemonName::AIPOM => &[Abilities::RUNAWAY, Abilities::PICKUP, Abilities::SKILLLINK],
+            PokemonName::SUNKERN => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::SOLARPOWER,
+                Abilities::EARLYBIRD,
+            ],
+            PokemonName::SUNFLORA => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::SOLARPOWER,
+                Abilities::EARLYBIRD,
+            ],
+            PokemonName::YANMA => &[
+                Abilities::SPEEDBOOST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRISK,
+            ],
+            PokemonName::WOOPER => &[Abilities::DAMP, Abilities::WATERABSORB, Abilities::UNAWARE],
+            PokemonName::WOOPERPALDEA => &[
+                Abilities::POISONPOINT,
+                Abilities::WATERABSORB,
+                Abilities::UNAWARE,
+            ],
+            PokemonName::QUAGSIRE => &[Abilities::DAMP, Abilities::WATERABSORB, Abilities::UNAWARE],
+            PokemonName::ESPEON => &[Abilities::SYNCHRONIZE, Abilities::MAGICBOUNCE],
+            PokemonName::UMBREON => &[Abilities::SYNCHRONIZE, Abilities::INNERFOCUS],
+            PokemonName::MURKROW => &[
+                Abilities::INSOMNIA,
+                Abilities::SUPERLUCK,
+                Abilities::PRANKSTER,
+            ],
+            PokemonName::SLOWKING => &[
+                Abilities::OBLIVIOUS,
+                Abilities::OWNTEMPO,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::SLOWKINGGALAR => &[
+                Abilities::CURIOUSMEDICINE,
+                Abilities::OWNTEMPO,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::MISDREAVUS => &[Abilities::LEVITATE],
+            PokemonName::UNOWN => &[Abilities::LEVITATE],
+            PokemonName::UNOWNB => &[Abilities::LEVITATE],
+            PokemonName::UNOWNC => &[Abilities::LEVITATE],
+            PokemonName::UNOWND => &[Abilities::LEVITATE],
+            PokemonName::UNOWNE => &[Abilities::LEVITATE],
+            PokemonName::UNOWNF => &[Abilities::LEVITATE],
+            PokemonName::UNOWNG => &[Abilities::LEVITATE],
+            PokemonName::UNOWNH => &[Abilities::LEVITATE],
+            PokemonName::UNOWNI => &[Abilities::LEVITATE],
+            PokemonName::UNOWNJ => &[Abilities::LEVITATE],
+            PokemonName::UNOWNK => &[Abilities::LEVITATE],
+            PokemonName::UNOWNL => &[Abilities::LEVITATE],
+            PokemonName::UNOWNM => &[Abilities::LEVITATE],
+            PokemonName::UNOWNN => &[Abilities::LEVITATE],
+            PokemonName::UNOWNO => &[Abilities::LEVITATE],
+            PokemonName::UNOWNP => &[Abilities::LEVITATE],
+            PokemonName::UNOWNQ => &[Abilities::LEVITATE],
+            PokemonName::UNOWNR => &[Abilities::LEVITATE],
+            PokemonName::UNOWNS => &[Abilities::LEVITATE],
+            PokemonName::UNOWNT => &[Abilities::LEVITATE],
+            PokemonName::UNOWNU => &[Abilities::LEVITATE],
+            PokemonName::UNOWNV => &[Abilities::LEVITATE],
+            PokemonName::UNOWNW => &[Abilities::LEVITATE],
+            PokemonName::UNOWNX => &[Abilities::LEVITATE],
+            PokemonName::UNOWNY => &[Abilities::LEVITATE],
+            PokemonName::UNOWNZ => &[Abilities::LEVITATE],
+            PokemonName::UNOWNEXCLAMATION => &[Abilities::LEVITATE],
+            PokemonName::UNOWNQUESTION => &[Abilities::LEVITATE],
+            PokemonName::WOBBUFFET => &[Abilities::SHADOWTAG, Abilities::TELEPATHY],
+            PokemonName::GIRAFARIG => &[
+                Abilities::INNERFOCUS,
+                Abilities::EARLYBIRD,
+                Abilities::SAPSIPPER,
+            ],
+            PokemonName::PINECO => &[Abilities::STURDY, Abilities::OVERCOAT],
+            PokemonName::FORRETRESS => &[Abilities::STURDY, Abilities::OVERCOAT],
+            PokemonName::DUNSPARCE => &[
+                Abilities::SERENEGRACE,
+                Abilities::RUNAWAY,
+                Abilities::RATTLED,
+            ],
+            PokemonName::GLIGAR => &[
+                Abilities::HYPERCUTTER,
+                Abilities::SANDVEIL,
+                Abilities::IMMUNITY,
+            ],
+            PokemonName::STEELIX => &[
+                Abilities::ROCKHEAD,
+                Abilities::STURDY,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::STEELIXMEGA => &[Abilities::SANDFORCE],
+            PokemonName::SNUBBULL => &[
+                Abilities::INTIMIDATE,
+                Abilities::RUNAWAY,
+                Abilities::RATTLED,
+            ],
+            PokemonName::GRANBULL => &[
+                Abilities::INTIMIDATE,
+                Abilities::QUICKFEET,
+                Abilities::RATTLED,
+            ],
+            PokemonName::QWILFISH => &[
+                Abilities::POISONPOINT,
+                Abilities::SWIFTSWIM,
+                Abilities::INTIMIDATE,
+            ],
+            PokemonName::QWILFISHHISUI => &[
+                Abilities::POISONPOINT,
+                Abilities::SWIFTSWIM,
+                Abilities::INTIMIDATE,
+            ],
+            PokemonName::SCIZOR => &[
+                Abilities::SWARM,
+                Abilities::TECHNICIAN,
+                Abilities::LIGHTMETAL,
+            ],
+            PokemonName::SCIZORMEGA => &[Abilities::TECHNICIAN],
+            PokemonName::SHUCKLE => &[Abilities::STURDY, Abilities::GLUTTONY, Abilities::CONTRARY],
+            PokemonName::HERACROSS => &[Abilities::SWARM, Abilities::GUTS, Abilities::MOXIE],
+            PokemonName::HERACROSSMEGA => &[Abilities::SKILLLINK],
+            PokemonName::SNEASEL => &[
+                Abilities::INNERFOCUS,
+                Abilities::KEENEYE,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::SNEASELHISUI => &[
+                Abilities::INNERFOCUS,
+                Abilities::KEENEYE,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::TEDDIURSA => &[
+                Abilities::PICKUP,
+                Abilities::QUICKFEET,
+                Abilities::HONEYGATHER,
+            ],
+            PokemonName::URSARING => &[Abilities::GUTS, Abilities::QUICKFEET, Abilities::UNNERVE],
+            PokemonName::SLUGMA => &[
+                Abilities::MAGMAARMOR,
+                Abilities::FLAMEBODY,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::MAGCARGO => &[
+                Abilities::MAGMAARMOR,
+                Abilities::FLAMEBODY,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::SWINUB => &[
+                Abilities::OBLIVIOUS,
+                Abilities::SNOWCLOAK,
+                Abilities::THICKFAT,
+            ],
+            PokemonName::PILOSWINE => &[
+                Abilities::OBLIVIOUS,
+                Abilities::SNOWCLOAK,
+                Abilities::THICKFAT,
+            ],
+            PokemonName::CORSOLA => &[
+                Abilities::HUSTLE,
+                Abilities::NATURALCURE,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::CORSOLAGALAR => &[Abilities::WEAKARMOR, Abilities::CURSEDBODY],
+            PokemonName::REMORAID => &[Abilities::HUSTLE, Abilities::SNIPER, Abilities::MOODY],
+            PokemonName::OCTILLERY => {
+                &[Abilities::SUCTIONCUPS, Abilities::SNIPER, Abilities::MOODY]
+            }
+            PokemonName::DELIBIRD => &[
+                Abilities::VITALSPIRIT,
+                Abilities::HUSTLE,
+                Abilities::INSOMNIA,
+            ],
+            PokemonName::MANTINE => &[
+                Abilities::SWIFTSWIM,
+                Abilities::WATERABSORB,
+                Abilities::WATERVEIL,
+            ],
+            PokemonName::SKARMORY => &[Abilities::KEENEYE, Abilities::STURDY, Abilities::WEAKARMOR],
+            PokemonName::HOUNDOUR => &[
+                Abilities::EARLYBIRD,
+                Abilities::FLASHFIRE,
+                Abilities::UNNERVE,
+            ],
+            PokemonName::HOUNDOOM => &[
+                Abilities::EARLYBIRD,
+                Abilities::FLASHFIRE,
+                Abilities::UNNERVE,
+            ],
+            PokemonName::HOUNDOOMMEGA => &[Abilities::SOLARPOWER],
+            PokemonName::KINGDRA => &[Abilities::SWIFTSWIM, Abilities::SNIPER, Abilities::DAMP],
+            PokemonName::PHANPY => &[Abilities::PICKUP, Abilities::SANDVEIL],
+            PokemonName::DONPHAN => &[Abilities::STURDY, Abilities::SANDVEIL],
+            PokemonName::PORYGON2 => &[Abilities::TRACE, Abilities::DOWNLOAD, Abilities::ANALYTIC],
+            PokemonName::STANTLER => &[
+                Abilities::INTIMIDATE,
+                Abilities::FRISK,
+                Abilities::SAPSIPPER,
+            ],
+            PokemonName::SMEARGLE => {
+                &[Abilities::OWNTEMPO, Abilities::TECHNICIAN, Abilities::MOODY]
+            }
+            PokemonName::TYROGUE => &[
+                Abilities::GUTS,
+                Abilities::STEADFAST,
+                Abilities::VITALSPIRIT,
+            ],
+            PokemonName::HITMONTOP => &[
+                Abilities::INTIMIDATE,
+                Abilities::TECHNICIAN,
+                Abilities::STEADFAST,
+            ],
+            PokemonName::SMOOCHUM => &[
+                Abilities::OBLIVIOUS,
+                Abilities::FOREWARN,
+                Abilities::HYDRATION,
+            ],
+            PokemonName::ELEKID => &[Abilities::STATIC, Abilities::VITALSPIRIT],
+            PokemonName::MAGBY => &[Abilities::FLAMEBODY, Abilities::VITALSPIRIT],
+            PokemonName::MILTANK => &[
+                Abilities::THICKFAT,
+                Abilities::SCRAPPY,
+                Abilities::SAPSIPPER,
+            ],
+            PokemonName::BLISSEY => &[
+                Abilities::NATURALCURE,
+                Abilities::SERENEGRACE,
+                Abilities::HEALER,
+            ],
+            PokemonName::RAIKOU => &[Abilities::PRESSURE, Abilities::INNERFOCUS],
+            PokemonName::ENTEI => &[Abilities::PRESSURE, Abilities::INNERFOCUS],
+            PokemonName::SUICUNE => &[Abilities::PRESSURE, Abilities::INNERFOCUS],
+            PokemonName::LARVITAR => &[Abilities::GUTS, Abilities::SANDVEIL],
+            PokemonName::PUPITAR => &[Abilities::SHEDSKIN],
+            PokemonName::TYRANITAR => &[Abilities::SANDSTREAM, Abilities::UNNERVE],
+            PokemonName::TYRANITARMEGA => &[Abilities::SANDSTREAM],
+            PokemonName::LUGIA => &[Abilities::PRESSURE, Abilities::MULTISCALE],
+            PokemonName::HOOH => &[Abilities::PRESSURE, Abilities::REGENERATOR],
+            PokemonName::CELEBI => &[Abilities::NATURALCURE],
+            PokemonName::TREECKO => &[Abilities::OVERGROW, Abilities::UNBURDEN],
+            PokemonName::GROVYLE => &[Abilities::OVERGROW, Abilities::UNBURDEN],
+            PokemonName::SCEPTILE => &[Abilities::OVERGROW, Abilities::UNBURDEN],
+            PokemonName::SCEPTILEMEGA => &[Abilities::LIGHTNINGROD],
+            PokemonName::TORCHIC => &[Abilities::BLAZE, Abilities::SPEEDBOOST],
+            PokemonName::COMBUSKEN => &[Abilities::BLAZE, Abilities::SPEEDBOOST],
+            PokemonName::BLAZIKEN => &[Abilities::BLAZE, Abilities::SPEEDBOOST],
+            PokemonName::BLAZIKENMEGA => &[Abilities::SPEEDBOOST],
+            PokemonName::MUDKIP => &[Abilities::TORRENT, Abilities::DAMP],
+            PokemonName::MARSHTOMP => &[Abilities::TORRENT, Abilities::DAMP],
+            PokemonName::SWAMPERT => &[Abilities::TORRENT, Abilities::DAMP],
+            PokemonName::SWAMPERTMEGA => &[Abilities::SWIFTSWIM],
+            PokemonName::POOCHYENA => {
+                &[Abilities::RUNAWAY, Abilities::QUICKFEET, Abilities::RATTLED]
+            }
+            PokemonName::MIGHTYENA => &[
+                Abilities::INTIMIDATE,
+                Abilities::QUICKFEET,
+                Abilities::MOXIE,
+            ],
+            PokemonName::ZIGZAGOON => {
+                &[Abilities::PICKUP, Abilities::GLUTTONY, Abilities::QUICKFEET]
+            }
+            PokemonName::ZIGZAGOONGALAR => {
+                &[Abilities::PICKUP, Abilities::GLUTTONY, Abilities::QUICKFEET]
+            }
+            PokemonName::LINOONE => &[Abilities::PICKUP, Abilities::GLUTTONY, Abilities::QUICKFEET],
+            PokemonName::LINOONEGALAR => {
+                &[Abilities::PICKUP, Abilities::GLUTTONY, Abilities::QUICKFEET]
+            }
+            PokemonName::WURMPLE => &[Abilities::SHIELDDUST, Abilities::RUNAWAY],
+            PokemonName::SILCOON => &[Abilities::SHEDSKIN],
+            PokemonName::BEAUTIFLY => &[Abilities::SWARM, Abilities::RIVALRY],
+            PokemonName::CASCOON => &[Abilities::SHEDSKIN],
+            PokemonName::DUSTOX => &[Abilities::SHIELDDUST, Abilities::COMPOUNDEYES],
+            PokemonName::LOTAD => &[
+                Abilities::SWIFTSWIM,
+                Abilities::RAINDISH,
+                Abilities::OWNTEMPO,
+            ],
+            PokemonName::LOMBRE => &[
+                Abilities::SWIFTSWIM,
+                Abilities::RAINDISH,
+                Abilities::OWNTEMPO,
+            ],
+            PokemonName::LUDICOLO => &[
+                Abilities::SWIFTSWIM,
+                Abilities::RAINDISH,
+                Abilities::OWNTEMPO,
+            ],
+            PokemonName::SEEDOT => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::EARLYBIRD,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::NUZLEAF => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::EARLYBIRD,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::SHIFTRY => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::WINDRIDER,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::TAILLOW => &[Abilities::GUTS, Abilities::SCRAPPY],
+            PokemonName::SWELLOW => &[Abilities::GUTS, Abilities::SCRAPPY],
+            PokemonName::WINGULL => &[
+                Abilities::KEENEYE,
+                Abilities::HYDRATION,
+                Abilities::RAINDISH,
+            ],
+            PokemonName::PELIPPER => &[Abilities::KEENEYE, Abilities::DRIZZLE, Abilities::RAINDISH],
+            PokemonName::RALTS => &[
+                Abilities::SYNCHRONIZE,
+                Abilities::TRACE,
+                Abilities::TELEPATHY,
+            ],
+            PokemonName::KIRLIA => &[
+                Abilities::SYNCHRONIZE,
+                Abilities::TRACE,
+                Abilities::TELEPATHY,
+            ],
+            PokemonName::GARDEVOIR => &[
+                Abilities::SYNCHRONIZE,
+                Abilities::TRACE,
+                Abilities::TELEPATHY,
+            ],
+            PokemonName::GARDEVOIRMEGA => &[Abilities::PIXILATE],
+            PokemonName::SURSKIT => &[Abilities::SWIFTSWIM, Abilities::RAINDISH],
+            PokemonName::MASQUERAIN => &[Abilities::INTIMIDATE, Abilities::UNNERVE],
+            PokemonName::SHROOMISH => &[
+                Abilities::EFFECTSPORE,
+                Abilities::POISONHEAL,
+                Abilities::QUICKFEET,
+            ],
+            PokemonName::BRELOOM => &[
+                Abilities::EFFECTSPORE,
+                Abilities::POISONHEAL,
+                Abilities::TECHNICIAN,
+            ],
+            PokemonName::SLAKOTH => &[Abilities::TRUANT],
+            PokemonName::VIGOROTH => &[Abilities::VITALSPIRIT],
+            PokemonName::SLAKING => &[Abilities::TRUANT],
+            PokemonName::NINCADA => &[Abilities::COMPOUNDEYES, Abilities::RUNAWAY],
+            PokemonName::NINJASK => &[Abilities::SPEEDBOOST, Abilities::INFILTRATOR],
+            PokemonName::SHEDINJA => &[Abilities::WONDERGUARD],
+            PokemonName::WHISMUR => &[Abilities::SOUNDPROOF, Abilities::RATTLED],
+            PokemonName::LOUDRED => &[Abilities::SOUNDPROOF, Abilities::SCRAPPY],
+            PokemonName::EXPLOUD => &[Abilities::SOUNDPROOF, Abilities::SCRAPPY],
+            PokemonName::MAKUHITA => &[Abilities::THICKFAT, Abilities::GUTS, Abilities::SHEERFORCE],
+            PokemonName::HARIYAMA => &[Abilities::THICKFAT, Abilities::GUTS, Abilities::SHEERFORCE],
+            PokemonName::AZURILL => &[
+                Abilities::THICKFAT,
+                Abilities::HUGEPOWER,
+                Abilities::SAPSIPPER,
+            ],
+            PokemonName::NOSEPASS => &[
+                Abilities::STURDY,
+                Abilities::MAGNETPULL,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::SKITTY => &[
+                Abilities::CUTECHARM,
+                Abilities::NORMALIZE,
+                Abilities::WONDERSKIN,
+            ],
+            PokemonName::DELCATTY => &[
+                Abilities::CUTECHARM,
+                Abilities::NORMALIZE,
+                Abilities::WONDERSKIN,
+            ],
+            PokemonName::SABLEYE => &[Abilities::KEENEYE, Abilities::STALL, Abilities::PRANKSTER],
+            PokemonName::SABLEYEMEGA => &[Abilities::MAGICBOUNCE],
+            PokemonName::MAWILE => &[
+                Abilities::HYPERCUTTER,
+                Abilities::INTIMIDATE,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::MAWILEMEGA => &[Abilities::HUGEPOWER],
+            PokemonName::ARON => &[
+                Abilities::STURDY,
+                Abilities::ROCKHEAD,
+                Abilities::HEAVYMETAL,
+            ],
+            PokemonName::LAIRON => &[
+                Abilities::STURDY,
+                Abilities::ROCKHEAD,
+                Abilities::HEAVYMETAL,
+            ],
+            PokemonName::AGGRON => &[
+                Abilities::STURDY,
+                Abilities::ROCKHEAD,
+                Abilities::HEAVYMETAL,
+            ],
+            PokemonName::AGGRONMEGA => &[Abilities::FILTER],
+            PokemonName::MEDITITE => &[Abilities::PUREPOWER, Abilities::TELEPATHY],
+            PokemonName::MEDICHAM => &[Abilities::PUREPOWER, Abilities::TELEPATHY],
+            PokemonName::MEDICHAMMEGA => &[Abilities::PUREPOWER],
+            PokemonName::ELECTRIKE => {
+                &[Abilities::STATIC, Abilities::LIGHTNINGROD, Abilities::MINUS]
+            }
+            PokemonName::MANECTRIC => {
+                &[Abilities::STATIC, Abilities::LIGHTNINGROD, Abilities::MINUS]
+            }
+            PokemonName::MANECTRICMEGA => &[Abilities::INTIMIDATE],
+            PokemonName::PLUSLE => &[Abilities::PLUS, Abilities::LIGHTNINGROD],
+            PokemonName::MINUN => &[Abilities::MINUS, Abilities::VOLTABSORB],
+            PokemonName::VOLBEAT => &[
+                Abilities::ILLUMINATE,
+                Abilities::SWARM,
+                Abilities::PRANKSTER,
+            ],
+            PokemonName::ILLUMISE => &[
+                Abilities::OBLIVIOUS,
+                Abilities::TINTEDLENS,
+                Abilities::PRANKSTER,
+            ],
+            PokemonName::ROSELIA => &[
+                Abilities::NATURALCURE,
+                Abilities::POISONPOINT,
+                Abilities::LEAFGUARD,
+            ],
+            PokemonName::GULPIN => &[
+                Abilities::LIQUIDOOZE,
+                Abilities::STICKYHOLD,
+                Abilities::GLUTTONY,
+            ],
+            PokemonName::SWALOT => &[
+                Abilities::LIQUIDOOZE,
+                Abilities::STICKYHOLD,
+                Abilities::GLUTTONY,
+            ],
+            PokemonName::CARVANHA => &[Abilities::ROUGHSKIN, Abilities::SPEEDBOOST],
+            PokemonName::SHARPEDO => &[Abilities::ROUGHSKIN, Abilities::SPEEDBOOST],
+            PokemonName::SHARPEDOMEGA => &[Abilities::STRONGJAW],
+            PokemonName::WAILMER => &[
+                Abilities::WATERVEIL,
+                Abilities::OBLIVIOUS,
+                Abilities::PRESSURE,
+            ],
+            PokemonName::WAILORD => &[
+                Abilities::WATERVEIL,
+                Abilities::OBLIVIOUS,
+                Abilities::PRESSURE,
+            ],
+            PokemonName::NUMEL => &[Abilities::OBLIVIOUS, Abilities::SIMPLE, Abilities::OWNTEMPO],
+            PokemonName::CAMERUPT => &[
+                Abilities::MAGMAARMOR,
+                Abilities::SOLIDROCK,
+                Abilities::ANGERPOINT,
+            ],
+            PokemonName::CAMERUPTMEGA => &[Abilities::SHEERFORCE],
+            PokemonName::TORKOAL => &[
+                Abilities::WHITESMOKE,
+                Abilities::DROUGHT,
+                Abilities::SHELLARMOR,
+            ],
+            PokemonName::SPOINK => &[
+                Abilities::THICKFAT,
+                Abilities::OWNTEMPO,
+                Abilities::GLUTTONY,
+            ],
+            PokemonName::GRUMPIG => &[
+                Abilities::THICKFAT,
+                Abilities::OWNTEMPO,
+                Abilities::GLUTTONY,
+            ],
+            PokemonName::SPINDA => &[
+                Abilities::OWNTEMPO,
+                Abilities::TANGLEDFEET,
+                Abilities::CONTRARY,
+            ],
+            PokemonName::TRAPINCH => &[
+                Abilities::HYPERCUTTER,
+                Abilities::ARENATRAP,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::VIBRAVA => &[Abilities::LEVITATE],
+            PokemonName::FLYGON => &[Abilities::LEVITATE],
+            PokemonName::CACNEA => &[Abilities::SANDVEIL, Abilities::WATERABSORB],
+            PokemonName::CACTURNE => &[Abilities::SANDVEIL, Abilities::WATERABSORB],
+            PokemonName::SWABLU => &[Abilities::NATURALCURE, Abilities::CLOUDNINE],
+            PokemonName::ALTARIA => &[Abilities::NATURALCURE, Abilities::CLOUDNINE],
+            PokemonName::ALTARIAMEGA => &[Abilities::PIXILATE],
+            PokemonName::ZANGOOSE => &[Abilities::IMMUNITY, Abilities::TOXICBOOST],
+            PokemonName::SEVIPER => &[Abilities::SHEDSKIN, Abilities::INFILTRATOR],
+            PokemonName::LUNATONE => &[Abilities::LEVITATE],
+            PokemonName::SOLROCK => &[Abilities::LEVITATE],
+            PokemonName::BARBOACH => &[
+                Abilities::OBLIVIOUS,
+                Abilities::ANTICIPATION,
+                Abilities::HYDRATION,
+            ],
+            PokemonName::WHISCASH => &[
+                Abilities::OBLIVIOUS,
+                Abilities::ANTICIPATION,
+                Abilities::HYDRATION,
+            ],
+            PokemonName::CORPHISH => &[
+                Abilities::HYPERCUTTER,
+                Abilities::SHELLARMOR,
+                Abilities::ADAPTABILITY,
+            ],
+            PokemonName::CRAWDAUNT => &[
+                Abilities::HYPERCUTTER,
+                Abilities::SHELLARMOR,
+                Abilities::ADAPTABILITY,
+            ],
+            PokemonName::BALTOY => &[Abilities::LEVITATE],
+            PokemonName::CLAYDOL => &[Abilities::LEVITATE],
+            PokemonName::LILEEP => &[Abilities::SUCTIONCUPS, Abilities::STORMDRAIN],
+            PokemonName::CRADILY => &[Abilities::SUCTIONCUPS, Abilities::STORMDRAIN],
+            PokemonName::ANORITH => &[Abilities::BATTLEARMOR, Abilities::SWIFTSWIM],
+            PokemonName::ARMALDO => &[Abilities::BATTLEARMOR, Abilities::SWIFTSWIM],
+            PokemonName::FEEBAS => &[
+                Abilities::SWIFTSWIM,
+                Abilities::OBLIVIOUS,
+                Abilities::ADAPTABILITY,
+            ],
+            PokemonName::MILOTIC => &[
+                Abilities::MARVELSCALE,
+                Abilities::COMPETITIVE,
+                Abilities::CUTECHARM,
+            ],
+            PokemonName::CASTFORM => &[Abilities::FORECAST],
+            PokemonName::CASTFORMSUNNY => &[Abilities::FORECAST],
+            PokemonName::CASTFORMRAINY => &[Abilities::FORECAST],
+            PokemonName::CASTFORMSNOWY => &[Abilities::FORECAST],
+            PokemonName::KECLEON => &[Abilities::COLORCHANGE, Abilities::PROTEAN],
+            PokemonName::SHUPPET => &[Abilities::INSOMNIA, Abilities::FRISK, Abilities::CURSEDBODY],
+            PokemonName::BANETTE => &[Abilities::INSOMNIA, Abilities::FRISK, Abilities::CURSEDBODY],
+            PokemonName::BANETTEMEGA => &[Abilities::PRANKSTER],
+            PokemonName::DUSKULL => &[Abilities::LEVITATE, Abilities::FRISK],
+            PokemonName::DUSCLOPS => &[Abilities::PRESSURE, Abilities::FRISK],
+            PokemonName::TROPIUS => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::SOLARPOWER,
+                Abilities::HARVEST,
+            ],
+            PokemonName::CHIMECHO => &[Abilities::LEVITATE],
+            PokemonName::ABSOL => &[
+                Abilities::PRESSURE,
+                Abilities::SUPERLUCK,
+                Abilities::JUSTIFIED,
+            ],
+            PokemonName::ABSOLMEGA => &[Abilities::MAGICBOUNCE],
+            PokemonName::WYNAUT => &[Abilities::SHADOWTAG, Abilities::TELEPATHY],
+            PokemonName::SNORUNT => &[Abilities::INNERFOCUS, Abilities::ICEBODY, Abilities::MOODY],
+            PokemonName::GLALIE => &[Abilities::INNERFOCUS, Abilities::ICEBODY, Abilities::MOODY],
+            PokemonName::GLALIEMEGA => &[Abilities::REFRIGERATE],
+            PokemonName::SPHEAL => &[
+                Abilities::THICKFAT,
+                Abilities::ICEBODY,
+                Abilities::OBLIVIOUS,
+            ],
+            PokemonName::SEALEO => &[
+                Abilities::THICKFAT,
+                Abilities::ICEBODY,
+                Abilities::OBLIVIOUS,
+            ],
+            PokemonName::WALREIN => &[
+                Abilities::THICKFAT,
+                Abilities::ICEBODY,
+                Abilities::OBLIVIOUS,
+            ],
+            PokemonName::CLAMPERL => &[Abilities::SHELLARMOR, Abilities::RATTLED],
+            PokemonName::HUNTAIL => &[Abilities::SWIFTSWIM, Abilities::WATERVEIL],
+            PokemonName::GOREBYSS => &[Abilities::SWIFTSWIM, Abilities::HYDRATION],
+            PokemonName::RELICANTH => {
+                &[Abilities::SWIFTSWIM, Abilities::ROCKHEAD, Abilities::STURDY]
+            }
+            PokemonName::LUVDISC => &[Abilities::SWIFTSWIM, Abilities::HYDRATION],
+            PokemonName::BAGON => &[Abilities::ROCKHEAD, Abilities::SHEERFORCE],
+            PokemonName::SHELGON => &[Abilities::ROCKHEAD, Abilities::OVERCOAT],
+            PokemonName::SALAMENCE => &[Abilities::INTIMIDATE, Abilities::MOXIE],
+            PokemonName::SALAMENCEMEGA => &[Abilities::AERILATE],
+            PokemonName::BELDUM => &[Abilities::CLEARBODY, Abilities::LIGHTMETAL],
+            PokemonName::METANG => &[Abilities::CLEARBODY, Abilities::LIGHTMETAL],
+            PokemonName::METAGROSS => &[Abilities::CLEARBODY, Abilities::LIGHTMETAL],
+            PokemonName::METAGROSSMEGA => &[Abilities::TOUGHCLAWS],
+            PokemonName::REGIROCK => &[Abilities::CLEARBODY, Abilities::STURDY],
+            PokemonName::REGICE => &[Abilities::CLEARBODY, Abilities::ICEBODY],
+            PokemonName::REGISTEEL => &[Abilities::CLEARBODY, Abilities::LIGHTMETAL],
+            PokemonName::LATIAS => &[Abilities::LEVITATE],
+            PokemonName::LATIASMEGA => &[Abilities::LEVITATE],
+            PokemonName::LATIOS => &[Abilities::LEVITATE],
+            PokemonName::LATIOSMEGA => &[Abilities::LEVITATE],
+            PokemonName::KYOGRE => &[Abilities::DRIZZLE],
+            PokemonName::KYOGREPRIMAL => &[Abilities::PRIMORDIALSEA],
+            PokemonName::GROUDON => &[Abilities::DROUGHT],
+            PokemonName::GROUDONPRIMAL => &[Abilities::DESOLATELAND],
+            PokemonName::RAYQUAZA => &[Abilities::AIRLOCK],
+            PokemonName::RAYQUAZAMEGA => &[Abilities::DELTASTREAM],
+            PokemonName::JIRACHI => &[Abilities::SERENEGRACE],
+            PokemonName::DEOXYS => &[Abilities::PRESSURE],
+            PokemonName::DEOXYSATTACK => &[Abilities::PRESSURE],
+            PokemonName::DEOXYSDEFENSE => &[Abilities::PRESSURE],
+            PokemonName::DEOXYSSPEED => &[Abilities::PRESSURE],
+            PokemonName::TURTWIG => &[Abilities::OVERGROW, Abilities::SHELLARMOR],
+            PokemonName::GROTLE => &[Abilities::OVERGROW, Abilities::SHELLARMOR],
+            PokemonName::TORTERRA => &[Abilities::OVERGROW, Abilities::SHELLARMOR],
+            PokemonName::CHIMCHAR => &[Abilities::BLAZE, Abilities::IRONFIST],
+            PokemonName::MONFERNO => &[Abilities::BLAZE, Abilities::IRONFIST],
+            PokemonName::INFERNAPE => &[Abilities::BLAZE, Abilities::IRONFIST],
+            PokemonName::PIPLUP => &[Abilities::TORRENT, Abilities::COMPETITIVE],
+            PokemonName::PRINPLUP => &[Abilities::TORRENT, Abilities::COMPETITIVE],
+            PokemonName::EMPOLEON => &[Abilities::TORRENT, Abilities::COMPETITIVE],
+            PokemonName::STARLY => &[Abilities::KEENEYE, Abilities::RECKLESS],
+            PokemonName::STARAVIA => &[Abilities::INTIMIDATE, Abilities::RECKLESS],
+            PokemonName::STARAPTOR => &[Abilities::INTIMIDATE, Abilities::RECKLESS],
+            PokemonName::BIDOOF => &[Abilities::SIMPLE, Abilities::UNAWARE, Abilities::MOODY],
+            PokemonName::BIBAREL => &[Abilities::SIMPLE, Abilities::UNAWARE, Abilities::MOODY],
+            PokemonName::KRICKETOT => &[Abilities::SHEDSKIN, Abilities::RUNAWAY],
+            PokemonName::KRICKETUNE => &[
+                Abilities::SWARM,
+                Abilities::SHARPNESS,
+                Abilities::TECHNICIAN,
+            ],
+            PokemonName::SHINX => &[Abilities::RIVALRY, Abilities::INTIMIDATE, Abilities::GUTS],
+            PokemonName::LUXIO => &[Abilities::RIVALRY, Abilities::INTIMIDATE, Abilities::GUTS],
+            PokemonName::LUXRAY => &[Abilities::RIVALRY, Abilities::INTIMIDATE, Abilities::GUTS],
+            PokemonName::BUDEW => &[
+                Abilities::NATURALCURE,
+                Abilities::POISONPOINT,
+                Abilities::LEAFGUARD,
+            ],
+            PokemonName::ROSERADE => &[
+                Abilities::NATURALCURE,
+                Abilities::POISONPOINT,
+                Abilities::TECHNICIAN,
+            ],
+            PokemonName::CRANIDOS => &[Abilities::MOLDBREAKER, Abilities::SHEERFORCE],
+            PokemonName::RAMPARDOS => &[Abilities::MOLDBREAKER, Abilities::SHEERFORCE],
+            PokemonName::SHIELDON => &[Abilities::STURDY, Abilities::SOUNDPROOF],
+            PokemonName::BASTIODON => &[Abilities::STURDY, Abilities::SOUNDPROOF],
+            PokemonName::BURMY => &[Abilities::SHEDSKIN, Abilities::OVERCOAT],
+            PokemonName::BURMYSANDY => &[Abilities::SHEDSKIN, Abilities::OVERCOAT],
+            PokemonName::BURMYTRASH => &[Abilities::SHEDSKIN, Abilities::OVERCOAT],
+            PokemonName::WORMADAM => &[Abilities::ANTICIPATION, Abilities::OVERCOAT],
+            PokemonName::WORMADAMSANDY => &[Abilities::ANTICIPATION, Abilities::OVERCOAT],
+            PokemonName::WORMADAMTRASH => &[Abilities::ANTICIPATION, Abilities::OVERCOAT],
+            PokemonName::MOTHIM => &[Abilities::SWARM, Abilities::TINTEDLENS],
+            PokemonName::COMBEE => &[Abilities::HONEYGATHER, Abilities::HUSTLE],
+            PokemonName::VESPIQUEN => &[Abilities::PRESSURE, Abilities::UNNERVE],
+            PokemonName::PACHIRISU => {
+                &[Abilities::RUNAWAY, Abilities::PICKUP, Abilities::VOLTABSORB]
+            }
+            PokemonName::BUIZEL => &[Abilities::SWIFTSWIM, Abilities::WATERVEIL],
+            PokemonName::FLOATZEL => &[Abilities::SWIFTSWIM, Abilities::WATERVEIL],
+            PokemonName::CHERUBI => &[Abilities::CHLOROPHYLL],
+            PokemonName::CHERRIM => &[Abilities::FLOWERGIFT],
+            PokemonName::CHERRIMSUNSHINE => &[Abilities::FLOWERGIFT],
+            PokemonName::SHELLOS => &[
+                Abilities::STICKYHOLD,
+                Abilities::STORMDRAIN,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::SHELLOSEAST => &[
+                Abilities::STICKYHOLD,
+                Abilities::STORMDRAIN,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::GASTRODON => &[
+                Abilities::STICKYHOLD,
+                Abilities::STORMDRAIN,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::GASTRODONEAST => &[
+                Abilities::STICKYHOLD,
+                Abilities::STORMDRAIN,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::AMBIPOM => &[
+                Abilities::TECHNICIAN,
+                Abilities::PICKUP,
+                Abilities::SKILLLINK,
+            ],
+            PokemonName::DRIFLOON => &[
+                Abilities::AFTERMATH,
+                Abilities::UNBURDEN,
+                Abilities::FLAREBOOST,
+            ],
+            PokemonName::DRIFBLIM => &[
+                Abilities::AFTERMATH,
+                Abilities::UNBURDEN,
+                Abilities::FLAREBOOST,
+            ],
+            PokemonName::BUNEARY => &[Abilities::RUNAWAY, Abilities::KLUTZ, Abilities::LIMBER],
+            PokemonName::LOPUNNY => &[Abilities::CUTECHARM, Abilities::KLUTZ, Abilities::LIMBER],
+            PokemonName::LOPUNNYMEGA => &[Abilities::SCRAPPY],
+            PokemonName::MISMAGIUS => &[Abilities::LEVITATE],
+            PokemonName::HONCHKROW => {
+                &[Abilities::INSOMNIA, Abilities::SUPERLUCK, Abilities::MOXIE]
+            }
+            PokemonName::GLAMEOW => &[Abilities::LIMBER, Abilities::OWNTEMPO, Abilities::KEENEYE],
+            PokemonName::PURUGLY => &[Abilities::THICKFAT, Abilities::OWNTEMPO, Abilities::DEFIANT],
+            PokemonName::CHINGLING => &[Abilities::LEVITATE],
+            PokemonName::STUNKY => &[Abilities::STENCH, Abilities::AFTERMATH, Abilities::KEENEYE],
+            PokemonName::SKUNTANK => &[Abilities::STENCH, Abilities::AFTERMATH, Abilities::KEENEYE],
+            PokemonName::BRONZOR => &[
+                Abilities::LEVITATE,
+                Abilities::HEATPROOF,
+                Abilities::HEAVYMETAL,
+            ],
+            PokemonName::BRONZONG => &[
+                Abilities::LEVITATE,
+                Abilities::HEATPROOF,
+                Abilities::HEAVYMETAL,
+            ],
+            PokemonName::BONSLY => &[Abilities::STURDY, Abilities::ROCKHEAD, Abilities::RATTLED],
+            PokemonName::MIMEJR => &[
+                Abilities::SOUNDPROOF,
+                Abilities::FILTER,
+                Abilities::TECHNICIAN,
+            ],
+            PokemonName::HAPPINY => &[
+                Abilities::NATURALCURE,
+                Abilities::SERENEGRACE,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::CHATOT => &[
+                Abilities::KEENEYE,
+                Abilities::TANGLEDFEET,
+                Abilities::BIGPECKS,
+            ],
+            PokemonName::SPIRITOMB => &[Abilities::PRESSURE, Abilities::INFILTRATOR],
+            PokemonName::GIBLE => &[Abilities::SANDVEIL, Abilities::ROUGHSKIN],
+            PokemonName::GABITE => &[Abilities::SANDVEIL, Abilities::ROUGHSKIN],
+            PokemonName::GARCHOMP => &[Abilities::SANDVEIL, Abilities::ROUGHSKIN],
+            PokemonName::GARCHOMPMEGA => &[Abilities::SANDFORCE],
+            PokemonName::MUNCHLAX => &[Abilities::PICKUP, Abilities::THICKFAT, Abilities::GLUTTONY],
+            PokemonName::RIOLU => &[
+                Abilities::STEADFAST,
+                Abilities::INNERFOCUS,
+                Abilities::PRANKSTER,
+            ],
+            PokemonName::LUCARIO => &[
+                Abilities::STEADFAST,
+                Abilities::INNERFOCUS,
+                Abilities::JUSTIFIED,
+            ],
+            PokemonName::LUCARIOMEGA => &[Abilities::ADAPTABILITY],
+            PokemonName::HIPPOPOTAS => &[Abilities::SANDSTREAM, Abilities::SANDFORCE],
+            PokemonName::HIPPOWDON => &[Abilities::SANDSTREAM, Abilities::SANDFORCE],
+            PokemonName::SKORUPI => &[
+                Abilities::BATTLEARMOR,
+                Abilities::SNIPER,
+                Abilities::KEENEYE,
+            ],
+            PokemonName::DRAPION => &[
+                Abilities::BATTLEARMOR,
+                Abilities::SNIPER,
+                Abilities::KEENEYE,
+            ],
+            PokemonName::CROAGUNK => &[
+                Abilities::ANTICIPATION,
+                Abilities::DRYSKIN,
+                Abilities::POISONTOUCH,
+            ],
+            PokemonName::TOXICROAK => &[
+                Abilities::ANTICIPATION,
+                Abilities::DRYSKIN,
+                Abilities::POISONTOUCH,
+            ],
+            PokemonName::CARNIVINE => &[Abilities::LEVITATE],
+            PokemonName::FINNEON => &[
+                Abilities::SWIFTSWIM,
+                Abilities::STORMDRAIN,
+                Abilities::WATERVEIL,
+            ],
+            PokemonName::LUMINEON => &[
+                Abilities::SWIFTSWIM,
+                Abilities::STORMDRAIN,
+                Abilities::WATERVEIL,
+            ],
+            PokemonName::MANTYKE => &[
+                Abilities::SWIFTSWIM,
+                Abilities::WATERABSORB,
+                Abilities::WATERVEIL,
+            ],
+            PokemonName::SNOVER => &[Abilities::SNOWWARNING, Abilities::SOUNDPROOF],
+            PokemonName::ABOMASNOW => &[Abilities::SNOWWARNING, Abilities::SOUNDPROOF],
+            PokemonName::ABOMASNOWMEGA => &[Abilities::SNOWWARNING],
+            PokemonName::WEAVILE => &[Abilities::PRESSURE, Abilities::PICKPOCKET],
+            PokemonName::MAGNEZONE => &[
+                Abilities::MAGNETPULL,
+                Abilities::STURDY,
+                Abilities::ANALYTIC,
+            ],
+            PokemonName::LICKILICKY => &[
+                Abilities::OWNTEMPO,
+                Abilities::OBLIVIOUS,
+                Abilities::CLOUDNINE,
+            ],
+            PokemonName::RHYPERIOR => &[
+                Abilities::LIGHTNINGROD,
+                Abilities::SOLIDROCK,
+                Abilities::RECKLESS,
+            ],
+            PokemonName::TANGROWTH => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::LEAFGUARD,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::ELECTIVIRE => &[Abilities::MOTORDRIVE, Abilities::VITALSPIRIT],
+            PokemonName::MAGMORTAR => &[Abilities::FLAMEBODY, Abilities::VITALSPIRIT],
+            PokemonName::TOGEKISS => &[
+                Abilities::HUSTLE,
+                Abilities::SERENEGRACE,
+                Abilities::SUPERLUCK,
+            ],
+            PokemonName::YANMEGA => &[
+                Abilities::SPEEDBOOST,
+                Abilities::TINTEDLENS,
+                Abilities::FRISK,
+            ],
+            PokemonName::LEAFEON => &[Abilities::LEAFGUARD, Abilities::CHLOROPHYLL],
+            PokemonName::GLACEON => &[Abilities::SNOWCLOAK, Abilities::ICEBODY],
+            PokemonName::GLISCOR => &[
+                Abilities::HYPERCUTTER,
+                Abilities::SANDVEIL,
+                Abilities::POISONHEAL,
+            ],
+            PokemonName::MAMOSWINE => &[
+                Abilities::OBLIVIOUS,
+                Abilities::SNOWCLOAK,
+                Abilities::THICKFAT,
+            ],
+            PokemonName::PORYGONZ => &[
+                Abilities::ADAPTABILITY,
+                Abilities::DOWNLOAD,
+                Abilities::ANALYTIC,
+            ],
+            PokemonName::GALLADE => &[
+                Abilities::STEADFAST,
+                Abilities::SHARPNESS,
+                Abilities::JUSTIFIED,
+            ],
+            PokemonName::GALLADEMEGA => &[Abilities::INNERFOCUS],
+            PokemonName::PROBOPASS => &[
+                Abilities::STURDY,
+                Abilities::MAGNETPULL,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::DUSKNOIR => &[Abilities::PRESSURE, Abilities::FRISK],
+            PokemonName::FROSLASS => &[Abilities::SNOWCLOAK, Abilities::CURSEDBODY],
+            PokemonName::ROTOM => &[Abilities::LEVITATE],
+            PokemonName::ROTOMHEAT => &[Abilities::LEVITATE],
+            PokemonName::ROTOMWASH => &[Abilities::LEVITATE],
+            PokemonName::ROTOMFROST => &[Abilities::LEVITATE],
+            PokemonName::ROTOMFAN => &[Abilities::LEVITATE],
+            PokemonName::ROTOMMOW => &[Abilities::LEVITATE],
+            PokemonName::UXIE => &[Abilities::LEVITATE],
+            PokemonName::MESPRIT => &[Abilities::LEVITATE],
+            PokemonName::AZELF => &[Abilities::LEVITATE],
+            PokemonName::DIALGA => &[Abilities::PRESSURE, Abilities::TELEPATHY],
+            PokemonName::DIALGAORIGIN => &[Abilities::PRESSURE, Abilities::TELEPATHY],
+            PokemonName::PALKIA => &[Abilities::PRESSURE, Abilities::TELEPATHY],
+            PokemonName::PALKIAORIGIN => &[Abilities::PRESSURE, Abilities::TELEPATHY],
+            PokemonName::HEATRAN => &[Abilities::FLASHFIRE, Abilities::FLAMEBODY],
+            PokemonName::REGIGIGAS => &[Abilities::SLOWSTART],
+            PokemonName::GIRATINA => &[Abilities::PRESSURE, Abilities::TELEPATHY],
+            PokemonName::GIRATINAORIGIN => &[Abilities::LEVITATE],
+            PokemonName::CRESSELIA => &[Abilities::LEVITATE],
+            PokemonName::PHIONE => &[Abilities::HYDRATION],
+            PokemonName::MANAPHY => &[Abilities::HYDRATION],
+            PokemonName::DARKRAI => &[Abilities::BADDREAMS],
+            PokemonName::SHAYMIN => &[Abilities::NATURALCURE],
+            PokemonName::SHAYMINSKY => &[Abilities::SERENEGRACE],
+            PokemonName::ARCEUS => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSBUG => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSDARK => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSDRAGON => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSELECTRIC => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSFAIRY => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSFIGHTING => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSFIRE => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSFLYING => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSGHOST => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSGRASS => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSGROUND => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSICE => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSPOISON => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSPSYCHIC => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSROCK => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSSTEEL => &[Abilities::MULTITYPE],
+            PokemonName::ARCEUSWATER => &[Abilities::MULTITYPE],
+            PokemonName::VICTINI => &[Abilities::VICTORYSTAR],
+            PokemonName::SNIVY => &[Abilities::OVERGROW, Abilities::CONTRARY],
+            PokemonName::SERVINE => &[Abilities::OVERGROW, Abilities::CONTRARY],
+            PokemonName::SERPERIOR => &[Abilities::OVERGROW, Abilities::CONTRARY],
+            PokemonName::TEPIG => &[Abilities::BLAZE, Abilities::THICKFAT],
+            PokemonName::PIGNITE => &[Abilities::BLAZE, Abilities::THICKFAT],
+            PokemonName::EMBOAR => &[Abilities::BLAZE, Abilities::RECKLESS],
+            PokemonName::OSHAWOTT => &[Abilities::TORRENT, Abilities::SHELLARMOR],
+            PokemonName::DEWOTT => &[Abilities::TORRENT, Abilities::SHELLARMOR],
+            PokemonName::SAMUROTT => &[Abilities::TORRENT, Abilities::SHELLARMOR],
+            PokemonName::SAMUROTTHISUI => &[Abilities::TORRENT, Abilities::SHARPNESS],
+            PokemonName::PATRAT => &[Abilities::RUNAWAY, Abilities::KEENEYE, Abilities::ANALYTIC],
+            PokemonName::WATCHOG => &[
+                Abilities::ILLUMINATE,
+                Abilities::KEENEYE,
+                Abilities::ANALYTIC,
+            ],
+            PokemonName::LILLIPUP => &[
+                Abilities::VITALSPIRIT,
+                Abilities::PICKUP,
+                Abilities::RUNAWAY,
+            ],
+            PokemonName::HERDIER => &[
+                Abilities::INTIMIDATE,
+                Abilities::SANDRUSH,
+                Abilities::SCRAPPY,
+            ],
+            PokemonName::STOUTLAND => &[
+                Abilities::INTIMIDATE,
+                Abilities::SANDRUSH,
+                Abilities::SCRAPPY,
+            ],
+            PokemonName::PURRLOIN => {
+                &[Abilities::LIMBER, Abilities::UNBURDEN, Abilities::PRANKSTER]
+            }
+            PokemonName::LIEPARD => &[Abilities::LIMBER, Abilities::UNBURDEN, Abilities::PRANKSTER],
+            PokemonName::PANSAGE => &[Abilities::GLUTTONY, Abilities::OVERGROW],
+            PokemonName::SIMISAGE => &[Abilities::GLUTTONY, Abilities::OVERGROW],
+            PokemonName::PANSEAR => &[Abilities::GLUTTONY, Abilities::BLAZE],
+            PokemonName::SIMISEAR => &[Abilities::GLUTTONY, Abilities::BLAZE],
+            PokemonName::PANPOUR => &[Abilities::GLUTTONY, Abilities::TORRENT],
+            PokemonName::SIMIPOUR => &[Abilities::GLUTTONY, Abilities::TORRENT],
+            PokemonName::MUNNA => &[
+                Abilities::FOREWARN,
+                Abilities::SYNCHRONIZE,
+                Abilities::TELEPATHY,
+            ],
+            PokemonName::MUSHARNA => &[
+                Abilities::FOREWARN,
+                Abilities::SYNCHRONIZE,
+                Abilities::TELEPATHY,
+            ],
+            PokemonName::PIDOVE => &[
+                Abilities::BIGPECKS,
+                Abilities::SUPERLUCK,
+                Abilities::RIVALRY,
+            ],
+            PokemonName::TRANQUILL => &[
+                Abilities::BIGPECKS,
+                Abilities::SUPERLUCK,
+                Abilities::RIVALRY,
+            ],
+            PokemonName::UNFEZANT => &[
+                Abilities::BIGPECKS,
+                Abilities::SUPERLUCK,
+                Abilities::RIVALRY,
+            ],
+            PokemonName::BLITZLE => &[
+                Abilities::LIGHTNINGROD,
+                Abilities::MOTORDRIVE,
+                Abilities::SAPSIPPER,
+            ],
+            PokemonName::ZEBSTRIKA => &[
+                Abilities::LIGHTNINGROD,
+                Abilities::MOTORDRIVE,
+                Abilities::SAPSIPPER,
+            ],
+            PokemonName::ROGGENROLA => &[
+                Abilities::STURDY,
+                Abilities::WEAKARMOR,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::BOLDORE => &[
+                Abilities::STURDY,
+                Abilities::WEAKARMOR,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::GIGALITH => &[
+                Abilities::STURDY,
+                Abilities::SANDSTREAM,
+                Abilities::SANDFORCE,
+            ],
+            PokemonName::WOOBAT => &[Abilities::UNAWARE, Abilities::KLUTZ, Abilities::SIMPLE],
+            PokemonName::SWOOBAT => &[Abilities::UNAWARE, Abilities::KLUTZ, Abilities::SIMPLE],
+            PokemonName::DRILBUR => &[
+                Abilities::SANDRUSH,
+                Abilities::SANDFORCE,
+                Abilities::MOLDBREAKER,
+            ],
+            PokemonName::EXCADRILL => &[
+                Abilities::SANDRUSH,
+                Abilities::SANDFORCE,
+                Abilities::MOLDBREAKER,
+            ],
+            PokemonName::AUDINO => &[Abilities::HEALER, Abilities::REGENERATOR, Abilities::KLUTZ],
+            PokemonName::AUDINOMEGA => &[Abilities::HEALER],
+            PokemonName::TIMBURR => &[Abilities::GUTS, Abilities::SHEERFORCE, Abilities::IRONFIST],
+            PokemonName::GURDURR => &[Abilities::GUTS, Abilities::SHEERFORCE, Abilities::IRONFIST],
+            PokemonName::CONKELDURR => {
+                &[Abilities::GUTS, Abilities::SHEERFORCE, Abilities::IRONFIST]
+            }
+            PokemonName::TYMPOLE => &[
+                Abilities::SWIFTSWIM,
+                Abilities::HYDRATION,
+                Abilities::WATERABSORB,
+            ],
+            PokemonName::PALPITOAD => &[
+                Abilities::SWIFTSWIM,
+                Abilities::HYDRATION,
+                Abilities::WATERABSORB,
+            ],
+            PokemonName::SEISMITOAD => &[
+                Abilities::SWIFTSWIM,
+                Abilities::POISONTOUCH,
+                Abilities::WATERABSORB,
+            ],
+            PokemonName::THROH => &[
+                Abilities::GUTS,
+                Abilities::INNERFOCUS,
+                Abilities::MOLDBREAKER,
+            ],
+            PokemonName::SAWK => &[
+                Abilities::STURDY,
+                Abilities::INNERFOCUS,
+                Abilities::MOLDBREAKER,
+            ],
+            PokemonName::SEWADDLE => &[
+                Abilities::SWARM,
+                Abilities::CHLOROPHYLL,
+                Abilities::OVERCOAT,
+            ],
+            PokemonName::SWADLOON => &[
+                Abilities::LEAFGUARD,
+                Abilities::CHLOROPHYLL,
+                Abilities::OVERCOAT,
+            ],
+            PokemonName::LEAVANNY => &[
+                Abilities::SWARM,
+                Abilities::CHLOROPHYLL,
+                Abilities::OVERCOAT,
+            ],
+            PokemonName::VENIPEDE => &[
+                Abilities::POISONPOINT,
+                Abilities::SWARM,
+                Abilities::SPEEDBOOST,
+            ],
+            PokemonName::WHIRLIPEDE => &[
+                Abilities::POISONPOINT,
+                Abilities::SWARM,
+                Abilities::SPEEDBOOST,
+            ],
+            PokemonName::SCOLIPEDE => &[
+                Abilities::POISONPOINT,
+                Abilities::SWARM,
+                Abilities::SPEEDBOOST,
+            ],
+            PokemonName::COTTONEE => &[
+                Abilities::PRANKSTER,
+                Abilities::INFILTRATOR,
+                Abilities::CHLOROPHYLL,
+            ],
+            PokemonName::WHIMSICOTT => &[
+                Abilities::PRANKSTER,
+                Abilities::INFILTRATOR,
+                Abilities::CHLOROPHYLL,
+            ],
+            PokemonName::PETILIL => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::OWNTEMPO,
+                Abilities::LEAFGUARD,
+            ],
+            PokemonName::LILLIGANT => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::OWNTEMPO,
+                Abilities::LEAFGUARD,
+            ],
+            PokemonName::LILLIGANTHISUI => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::HUSTLE,
+                Abilities::LEAFGUARD,
+            ],
+            PokemonName::BASCULIN => &[
+                Abilities::RECKLESS,
+                Abilities::ADAPTABILITY,
+                Abilities::MOLDBREAKER,
+            ],
+            PokemonName::BASCULINBLUESTRIPED => &[
+                Abilities::ROCKHEAD,
+                Abilities::ADAPTABILITY,
+                Abilities::MOLDBREAKER,
+            ],
+            PokemonName::BASCULINWHITESTRIPED => &[
+                Abilities::RATTLED,
+                Abilities::ADAPTABILITY,
+                Abilities::MOLDBREAKER,
+            ],
+            PokemonName::SANDILE => &[
+                Abilities::INTIMIDATE,
+                Abilities::MOXIE,
+                Abilities::ANGERPOINT,
+            ],
+            PokemonName::KROKOROK => &[
+                Abilities::INTIMIDATE,
+                Abilities::MOXIE,
+                Abilities::ANGERPOINT,
+            ],
+            PokemonName::KROOKODILE => &[
+                Abilities::INTIMIDATE,
+                Abilities::MOXIE,
+                Abilities::ANGERPOINT,
+            ],
+            PokemonName::DARUMAKA => &[Abilities::HUSTLE, Abilities::INNERFOCUS],
+            PokemonName::DARUMAKAGALAR => &[Abilities::HUSTLE, Abilities::INNERFOCUS],
+            PokemonName::DARMANITAN => &[Abilities::SHEERFORCE, Abilities::ZENMODE],
+            PokemonName::DARMANITANZEN => &[Abilities::ZENMODE],
+            PokemonName::DARMANITANGALAR => &[Abilities::GORILLATACTICS, Abilities::ZENMODE],
+            PokemonName::DARMANITANGALARZEN => &[Abilities::ZENMODE],
+            PokemonName::MARACTUS => &[
+                Abilities::WATERABSORB,
+                Abilities::CHLOROPHYLL,
+                Abilities::STORMDRAIN,
+            ],
+            PokemonName::DWEBBLE => &[
+                Abilities::STURDY,
+                Abilities::SHELLARMOR,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::CRUSTLE => &[
+                Abilities::STURDY,
+                Abilities::SHELLARMOR,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::SCRAGGY => &[Abilities::SHEDSKIN, Abilities::MOXIE, Abilities::INTIMIDATE],
+            PokemonName::SCRAFTY => &[Abilities::SHEDSKIN, Abilities::MOXIE, Abilities::INTIMIDATE],
+            PokemonName::SIGILYPH => &[
+                Abilities::WONDERSKIN,
+                Abilities::MAGICGUARD,
+                Abilities::TINTEDLENS,
+            ],
+            PokemonName::YAMASK => &[Abilities::MUMMY],
+            PokemonName::YAMASKGALAR => &[Abilities::WANDERINGSPIRIT],
+            PokemonName::COFAGRIGUS => &[Abilities::MUMMY],
+            PokemonName::TIRTOUGA => &[
+                Abilities::SOLIDROCK,
+                Abilities::STURDY,
+                Abilities::SWIFTSWIM,
+            ],
+            PokemonName::CARRACOSTA => &[
+                Abilities::SOLIDROCK,
+                Abilities::STURDY,
+                Abilities::SWIFTSWIM,
+            ],
+            PokemonName::ARCHEN => &[Abilities::DEFEATIST],
+            PokemonName::ARCHEOPS => &[Abilities::DEFEATIST],
+            PokemonName::TRUBBISH => &[
+                Abilities::STENCH,
+                Abilities::STICKYHOLD,
+                Abilities::AFTERMATH,
+            ],
+            PokemonName::GARBODOR => &[
+                Abilities::STENCH,
+                Abilities::WEAKARMOR,
+                Abilities::AFTERMATH,
+            ],
+            PokemonName::GARBODORGMAX => &[
+                Abilities::STENCH,
+                Abilities::WEAKARMOR,
+                Abilities::AFTERMATH,
+            ],
+            PokemonName::ZORUA => &[Abilities::ILLUSION],
+            PokemonName::ZORUAHISUI => &[Abilities::ILLUSION],
+            PokemonName::ZOROARK => &[Abilities::ILLUSION],
+            PokemonName::ZOROARKHISUI => &[Abilities::ILLUSION],
+            PokemonName::MINCCINO => &[
+                Abilities::CUTECHARM,
+                Abilities::TECHNICIAN,
+                Abilities::SKILLLINK,
+            ],
+            PokemonName::CINCCINO => &[
+                Abilities::CUTECHARM,
+                Abilities::TECHNICIAN,
+                Abilities::SKILLLINK,
+            ],
+            PokemonName::GOTHITA => &[
+                Abilities::FRISK,
+                Abilities::COMPETITIVE,
+                Abilities::SHADOWTAG,
+            ],
+            PokemonName::GOTHORITA => &[
+                Abilities::FRISK,
+                Abilities::COMPETITIVE,
+                Abilities::SHADOWTAG,
+            ],
+            PokemonName::GOTHITELLE => &[
+                Abilities::FRISK,
+                Abilities::COMPETITIVE,
+                Abilities::SHADOWTAG,
+            ],
+            PokemonName::SOLOSIS => &[
+                Abilities::OVERCOAT,
+                Abilities::MAGICGUARD,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::DUOSION => &[
+                Abilities::OVERCOAT,
+                Abilities::MAGICGUARD,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::REUNICLUS => &[
+                Abilities::OVERCOAT,
+                Abilities::MAGICGUARD,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::DUCKLETT => &[
+                Abilities::KEENEYE,
+                Abilities::BIGPECKS,
+                Abilities::HYDRATION,
+            ],
+            PokemonName::SWANNA => &[
+                Abilities::KEENEYE,
+                Abilities::BIGPECKS,
+                Abilities::HYDRATION,
+            ],
+            PokemonName::VANILLITE => &[
+                Abilities::ICEBODY,
+                Abilities::SNOWCLOAK,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::VANILLISH => &[
+                Abilities::ICEBODY,
+                Abilities::SNOWCLOAK,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::VANILLUXE => &[
+                Abilities::ICEBODY,
+                Abilities::SNOWWARNING,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::DEERLING => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::SAPSIPPER,
+                Abilities::SERENEGRACE,
+            ],
+            PokemonName::DEERLINGSUMMER => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::SAPSIPPER,
+                Abilities::SERENEGRACE,
+            ],
+            PokemonName::DEERLINGAUTUMN => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::SAPSIPPER,
+                Abilities::SERENEGRACE,
+            ],
+            PokemonName::DEERLINGWINTER => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::SAPSIPPER,
+                Abilities::SERENEGRACE,
+            ],
+            PokemonName::SAWSBUCK => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::SAPSIPPER,
+                Abilities::SERENEGRACE,
+            ],
+            PokemonName::SAWSBUCKSUMMER => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::SAPSIPPER,
+                Abilities::SERENEGRACE,
+            ],
+            PokemonName::SAWSBUCKAUTUMN => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::SAPSIPPER,
+                Abilities::SERENEGRACE,
+            ],
+            PokemonName::SAWSBUCKWINTER => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::SAPSIPPER,
+                Abilities::SERENEGRACE,
+            ],
+            PokemonName::EMOLGA => &[Abilities::STATIC, Abilities::MOTORDRIVE],
+            PokemonName::KARRABLAST => &[Abilities::SWARM, Abilities::SHEDSKIN, Abilities::NOGUARD],
+            PokemonName::ESCAVALIER => {
+                &[Abilities::SWARM, Abilities::SHELLARMOR, Abilities::OVERCOAT]
+            }
+            PokemonName::FOONGUS => &[Abilities::EFFECTSPORE, Abilities::REGENERATOR],
+            PokemonName::AMOONGUSS => &[Abilities::EFFECTSPORE, Abilities::REGENERATOR],
+            PokemonName::FRILLISH => &[
+                Abilities::WATERABSORB,
+                Abilities::CURSEDBODY,
+                Abilities::DAMP,
+            ],
+            PokemonName::JELLICENT => &[
+                Abilities::WATERABSORB,
+                Abilities::CURSEDBODY,
+                Abilities::DAMP,
+            ],
+            PokemonName::ALOMOMOLA => &[
+                Abilities::HEALER,
+                Abilities::HYDRATION,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::JOLTIK => &[
+                Abilities::COMPOUNDEYES,
+                Abilities::UNNERVE,
+                Abilities::SWARM,
+            ],
+            PokemonName::GALVANTULA => &[
+                Abilities::COMPOUNDEYES,
+                Abilities::UNNERVE,
+                Abilities::SWARM,
+            ],
+            PokemonName::FERROSEED => &[Abilities::IRONBARBS],
+            PokemonName::FERROTHORN => &[Abilities::IRONBARBS, Abilities::ANTICIPATION],
+            PokemonName::KLINK => &[Abilities::PLUS, Abilities::MINUS, Abilities::CLEARBODY],
+            PokemonName::KLANG => &[Abilities::PLUS, Abilities::MINUS, Abilities::CLEARBODY],
+            PokemonName::KLINKLANG => &[Abilities::PLUS, Abilities::MINUS, Abilities::CLEARBODY],
+            PokemonName::TYNAMO => &[Abilities::LEVITATE],
+            PokemonName::EELEKTRIK => &[Abilities::LEVITATE],
+            PokemonName::EELEKTROSS => &[Abilities::LEVITATE],
+            PokemonName::ELGYEM => &[
+                Abilities::TELEPATHY,
+                Abilities::SYNCHRONIZE,
+                Abilities::ANALYTIC,
+            ],
+            PokemonName::BEHEEYEM => &[
+                Abilities::TELEPATHY,
+                Abilities::SYNCHRONIZE,
+                Abilities::ANALYTIC,
+            ],
+            PokemonName::LITWICK => &[
+                Abilities::FLASHFIRE,
+                Abilities::FLAMEBODY,
+                Abilities::INFILTRATOR,
+            ],
+            PokemonName::LAMPENT => &[
+                Abilities::FLASHFIRE,
+                Abilities::FLAMEBODY,
+                Abilities::INFILTRATOR,
+            ],
+            PokemonName::CHANDELURE => &[
+                Abilities::FLASHFIRE,
+                Abilities::FLAMEBODY,
+                Abilities::INFILTRATOR,
+            ],
+            PokemonName::AXEW => &[
+                Abilities::RIVALRY,
+                Abilities::MOLDBREAKER,
+                Abilities::UNNERVE,
+            ],
+            PokemonName::FRAXURE => &[
+                Abilities::RIVALRY,
+                Abilities::MOLDBREAKER,
+                Abilities::UNNERVE,
+            ],
+            PokemonName::HAXORUS => &[
+                Abilities::RIVALRY,
+                Abilities::MOLDBREAKER,
+                Abilities::UNNERVE,
+            ],
+            PokemonName::CUBCHOO => &[
+                Abilities::SNOWCLOAK,
+                Abilities::SLUSHRUSH,
+                Abilities::RATTLED,
+            ],
+            PokemonName::BEARTIC => &[
+                Abilities::SNOWCLOAK,
+                Abilities::SLUSHRUSH,
+                Abilities::SWIFTSWIM,
+            ],
+            PokemonName::CRYOGONAL => &[Abilities::LEVITATE],
+            PokemonName::SHELMET => &[
+                Abilities::HYDRATION,
+                Abilities::SHELLARMOR,
+                Abilities::OVERCOAT,
+            ],
+            PokemonName::ACCELGOR => &[
+                Abilities::HYDRATION,
+                Abilities::STICKYHOLD,
+                Abilities::UNBURDEN,
+            ],
+            PokemonName::STUNFISK => &[Abilities::STATIC, Abilities::LIMBER, Abilities::SANDVEIL],
+            PokemonName::STUNFISKGALAR => &[Abilities::MIMICRY],
+            PokemonName::MIENFOO => &[
+                Abilities::INNERFOCUS,
+                Abilities::REGENERATOR,
+                Abilities::RECKLESS,
+            ],
+            PokemonName::MIENSHAO => &[
+                Abilities::INNERFOCUS,
+                Abilities::REGENERATOR,
+                Abilities::RECKLESS,
+            ],
+            PokemonName::DRUDDIGON => &[
+                Abilities::ROUGHSKIN,
+                Abilities::SHEERFORCE,
+                Abilities::MOLDBREAKER,
+            ],
+            PokemonName::GOLETT => &[Abilities::IRONFIST, Abilities::KLUTZ, Abilities::NOGUARD],
+            PokemonName::GOLURK => &[Abilities::IRONFIST, Abilities::KLUTZ, Abilities::NOGUARD],
+            PokemonName::PAWNIARD => &[
+                Abilities::DEFIANT,
+                Abilities::INNERFOCUS,
+                Abilities::PRESSURE,
+            ],
+            PokemonName::BISHARP => &[
+                Abilities::DEFIANT,
+                Abilities::INNERFOCUS,
+                Abilities::PRESSURE,
+            ],
+            PokemonName::BOUFFALANT => &[
+                Abilities::RECKLESS,
+                Abilities::SAPSIPPER,
+                Abilities::SOUNDPROOF,
+            ],
+            PokemonName::RUFFLET => &[Abilities::KEENEYE, Abilities::SHEERFORCE, Abilities::HUSTLE],
+            PokemonName::BRAVIARY => &[
+                Abilities::KEENEYE,
+                Abilities::SHEERFORCE,
+                Abilities::DEFIANT,
+            ],
+            PokemonName::BRAVIARYHISUI => &[
+                Abilities::KEENEYE,
+                Abilities::SHEERFORCE,
+                Abilities::TINTEDLENS,
+            ],
+            PokemonName::VULLABY => &[
+                Abilities::BIGPECKS,
+                Abilities::OVERCOAT,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::MANDIBUZZ => &[
+                Abilities::BIGPECKS,
+                Abilities::OVERCOAT,
+                Abilities::WEAKARMOR,
+            ],
+            PokemonName::HEATMOR => &[
+                Abilities::GLUTTONY,
+                Abilities::FLASHFIRE,
+                Abilities::WHITESMOKE,
+            ],
+            PokemonName::DURANT => &[Abilities::SWARM, Abilities::HUSTLE, Abilities::TRUANT],
+            PokemonName::DEINO => &[Abilities::HUSTLE],
+            PokemonName::ZWEILOUS => &[Abilities::HUSTLE],
+            PokemonName::HYDREIGON => &[Abilities::LEVITATE],
+            PokemonName::LARVESTA => &[Abilities::FLAMEBODY, Abilities::SWARM],
+            PokemonName::VOLCARONA => &[Abilities::FLAMEBODY, Abilities::SWARM],
+            PokemonName::COBALION => &[Abilities::JUSTIFIED],
+            PokemonName::TERRAKION => &[Abilities::JUSTIFIED],
+            PokemonName::VIRIZION => &[Abilities::JUSTIFIED],
+            PokemonName::TORNADUS => &[Abilities::PRANKSTER, Abilities::DEFIANT],
+            PokemonName::TORNADUSTHERIAN => &[Abilities::REGENERATOR],
+            PokemonName::THUNDURUS => &[Abilities::PRANKSTER, Abilities::DEFIANT],
+            PokemonName::THUNDURUSTHERIAN => &[Abilities::VOLTABSORB],
+            PokemonName::RESHIRAM => &[Abilities::TURBOBLAZE],
+            PokemonName::ZEKROM => &[Abilities::TERAVOLT],
+            PokemonName::LANDORUS => &[Abilities::SANDFORCE, Abilities::SHEERFORCE],
+            PokemonName::LANDORUSTHERIAN => &[Abilities::INTIMIDATE],
+            PokemonName::KYUREM => &[Abilities::PRESSURE],
+            PokemonName::KYUREMBLACK => &[Abilities::TERAVOLT],
+            PokemonName::KYUREMWHITE => &[Abilities::TURBOBLAZE],
+            PokemonName::KELDEO => &[Abilities::JUSTIFIED],
+            PokemonName::KELDEORESOLUTE => &[Abilities::JUSTIFIED],
+            PokemonName::MELOETTA => &[Abilities::SERENEGRACE],
+            PokemonName::MELOETTAPIROUETTE => &[Abilities::SERENEGRACE],
+            PokemonName::GENESECT => &[Abilities::DOWNLOAD],
+            PokemonName::GENESECTDOUSE => &[Abilities::DOWNLOAD],
+            PokemonName::GENESECTSHOCK => &[Abilities::DOWNLOAD],
+            PokemonName::GENESECTBURN => &[Abilities::DOWNLOAD],
+            PokemonName::GENESECTCHILL => &[Abilities::DOWNLOAD],
+            PokemonName::CHESPIN => &[Abilities::OVERGROW, Abilities::BULLETPROOF],
+            PokemonName::QUILLADIN => &[Abilities::OVERGROW, Abilities::BULLETPROOF],
+            PokemonName::CHESNAUGHT => &[Abilities::OVERGROW, Abilities::BULLETPROOF],
+            PokemonName::FENNEKIN => &[Abilities::BLAZE, Abilities::MAGICIAN],
+            PokemonName::BRAIXEN => &[Abilities::BLAZE, Abilities::MAGICIAN],
+            PokemonName::DELPHOX => &[Abilities::BLAZE, Abilities::MAGICIAN],
+            PokemonName::FROAKIE => &[Abilities::TORRENT, Abilities::PROTEAN],
+            PokemonName::FROGADIER => &[Abilities::TORRENT, Abilities::PROTEAN],
+            PokemonName::GRENINJA => &[
+                Abilities::TORRENT,
+                Abilities::PROTEAN,
+                Abilities::BATTLEBOND,
+            ],
+            PokemonName::GRENINJABOND => &[Abilities::BATTLEBOND],
+            PokemonName::GRENINJAASH => &[Abilities::BATTLEBOND],
+            PokemonName::BUNNELBY => &[
+                Abilities::PICKUP,
+                Abilities::CHEEKPOUCH,
+                Abilities::HUGEPOWER,
+            ],
+            PokemonName::DIGGERSBY => &[
+                Abilities::PICKUP,
+                Abilities::CHEEKPOUCH,
+                Abilities::HUGEPOWER,
+            ],
+            PokemonName::FLETCHLING => &[Abilities::BIGPECKS, Abilities::GALEWINGS],
+            PokemonName::FLETCHINDER => &[Abilities::FLAMEBODY, Abilities::GALEWINGS],
+            PokemonName::TALONFLAME => &[Abilities::FLAMEBODY, Abilities::GALEWINGS],
+            PokemonName::SCATTERBUG => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::SPEWPA => &[Abilities::SHEDSKIN, Abilities::FRIENDGUARD],
+            PokemonName::VIVILLON => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONARCHIPELAGO => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONCONTINENTAL => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONELEGANT => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONGARDEN => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONHIGHPLAINS => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONICYSNOW => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONJUNGLE => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONMARINE => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONMODERN => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONMONSOON => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONOCEAN => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONPOLAR => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONRIVER => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONSANDSTORM => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONSAVANNA => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONSUN => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONTUNDRA => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONFANCY => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::VIVILLONPOKEBALL => &[
+                Abilities::SHIELDDUST,
+                Abilities::COMPOUNDEYES,
+                Abilities::FRIENDGUARD,
+            ],
+            PokemonName::LITLEO => &[Abilities::RIVALRY, Abilities::UNNERVE, Abilities::MOXIE],
+            PokemonName::PYROAR => &[Abilities::RIVALRY, Abilities::UNNERVE, Abilities::MOXIE],
+            PokemonName::FLABEBE => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLABEBEBLUE => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLABEBEORANGE => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLABEBEWHITE => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLABEBEYELLOW => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLOETTE => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLOETTEBLUE => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLOETTEORANGE => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLOETTEWHITE => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLOETTEYELLOW => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLOETTEETERNAL => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLORGES => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLORGESBLUE => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLORGESORANGE => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLORGESWHITE => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::FLORGESYELLOW => &[Abilities::FLOWERVEIL, Abilities::SYMBIOSIS],
+            PokemonName::SKIDDO => &[Abilities::SAPSIPPER, Abilities::GRASSPELT],
+            PokemonName::GOGOAT => &[Abilities::SAPSIPPER, Abilities::GRASSPELT],
+            PokemonName::PANCHAM => &[
+                Abilities::IRONFIST,
+                Abilities::MOLDBREAKER,
+                Abilities::SCRAPPY,
+            ],
+            PokemonName::PANGORO => &[
+                Abilities::IRONFIST,
+                Abilities::MOLDBREAKER,
+                Abilities::SCRAPPY,
+            ],
+            PokemonName::FURFROU => &[Abilities::FURCOAT],
+            PokemonName::FURFROUDANDY => &[Abilities::FURCOAT],
+            PokemonName::FURFROUDEBUTANTE => &[Abilities::FURCOAT],
+            PokemonName::FURFROUDIAMOND => &[Abilities::FURCOAT],
+            PokemonName::FURFROUHEART => &[Abilities::FURCOAT],
+            PokemonName::FURFROUKABUKI => &[Abilities::FURCOAT],
+            PokemonName::FURFROULAREINE => &[Abilities::FURCOAT],
+            PokemonName::FURFROUMATRON => &[Abilities::FURCOAT],
+            PokemonName::FURFROUPHARAOH => &[Abilities::FURCOAT],
+            PokemonName::FURFROUSTAR => &[Abilities::FURCOAT],
+            PokemonName::ESPURR => &[
+                Abilities::KEENEYE,
+                Abilities::INFILTRATOR,
+                Abilities::OWNTEMPO,
+            ],
+            PokemonName::MEOWSTIC => &[
+                Abilities::KEENEYE,
+                Abilities::INFILTRATOR,
+                Abilities::PRANKSTER,
+            ],
+            PokemonName::MEOWSTICF => &[
+                Abilities::KEENEYE,
+                Abilities::INFILTRATOR,
+                Abilities::COMPETITIVE,
+            ],
+            PokemonName::HONEDGE => &[Abilities::NOGUARD],
+            PokemonName::DOUBLADE => &[Abilities::NOGUARD],
+            PokemonName::AEGISLASH => &[Abilities::STANCECHANGE],
+            PokemonName::AEGISLASHBLADE => &[Abilities::STANCECHANGE],
+            PokemonName::SPRITZEE => &[Abilities::HEALER, Abilities::AROMAVEIL],
+            PokemonName::AROMATISSE => &[Abilities::HEALER, Abilities::AROMAVEIL],
+            PokemonName::SWIRLIX => &[Abilities::SWEETVEIL, Abilities::UNBURDEN],
+            PokemonName::SLURPUFF => &[Abilities::SWEETVEIL, Abilities::UNBURDEN],
+            PokemonName::INKAY => &[
+                Abilities::CONTRARY,
+                Abilities::SUCTIONCUPS,
+                Abilities::INFILTRATOR,
+            ],
+            PokemonName::MALAMAR => &[
+                Abilities::CONTRARY,
+                Abilities::SUCTIONCUPS,
+                Abilities::INFILTRATOR,
+            ],
+            PokemonName::BINACLE => &[
+                Abilities::TOUGHCLAWS,
+                Abilities::SNIPER,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::BARBARACLE => &[
+                Abilities::TOUGHCLAWS,
+                Abilities::SNIPER,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::SKRELP => &[
+                Abilities::POISONPOINT,
+                Abilities::POISONTOUCH,
+                Abilities::ADAPTABILITY,
+            ],
+            PokemonName::DRAGALGE => &[
+                Abilities::POISONPOINT,
+                Abilities::POISONTOUCH,
+                Abilities::ADAPTABILITY,
+            ],
+            PokemonName::CLAUNCHER => &[Abilities::MEGALAUNCHER],
+            PokemonName::CLAWITZER => &[Abilities::MEGALAUNCHER],
+            PokemonName::HELIOPTILE => &[
+                Abilities::DRYSKIN,
+                Abilities::SANDVEIL,
+                Abilities::SOLARPOWER,
+            ],
+            PokemonName::HELIOLISK => &[
+                Abilities::DRYSKIN,
+                Abilities::SANDVEIL,
+                Abilities::SOLARPOWER,
+            ],
+            PokemonName::TYRUNT => &[Abilities::STRONGJAW, Abilities::STURDY],
+            PokemonName::TYRANTRUM => &[Abilities::STRONGJAW, Abilities::ROCKHEAD],
+            PokemonName::AMAURA => &[Abilities::REFRIGERATE, Abilities::SNOWWARNING],
+            PokemonName::AURORUS => &[Abilities::REFRIGERATE, Abilities::SNOWWARNING],
+            PokemonName::SYLVEON => &[Abilities::CUTECHARM, Abilities::PIXILATE],
+            PokemonName::HAWLUCHA => &[
+                Abilities::LIMBER,
+                Abilities::UNBURDEN,
+                Abilities::MOLDBREAKER,
+            ],
+            PokemonName::DEDENNE => &[Abilities::CHEEKPOUCH, Abilities::PICKUP, Abilities::PLUS],
+            PokemonName::CARBINK => &[Abilities::CLEARBODY, Abilities::STURDY],
+            PokemonName::GOOMY => &[Abilities::SAPSIPPER, Abilities::HYDRATION, Abilities::GOOEY],
+            PokemonName::SLIGGOO => &[Abilities::SAPSIPPER, Abilities::HYDRATION, Abilities::GOOEY],
+            PokemonName::SLIGGOOHISUI => &[
+                Abilities::SAPSIPPER,
+                Abilities::SHELLARMOR,
+                Abilities::GOOEY,
+            ],
+            PokemonName::GOODRA => &[Abilities::SAPSIPPER, Abilities::HYDRATION, Abilities::GOOEY],
+            PokemonName::GOODRAHISUI => &[
+                Abilities::SAPSIPPER,
+                Abilities::SHELLARMOR,
+                Abilities::GOOEY,
+            ],
+            PokemonName::KLEFKI => &[Abilities::PRANKSTER, Abilities::MAGICIAN],
+            PokemonName::PHANTUMP => {
+                &[Abilities::NATURALCURE, Abilities::FRISK, Abilities::HARVEST]
+            }
+            PokemonName::TREVENANT => {
+                &[Abilities::NATURALCURE, Abilities::FRISK, Abilities::HARVEST]
+            }
+            PokemonName::PUMPKABOO => &[Abilities::PICKUP, Abilities::FRISK, Abilities::INSOMNIA],
+            PokemonName::PUMPKABOOSMALL => {
+                &[Abilities::PICKUP, Abilities::FRISK, Abilities::INSOMNIA]
+            }
+            PokemonName::PUMPKABOOLARGE => {
+                &[Abilities::PICKUP, Abilities::FRISK, Abilities::INSOMNIA]
+            }
+            PokemonName::PUMPKABOOSUPER => {
+                &[Abilities::PICKUP, Abilities::FRISK, Abilities::INSOMNIA]
+            }
+            PokemonName::GOURGEIST => &[Abilities::PICKUP, Abilities::FRISK, Abilities::INSOMNIA],
+            PokemonName::GOURGEISTSMALL => {
+                &[Abilities::PICKUP, Abilities::FRISK, Abilities::INSOMNIA]
+            }
+            PokemonName::GOURGEISTLARGE => {
+                &[Abilities::PICKUP, Abilities::FRISK, Abilities::INSOMNIA]
+            }
+            PokemonName::GOURGEISTSUPER => {
+                &[Abilities::PICKUP, Abilities::FRISK, Abilities::INSOMNIA]
+            }
+            PokemonName::BERGMITE => &[Abilities::OWNTEMPO, Abilities::ICEBODY, Abilities::STURDY],
+            PokemonName::AVALUGG => &[Abilities::OWNTEMPO, Abilities::ICEBODY, Abilities::STURDY],
+            PokemonName::AVALUGGHISUI => {
+                &[Abilities::STRONGJAW, Abilities::ICEBODY, Abilities::STURDY]
+            }
+            PokemonName::NOIBAT => &[
+                Abilities::FRISK,
+                Abilities::INFILTRATOR,
+                Abilities::TELEPATHY,
+            ],
+            PokemonName::NOIVERN => &[
+                Abilities::FRISK,
+                Abilities::INFILTRATOR,
+                Abilities::TELEPATHY,
+            ],
+            PokemonName::XERNEAS => &[Abilities::FAIRYAURA],
+            PokemonName::XERNEASNEUTRAL => &[Abilities::FAIRYAURA],
+            PokemonName::YVELTAL => &[Abilities::DARKAURA],
+            PokemonName::ZYGARDE => &[Abilities::AURABREAK, Abilities::POWERCONSTRUCT],
+            PokemonName::ZYGARDE10 => &[Abilities::AURABREAK, Abilities::POWERCONSTRUCT],
+            PokemonName::ZYGARDECOMPLETE => &[Abilities::POWERCONSTRUCT],
+            PokemonName::DIANCIE => &[Abilities::CLEARBODY],
+            PokemonName::DIANCIEMEGA => &[Abilities::MAGICBOUNCE],
+            PokemonName::HOOPA => &[Abilities::MAGICIAN],
+            PokemonName::HOOPAUNBOUND => &[Abilities::MAGICIAN],
+            PokemonName::VOLCANION => &[Abilities::WATERABSORB],
+            PokemonName::ROWLET => &[Abilities::OVERGROW, Abilities::LONGREACH],
+            PokemonName::DARTRIX => &[Abilities::OVERGROW, Abilities::LONGREACH],
+            PokemonName::DECIDUEYE => &[Abilities::OVERGROW, Abilities::LONGREACH],
+            PokemonName::DECIDUEYEHISUI => &[Abilities::OVERGROW, Abilities::SCRAPPY],
+            PokemonName::LITTEN => &[Abilities::BLAZE, Abilities::INTIMIDATE],
+            PokemonName::TORRACAT => &[Abilities::BLAZE, Abilities::INTIMIDATE],
+            PokemonName::INCINEROAR => &[Abilities::BLAZE, Abilities::INTIMIDATE],
+            PokemonName::POPPLIO => &[Abilities::TORRENT, Abilities::LIQUIDVOICE],
+            PokemonName::BRIONNE => &[Abilities::TORRENT, Abilities::LIQUIDVOICE],
+            PokemonName::PRIMARINA => &[Abilities::TORRENT, Abilities::LIQUIDVOICE],
+            PokemonName::PIKIPEK => &[Abilities::KEENEYE, Abilities::SKILLLINK, Abilities::PICKUP],
+            PokemonName::TRUMBEAK => &[Abilities::KEENEYE, Abilities::SKILLLINK, Abilities::PICKUP],
+            PokemonName::TOUCANNON => &[
+                Abilities::KEENEYE,
+                Abilities::SKILLLINK,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::YUNGOOS => &[
+                Abilities::STAKEOUT,
+                Abilities::STRONGJAW,
+                Abilities::ADAPTABILITY,
+            ],
+            PokemonName::GUMSHOOS => &[
+                Abilities::STAKEOUT,
+                Abilities::STRONGJAW,
+                Abilities::ADAPTABILITY,
+            ],
+            PokemonName::GUMSHOOSTOTEM => &[Abilities::ADAPTABILITY],
+            PokemonName::GRUBBIN => &[Abilities::SWARM],
+            PokemonName::CHARJABUG => &[Abilities::BATTERY],
+            PokemonName::VIKAVOLT => &[Abilities::LEVITATE],
+            PokemonName::VIKAVOLTTOTEM => &[Abilities::LEVITATE],
+            PokemonName::CRABRAWLER => &[
+                Abilities::HYPERCUTTER,
+                Abilities::IRONFIST,
+                Abilities::ANGERPOINT,
+            ],
+            PokemonName::CRABOMINABLE => &[
+                Abilities::HYPERCUTTER,
+                Abilities::IRONFIST,
+                Abilities::ANGERPOINT,
+            ],
+            PokemonName::ORICORIO => &[Abilities::DANCER],
+            PokemonName::ORICORIOPOMPOM => &[Abilities::DANCER],
+            PokemonName::ORICORIOPAU => &[Abilities::DANCER],
+            PokemonName::ORICORIOSENSU => &[Abilities::DANCER],
+            PokemonName::CUTIEFLY => &[
+                Abilities::HONEYGATHER,
+                Abilities::SHIELDDUST,
+                Abilities::SWEETVEIL,
+            ],
+            PokemonName::RIBOMBEE => &[
+                Abilities::HONEYGATHER,
+                Abilities::SHIELDDUST,
+                Abilities::SWEETVEIL,
+            ],
+            PokemonName::RIBOMBEETOTEM => &[Abilities::SWEETVEIL],
+            PokemonName::ROCKRUFF => &[
+                Abilities::KEENEYE,
+                Abilities::VITALSPIRIT,
+                Abilities::STEADFAST,
+                Abilities::OWNTEMPO,
+            ],
+            PokemonName::LYCANROC => &[
+                Abilities::KEENEYE,
+                Abilities::SANDRUSH,
+                Abilities::STEADFAST,
+            ],
+            PokemonName::LYCANROCMIDNIGHT => &[
+                Abilities::KEENEYE,
+                Abilities::VITALSPIRIT,
+                Abilities::NOGUARD,
+            ],
+            PokemonName::LYCANROCDUSK => &[Abilities::TOUGHCLAWS],
+            PokemonName::WISHIWASHI => &[Abilities::SCHOOLING],
+            PokemonName::WISHIWASHISCHOOL => &[Abilities::SCHOOLING],
+            PokemonName::MAREANIE => &[
+                Abilities::MERCILESS,
+                Abilities::LIMBER,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::TOXAPEX => &[
+                Abilities::MERCILESS,
+                Abilities::LIMBER,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::MUDBRAY => &[
+                Abilities::OWNTEMPO,
+                Abilities::STAMINA,
+                Abilities::INNERFOCUS,
+            ],
+            PokemonName::MUDSDALE => &[
+                Abilities::OWNTEMPO,
+                Abilities::STAMINA,
+                Abilities::INNERFOCUS,
+            ],
+            PokemonName::DEWPIDER => &[Abilities::WATERBUBBLE, Abilities::WATERABSORB],
+            PokemonName::ARAQUANID => &[Abilities::WATERBUBBLE, Abilities::WATERABSORB],
+            PokemonName::ARAQUANIDTOTEM => &[Abilities::WATERBUBBLE],
+            PokemonName::FOMANTIS => &[Abilities::LEAFGUARD, Abilities::CONTRARY],
+            PokemonName::LURANTIS => &[Abilities::LEAFGUARD, Abilities::CONTRARY],
+            PokemonName::LURANTISTOTEM => &[Abilities::CONTRARY],
+            PokemonName::MORELULL => &[
+                Abilities::ILLUMINATE,
+                Abilities::EFFECTSPORE,
+                Abilities::RAINDISH,
+            ],
+            PokemonName::SHIINOTIC => &[
+                Abilities::ILLUMINATE,
+                Abilities::EFFECTSPORE,
+                Abilities::RAINDISH,
+            ],
+            PokemonName::SALANDIT => &[Abilities::CORROSION, Abilities::OBLIVIOUS],
+            PokemonName::SALAZZLE => &[Abilities::CORROSION, Abilities::OBLIVIOUS],
+            PokemonName::SALAZZLETOTEM => &[Abilities::CORROSION],
+            PokemonName::STUFFUL => &[Abilities::FLUFFY, Abilities::KLUTZ, Abilities::CUTECHARM],
+            PokemonName::BEWEAR => &[Abilities::FLUFFY, Abilities::KLUTZ, Abilities::UNNERVE],
+            PokemonName::BOUNSWEET => &[
+                Abilities::LEAFGUARD,
+                Abilities::OBLIVIOUS,
+                Abilities::SWEETVEIL,
+            ],
+            PokemonName::STEENEE => &[
+                Abilities::LEAFGUARD,
+                Abilities::OBLIVIOUS,
+                Abilities::SWEETVEIL,
+            ],
+            PokemonName::TSAREENA => &[
+                Abilities::LEAFGUARD,
+                Abilities::QUEENLYMAJESTY,
+                Abilities::SWEETVEIL,
+            ],
+            PokemonName::COMFEY => &[
+                Abilities::FLOWERVEIL,
+                Abilities::TRIAGE,
+                Abilities::NATURALCURE,
+            ],
+            PokemonName::ORANGURU => &[
+                Abilities::INNERFOCUS,
+                Abilities::TELEPATHY,
+                Abilities::SYMBIOSIS,
+            ],
+            PokemonName::PASSIMIAN => &[Abilities::RECEIVER, Abilities::DEFIANT],
+            PokemonName::WIMPOD => &[Abilities::WIMPOUT],
+            PokemonName::GOLISOPOD => &[Abilities::EMERGENCYEXIT],
+            PokemonName::SANDYGAST => &[Abilities::WATERCOMPACTION, Abilities::SANDVEIL],
+            PokemonName::PALOSSAND => &[Abilities::WATERCOMPACTION, Abilities::SANDVEIL],
+            PokemonName::PYUKUMUKU => &[Abilities::INNARDSOUT, Abilities::UNAWARE],
+            PokemonName::TYPENULL => &[Abilities::BATTLEARMOR],
+            PokemonName::SILVALLY => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYBUG => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYDARK => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYDRAGON => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYELECTRIC => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYFAIRY => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYFIGHTING => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYFIRE => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYFLYING => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYGHOST => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYGRASS => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYGROUND => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYICE => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYPOISON => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYPSYCHIC => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYROCK => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYSTEEL => &[Abilities::RKSSYSTEM],
+            PokemonName::SILVALLYWATER => &[Abilities::RKSSYSTEM],
+            PokemonName::MINIOR => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORORANGE => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORYELLOW => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORGREEN => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORBLUE => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORINDIGO => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORVIOLET => &[Abilities::SHIELDSDOWN],
+            PokemonName::MINIORMETEOR => &[Abilities::SHIELDSDOWN],
+            PokemonName::KOMALA => &[Abilities::COMATOSE],
+            PokemonName::TURTONATOR => &[Abilities::SHELLARMOR],
+            PokemonName::TOGEDEMARU => &[
+                Abilities::IRONBARBS,
+                Abilities::LIGHTNINGROD,
+                Abilities::STURDY,
+            ],
+            PokemonName::TOGEDEMARUTOTEM => &[Abilities::STURDY],
+            PokemonName::MIMIKYU => &[Abilities::DISGUISE],
+            PokemonName::MIMIKYUBUSTED => &[Abilities::DISGUISE],
+            PokemonName::MIMIKYUTOTEM => &[Abilities::DISGUISE],
+            PokemonName::MIMIKYUBUSTEDTOTEM => &[Abilities::DISGUISE],
+            PokemonName::BRUXISH => &[
+                Abilities::DAZZLING,
+                Abilities::STRONGJAW,
+                Abilities::WONDERSKIN,
+            ],
+            PokemonName::DRAMPA => &[
+                Abilities::BERSERK,
+                Abilities::SAPSIPPER,
+                Abilities::CLOUDNINE,
+            ],
+            PokemonName::DHELMISE => &[Abilities::STEELWORKER],
+            PokemonName::JANGMOO => &[
+                Abilities::BULLETPROOF,
+                Abilities::SOUNDPROOF,
+                Abilities::OVERCOAT,
+            ],
+            PokemonName::HAKAMOO => &[
+                Abilities::BULLETPROOF,
+                Abilities::SOUNDPROOF,
+                Abilities::OVERCOAT,
+            ],
+            PokemonName::KOMMOO => &[
+                Abilities::BULLETPROOF,
+                Abilities::SOUNDPROOF,
+                Abilities::OVERCOAT,
+            ],
+            PokemonName::KOMMOOTOTEM => &[Abilities::OVERCOAT],
+            PokemonName::TAPUKOKO => &[Abilities::ELECTRICSURGE, Abilities::TELEPATHY],
+            PokemonName::TAPULELE => &[Abilities::PSYCHICSURGE, Abilities::TELEPATHY],
+            PokemonName::TAPUBULU => &[Abilities::GRASSYSURGE, Abilities::TELEPATHY],
+            PokemonName::TAPUFINI => &[Abilities::MISTYSURGE, Abilities::TELEPATHY],
+            PokemonName::COSMOG => &[Abilities::UNAWARE],
+            PokemonName::COSMOEM => &[Abilities::STURDY],
+            PokemonName::SOLGALEO => &[Abilities::FULLMETALBODY],
+            PokemonName::LUNALA => &[Abilities::SHADOWSHIELD],
+            PokemonName::NIHILEGO => &[Abilities::BEASTBOOST],
+            PokemonName::BUZZWOLE => &[Abilities::BEASTBOOST],
+            PokemonName::PHEROMOSA => &[Abilities::BEASTBOOST],
+            PokemonName::XURKITREE => &[Abilities::BEASTBOOST],
+            PokemonName::CELESTEELA => &[Abilities::BEASTBOOST],
+            PokemonName::KARTANA => &[Abilities::BEASTBOOST],
+            PokemonName::GUZZLORD => &[Abilities::BEASTBOOST],
+            PokemonName::NECROZMA => &[Abilities::PRISMARMOR],
+            PokemonName::NECROZMADUSKMANE => &[Abilities::PRISMARMOR],
+            PokemonName::NECROZMADAWNWINGS => &[Abilities::PRISMARMOR],
+            PokemonName::NECROZMAULTRA => &[Abilities::NEUROFORCE],
+            PokemonName::MAGEARNA => &[Abilities::SOULHEART],
+            PokemonName::MAGEARNAORIGINAL => &[Abilities::SOULHEART],
+            PokemonName::MARSHADOW => &[Abilities::TECHNICIAN],
+            PokemonName::POIPOLE => &[Abilities::BEASTBOOST],
+            PokemonName::NAGANADEL => &[Abilities::BEASTBOOST],
+            PokemonName::STAKATAKA => &[Abilities::BEASTBOOST],
+            PokemonName::BLACEPHALON => &[Abilities::BEASTBOOST],
+            PokemonName::ZERAORA => &[Abilities::VOLTABSORB],
+            PokemonName::MELTAN => &[Abilities::MAGNETPULL],
+            PokemonName::MELMETAL => &[Abilities::IRONFIST],
+            PokemonName::MELMETALGMAX => &[Abilities::IRONFIST],
+            PokemonName::GROOKEY => &[Abilities::OVERGROW, Abilities::GRASSYSURGE],
+            PokemonName::THWACKEY => &[Abilities::OVERGROW, Abilities::GRASSYSURGE],
+            PokemonName::RILLABOOM => &[Abilities::OVERGROW, Abilities::GRASSYSURGE],
+            PokemonName::RILLABOOMGMAX => &[Abilities::OVERGROW, Abilities::GRASSYSURGE],
+            PokemonName::SCORBUNNY => &[Abilities::BLAZE, Abilities::LIBERO],
+            PokemonName::RABOOT => &[Abilities::BLAZE, Abilities::LIBERO],
+            PokemonName::CINDERACE => &[Abilities::BLAZE, Abilities::LIBERO],
+            PokemonName::CINDERACEGMAX => &[Abilities::BLAZE, Abilities::LIBERO],
+            PokemonName::SOBBLE => &[Abilities::TORRENT, Abilities::SNIPER],
+            PokemonName::DRIZZILE => &[Abilities::TORRENT, Abilities::SNIPER],
+            PokemonName::INTELEON => &[Abilities::TORRENT, Abilities::SNIPER],
+            PokemonName::INTELEONGMAX => &[Abilities::TORRENT, Abilities::SNIPER],
+            PokemonName::SKWOVET => &[Abilities::CHEEKPOUCH, Abilities::GLUTTONY],
+            PokemonName::GREEDENT => &[Abilities::CHEEKPOUCH, Abilities::GLUTTONY],
+            PokemonName::ROOKIDEE => &[Abilities::KEENEYE, Abilities::UNNERVE, Abilities::BIGPECKS],
+            PokemonName::CORVISQUIRE => {
+                &[Abilities::KEENEYE, Abilities::UNNERVE, Abilities::BIGPECKS]
+            }
+            PokemonName::CORVIKNIGHT => &[
+                Abilities::PRESSURE,
+                Abilities::UNNERVE,
+                Abilities::MIRRORARMOR,
+            ],
+            PokemonName::CORVIKNIGHTGMAX => &[
+                Abilities::PRESSURE,
+                Abilities::UNNERVE,
+                Abilities::MIRRORARMOR,
+            ],
+            PokemonName::BLIPBUG => &[
+                Abilities::SWARM,
+                Abilities::COMPOUNDEYES,
+                Abilities::TELEPATHY,
+            ],
+            PokemonName::DOTTLER => &[
+                Abilities::SWARM,
+                Abilities::COMPOUNDEYES,
+                Abilities::TELEPATHY,
+            ],
+            PokemonName::ORBEETLE => &[Abilities::SWARM, Abilities::FRISK, Abilities::TELEPATHY],
+            PokemonName::ORBEETLEGMAX => {
+                &[Abilities::SWARM, Abilities::FRISK, Abilities::TELEPATHY]
+            }
+            PokemonName::NICKIT => &[Abilities::RUNAWAY, Abilities::UNBURDEN, Abilities::STAKEOUT],
+            PokemonName::THIEVUL => &[Abilities::RUNAWAY, Abilities::UNBURDEN, Abilities::STAKEOUT],
+            PokemonName::GOSSIFLEUR => &[
+                Abilities::COTTONDOWN,
+                Abilities::REGENERATOR,
+                Abilities::EFFECTSPORE,
+            ],
+            PokemonName::ELDEGOSS => &[
+                Abilities::COTTONDOWN,
+                Abilities::REGENERATOR,
+                Abilities::EFFECTSPORE,
+            ],
+            PokemonName::WOOLOO => &[
+                Abilities::FLUFFY,
+                Abilities::RUNAWAY,
+                Abilities::BULLETPROOF,
+            ],
+            PokemonName::DUBWOOL => &[
+                Abilities::FLUFFY,
+                Abilities::STEADFAST,
+                Abilities::BULLETPROOF,
+            ],
+            PokemonName::CHEWTLE => &[
+                Abilities::STRONGJAW,
+                Abilities::SHELLARMOR,
+                Abilities::SWIFTSWIM,
+            ],
+            PokemonName::DREDNAW => &[
+                Abilities::STRONGJAW,
+                Abilities::SHELLARMOR,
+                Abilities::SWIFTSWIM,
+            ],
+            PokemonName::DREDNAWGMAX => &[
+                Abilities::STRONGJAW,
+                Abilities::SHELLARMOR,
+                Abilities::SWIFTSWIM,
+            ],
+            PokemonName::YAMPER => &[Abilities::BALLFETCH, Abilities::RATTLED],
+            PokemonName::BOLTUND => &[Abilities::STRONGJAW, Abilities::COMPETITIVE],
+            PokemonName::ROLYCOLY => &[
+                Abilities::STEAMENGINE,
+                Abilities::HEATPROOF,
+                Abilities::FLASHFIRE,
+            ],
+            PokemonName::CARKOL => &[
+                Abilities::STEAMENGINE,
+                Abilities::FLAMEBODY,
+                Abilities::FLASHFIRE,
+            ],
+            PokemonName::COALOSSAL => &[
+                Abilities::STEAMENGINE,
+                Abilities::FLAMEBODY,
+                Abilities::FLASHFIRE,
+            ],
+            PokemonName::COALOSSALGMAX => &[
+                Abilities::STEAMENGINE,
+                Abilities::FLAMEBODY,
+                Abilities::FLASHFIRE,
+            ],
+            PokemonName::APPLIN => &[
+                Abilities::RIPEN,
+                Abilities::GLUTTONY,
+                Abilities::BULLETPROOF,
+            ],
+            PokemonName::FLAPPLE => &[Abilities::RIPEN, Abilities::GLUTTONY, Abilities::HUSTLE],
+            PokemonName::FLAPPLEGMAX => &[Abilities::RIPEN, Abilities::GLUTTONY, Abilities::HUSTLE],
+            PokemonName::APPLETUN => &[Abilities::RIPEN, Abilities::GLUTTONY, Abilities::THICKFAT],
+            PokemonName::APPLETUNGMAX => {
+                &[Abilities::RIPEN, Abilities::GLUTTONY, Abilities::THICKFAT]
+            }
+            PokemonName::SILICOBRA => &[
+                Abilities::SANDSPIT,
+                Abilities::SHEDSKIN,
+                Abilities::SANDVEIL,
+            ],
+            PokemonName::SANDACONDA => &[
+                Abilities::SANDSPIT,
+                Abilities::SHEDSKIN,
+                Abilities::SANDVEIL,
+            ],
+            PokemonName::SANDACONDAGMAX => &[
+                Abilities::SANDSPIT,
+                Abilities::SHEDSKIN,
+                Abilities::SANDVEIL,
+            ],
+            PokemonName::CRAMORANT => &[Abilities::GULPMISSILE],
+            PokemonName::CRAMORANTGULPING => &[Abilities::GULPMISSILE],
+            PokemonName::CRAMORANTGORGING => &[Abilities::GULPMISSILE],
+            PokemonName::ARROKUDA => &[Abilities::SWIFTSWIM, Abilities::PROPELLERTAIL],
+            PokemonName::BARRASKEWDA => &[Abilities::SWIFTSWIM, Abilities::PROPELLERTAIL],
+            PokemonName::TOXEL => &[Abilities::RATTLED, Abilities::STATIC, Abilities::KLUTZ],
+            PokemonName::TOXTRICITY => {
+                &[Abilities::PUNKROCK, Abilities::PLUS, Abilities::TECHNICIAN]
+            }
+            PokemonName::TOXTRICITYLOWKEY => {
+                &[Abilities::PUNKROCK, Abilities::MINUS, Abilities::TECHNICIAN]
+            }
+            PokemonName::TOXTRICITYGMAX => {
+                &[Abilities::PUNKROCK, Abilities::PLUS, Abilities::TECHNICIAN]
+            }
+            PokemonName::TOXTRICITYLOWKEYGMAX => {
+                &[Abilities::PUNKROCK, Abilities::MINUS, Abilities::TECHNICIAN]
+            }
+            PokemonName::SIZZLIPEDE => &[
+                Abilities::FLASHFIRE,
+                Abilities::WHITESMOKE,
+                Abilities::FLAMEBODY,
+            ],
+            PokemonName::CENTISKORCH => &[
+                Abilities::FLASHFIRE,
+                Abilities::WHITESMOKE,
+                Abilities::FLAMEBODY,
+            ],
+            PokemonName::CENTISKORCHGMAX => &[
+                Abilities::FLASHFIRE,
+                Abilities::WHITESMOKE,
+                Abilities::FLAMEBODY,
+            ],
+            PokemonName::CLOBBOPUS => &[Abilities::LIMBER, Abilities::TECHNICIAN],
+            PokemonName::GRAPPLOCT => &[Abilities::LIMBER, Abilities::TECHNICIAN],
+            PokemonName::SINISTEA => &[Abilities::WEAKARMOR, Abilities::CURSEDBODY],
+            PokemonName::SINISTEAANTIQUE => &[Abilities::WEAKARMOR, Abilities::CURSEDBODY],
+            PokemonName::POLTEAGEIST => &[Abilities::WEAKARMOR, Abilities::CURSEDBODY],
+            PokemonName::POLTEAGEISTANTIQUE => &[Abilities::WEAKARMOR, Abilities::CURSEDBODY],
+            PokemonName::HATENNA => &[
+                Abilities::HEALER,
+                Abilities::ANTICIPATION,
+                Abilities::MAGICBOUNCE,
+            ],
+            PokemonName::HATTREM => &[
+                Abilities::HEALER,
+                Abilities::ANTICIPATION,
+                Abilities::MAGICBOUNCE,
+            ],
+            PokemonName::HATTERENE => &[
+                Abilities::HEALER,
+                Abilities::ANTICIPATION,
+                Abilities::MAGICBOUNCE,
+            ],
+            PokemonName::HATTERENEGMAX => &[
+                Abilities::HEALER,
+                Abilities::ANTICIPATION,
+                Abilities::MAGICBOUNCE,
+            ],
+            PokemonName::IMPIDIMP => &[
+                Abilities::PRANKSTER,
+                Abilities::FRISK,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::MORGREM => &[
+                Abilities::PRANKSTER,
+                Abilities::FRISK,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::GRIMMSNARL => &[
+                Abilities::PRANKSTER,
+                Abilities::FRISK,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::GRIMMSNARLGMAX => &[
+                Abilities::PRANKSTER,
+                Abilities::FRISK,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::OBSTAGOON => &[Abilities::RECKLESS, Abilities::GUTS, Abilities::DEFIANT],
+            PokemonName::PERRSERKER => &[
+                Abilities::BATTLEARMOR,
+                Abilities::TOUGHCLAWS,
+                Abilities::STEELYSPIRIT,
+            ],
+            PokemonName::CURSOLA => &[Abilities::WEAKARMOR, Abilities::PERISHBODY],
+            PokemonName::SIRFETCHD => &[Abilities::STEADFAST, Abilities::SCRAPPY],
+            PokemonName::MRRIME => &[
+                Abilities::TANGLEDFEET,
+                Abilities::SCREENCLEANER,
+                Abilities::ICEBODY,
+            ],
+            PokemonName::RUNERIGUS => &[Abilities::WANDERINGSPIRIT],
+            PokemonName::MILCERY => &[Abilities::SWEETVEIL, Abilities::AROMAVEIL],
+            PokemonName::ALCREMIE => &[Abilities::SWEETVEIL, Abilities::AROMAVEIL],
+            PokemonName::ALCREMIERUBYCREAM => &[Abilities::SWEETVEIL, Abilities::AROMAVEIL],
+            PokemonName::ALCREMIEMATCHACREAM => &[Abilities::SWEETVEIL, Abilities::AROMAVEIL],
+            PokemonName::ALCREMIEMINTCREAM => &[Abilities::SWEETVEIL, Abilities::AROMAVEIL],
+            PokemonName::ALCREMIELEMONCREAM => &[Abilities::SWEETVEIL, Abilities::AROMAVEIL],
+            PokemonName::ALCREMIESALTEDCREAM => &[Abilities::SWEETVEIL, Abilities::AROMAVEIL],
+            PokemonName::ALCREMIERUBYSWIRL => &[Abilities::SWEETVEIL, Abilities::AROMAVEIL],
+            PokemonName::ALCREMIECARAMELSWIRL => &[Abilities::SWEETVEIL, Abilities::AROMAVEIL],
+            PokemonName::ALCREMIERAINBOWSWIRL => &[Abilities::SWEETVEIL, Abilities::AROMAVEIL],
+            PokemonName::ALCREMIEGMAX => &[Abilities::SWEETVEIL, Abilities::AROMAVEIL],
+            PokemonName::FALINKS => &[Abilities::BATTLEARMOR, Abilities::DEFIANT],
+            PokemonName::PINCURCHIN => &[Abilities::LIGHTNINGROD, Abilities::ELECTRICSURGE],
+            PokemonName::SNOM => &[Abilities::SHIELDDUST, Abilities::ICESCALES],
+            PokemonName::FROSMOTH => &[Abilities::SHIELDDUST, Abilities::ICESCALES],
+            PokemonName::STONJOURNER => &[Abilities::POWERSPOT],
+            PokemonName::EISCUE => &[Abilities::ICEFACE],
+            PokemonName::EISCUENOICE => &[Abilities::ICEFACE],
+            PokemonName::INDEEDEE => &[
+                Abilities::INNERFOCUS,
+                Abilities::SYNCHRONIZE,
+                Abilities::PSYCHICSURGE,
+            ],
+            PokemonName::INDEEDEEF => &[
+                Abilities::OWNTEMPO,
+                Abilities::SYNCHRONIZE,
+                Abilities::PSYCHICSURGE,
+            ],
+            PokemonName::MORPEKO => &[Abilities::HUNGERSWITCH],
+            PokemonName::MORPEKOHANGRY => &[Abilities::HUNGERSWITCH],
+            PokemonName::CUFANT => &[Abilities::SHEERFORCE, Abilities::HEAVYMETAL],
+            PokemonName::COPPERAJAH => &[Abilities::SHEERFORCE, Abilities::HEAVYMETAL],
+            PokemonName::COPPERAJAHGMAX => &[Abilities::SHEERFORCE, Abilities::HEAVYMETAL],
+            PokemonName::DRACOZOLT => &[
+                Abilities::VOLTABSORB,
+                Abilities::HUSTLE,
+                Abilities::SANDRUSH,
+            ],
+            PokemonName::ARCTOZOLT => &[
+                Abilities::VOLTABSORB,
+                Abilities::STATIC,
+                Abilities::SLUSHRUSH,
+            ],
+            PokemonName::DRACOVISH => &[
+                Abilities::WATERABSORB,
+                Abilities::STRONGJAW,
+                Abilities::SANDRUSH,
+            ],
+            PokemonName::ARCTOVISH => &[
+                Abilities::WATERABSORB,
+                Abilities::ICEBODY,
+                Abilities::SLUSHRUSH,
+            ],
+            PokemonName::DURALUDON => &[
+                Abilities::LIGHTMETAL,
+                Abilities::HEAVYMETAL,
+                Abilities::STALWART,
+            ],
+            PokemonName::DURALUDONGMAX => &[
+                Abilities::LIGHTMETAL,
+                Abilities::HEAVYMETAL,
+                Abilities::STALWART,
+            ],
+            PokemonName::DREEPY => &[
+                Abilities::CLEARBODY,
+                Abilities::INFILTRATOR,
+                Abilities::CURSEDBODY,
+            ],
+            PokemonName::DRAKLOAK => &[
+                Abilities::CLEARBODY,
+                Abilities::INFILTRATOR,
+                Abilities::CURSEDBODY,
+            ],
+            PokemonName::DRAGAPULT => &[
+                Abilities::CLEARBODY,
+                Abilities::INFILTRATOR,
+                Abilities::CURSEDBODY,
+            ],
+            PokemonName::ZACIAN => &[Abilities::INTREPIDSWORD],
+            PokemonName::ZACIANCROWNED => &[Abilities::INTREPIDSWORD],
+            PokemonName::ZAMAZENTA => &[Abilities::DAUNTLESSSHIELD],
+            PokemonName::ZAMAZENTACROWNED => &[Abilities::DAUNTLESSSHIELD],
+            PokemonName::ETERNATUS => &[Abilities::PRESSURE],
+            PokemonName::ETERNATUSETERNAMAX => &[Abilities::PRESSURE],
+            PokemonName::KUBFU => &[Abilities::INNERFOCUS],
+            PokemonName::URSHIFU => &[Abilities::UNSEENFIST],
+            PokemonName::URSHIFURAPIDSTRIKE => &[Abilities::UNSEENFIST],
+            PokemonName::URSHIFUGMAX => &[Abilities::UNSEENFIST],
+            PokemonName::URSHIFURAPIDSTRIKEGMAX => &[Abilities::UNSEENFIST],
+            PokemonName::ZARUDE => &[Abilities::LEAFGUARD],
+            PokemonName::ZARUDEDADA => &[Abilities::LEAFGUARD],
+            PokemonName::REGIELEKI => &[Abilities::TRANSISTOR],
+            PokemonName::REGIDRAGO => &[Abilities::DRAGONSMAW],
+            PokemonName::GLASTRIER => &[Abilities::CHILLINGNEIGH],
+            PokemonName::SPECTRIER => &[Abilities::GRIMNEIGH],
+            PokemonName::CALYREX => &[Abilities::UNNERVE],
+            PokemonName::CALYREXICE => &[Abilities::ASONEGLASTRIER],
+            PokemonName::CALYREXSHADOW => &[Abilities::ASONESPECTRIER],
+            PokemonName::WYRDEER => &[
+                Abilities::INTIMIDATE,
+                Abilities::FRISK,
+                Abilities::SAPSIPPER,
+            ],
+            PokemonName::KLEAVOR => &[
+                Abilities::SWARM,
+                Abilities::SHEERFORCE,
+                Abilities::SHARPNESS,
+            ],
+            PokemonName::URSALUNA => &[Abilities::GUTS, Abilities::BULLETPROOF, Abilities::UNNERVE],
+            PokemonName::URSALUNABLOODMOON => &[Abilities::MINDSEYE],
+            PokemonName::BASCULEGION => &[
+                Abilities::SWIFTSWIM,
+                Abilities::ADAPTABILITY,
+                Abilities::MOLDBREAKER,
+            ],
+            PokemonName::BASCULEGIONF => &[
+                Abilities::SWIFTSWIM,
+                Abilities::ADAPTABILITY,
+                Abilities::MOLDBREAKER,
+            ],
+            PokemonName::SNEASLER => &[
+                Abilities::PRESSURE,
+                Abilities::UNBURDEN,
+                Abilities::POISONTOUCH,
+            ],
+            PokemonName::OVERQWIL => &[
+                Abilities::POISONPOINT,
+                Abilities::SWIFTSWIM,
+                Abilities::INTIMIDATE,
+            ],
+            PokemonName::ENAMORUS => &[Abilities::CUTECHARM, Abilities::CONTRARY],
+            PokemonName::ENAMORUSTHERIAN => &[Abilities::OVERCOAT],
+            PokemonName::SPRIGATITO => &[Abilities::OVERGROW, Abilities::PROTEAN],
+            PokemonName::FLORAGATO => &[Abilities::OVERGROW, Abilities::PROTEAN],
+            PokemonName::MEOWSCARADA => &[Abilities::OVERGROW, Abilities::PROTEAN],
+            PokemonName::FUECOCO => &[Abilities::BLAZE, Abilities::UNAWARE],
+            PokemonName::CROCALOR => &[Abilities::BLAZE, Abilities::UNAWARE],
+            PokemonName::SKELEDIRGE => &[Abilities::BLAZE, Abilities::UNAWARE],
+            PokemonName::QUAXLY => &[Abilities::TORRENT, Abilities::MOXIE],
+            PokemonName::QUAXWELL => &[Abilities::TORRENT, Abilities::MOXIE],
+            PokemonName::QUAQUAVAL => &[Abilities::TORRENT, Abilities::MOXIE],
+            PokemonName::LECHONK => &[
+                Abilities::AROMAVEIL,
+                Abilities::GLUTTONY,
+                Abilities::THICKFAT,
+            ],
+            PokemonName::OINKOLOGNE => &[
+                Abilities::LINGERINGAROMA,
+                Abilities::GLUTTONY,
+                Abilities::THICKFAT,
+            ],
+            PokemonName::OINKOLOGNEF => &[
+                Abilities::AROMAVEIL,
+                Abilities::GLUTTONY,
+                Abilities::THICKFAT,
+            ],
+            PokemonName::TAROUNTULA => &[Abilities::INSOMNIA, Abilities::STAKEOUT],
+            PokemonName::SPIDOPS => &[Abilities::INSOMNIA, Abilities::STAKEOUT],
+            PokemonName::NYMBLE => &[Abilities::SWARM, Abilities::TINTEDLENS],
+            PokemonName::LOKIX => &[Abilities::SWARM, Abilities::TINTEDLENS],
+            PokemonName::PAWMI => &[
+                Abilities::STATIC,
+                Abilities::NATURALCURE,
+                Abilities::IRONFIST,
+            ],
+            PokemonName::PAWMO => &[
+                Abilities::VOLTABSORB,
+                Abilities::NATURALCURE,
+                Abilities::IRONFIST,
+            ],
+            PokemonName::PAWMOT => &[
+                Abilities::VOLTABSORB,
+                Abilities::NATURALCURE,
+                Abilities::IRONFIST,
+            ],
+            PokemonName::TANDEMAUS => &[Abilities::RUNAWAY, Abilities::PICKUP, Abilities::OWNTEMPO],
+            PokemonName::MAUSHOLD => &[
+                Abilities::FRIENDGUARD,
+                Abilities::CHEEKPOUCH,
+                Abilities::TECHNICIAN,
+            ],
+            PokemonName::MAUSHOLDFOUR => &[
+                Abilities::FRIENDGUARD,
+                Abilities::CHEEKPOUCH,
+                Abilities::TECHNICIAN,
+            ],
+            PokemonName::FIDOUGH => &[Abilities::OWNTEMPO, Abilities::KLUTZ],
+            PokemonName::DACHSBUN => &[Abilities::WELLBAKEDBODY, Abilities::AROMAVEIL],
+            PokemonName::SMOLIV => &[Abilities::EARLYBIRD, Abilities::HARVEST],
+            PokemonName::DOLLIV => &[Abilities::EARLYBIRD, Abilities::HARVEST],
+            PokemonName::ARBOLIVA => &[Abilities::SEEDSOWER, Abilities::HARVEST],
+            PokemonName::SQUAWKABILLY => {
+                &[Abilities::INTIMIDATE, Abilities::HUSTLE, Abilities::GUTS]
+            }
+            PokemonName::SQUAWKABILLYBLUE => {
+                &[Abilities::INTIMIDATE, Abilities::HUSTLE, Abilities::GUTS]
+            }
+            PokemonName::SQUAWKABILLYYELLOW => &[
+                Abilities::INTIMIDATE,
+                Abilities::HUSTLE,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::SQUAWKABILLYWHITE => &[
+                Abilities::INTIMIDATE,
+                Abilities::HUSTLE,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::NACLI => &[
+                Abilities::PURIFYINGSALT,
+                Abilities::STURDY,
+                Abilities::CLEARBODY,
+            ],
+            PokemonName::NACLSTACK => &[
+                Abilities::PURIFYINGSALT,
+                Abilities::STURDY,
+                Abilities::CLEARBODY,
+            ],
+            PokemonName::GARGANACL => &[
+                Abilities::PURIFYINGSALT,
+                Abilities::STURDY,
+                Abilities::CLEARBODY,
+            ],
+            PokemonName::CHARCADET => &[Abilities::FLASHFIRE, Abilities::FLAMEBODY],
+            PokemonName::ARMAROUGE => &[Abilities::FLASHFIRE, Abilities::WEAKARMOR],
+            PokemonName::CERULEDGE => &[Abilities::FLASHFIRE, Abilities::WEAKARMOR],
+            PokemonName::TADBULB => &[Abilities::OWNTEMPO, Abilities::STATIC, Abilities::DAMP],
+            PokemonName::BELLIBOLT => &[
+                Abilities::ELECTROMORPHOSIS,
+                Abilities::STATIC,
+                Abilities::DAMP,
+            ],
+            PokemonName::WATTREL => &[
+                Abilities::WINDPOWER,
+                Abilities::VOLTABSORB,
+                Abilities::COMPETITIVE,
+            ],
+            PokemonName::KILOWATTREL => &[
+                Abilities::WINDPOWER,
+                Abilities::VOLTABSORB,
+                Abilities::COMPETITIVE,
+            ],
+            PokemonName::MASCHIFF => &[
+                Abilities::INTIMIDATE,
+                Abilities::RUNAWAY,
+                Abilities::STAKEOUT,
+            ],
+            PokemonName::MABOSSTIFF => &[
+                Abilities::INTIMIDATE,
+                Abilities::GUARDDOG,
+                Abilities::STAKEOUT,
+            ],
+            PokemonName::SHROODLE => &[
+                Abilities::UNBURDEN,
+                Abilities::PICKPOCKET,
+                Abilities::PRANKSTER,
+            ],
+            PokemonName::GRAFAIAI => &[
+                Abilities::UNBURDEN,
+                Abilities::POISONTOUCH,
+                Abilities::PRANKSTER,
+            ],
+            PokemonName::BRAMBLIN => &[Abilities::WINDRIDER, Abilities::INFILTRATOR],
+            PokemonName::BRAMBLEGHAST => &[Abilities::WINDRIDER, Abilities::INFILTRATOR],
+            PokemonName::TOEDSCOOL => &[Abilities::MYCELIUMMIGHT],
+            PokemonName::TOEDSCRUEL => &[Abilities::MYCELIUMMIGHT],
+            PokemonName::KLAWF => &[
+                Abilities::ANGERSHELL,
+                Abilities::SHELLARMOR,
+                Abilities::REGENERATOR,
+            ],
+            PokemonName::CAPSAKID => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::INSOMNIA,
+                Abilities::KLUTZ,
+            ],
+            PokemonName::SCOVILLAIN => &[
+                Abilities::CHLOROPHYLL,
+                Abilities::INSOMNIA,
+                Abilities::MOODY,
+            ],
+            PokemonName::RELLOR => &[Abilities::COMPOUNDEYES, Abilities::SHEDSKIN],
+            PokemonName::RABSCA => &[Abilities::SYNCHRONIZE, Abilities::TELEPATHY],
+            PokemonName::FLITTLE => &[
+                Abilities::ANTICIPATION,
+                Abilities::FRISK,
+                Abilities::SPEEDBOOST,
+            ],
+            PokemonName::ESPATHRA => &[
+                Abilities::OPPORTUNIST,
+                Abilities::FRISK,
+                Abilities::SPEEDBOOST,
+            ],
+            PokemonName::TINKATINK => &[
+                Abilities::MOLDBREAKER,
+                Abilities::OWNTEMPO,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::TINKATUFF => &[
+                Abilities::MOLDBREAKER,
+                Abilities::OWNTEMPO,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::TINKATON => &[
+                Abilities::MOLDBREAKER,
+                Abilities::OWNTEMPO,
+                Abilities::PICKPOCKET,
+            ],
+            PokemonName::WIGLETT => &[Abilities::GOOEY, Abilities::RATTLED, Abilities::SANDVEIL],
+            PokemonName::WUGTRIO => &[Abilities::GOOEY, Abilities::RATTLED, Abilities::SANDVEIL],
+            PokemonName::BOMBIRDIER => &[
+                Abilities::BIGPECKS,
+                Abilities::KEENEYE,
+                Abilities::ROCKYPAYLOAD,
+            ],
+            PokemonName::FINIZEN => &[Abilities::WATERVEIL],
+            PokemonName::PALAFIN => &[Abilities::ZEROTOHERO],
+            PokemonName::PALAFINHERO => &[Abilities::ZEROTOHERO],
+            PokemonName::VAROOM => &[Abilities::OVERCOAT, Abilities::SLOWSTART],
+            PokemonName::REVAVROOM => &[Abilities::OVERCOAT, Abilities::FILTER],
+            PokemonName::CYCLIZAR => &[Abilities::SHEDSKIN, Abilities::REGENERATOR],
+            PokemonName::ORTHWORM => &[Abilities::EARTHEATER, Abilities::SANDVEIL],
+            PokemonName::GLIMMET => &[Abilities::TOXICDEBRIS, Abilities::CORROSION],
+            PokemonName::GLIMMORA => &[Abilities::TOXICDEBRIS, Abilities::CORROSION],
+            PokemonName::GREAVARD => &[Abilities::PICKUP, Abilities::FRISK],
+            PokemonName::HOUNDSTONE => &[Abilities::SANDRUSH, Abilities::FRISK],
+            PokemonName::FLAMIGO => &[
+                Abilities::SCRAPPY,
+                Abilities::TANGLEDFEET,
+                Abilities::COSTAR,
+            ],
+            PokemonName::CETODDLE => &[
+                Abilities::THICKFAT,
+                Abilities::SNOWCLOAK,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::CETITAN => &[
+                Abilities::THICKFAT,
+                Abilities::SLUSHRUSH,
+                Abilities::SHEERFORCE,
+            ],
+            PokemonName::VELUZA => &[Abilities::MOLDBREAKER, Abilities::SHARPNESS],
+            PokemonName::DONDOZO => &[
+                Abilities::UNAWARE,
+                Abilities::OBLIVIOUS,
+                Abilities::WATERVEIL,
+            ],
+            PokemonName::TATSUGIRI => &[Abilities::COMMANDER, Abilities::STORMDRAIN],
+            PokemonName::TATSUGIRIDROOPY => &[Abilities::COMMANDER, Abilities::STORMDRAIN],
+            PokemonName::TATSUGIRISTRETCHY => &[Abilities::COMMANDER, Abilities::STORMDRAIN],
+            PokemonName::ANNIHILAPE => &[
+                Abilities::VITALSPIRIT,
+                Abilities::INNERFOCUS,
+                Abilities::DEFIANT,
+            ],
+            PokemonName::CLODSIRE => &[
+                Abilities::POISONPOINT,
+                Abilities::WATERABSORB,
+                Abilities::UNAWARE,
+            ],
+            PokemonName::FARIGIRAF => &[
+                Abilities::CUDCHEW,
+                Abilities::ARMORTAIL,
+                Abilities::SAPSIPPER,
+            ],
+            PokemonName::DUDUNSPARCE => &[
+                Abilities::SERENEGRACE,
+                Abilities::RUNAWAY,
+                Abilities::RATTLED,
+            ],
+            PokemonName::DUDUNSPARCETHREESEGMENT => &[
+                Abilities::SERENEGRACE,
+                Abilities::RUNAWAY,
+                Abilities::RATTLED,
+            ],
+            PokemonName::KINGAMBIT => &[
+                Abilities::DEFIANT,
+                Abilities::SUPREMEOVERLORD,
+                Abilities::PRESSURE,
+            ],
+            PokemonName::GREATTUSK => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::SCREAMTAIL => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::BRUTEBONNET => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::FLUTTERMANE => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::SLITHERWING => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::SANDYSHOCKS => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::IRONTREADS => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONBUNDLE => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONHANDS => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONJUGULIS => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONMOTH => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONTHORNS => &[Abilities::QUARKDRIVE],
+            PokemonName::FRIGIBAX => &[Abilities::THERMALEXCHANGE, Abilities::ICEBODY],
+            PokemonName::ARCTIBAX => &[Abilities::THERMALEXCHANGE, Abilities::ICEBODY],
+            PokemonName::BAXCALIBUR => &[Abilities::THERMALEXCHANGE, Abilities::ICEBODY],
+            PokemonName::GIMMIGHOUL => &[Abilities::RATTLED],
+            PokemonName::GIMMIGHOULROAMING => &[Abilities::RUNAWAY],
+            PokemonName::GHOLDENGO => &[Abilities::GOODASGOLD],
+            PokemonName::WOCHIEN => &[Abilities::TABLETSOFRUIN],
+            PokemonName::CHIENPAO => &[Abilities::SWORDOFRUIN],
+            PokemonName::TINGLU => &[Abilities::VESSELOFRUIN],
+            PokemonName::CHIYU => &[Abilities::BEADSOFRUIN],
+            PokemonName::ROARINGMOON => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::IRONVALIANT => &[Abilities::QUARKDRIVE],
+            PokemonName::KORAIDON => &[Abilities::ORICHALCUMPULSE],
+            PokemonName::MIRAIDON => &[Abilities::HADRONENGINE],
+            PokemonName::WALKINGWAKE => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::IRONLEAVES => &[Abilities::QUARKDRIVE],
+            PokemonName::DIPPLIN => &[
+                Abilities::SUPERSWEETSYRUP,
+                Abilities::GLUTTONY,
+                Abilities::STICKYHOLD,
+            ],
+            PokemonName::POLTCHAGEIST => &[Abilities::HOSPITALITY, Abilities::HEATPROOF],
+            PokemonName::POLTCHAGEISTARTISAN => &[Abilities::HOSPITALITY, Abilities::HEATPROOF],
+            PokemonName::SINISTCHA => &[Abilities::HOSPITALITY, Abilities::HEATPROOF],
+            PokemonName::SINISTCHAMASTERPIECE => &[Abilities::HOSPITALITY, Abilities::HEATPROOF],
+            PokemonName::OKIDOGI => &[Abilities::TOXICCHAIN, Abilities::GUARDDOG],
+            PokemonName::MUNKIDORI => &[Abilities::TOXICCHAIN, Abilities::FRISK],
+            PokemonName::FEZANDIPITI => &[Abilities::TOXICCHAIN, Abilities::TECHNICIAN],
+            PokemonName::OGERPON => &[Abilities::DEFIANT],
+            PokemonName::OGERPONWELLSPRING => &[Abilities::WATERABSORB],
+            PokemonName::OGERPONHEARTHFLAME => &[Abilities::MOLDBREAKER],
+            PokemonName::OGERPONCORNERSTONE => &[Abilities::STURDY],
+            PokemonName::OGERPONTEALTERA => &[Abilities::EMBODYASPECTTEAL],
+            PokemonName::OGERPONWELLSPRINGTERA => &[Abilities::EMBODYASPECTWELLSPRING],
+            PokemonName::OGERPONHEARTHFLAMETERA => &[Abilities::EMBODYASPECTHEARTHFLAME],
+            PokemonName::OGERPONCORNERSTONETERA => &[Abilities::EMBODYASPECTCORNERSTONE],
+            PokemonName::ARCHALUDON => {
+                &[Abilities::STAMINA, Abilities::STURDY, Abilities::STALWART]
+            }
+            PokemonName::HYDRAPPLE => &[
+                Abilities::SUPERSWEETSYRUP,
+                Abilities::REGENERATOR,
+                Abilities::STICKYHOLD,
+            ],
+            PokemonName::GOUGINGFIRE => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::RAGINGBOLT => &[Abilities::PROTOSYNTHESIS],
+            PokemonName::IRONBOULDER => &[Abilities::QUARKDRIVE],
+            PokemonName::IRONCROWN => &[Abilities::QUARKDRIVE],
+            PokemonName::TERAPAGOS => &[Abilities::TERASHIFT],
+            PokemonName::TERAPAGOSTERASTAL => &[Abilities::TERASHELL],
+            PokemonName::TERAPAGOSSTELLAR => &[Abilities::TERAFORMZERO],
+            PokemonName::PECHARUNT => &[Abilities::POISONPUPPETEER],
+            PokemonName::DRAGONITEMEGA => &[Abilities::MULTISCALE],
+            PokemonName::CLEFABLEMEGA => &[Abilities::MAGICBOUNCE],
+            PokemonName::MEGANIUMMEGA => &[Abilities::MEGASOL],
+            PokemonName::FERALIGATRMEGA => &[Abilities::DRAGONIZE],
+            PokemonName::EMBOARMEGA => &[Abilities::MOLDBREAKER],
+            PokemonName::CHESNAUGHTMEGA => &[Abilities::BULLETPROOF],
+            PokemonName::DELPHOXMEGA => &[Abilities::LEVITATE],
+            PokemonName::GRENINJAMEGA => &[Abilities::PROTEAN],
+            PokemonName::CRABOMINABLEMEGA => &[Abilities::IRONFIST],
+            PokemonName::GOLURKMEGA => &[Abilities::UNSEENFIST],
+            PokemonName::SCOVILLAINMEGA => &[Abilities::SPICYSPRAY],
+            PokemonName::GLIMMORAMEGA => &[Abilities::ADAPTABILITY],
+            PokemonName::FLOETTEMEGA => &[Abilities::FAIRYAURA],
+            PokemonName::VICTREEBELMEGA => &[Abilities::INNARDSOUT],
+            PokemonName::STARMIEMEGA => &[Abilities::HUGEPOWER],
+            PokemonName::HAWLUCHAMEGA => &[Abilities::NOGUARD],
+            PokemonName::SKARMORYMEGA => &[Abilities::STALWART],
+            PokemonName::MEOWSTICMEGA => &[Abilities::TRACE],
+            PokemonName::FROSLASSMEGA => &[Abilities::SNOWWARNING],
+            PokemonName::EXCADRILLMEGA => &[Abilities::PIERCINGDRILL],
+            PokemonName::DRAMPAMEGA => &[Abilities::BERSERK],
+            PokemonName::CHIMECHOMEGA => &[Abilities::LEVITATE],
+            PokemonName::CHANDELUREMEGA => &[Abilities::INFILTRATOR],
+        }
+    }
 }
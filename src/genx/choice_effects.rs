@@ -15,8 +15,8 @@ use crate::instruction::{
 };
 use crate::pokemon::PokemonName;
 use crate::state::{
-    pokemon_index_iter, LastUsedMove, PokemonBoostableStat, PokemonSideCondition, PokemonStatus,
-    PokemonType, Side, SideReference, State,
+    pokemon_index_iter, Generation, LastUsedMove, PokemonBoostableStat, PokemonSideCondition,
+    PokemonStatus, PokemonType, Side, SideReference, State,
 };
 use std::cmp;
 
@@ -250,8 +250,7 @@ pub fn modify_choice(
                 attacker_choice.base_power *= 1.5;
             }
         }
-        #[cfg(any(feature = "gen3", feature = "gen4"))]
-        Choices::EXPLOSION | Choices::SELFDESTRUCT => {
+        Choices::EXPLOSION | Choices::SELFDESTRUCT if state.generation <= Generation::GEN4 => {
             attacker_choice.base_power *= 2.0;
         }
 
@@ -333,8 +332,8 @@ pub fn modify_choice(
                 },
             });
             if defending_side.attack_boost != -6 {
-                let defender_attack =
-                    defending_side.calculate_boosted_stat(PokemonBoostableStat::Attack);
+                let defender_attack = defending_side
+                    .calculate_boosted_stat(state.generation, PokemonBoostableStat::Attack);
                 let attacker_maxhp = attacking_side.get_active_immutable().maxhp;
 
                 if defending_side.get_active_immutable().ability == Abilities::LIQUIDOOZE {
@@ -354,8 +353,12 @@ pub fn modify_choice(
             let active = attacking_side.get_active_immutable();
             if active.terastallized {
                 attacker_choice.move_type = active.tera_type;
-                if attacking_side.calculate_boosted_stat(PokemonBoostableStat::Attack)
-                    > attacking_side.calculate_boosted_stat(PokemonBoostableStat::SpecialAttack)
+                if attacking_side
+                    .calculate_boosted_stat(state.generation, PokemonBoostableStat::Attack)
+                    > attacking_side.calculate_boosted_stat(
+                        state.generation,
+                        PokemonBoostableStat::SpecialAttack,
+                    )
                 {
                     attacker_choice.category = MoveCategory::Physical;
                 }
@@ -376,8 +379,9 @@ pub fn modify_choice(
             }
         }
         Choices::PHOTONGEYSER => {
-            if attacking_side.calculate_boosted_stat(PokemonBoostableStat::Attack)
-                > attacking_side.calculate_boosted_stat(PokemonBoostableStat::SpecialAttack)
+            if attacking_side.calculate_boosted_stat(state.generation, PokemonBoostableStat::Attack)
+                > attacking_side
+                    .calculate_boosted_stat(state.generation, PokemonBoostableStat::SpecialAttack)
             {
                 attacker_choice.category = MoveCategory::Physical;
             }
@@ -455,8 +459,7 @@ pub fn modify_choice(
             }
         }
 
-        #[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
-        Choices::KNOCKOFF => {
+        Choices::KNOCKOFF if state.generation >= Generation::GEN6 => {
             // Bonus damage still applies if substitute is hit
             let defender = defending_side.get_active_immutable();
             if !defender.item_is_permanent() && defender.item != Items::NONE {
@@ -479,8 +482,10 @@ pub fn modify_choice(
             }
         }
         Choices::ELECTROBALL => {
-            let attacker_speed = attacking_side.calculate_boosted_stat(PokemonBoostableStat::Speed);
-            let defender_speed = defending_side.calculate_boosted_stat(PokemonBoostableStat::Speed);
+            let attacker_speed = attacking_side
+                .calculate_boosted_stat(state.generation, PokemonBoostableStat::Speed);
+            let defender_speed = defending_side
+                .calculate_boosted_stat(state.generation, PokemonBoostableStat::Speed);
             let speed_ratio = attacker_speed as f32 / defender_speed as f32;
             if speed_ratio >= 4.0 {
                 attacker_choice.base_power = 150.0;
@@ -495,8 +500,10 @@ pub fn modify_choice(
             }
         }
         Choices::GYROBALL => {
-            let attacker_speed = attacking_side.calculate_boosted_stat(PokemonBoostableStat::Speed);
-            let defender_speed = defending_side.calculate_boosted_stat(PokemonBoostableStat::Speed);
+            let attacker_speed = attacking_side
+                .calculate_boosted_stat(state.generation, PokemonBoostableStat::Speed);
+            let defender_speed = defending_side
+                .calculate_boosted_stat(state.generation, PokemonBoostableStat::Speed);
 
             attacker_choice.base_power =
                 ((25.0 * defender_speed as f32 / attacker_speed as f32) + 1.0).min(150.0);
@@ -507,21 +514,13 @@ pub fn modify_choice(
             }
         }
 
-        #[cfg(any(feature = "gen3", feature = "gen4"))]
-        Choices::PAYBACK => {
+        Choices::PAYBACK if state.generation <= Generation::GEN4 => {
             if !attacker_choice.first_move {
                 attacker_choice.base_power *= 2.0;
             }
         }
 
-        #[cfg(any(
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
-        Choices::PAYBACK => {
+        Choices::PAYBACK if state.generation >= Generation::GEN5 => {
             if !attacker_choice.first_move && defender_choice.category != MoveCategory::Switch {
                 attacker_choice.base_power *= 2.0;
             }
@@ -577,7 +576,12 @@ pub fn modify_choice(
         }
         Choices::COLLISIONCOURSE | Choices::ELECTRODRIFT => {
             let defender_active = defending_side.get_active_immutable();
-            if type_effectiveness_modifier(&attacker_choice.move_type, &defender_active) > 1.0 {
+            if type_effectiveness_modifier(
+                state.generation,
+                &attacker_choice.move_type,
+                &defender_active,
+            ) > 1.0
+            {
                 attacker_choice.base_power *= 1.3;
             }
         }
@@ -828,45 +832,20 @@ pub fn choice_after_damage_hit(
     }
 }
 
-#[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
 fn destinybond_before_move(
+    generation: Generation,
     attacking_side: &mut Side,
     attacking_side_ref: &SideReference,
-    choice: &Choice,
+    choice: &mut Choice,
     instructions: &mut StateInstructions,
 ) {
     // gens 2-6 destinybond is only removed if you are not using destinybond
     // destinybond is preserved, even if used twice in a row
-    if choice.move_id != Choices::DESTINYBOND
-        && attacking_side
-            .volatile_statuses
-            .contains(&PokemonVolatileStatus::DESTINYBOND)
-    {
-        instructions
-            .instruction_list
-            .push(Instruction::RemoveVolatileStatus(
-                RemoveVolatileStatusInstruction {
-                    side_ref: *attacking_side_ref,
-                    volatile_status: PokemonVolatileStatus::DESTINYBOND,
-                },
-            ));
-        attacking_side
-            .volatile_statuses
-            .remove(&PokemonVolatileStatus::DESTINYBOND);
-    }
-}
-
-#[cfg(any(feature = "gen7", feature = "gen8", feature = "gen9"))]
-fn destinybond_before_move(
-    attacking_side: &mut Side,
-    attacking_side_ref: &SideReference,
-    choice: &mut Choice,
-    instructions: &mut StateInstructions,
-) {
     // gens 7+ destinybond cannot be used if destinybond is active
     if attacking_side
         .volatile_statuses
         .contains(&PokemonVolatileStatus::DESTINYBOND)
+        && (generation >= Generation::GEN7 || choice.move_id != Choices::DESTINYBOND)
     {
         instructions
             .instruction_list
@@ -891,9 +870,16 @@ pub fn choice_before_move(
     attacking_side_ref: &SideReference,
     instructions: &mut StateInstructions,
 ) {
+    let generation = state.generation;
     let (attacking_side, defending_side) = state.get_both_sides(attacking_side_ref);
 
-    destinybond_before_move(attacking_side, attacking_side_ref, choice, instructions);
+    destinybond_before_move(
+        generation,
+        attacking_side,
+        attacking_side_ref,
+        choice,
+        instructions,
+    );
 
     if attacking_side.get_active_immutable().status == PokemonStatus::FREEZE
         && CHOICE_THAWS_USER.contains(&choice.move_id)
@@ -1216,6 +1202,7 @@ pub fn choice_special_effect(
     attacking_side_ref: &SideReference,
     instructions: &mut StateInstructions,
 ) {
+    let generation = state.generation;
     let (attacking_side, defending_side) = state.get_both_sides(attacking_side_ref);
     match choice.move_id {
         Choices::BELLYDRUM => {
@@ -1398,7 +1385,8 @@ pub fn choice_special_effect(
                 return;
             }
             if choice.move_id == Choices::SUPERFANG
-                && type_effectiveness_modifier(&PokemonType::NORMAL, &target_pkmn) == 0.0
+                && type_effectiveness_modifier(generation, &PokemonType::NORMAL, &target_pkmn)
+                    == 0.0
             {
                 return;
             }
@@ -1415,7 +1403,8 @@ pub fn choice_special_effect(
             let (attacking_side, defending_side) = state.get_both_sides(attacking_side_ref);
             let attacker_level = attacking_side.get_active_immutable().level;
             let defender_active = defending_side.get_active();
-            if type_effectiveness_modifier(&PokemonType::GHOST, &defender_active) == 0.0 {
+            if type_effectiveness_modifier(generation, &PokemonType::GHOST, &defender_active) == 0.0
+            {
                 return;
             }
 
@@ -1432,7 +1421,9 @@ pub fn choice_special_effect(
             let (attacking_side, defending_side) = state.get_both_sides(attacking_side_ref);
             let attacker_level = attacking_side.get_active_immutable().level;
             let defender_active = defending_side.get_active();
-            if type_effectiveness_modifier(&PokemonType::NORMAL, &defender_active) == 0.0 {
+            if type_effectiveness_modifier(generation, &PokemonType::NORMAL, &defender_active)
+                == 0.0
+            {
                 return;
             }
 
@@ -1450,7 +1441,7 @@ pub fn choice_special_effect(
             let attacker = attacking_side.get_active();
             let defender = defending_side.get_active();
 
-            if type_effectiveness_modifier(&PokemonType::NORMAL, &defender) == 0.0
+            if type_effectiveness_modifier(generation, &PokemonType::NORMAL, &defender) == 0.0
                 || attacker.hp >= defender.hp
             {
                 return;
@@ -1470,7 +1461,7 @@ pub fn choice_special_effect(
             let attacker = attacking_side.get_active();
             let defender = defending_side.get_active();
 
-            if type_effectiveness_modifier(&PokemonType::NORMAL, &defender) == 0.0 {
+            if type_effectiveness_modifier(generation, &PokemonType::NORMAL, &defender) == 0.0 {
                 return;
             }
 
@@ -1,14 +1,13 @@
 use super::abilities::Abilities;
 use super::state::{PokemonVolatileStatus, Terrain, Weather};
+use crate::choices::{moves, Choices};
 use crate::choices::{Choice, MoveCategory};
-use crate::choices::{Choices, MOVES};
 use crate::state::{
-    Pokemon, PokemonBoostableStat, PokemonIndex, PokemonStatus, PokemonType, Side, SideReference,
-    State,
+    Generation, Pokemon, PokemonBoostableStat, PokemonIndex, PokemonStatus, PokemonType, Side,
+    SideReference, State,
 };
 
 #[rustfmt::skip]
-#[cfg(any(feature = "gen9",feature = "gen8",feature = "gen7",feature = "gen6"))]
 const TYPE_MATCHUP_DAMAGE_MULTIPICATION: [[f32; 19]; 19] = [
 /*         0    1    2    3    4    5    6    7    8    9   10   11   12   13   14   15   16   17   18  */
 /*  0 */ [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 0.0, 1.0, 1.0, 0.5, 1.0, 1.0],
@@ -32,9 +31,9 @@ const TYPE_MATCHUP_DAMAGE_MULTIPICATION: [[f32; 19]; 19] = [
 /* 18 */ [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0]
 ];
 
+// gen3-5: no fairy type, and steel resists ghost and dark
 #[rustfmt::skip]
-#[cfg(any(feature = "gen5",feature = "gen4", feature = "gen3"))]
-const TYPE_MATCHUP_DAMAGE_MULTIPICATION: [[f32; 19]; 19] = [
+const TYPE_MATCHUP_DAMAGE_MULTIPICATION_GEN5: [[f32; 19]; 19] = [
 /*         0    1    2    3    4    5    6    7    8    9   10   11   12   13   14   15   16   17   18  */
 /*  0 */ [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 0.0, 1.0, 1.0, 0.5, 1.0, 1.0],
 /*  1 */ [1.0, 0.5, 0.5, 1.0, 2.0, 2.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 0.5, 1.0, 0.5, 1.0, 2.0, 1.0, 1.0],
@@ -57,11 +56,21 @@ const TYPE_MATCHUP_DAMAGE_MULTIPICATION: [[f32; 19]; 19] = [
 /* 18 */ [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0]
 ];
 
-#[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5"))]
-pub const CRIT_MULTIPLIER: f32 = 2.0;
+pub fn crit_multiplier(generation: Generation) -> f32 {
+    if generation >= Generation::GEN6 {
+        1.5
+    } else {
+        2.0
+    }
+}
 
-#[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
-pub const CRIT_MULTIPLIER: f32 = 1.5;
+fn type_matchup_damage_multiplication(generation: Generation) -> &'static [[f32; 19]; 19] {
+    if generation >= Generation::GEN6 {
+        &TYPE_MATCHUP_DAMAGE_MULTIPICATION
+    } else {
+        &TYPE_MATCHUP_DAMAGE_MULTIPICATION_GEN5
+    }
+}
 
 #[allow(dead_code)]
 pub enum DamageRolls {
@@ -95,30 +104,31 @@ fn type_enum_to_type_matchup_int(type_enum: &PokemonType) -> usize {
     }
 }
 
-pub fn type_effectiveness_modifier(attacking_type: &PokemonType, defender: &Pokemon) -> f32 {
-    #[cfg(not(feature = "terastallization"))]
-    let defending_types = defender.types;
-    #[cfg(feature = "terastallization")]
+pub fn type_effectiveness_modifier(
+    generation: Generation,
+    attacking_type: &PokemonType,
+    defender: &Pokemon,
+) -> f32 {
     let defending_types = if defender.terastallized {
         (defender.tera_type, PokemonType::TYPELESS)
     } else {
         defender.types
     };
-    _type_effectiveness_modifier(attacking_type, &defending_types)
+    _type_effectiveness_modifier(generation, attacking_type, &defending_types)
 }
 
 fn _type_effectiveness_modifier(
+    generation: Generation,
     attacking_type: &PokemonType,
     defending_types: &(PokemonType, PokemonType),
 ) -> f32 {
+    let type_matchups = type_matchup_damage_multiplication(generation);
     let mut modifier = 1.0;
     let attacking_type_index = type_enum_to_type_matchup_int(attacking_type);
     modifier = modifier
-        * TYPE_MATCHUP_DAMAGE_MULTIPICATION[attacking_type_index]
-            [type_enum_to_type_matchup_int(&defending_types.0)];
+        * type_matchups[attacking_type_index][type_enum_to_type_matchup_int(&defending_types.0)];
     modifier = modifier
-        * TYPE_MATCHUP_DAMAGE_MULTIPICATION[attacking_type_index]
-            [type_enum_to_type_matchup_int(&defending_types.1)];
+        * type_matchups[attacking_type_index][type_enum_to_type_matchup_int(&defending_types.1)];
     modifier
 }
 
@@ -182,16 +192,17 @@ fn burn_modifier(
 }
 
 fn terrain_modifier(
+    generation: Generation,
     terrain: &Terrain,
     attacker: &Pokemon,
     defender: &Pokemon,
     choice: &Choice,
 ) -> f32 {
-    #[cfg(any(feature = "gen9", feature = "gen8"))]
-    let terrain_boost = 1.3;
-
-    #[cfg(not(any(feature = "gen9", feature = "gen8")))]
-    let terrain_boost = 1.5;
+    let terrain_boost = if generation >= Generation::GEN8 {
+        1.3
+    } else {
+        1.5
+    };
 
     match terrain {
         Terrain::ELECTRICTERRAIN => {
@@ -349,14 +360,14 @@ fn get_attacking_and_defending_stats(
     match choice.category {
         MoveCategory::Physical => {
             if attacking_side.attack_boost > 0 {
-                crit_attacking_stat =
-                    attacking_side.calculate_boosted_stat(PokemonBoostableStat::Attack);
+                crit_attacking_stat = attacking_side
+                    .calculate_boosted_stat(state.generation, PokemonBoostableStat::Attack);
             } else {
                 crit_attacking_stat = attacker.attack;
             }
             if defending_side.defense_boost <= 0 {
-                crit_defending_stat =
-                    defending_side.calculate_boosted_stat(PokemonBoostableStat::Defense);
+                crit_defending_stat = defending_side
+                    .calculate_boosted_stat(state.generation, PokemonBoostableStat::Defense);
             } else {
                 crit_defending_stat = defender.defense;
             }
@@ -376,23 +387,23 @@ fn get_attacking_and_defending_stats(
             // checks for moves that change which stat is used for the attacking_stat
             if choice.move_id == Choices::FOULPLAY {
                 if should_calc_attacker_boost {
-                    attacking_final_stat =
-                        defending_side.calculate_boosted_stat(PokemonBoostableStat::Attack);
+                    attacking_final_stat = defending_side
+                        .calculate_boosted_stat(state.generation, PokemonBoostableStat::Attack);
                 } else {
                     attacking_final_stat = defender.attack;
                 }
                 crit_attacking_stat = defending_side.get_active_immutable().attack;
             } else if choice.move_id == Choices::BODYPRESS {
                 if should_calc_attacker_boost {
-                    attacking_final_stat =
-                        attacking_side.calculate_boosted_stat(PokemonBoostableStat::Defense);
+                    attacking_final_stat = attacking_side
+                        .calculate_boosted_stat(state.generation, PokemonBoostableStat::Defense);
                 } else {
                     attacking_final_stat = attacker.defense;
                 }
                 crit_attacking_stat = attacking_side.get_active_immutable().defense;
             } else if should_calc_attacker_boost {
-                attacking_final_stat =
-                    attacking_side.calculate_boosted_stat(PokemonBoostableStat::Attack);
+                attacking_final_stat = attacking_side
+                    .calculate_boosted_stat(state.generation, PokemonBoostableStat::Attack);
             } else {
                 attacking_final_stat = attacker.attack;
             }
@@ -400,22 +411,22 @@ fn get_attacking_and_defending_stats(
             // Get the defending stat
             defending_stat = PokemonBoostableStat::Defense;
             if should_calc_defender_boost {
-                defending_final_stat =
-                    defending_side.calculate_boosted_stat(PokemonBoostableStat::Defense);
+                defending_final_stat = defending_side
+                    .calculate_boosted_stat(state.generation, PokemonBoostableStat::Defense);
             } else {
                 defending_final_stat = defender.defense;
             }
         }
         MoveCategory::Special => {
             if attacking_side.special_attack_boost > 0 {
-                crit_attacking_stat =
-                    attacking_side.calculate_boosted_stat(PokemonBoostableStat::SpecialAttack);
+                crit_attacking_stat = attacking_side
+                    .calculate_boosted_stat(state.generation, PokemonBoostableStat::SpecialAttack);
             } else {
                 crit_attacking_stat = attacker.special_attack;
             }
             if defending_side.special_defense_boost <= 0 {
-                crit_defending_stat =
-                    defending_side.calculate_boosted_stat(PokemonBoostableStat::SpecialDefense);
+                crit_defending_stat = defending_side
+                    .calculate_boosted_stat(state.generation, PokemonBoostableStat::SpecialDefense);
             } else {
                 crit_defending_stat = defender.special_defense;
             }
@@ -432,8 +443,8 @@ fn get_attacking_and_defending_stats(
 
             // Get the attacking stat
             if should_calc_attacker_boost {
-                attacking_final_stat =
-                    attacking_side.calculate_boosted_stat(PokemonBoostableStat::SpecialAttack);
+                attacking_final_stat = attacking_side
+                    .calculate_boosted_stat(state.generation, PokemonBoostableStat::SpecialAttack);
             } else {
                 attacking_final_stat = attacker.special_attack;
             }
@@ -445,24 +456,26 @@ fn get_attacking_and_defending_stats(
                 || choice.move_id == Choices::PSYSTRIKE
             {
                 if defending_side.defense_boost <= 0 {
-                    crit_defending_stat =
-                        defending_side.calculate_boosted_stat(PokemonBoostableStat::Defense);
+                    crit_defending_stat = defending_side
+                        .calculate_boosted_stat(state.generation, PokemonBoostableStat::Defense);
                 } else {
                     crit_defending_stat = defender.defense;
                 }
 
                 defending_stat = PokemonBoostableStat::Defense;
                 if should_calc_defender_boost {
-                    defending_final_stat =
-                        defending_side.calculate_boosted_stat(PokemonBoostableStat::Defense);
+                    defending_final_stat = defending_side
+                        .calculate_boosted_stat(state.generation, PokemonBoostableStat::Defense);
                 } else {
                     defending_final_stat = defender.defense;
                 }
             } else {
                 defending_stat = PokemonBoostableStat::SpecialDefense;
                 if should_calc_defender_boost {
-                    defending_final_stat =
-                        defending_side.calculate_boosted_stat(PokemonBoostableStat::SpecialDefense);
+                    defending_final_stat = defending_side.calculate_boosted_stat(
+                        state.generation,
+                        PokemonBoostableStat::SpecialDefense,
+                    );
                 } else {
                     defending_final_stat = defender.special_defense;
                 }
@@ -471,14 +484,6 @@ fn get_attacking_and_defending_stats(
         _ => panic!("Can only calculate damage for physical or special moves"),
     }
 
-    #[cfg(any(
-        feature = "gen4",
-        feature = "gen5",
-        feature = "gen6",
-        feature = "gen7",
-        feature = "gen8",
-        feature = "gen9"
-    ))]
     if state.weather_is_active(&Weather::SNOW)
         && defender.has_type(&PokemonType::ICE)
         && defending_stat == PokemonBoostableStat::Defense
@@ -502,6 +507,7 @@ fn get_attacking_and_defending_stats(
 }
 
 fn common_pkmn_damage_calc(
+    generation: Generation,
     attacking_side: &Side,
     attacker: &Pokemon,
     attacking_stat: i16,
@@ -528,7 +534,8 @@ fn common_pkmn_damage_calc(
     if defender.terastallized && choice.move_type == PokemonType::STELLAR {
         damage_modifier *= 2.0;
     } else {
-        damage_modifier *= _type_effectiveness_modifier(&choice.move_type, &defender_types);
+        damage_modifier *=
+            _type_effectiveness_modifier(generation, &choice.move_type, &defender_types);
     }
 
     if attacker.ability != Abilities::CLOUDNINE
@@ -542,7 +549,7 @@ fn common_pkmn_damage_calc(
     damage_modifier *= stab_modifier(&choice.move_type, &attacker);
     damage_modifier *= burn_modifier(&choice.category, &attacker.status);
     damage_modifier *= volatile_status_modifier(&choice, attacking_side, defending_side);
-    damage_modifier *= terrain_modifier(terrain, attacker, defender, &choice);
+    damage_modifier *= terrain_modifier(generation, terrain, attacker, defender, &choice);
 
     damage * damage_modifier
 }
@@ -577,6 +584,7 @@ pub fn calculate_damage(
         );
 
     let mut damage = common_pkmn_damage_calc(
+        state.generation,
         attacking_side,
         attacker,
         attacking_stat,
@@ -602,6 +610,7 @@ pub fn calculate_damage(
     }
 
     let mut crit_damage = common_pkmn_damage_calc(
+        state.generation,
         attacking_side,
         attacker,
         crit_attacking_stat,
@@ -612,7 +621,7 @@ pub fn calculate_damage(
         &state.terrain.terrain_type,
         choice,
     );
-    crit_damage *= CRIT_MULTIPLIER;
+    crit_damage *= crit_multiplier(state.generation);
 
     match _damage_rolls {
         DamageRolls::Average => {
@@ -633,6 +642,7 @@ pub fn calculate_damage(
 }
 
 pub fn calculate_futuresight_damage(
+    generation: Generation,
     attacking_side: &Side,
     defending_side: &Side,
     attacking_side_pokemon_index: &PokemonIndex,
@@ -641,6 +651,7 @@ pub fn calculate_futuresight_damage(
     let defending_stat = defending_side.get_active_immutable().special_defense;
     let attacker = attacking_side.get_active_immutable();
     let mut damage = common_pkmn_damage_calc(
+        generation,
         attacking_side,
         attacker,
         attacking_stat,
@@ -649,7 +660,7 @@ pub fn calculate_futuresight_damage(
         defending_stat,
         &Weather::NONE,
         &Terrain::NONE,
-        MOVES.get(&Choices::FUTURESIGHT).unwrap(),
+        moves(generation).get(&Choices::FUTURESIGHT).unwrap(),
     );
     if attacker.ability != Abilities::INFILTRATOR {
         if defending_side.side_conditions.light_screen > 0 {
@@ -9,7 +9,7 @@ use super::choice_effects::{
 };
 use crate::choices::{
     Boost, Choices, Effect, Heal, MoveTarget, MultiHitMove, Secondary, SideCondition, StatBoosts,
-    Status, VolatileStatus, MOVES,
+    Status, VolatileStatus,
 };
 use crate::instruction::{
     ApplyVolatileStatusInstruction, BoostInstruction, ChangeDamageDealtDamageInstruction,
@@ -32,54 +32,48 @@ use super::items::{
     item_on_switch_in, Items,
 };
 use super::state::{MoveChoice, PokemonVolatileStatus, Terrain, Weather};
-use crate::choices::{Choice, MoveCategory};
+use crate::choices::{moves, Choice, MoveCategory, MultiAccuracyMove};
 use crate::instruction::{
     ChangeStatusInstruction, DamageInstruction, Instruction, StateInstructions, SwitchInstruction,
 };
 use crate::state::{
-    LastUsedMove, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, PokemonSideCondition,
-    PokemonStatus, PokemonType, Side, SideMovesFirst, SideReference, State,
+    Generation, LastUsedMove, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex,
+    PokemonSideCondition, PokemonStatus, PokemonType, Side, SideMovesFirst, SideReference, State,
 };
+use crate::{gen1, gen2, gen3};
 use std::cmp;
 
-#[cfg(feature = "terastallization")]
-use crate::choices::MultiAccuracyMove;
-
-#[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
-pub const BASE_CRIT_CHANCE: f32 = 1.0 / 16.0;
-
-#[cfg(any(feature = "gen7", feature = "gen8", feature = "gen9"))]
-pub const BASE_CRIT_CHANCE: f32 = 1.0 / 24.0;
-
-#[cfg(any(feature = "gen3", feature = "gen4"))]
-pub const MAX_SLEEP_TURNS: i8 = 4;
-
-#[cfg(any(
-    feature = "gen5",
-    feature = "gen6",
-    feature = "gen7",
-    feature = "gen8",
-    feature = "gen9"
-))]
-pub const MAX_SLEEP_TURNS: i8 = 3;
-
-#[cfg(any(feature = "gen7", feature = "gen8", feature = "gen9"))]
-pub const HIT_SELF_IN_CONFUSION_CHANCE: f32 = 1.0 / 3.0;
+pub fn base_crit_chance(generation: Generation) -> f32 {
+    if generation >= Generation::GEN7 {
+        1.0 / 24.0
+    } else {
+        1.0 / 16.0
+    }
+}
 
-#[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
-pub const HIT_SELF_IN_CONFUSION_CHANCE: f32 = 1.0 / 2.0;
+pub fn max_sleep_turns(generation: Generation) -> i8 {
+    if generation >= Generation::GEN5 {
+        3
+    } else {
+        4
+    }
+}
 
-#[cfg(any(
-    feature = "gen5",
-    feature = "gen6",
-    feature = "gen7",
-    feature = "gen8",
-    feature = "gen9"
-))]
-pub const CONSECUTIVE_PROTECT_CHANCE: f32 = 1.0 / 3.0;
+pub fn hit_self_in_confusion_chance(generation: Generation) -> f32 {
+    if generation >= Generation::GEN7 {
+        1.0 / 3.0
+    } else {
+        1.0 / 2.0
+    }
+}
 
-#[cfg(any(feature = "gen3", feature = "gen4"))]
-pub const CONSECUTIVE_PROTECT_CHANCE: f32 = 1.0 / 2.0;
+pub fn consecutive_protect_chance(generation: Generation) -> f32 {
+    if generation >= Generation::GEN5 {
+        1.0 / 3.0
+    } else {
+        1.0 / 2.0
+    }
+}
 
 pub const SIDE_CONDITION_DURATION: i8 = 5;
 pub const TAILWIND_DURATION: i8 = 4;
@@ -93,11 +87,11 @@ const PROTECT_VOLATILES: [PokemonVolatileStatus; 6] = [
     PokemonVolatileStatus::ENDURE,
 ];
 
-fn chance_to_wake_up(turns_asleep: i8) -> f32 {
+fn chance_to_wake_up(generation: Generation, turns_asleep: i8) -> f32 {
     if turns_asleep == 0 {
         0.0
     } else {
-        1.0 / (1 + MAX_SLEEP_TURNS - turns_asleep) as f32
+        1.0 / (1 + max_sleep_turns(generation) - turns_asleep) as f32
     }
 }
 
@@ -166,6 +160,7 @@ fn generate_instructions_from_switch(
     incoming_instructions: &mut StateInstructions,
 ) {
     let should_last_used_move = state.use_last_used_move;
+    let generation = state.generation;
     state.apply_instructions(&incoming_instructions.instruction_list);
 
     let (side, opposite_side) = state.get_both_sides(&switching_side_ref);
@@ -237,8 +232,8 @@ fn generate_instructions_from_switch(
         }
     }
 
-    #[cfg(feature = "gen5")]
-    if side.get_active_immutable().status == PokemonStatus::SLEEP {
+    if generation == Generation::GEN5 && side.get_active_immutable().status == PokemonStatus::SLEEP
+    {
         let current_active_index = side.active_index;
         let active = side.get_active();
         if active.rest_turns > 0 {
@@ -328,17 +323,8 @@ fn generate_instructions_from_switch(
     }
 
     if side.side_conditions.healing_wish > 0 {
-        #[cfg(any(feature = "gen8", feature = "gen9"))]
-        let mut healing_wish_consumed = false;
-
-        #[cfg(any(
-            feature = "gen3",
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7"
-        ))]
-        let mut healing_wish_consumed = true;
+        // gen8+ only consumes healing wish if it had an effect
+        let mut healing_wish_consumed = generation <= Generation::GEN7;
 
         let switched_in_pkmn = side.get_active();
         if switched_in_pkmn.hp < switched_in_pkmn.maxhp {
@@ -441,7 +427,8 @@ fn generate_instructions_from_switch(
         if active.ability != Abilities::MAGICGUARD {
             if side.side_conditions.stealth_rock == 1 {
                 let switched_in_pkmn = side.get_active();
-                let multiplier = type_effectiveness_modifier(&PokemonType::ROCK, &switched_in_pkmn);
+                let multiplier =
+                    type_effectiveness_modifier(generation, &PokemonType::ROCK, &switched_in_pkmn);
 
                 let dmg_amount = cmp::min(
                     (switched_in_pkmn.maxhp as f32 * multiplier / 8.0) as i16,
@@ -746,13 +733,11 @@ pub fn immune_to_status(
                 // sleep clause
             }
 
-            #[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
-            PokemonStatus::PARALYZE => {
+            PokemonStatus::PARALYZE if state.generation >= Generation::GEN6 => {
                 target_pkmn.has_type(&PokemonType::ELECTRIC)
                     || target_pkmn.ability == Abilities::LIMBER
             }
 
-            #[cfg(any(feature = "gen4", feature = "gen5", feature = "gen3"))]
             PokemonStatus::PARALYZE => target_pkmn.ability == Abilities::LIMBER,
 
             PokemonStatus::POISON | PokemonStatus::TOXIC => {
@@ -1492,8 +1477,8 @@ fn move_has_no_effect(state: &State, choice: &Choice, attacking_side_ref: &SideR
     let (_attacking_side, defending_side) = state.get_both_sides_immutable(attacking_side_ref);
     let defender = defending_side.get_active_immutable();
 
-    #[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
-    if choice.flags.powder
+    if state.generation >= Generation::GEN6
+        && choice.flags.powder
         && choice.target == MoveTarget::Opponent
         && defender.has_type(&PokemonType::GRASS)
     {
@@ -1554,7 +1539,6 @@ fn cannot_use_move(state: &State, choice: &Choice, attacking_side_ref: &SideRefe
     false
 }
 
-#[cfg(feature = "terastallization")]
 fn terastallized_base_power_floor(
     state: &mut State,
     choice: &mut Choice,
@@ -1582,7 +1566,6 @@ fn before_move(
     attacking_side: &SideReference,
     incoming_instructions: &mut StateInstructions,
 ) {
-    #[cfg(feature = "terastallization")]
     terastallized_base_power_floor(state, choice, attacking_side);
 
     ability_before_move(state, choice, attacking_side, incoming_instructions);
@@ -1702,6 +1685,7 @@ fn generate_instructions_from_existing_status_conditions(
     incoming_instructions: &mut StateInstructions,
     final_instructions: &mut Vec<StateInstructions>,
 ) {
+    let generation = state.generation;
     let (attacking_side, _defending_side) = state.get_both_sides(attacking_side_ref);
     let current_active_index = attacking_side.active_index;
     let attacker_active = attacking_side.get_active();
@@ -1736,7 +1720,7 @@ fn generate_instructions_from_existing_status_conditions(
                 // Pokemon is not asleep because of Rest.
                 0 => {
                     let current_sleep_turns = attacker_active.sleep_turns;
-                    let chance_to_wake = chance_to_wake_up(current_sleep_turns);
+                    let chance_to_wake = chance_to_wake_up(generation, current_sleep_turns);
                     if chance_to_wake == 1.0 {
                         attacker_active.status = PokemonStatus::NONE;
                         attacker_active.sleep_turns = 0;
@@ -1893,10 +1877,12 @@ fn generate_instructions_from_existing_status_conditions(
         .contains(&PokemonVolatileStatus::CONFUSION)
     {
         let mut hit_yourself_instruction = incoming_instructions.clone();
-        hit_yourself_instruction.update_percentage(HIT_SELF_IN_CONFUSION_CHANCE);
+        hit_yourself_instruction.update_percentage(hit_self_in_confusion_chance(generation));
 
-        let attacking_stat = attacking_side.calculate_boosted_stat(PokemonBoostableStat::Attack);
-        let defending_stat = attacking_side.calculate_boosted_stat(PokemonBoostableStat::Defense);
+        let attacking_stat =
+            attacking_side.calculate_boosted_stat(generation, PokemonBoostableStat::Attack);
+        let defending_stat =
+            attacking_side.calculate_boosted_stat(generation, PokemonBoostableStat::Defense);
 
         let attacker_active = attacking_side.get_active();
         let mut damage_dealt = 2.0 * attacker_active.level as f32;
@@ -1921,14 +1907,14 @@ fn generate_instructions_from_existing_status_conditions(
 
         final_instructions.push(hit_yourself_instruction);
 
-        incoming_instructions.update_percentage(1.0 - HIT_SELF_IN_CONFUSION_CHANCE);
+        incoming_instructions.update_percentage(1.0 - hit_self_in_confusion_chance(generation));
     }
 
     if attacking_side.side_conditions.protect > 0 {
         if let Some(vs) = &attacker_choice.volatile_status {
             if PROTECT_VOLATILES.contains(&vs.volatile_status) {
-                let protect_success_chance =
-                    CONSECUTIVE_PROTECT_CHANCE.powi(attacking_side.side_conditions.protect as i32);
+                let protect_success_chance = consecutive_protect_chance(generation)
+                    .powi(attacking_side.side_conditions.protect as i32);
                 let mut protect_fail_instruction = incoming_instructions.clone();
                 protect_fail_instruction.update_percentage(1.0 - protect_success_chance);
                 final_instructions.push(protect_fail_instruction);
@@ -2010,6 +1996,7 @@ pub fn generate_instructions_from_move(
 
     state.apply_instructions(&incoming_instructions.instruction_list);
 
+    let generation = state.generation;
     let side = state.get_side(&attacking_side);
     if side
         .volatile_statuses
@@ -2018,7 +2005,7 @@ pub fn generate_instructions_from_move(
         match side.last_used_move {
             LastUsedMove::Move(last_used_move) => {
                 if choice.move_index != last_used_move {
-                    *choice = MOVES
+                    *choice = moves(generation)
                         .get(&side.get_active_immutable().moves[&last_used_move].id)
                         .unwrap()
                         .clone();
@@ -2030,14 +2017,9 @@ pub fn generate_instructions_from_move(
 
         // this value is incremented when an encored move has been used
         // the value being 2 means we are currently using the 3rd move so we can remove it
-        #[cfg(any(
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
-        if side.volatile_status_durations.encore == 2 {
+        if generation <= Generation::GEN4 {
+            // encore's duration is not tracked in gen4
+        } else if side.volatile_status_durations.encore == 2 {
             incoming_instructions
                 .instruction_list
                 .push(Instruction::RemoveVolatileStatus(
@@ -2072,16 +2054,10 @@ pub fn generate_instructions_from_move(
         }
     }
 
-    #[cfg(any(
-        feature = "gen5",
-        feature = "gen6",
-        feature = "gen7",
-        feature = "gen8",
-        feature = "gen9"
-    ))]
-    if side
-        .volatile_statuses
-        .contains(&PokemonVolatileStatus::TAUNT)
+    if generation >= Generation::GEN5
+        && side
+            .volatile_statuses
+            .contains(&PokemonVolatileStatus::TAUNT)
     {
         match side.volatile_status_durations.taunt {
             0 | 1 => {
@@ -2344,7 +2320,7 @@ pub fn generate_instructions_from_move(
             } else if choice.move_id.increased_crit_ratio() {
                 1.0 / 8.0
             } else {
-                BASE_CRIT_CHANCE
+                base_crit_chance(generation)
             };
 
             // the chance of a branch is the chance of the roll killing + the chance of a crit
@@ -2367,7 +2343,7 @@ pub fn generate_instructions_from_move(
             } else if choice.move_id.increased_crit_ratio() {
                 1.0 / 8.0
             } else {
-                BASE_CRIT_CHANCE
+                base_crit_chance(generation)
             };
             let mut branch_ins = incoming_instructions.clone();
             branch_ins.update_percentage(crit_rate);
@@ -2437,7 +2413,8 @@ fn get_effective_speed(state: &State, side_reference: &SideReference) -> i16 {
     let side = state.get_side_immutable(side_reference);
     let active_pkmn = side.get_active_immutable();
 
-    let mut boosted_speed = side.calculate_boosted_stat(PokemonBoostableStat::Speed) as f32;
+    let mut boosted_speed =
+        side.calculate_boosted_stat(state.generation, PokemonBoostableStat::Speed) as f32;
 
     match state.weather.weather_type {
         Weather::SUN | Weather::HARSHSUN if active_pkmn.ability == Abilities::CHLOROPHYLL => {
@@ -2493,16 +2470,13 @@ fn get_effective_speed(state: &State, side_reference: &SideReference) -> i16 {
         _ => {}
     }
 
-    #[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
     if active_pkmn.status == PokemonStatus::PARALYZE && active_pkmn.ability != Abilities::QUICKFEET
     {
-        boosted_speed *= 0.25;
-    }
-
-    #[cfg(any(feature = "gen7", feature = "gen8", feature = "gen9"))]
-    if active_pkmn.status == PokemonStatus::PARALYZE && active_pkmn.ability != Abilities::QUICKFEET
-    {
-        boosted_speed *= 0.50;
+        if state.generation >= Generation::GEN7 {
+            boosted_speed *= 0.50;
+        } else {
+            boosted_speed *= 0.25;
+        }
     }
 
     boosted_speed as i16
@@ -2768,6 +2742,7 @@ fn add_end_of_turn_instructions(
         return;
     }
 
+    let generation = state.generation;
     let sides = [first_move_side, &first_move_side.get_other_side()];
 
     // Weather decrement / dissipation
@@ -2966,6 +2941,7 @@ fn add_end_of_turn_instructions(
                 });
             if attacking_side.future_sight.0 == 1 {
                 let mut damage = calculate_futuresight_damage(
+                    generation,
                     &attacking_side,
                     &defending_side,
                     &attacking_side.future_sight.1,
@@ -2999,12 +2975,11 @@ fn add_end_of_turn_instructions(
                 side_ref: *side_ref,
             });
             if side_wish.0 == 1 && 0 < active_pkmn.hp && active_pkmn.hp < active_pkmn.maxhp {
-                #[cfg(not(feature = "gen4"))]
-                let heal_amount = cmp::min(active_pkmn.maxhp - active_pkmn.hp, side_wish.1);
-
-                #[cfg(feature = "gen4")]
-                let heal_amount =
-                    cmp::min(active_pkmn.maxhp - active_pkmn.hp, active_pkmn.maxhp / 2);
+                let heal_amount = if generation == Generation::GEN4 {
+                    cmp::min(active_pkmn.maxhp - active_pkmn.hp, active_pkmn.maxhp / 2)
+                } else {
+                    cmp::min(active_pkmn.maxhp - active_pkmn.hp, side_wish.1)
+                };
 
                 let wish_heal_instruction = Instruction::Heal(HealInstruction {
                     side_ref: *side_ref,
@@ -3034,11 +3009,11 @@ fn add_end_of_turn_instructions(
 
         match active_pkmn.status {
             PokemonStatus::BURN => {
-                #[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
-                let mut damage_factor = 0.125;
-
-                #[cfg(any(feature = "gen7", feature = "gen8", feature = "gen9",))]
-                let mut damage_factor = 0.0625;
+                let mut damage_factor = if generation >= Generation::GEN7 {
+                    0.0625
+                } else {
+                    0.125
+                };
 
                 if active_pkmn.ability == Abilities::HEATPROOF {
                     damage_factor /= 2.0;
@@ -3431,11 +3406,11 @@ fn add_end_of_turn_instructions(
         {
             let active_pkmn = side.get_active();
 
-            #[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5"))]
-            let damage_amount = cmp::min((active_pkmn.maxhp as f32 / 16.0) as i16, active_pkmn.hp);
-
-            #[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
-            let damage_amount = cmp::min((active_pkmn.maxhp as f32 / 8.0) as i16, active_pkmn.hp);
+            let damage_amount = if generation >= Generation::GEN6 {
+                cmp::min((active_pkmn.maxhp as f32 / 8.0) as i16, active_pkmn.hp)
+            } else {
+                cmp::min((active_pkmn.maxhp as f32 / 16.0) as i16, active_pkmn.hp)
+            };
 
             incoming_instructions
                 .instruction_list
@@ -3856,6 +3831,36 @@ pub fn generate_instructions_from_move_pair(
     side_two_move: &MoveChoice,
     branch_on_damage: bool,
 ) -> Vec<StateInstructions> {
+    match state.generation {
+        Generation::GEN1 => {
+            return gen1::generate_instructions::generate_instructions_from_move_pair(
+                state,
+                side_one_move,
+                side_two_move,
+                branch_on_damage,
+            )
+        }
+        Generation::GEN2 => {
+            return gen2::generate_instructions::generate_instructions_from_move_pair(
+                state,
+                side_one_move,
+                side_two_move,
+                branch_on_damage,
+            )
+        }
+        Generation::GEN3 => {
+            return gen3::generate_instructions::generate_instructions_from_move_pair(
+                state,
+                side_one_move,
+                side_two_move,
+                branch_on_damage,
+            )
+        }
+        _ => {}
+    }
+    // the state is modified directly while generating instructions, which does not keep the
+    // incremental hash in sync. Every modification is undone by the end so it can be restored
+    let zobrist_hash = state.zobrist_hash;
     let mut side_one_choice;
     let mut s1_tera = false;
     let mut s1_mega = false;
@@ -4062,9 +4067,12 @@ pub fn generate_instructions_from_move_pair(
             }
 
             // combine both vectors into the final vector
+            // either side moving first can end in the same state, e.g. when neither move does anything
             state_instructions_vec.extend(side_two_moves_first_si);
+            combine_duplicate_instructions(&mut state_instructions_vec);
         }
     }
+    state.zobrist_hash = zobrist_hash;
     state_instructions_vec
 }
 
@@ -4091,25 +4099,32 @@ pub fn calculate_damage_rolls(
         .get_active_immutable();
     match choice.move_id {
         Choices::SEISMICTOSS => {
-            if type_effectiveness_modifier(&PokemonType::NORMAL, &defender_active) == 0.0 {
+            if type_effectiveness_modifier(state.generation, &PokemonType::NORMAL, &defender_active)
+                == 0.0
+            {
                 return None;
             }
             return Some(vec![attacker_active.level as i16]);
         }
         Choices::NIGHTSHADE => {
-            if type_effectiveness_modifier(&PokemonType::GHOST, &defender_active) == 0.0 {
+            if type_effectiveness_modifier(state.generation, &PokemonType::GHOST, &defender_active)
+                == 0.0
+            {
                 return None;
             }
             return Some(vec![attacker_active.level as i16]);
         }
         Choices::FINALGAMBIT => {
-            if type_effectiveness_modifier(&PokemonType::GHOST, &defender_active) == 0.0 {
+            if type_effectiveness_modifier(state.generation, &PokemonType::GHOST, &defender_active)
+                == 0.0
+            {
                 return None;
             }
             return Some(vec![attacker_active.hp]);
         }
         Choices::ENDEAVOR => {
-            if type_effectiveness_modifier(&PokemonType::GHOST, &defender_active) == 0.0
+            if type_effectiveness_modifier(state.generation, &PokemonType::GHOST, &defender_active)
+                == 0.0
                 || defender_active.hp <= attacker_active.hp
             {
                 return None;
@@ -4117,7 +4132,8 @@ pub fn calculate_damage_rolls(
             return Some(vec![defender_active.hp - attacker_active.hp]);
         }
         Choices::PAINSPLIT => {
-            if type_effectiveness_modifier(&PokemonType::GHOST, &defender_active) == 0.0
+            if type_effectiveness_modifier(state.generation, &PokemonType::GHOST, &defender_active)
+                == 0.0
                 || defender_active.hp <= attacker_active.hp
             {
                 return None;
@@ -4127,7 +4143,11 @@ pub fn calculate_damage_rolls(
             ]);
         }
         Choices::SUPERFANG
-            if type_effectiveness_modifier(&PokemonType::NORMAL, &defender_active) == 0.0 =>
+            if type_effectiveness_modifier(
+                state.generation,
+                &PokemonType::NORMAL,
+                &defender_active,
+            ) == 0.0 =>
         {
             return None;
         }
@@ -4135,7 +4155,7 @@ pub fn calculate_damage_rolls(
             return Some(vec![defender_active.hp / 2]);
         }
         Choices::SUCKERPUNCH | Choices::THUNDERCLAP => {
-            defending_choice = MOVES.get(&Choices::TACKLE).unwrap();
+            defending_choice = moves(state.generation).get(&Choices::TACKLE).unwrap();
         }
 
         _ => {}
@@ -4150,7 +4170,7 @@ pub fn calculate_damage_rolls(
     );
 
     if choice.move_id == Choices::FUTURESIGHT {
-        choice = MOVES.get(&Choices::FUTURESIGHT)?.clone();
+        choice = moves(state.generation).get(&Choices::FUTURESIGHT)?.clone();
     }
 
     let mut return_vec = Vec::with_capacity(4);
@@ -4171,6 +4191,33 @@ pub fn calculate_both_damage_rolls(
     mut s2_choice: Choice,
     side_one_moves_first: bool,
 ) -> (Option<Vec<i16>>, Option<Vec<i16>>) {
+    match state.generation {
+        Generation::GEN1 => {
+            return gen1::generate_instructions::calculate_both_damage_rolls(
+                state,
+                s1_choice,
+                s2_choice,
+                side_one_moves_first,
+            )
+        }
+        Generation::GEN2 => {
+            return gen2::generate_instructions::calculate_both_damage_rolls(
+                state,
+                s1_choice,
+                s2_choice,
+                side_one_moves_first,
+            )
+        }
+        Generation::GEN3 => {
+            return gen3::generate_instructions::calculate_both_damage_rolls(
+                state,
+                s1_choice,
+                s2_choice,
+                side_one_moves_first,
+            )
+        }
+        _ => {}
+    }
     if side_one_moves_first {
         s1_choice.first_move = true;
         s2_choice.first_move = false;
@@ -4268,19 +4315,18 @@ mod tests {
             false,
         );
 
-        #[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
-        let expected_instructions = vec![StateInstructions::default()];
-
-        #[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5"))]
-        let expected_instructions = vec![StateInstructions {
-            percentage: 100.0,
-            instruction_list: vec![Instruction::ChangeStatus(ChangeStatusInstruction {
-                side_ref: SideReference::SideTwo,
-                pokemon_index: PokemonIndex::P0,
-                old_status: PokemonStatus::NONE,
-                new_status: PokemonStatus::SLEEP,
-            })],
-        }];
+        let expected_instructions = match state.generation {
+            Generation::GEN3 | Generation::GEN4 | Generation::GEN5 => vec![StateInstructions {
+                percentage: 100.0,
+                instruction_list: vec![Instruction::ChangeStatus(ChangeStatusInstruction {
+                    side_ref: SideReference::SideTwo,
+                    pokemon_index: PokemonIndex::P0,
+                    old_status: PokemonStatus::NONE,
+                    new_status: PokemonStatus::SLEEP,
+                })],
+            }],
+            _ => vec![StateInstructions::default()],
+        };
 
         assert_eq!(instructions, expected_instructions)
     }
@@ -5107,10 +5153,13 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "gen9")]
     fn test_basic_status_move() {
         let mut state: State = State::default();
-        let mut choice = MOVES.get(&Choices::GLARE).unwrap().to_owned();
+        state.set_generation(Generation::GEN9);
+        let mut choice = moves(state.generation)
+            .get(&Choices::GLARE)
+            .unwrap()
+            .to_owned();
 
         let mut instructions = vec![];
         generate_instructions_from_move(
@@ -5137,10 +5186,13 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "gen9")]
     fn test_status_move_that_can_miss() {
         let mut state: State = State::default();
-        let mut choice = MOVES.get(&Choices::THUNDERWAVE).unwrap().to_owned();
+        state.set_generation(Generation::GEN9);
+        let mut choice = moves(state.generation)
+            .get(&Choices::THUNDERWAVE)
+            .unwrap()
+            .to_owned();
 
         let mut instructions = vec![];
         generate_instructions_from_move(
@@ -6178,12 +6230,15 @@ mod tests {
     }
 
     #[test]
-    #[cfg(any(feature = "gen8", feature = "gen9"))]
     fn test_rapidspin_clears_hazards() {
         let mut state: State = State::default();
+        state.set_generation(Generation::GEN9);
         state.side_one.side_conditions.stealth_rock = 1;
 
-        let mut choice = MOVES.get(&Choices::RAPIDSPIN).unwrap().to_owned();
+        let mut choice = moves(state.generation)
+            .get(&Choices::RAPIDSPIN)
+            .unwrap()
+            .to_owned();
 
         let mut instructions = vec![];
         generate_instructions_from_move(
@@ -6271,15 +6326,18 @@ mod tests {
     }
 
     #[test]
-    #[cfg(any(feature = "gen8", feature = "gen9"))]
     fn test_rapidspin_clears_multiple_hazards() {
         let mut state: State = State::default();
+        state.set_generation(Generation::GEN9);
         state.side_one.side_conditions.stealth_rock = 1;
         state.side_one.side_conditions.toxic_spikes = 2;
         state.side_one.side_conditions.spikes = 3;
         state.side_one.side_conditions.sticky_web = 1;
 
-        let mut choice = MOVES.get(&Choices::RAPIDSPIN).unwrap().to_owned();
+        let mut choice = moves(state.generation)
+            .get(&Choices::RAPIDSPIN)
+            .unwrap()
+            .to_owned();
 
         let mut instructions = vec![];
         generate_instructions_from_move(
@@ -6331,15 +6389,18 @@ mod tests {
     }
 
     #[test]
-    #[cfg(any(feature = "gen8", feature = "gen9"))]
     fn test_rapidspin_does_not_clear_opponent_hazards() {
         let mut state: State = State::default();
+        state.set_generation(Generation::GEN9);
         state.side_two.side_conditions.stealth_rock = 1;
         state.side_two.side_conditions.toxic_spikes = 2;
         state.side_two.side_conditions.spikes = 3;
         state.side_two.side_conditions.sticky_web = 1;
 
-        let mut choice = MOVES.get(&Choices::RAPIDSPIN).unwrap().to_owned();
+        let mut choice = moves(state.generation)
+            .get(&Choices::RAPIDSPIN)
+            .unwrap()
+            .to_owned();
 
         let mut instructions = vec![];
         generate_instructions_from_move(
@@ -7058,10 +7119,13 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "gen9")]
     fn test_knockoff_removing_item() {
         let mut state: State = State::default();
-        let mut choice = MOVES.get(&Choices::KNOCKOFF).unwrap().to_owned();
+        state.set_generation(Generation::GEN9);
+        let mut choice = moves(state.generation)
+            .get(&Choices::KNOCKOFF)
+            .unwrap()
+            .to_owned();
         state.get_side(&SideReference::SideTwo).get_active().item = Items::HEAVYDUTYBOOTS;
 
         let mut instructions = vec![];
@@ -8426,12 +8490,12 @@ mod tests {
         let mut incoming_instructions = StateInstructions::default();
 
         let expected_instructions = StateInstructions {
-            percentage: 100.0 * (1.0 - HIT_SELF_IN_CONFUSION_CHANCE),
+            percentage: 100.0 * (1.0 - hit_self_in_confusion_chance(Generation::default())),
             instruction_list: vec![],
         };
 
         let expected_frozen_instructions = &mut vec![StateInstructions {
-            percentage: 100.0 * (HIT_SELF_IN_CONFUSION_CHANCE),
+            percentage: 100.0 * (hit_self_in_confusion_chance(Generation::default())),
             instruction_list: vec![Instruction::Damage(DamageInstruction {
                 side_ref: SideReference::SideOne,
                 damage_amount: 35,
@@ -8466,7 +8530,7 @@ mod tests {
         })];
 
         let expected_instructions = StateInstructions {
-            percentage: 100.0 * (1.0 - HIT_SELF_IN_CONFUSION_CHANCE),
+            percentage: 100.0 * (1.0 - hit_self_in_confusion_chance(Generation::default())),
             instruction_list: vec![Instruction::Damage(DamageInstruction {
                 side_ref: SideReference::SideOne,
                 damage_amount: 1,
@@ -8474,7 +8538,7 @@ mod tests {
         };
 
         let expected_frozen_instructions = &mut vec![StateInstructions {
-            percentage: 100.0 * HIT_SELF_IN_CONFUSION_CHANCE,
+            percentage: 100.0 * hit_self_in_confusion_chance(Generation::default()),
             instruction_list: vec![
                 Instruction::Damage(DamageInstruction {
                     side_ref: SideReference::SideOne,
@@ -8516,7 +8580,7 @@ mod tests {
         })];
 
         let expected_instructions = StateInstructions {
-            percentage: 100.0 * (1.0 - HIT_SELF_IN_CONFUSION_CHANCE),
+            percentage: 100.0 * (1.0 - hit_self_in_confusion_chance(Generation::default())),
             instruction_list: vec![Instruction::Damage(DamageInstruction {
                 side_ref: SideReference::SideOne,
                 damage_amount: 1,
@@ -8524,7 +8588,7 @@ mod tests {
         };
 
         let expected_frozen_instructions = &mut vec![StateInstructions {
-            percentage: 100.0 * HIT_SELF_IN_CONFUSION_CHANCE,
+            percentage: 100.0 * hit_self_in_confusion_chance(Generation::default()),
             instruction_list: vec![
                 Instruction::Damage(DamageInstruction {
                     side_ref: SideReference::SideOne,
@@ -8590,7 +8654,7 @@ mod tests {
     fn test_asleep_pokemon_with_no_prior_instructions() {
         let mut state = State::default();
         state.side_one.get_active().status = PokemonStatus::SLEEP;
-        state.side_one.get_active().sleep_turns = MAX_SLEEP_TURNS;
+        state.side_one.get_active().sleep_turns = max_sleep_turns(Generation::default());
         let mut incoming_instructions = StateInstructions::default();
 
         let expected_instructions = StateInstructions {
@@ -8606,7 +8670,7 @@ mod tests {
                     side_ref: SideReference::SideOne,
                     pokemon_index: PokemonIndex::P0,
                     new_turns: 0,
-                    previous_turns: MAX_SLEEP_TURNS,
+                    previous_turns: max_sleep_turns(Generation::default()),
                 }),
             ],
         };
@@ -8631,7 +8695,7 @@ mod tests {
     fn test_asleep_waking_up_and_confused() {
         let mut state = State::default();
         state.side_one.get_active().status = PokemonStatus::SLEEP;
-        state.side_one.get_active().sleep_turns = MAX_SLEEP_TURNS;
+        state.side_one.get_active().sleep_turns = max_sleep_turns(Generation::default());
         state
             .side_one
             .volatile_statuses
@@ -8639,7 +8703,7 @@ mod tests {
         let mut incoming_instructions = StateInstructions::default();
 
         let expected_instructions = StateInstructions {
-            percentage: 100.0 * (1.0 - HIT_SELF_IN_CONFUSION_CHANCE),
+            percentage: 100.0 * (1.0 - hit_self_in_confusion_chance(Generation::default())),
             instruction_list: vec![
                 Instruction::ChangeStatus(ChangeStatusInstruction {
                     side_ref: SideReference::SideOne,
@@ -8651,13 +8715,13 @@ mod tests {
                     side_ref: SideReference::SideOne,
                     pokemon_index: PokemonIndex::P0,
                     new_turns: 0,
-                    previous_turns: MAX_SLEEP_TURNS,
+                    previous_turns: max_sleep_turns(Generation::default()),
                 }),
             ],
         };
 
         let expected_frozen_instructions = &mut vec![StateInstructions {
-            percentage: 100.0 * HIT_SELF_IN_CONFUSION_CHANCE,
+            percentage: 100.0 * hit_self_in_confusion_chance(Generation::default()),
             instruction_list: vec![
                 Instruction::ChangeStatus(ChangeStatusInstruction {
                     side_ref: SideReference::SideOne,
@@ -8669,7 +8733,7 @@ mod tests {
                     side_ref: SideReference::SideOne,
                     pokemon_index: PokemonIndex::P0,
                     new_turns: 0,
-                    previous_turns: MAX_SLEEP_TURNS,
+                    previous_turns: max_sleep_turns(Generation::default()),
                 }),
                 Instruction::Damage(DamageInstruction {
                     side_ref: SideReference::SideOne,
@@ -8999,9 +9063,9 @@ mod tests {
     }
 
     #[test]
-    #[cfg(any(feature = "gen7", feature = "gen8", feature = "gen9"))]
     fn test_later_gen_speed_cutting_in_half() {
         let mut state = State::default();
+        state.set_generation(Generation::GEN9);
         state.side_one.get_active().status = PokemonStatus::PARALYZE;
         state.side_one.get_active().speed = 100;
 
@@ -9009,9 +9073,9 @@ mod tests {
     }
 
     #[test]
-    #[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
     fn test_earlier_gen_speed_cutting_by_75_percent() {
         let mut state = State::default();
+        state.set_generation(Generation::GEN6);
         state.side_one.get_active().status = PokemonStatus::PARALYZE;
         state.side_one.get_active().speed = 100;
 
@@ -9037,7 +9101,7 @@ mod tests {
     }
 
     #[test]
-    fn test_speed_tie_goes_to_side_two() {
+    fn test_equal_speed_is_a_speed_tie() {
         let mut state = State::default();
         let side_one_choice = MOVES.get(&Choices::TACKLE).unwrap().to_owned();
         let side_two_choice = MOVES.get(&Choices::TACKLE).unwrap().to_owned();
@@ -9341,9 +9405,9 @@ mod tests {
     }
 
     #[test]
-    #[cfg(not(feature = "gen4"))]
     fn test_wished_pokemon_gets_healed() {
         let mut state = State::default();
+        state.set_generation(Generation::GEN9);
         state.side_one.wish = (1, 5);
         state.side_one.get_active().hp = 50;
 
@@ -9919,9 +9983,9 @@ mod tests {
     }
 
     #[test]
-    #[cfg(any(feature = "gen9", feature = "gen8", feature = "gen7"))]
     fn test_end_of_turn_burn_damage() {
         let mut state = State::default();
+        state.set_generation(Generation::GEN9);
         state.side_one.get_active().status = PokemonStatus::BURN;
 
         let mut incoming_instructions = StateInstructions::default();
@@ -9943,9 +10007,9 @@ mod tests {
     }
 
     #[test]
-    #[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
     fn test_early_generation_burn_one_eigth() {
         let mut state = State::default();
+        state.set_generation(Generation::GEN6);
         state.side_one.get_active().status = PokemonStatus::BURN;
 
         let mut incoming_instructions = StateInstructions::default();
@@ -10274,21 +10338,15 @@ mod tests {
             &SideReference::SideOne,
         );
 
-        #[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5"))]
-        let expected_instructions = StateInstructions {
-            percentage: 100.0,
-            instruction_list: vec![Instruction::Damage(DamageInstruction {
-                side_ref: SideReference::SideOne,
-                damage_amount: 6,
-            })],
+        let damage_amount = match state.generation {
+            Generation::GEN3 | Generation::GEN4 | Generation::GEN5 => 6,
+            _ => 12,
         };
-
-        #[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
         let expected_instructions = StateInstructions {
             percentage: 100.0,
             instruction_list: vec![Instruction::Damage(DamageInstruction {
                 side_ref: SideReference::SideOne,
-                damage_amount: 12,
+                damage_amount,
             })],
         };
 
@@ -10324,18 +10382,21 @@ mod tests {
 
     #[test]
     fn test_chance_to_wake_up_with_no_turns_asleep_is_0() {
-        assert_eq!(0.0, chance_to_wake_up(0));
+        assert_eq!(0.0, chance_to_wake_up(Generation::default(), 0));
     }
 
     #[test]
-    #[cfg(any(feature = "gen4"))]
     fn test_gen4_25_percent_to_wake_after_1_sleep_turn() {
-        assert_eq!(0.25, chance_to_wake_up(1));
+        assert_eq!(0.25, chance_to_wake_up(Generation::GEN4, 1));
     }
 
     #[test]
-    #[cfg(any(feature = "gen4"))]
     fn test_gen4_100_percent_to_wake_after_4_sleep_turn() {
-        assert_eq!(1.0, chance_to_wake_up(4));
+        assert_eq!(1.0, chance_to_wake_up(Generation::GEN4, 4));
+    }
+
+    #[test]
+    fn test_gen5_50_percent_to_wake_after_2_sleep_turns() {
+        assert_eq!(0.5, chance_to_wake_up(Generation::GEN5, 2));
     }
 }
@@ -2,6 +2,7 @@
 use super::abilities::Abilities;
 use super::damage_calc::type_effectiveness_modifier;
 use super::generate_instructions::{apply_boost_instruction, immune_to_status};
+use super::state::PokemonVolatileStatus;
 use super::state::Terrain;
 use crate::choices::{Choice, Choices, Effect, MoveCategory, MoveTarget, Secondary, StatBoosts};
 use crate::define_enum_with_from_str;
@@ -12,13 +13,11 @@ use crate::instruction::{
 };
 use crate::pokemon::PokemonName;
 use crate::state::{
-    Pokemon, PokemonBoostableStat, PokemonStatus, PokemonType, Side, SideReference, State,
+    Generation, Pokemon, PokemonBoostableStat, PokemonStatus, PokemonType, Side, SideReference,
+    State,
 };
 use std::cmp;
 
-#[cfg(feature = "gen4")]
-use super::state::PokemonVolatileStatus;
-
 define_enum_with_from_str! {
     #[repr(u8)]
     #[derive(Debug, PartialEq, Clone, Copy)]
@@ -253,6 +252,12 @@ define_enum_with_from_str! {
         DRAMPANITE,
         CHIMECHITE,
         CHANDELURITE,
+        MIRACLEBERRY,
+        MINTBERRY,
+        HEATROCK,
+        DAMPROCK,
+        SMOOTHROCK,
+        ICYROCK,
     },
     default = UNKNOWNITEM
 }
@@ -276,6 +281,7 @@ pub fn get_choice_move_disable_instructions(
 }
 
 fn damage_reduction_berry(
+    generation: Generation,
     defending_pkmn: &mut Pokemon,
     attacking_side_ref: &SideReference,
     choice: &mut Choice,
@@ -284,7 +290,7 @@ fn damage_reduction_berry(
     instructions: &mut StateInstructions,
 ) {
     if &choice.move_type == pkmn_type
-        && type_effectiveness_modifier(pkmn_type, &defending_pkmn) > 1.0
+        && type_effectiveness_modifier(generation, pkmn_type, &defending_pkmn) > 1.0
     {
         instructions
             .instruction_list
@@ -302,6 +308,7 @@ fn damage_reduction_berry(
 NormalGem, FlyingGem, etc.
 */
 fn power_up_gem(
+    generation: Generation,
     attacking_side_ref: &SideReference,
     attacking_pkmn: &mut Pokemon,
     choice: &mut Choice,
@@ -309,12 +316,9 @@ fn power_up_gem(
     instructions: &mut StateInstructions,
 ) {
     if &choice.move_type == &gem_type {
-        #[cfg(feature = "gen5")]
-        {
+        if generation == Generation::GEN5 {
             choice.base_power *= 1.5;
-        }
-        #[cfg(not(feature = "gen5"))]
-        {
+        } else {
             choice.base_power *= 1.3;
         }
 
@@ -435,11 +439,13 @@ pub fn item_before_move(
     side_ref: &SideReference,
     instructions: &mut StateInstructions,
 ) {
+    let generation = state.generation;
     let (attacking_side, defending_side) = state.get_both_sides(side_ref);
     let active_pkmn = attacking_side.get_active();
     let defending_pkmn = defending_side.get_active();
     match defending_pkmn.item {
         Items::CHOPLEBERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -448,6 +454,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::BABIRIBERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -456,6 +463,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::CHARTIBERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -478,6 +486,7 @@ pub fn item_before_move(
             }
         }
         Items::COBABERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -486,6 +495,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::COLBURBERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -494,6 +504,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::HABANBERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -502,6 +513,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::KASIBBERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -510,6 +522,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::KEBIABERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -518,6 +531,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::OCCABERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -526,6 +540,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::PASSHOBERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -534,6 +549,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::PAYAPABERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -542,6 +558,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::RINDOBERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -550,6 +567,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::ROSELIBERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -558,6 +576,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::SHUCABERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -566,6 +585,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::TANGABERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -574,6 +594,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::WACANBERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -582,6 +603,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::YACHEBERRY => damage_reduction_berry(
+            generation,
             defending_pkmn,
             side_ref,
             choice,
@@ -593,6 +615,7 @@ pub fn item_before_move(
     }
     match active_pkmn.item {
         Items::NORMALGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -600,6 +623,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::BUGGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -607,6 +631,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::ELECTRICGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -614,6 +639,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::FIGHTINGGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -621,6 +647,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::GHOSTGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -628,6 +655,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::PSYCHICGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -635,6 +663,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::FLYINGGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -642,6 +671,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::STEELGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -649,6 +679,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::ICEGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -656,6 +687,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::POISONGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -663,6 +695,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::FIREGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -670,6 +703,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::DRAGONGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -677,6 +711,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::GROUNDGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -684,6 +719,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::WATERGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -691,6 +727,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::DARKGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -698,6 +735,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::ROCKGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -705,6 +743,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::GRASSGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -712,6 +751,7 @@ pub fn item_before_move(
             instructions,
         ),
         Items::FAIRYGEM => power_up_gem(
+            generation,
             side_ref,
             active_pkmn,
             choice,
@@ -1025,6 +1065,7 @@ pub fn item_modify_attack_against(
         Items::WEAKNESSPOLICY => {
             if attacking_choice.category != MoveCategory::Status
                 && type_effectiveness_modifier(
+                    state.generation,
                     &attacking_choice.move_type,
                     &defending_side.get_active_immutable(),
                 ) > 1.0
@@ -1052,8 +1093,9 @@ pub fn item_modify_attack_against(
             if defending_side.get_active_immutable().id == PokemonName::LATIOS
                 || defending_side.get_active_immutable().id == PokemonName::LATIAS
             {
-                #[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
-                if attacking_choice.category == MoveCategory::Special {
+                if state.generation <= Generation::GEN6
+                    && attacking_choice.category == MoveCategory::Special
+                {
                     attacking_choice.base_power /= 1.5;
                 }
             }
@@ -1087,58 +1129,16 @@ pub fn item_modify_attack_being_used(
             }
             _ => {}
         },
-        #[cfg(feature = "gen3")]
-        Items::BLACKBELT => {
-            if attacking_choice.move_type == PokemonType::FIGHTING {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::BLACKBELT => {
             if attacking_choice.move_type == PokemonType::FIGHTING {
                 attacking_choice.base_power *= 1.2;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::BLACKGLASSES => {
-            if attacking_choice.move_type == PokemonType::DARK {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::BLACKGLASSES => {
             if attacking_choice.move_type == PokemonType::DARK {
                 attacking_choice.base_power *= 1.2;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::CHARCOAL => {
-            if attacking_choice.move_type == PokemonType::FIRE {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::CHARCOAL => {
             if attacking_choice.move_type == PokemonType::FIRE {
                 attacking_choice.base_power *= 1.2;
@@ -1154,20 +1154,6 @@ pub fn item_modify_attack_being_used(
                 attacking_choice.base_power *= 1.5;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::DRAGONFANG | Items::DRAGONSCALE => {
-            if attacking_choice.move_type == PokemonType::DRAGON {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::DRAGONFANG | Items::DRAGONSCALE => {
             if attacking_choice.move_type == PokemonType::DRAGON {
                 attacking_choice.base_power *= 1.2;
@@ -1175,6 +1161,7 @@ pub fn item_modify_attack_being_used(
         }
         Items::EXPERTBELT => {
             if type_effectiveness_modifier(
+                state.generation,
                 &attacking_choice.move_type,
                 &defending_side.get_active_immutable(),
             ) > 1.0
@@ -1191,10 +1178,12 @@ pub fn item_modify_attack_being_used(
             if attacking_choice.category != MoveCategory::Status {
                 attacking_choice.base_power *= 1.3;
 
-                #[cfg(feature = "gen4")]
-                if !defending_side
-                    .volatile_statuses
-                    .contains(&PokemonVolatileStatus::SUBSTITUTE)
+                // in gen4 life orb does not activate when hitting a substitute
+                let hit_substitute = state.generation == Generation::GEN4
+                    && defending_side
+                        .volatile_statuses
+                        .contains(&PokemonVolatileStatus::SUBSTITUTE);
+                if !hit_substitute
                     && attacking_side.get_active_immutable().ability != Abilities::MAGICGUARD
                 {
                     attacking_choice.add_or_create_secondaries(Secondary {
@@ -1203,31 +1192,8 @@ pub fn item_modify_attack_being_used(
                         target: MoveTarget::User,
                     });
                 }
-
-                #[cfg(not(feature = "gen4"))]
-                if attacking_side.get_active_immutable().ability != Abilities::MAGICGUARD {
-                    attacking_choice.add_or_create_secondaries(Secondary {
-                        chance: 100.0,
-                        effect: Effect::Heal(-0.1),
-                        target: MoveTarget::User,
-                    });
-                }
-            }
-        }
-        #[cfg(feature = "gen3")]
-        Items::METALCOAT => {
-            if attacking_choice.move_type == PokemonType::STEEL {
-                attacking_choice.base_power *= 1.1;
             }
         }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::METALCOAT => {
             if attacking_choice.move_type == PokemonType::STEEL {
                 attacking_choice.base_power *= 1.2;
@@ -1238,39 +1204,11 @@ pub fn item_modify_attack_being_used(
                 attacking_choice.base_power *= 1.1;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::MYSTICWATER => {
-            if attacking_choice.move_type == PokemonType::WATER {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::MYSTICWATER => {
             if attacking_choice.move_type == PokemonType::WATER {
                 attacking_choice.base_power *= 1.2;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::NEVERMELTICE => {
-            if attacking_choice.move_type == PokemonType::ICE {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::NEVERMELTICE => {
             if attacking_choice.move_type == PokemonType::ICE {
                 attacking_choice.base_power *= 1.2;
@@ -1286,20 +1224,6 @@ pub fn item_modify_attack_being_used(
                 attacking_choice.base_power *= 1.2;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::POISONBARB => {
-            if attacking_choice.move_type == PokemonType::POISON {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::POISONBARB => {
             if attacking_choice.move_type == PokemonType::POISON {
                 attacking_choice.base_power *= 1.2;
@@ -1316,20 +1240,6 @@ pub fn item_modify_attack_being_used(
                 attacking_choice.base_power *= 1.2;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::SHARPBEAK => {
-            if attacking_choice.move_type == PokemonType::FLYING {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::SHARPBEAK => {
             if attacking_choice.move_type == PokemonType::FLYING {
                 attacking_choice.base_power *= 1.2;
@@ -1343,77 +1253,21 @@ pub fn item_modify_attack_being_used(
                 attacking_choice.base_power *= 1.2;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::SILVERPOWDER => {
-            if attacking_choice.move_type == PokemonType::BUG {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::SILVERPOWDER => {
             if attacking_choice.move_type == PokemonType::BUG {
                 attacking_choice.base_power *= 1.2;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::SOFTSAND => {
-            if attacking_choice.move_type == PokemonType::GROUND {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::SOFTSAND => {
             if attacking_choice.move_type == PokemonType::GROUND {
                 attacking_choice.base_power *= 1.2;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::SPELLTAG => {
-            if attacking_choice.move_type == PokemonType::GHOST {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::SPELLTAG => {
             if attacking_choice.move_type == PokemonType::GHOST {
                 attacking_choice.base_power *= 1.2;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::MIRACLESEED => {
-            if attacking_choice.move_type == PokemonType::GRASS {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::MIRACLESEED => {
             if attacking_choice.move_type == PokemonType::GRASS {
                 attacking_choice.base_power *= 1.2;
@@ -1423,18 +1277,11 @@ pub fn item_modify_attack_being_used(
             if attacking_side.get_active_immutable().id == PokemonName::LATIOS
                 || attacking_side.get_active_immutable().id == PokemonName::LATIAS
             {
-                #[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
-                if attacking_choice.category == MoveCategory::Special {
-                    attacking_choice.base_power *= 1.5;
-                }
-
-                #[cfg(not(any(
-                    feature = "gen3",
-                    feature = "gen4",
-                    feature = "gen5",
-                    feature = "gen6"
-                )))]
-                if attacking_choice.move_type == PokemonType::DRAGON
+                if state.generation <= Generation::GEN6 {
+                    if attacking_choice.category == MoveCategory::Special {
+                        attacking_choice.base_power *= 1.5;
+                    }
+                } else if attacking_choice.move_type == PokemonType::DRAGON
                     || attacking_choice.move_type == PokemonType::PSYCHIC
                 {
                     attacking_choice.base_power *= 1.2;
@@ -1504,39 +1351,11 @@ pub fn item_modify_attack_being_used(
             }
             _ => {}
         },
-        #[cfg(feature = "gen3")]
-        Items::TWISTEDSPOON => {
-            if attacking_choice.move_type == PokemonType::PSYCHIC {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::TWISTEDSPOON => {
             if attacking_choice.move_type == PokemonType::PSYCHIC {
                 attacking_choice.base_power *= 1.2;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::HARDSTONE => {
-            if attacking_choice.move_type == PokemonType::ROCK {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::HARDSTONE => {
             if attacking_choice.move_type == PokemonType::ROCK {
                 attacking_choice.base_power *= 1.2;
@@ -1547,20 +1366,6 @@ pub fn item_modify_attack_being_used(
                 attacking_choice.base_power *= 1.2;
             }
         }
-        #[cfg(feature = "gen3")]
-        Items::MAGNET => {
-            if attacking_choice.move_type == PokemonType::ELECTRIC {
-                attacking_choice.base_power *= 1.1;
-            }
-        }
-        #[cfg(any(
-            feature = "gen4",
-            feature = "gen5",
-            feature = "gen6",
-            feature = "gen7",
-            feature = "gen8",
-            feature = "gen9"
-        ))]
         Items::MAGNET => {
             if attacking_choice.move_type == PokemonType::ELECTRIC {
                 attacking_choice.base_power *= 1.2;
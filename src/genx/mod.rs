@@ -5,4 +5,7 @@ pub mod damage_calc;
 pub mod evaluate;
 pub mod generate_instructions;
 pub mod items;
+pub mod randbats;
+pub mod showdown;
 pub mod state;
+pub mod team;
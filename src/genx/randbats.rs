@@ -0,0 +1,492 @@
+// A database of random battle sets: for each species, the level it is given and how often
+// each ability, item, move and tera type appears in its sets. Stored one species per line:
+//
+// SPECIES|level|ABILITY:frequency,...|ITEM:frequency,...|MOVE:frequency,...|TERATYPE:frequency,...
+//
+// A frequency is the share of the species' sets that have it, so the move frequencies
+// of a species add up to about 4. Lines starting with '#' are comments.
+use super::abilities::Abilities;
+use super::items::Items;
+use super::team::parse_id;
+use crate::choices::{moves, Choices};
+use crate::determinize::sample_weighted;
+use crate::pokemon::PokemonName;
+use crate::state::{
+    Generation, Move, Pokemon, PokemonMoveIndex, PokemonNature, PokemonType, Side, SidePokemon,
+};
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+// random battle sets use 85 EVs and 31 IVs in every stat with a neutral nature
+const RANDOM_BATTLE_EVS: (u8, u8, u8, u8, u8, u8) = (85, 85, 85, 85, 85, 85);
+const RANDOM_BATTLE_IVS: (u8, u8, u8, u8, u8, u8) = (31, 31, 31, 31, 31, 31);
+
+/// Everything a species can be given in random battles
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeciesSets {
+    pub species: PokemonName,
+    pub level: i8,
+    pub abilities: Vec<(Abilities, f32)>,
+    pub items: Vec<(Items, f32)>,
+    pub moves: Vec<(Choices, f32)>,
+    pub tera_types: Vec<(PokemonType, f32)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetDatabase {
+    pub generation: Generation,
+    pub species: Vec<SpeciesSets>,
+}
+
+fn parse_frequencies<T>(
+    text: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Vec<(T, f32)>, String> {
+    let mut frequencies = vec![];
+    for entry in text.split(',').map(|e| e.trim()).filter(|e| !e.is_empty()) {
+        let (name, frequency) = match entry.rsplit_once(':') {
+            Some((name, frequency)) => (name.trim(), frequency.trim()),
+            None => return Err(format!("expected name:frequency, got \"{}\"", entry)),
+        };
+        let parsed = match parse(name) {
+            Some(parsed) => parsed,
+            None => return Err(format!("unknown name \"{}\"", name)),
+        };
+        match frequency.parse::<f32>() {
+            Ok(frequency) if frequency >= 0.0 => frequencies.push((parsed, frequency)),
+            _ => return Err(format!("invalid frequency \"{}\"", frequency)),
+        }
+    }
+    Ok(frequencies)
+}
+
+fn serialize_frequencies<T: Display>(frequencies: &[(T, f32)]) -> String {
+    frequencies
+        .iter()
+        .map(|(name, frequency)| format!("{}:{:.3}", name, frequency))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+// counts how many times each value was seen
+fn add_observation<T: PartialEq>(counts: &mut Vec<(T, f32)>, value: T) {
+    match counts.iter_mut().find(|(v, _)| *v == value) {
+        Some((_, count)) => *count += 1.0,
+        None => counts.push((value, 1.0)),
+    }
+}
+
+fn to_frequencies<T>(counts: &mut [(T, f32)], observations: f32) {
+    for (_, count) in counts.iter_mut() {
+        *count /= observations;
+    }
+}
+
+// the share of observations, for values that every set has exactly one of
+fn to_shares<T>(counts: &mut [(T, f32)]) {
+    let observations: f32 = counts.iter().map(|(_, count)| count).sum();
+    to_frequencies(counts, observations);
+}
+
+fn item_is_known(item: Items) -> bool {
+    item != Items::NONE && item != Items::UNKNOWNITEM
+}
+
+impl SetDatabase {
+    pub fn parse(text: &str, generation: Generation) -> Result<SetDatabase, String> {
+        let generation_moves = moves(generation);
+        // a move is only known if the generation's move table has it
+        let parse_move =
+            |name: &str| parse_id::<Choices>(name).filter(|id| generation_moves.contains_key(id));
+        let mut species = vec![];
+        for (line_number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: String| format!("line {}: {}", line_number + 1, message);
+            let fields: Vec<&str> = line.split('|').collect();
+            if fields.len() != 6 {
+                return Err(error(format!("expected 6 fields, got {}", fields.len())));
+            }
+            let id = match parse_id::<PokemonName>(fields[0]) {
+                Some(id) => id,
+                None => return Err(error(format!("unknown species \"{}\"", fields[0]))),
+            };
+            let level = match fields[1].trim().parse::<i8>() {
+                Ok(level) if level > 0 => level,
+                _ => return Err(error(format!("invalid level \"{}\"", fields[1]))),
+            };
+            species.push(SpeciesSets {
+                species: id,
+                level,
+                abilities: parse_frequencies(fields[2], parse_id).map_err(error)?,
+                items: parse_frequencies(fields[3], parse_id).map_err(error)?,
+                moves: parse_frequencies(fields[4], parse_move).map_err(error)?,
+                tera_types: parse_frequencies(fields[5], parse_id).map_err(error)?,
+            });
+        }
+        Ok(SetDatabase {
+            generation,
+            species,
+        })
+    }
+
+    pub fn from_file<P: AsRef<Path>>(
+        path: P,
+        generation: Generation,
+    ) -> Result<SetDatabase, String> {
+        match fs::read_to_string(&path) {
+            Ok(text) => SetDatabase::parse(&text, generation),
+            Err(e) => Err(format!("{}: {}", path.as_ref().display(), e)),
+        }
+    }
+
+    pub fn serialize(&self) -> String {
+        self.species
+            .iter()
+            .map(|sets| {
+                format!(
+                    "{}|{}|{}|{}|{}|{}\n",
+                    sets.species,
+                    sets.level,
+                    serialize_frequencies(&sets.abilities),
+                    serialize_frequencies(&sets.items),
+                    serialize_frequencies(&sets.moves),
+                    serialize_frequencies(&sets.tera_types)
+                )
+            })
+            .collect()
+    }
+
+    /// Builds a database from pokemon that were seen in random battles, e.g. the teams of
+    /// logged battles. Abilities, items and tera types that were not revealed are skipped
+    pub fn from_pokemon<'a>(
+        pokemon: impl IntoIterator<Item = &'a Pokemon>,
+        generation: Generation,
+    ) -> SetDatabase {
+        let mut species: Vec<SpeciesSets> = vec![];
+        let mut appearances: Vec<f32> = vec![];
+        for pkmn in pokemon.into_iter().filter(|p| p.id != PokemonName::NONE) {
+            let index = match species.iter().position(|s| s.species == pkmn.id) {
+                Some(index) => index,
+                None => {
+                    species.push(SpeciesSets {
+                        species: pkmn.id,
+                        level: pkmn.level,
+                        abilities: vec![],
+                        items: vec![],
+                        moves: vec![],
+                        tera_types: vec![],
+                    });
+                    appearances.push(0.0);
+                    species.len() - 1
+                }
+            };
+            let sets = &mut species[index];
+            appearances[index] += 1.0;
+            if pkmn.base_ability != Abilities::NONE {
+                add_observation(&mut sets.abilities, pkmn.base_ability);
+            }
+            if item_is_known(pkmn.item) {
+                add_observation(&mut sets.items, pkmn.item);
+            }
+            if pkmn.tera_type != PokemonType::TYPELESS {
+                add_observation(&mut sets.tera_types, pkmn.tera_type);
+            }
+            for m in pkmn.moves.into_iter().filter(|m| m.id != Choices::NONE) {
+                add_observation(&mut sets.moves, m.id);
+            }
+        }
+
+        for (sets, appearances) in species.iter_mut().zip(appearances) {
+            to_shares(&mut sets.abilities);
+            to_shares(&mut sets.items);
+            to_frequencies(&mut sets.moves, appearances);
+            to_shares(&mut sets.tera_types);
+        }
+        SetDatabase {
+            generation,
+            species,
+        }
+    }
+
+    pub fn sets(&self, species: PokemonName) -> Option<&SpeciesSets> {
+        self.species.iter().find(|s| s.species == species)
+    }
+
+    /// Samples a full set for a partially revealed pokemon. Its moves that are not `NONE` are kept
+    /// and the rest are sampled from the species' moves. Its ability and item are kept unless
+    /// they are `NONE` (or `UNKNOWNITEM`), and its tera type is kept once it has terastallized.
+    /// The current HP percentage, status and terastallization carry over to the sampled set
+    pub fn sample_set(&self, revealed: &Pokemon, rng: &mut impl Rng) -> Result<Pokemon, String> {
+        let sets = match self.sets(revealed.id) {
+            Some(sets) => sets,
+            None => return Err(format!("no random battle sets for {}", revealed.id)),
+        };
+        let mut pkmn = Pokemon::from_species(
+            revealed.id,
+            self.generation,
+            sets.level,
+            RANDOM_BATTLE_EVS,
+            RANDOM_BATTLE_IVS,
+            PokemonNature::SERIOUS,
+        );
+
+        if revealed.base_ability != Abilities::NONE {
+            pkmn.ability = revealed.ability;
+            pkmn.base_ability = revealed.base_ability;
+        } else if !sets.abilities.is_empty() {
+            pkmn.base_ability = *sample_weighted(&sets.abilities, rng)?;
+            pkmn.ability = pkmn.base_ability;
+        }
+        if item_is_known(revealed.item) {
+            pkmn.item = revealed.item;
+        } else if !sets.items.is_empty() {
+            pkmn.item = *sample_weighted(&sets.items, rng)?;
+        }
+        if revealed.terastallized {
+            pkmn.terastallized = true;
+            pkmn.tera_type = revealed.tera_type;
+            pkmn.types = revealed.types;
+        } else if !sets.tera_types.is_empty() {
+            pkmn.tera_type = *sample_weighted(&sets.tera_types, rng)?;
+        }
+
+        let mut move_pool: Vec<(Choices, f32)> = sets
+            .moves
+            .iter()
+            .filter(|(id, _)| !revealed.moves.into_iter().any(|m| m.id == *id))
+            .copied()
+            .collect();
+        let generation_moves = moves(self.generation);
+        for index in [
+            PokemonMoveIndex::M0,
+            PokemonMoveIndex::M1,
+            PokemonMoveIndex::M2,
+            PokemonMoveIndex::M3,
+        ] {
+            let revealed_move = &revealed.moves[&index];
+            if revealed_move.id != Choices::NONE {
+                pkmn.moves[&index] = revealed_move.clone();
+                continue;
+            }
+            // moves are sampled without replacement until the species has none left
+            if move_pool.iter().all(|(_, frequency)| *frequency <= 0.0) {
+                continue;
+            }
+            let id = *sample_weighted(&move_pool, rng)?;
+            move_pool.retain(|(pool_id, _)| *pool_id != id);
+            let choice = match generation_moves.get(&id) {
+                Some(choice) => choice.to_owned(),
+                None => return Err(format!("{} is not a move in {:?}", id, self.generation)),
+            };
+            pkmn.moves[&index] = Move {
+                id,
+                choice,
+                ..Default::default()
+            };
+        }
+
+        if revealed.maxhp > 0 {
+            pkmn.hp = (pkmn.maxhp as i32 * revealed.hp as i32 / revealed.maxhp as i32) as i16;
+        }
+        pkmn.status = revealed.status;
+        pkmn.rest_turns = revealed.rest_turns;
+        pkmn.sleep_turns = revealed.sleep_turns;
+        Ok(pkmn)
+    }
+
+    /// A team of 6 different species with sets sampled from the database, for self-play
+    pub fn random_team(&self, rng: &mut impl Rng) -> Result<Side, String> {
+        if self.species.len() < 6 {
+            return Err(format!(
+                "a team needs 6 species but the database has {}",
+                self.species.len()
+            ));
+        }
+        let mut team = vec![];
+        for sets in self.species.choose_multiple(rng, 6) {
+            let unrevealed = Pokemon {
+                id: sets.species,
+                ..Default::default()
+            };
+            team.push(self.sample_set(&unrevealed, rng)?);
+        }
+
+        let mut side = Side::default();
+        let mut team = team.into_iter();
+        side.pokemon = SidePokemon {
+            pkmn: [(); 6].map(|_| team.next().unwrap()),
+        };
+        Ok(side)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const SETS: &str = "# comment
+CRESSELIA|80|LEVITATE:1.000|LEFTOVERS:1.000|PSYSHOCK:1.000,CALMMIND:1.000,MOONLIGHT:1.000,MOONBLAST:0.500,THUNDERBOLT:0.500|FAIRY:0.500,STEEL:0.500
+";
+
+    #[test]
+    fn test_parse_then_serialize_gives_the_same_sets() {
+        let database = SetDatabase::parse(SETS, Generation::GEN9).unwrap();
+        assert_eq!(
+            SETS.lines().nth(1).unwrap(),
+            database.serialize().trim_end()
+        );
+        assert_eq!(80, database.sets(PokemonName::CRESSELIA).unwrap().level);
+    }
+
+    #[test]
+    fn test_parse_unknown_move_is_an_error() {
+        let error = SetDatabase::parse("CRESSELIA|80|||NOTAMOVE:1.0|", Generation::GEN9);
+        assert_eq!(Err("line 1: unknown name \"NOTAMOVE\"".to_string()), error);
+    }
+
+    fn cresselia(item: Items, tera_type: PokemonType, move_ids: [Choices; 4]) -> Pokemon {
+        let mut pkmn = Pokemon {
+            id: PokemonName::CRESSELIA,
+            level: 80,
+            base_ability: Abilities::LEVITATE,
+            item,
+            tera_type,
+            ..Default::default()
+        };
+        let [m0, m1, m2, m3] = move_ids.map(|id| Move {
+            id,
+            ..Default::default()
+        });
+        pkmn.moves.m0 = m0;
+        pkmn.moves.m1 = m1;
+        pkmn.moves.m2 = m2;
+        pkmn.moves.m3 = m3;
+        pkmn
+    }
+
+    #[test]
+    fn test_from_pokemon_gives_move_frequencies_and_shares_of_what_was_revealed() {
+        let pokemon = [
+            cresselia(
+                Items::LEFTOVERS,
+                PokemonType::FAIRY,
+                [
+                    Choices::PSYSHOCK,
+                    Choices::CALMMIND,
+                    Choices::MOONLIGHT,
+                    Choices::MOONBLAST,
+                ],
+            ),
+            cresselia(
+                Items::UNKNOWNITEM,
+                PokemonType::TYPELESS,
+                [
+                    Choices::PSYSHOCK,
+                    Choices::CALMMIND,
+                    Choices::MOONLIGHT,
+                    Choices::THUNDERBOLT,
+                ],
+            ),
+            cresselia(
+                Items::LEFTOVERS,
+                PokemonType::STEEL,
+                [
+                    Choices::PSYSHOCK,
+                    Choices::CALMMIND,
+                    Choices::NONE,
+                    Choices::NONE,
+                ],
+            ),
+            Pokemon::default(),
+        ];
+        let database = SetDatabase::from_pokemon(&pokemon, Generation::GEN9);
+
+        assert_eq!(1, database.species.len());
+        let sets = database.sets(PokemonName::CRESSELIA).unwrap();
+        assert_eq!(80, sets.level);
+        assert_eq!(vec![(Abilities::LEVITATE, 1.0)], sets.abilities);
+        // abilities, items and tera types are shares of the sets they were revealed in
+        assert_eq!(vec![(Items::LEFTOVERS, 1.0)], sets.items);
+        assert_eq!(
+            vec![(PokemonType::FAIRY, 0.5), (PokemonType::STEEL, 0.5)],
+            sets.tera_types
+        );
+        // moves are the share of every set of the species, revealed or not
+        assert_eq!(
+            vec![
+                (Choices::PSYSHOCK, 1.0),
+                (Choices::CALMMIND, 1.0),
+                (Choices::MOONLIGHT, 2.0 / 3.0),
+                (Choices::MOONBLAST, 1.0 / 3.0),
+                (Choices::THUNDERBOLT, 1.0 / 3.0),
+            ],
+            sets.moves
+        );
+    }
+
+    #[test]
+    fn test_sampled_set_keeps_what_was_revealed() {
+        let database = SetDatabase::parse(SETS, Generation::GEN9).unwrap();
+        let mut revealed = Pokemon {
+            id: PokemonName::CRESSELIA,
+            hp: 50,
+            maxhp: 100,
+            ..Default::default()
+        };
+        revealed.moves.m2 = Move {
+            id: Choices::THUNDERBOLT,
+            pp: 3,
+            ..Default::default()
+        };
+
+        for seed in 0..20 {
+            let pkmn = database
+                .sample_set(&revealed, &mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            assert_eq!(80, pkmn.level);
+            assert_eq!(Abilities::LEVITATE, pkmn.ability);
+            assert_eq!(Items::LEFTOVERS, pkmn.item);
+            assert_eq!(pkmn.maxhp / 2, pkmn.hp);
+            assert_eq!(Choices::THUNDERBOLT, pkmn.moves.m2.id);
+            assert_eq!(3, pkmn.moves.m2.pp);
+
+            // the three other moves are the ones every set has
+            let mut ids: Vec<Choices> = pkmn.moves.into_iter().map(|m| m.id).collect();
+            ids.retain(|id| *id != Choices::THUNDERBOLT);
+            assert_eq!(3, ids.len());
+            assert!(ids.iter().all(|id| *id != Choices::NONE));
+            assert_ne!(ids[0], ids[1]);
+            assert_ne!(ids[1], ids[2]);
+            assert_ne!(ids[0], ids[2]);
+        }
+    }
+
+    #[test]
+    fn test_random_team_has_six_different_species() {
+        let database = SetDatabase::parse(
+            include_str!("../../data/gen9randombattle_sets.txt"),
+            Generation::GEN9,
+        )
+        .unwrap();
+        let side = database.random_team(&mut StdRng::seed_from_u64(1)).unwrap();
+
+        let species: Vec<PokemonName> = side.pokemon.into_iter().map(|p| p.id).collect();
+        assert_eq!(6, species.len());
+        for (index, id) in species.iter().enumerate() {
+            assert!(database.sets(*id).is_some());
+            assert!(!species[index + 1..].contains(id));
+        }
+        assert!(side
+            .pokemon
+            .into_iter()
+            .all(|p| p.hp == p.maxhp && p.hp > 0));
+    }
+}
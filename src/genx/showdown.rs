@@ -0,0 +1,1262 @@
+// Keeps a `State` in sync with a battle from Pokémon Showdown's protocol messages, i.e.
+//
+// |switch|p2a: Tyranitar|Tyranitar, L80, M|100/100
+// |move|p1a: Cresselia|Moonblast|p2a: Tyranitar
+// |-damage|p2a: Tyranitar|62/100
+//
+// Each message the engine tracks becomes `Instruction`s that are applied to the state.
+// Revealing side two's pokemon, moves, abilities and items has no instruction,
+// so those are filled in on the state directly.
+use super::abilities::{weather_ability_turns, Abilities};
+use super::generate_instructions::{SIDE_CONDITION_DURATION, TAILWIND_DURATION};
+use super::items::Items;
+use super::state::{PokemonVolatileStatus, Terrain, Weather};
+use super::team::{parse_id, parse_move};
+use crate::choices::{moves, Choices};
+use crate::instruction::{
+    ApplyVolatileStatusInstruction, BoostInstruction, ChangeAbilityInstruction,
+    ChangeItemInstruction, ChangeSideConditionInstruction, ChangeStatusInstruction,
+    ChangeSubsituteHealthInstruction, ChangeTerrain, ChangeType, ChangeWeather, DamageInstruction,
+    DecrementPPInstruction, FormeChangeInstruction, HealInstruction, Instruction,
+    RemoveVolatileStatusInstruction, StateInstructions, SwitchInstruction,
+    ToggleTerastallizedInstruction, ToggleTrickRoomInstruction,
+};
+use crate::pokemon::PokemonName;
+use crate::state::{
+    pokemon_index_iter, Move, Pokemon, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex,
+    PokemonNature, PokemonSideCondition, PokemonStatus, PokemonType, SideReference, State,
+};
+
+// weather from moves lasts 5 turns, or 8 when the pokemon that set it holds the matching rock
+const WEATHER_DURATION: i8 = 5;
+const WEATHER_ROCK_DURATION: i8 = 8;
+const TERRAIN_DURATION: i8 = 5;
+const TRICK_ROOM_DURATION: i8 = 5;
+
+const BOOSTABLE_STATS: [PokemonBoostableStat; 7] = [
+    PokemonBoostableStat::Attack,
+    PokemonBoostableStat::Defense,
+    PokemonBoostableStat::SpecialAttack,
+    PokemonBoostableStat::SpecialDefense,
+    PokemonBoostableStat::Speed,
+    PokemonBoostableStat::Evasion,
+    PokemonBoostableStat::Accuracy,
+];
+
+const MOVE_INDICES: [PokemonMoveIndex; 4] = [
+    PokemonMoveIndex::M0,
+    PokemonMoveIndex::M1,
+    PokemonMoveIndex::M2,
+    PokemonMoveIndex::M3,
+];
+
+/// A pokemon referred to by a message, e.g. "p2a: Tyranitar"
+struct PokemonIdent<'a> {
+    side_ref: SideReference,
+    /// whether it is the active pokemon ("p2a") rather than any pokemon on the side ("p2")
+    active: bool,
+    name: &'a str,
+}
+
+fn to_pokemon_index(index: usize) -> PokemonIndex {
+    pokemon_index_iter().nth(index).unwrap()
+}
+
+/// "62/100", "62/100 par" or "0 fnt" as the HP out of the maximum and an optional status
+fn parse_hp(text: &str) -> Result<(i32, i32, Option<&str>), String> {
+    let mut parts = text.split_whitespace();
+    let hp_part = parts.next().unwrap_or("");
+    let status = parts.next();
+    let (hp, max) = match hp_part.split_once('/') {
+        Some((hp, max)) => (hp.parse::<i32>(), max.parse::<i32>()),
+        None => (hp_part.parse::<i32>(), Ok(100)),
+    };
+    match (hp, max) {
+        (Ok(hp), Ok(max)) if max > 0 => Ok((hp, max, status)),
+        _ => Err(format!("invalid HP \"{}\"", text)),
+    }
+}
+
+fn parse_status(status: &str) -> Result<PokemonStatus, String> {
+    match status {
+        "brn" => Ok(PokemonStatus::BURN),
+        "par" => Ok(PokemonStatus::PARALYZE),
+        "slp" => Ok(PokemonStatus::SLEEP),
+        "frz" => Ok(PokemonStatus::FREEZE),
+        "psn" => Ok(PokemonStatus::POISON),
+        "tox" => Ok(PokemonStatus::TOXIC),
+        _ => Err(format!("unknown status \"{}\"", status)),
+    }
+}
+
+fn parse_boostable_stat(stat: &str) -> Result<PokemonBoostableStat, String> {
+    match stat {
+        "atk" => Ok(PokemonBoostableStat::Attack),
+        "def" => Ok(PokemonBoostableStat::Defense),
+        "spa" => Ok(PokemonBoostableStat::SpecialAttack),
+        "spd" => Ok(PokemonBoostableStat::SpecialDefense),
+        "spe" => Ok(PokemonBoostableStat::Speed),
+        "evasion" => Ok(PokemonBoostableStat::Evasion),
+        "accuracy" => Ok(PokemonBoostableStat::Accuracy),
+        _ => Err(format!("unknown stat \"{}\"", stat)),
+    }
+}
+
+// "move: Stealth Rock" and "Stealth Rock" both refer to the move
+fn effect_name(effect: &str) -> &str {
+    match effect.split_once(':') {
+        Some((_, name)) => name.trim(),
+        None => effect.trim(),
+    }
+}
+
+fn parse_weather(weather: &str) -> Option<Weather> {
+    match weather {
+        "SunnyDay" => Some(Weather::SUN),
+        "RainDance" => Some(Weather::RAIN),
+        "Sandstorm" => Some(Weather::SAND),
+        "Hail" => Some(Weather::HAIL),
+        "Snow" | "Snowscape" => Some(Weather::SNOW),
+        "DesolateLand" => Some(Weather::HARSHSUN),
+        "PrimordialSea" => Some(Weather::HEAVYRAIN),
+        "none" => Some(Weather::NONE),
+        _ => None,
+    }
+}
+
+fn extends_weather(item: Items, weather: Weather) -> bool {
+    matches!(
+        (item, weather),
+        (Items::HEATROCK, Weather::SUN)
+            | (Items::DAMPROCK, Weather::RAIN)
+            | (Items::SMOOTHROCK, Weather::SAND)
+            | (Items::ICYROCK, Weather::HAIL | Weather::SNOW)
+    )
+}
+
+fn parse_side_condition(condition: &str) -> Option<PokemonSideCondition> {
+    match effect_name(condition) {
+        "Aurora Veil" => Some(PokemonSideCondition::AuroraVeil),
+        "Light Screen" => Some(PokemonSideCondition::LightScreen),
+        "Mist" => Some(PokemonSideCondition::Mist),
+        "Reflect" => Some(PokemonSideCondition::Reflect),
+        "Safeguard" => Some(PokemonSideCondition::Safeguard),
+        "Spikes" => Some(PokemonSideCondition::Spikes),
+        "Stealth Rock" => Some(PokemonSideCondition::Stealthrock),
+        "Sticky Web" => Some(PokemonSideCondition::StickyWeb),
+        "Tailwind" => Some(PokemonSideCondition::Tailwind),
+        "Toxic Spikes" => Some(PokemonSideCondition::ToxicSpikes),
+        _ => None,
+    }
+}
+
+/// Keeps `state` in sync with a battle as its protocol messages are received
+pub struct ShowdownBattle {
+    pub state: State,
+
+    /// The player ("p1" or "p2") that is side one
+    side_one_player: String,
+
+    // the names messages use for each pokemon, in the order of `Side::pokemon`
+    nicknames: [[String; 6]; 2],
+
+    // the side that last used a move, whose active pokemon set any weather the move started
+    last_move_side: Option<SideReference>,
+}
+
+impl ShowdownBattle {
+    /// `state` holds what is known before the battle starts: usually side one's team
+    /// and an empty side two whose pokemon are filled in as they are revealed
+    pub fn new(state: State, side_one_player: &str) -> ShowdownBattle {
+        ShowdownBattle {
+            state,
+            side_one_player: side_one_player.to_string(),
+            nicknames: Default::default(),
+            last_move_side: None,
+        }
+    }
+
+    /// Applies every line of a battle log, returning the instructions that were applied
+    pub fn apply_log(&mut self, log: &str) -> Result<Vec<Instruction>, String> {
+        let mut instructions = vec![];
+        for line in log.lines() {
+            instructions.extend(self.apply_message(line)?);
+        }
+        Ok(instructions)
+    }
+
+    /// Applies one protocol message to the state, returning the instructions that were applied.
+    /// Messages the engine does not track, such as `|turn|` or `|-crit|`, are ignored.
+    /// Messages that change the state in a way the engine cannot follow, such as `|-transform|`,
+    /// are an error since the state would no longer match the battle
+    pub fn apply_message(&mut self, line: &str) -> Result<Vec<Instruction>, String> {
+        let mut instructions = vec![];
+        if !line.starts_with('|') {
+            return Ok(instructions);
+        }
+        let args: Vec<&str> = line[1..].split('|').collect();
+        let arg = |index: usize| -> Result<&str, String> {
+            args.get(index)
+                .copied()
+                .ok_or_else(|| format!("missing argument {} in \"{}\"", index, line))
+        };
+        let result = match args[0] {
+            "switch" | "drag" => self.switch(arg(1)?, arg(2)?, arg(3)?, &mut instructions),
+            "move" => self.use_move(arg(1)?, arg(2)?, &mut instructions),
+            "-damage" | "-heal" | "-sethp" => self.set_hp(arg(1)?, arg(2)?, &mut instructions),
+            "faint" => self.set_hp(arg(1)?, "0 fnt", &mut instructions),
+            "-boost" | "-unboost" => {
+                let amount = match arg(3)?.parse::<i8>() {
+                    Ok(amount) if args[0] == "-unboost" => -amount,
+                    Ok(amount) => amount,
+                    Err(_) => return Err(format!("invalid boost in \"{}\"", line)),
+                };
+                self.boost(arg(1)?, arg(2)?, amount, &mut instructions)
+            }
+            "-setboost" => match arg(3)?.parse::<i8>() {
+                Ok(boost) => self.set_boost(arg(1)?, arg(2)?, boost, &mut instructions),
+                Err(_) => return Err(format!("invalid boost in \"{}\"", line)),
+            },
+            "-clearboost" => self.clear_boosts(arg(1)?, &mut instructions),
+            "-clearallboost" => {
+                self.clear_boosts_of(SideReference::SideOne, &mut instructions);
+                self.clear_boosts_of(SideReference::SideTwo, &mut instructions);
+                Ok(())
+            }
+            "-clearnegativeboost" => self.clear_negative_boosts(arg(1)?, &mut instructions),
+            "detailschange" | "-formechange" => {
+                self.forme_change(arg(1)?, arg(2)?, &mut instructions)
+            }
+            "-status" => self.status(arg(1)?, Some(arg(2)?), &mut instructions),
+            "-curestatus" => self.status(arg(1)?, None, &mut instructions),
+            "-weather" => self.weather(arg(1)?, &args[2..], &mut instructions),
+            "-fieldstart" => self.field(arg(1)?, true, &mut instructions),
+            "-fieldend" => self.field(arg(1)?, false, &mut instructions),
+            "-sidestart" => self.side_condition(arg(1)?, arg(2)?, true, &mut instructions),
+            "-sideend" => self.side_condition(arg(1)?, arg(2)?, false, &mut instructions),
+            "-start" => self.volatile_status(arg(1)?, arg(2)?, true, &mut instructions),
+            "-end" => self.volatile_status(arg(1)?, arg(2)?, false, &mut instructions),
+            "-item" => self.item(arg(1)?, Some(arg(2)?), &mut instructions),
+            "-enditem" => self.item(arg(1)?, None, &mut instructions),
+            "-ability" => self.ability(arg(1)?, arg(2)?, &mut instructions),
+            "-terastallize" => self.terastallize(arg(1)?, arg(2)?, &mut instructions),
+            "-transform"
+            | "-copyboost"
+            | "-swapboost"
+            | "-invertboost"
+            | "-swapsideconditions"
+            | "replace" => Err("this message is not supported".to_string()),
+            _ => Ok(()),
+        };
+        result.map_err(|e| format!("{}: {}", line, e))?;
+        Ok(instructions)
+    }
+
+    fn push(&mut self, instructions: &mut Vec<Instruction>, instruction: Instruction) {
+        self.state.apply_one_instruction(&instruction);
+        instructions.push(instruction);
+    }
+
+    fn side_ref(&self, player: &str) -> Result<SideReference, String> {
+        match player {
+            "p1" | "p2" if player == self.side_one_player => Ok(SideReference::SideOne),
+            "p1" | "p2" => Ok(SideReference::SideTwo),
+            _ => Err(format!("unknown player \"{}\"", player)),
+        }
+    }
+
+    fn parse_ident<'a>(&self, ident: &'a str) -> Result<PokemonIdent<'a>, String> {
+        let (position, name) = match ident.split_once(':') {
+            Some((position, name)) => (position.trim(), name.trim()),
+            None => (ident.trim(), ""),
+        };
+        let player = position
+            .get(..2)
+            .ok_or_else(|| format!("invalid pokemon \"{}\"", ident))?;
+        Ok(PokemonIdent {
+            side_ref: self.side_ref(player)?,
+            active: position.len() > 2,
+            name,
+        })
+    }
+
+    fn nicknames(&self, side_ref: SideReference) -> &[String; 6] {
+        match side_ref {
+            SideReference::SideOne => &self.nicknames[0],
+            SideReference::SideTwo => &self.nicknames[1],
+        }
+    }
+
+    fn set_nickname(&mut self, side_ref: SideReference, index: usize, name: String) {
+        match side_ref {
+            SideReference::SideOne => self.nicknames[0][index] = name,
+            SideReference::SideTwo => self.nicknames[1][index] = name,
+        }
+    }
+
+    // a pokemon's nickname is only known once it switches in, before that it is found by species
+    fn find_pokemon(&self, side_ref: SideReference, name: &str) -> Option<usize> {
+        self.nicknames(side_ref)
+            .iter()
+            .position(|n| n == name)
+            .or_else(|| {
+                let species = parse_id::<PokemonName>(name)?;
+                self.state
+                    .get_side_immutable(&side_ref)
+                    .pokemon
+                    .pkmn
+                    .iter()
+                    .position(|p| p.id == species)
+            })
+    }
+
+    // the index of the pokemon a message refers to
+    fn pokemon_index(&self, ident: &PokemonIdent) -> Result<PokemonIndex, String> {
+        if ident.active {
+            return Ok(self.state.get_side_immutable(&ident.side_ref).active_index);
+        }
+        match self.find_pokemon(ident.side_ref, ident.name) {
+            Some(index) => Ok(to_pokemon_index(index)),
+            None => Err(format!("unknown pokemon \"{}\"", ident.name)),
+        }
+    }
+
+    /// |switch|p2a: Tyranitar|Tyranitar, L80, M|100/100
+    fn switch(
+        &mut self,
+        ident: &str,
+        details: &str,
+        hp: &str,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        let mut details = details.split(',').map(|d| d.trim());
+        let species_name = details.next().unwrap_or("");
+        let species = match parse_id::<PokemonName>(species_name) {
+            Some(species) => species,
+            None => return Err(format!("unknown species \"{}\"", species_name)),
+        };
+        let level = details
+            .find_map(|d| d.strip_prefix('L').and_then(|l| l.parse::<i8>().ok()))
+            .unwrap_or(100);
+
+        let name = ident.name.to_string();
+        let side = self.state.get_side_immutable(&ident.side_ref);
+        let known_index = self
+            .nicknames(ident.side_ref)
+            .iter()
+            .position(|n| *n == name)
+            .or_else(|| side.pokemon.pkmn.iter().position(|p| p.id == species));
+        let empty_index = side
+            .pokemon
+            .pkmn
+            .iter()
+            .position(|p| p.id == PokemonName::NONE);
+        let next_index = match known_index {
+            Some(index) => index,
+            // a pokemon seen for the first time fills the first empty slot
+            None => match empty_index {
+                Some(index) => {
+                    self.state.get_side(&ident.side_ref).pokemon.pkmn[index] =
+                        Pokemon::from_species(
+                            species,
+                            self.state.generation,
+                            level,
+                            (85, 85, 85, 85, 85, 85),
+                            (31, 31, 31, 31, 31, 31),
+                            PokemonNature::SERIOUS,
+                        );
+                    self.state.update_zobrist_hash();
+                    index
+                }
+                None => return Err(format!("no room on the side for \"{}\"", species_name)),
+            },
+        };
+        self.set_nickname(ident.side_ref, next_index, name);
+        let next_index = to_pokemon_index(next_index);
+
+        let previous_index = self.state.get_side_immutable(&ident.side_ref).active_index;
+        if previous_index != next_index {
+            self.switch_out(ident.side_ref, instructions);
+            self.push(
+                instructions,
+                Instruction::Switch(SwitchInstruction {
+                    side_ref: ident.side_ref,
+                    previous_index,
+                    next_index,
+                }),
+            );
+        }
+        self.set_hp_of(ident.side_ref, next_index, hp, instructions)
+    }
+
+    // boosts, volatile statuses and the substitute do not stay with a pokemon that switches out
+    fn switch_out(&mut self, side_ref: SideReference, instructions: &mut Vec<Instruction>) {
+        self.clear_boosts_of(side_ref, instructions);
+        let side = self.state.get_side_immutable(&side_ref);
+        let mut volatile_statuses = vec![];
+        side.volatile_statuses
+            .clone()
+            .retain(&mut |vs: &PokemonVolatileStatus| {
+                volatile_statuses.push(*vs);
+                true
+            });
+        let substitute_health = side.substitute_health;
+        let toxic_count = side.side_conditions.toxic_count;
+        for volatile_status in volatile_statuses {
+            self.push(
+                instructions,
+                Instruction::RemoveVolatileStatus(RemoveVolatileStatusInstruction {
+                    side_ref,
+                    volatile_status,
+                }),
+            );
+        }
+        if substitute_health != 0 {
+            self.push(
+                instructions,
+                Instruction::ChangeSubstituteHealth(ChangeSubsituteHealthInstruction {
+                    side_ref,
+                    health_change: -substitute_health,
+                }),
+            );
+        }
+        if toxic_count != 0 {
+            self.push(
+                instructions,
+                Instruction::ChangeSideCondition(ChangeSideConditionInstruction {
+                    side_ref,
+                    side_condition: PokemonSideCondition::ToxicCount,
+                    amount: -toxic_count,
+                }),
+            );
+        }
+    }
+
+    /// |move|p1a: Cresselia|Moonblast|p2a: Tyranitar
+    fn use_move(
+        &mut self,
+        ident: &str,
+        move_name: &str,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        self.last_move_side = Some(ident.side_ref);
+        let move_id = match parse_move(move_name, self.state.generation) {
+            Some(move_id) => move_id,
+            // moves the engine does not know, e.g. struggle, do not change the state
+            None => return Ok(()),
+        };
+        let generation = self.state.generation;
+        let active = self.state.get_side(&ident.side_ref).get_active();
+        let move_index = match MOVE_INDICES
+            .iter()
+            .find(|index| active.moves[index].id == move_id)
+        {
+            Some(index) => *index,
+            None => match MOVE_INDICES
+                .iter()
+                .find(|index| active.moves[index].id == Choices::NONE)
+            {
+                Some(index) => {
+                    active.moves[index] = Move {
+                        id: move_id,
+                        choice: moves(generation).get(&move_id).unwrap().to_owned(),
+                        ..Default::default()
+                    };
+                    self.state.update_zobrist_hash();
+                    *index
+                }
+                // e.g. a move called by another move
+                None => return Ok(()),
+            },
+        };
+        self.push(
+            instructions,
+            Instruction::DecrementPP(DecrementPPInstruction {
+                side_ref: ident.side_ref,
+                move_index,
+                amount: 1,
+            }),
+        );
+        Ok(())
+    }
+
+    /// |-damage|p2a: Tyranitar|62/100
+    fn set_hp(
+        &mut self,
+        ident: &str,
+        hp: &str,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        let index = self.pokemon_index(&ident)?;
+        self.set_hp_of(ident.side_ref, index, hp, instructions)
+    }
+
+    fn set_hp_of(
+        &mut self,
+        side_ref: SideReference,
+        index: PokemonIndex,
+        hp: &str,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let (hp, max, status) = parse_hp(hp)?;
+        let side = self.state.get_side_immutable(&side_ref);
+        // damage and heal instructions only change the HP of the active pokemon
+        if side.active_index != index {
+            return Ok(());
+        }
+        let pkmn = side.get_active_immutable();
+        // side one usually sees exact HP, side two a percentage
+        let new_hp = if status == Some("fnt") {
+            0
+        } else {
+            ((pkmn.maxhp as i32 * hp) as f32 / max as f32).round() as i16
+        };
+        let change = new_hp - pkmn.hp;
+        if change < 0 {
+            self.push(
+                instructions,
+                Instruction::Damage(DamageInstruction {
+                    side_ref,
+                    damage_amount: -change,
+                }),
+            );
+        } else if change > 0 {
+            self.push(
+                instructions,
+                Instruction::Heal(HealInstruction {
+                    side_ref,
+                    heal_amount: change,
+                }),
+            );
+        }
+        match status {
+            Some("fnt") | None => Ok(()),
+            Some(status) => {
+                self.change_status(side_ref, index, Some(parse_status(status)?), instructions)
+            }
+        }
+    }
+
+    /// |-boost|p1a: Cresselia|spa|1
+    fn boost(
+        &mut self,
+        ident: &str,
+        stat: &str,
+        amount: i8,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        let stat = parse_boostable_stat(stat)?;
+        let current = self
+            .state
+            .get_side_immutable(&ident.side_ref)
+            .get_boost_from_boost_enum(&stat);
+        // Showdown reports the attempted amount, which can go past +6 or -6
+        self.set_boost_of(ident.side_ref, stat, current + amount, instructions);
+        Ok(())
+    }
+
+    /// |-setboost|p1a: Azumarill|atk|6
+    fn set_boost(
+        &mut self,
+        ident: &str,
+        stat: &str,
+        boost: i8,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        let stat = parse_boostable_stat(stat)?;
+        self.set_boost_of(ident.side_ref, stat, boost, instructions);
+        Ok(())
+    }
+
+    fn set_boost_of(
+        &mut self,
+        side_ref: SideReference,
+        stat: PokemonBoostableStat,
+        boost: i8,
+        instructions: &mut Vec<Instruction>,
+    ) {
+        let current = self
+            .state
+            .get_side_immutable(&side_ref)
+            .get_boost_from_boost_enum(&stat);
+        let amount = boost.clamp(-6, 6) - current;
+        if amount != 0 {
+            self.push(
+                instructions,
+                Instruction::Boost(BoostInstruction {
+                    side_ref,
+                    stat,
+                    amount,
+                }),
+            );
+        }
+    }
+
+    /// |-clearboost|p1a: Cresselia
+    fn clear_boosts(
+        &mut self,
+        ident: &str,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        self.clear_boosts_of(ident.side_ref, instructions);
+        Ok(())
+    }
+
+    fn clear_boosts_of(&mut self, side_ref: SideReference, instructions: &mut Vec<Instruction>) {
+        for stat in BOOSTABLE_STATS {
+            self.set_boost_of(side_ref, stat, 0, instructions);
+        }
+    }
+
+    /// |-clearnegativeboost|p1a: Cresselia
+    fn clear_negative_boosts(
+        &mut self,
+        ident: &str,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        for stat in BOOSTABLE_STATS {
+            let current = self
+                .state
+                .get_side_immutable(&ident.side_ref)
+                .get_boost_from_boost_enum(&stat);
+            if current < 0 {
+                self.set_boost_of(ident.side_ref, stat, 0, instructions);
+            }
+        }
+        Ok(())
+    }
+
+    /// |detailschange|p2a: Charizard|Charizard-Mega-X, L80, M
+    /// and |-formechange|p2a: Minior|Minior-Meteor
+    fn forme_change(
+        &mut self,
+        ident: &str,
+        details: &str,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        let species_name = details.split(',').next().unwrap_or("").trim();
+        let species = match parse_id::<PokemonName>(species_name) {
+            Some(species) => species,
+            None => return Err(format!("unknown species \"{}\"", species_name)),
+        };
+        let active = self
+            .state
+            .get_side_immutable(&ident.side_ref)
+            .get_active_immutable();
+        if active.id == species {
+            return Ok(());
+        }
+        let old_types = active.types;
+        let terastallized = active.terastallized;
+        self.push(
+            instructions,
+            Instruction::FormeChange(FormeChangeInstruction {
+                side_ref: ident.side_ref,
+                name_change: species as i16 - active.id as i16,
+            }),
+        );
+
+        // the stat changes are worked out on a copy so that they are only applied once, by `push`
+        let mut stat_changes = StateInstructions::default();
+        self.state
+            .get_side_immutable(&ident.side_ref)
+            .get_active_immutable()
+            .clone()
+            .recalculate_stats(&ident.side_ref, &mut stat_changes);
+        for instruction in stat_changes.instruction_list {
+            self.push(instructions, instruction);
+        }
+
+        let new_types = species.pokedex_entry_in(self.state.generation).types;
+        if !terastallized && new_types != old_types {
+            self.push(
+                instructions,
+                Instruction::ChangeType(ChangeType {
+                    side_ref: ident.side_ref,
+                    new_types,
+                    old_types,
+                }),
+            );
+        }
+        Ok(())
+    }
+
+    /// |-status|p2a: Tyranitar|par and |-curestatus|p2: Tyranitar|slp
+    fn status(
+        &mut self,
+        ident: &str,
+        status: Option<&str>,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        let index = self.pokemon_index(&ident)?;
+        let status = match status {
+            Some(status) => Some(parse_status(status)?),
+            None => None,
+        };
+        self.change_status(ident.side_ref, index, status, instructions)
+    }
+
+    fn change_status(
+        &mut self,
+        side_ref: SideReference,
+        pokemon_index: PokemonIndex,
+        status: Option<PokemonStatus>,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let new_status = status.unwrap_or(PokemonStatus::NONE);
+        let old_status = self.state.get_side_immutable(&side_ref).pokemon[pokemon_index].status;
+        if old_status != new_status {
+            self.push(
+                instructions,
+                Instruction::ChangeStatus(ChangeStatusInstruction {
+                    side_ref,
+                    pokemon_index,
+                    old_status,
+                    new_status,
+                }),
+            );
+        }
+        Ok(())
+    }
+
+    /// |-weather|SunnyDay, |-weather|SunnyDay|[upkeep], |-weather|none and
+    /// |-weather|Sandstorm|[from] ability: Sand Stream|[of] p2a: Tyranitar
+    fn weather(
+        &mut self,
+        weather: &str,
+        tags: &[&str],
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let new_weather = match parse_weather(weather) {
+            Some(new_weather) => new_weather,
+            None => return Err(format!("unknown weather \"{}\"", weather)),
+        };
+        if tags.contains(&"[upkeep]") {
+            if self.state.weather.turns_remaining > 0 {
+                self.push(instructions, Instruction::DecrementWeatherTurnsRemaining);
+            }
+            return Ok(());
+        }
+        let new_weather_turns_remaining = match new_weather {
+            Weather::NONE => 0,
+            // primal weather lasts until the pokemon that set it leaves
+            Weather::HARSHSUN | Weather::HEAVYRAIN => -1,
+            _ => {
+                let setter = match tags.iter().find_map(|tag| tag.strip_prefix("[of] ")) {
+                    Some(ident) => Some(self.parse_ident(ident)?.side_ref),
+                    None => self.last_move_side,
+                };
+                let setter_item = setter.map(|side_ref| {
+                    self.state
+                        .get_side_immutable(&side_ref)
+                        .get_active_immutable()
+                        .item
+                });
+                let turns = match tags.iter().any(|tag| tag.starts_with("[from] ability:")) {
+                    true => weather_ability_turns(self.state.generation),
+                    false => WEATHER_DURATION,
+                };
+                // weather that lasts forever is not extended
+                match setter_item {
+                    Some(item) if turns > 0 && extends_weather(item, new_weather) => {
+                        WEATHER_ROCK_DURATION
+                    }
+                    _ => turns,
+                }
+            }
+        };
+        self.push(
+            instructions,
+            Instruction::ChangeWeather(ChangeWeather {
+                new_weather,
+                new_weather_turns_remaining,
+                previous_weather: self.state.weather.weather_type,
+                previous_weather_turns_remaining: self.state.weather.turns_remaining,
+            }),
+        );
+        Ok(())
+    }
+
+    /// |-fieldstart|move: Electric Terrain and |-fieldstart|move: Trick Room
+    fn field(
+        &mut self,
+        effect: &str,
+        start: bool,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let name = effect_name(effect);
+        if name == "Trick Room" {
+            if self.state.trick_room.active != start {
+                self.push(
+                    instructions,
+                    Instruction::ToggleTrickRoom(ToggleTrickRoomInstruction {
+                        currently_active: self.state.trick_room.active,
+                        new_trickroom_turns_remaining: if start { TRICK_ROOM_DURATION } else { 0 },
+                        previous_trickroom_turns_remaining: self.state.trick_room.turns_remaining,
+                    }),
+                );
+            }
+            return Ok(());
+        }
+        let terrain = match parse_id::<Terrain>(name) {
+            Some(terrain) => terrain,
+            // other field effects, e.g. gravity, are not tracked
+            None => return Ok(()),
+        };
+        let (new_terrain, new_terrain_turns_remaining) = if start {
+            (terrain, TERRAIN_DURATION)
+        } else {
+            (Terrain::NONE, 0)
+        };
+        self.push(
+            instructions,
+            Instruction::ChangeTerrain(ChangeTerrain {
+                new_terrain,
+                new_terrain_turns_remaining,
+                previous_terrain: self.state.terrain.terrain_type,
+                previous_terrain_turns_remaining: self.state.terrain.turns_remaining,
+            }),
+        );
+        Ok(())
+    }
+
+    /// |-sidestart|p2: Player|move: Stealth Rock and |-sideend|p1: Player|Reflect
+    fn side_condition(
+        &mut self,
+        side: &str,
+        condition: &str,
+        start: bool,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let side_ref = self.parse_ident(side)?.side_ref;
+        let side_condition = match parse_side_condition(condition) {
+            Some(side_condition) => side_condition,
+            None => return Ok(()),
+        };
+        let current = self
+            .state
+            .get_side_immutable(&side_ref)
+            .get_side_condition(side_condition);
+        let amount = if !start {
+            -current
+        } else {
+            match side_condition {
+                PokemonSideCondition::Spikes | PokemonSideCondition::ToxicSpikes => 1,
+                PokemonSideCondition::Tailwind => TAILWIND_DURATION - current,
+                PokemonSideCondition::Stealthrock | PokemonSideCondition::StickyWeb => 1 - current,
+                _ => SIDE_CONDITION_DURATION - current,
+            }
+        };
+        if amount != 0 {
+            self.push(
+                instructions,
+                Instruction::ChangeSideCondition(ChangeSideConditionInstruction {
+                    side_ref,
+                    side_condition,
+                    amount,
+                }),
+            );
+        }
+        Ok(())
+    }
+
+    /// |-start|p2a: Tyranitar|Substitute and |-end|p2a: Tyranitar|move: Taunt
+    fn volatile_status(
+        &mut self,
+        ident: &str,
+        effect: &str,
+        start: bool,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        let volatile_status = match parse_id::<PokemonVolatileStatus>(effect_name(effect)) {
+            Some(volatile_status) => volatile_status,
+            // e.g. the end of a perish song count, which is not tracked
+            None => return Ok(()),
+        };
+        let side = self.state.get_side_immutable(&ident.side_ref);
+        let has_volatile_status = side.volatile_statuses.contains(&volatile_status);
+        let substitute_health = side.substitute_health;
+        let maxhp = side.get_active_immutable().maxhp;
+        if start && !has_volatile_status {
+            self.push(
+                instructions,
+                Instruction::ApplyVolatileStatus(ApplyVolatileStatusInstruction {
+                    side_ref: ident.side_ref,
+                    volatile_status,
+                }),
+            );
+            if volatile_status == PokemonVolatileStatus::SUBSTITUTE {
+                self.push(
+                    instructions,
+                    Instruction::ChangeSubstituteHealth(ChangeSubsituteHealthInstruction {
+                        side_ref: ident.side_ref,
+                        health_change: maxhp / 4 - substitute_health,
+                    }),
+                );
+            }
+        } else if !start && has_volatile_status {
+            self.push(
+                instructions,
+                Instruction::RemoveVolatileStatus(RemoveVolatileStatusInstruction {
+                    side_ref: ident.side_ref,
+                    volatile_status,
+                }),
+            );
+            if volatile_status == PokemonVolatileStatus::SUBSTITUTE && substitute_health != 0 {
+                self.push(
+                    instructions,
+                    Instruction::ChangeSubstituteHealth(ChangeSubsituteHealthInstruction {
+                        side_ref: ident.side_ref,
+                        health_change: -substitute_health,
+                    }),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// |-item|p2a: Tyranitar|Choice Band and |-enditem|p2a: Tyranitar|Choice Band
+    fn item(
+        &mut self,
+        ident: &str,
+        item: Option<&str>,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        let new_item = match item {
+            Some(item) => match parse_id::<Items>(item) {
+                Some(new_item) => new_item,
+                None => return Err(format!("unknown item \"{}\"", item)),
+            },
+            None => Items::NONE,
+        };
+        let current_item = self
+            .state
+            .get_side_immutable(&ident.side_ref)
+            .get_active_immutable()
+            .item;
+        if current_item != new_item {
+            self.push(
+                instructions,
+                Instruction::ChangeItem(ChangeItemInstruction {
+                    side_ref: ident.side_ref,
+                    current_item,
+                    new_item,
+                }),
+            );
+        }
+        Ok(())
+    }
+
+    /// |-ability|p2a: Tyranitar|Sand Stream
+    fn ability(
+        &mut self,
+        ident: &str,
+        ability: &str,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        let new_ability = match parse_id::<Abilities>(ability) {
+            Some(new_ability) => new_ability,
+            None => return Err(format!("unknown ability \"{}\"", ability)),
+        };
+        let active = self.state.get_side(&ident.side_ref).get_active();
+        let ability_change = new_ability as i16 - active.ability as i16;
+        // the first time an ability is seen it is also the pokemon's own ability
+        if active.base_ability == Abilities::NONE {
+            active.base_ability = new_ability;
+            self.state.update_zobrist_hash();
+        }
+        if ability_change != 0 {
+            self.push(
+                instructions,
+                Instruction::ChangeAbility(ChangeAbilityInstruction {
+                    side_ref: ident.side_ref,
+                    ability_change,
+                }),
+            );
+        }
+        Ok(())
+    }
+
+    /// |-terastallize|p2a: Tyranitar|Ghost
+    fn terastallize(
+        &mut self,
+        ident: &str,
+        tera_type: &str,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<(), String> {
+        let ident = self.parse_ident(ident)?;
+        let tera_type = match parse_id::<PokemonType>(tera_type) {
+            Some(tera_type) => tera_type,
+            None => return Err(format!("unknown type \"{}\"", tera_type)),
+        };
+        let active = self.state.get_side(&ident.side_ref).get_active();
+        if active.terastallized {
+            return Ok(());
+        }
+        active.tera_type = tera_type;
+        self.state.update_zobrist_hash();
+        self.push(
+            instructions,
+            Instruction::ToggleTerastallized(ToggleTerastallizedInstruction {
+                side_ref: ident.side_ref,
+            }),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Generation;
+
+    const LOG: &str = "|player|p1|Alice|1
+|player|p2|Bob|2
+|switch|p1a: Cress|Cresselia, L80, F|100/100
+|switch|p2a: Tyranitar|Tyranitar, L80, M|100/100
+|-weather|Sandstorm|[from] ability: Sand Stream|[of] p2a: Tyranitar
+|turn|1
+|move|p2a: Tyranitar|Stealth Rock|p1a: Cress
+|-sidestart|p1: Alice|move: Stealth Rock
+|move|p1a: Cress|Calm Mind|p1a: Cress
+|-boost|p1a: Cress|spa|1
+|-boost|p1a: Cress|spd|1
+|-weather|Sandstorm|[upkeep]
+|-damage|p1a: Cress|94/100|[from] Sandstorm
+|turn|2
+";
+
+    fn battle() -> ShowdownBattle {
+        ShowdownBattle::new(State::default(), "p1")
+    }
+
+    #[test]
+    fn test_log_reveals_pokemon_and_moves() {
+        let mut battle = battle();
+        battle.apply_log(LOG).unwrap();
+
+        let tyranitar = battle.state.side_two.get_active_immutable();
+        assert_eq!(PokemonName::TYRANITAR, tyranitar.id);
+        assert_eq!(80, tyranitar.level);
+        assert_eq!(Choices::STEALTHROCK, tyranitar.moves.m0.id);
+        assert_eq!(
+            Choices::CALMMIND,
+            battle.state.side_one.get_active_immutable().moves.m0.id
+        );
+    }
+
+    #[test]
+    fn test_log_changes_the_field() {
+        let mut battle = battle();
+        battle.apply_log(LOG).unwrap();
+
+        assert_eq!(Weather::SAND, battle.state.weather.weather_type);
+        // sand stream's sand lasts forever in gen4
+        assert_eq!(-1, battle.state.weather.turns_remaining);
+        assert_eq!(1, battle.state.side_one.side_conditions.stealth_rock);
+        assert_eq!(1, battle.state.side_one.special_attack_boost);
+        assert_eq!(1, battle.state.side_one.special_defense_boost);
+        let cresselia = battle.state.side_one.get_active_immutable();
+        assert_eq!((cresselia.maxhp as f32 * 0.94).round() as i16, cresselia.hp);
+    }
+
+    #[test]
+    fn test_reversing_the_instructions_gives_the_starting_state() {
+        let mut battle = battle();
+        battle
+            .apply_log("|switch|p2a: Tyranitar|Tyranitar, L80|100/100")
+            .unwrap();
+        let before = battle.state.serialize();
+
+        let instructions = battle
+            .apply_log(
+                "|-damage|p2a: Tyranitar|50/100 par
+|-unboost|p2a: Tyranitar|atk|2
+|-start|p2a: Tyranitar|Substitute
+|-sidestart|p2: Bob|Reflect
+|-fieldstart|move: Electric Terrain",
+            )
+            .unwrap();
+        assert_eq!(
+            PokemonStatus::PARALYZE,
+            battle.state.side_two.get_active_immutable().status
+        );
+        assert_eq!(
+            battle.state.side_two.get_active_immutable().maxhp / 4,
+            battle.state.side_two.substitute_health
+        );
+        battle.state.reverse_instructions(&instructions);
+        assert_eq!(before, battle.state.serialize());
+    }
+
+    #[test]
+    fn test_switching_clears_boosts_and_volatile_statuses() {
+        let mut battle = battle();
+        battle
+            .apply_log(
+                "|switch|p2a: Tyranitar|Tyranitar, L80|100/100
+|-boost|p2a: Tyranitar|atk|8
+|-start|p2a: Tyranitar|confusion
+|switch|p2a: Garchomp|Garchomp, L80|100/100",
+            )
+            .unwrap();
+
+        assert_eq!(PokemonIndex::P1, battle.state.side_two.active_index);
+        assert_eq!(0, battle.state.side_two.attack_boost);
+        assert!(!battle
+            .state
+            .side_two
+            .volatile_statuses
+            .contains(&PokemonVolatileStatus::CONFUSION));
+        assert_eq!(
+            PokemonName::TYRANITAR,
+            battle.state.side_two.pokemon[PokemonIndex::P0].id
+        );
+    }
+
+    #[test]
+    fn test_revealing_an_ability_keeps_the_zobrist_hash_up_to_date() {
+        let mut battle = battle();
+        battle
+            .apply_log(
+                "|switch|p2a: Tyranitar|Tyranitar, L80|100/100
+|-ability|p2a: Tyranitar|Sand Stream",
+            )
+            .unwrap();
+
+        let mut recomputed = battle.state.clone();
+        recomputed.update_zobrist_hash();
+        assert_eq!(recomputed.zobrist_hash, battle.state.zobrist_hash);
+    }
+
+    #[test]
+    fn test_setting_and_clearing_boosts() {
+        let mut battle = battle();
+        battle
+            .apply_log(
+                "|switch|p1a: Cress|Cresselia, L80|100/100
+|switch|p2a: Tyranitar|Tyranitar, L80|100/100
+|-setboost|p2a: Tyranitar|atk|6
+|-unboost|p2a: Tyranitar|spe|2
+|-boost|p1a: Cress|spa|1",
+            )
+            .unwrap();
+        assert_eq!(6, battle.state.side_two.attack_boost);
+
+        battle
+            .apply_message("|-clearnegativeboost|p2a: Tyranitar")
+            .unwrap();
+        assert_eq!(6, battle.state.side_two.attack_boost);
+        assert_eq!(0, battle.state.side_two.speed_boost);
+
+        battle.apply_message("|-clearallboost").unwrap();
+        assert_eq!(0, battle.state.side_two.attack_boost);
+        assert_eq!(0, battle.state.side_one.special_attack_boost);
+    }
+
+    #[test]
+    fn test_forme_change_changes_the_species_stats_and_types() {
+        let mut battle = battle();
+        battle
+            .apply_log("|switch|p2a: Charizard|Charizard, L80|100/100")
+            .unwrap();
+        let before = battle.state.serialize();
+        let attack_before = battle.state.side_two.get_active_immutable().attack;
+
+        let instructions = battle
+            .apply_message("|detailschange|p2a: Charizard|Charizard-Mega-X, L80")
+            .unwrap();
+        let charizard = battle.state.side_two.get_active_immutable();
+        assert_eq!(PokemonName::CHARIZARDMEGAX, charizard.id);
+        assert_eq!((PokemonType::FIRE, PokemonType::DRAGON), charizard.types);
+        assert!(charizard.attack > attack_before);
+
+        battle.state.reverse_instructions(&instructions);
+        assert_eq!(before, battle.state.serialize());
+    }
+
+    #[test]
+    fn test_transform_is_an_error() {
+        let mut battle = battle();
+        battle
+            .apply_log(
+                "|switch|p1a: Ditto|Ditto, L80|100/100
+|switch|p2a: Tyranitar|Tyranitar, L80|100/100",
+            )
+            .unwrap();
+        assert!(battle
+            .apply_message("|-transform|p1a: Ditto|p2a: Tyranitar")
+            .is_err());
+    }
+
+    #[test]
+    fn test_unknown_pokemon_is_an_error() {
+        let mut battle = battle();
+        assert!(battle.apply_message("|-status|p2: Tyranitar|par").is_err());
+    }
+
+    #[test]
+    fn test_weather_from_an_ability_lasts_5_turns_from_gen6() {
+        let mut battle = battle();
+        battle.state.set_generation(Generation::GEN9);
+        battle
+            .apply_log(
+                "|switch|p2a: Tyranitar|Tyranitar, L80|100/100
+|-weather|Sandstorm|[from] ability: Sand Stream|[of] p2a: Tyranitar",
+            )
+            .unwrap();
+        assert_eq!(WEATHER_DURATION, battle.state.weather.turns_remaining);
+    }
+
+    #[test]
+    fn test_weather_rock_extends_weather_from_a_move() {
+        let mut battle = battle();
+        battle
+            .apply_log(
+                "|switch|p1a: Cress|Cresselia, L80|100/100
+|-item|p1a: Cress|Heat Rock
+|move|p1a: Cress|Sunny Day|p1a: Cress
+|-weather|SunnyDay",
+            )
+            .unwrap();
+        assert_eq!(Weather::SUN, battle.state.weather.weather_type);
+        assert_eq!(WEATHER_ROCK_DURATION, battle.state.weather.turns_remaining);
+    }
+
+    #[test]
+    fn test_weather_rock_extends_weather_from_an_ability_from_gen6() {
+        let mut battle = battle();
+        battle.state.set_generation(Generation::GEN9);
+        battle
+            .apply_log(
+                "|switch|p2a: Tyranitar|Tyranitar, L80|100/100
+|-item|p2a: Tyranitar|Smooth Rock
+|-weather|Sandstorm|[from] ability: Sand Stream|[of] p2a: Tyranitar",
+            )
+            .unwrap();
+        assert_eq!(WEATHER_ROCK_DURATION, battle.state.weather.turns_remaining);
+    }
+
+    #[test]
+    fn test_position_that_is_not_a_player_is_an_error() {
+        let mut battle = battle();
+        assert!(battle
+            .apply_message("|-damage|pé: Tyranitar|50/100")
+            .is_err());
+        assert!(battle
+            .apply_message("|-damage|p: Tyranitar|50/100")
+            .is_err());
+    }
+}
@@ -11,14 +11,48 @@ use crate::instruction::{
 use crate::pokemon::PokemonName;
 use crate::state::VolatileStatusBitset;
 use crate::state::{
-    LastUsedMove, Pokemon, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex,
-    PokemonSideCondition, PokemonStatus, PokemonType, Side, SideReference, State,
+    Generation, LastUsedMove, Pokemon, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex,
+    PokemonNature, PokemonSideCondition, PokemonStatus, PokemonType, Side, SideReference, State,
 };
+use crate::{gen1, gen2, gen3};
 use core::panic;
 
-fn common_pkmn_stat_calc(stat: u16, ev: u16, level: u16) -> u16 {
-    // 31 IV always used
-    ((2 * stat + 31 + (ev / 4)) * level) / 100
+fn common_pkmn_stat_calc(stat: u16, ev: u16, iv: u16, level: u16) -> u16 {
+    ((2 * stat + iv + (ev / 4)) * level) / 100
+}
+
+/// Calculates (hp, attack, defense, special_attack, special_defense, speed) from base stats
+pub fn calculate_stats(
+    base_stats: (i16, i16, i16, i16, i16, i16),
+    level: i8,
+    evs: (u8, u8, u8, u8, u8, u8),
+    ivs: (u8, u8, u8, u8, u8, u8),
+    nature: &PokemonNature,
+) -> (i16, i16, i16, i16, i16, i16) {
+    let level = level as u16;
+    let stat = |base: i16, ev: u8, iv: u8, boostable_stat: PokemonBoostableStat| {
+        let value = (common_pkmn_stat_calc(base as u16, ev as u16, iv as u16, level) + 5) as i16;
+        nature.modify_stat(boostable_stat, value)
+    };
+    (
+        (common_pkmn_stat_calc(base_stats.0 as u16, evs.0 as u16, ivs.0 as u16, level) + level + 10)
+            as i16,
+        stat(base_stats.1, evs.1, ivs.1, PokemonBoostableStat::Attack),
+        stat(base_stats.2, evs.2, ivs.2, PokemonBoostableStat::Defense),
+        stat(
+            base_stats.3,
+            evs.3,
+            ivs.3,
+            PokemonBoostableStat::SpecialAttack,
+        ),
+        stat(
+            base_stats.4,
+            evs.4,
+            ivs.4,
+            PokemonBoostableStat::SpecialDefense,
+        ),
+        stat(base_stats.5, evs.5, ivs.5, PokemonBoostableStat::Speed),
+    )
 }
 
 fn multiply_boost(boost_num: i8, stat_value: i16) -> i16 {
@@ -41,6 +75,7 @@ fn multiply_boost(boost_num: i8, stat_value: i16) -> i16 {
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MoveChoice {
     MoveTera(PokemonMoveIndex),
     MoveMega(PokemonMoveIndex),
@@ -219,6 +254,9 @@ define_enum_with_from_str! {
         UNBURDEN,
         UPROAR,
         YAWN,
+
+        GEN1BURNNULLIFY,
+        GEN1PARALYSISNULLIFY,
     },
     default = NONE
 }
@@ -251,6 +289,42 @@ define_enum_with_from_str! {
 }
 
 impl Pokemon {
+    /// Creates a pokemon at full health from its species' pokedex entry in `generation`.
+    /// Types, weight and stats come from the species and the ability is its first legal ability
+    pub fn from_species(
+        id: PokemonName,
+        generation: Generation,
+        level: i8,
+        evs: (u8, u8, u8, u8, u8, u8),
+        ivs: (u8, u8, u8, u8, u8, u8),
+        nature: PokemonNature,
+    ) -> Pokemon {
+        let entry = id.pokedex_entry_in(generation);
+        let stats = calculate_stats(entry.base_stats, level, evs, ivs, &nature);
+        let ability = id.abilities().first().copied().unwrap_or(Abilities::NONE);
+        Pokemon {
+            id,
+            level,
+            types: entry.types,
+            base_types: entry.types,
+            hp: stats.0,
+            maxhp: stats.0,
+            ability,
+            base_ability: ability,
+            nature,
+            evs,
+            ivs,
+            attack: stats.1,
+            defense: stats.2,
+            special_attack: stats.3,
+            special_defense: stats.4,
+            speed: stats.5,
+            weight_kg: entry.weight_kg,
+            tera_type: entry.types.0,
+            ..Default::default()
+        }
+    }
+
     pub fn can_mega_evolve(&self) -> bool {
         // this assumes that if you have the correct mega stone, you can always mega evolve
         // even if another pkmn on the team already mega evolved
@@ -311,21 +385,13 @@ impl Pokemon {
         }
     }
     pub fn calculate_stats_from_base_stats(&self) -> (i16, i16, i16, i16, i16, i16) {
-        let base_stats = self.id.base_stats();
-        (
-            (common_pkmn_stat_calc(base_stats.0 as u16, self.evs.0 as u16, self.level as u16)
-                + self.level as u16
-                + 10) as i16,
-            (common_pkmn_stat_calc(base_stats.1 as u16, self.evs.1 as u16, self.level as u16) + 5)
-                as i16,
-            (common_pkmn_stat_calc(base_stats.2 as u16, self.evs.2 as u16, self.level as u16) + 5)
-                as i16,
-            (common_pkmn_stat_calc(base_stats.3 as u16, self.evs.3 as u16, self.level as u16) + 5)
-                as i16,
-            (common_pkmn_stat_calc(base_stats.4 as u16, self.evs.4 as u16, self.level as u16) + 5)
-                as i16,
-            (common_pkmn_stat_calc(base_stats.5 as u16, self.evs.5 as u16, self.level as u16) + 5)
-                as i16,
+        // the nature is not applied
+        calculate_stats(
+            self.id.base_stats(),
+            self.level,
+            self.evs,
+            self.ivs,
+            &PokemonNature::SERIOUS,
         )
     }
     pub fn add_available_moves(
@@ -381,7 +447,6 @@ impl Pokemon {
         }
     }
 
-    #[cfg(feature = "terastallization")]
     pub fn has_type(&self, pkmn_type: &PokemonType) -> bool {
         if self.terastallized {
             pkmn_type == &self.tera_type
@@ -390,11 +455,6 @@ impl Pokemon {
         }
     }
 
-    #[cfg(not(feature = "terastallization"))]
-    pub fn has_type(&self, pkmn_type: &PokemonType) -> bool {
-        pkmn_type == &self.types.0 || pkmn_type == &self.types.1
-    }
-
     pub fn item_is_permanent(&self) -> bool {
         match self.item {
             Items::LUSTROUSGLOBE => self.id == PokemonName::PALKIAORIGIN,
@@ -671,16 +731,17 @@ impl Side {
         None
     }
 
-    pub fn calculate_highest_stat(&self) -> PokemonBoostableStat {
+    pub fn calculate_highest_stat(&self, generation: Generation) -> PokemonBoostableStat {
         let mut highest_stat = PokemonBoostableStat::Attack;
-        let mut highest_stat_value = self.calculate_boosted_stat(PokemonBoostableStat::Attack);
+        let mut highest_stat_value =
+            self.calculate_boosted_stat(generation, PokemonBoostableStat::Attack);
         for stat in [
             PokemonBoostableStat::Defense,
             PokemonBoostableStat::SpecialAttack,
             PokemonBoostableStat::SpecialDefense,
             PokemonBoostableStat::Speed,
         ] {
-            let stat_value = self.calculate_boosted_stat(stat);
+            let stat_value = self.calculate_boosted_stat(generation, stat);
             if stat_value > highest_stat_value {
                 highest_stat = stat;
                 highest_stat_value = stat_value;
@@ -700,7 +761,11 @@ impl Side {
         }
     }
 
-    pub fn calculate_boosted_stat(&self, stat: PokemonBoostableStat) -> i16 {
+    pub fn calculate_boosted_stat(
+        &self,
+        generation: Generation,
+        stat: PokemonBoostableStat,
+    ) -> i16 {
         /*
         In Gen4, simple doubles the effective boost, without it visually being doubled
         It will not boost beyond an effective value of 6 though.
@@ -708,63 +773,52 @@ impl Side {
         let active = self.get_active_immutable();
         match stat {
             PokemonBoostableStat::Attack => {
-                #[cfg(feature = "gen4")]
-                let boost = if active.ability == Abilities::SIMPLE {
+                let boost = if generation == Generation::GEN4 && active.ability == Abilities::SIMPLE
+                {
                     (self.attack_boost * 2).min(6).max(-6)
                 } else {
                     self.attack_boost
                 };
 
-                #[cfg(not(feature = "gen4"))]
-                let boost = self.attack_boost;
-
                 multiply_boost(boost, active.attack)
             }
             PokemonBoostableStat::Defense => {
-                #[cfg(feature = "gen4")]
-                let boost = if active.ability == Abilities::SIMPLE {
+                let boost = if generation == Generation::GEN4 && active.ability == Abilities::SIMPLE
+                {
                     (self.defense_boost * 2).min(6).max(-6)
                 } else {
                     self.defense_boost
                 };
-                #[cfg(not(feature = "gen4"))]
-                let boost = self.defense_boost;
 
                 multiply_boost(boost, active.defense)
             }
             PokemonBoostableStat::SpecialAttack => {
-                #[cfg(feature = "gen4")]
-                let boost = if active.ability == Abilities::SIMPLE {
+                let boost = if generation == Generation::GEN4 && active.ability == Abilities::SIMPLE
+                {
                     (self.special_attack_boost * 2).min(6).max(-6)
                 } else {
                     self.special_attack_boost
                 };
-                #[cfg(not(feature = "gen4"))]
-                let boost = self.special_attack_boost;
 
                 multiply_boost(boost, active.special_attack)
             }
             PokemonBoostableStat::SpecialDefense => {
-                #[cfg(feature = "gen4")]
-                let boost = if active.ability == Abilities::SIMPLE {
+                let boost = if generation == Generation::GEN4 && active.ability == Abilities::SIMPLE
+                {
                     (self.special_defense_boost * 2).min(6).max(-6)
                 } else {
                     self.special_defense_boost
                 };
-                #[cfg(not(feature = "gen4"))]
-                let boost = self.special_defense_boost;
 
                 multiply_boost(boost, active.special_defense)
             }
             PokemonBoostableStat::Speed => {
-                #[cfg(feature = "gen4")]
-                let boost = if active.ability == Abilities::SIMPLE {
+                let boost = if generation == Generation::GEN4 && active.ability == Abilities::SIMPLE
+                {
                     (self.speed_boost * 2).min(6).max(-6)
                 } else {
                     self.speed_boost
                 };
-                #[cfg(not(feature = "gen4"))]
-                let boost = self.speed_boost;
 
                 multiply_boost(boost, active.speed)
             }
@@ -783,12 +837,6 @@ impl Side {
         false
     }
 
-    #[cfg(not(feature = "terastallization"))]
-    pub fn can_use_tera(&self) -> bool {
-        false
-    }
-
-    #[cfg(feature = "terastallization")]
     pub fn can_use_tera(&self) -> bool {
         for p in self.pokemon.into_iter() {
             if p.terastallized {
@@ -853,6 +901,12 @@ impl Side {
 
 impl State {
     pub fn root_get_all_options(&self) -> (Vec<MoveChoice>, Vec<MoveChoice>) {
+        match self.generation {
+            Generation::GEN1 => return gen1::state::root_get_all_options(self),
+            Generation::GEN2 => return gen2::state::root_get_all_options(self),
+            Generation::GEN3 => return gen3::state::root_get_all_options(self),
+            _ => {}
+        }
         if self.team_preview {
             let mut s1_options = Vec::with_capacity(6);
             let mut s2_options = Vec::with_capacity(6);
@@ -896,7 +950,7 @@ impl State {
                 &self.side_one.last_used_move,
                 encored,
                 taunted,
-                self.side_one.can_use_tera(),
+                self.terastallization && self.side_one.can_use_tera(),
             );
         }
 
@@ -922,7 +976,7 @@ impl State {
                 &self.side_two.last_used_move,
                 encored,
                 taunted,
-                self.side_two.can_use_tera(),
+                self.terastallization && self.side_two.can_use_tera(),
             );
         }
 
@@ -937,6 +991,12 @@ impl State {
     }
 
     pub fn get_all_options(&self) -> (Vec<MoveChoice>, Vec<MoveChoice>) {
+        match self.generation {
+            Generation::GEN1 => return gen1::state::get_all_options(self),
+            Generation::GEN2 => return gen2::state::get_all_options(self),
+            Generation::GEN3 => return gen3::state::get_all_options(self),
+            _ => {}
+        }
         let mut side_one_options: Vec<MoveChoice> = Vec::with_capacity(9);
         let mut side_two_options: Vec<MoveChoice> = Vec::with_capacity(9);
 
@@ -1010,7 +1070,7 @@ impl State {
                 &self.side_one.last_used_move,
                 encored,
                 taunted,
-                self.side_one.can_use_tera(),
+                self.terastallization && self.side_one.can_use_tera(),
             );
             if !self.side_one.trapped(side_two_active) {
                 self.side_one.add_switches(&mut side_one_options);
@@ -1039,7 +1099,7 @@ impl State {
                 &self.side_two.last_used_move,
                 encored,
                 taunted,
-                self.side_two.can_use_tera(),
+                self.terastallization && self.side_two.can_use_tera(),
             );
             if !self.side_two.trapped(side_one_active) {
                 self.side_two.add_switches(&mut side_two_options);
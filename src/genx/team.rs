@@ -0,0 +1,477 @@
+// Import and export of teams in the Pokémon Showdown export format, i.e.
+//
+// Garchomp @ Choice Scarf
+// Ability: Rough Skin
+// Tera Type: Steel
+// EVs: 252 Atk / 4 SpD / 252 Spe
+// Jolly Nature
+// - Earthquake
+// - Outrage
+//
+// Pokemon are separated by a blank line.
+use super::abilities::Abilities;
+use super::items::Items;
+use crate::choices::{moves, Choices};
+use crate::pokemon::PokemonName;
+use crate::state::{
+    Generation, Move, Pokemon, PokemonMoves, PokemonNature, PokemonType, Side, SidePokemon,
+};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamParseError {
+    /// index of the pokemon in the team that could not be parsed
+    pub pokemon_index: usize,
+    /// the line that could not be parsed
+    pub line: String,
+    pub message: String,
+}
+
+impl fmt::Display for TeamParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "pokemon {} \"{}\": {}",
+            self.pokemon_index, self.line, self.message
+        )
+    }
+}
+
+impl std::error::Error for TeamParseError {}
+
+// Showdown ids ignore case, spaces and punctuation: "U-turn" and "uturn" are the same move
+fn to_id(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_uppercase()
+}
+
+// `from_str` falls back to a default variant for unknown names, so the name is checked too
+pub(crate) fn parse_id<T: FromStr + fmt::Display>(name: &str) -> Option<T> {
+    let id = to_id(name);
+    match T::from_str(&id) {
+        Ok(parsed) if parsed.to_string() == id => Some(parsed),
+        _ => None,
+    }
+}
+
+pub(crate) fn parse_move(name: &str, generation: Generation) -> Option<Choices> {
+    let id = to_id(name);
+    // hiddenpower's base power is part of the move's name in the engine
+    if id.starts_with("HIDDENPOWER") && id != "HIDDENPOWER" {
+        let base_power = if generation >= Generation::GEN6 {
+            60
+        } else {
+            70
+        };
+        return parse_id(&format!("{}{}", id, base_power));
+    }
+    parse_id(&id)
+}
+
+// parses "252 Atk / 4 SpD / 252 Spe" on top of `default`
+fn parse_stat_spread(spread: &str, default: u8) -> Result<(u8, u8, u8, u8, u8, u8), String> {
+    let mut values = [default; 6];
+    for part in spread.split("/") {
+        let mut split = part.split_whitespace();
+        let value = match split.next().map(|v| v.parse::<u8>()) {
+            Some(Ok(value)) => value,
+            _ => return Err(format!("invalid stat value \"{}\"", part.trim())),
+        };
+        let index = match split.next().map(|s| s.to_lowercase()).as_deref() {
+            Some("hp") => 0,
+            Some("atk") => 1,
+            Some("def") => 2,
+            Some("spa") => 3,
+            Some("spd") => 4,
+            Some("spe") => 5,
+            _ => return Err(format!("invalid stat \"{}\"", part.trim())),
+        };
+        values[index] = value;
+    }
+    Ok((
+        values[0], values[1], values[2], values[3], values[4], values[5],
+    ))
+}
+
+/// Parses a single pokemon in the Showdown export format.
+/// Anything not given, such as the ability, comes from the species' pokedex entry
+pub fn import_pokemon(text: &str, generation: Generation) -> Result<Pokemon, TeamParseError> {
+    let error = |line: &str, message: String| TeamParseError {
+        pokemon_index: 0,
+        line: line.to_string(),
+        message,
+    };
+    let mut lines = text.lines().map(|l| l.trim()).filter(|l| !l.is_empty());
+
+    // Nickname (Species) (M) @ Item
+    let first_line = match lines.next() {
+        Some(line) => line,
+        None => return Err(error("", "missing species".to_string())),
+    };
+    let (name_part, item_part) = match first_line.split_once(" @ ") {
+        Some((name, item)) => (name.trim(), Some(item.trim())),
+        None => (first_line, None),
+    };
+    let name_part = name_part
+        .trim_end_matches(" (M)")
+        .trim_end_matches(" (F)")
+        .trim();
+    let species_name = match (name_part.rfind("("), name_part.ends_with(")")) {
+        (Some(open), true) => &name_part[open + 1..name_part.len() - 1],
+        _ => name_part,
+    };
+
+    let id = match parse_id::<PokemonName>(species_name) {
+        Some(id) => id,
+        None => {
+            return Err(error(
+                first_line,
+                format!("unknown species \"{}\"", species_name),
+            ))
+        }
+    };
+    let item = match item_part {
+        Some(item) => match parse_id::<Items>(item) {
+            Some(item) => item,
+            None => return Err(error(first_line, format!("unknown item \"{}\"", item))),
+        },
+        None => Items::NONE,
+    };
+    let mut level = 100;
+    let mut evs = (0, 0, 0, 0, 0, 0);
+    let mut ivs = (31, 31, 31, 31, 31, 31);
+    let mut nature = PokemonNature::SERIOUS;
+    let mut ability = None;
+    let mut tera_type = None;
+    let mut move_ids = vec![];
+    for line in lines {
+        if let Some(move_name) = line.strip_prefix("-") {
+            // alternatives are written as "- Move A / Move B", the first one is used
+            let move_name = move_name.split("/").next().unwrap_or("").trim();
+            match parse_move(move_name, generation) {
+                Some(move_id) => move_ids.push(move_id),
+                None => return Err(error(line, format!("unknown move \"{}\"", move_name))),
+            }
+        } else if let Some(ability_name) = line.strip_prefix("Ability:") {
+            ability = match parse_id::<Abilities>(ability_name) {
+                Some(ability) => Some(ability),
+                None => return Err(error(line, "unknown ability".to_string())),
+            };
+        } else if let Some(level_value) = line.strip_prefix("Level:") {
+            level = match level_value.trim().parse::<i8>() {
+                Ok(level) if level > 0 => level,
+                _ => return Err(error(line, "invalid level".to_string())),
+            };
+        } else if let Some(tera_type_name) = line.strip_prefix("Tera Type:") {
+            tera_type = match parse_id::<PokemonType>(tera_type_name) {
+                Some(tera_type) => Some(tera_type),
+                None => return Err(error(line, "unknown type".to_string())),
+            };
+        } else if let Some(ev_spread) = line.strip_prefix("EVs:") {
+            evs = parse_stat_spread(ev_spread, 0).map_err(|e| error(line, e))?;
+        } else if let Some(iv_spread) = line.strip_prefix("IVs:") {
+            ivs = parse_stat_spread(iv_spread, 31).map_err(|e| error(line, e))?;
+        } else if let Some(nature_name) = line.strip_suffix(" Nature") {
+            nature = match parse_id(nature_name) {
+                Some(nature) => nature,
+                None => return Err(error(line, "unknown nature".to_string())),
+            };
+        }
+        // other lines such as "Shiny: Yes" or "Happiness: 0" do not matter to the engine
+    }
+    if move_ids.len() > 4 {
+        return Err(error(first_line, "more than 4 moves".to_string()));
+    }
+
+    let mut pkmn = Pokemon::from_species(id, generation, level, evs, ivs, nature);
+    pkmn.item = item;
+    if let Some(ability) = ability {
+        pkmn.ability = ability;
+        pkmn.base_ability = ability;
+    }
+    if let Some(tera_type) = tera_type {
+        pkmn.tera_type = tera_type;
+    }
+
+    let generation_moves = moves(generation);
+    let mut pkmn_moves = move_ids.into_iter().map(|id| Move {
+        id,
+        choice: generation_moves.get(&id).unwrap().to_owned(),
+        ..Default::default()
+    });
+    pkmn.moves = PokemonMoves {
+        m0: pkmn_moves.next().unwrap_or_default(),
+        m1: pkmn_moves.next().unwrap_or_default(),
+        m2: pkmn_moves.next().unwrap_or_default(),
+        m3: pkmn_moves.next().unwrap_or_default(),
+    };
+    Ok(pkmn)
+}
+
+/// Parses a team of up to 6 pokemon in the Showdown export format.
+/// Unused slots are filled with fainted pokemon
+pub fn import_team(text: &str, generation: Generation) -> Result<Side, TeamParseError> {
+    let mut team = vec![];
+    let mut current = String::new();
+    for line in text.lines().chain(std::iter::once("")) {
+        // "=== [gen9ou] Team Name ===" headers from the teambuilder are skipped
+        if line.trim().is_empty() || line.trim().starts_with("===") {
+            if !current.is_empty() {
+                let pkmn = import_pokemon(&current, generation).map_err(|mut e| {
+                    e.pokemon_index = team.len();
+                    e
+                })?;
+                team.push(pkmn);
+                current.clear();
+            }
+        } else {
+            current.push_str(line);
+            current.push('\n');
+        }
+    }
+    if team.len() > 6 {
+        return Err(TeamParseError {
+            pokemon_index: 6,
+            line: String::new(),
+            message: "a team has at most 6 pokemon".to_string(),
+        });
+    }
+    while team.len() < 6 {
+        team.push(Pokemon {
+            id: PokemonName::NONE,
+            hp: 0,
+            ..Default::default()
+        });
+    }
+
+    let mut side = Side::default();
+    let mut team = team.into_iter();
+    side.pokemon = SidePokemon {
+        pkmn: [(); 6].map(|_| team.next().unwrap()),
+    };
+    Ok(side)
+}
+
+fn capitalize(name: &str) -> String {
+    let lowercase = name.to_lowercase();
+    let mut chars = lowercase.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => lowercase,
+    }
+}
+
+/// Writes a pokemon in the Showdown export format.
+/// Names are written as Showdown ids, which Showdown accepts when importing
+pub fn export_pokemon(pkmn: &Pokemon) -> String {
+    let mut lines = vec![];
+    if pkmn.item == Items::NONE {
+        lines.push(pkmn.id.to_string().to_lowercase());
+    } else {
+        lines.push(format!(
+            "{} @ {}",
+            pkmn.id.to_string().to_lowercase(),
+            pkmn.item.to_string().to_lowercase()
+        ));
+    }
+    if pkmn.ability != Abilities::NONE {
+        lines.push(format!(
+            "Ability: {}",
+            pkmn.ability.to_string().to_lowercase()
+        ));
+    }
+    if pkmn.level != 100 {
+        lines.push(format!("Level: {}", pkmn.level));
+    }
+    lines.push(format!(
+        "Tera Type: {}",
+        capitalize(&pkmn.tera_type.to_string())
+    ));
+
+    let evs = [
+        (pkmn.evs.0, "HP"),
+        (pkmn.evs.1, "Atk"),
+        (pkmn.evs.2, "Def"),
+        (pkmn.evs.3, "SpA"),
+        (pkmn.evs.4, "SpD"),
+        (pkmn.evs.5, "Spe"),
+    ];
+    let evs: Vec<String> = evs
+        .iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, stat)| format!("{} {}", value, stat))
+        .collect();
+    if !evs.is_empty() {
+        lines.push(format!("EVs: {}", evs.join(" / ")));
+    }
+    lines.push(format!("{} Nature", capitalize(&pkmn.nature.to_string())));
+
+    let ivs = [
+        (pkmn.ivs.0, "HP"),
+        (pkmn.ivs.1, "Atk"),
+        (pkmn.ivs.2, "Def"),
+        (pkmn.ivs.3, "SpA"),
+        (pkmn.ivs.4, "SpD"),
+        (pkmn.ivs.5, "Spe"),
+    ];
+    let ivs: Vec<String> = ivs
+        .iter()
+        .filter(|(value, _)| *value != 31)
+        .map(|(value, stat)| format!("{} {}", value, stat))
+        .collect();
+    if !ivs.is_empty() {
+        lines.push(format!("IVs: {}", ivs.join(" / ")));
+    }
+
+    for m in pkmn.moves.into_iter() {
+        if m.id == Choices::NONE {
+            continue;
+        }
+        let move_name = m.id.to_string().to_lowercase();
+        if move_name.starts_with("hiddenpower") {
+            lines.push(format!(
+                "- {}",
+                move_name.trim_end_matches(|c: char| c.is_ascii_digit())
+            ));
+        } else {
+            lines.push(format!("- {}", move_name));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Writes every pokemon on a side in the Showdown export format
+pub fn export_team(side: &Side) -> String {
+    side.pokemon
+        .into_iter()
+        .filter(|pkmn| pkmn.id != PokemonName::NONE)
+        .map(export_pokemon)
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::PokemonIndex;
+
+    const PALAFIN: &str = "Palafin @ Choice Band
+Ability: Zero to Hero
+Tera Type: Water
+EVs: 4 HP / 252 Atk / 252 Spe
+Adamant Nature
+IVs: 0 SpA
+- Jet Punch
+- Wave Crash
+- Close Combat
+- U-turn";
+
+    #[test]
+    fn test_import_pokemon() {
+        let pkmn = import_pokemon(PALAFIN, Generation::GEN9).unwrap();
+        assert_eq!(PokemonName::PALAFIN, pkmn.id);
+        assert_eq!(Items::CHOICEBAND, pkmn.item);
+        assert_eq!(Abilities::ZEROTOHERO, pkmn.ability);
+        assert_eq!(PokemonType::WATER, pkmn.tera_type);
+        assert_eq!(PokemonNature::ADAMANT, pkmn.nature);
+        assert_eq!((4, 252, 0, 0, 0, 252), pkmn.evs);
+        assert_eq!((31, 31, 31, 0, 31, 31), pkmn.ivs);
+        assert_eq!(Choices::UTURN, pkmn.moves.m3.id);
+
+        // base stats 100/70/72/53/62/100
+        assert_eq!(342, pkmn.maxhp);
+        assert_eq!(262, pkmn.attack);
+        assert_eq!(180, pkmn.defense);
+        assert_eq!(99, pkmn.special_attack); // 0 IVs and lowered by the nature
+        assert_eq!(160, pkmn.special_defense);
+        assert_eq!(299, pkmn.speed);
+    }
+
+    #[test]
+    fn test_import_nickname_gender_and_level() {
+        let pkmn = import_pokemon(
+            "Fishy (Palafin) (F) @ Leftovers\nLevel: 50\n- Jet Punch",
+            Generation::GEN9,
+        )
+        .unwrap();
+        assert_eq!(PokemonName::PALAFIN, pkmn.id);
+        assert_eq!(Items::LEFTOVERS, pkmn.item);
+        assert_eq!(50, pkmn.level);
+        assert_eq!(Choices::NONE, pkmn.moves.m1.id);
+    }
+
+    #[test]
+    fn test_import_fills_in_the_species_data() {
+        let pkmn = import_pokemon("Garchomp\n- Earthquake", Generation::GEN9).unwrap();
+        assert_eq!((PokemonType::DRAGON, PokemonType::GROUND), pkmn.types);
+        assert_eq!(pkmn.types, pkmn.base_types);
+        assert_eq!(PokemonType::DRAGON, pkmn.tera_type);
+        assert_eq!(95.0, pkmn.weight_kg);
+        assert_eq!(Abilities::SANDVEIL, pkmn.ability);
+        assert_eq!(pkmn.maxhp, pkmn.hp);
+    }
+
+    #[test]
+    fn test_import_uses_the_species_data_of_the_generation() {
+        let gen9 = import_pokemon("Clefable\n- Moonblast", Generation::GEN9).unwrap();
+        let gen4 = import_pokemon("Clefable\n- Psychic", Generation::GEN4).unwrap();
+        assert_eq!((PokemonType::FAIRY, PokemonType::TYPELESS), gen9.types);
+        assert_eq!((PokemonType::NORMAL, PokemonType::TYPELESS), gen4.types);
+
+        // base special attack was 85 before gen6 and 95 after
+        assert_eq!(gen9.special_attack - 20, gen4.special_attack);
+        assert_eq!(gen9.special_defense, gen4.special_defense);
+    }
+
+    #[test]
+    fn test_import_hidden_power_uses_the_generation_base_power() {
+        let text = "Palafin\n- Hidden Power [Fire]";
+        let gen5 = import_pokemon(text, Generation::GEN5).unwrap();
+        let gen6 = import_pokemon(text, Generation::GEN6).unwrap();
+        assert_eq!(Choices::HIDDENPOWERFIRE70, gen5.moves.m0.id);
+        assert_eq!(Choices::HIDDENPOWERFIRE60, gen6.moves.m0.id);
+    }
+
+    #[test]
+    fn test_import_unknown_move_is_an_error() {
+        let error = import_team(
+            &format!("{}\n\nPalafin\n- Not A Move", PALAFIN),
+            Generation::GEN9,
+        )
+        .unwrap_err();
+        assert_eq!(1, error.pokemon_index);
+        assert_eq!("- Not A Move", error.line);
+    }
+
+    #[test]
+    fn test_import_team_fills_empty_slots_with_fainted_pokemon() {
+        let side = import_team(PALAFIN, Generation::GEN9).unwrap();
+        assert_eq!(PokemonName::PALAFIN, side.pokemon[PokemonIndex::P0].id);
+        assert_eq!(PokemonName::NONE, side.pokemon[PokemonIndex::P1].id);
+        assert_eq!(0, side.pokemon[PokemonIndex::P5].hp);
+    }
+
+    #[test]
+    fn test_export_then_import_gives_the_same_pokemon() {
+        let side = import_team(PALAFIN, Generation::GEN9).unwrap();
+        let exported = export_team(&side);
+        let reimported = import_team(&exported, Generation::GEN9).unwrap();
+
+        let original = &side.pokemon[PokemonIndex::P0];
+        let pkmn = &reimported.pokemon[PokemonIndex::P0];
+        assert_eq!(original.serialize(), pkmn.serialize());
+    }
+
+    #[test]
+    fn test_export_writes_the_ivs_that_are_not_31() {
+        let side = import_team(PALAFIN, Generation::GEN9).unwrap();
+        let exported = export_team(&side);
+        assert!(exported.contains("\nIVs: 0 SpA\n"));
+
+        let side = import_team("Palafin\n- Jet Punch", Generation::GEN9).unwrap();
+        assert!(!export_team(&side).contains("IVs:"));
+    }
+}
@@ -9,6 +9,7 @@ use std::fmt;
 use std::fmt::Formatter;
 
 #[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateInstructions {
     pub percentage: f32,
     pub instruction_list: Vec<Instruction>,
@@ -39,8 +40,108 @@ impl StateInstructions {
     }
 }
 
+// the percentage below which branches are pruned when the remove_low_chance_instructions
+// feature is enabled and no other threshold is given
+#[cfg(feature = "remove_low_chance_instructions")]
+const DEFAULT_MIN_PERCENTAGE: f32 = 1.0;
+#[cfg(not(feature = "remove_low_chance_instructions"))]
+const DEFAULT_MIN_PERCENTAGE: f32 = 0.0;
+
+/// What happens to the chance of the branches that `BranchPruning` removes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrunedBranches {
+    /// The surviving branches are scaled up so that their total chance is unchanged
+    Renormalize,
+    /// Each removed branch's chance is added to the surviving branch that shares the
+    /// longest run of leading instructions with it, the more likely one on a tie
+    MergeIntoClosest,
+}
+
+/// Removes the unlikely branches of a move pair before they are searched.
+/// The most likely branch is never removed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BranchPruning {
+    /// Branches less likely than this percentage are removed. 0 removes nothing
+    pub min_percentage: f32,
+    pub pruned_branches: PrunedBranches,
+}
+
+impl Default for BranchPruning {
+    fn default() -> BranchPruning {
+        BranchPruning {
+            min_percentage: DEFAULT_MIN_PERCENTAGE,
+            pruned_branches: PrunedBranches::Renormalize,
+        }
+    }
+}
+
+impl BranchPruning {
+    pub fn prune(&self, instructions: &mut Vec<StateInstructions>) {
+        if self.min_percentage <= 0.0 || instructions.len() < 2 {
+            return;
+        }
+        let most_likely = instructions
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.percentage.total_cmp(&b.percentage))
+            .map(|(index, _)| index)
+            .unwrap();
+        if instructions
+            .iter()
+            .enumerate()
+            .all(|(index, i)| index == most_likely || i.percentage >= self.min_percentage)
+        {
+            return;
+        }
+
+        let mut kept = Vec::with_capacity(instructions.len());
+        let mut pruned = Vec::new();
+        for (index, state_instructions) in instructions.drain(..).enumerate() {
+            if index == most_likely || state_instructions.percentage >= self.min_percentage {
+                kept.push(state_instructions);
+            } else {
+                pruned.push(state_instructions);
+            }
+        }
+        match self.pruned_branches {
+            PrunedBranches::Renormalize => {
+                let pruned_total: f32 = pruned.iter().map(|i| i.percentage).sum();
+                let kept_total: f32 = kept.iter().map(|i| i.percentage).sum();
+                if kept_total > 0.0 {
+                    let modifier = (kept_total + pruned_total) / kept_total;
+                    for state_instructions in kept.iter_mut() {
+                        state_instructions.update_percentage(modifier);
+                    }
+                }
+            }
+            PrunedBranches::MergeIntoClosest => {
+                for removed in pruned {
+                    let shared_prefix = |i: &StateInstructions| {
+                        i.instruction_list
+                            .iter()
+                            .zip(removed.instruction_list.iter())
+                            .take_while(|(a, b)| a == b)
+                            .count()
+                    };
+                    let closest = kept
+                        .iter_mut()
+                        .max_by(|a, b| {
+                            shared_prefix(a)
+                                .cmp(&shared_prefix(b))
+                                .then(a.percentage.total_cmp(&b.percentage))
+                        })
+                        .unwrap();
+                    closest.percentage += removed.percentage;
+                }
+            }
+        }
+        *instructions = kept;
+    }
+}
+
 // https://stackoverflow.com/questions/50686411/whats-the-usual-way-to-create-a-vector-of-different-structs
 #[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Instruction {
     Switch(SwitchInstruction),
     ApplyVolatileStatus(ApplyVolatileStatusInstruction),
@@ -337,12 +438,14 @@ impl fmt::Debug for Instruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeDamageDealtDamageInstruction {
     pub side_ref: SideReference,
     pub damage_change: i16,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeDamageDealtMoveCategoryInstruction {
     pub side_ref: SideReference,
     pub move_category: MoveCategory,
@@ -350,11 +453,13 @@ pub struct ChangeDamageDealtMoveCategoryInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToggleDamageDealtHitSubstituteInstruction {
     pub side_ref: SideReference,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DecrementPPInstruction {
     pub side_ref: SideReference,
     pub move_index: PokemonMoveIndex,
@@ -362,6 +467,7 @@ pub struct DecrementPPInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetLastUsedMoveInstruction {
     pub side_ref: SideReference,
     pub last_used_move: LastUsedMove,
@@ -369,21 +475,25 @@ pub struct SetLastUsedMoveInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToggleBatonPassingInstruction {
     pub side_ref: SideReference,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToggleShedTailingInstruction {
     pub side_ref: SideReference,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DecrementRestTurnsInstruction {
     pub side_ref: SideReference,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetSleepTurnsInstruction {
     pub side_ref: SideReference,
     pub pokemon_index: PokemonIndex,
@@ -392,23 +502,27 @@ pub struct SetSleepTurnsInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetSecondMoveSwitchOutMoveInstruction {
     pub new_choice: Choices,
     pub previous_choice: Choices,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeWishInstruction {
     pub side_ref: SideReference,
     pub wish_amount_change: i16,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DecrementWishInstruction {
     pub side_ref: SideReference,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetFutureSightInstruction {
     pub side_ref: SideReference,
     pub pokemon_index: PokemonIndex,
@@ -416,23 +530,27 @@ pub struct SetFutureSightInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DecrementFutureSightInstruction {
     pub side_ref: SideReference,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnableMoveInstruction {
     pub side_ref: SideReference,
     pub move_index: PokemonMoveIndex,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DisableMoveInstruction {
     pub side_ref: SideReference,
     pub move_index: PokemonMoveIndex,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeItemInstruction {
     pub side_ref: SideReference,
     pub current_item: Items,
@@ -440,30 +558,35 @@ pub struct ChangeItemInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeStatInstruction {
     pub side_ref: SideReference,
     pub amount: i16,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HealInstruction {
     pub side_ref: SideReference,
     pub heal_amount: i16,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DamageInstruction {
     pub side_ref: SideReference,
     pub damage_amount: i16,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeSubsituteHealthInstruction {
     pub side_ref: SideReference,
     pub health_change: i16,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FormeChangeInstruction {
     pub side_ref: SideReference,
 
@@ -473,6 +596,7 @@ pub struct FormeChangeInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwitchInstruction {
     pub side_ref: SideReference,
     pub previous_index: PokemonIndex,
@@ -482,6 +606,7 @@ pub struct SwitchInstruction {
 // pokemon_index is present because even reserve pokemon can have their status
 // changed (i.e. healbell)
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeStatusInstruction {
     pub side_ref: SideReference,
     pub pokemon_index: PokemonIndex,
@@ -490,18 +615,21 @@ pub struct ChangeStatusInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ApplyVolatileStatusInstruction {
     pub side_ref: SideReference,
     pub volatile_status: PokemonVolatileStatus,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveVolatileStatusInstruction {
     pub side_ref: SideReference,
     pub volatile_status: PokemonVolatileStatus,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoostInstruction {
     pub side_ref: SideReference,
     pub stat: PokemonBoostableStat,
@@ -509,6 +637,7 @@ pub struct BoostInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeSideConditionInstruction {
     pub side_ref: SideReference,
     pub side_condition: PokemonSideCondition,
@@ -516,6 +645,7 @@ pub struct ChangeSideConditionInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeVolatileStatusDurationInstruction {
     pub side_ref: SideReference,
     pub volatile_status: PokemonVolatileStatus,
@@ -523,6 +653,7 @@ pub struct ChangeVolatileStatusDurationInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeWeather {
     pub new_weather: Weather,
     pub new_weather_turns_remaining: i8,
@@ -531,6 +662,7 @@ pub struct ChangeWeather {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeTerrain {
     pub new_terrain: Terrain,
     pub new_terrain_turns_remaining: i8,
@@ -539,6 +671,7 @@ pub struct ChangeTerrain {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToggleTrickRoomInstruction {
     pub currently_active: bool,
     pub new_trickroom_turns_remaining: i8,
@@ -546,11 +679,13 @@ pub struct ToggleTrickRoomInstruction {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToggleTerastallizedInstruction {
     pub side_ref: SideReference,
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeType {
     pub side_ref: SideReference,
     pub new_types: (PokemonType, PokemonType),
@@ -558,6 +693,7 @@ pub struct ChangeType {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeAbilityInstruction {
     pub side_ref: SideReference,
 
@@ -568,7 +704,23 @@ pub struct ChangeAbilityInstruction {
 
 #[cfg(test)]
 mod test {
-    use super::Instruction;
+    use super::{BranchPruning, DamageInstruction, Instruction, PrunedBranches, StateInstructions};
+    use crate::state::SideReference;
+
+    fn branch(percentage: f32, damage: &[i16]) -> StateInstructions {
+        StateInstructions {
+            percentage,
+            instruction_list: damage
+                .iter()
+                .map(|d| {
+                    Instruction::Damage(DamageInstruction {
+                        side_ref: SideReference::SideOne,
+                        damage_amount: *d,
+                    })
+                })
+                .collect(),
+        }
+    }
 
     // Make sure that the size of the Instruction enum doesn't change
     #[test]
@@ -576,4 +728,54 @@ mod test {
         assert_eq!(size_of::<Instruction>(), 6);
         assert_eq!(align_of::<Instruction>(), 2);
     }
+
+    #[test]
+    fn test_pruned_branches_are_renormalized() {
+        let mut instructions = vec![branch(90.0, &[10]), branch(8.0, &[20]), branch(2.0, &[30])];
+        let pruning = BranchPruning {
+            min_percentage: 5.0,
+            pruned_branches: PrunedBranches::Renormalize,
+        };
+        pruning.prune(&mut instructions);
+
+        assert_eq!(2, instructions.len());
+        assert!((instructions[0].percentage - 90.0 / 0.98).abs() < 1e-3);
+        assert!((instructions[1].percentage - 8.0 / 0.98).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_pruned_branches_merge_into_the_closest_branch() {
+        let mut instructions = vec![
+            branch(70.0, &[10, 1]),
+            branch(25.0, &[20, 1]),
+            branch(5.0, &[20, 2]),
+        ];
+        let pruning = BranchPruning {
+            min_percentage: 10.0,
+            pruned_branches: PrunedBranches::MergeIntoClosest,
+        };
+        pruning.prune(&mut instructions);
+
+        assert_eq!(
+            vec![branch(70.0, &[10, 1]), branch(30.0, &[20, 1])],
+            instructions
+        );
+    }
+
+    #[test]
+    fn test_the_most_likely_branch_is_never_pruned() {
+        let mut instructions = vec![
+            branch(30.0, &[10]),
+            branch(35.0, &[20]),
+            branch(35.0, &[30]),
+        ];
+        let pruning = BranchPruning {
+            min_percentage: 50.0,
+            pruned_branches: PrunedBranches::Renormalize,
+        };
+        pruning.prune(&mut instructions);
+
+        assert_eq!(1, instructions.len());
+        assert!((instructions[0].percentage - 100.0).abs() < 1e-3);
+    }
 }
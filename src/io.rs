@@ -1,14 +1,22 @@
-use crate::choices::{Choice, Choices, MoveCategory, MOVES};
-use crate::engine::evaluate::evaluate;
+use crate::analyze::{analyze_all, Analysis, AnalysisSearch};
+use crate::arena::{play_arena, Agent, ArenaResult};
+use crate::choices::{moves, Choice, Choices, MoveCategory};
+use crate::control::SearchControl;
 use crate::engine::generate_instructions::{
     calculate_both_damage_rolls, generate_instructions_from_move_pair,
 };
 use crate::engine::state::MoveChoice;
-use crate::instruction::{Instruction, StateInstructions};
-use crate::mcts::{perform_mcts, MctsResult};
-use crate::mcts_threaded::perform_mcts_shared_tree;
-use crate::search::{expectiminimax_search, iterative_deepen_expectiminimax, pick_safest};
-use crate::state::State;
+use crate::evaluator::{EvaluationWeights, Evaluator};
+use crate::instruction::{BranchPruning, Instruction, PrunedBranches, StateInstructions};
+use crate::mcts::{MctsConfig, MctsResult, MctsSideResult, MctsTree};
+use crate::mcts_threaded::SharedMctsTree;
+use crate::nash::solve_nash_equilibrium;
+use crate::protocol::Protocol;
+use crate::search::{
+    expectiminimax_search, iterative_deepen_expectiminimax, pick_safest, ExpectiminimaxOptions,
+    SubGameSolver, TranspositionTable, TRANSPOSITION_TABLE_SIZE,
+};
+use crate::state::{Generation, Side, State, StateParseError};
 use clap::Parser;
 use std::io;
 use std::io::Write;
@@ -18,6 +26,10 @@ use std::sync::{Arc, Mutex};
 
 struct IOData {
     state: State,
+    generation: Option<Generation>,
+    weights: EvaluationWeights,
+    seed: Option<u64>,
+    pruning: BranchPruning,
     instruction_list: Vec<Vec<Instruction>>,
     last_instructions_generated: Vec<StateInstructions>,
 }
@@ -27,10 +39,45 @@ struct Cli {
     #[clap(short, long, default_value = "")]
     state: String,
 
+    /// Generation to use for the state, e.g. gen5. Defaults to gen4
+    #[clap(short, long, global = true)]
+    generation: Option<String>,
+
+    /// File of `name = value` evaluation weights. Weights that are not given keep their defaults
+    #[clap(short, long, global = true)]
+    weights: Option<String>,
+
+    /// Seed for the random choices of monte-carlo tree search, making searches limited by iterations reproducible
+    #[clap(long, global = true)]
+    seed: Option<u64>,
+
+    /// Leave the outcomes of a turn that are less likely than this percentage out of searches.
+    /// The chance of the outcomes that are left out goes to the ones that are kept
+    #[clap(long, global = true)]
+    prune_below: Option<f32>,
+
+    /// Give the chance of each pruned outcome to the kept outcome most like it, instead of
+    /// spreading it over every kept outcome
+    #[clap(long, global = true)]
+    merge_pruned: bool,
+
+    // only offered when JSON can be written
+    /// How subcommands print their results
+    #[cfg(feature = "serde")]
+    #[clap(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
     #[clap(subcommand)]
     subcmd: Option<SubCommand>,
 }
 
+#[derive(Clone, Copy, PartialEq, clap::ValueEnum)]
+#[cfg_attr(not(feature = "serde"), allow(dead_code))]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
 #[derive(Parser)]
 enum SubCommand {
     Expectiminimax(Expectiminimax),
@@ -38,6 +85,45 @@ enum SubCommand {
     MonteCarloTreeSearch(MonteCarloTreeSearch),
     CalculateDamage(CalculateDamage),
     GenerateInstructions(GenerateInstructions),
+    Protocol,
+    Analyze(Analyze),
+    Arena(Arena),
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum AnalyzeSearch {
+    Expectiminimax,
+    IterativeDeepening,
+    MonteCarloTreeSearch,
+}
+
+#[derive(Parser)]
+struct Analyze {
+    /// File of states: one serialized state per line, or JSON lines with a `state` field
+    /// (JSON lines need the `serde` feature)
+    #[clap(short, long, required = true)]
+    file: String,
+
+    #[clap(long, value_enum, default_value_t = AnalyzeSearch::MonteCarloTreeSearch)]
+    search: AnalyzeSearch,
+
+    #[clap(short = 't', long, default_value_t = 1000)]
+    time_to_search_ms: u64,
+
+    /// Iterations of monte-carlo tree search. Takes the place of the time limit when it is given
+    #[clap(short = 'i', long, default_value_t = 0)]
+    iterations: u32,
+
+    /// Depth of expectiminimax
+    #[clap(short, long, default_value_t = 2)]
+    depth: i8,
+
+    #[clap(long, default_value_t = false)]
+    nash: bool,
+
+    /// Number of states analyzed at the same time
+    #[clap(short = 'n', long, default_value_t = 1)]
+    threads: usize,
 }
 
 #[derive(Parser)]
@@ -45,11 +131,15 @@ struct Expectiminimax {
     #[clap(short, long, required = true)]
     state: String,
 
-    #[clap(short, long, default_value_t = false)]
+    /// Alpha-beta pruning at the root. Only sound with the default (maximin) solver
+    #[clap(short, long, default_value_t = false, conflicts_with = "nash")]
     ab_prune: bool,
 
     #[clap(short, long, default_value_t = 2)]
     depth: i8,
+
+    #[clap(short, long, default_value_t = false)]
+    nash: bool,
 }
 
 #[derive(Parser)]
@@ -59,6 +149,9 @@ struct IterativeDeepening {
 
     #[clap(short, long, default_value_t = 5000)]
     time_to_search_ms: u64,
+
+    #[clap(short, long, default_value_t = false)]
+    nash: bool,
 }
 
 #[derive(Parser)]
@@ -76,6 +169,33 @@ struct MonteCarloTreeSearch {
     threads: usize,
 }
 
+#[derive(Parser)]
+struct Arena {
+    /// An agent to play, e.g. `random`, `expectiminimax:depth=2`, `id:time=100` or
+    /// `mcts:time=100,threads=2`. Give at least two
+    #[clap(short, long = "agent", required = true)]
+    agents: Vec<String>,
+
+    /// File of starting states, in the same format as `analyze`
+    #[clap(short, long, conflicts_with = "sets")]
+    file: Option<String>,
+
+    /// Random battle sets to build random teams from, e.g. data/gen9randombattle_sets.txt
+    #[clap(long)]
+    sets: Option<String>,
+
+    /// Number of random battles each pair of agents plays from each side
+    #[clap(short = 'b', long, default_value_t = 10)]
+    battles: usize,
+
+    #[clap(long, default_value_t = 500)]
+    max_turns: usize,
+
+    /// Number of games played at the same time
+    #[clap(short = 'n', long, default_value_t = 1)]
+    threads: usize,
+}
+
 #[derive(Parser)]
 struct CalculateDamage {
     #[clap(short, long, required = true)]
@@ -107,12 +227,111 @@ impl Default for IOData {
     fn default() -> Self {
         IOData {
             state: State::default(),
+            generation: None,
+            weights: EvaluationWeights::default(),
+            seed: None,
+            pruning: BranchPruning::default(),
             instruction_list: Vec::new(),
             last_instructions_generated: Vec::new(),
         }
     }
 }
 
+// the results of the subcommands for `--format json`
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct SearchOutput {
+    side_one_options: Vec<String>,
+    side_two_options: Vec<String>,
+    /// One row per side one option, one column per side two option
+    matrix: Vec<Vec<f32>>,
+    choice: String,
+    evaluation: f32,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    side_one_strategy: Option<Vec<f32>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    side_two_strategy: Option<Vec<f32>>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(not(feature = "serde"), allow(dead_code))]
+struct MctsMoveOutput {
+    #[cfg_attr(feature = "serde", serde(rename = "move"))]
+    move_choice: String,
+    total_score: f32,
+    average_score: f32,
+    visits: u32,
+    policy: f32,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct MctsOutput {
+    iteration_count: u32,
+    side_one: Vec<MctsMoveOutput>,
+    side_two: Vec<MctsMoveOutput>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct DamageOutput {
+    side_one_damage_rolls: Option<Vec<i16>>,
+    side_two_damage_rolls: Option<Vec<i16>>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct PolicyOutput {
+    #[cfg_attr(feature = "serde", serde(rename = "move"))]
+    move_choice: String,
+    probability: f32,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct AnalysisOutput {
+    index: usize,
+    best_move: String,
+    policy: Vec<PolicyOutput>,
+    evaluation: f32,
+    iterations: u32,
+    depth: i8,
+    time_ms: u128,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct RecordOutput {
+    opponent: String,
+    wins: u32,
+    losses: u32,
+    draws: u32,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct ArenaAgentOutput {
+    agent: String,
+    games: u32,
+    wins: u32,
+    losses: u32,
+    draws: u32,
+    score: f32,
+    score_interval: (f32, f32),
+    elo: f32,
+    opponents: Vec<RecordOutput>,
+}
+
+#[cfg(feature = "serde")]
+fn print_json<T: serde::Serialize>(value: &T) {
+    match serde_json::to_string(value) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            println!("Could not serialize the result: {}", e);
+            exit(1);
+        }
+    }
+}
+
+// `--format` is only offered with the serde feature, so the output is always text without it
+#[cfg(not(feature = "serde"))]
+fn print_json<T>(_value: &T) {
+    unreachable!("JSON output needs the serde feature")
+}
+
 fn pprint_expectiminimax_result(
     result: &Vec<f32>,
     s1_options: &Vec<MoveChoice>,
@@ -145,70 +364,73 @@ fn pprint_expectiminimax_result(
     );
 }
 
-fn print_mcts_result(state: &State, result: MctsResult) {
-    let s1_joined_options = result
-        .s1
-        .iter()
-        .map(|x| {
-            format!(
-                "{},{:.2},{}",
-                x.move_choice.to_string(&state.side_one),
-                x.total_score,
-                x.visits
-            )
-        })
-        .collect::<Vec<String>>()
-        .join("|");
-    let s2_joined_options = result
-        .s2
-        .iter()
-        .map(|x| {
-            format!(
-                "{},{:.2},{}",
-                x.move_choice.to_string(&state.side_two),
-                x.total_score,
-                x.visits
-            )
-        })
-        .collect::<Vec<String>>()
-        .join("|");
+fn mcts_output(state: &State, result: &MctsResult) -> MctsOutput {
+    let side_output = |side_results: &Vec<MctsSideResult>, side: &Side| {
+        side_results
+            .iter()
+            .map(|x| MctsMoveOutput {
+                move_choice: x.move_choice.to_string(side),
+                total_score: x.total_score,
+                average_score: x.total_score / x.visits.max(1) as f32,
+                visits: x.visits,
+                policy: x.policy,
+            })
+            .collect()
+    };
+    MctsOutput {
+        iteration_count: result.iteration_count,
+        side_one: side_output(&result.s1, &state.side_one),
+        side_two: side_output(&result.s2, &state.side_two),
+    }
+}
+
+fn print_mcts_result(output: &MctsOutput) {
+    let joined_options = |moves: &Vec<MctsMoveOutput>| {
+        moves
+            .iter()
+            .map(|x| format!("{},{:.2},{}", x.move_choice, x.total_score, x.visits))
+            .collect::<Vec<String>>()
+            .join("|")
+    };
 
-    println!("Total Iterations: {}", result.iteration_count);
-    println!("side one: {}", s1_joined_options);
-    println!("side two: {}", s2_joined_options);
+    println!("Total Iterations: {}", output.iteration_count);
+    println!("side one: {}", joined_options(&output.side_one));
+    println!("side two: {}", joined_options(&output.side_two));
 }
 
 fn pprint_mcts_result(state: &State, result: MctsResult) {
     println!("\nTotal Iterations: {}\n", result.iteration_count);
     println!("Side One:");
     println!(
-        "\t{:<25}{:>12}{:>12}{:>10}{:>10}",
-        "Move", "Total Score", "Avg Score", "Visits", "% Visits"
+        "\t{:<25}{:>12}{:>12}{:>10}{:>10}{:>10}",
+        "Move", "Total Score", "Avg Score", "Visits", "% Visits", "% Policy"
     );
     for x in result.s1.iter() {
         println!(
-            "\t{:<25}{:>12.2}{:>12.2}{:>10}{:>10.2}",
+            "\t{:<25}{:>12.2}{:>12.2}{:>10}{:>10.2}{:>10.2}",
             x.move_choice.to_string(&state.side_one),
             x.total_score,
             x.total_score / x.visits as f32,
             x.visits,
-            (x.visits as f32 / result.iteration_count as f32) * 100.0
+            (x.visits as f32 / result.iteration_count as f32) * 100.0,
+            x.policy * 100.0
         );
     }
 
     println!("Side Two:");
     println!(
-        "\t{:<25}{:>12}{:>12}{:>10}{:>10}",
-        "Move", "Total Score", "Avg Score", "Visits", "% Visits"
+        "\t{:<25}{:>12}{:>12}{:>10}{:>10}{:>10}",
+        "Move", "Total Score", "Avg Score", "Visits", "% Visits", "% Policy"
     );
     for x in result.s2.iter() {
         println!(
-            "\t{:<25}{:>12.2}{:>12.2}{:>10}{:>10.2}",
+            "\t{:<25}{:>12.2}{:>12.2}{:>10}{:>10.2}{:>10.2}",
             x.move_choice.to_string(&state.side_two),
             x.total_score,
             x.total_score / x.visits as f32,
             x.visits,
-            (x.visits as f32 / result.iteration_count as f32) * 100.0
+            (x.visits as f32 / result.iteration_count as f32) * 100.0,
+            x.policy * 100.0
         );
     }
 }
@@ -220,45 +442,332 @@ fn pprint_state_instruction_vector(instructions: &Vec<StateInstructions>) {
     }
 }
 
-fn print_subcommand_result(
+fn get_sub_game_solver(nash: bool) -> SubGameSolver {
+    if nash {
+        SubGameSolver::Nash
+    } else {
+        SubGameSolver::Safest
+    }
+}
+
+fn search_output(
     result: &Vec<f32>,
     side_one_options: &Vec<MoveChoice>,
     side_two_options: &Vec<MoveChoice>,
     state: &State,
-) {
-    let safest = pick_safest(&result, side_one_options.len(), side_two_options.len());
-    let move_choice = side_one_options[safest.0];
+    solver: SubGameSolver,
+) -> SearchOutput {
+    let (choice_index, evaluation, side_one_strategy, side_two_strategy) = match solver {
+        SubGameSolver::Safest => {
+            let (choice_index, evaluation) =
+                pick_safest(result, side_one_options.len(), side_two_options.len());
+            (choice_index, evaluation, None, None)
+        }
+        SubGameSolver::Nash => {
+            let equilibrium =
+                solve_nash_equilibrium(result, side_one_options.len(), side_two_options.len());
+            (
+                equilibrium.s1_best_index(),
+                equilibrium.value,
+                Some(equilibrium.s1_strategy),
+                Some(equilibrium.s2_strategy),
+            )
+        }
+    };
+    SearchOutput {
+        side_one_options: side_one_options
+            .iter()
+            .map(|x| x.to_string(&state.side_one))
+            .collect(),
+        side_two_options: side_two_options
+            .iter()
+            .map(|x| x.to_string(&state.side_two))
+            .collect(),
+        matrix: result
+            .chunks(side_two_options.len().max(1))
+            .map(|row| row.to_vec())
+            .collect(),
+        choice: side_one_options[choice_index].to_string(&state.side_one),
+        evaluation,
+        side_one_strategy,
+        side_two_strategy,
+    }
+}
 
-    let joined_side_one_options = side_one_options
-        .iter()
-        .map(|x| format!("{}", x.to_string(&state.side_one)))
-        .collect::<Vec<String>>()
-        .join(",");
-    println!("side one options: {}", joined_side_one_options);
+fn print_subcommand_result(output: &SearchOutput) {
+    let joined_strategy = |strategy: &Vec<f32>| {
+        strategy
+            .iter()
+            .map(|x| format!("{:.3}", x))
+            .collect::<Vec<String>>()
+            .join(",")
+    };
+    if let Some(strategy) = &output.side_one_strategy {
+        println!("side one strategy: {}", joined_strategy(strategy));
+    }
+    if let Some(strategy) = &output.side_two_strategy {
+        println!("side two strategy: {}", joined_strategy(strategy));
+    }
+    println!("side one options: {}", output.side_one_options.join(","));
+    println!("side two options: {}", output.side_two_options.join(","));
 
-    let joined_side_two_options = side_two_options
+    let joined = output
+        .matrix
         .iter()
-        .map(|x| format!("{}", x.to_string(&state.side_two)))
+        .flatten()
+        .map(|x| format!("{:.2}", x))
         .collect::<Vec<String>>()
         .join(",");
-    println!("side two options: {}", joined_side_two_options);
+    println!("matrix: {}", joined);
+    println!("choice: {}", output.choice);
+    println!("evaluation: {}", output.evaluation);
+}
+
+fn analysis_output(index: usize, state: &State, analysis: Analysis) -> AnalysisOutput {
+    AnalysisOutput {
+        index,
+        best_move: analysis.best_move.to_string(&state.side_one),
+        policy: analysis
+            .policy
+            .iter()
+            .map(|(move_choice, probability)| PolicyOutput {
+                move_choice: move_choice.to_string(&state.side_one),
+                probability: *probability,
+            })
+            .collect(),
+        evaluation: analysis.evaluation,
+        iterations: analysis.iterations,
+        depth: analysis.depth,
+        time_ms: analysis.elapsed.as_millis(),
+    }
+}
 
-    let joined = result
+fn print_analysis(output: &AnalysisOutput) {
+    let policy = output
+        .policy
         .iter()
-        .map(|x| format!("{:.2}", x))
+        .map(|x| format!("{}:{:.3}", x.move_choice, x.probability))
         .collect::<Vec<String>>()
         .join(",");
-    println!("matrix: {}", joined);
-    println!("choice: {}", move_choice.to_string(&state.side_one));
-    println!("evaluation: {}", safest.1);
+    println!(
+        "{}\t{}\t{:.4}\t{}\t{}\t{}\t{}",
+        output.index,
+        output.best_move,
+        output.evaluation,
+        output.iterations,
+        output.depth,
+        output.time_ms,
+        policy
+    );
+}
+
+// a JSON line is a state with the `serde` representation of `State`,
+// or an object whose `state` field is a serialized state or a `State`
+#[cfg(feature = "serde")]
+fn deserialize_json_state(line: &str) -> Result<State, String> {
+    let value: serde_json::Value = serde_json::from_str(line).map_err(|e| e.to_string())?;
+    match value.get("state") {
+        Some(serde_json::Value::String(state)) => {
+            State::try_deserialize(state).map_err(|e| e.to_string())
+        }
+        Some(state) => serde_json::from_value(state.clone()).map_err(|e| e.to_string()),
+        None => serde_json::from_value(value).map_err(|e| e.to_string()),
+    }
+}
+
+#[cfg(not(feature = "serde"))]
+fn deserialize_json_state(_line: &str) -> Result<State, String> {
+    Err("JSON states need the serde feature".to_string())
+}
+
+fn read_states(path: &str, generation: Option<Generation>) -> Result<Vec<State>, String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    let mut states = vec![];
+    for (line_number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let state = if line.starts_with('{') {
+            deserialize_json_state(line).map(|mut state| {
+                if let Some(generation) = generation {
+                    state.set_generation(generation);
+                }
+                state
+            })
+        } else {
+            deserialize_state(line, generation).map_err(|e| e.to_string())
+        };
+        states.push(state.map_err(|e| format!("line {}: {}", line_number + 1, e))?);
+    }
+    Ok(states)
+}
+
+fn arena_output(result: &ArenaResult) -> Vec<ArenaAgentOutput> {
+    (0..result.agents.len())
+        .map(|i| {
+            let total = result.total(i);
+            ArenaAgentOutput {
+                agent: result.agents[i].to_string(),
+                games: total.games(),
+                wins: total.wins,
+                losses: total.losses,
+                draws: total.draws,
+                score: total.score(),
+                score_interval: total.score_interval(),
+                elo: result.elo[i],
+                opponents: (0..result.agents.len())
+                    .filter(|j| *j != i)
+                    .map(|j| {
+                        let record = &result.records[i][j];
+                        RecordOutput {
+                            opponent: result.agents[j].to_string(),
+                            wins: record.wins,
+                            losses: record.losses,
+                            draws: record.draws,
+                        }
+                    })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn print_arena_result(output: &[ArenaAgentOutput]) {
+    println!(
+        "{:<40}{:>8}{:>16}{:>10}{:>18}{:>10}",
+        "Agent", "Games", "W-L-D", "Score", "95% Interval", "Elo"
+    );
+    for agent in output.iter() {
+        println!(
+            "{:<40}{:>8}{:>16}{:>10.3}{:>18}{:>10.1}",
+            agent.agent,
+            agent.games,
+            format!("{}-{}-{}", agent.wins, agent.losses, agent.draws),
+            agent.score,
+            format!(
+                "{:.3}-{:.3}",
+                agent.score_interval.0, agent.score_interval.1
+            ),
+            agent.elo
+        );
+    }
+    for agent in output.iter() {
+        for record in agent.opponents.iter() {
+            println!(
+                "{} vs {}: {}-{}-{}",
+                agent.agent, record.opponent, record.wins, record.losses, record.draws
+            );
+        }
+    }
+}
+
+fn random_battles(
+    path: &str,
+    count: usize,
+    seed: u64,
+    generation: Option<Generation>,
+) -> Result<Vec<State>, String> {
+    use crate::engine::randbats::SetDatabase;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let generation = generation.unwrap_or(State::default().generation);
+    if generation < Generation::GEN4 {
+        return Err("random battle sets need gen4 or later".to_string());
+    }
+    let database = SetDatabase::from_file(path, generation)?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count)
+        .map(|_| {
+            let mut state = State::default();
+            state.set_generation(generation);
+            state.side_one = database.random_team(&mut rng)?;
+            state.side_two = database.random_team(&mut rng)?;
+            state.update_zobrist_hash();
+            Ok(state)
+        })
+        .collect()
+}
+
+pub(crate) fn deserialize_state(
+    state_string: &str,
+    generation: Option<Generation>,
+) -> Result<State, StateParseError> {
+    let mut state = State::try_deserialize(state_string)?;
+    if let Some(generation) = generation {
+        state.set_generation(generation);
+    }
+    Ok(state)
+}
+
+fn find_choice(generation: Generation, move_name: &str) -> Result<Choice, String> {
+    let invalid_move = || format!("Invalid move: {}", move_name);
+    let id = Choices::from_str(move_name).map_err(|_| invalid_move())?;
+    moves(generation)
+        .get(&id)
+        .map(|choice| choice.to_owned())
+        .ok_or_else(invalid_move)
+}
+
+fn find_choice_or_exit(generation: Generation, move_name: &str) -> Choice {
+    match find_choice(generation, move_name) {
+        Ok(choice) => choice,
+        Err(e) => {
+            println!("{}", e);
+            exit(1);
+        }
+    }
+}
+
+fn deserialize_state_or_exit(state_string: &str, generation: Option<Generation>) -> State {
+    match deserialize_state(state_string, generation) {
+        Ok(state) => state,
+        Err(e) => {
+            println!("Invalid state: {}", e);
+            exit(1);
+        }
+    }
 }
 
 pub fn main() {
     let args = Cli::parse();
     let mut io_data = IOData::default();
+    let generation = args
+        .generation
+        .map(|g| match Generation::from_str(g.as_str()) {
+            Ok(generation) => generation,
+            Err(e) => {
+                println!("{}", e);
+                exit(1);
+            }
+        });
+    io_data.generation = generation;
+    if let Some(path) = args.weights {
+        match EvaluationWeights::from_file(path) {
+            Ok(weights) => io_data.weights = weights,
+            Err(e) => {
+                println!("Invalid weights: {}", e);
+                exit(1);
+            }
+        }
+    }
+    io_data.seed = args.seed;
+    if let Some(min_percentage) = args.prune_below {
+        io_data.pruning.min_percentage = min_percentage;
+    }
+    if args.merge_pruned {
+        io_data.pruning.pruned_branches = PrunedBranches::MergeIntoClosest;
+    }
+    let pruning = io_data.pruning;
+    #[cfg(feature = "serde")]
+    let format = args.format;
+    #[cfg(not(feature = "serde"))]
+    let format = OutputFormat::Text;
+    let weights = io_data.weights.clone();
 
     if args.state != "" {
-        let state = State::deserialize(args.state.as_str());
+        let state = deserialize_state_or_exit(args.state.as_str(), generation);
         io_data.state = state;
     }
 
@@ -273,62 +782,211 @@ pub fn main() {
         }
         Some(subcmd) => match subcmd {
             SubCommand::Expectiminimax(expectiminimax) => {
-                state = State::deserialize(expectiminimax.state.as_str());
+                state = deserialize_state_or_exit(expectiminimax.state.as_str(), generation);
+                let solver = get_sub_game_solver(expectiminimax.nash);
                 (side_one_options, side_two_options) = state.root_get_all_options();
                 result = expectiminimax_search(
                     &mut state,
                     expectiminimax.depth,
                     side_one_options.clone(),
                     side_two_options.clone(),
-                    expectiminimax.ab_prune,
-                    &Arc::new(Mutex::new(true)),
+                    ExpectiminimaxOptions {
+                        ab_prune: expectiminimax.ab_prune,
+                        solver,
+                        pruning,
+                        transposition_table: Some(&mut TranspositionTable::new(
+                            TRANSPOSITION_TABLE_SIZE,
+                        )),
+                        evaluator: &weights,
+                    },
+                    &SearchControl::default(),
+                );
+                let output = search_output(
+                    &result,
+                    &side_one_options,
+                    &side_two_options,
+                    &state,
+                    solver,
                 );
-                print_subcommand_result(&result, &side_one_options, &side_two_options, &state);
+                match format {
+                    OutputFormat::Text => print_subcommand_result(&output),
+                    OutputFormat::Json => print_json(&output),
+                }
             }
             SubCommand::IterativeDeepening(iterative_deepending) => {
-                state = State::deserialize(iterative_deepending.state.as_str());
+                state = deserialize_state_or_exit(iterative_deepending.state.as_str(), generation);
+                let solver = get_sub_game_solver(iterative_deepending.nash);
                 (side_one_options, side_two_options) = state.root_get_all_options();
                 (side_one_options, side_two_options, result, _) = iterative_deepen_expectiminimax(
                     &mut state,
                     side_one_options.clone(),
                     side_two_options.clone(),
                     std::time::Duration::from_millis(iterative_deepending.time_to_search_ms),
+                    ExpectiminimaxOptions {
+                        solver,
+                        pruning,
+                        evaluator: &weights,
+                        ..Default::default()
+                    },
+                );
+                let output = search_output(
+                    &result,
+                    &side_one_options,
+                    &side_two_options,
+                    &state,
+                    solver,
                 );
-                print_subcommand_result(&result, &side_one_options, &side_two_options, &state);
+                match format {
+                    OutputFormat::Text => print_subcommand_result(&output),
+                    OutputFormat::Json => print_json(&output),
+                }
             }
             SubCommand::MonteCarloTreeSearch(mcts) => {
-                state = State::deserialize(mcts.state.as_str());
+                state = deserialize_state_or_exit(mcts.state.as_str(), generation);
                 (side_one_options, side_two_options) = state.root_get_all_options();
+                let config = MctsConfig {
+                    pruning,
+                    threads: mcts.threads,
+                    seed: io_data.seed,
+                    ..Default::default()
+                };
                 let result = if mcts.threads > 1 {
-                    perform_mcts_shared_tree(
+                    SharedMctsTree::with_config(config).search(
                         &mut state,
                         side_one_options.clone(),
                         side_two_options.clone(),
                         std::time::Duration::from_millis(mcts.time_to_search_ms),
                         mcts.iterations,
-                        mcts.threads,
+                        &weights,
                     )
                 } else {
-                    perform_mcts(
+                    MctsTree::with_config(config).search(
                         &mut state,
                         side_one_options.clone(),
                         side_two_options.clone(),
                         std::time::Duration::from_millis(mcts.time_to_search_ms),
                         mcts.iterations,
+                        &weights,
                     )
                 };
-                print_mcts_result(&state, result);
+                let output = mcts_output(&state, &result);
+                match format {
+                    OutputFormat::Text => print_mcts_result(&output),
+                    OutputFormat::Json => print_json(&output),
+                }
+            }
+            SubCommand::Protocol => {
+                let mut protocol = Protocol::new(
+                    io_data.state,
+                    generation,
+                    weights,
+                    io_data.seed,
+                    pruning,
+                    Arc::new(Mutex::new(io::stdout())),
+                );
+                protocol.run(io::stdin().lock());
+            }
+            SubCommand::Analyze(analyze) => {
+                let states = match read_states(&analyze.file, generation) {
+                    Ok(states) => states,
+                    Err(e) => {
+                        println!("Invalid states: {}", e);
+                        exit(1);
+                    }
+                };
+                let solver = get_sub_game_solver(analyze.nash);
+                let max_time = std::time::Duration::from_millis(analyze.time_to_search_ms);
+                let search = match analyze.search {
+                    AnalyzeSearch::Expectiminimax => AnalysisSearch::Expectiminimax {
+                        depth: analyze.depth,
+                        solver,
+                        pruning,
+                    },
+                    AnalyzeSearch::IterativeDeepening => AnalysisSearch::IterativeDeepening {
+                        max_time,
+                        solver,
+                        pruning,
+                    },
+                    AnalyzeSearch::MonteCarloTreeSearch => AnalysisSearch::MonteCarloTreeSearch {
+                        max_time,
+                        max_iterations: analyze.iterations,
+                        config: MctsConfig {
+                            pruning,
+                            seed: io_data.seed,
+                            ..Default::default()
+                        },
+                    },
+                };
+                if format == OutputFormat::Text {
+                    println!("index\tbest_move\tevaluation\titerations\tdepth\ttime_ms\tpolicy");
+                }
+                analyze_all(
+                    &states,
+                    &search,
+                    analyze.threads,
+                    &weights,
+                    |index, analysis| {
+                        let output = analysis_output(index, &states[index], analysis);
+                        match format {
+                            OutputFormat::Text => print_analysis(&output),
+                            OutputFormat::Json => print_json(&output),
+                        }
+                    },
+                );
+            }
+            SubCommand::Arena(arena) => {
+                let agents = match arena
+                    .agents
+                    .iter()
+                    .map(|a| Agent::from_str(a))
+                    .collect::<Result<Vec<Agent>, String>>()
+                {
+                    Ok(agents) if agents.len() >= 2 => agents,
+                    Ok(_) => {
+                        println!("The arena needs at least two agents");
+                        exit(1);
+                    }
+                    Err(e) => {
+                        println!("{}", e);
+                        exit(1);
+                    }
+                };
+                let seed = io_data.seed.unwrap_or(0);
+                let starting_states = match (&arena.file, &arena.sets) {
+                    (Some(file), _) => read_states(file, generation),
+                    (None, Some(sets)) => random_battles(sets, arena.battles, seed, generation),
+                    (None, None) => {
+                        Err("give a --file of states or random battle --sets".to_string())
+                    }
+                };
+                let starting_states = match starting_states {
+                    Ok(states) => states,
+                    Err(e) => {
+                        println!("Invalid starting states: {}", e);
+                        exit(1);
+                    }
+                };
+                let result = play_arena(
+                    &agents,
+                    &starting_states,
+                    arena.max_turns,
+                    seed,
+                    arena.threads,
+                    pruning,
+                    &weights,
+                );
+                let output = arena_output(&result);
+                match format {
+                    OutputFormat::Text => print_arena_result(&output),
+                    OutputFormat::Json => print_json(&output),
+                }
             }
             SubCommand::CalculateDamage(calculate_damage) => {
-                state = State::deserialize(calculate_damage.state.as_str());
-                let mut s1_choice = MOVES
-                    .get(&Choices::from_str(calculate_damage.side_one_move.as_str()).unwrap())
-                    .unwrap()
-                    .to_owned();
-                let mut s2_choice = MOVES
-                    .get(&Choices::from_str(calculate_damage.side_two_move.as_str()).unwrap())
-                    .unwrap()
-                    .to_owned();
+                state = deserialize_state_or_exit(calculate_damage.state.as_str(), generation);
+                let mut s1_choice =
+                    find_choice_or_exit(state.generation, &calculate_damage.side_one_move);
+                let mut s2_choice =
+                    find_choice_or_exit(state.generation, &calculate_damage.side_two_move);
                 let s1_moves_first = calculate_damage.side_one_moves_first;
                 if calculate_damage.side_one_move == "switch" {
                     s1_choice.category = MoveCategory::Switch
@@ -336,10 +994,10 @@ pub fn main() {
                 if calculate_damage.side_two_move == "switch" {
                     s2_choice.category = MoveCategory::Switch
                 }
-                calculate_damage_io(&state, s1_choice, s2_choice, s1_moves_first);
+                calculate_damage_io(&state, s1_choice, s2_choice, s1_moves_first, format);
             }
             SubCommand::GenerateInstructions(generate_instructions) => {
-                state = State::deserialize(generate_instructions.state.as_str());
+                state = deserialize_state_or_exit(generate_instructions.state.as_str(), generation);
                 let (s1_movechoice, s2_movechoice);
                 match MoveChoice::from_string(
                     generate_instructions.side_one_move.as_str(),
@@ -367,13 +1025,17 @@ pub fn main() {
                     }
                     Some(v) => s2_movechoice = v,
                 }
-                let instructions = generate_instructions_from_move_pair(
+                let mut instructions = generate_instructions_from_move_pair(
                     &mut state,
                     &s1_movechoice,
                     &s2_movechoice,
                     true,
                 );
-                pprint_state_instruction_vector(&instructions);
+                pruning.prune(&mut instructions);
+                match format {
+                    OutputFormat::Text => pprint_state_instruction_vector(&instructions),
+                    OutputFormat::Json => print_json(&instructions),
+                }
             }
         },
     }
@@ -386,11 +1048,20 @@ fn calculate_damage_io(
     s1_choice: Choice,
     s2_choice: Choice,
     side_one_moves_first: bool,
+    format: OutputFormat,
 ) {
-    let (damages_dealt_s1, damages_dealt_s2) =
+    let (side_one_damage_rolls, side_two_damage_rolls) =
         calculate_both_damage_rolls(state, s1_choice, s2_choice, side_one_moves_first);
+    let output = DamageOutput {
+        side_one_damage_rolls,
+        side_two_damage_rolls,
+    };
+    if format == OutputFormat::Json {
+        print_json(&output);
+        return;
+    }
 
-    for dmg in [damages_dealt_s1, damages_dealt_s2] {
+    for dmg in [&output.side_one_damage_rolls, &output.side_two_damage_rolls] {
         match dmg {
             Some(damages_vec) => {
                 let joined = damages_vec
@@ -430,9 +1101,13 @@ fn command_loop(mut io_data: IOData) {
                 match args.next() {
                     Some(s) => {
                         state_string = s;
-                        let state = State::deserialize(state_string);
-                        io_data.state = state;
-                        println!("state initialized");
+                        match deserialize_state(state_string, io_data.generation) {
+                            Ok(state) => {
+                                io_data.state = state;
+                                println!("state initialized");
+                            }
+                            Err(e) => println!("Invalid state: {}", e),
+                        }
                     }
                     None => {
                         println!("Expected state string");
@@ -478,12 +1153,13 @@ fn command_loop(mut io_data: IOData) {
                         continue;
                     }
                 }
-                let instructions = generate_instructions_from_move_pair(
+                let mut instructions = generate_instructions_from_move_pair(
                     &mut io_data.state,
                     &s1_move,
                     &s2_move,
                     true,
                 );
+                io_data.pruning.prune(&mut instructions);
                 pprint_state_instruction_vector(&instructions);
                 io_data.last_instructions_generated = instructions;
             }
@@ -491,10 +1167,13 @@ fn command_loop(mut io_data: IOData) {
                 let (mut s1_choice, mut s2_choice);
                 match args.next() {
                     Some(s) => {
-                        s1_choice = MOVES
-                            .get(&Choices::from_str(s).unwrap())
-                            .unwrap()
-                            .to_owned();
+                        s1_choice = match find_choice(io_data.state.generation, s) {
+                            Ok(choice) => choice,
+                            Err(e) => {
+                                println!("{}", e);
+                                continue;
+                            }
+                        };
                         if s == "switch" {
                             s1_choice.category = MoveCategory::Switch
                         }
@@ -506,10 +1185,13 @@ fn command_loop(mut io_data: IOData) {
                 }
                 match args.next() {
                     Some(s) => {
-                        s2_choice = MOVES
-                            .get(&Choices::from_str(s).unwrap())
-                            .unwrap()
-                            .to_owned();
+                        s2_choice = match find_choice(io_data.state.generation, s) {
+                            Ok(choice) => choice,
+                            Err(e) => {
+                                println!("{}", e);
+                                continue;
+                            }
+                        };
                         if s == "switch" {
                             s2_choice.category = MoveCategory::Switch
                         }
@@ -529,14 +1211,29 @@ fn command_loop(mut io_data: IOData) {
                         continue;
                     }
                 }
-                calculate_damage_io(&io_data.state, s1_choice, s2_choice, s1_moves_first);
+                calculate_damage_io(
+                    &io_data.state,
+                    s1_choice,
+                    s2_choice,
+                    s1_moves_first,
+                    OutputFormat::Text,
+                );
             }
             "instructions" | "i" => {
                 println!("{:?}", io_data.last_instructions_generated);
             }
-            "evaluate" | "ev" => {
-                println!("Evaluation: {}", evaluate(&io_data.state));
-            }
+            "evaluate" | "ev" => match args.next() {
+                Some("explain") => {
+                    println!("{}", io_data.weights.explain(&io_data.state));
+                }
+                Some(_) => {
+                    println!("Usage: evaluate [explain]");
+                    continue;
+                }
+                None => {
+                    println!("Evaluation: {}", io_data.weights.evaluate(&io_data.state));
+                }
+            },
             "iterative-deepening" | "id" => match args.next() {
                 Some(s) => {
                     let max_time_ms = s.parse::<u64>().unwrap();
@@ -549,6 +1246,11 @@ fn command_loop(mut io_data: IOData) {
                             side_one_options.clone(),
                             side_two_options.clone(),
                             std::time::Duration::from_millis(max_time_ms),
+                            ExpectiminimaxOptions {
+                                pruning: io_data.pruning,
+                                evaluator: &io_data.weights,
+                                ..Default::default()
+                            },
                         );
                     let elapsed = start_time.elapsed();
 
@@ -575,12 +1277,18 @@ fn command_loop(mut io_data: IOData) {
                     let (side_one_options, side_two_options) = io_data.state.root_get_all_options();
 
                     let start_time = std::time::Instant::now();
-                    let result = perform_mcts(
+                    let config = MctsConfig {
+                        pruning: io_data.pruning,
+                        seed: io_data.seed,
+                        ..Default::default()
+                    };
+                    let result = MctsTree::with_config(config).search(
                         &mut io_data.state,
                         side_one_options.clone(),
                         side_two_options.clone(),
                         std::time::Duration::from_millis(max_time_ms),
                         0,
+                        &io_data.weights,
                     );
                     let elapsed = start_time.elapsed();
                     pprint_mcts_result(&io_data.state, result);
@@ -613,13 +1321,19 @@ fn command_loop(mut io_data: IOData) {
                 };
                 let (side_one_options, side_two_options) = io_data.state.root_get_all_options();
                 let start_time = std::time::Instant::now();
-                let result = perform_mcts_shared_tree(
+                let config = MctsConfig {
+                    pruning: io_data.pruning,
+                    threads: worker_count,
+                    seed: io_data.seed,
+                    ..Default::default()
+                };
+                let result = SharedMctsTree::with_config(config).search(
                     &mut io_data.state,
                     side_one_options.clone(),
                     side_two_options.clone(),
                     std::time::Duration::from_millis(max_time_ms),
                     0,
-                    worker_count,
+                    &io_data.weights,
                 );
                 let elapsed = start_time.elapsed();
                 pprint_mcts_result(&io_data.state, result);
@@ -672,8 +1386,16 @@ fn command_loop(mut io_data: IOData) {
                         depth,
                         side_one_options.clone(),
                         side_two_options.clone(),
-                        ab_prune,
-                        &Arc::new(Mutex::new(true)),
+                        ExpectiminimaxOptions {
+                            ab_prune,
+                            pruning: io_data.pruning,
+                            transposition_table: Some(&mut TranspositionTable::new(
+                                TRANSPOSITION_TABLE_SIZE,
+                            )),
+                            evaluator: &io_data.weights,
+                            ..Default::default()
+                        },
+                        &SearchControl::default(),
                     );
                     let elapsed = start_time.elapsed();
 
@@ -705,3 +1427,44 @@ fn command_loop(mut io_data: IOData) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_output_has_one_matrix_row_per_side_one_option() {
+        let state = State::default();
+        let side_one_options = vec![MoveChoice::None, MoveChoice::None];
+        let side_two_options = vec![MoveChoice::None, MoveChoice::None, MoveChoice::None];
+        let result = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+        let output = search_output(
+            &result,
+            &side_one_options,
+            &side_two_options,
+            &state,
+            SubGameSolver::Safest,
+        );
+        assert_eq!(
+            vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]],
+            output.matrix
+        );
+        assert_eq!(4.0, output.evaluation);
+        assert!(output.side_one_strategy.is_none());
+    }
+
+    #[test]
+    fn test_nash_conflicts_with_ab_prune() {
+        let args = [
+            "poke-engine",
+            "expectiminimax",
+            "-s",
+            "state",
+            "--nash",
+            "--ab-prune",
+        ];
+        assert!(Cli::try_parse_from(args).is_err());
+        assert!(Cli::try_parse_from(&args[..5]).is_ok());
+    }
+}
@@ -1,40 +1,30 @@
-#[cfg(feature = "gen1")]
-#[path = "gen1/mod.rs"]
-pub mod engine;
-
-#[cfg(feature = "gen2")]
-#[path = "gen2/mod.rs"]
-pub mod engine;
-
-#[cfg(feature = "gen3")]
-#[path = "gen3/mod.rs"]
-pub mod engine;
-
-// All other generations
-#[cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
+// Generations 4 through 9 share this engine. States from generations 1, 2, and 3
+// are handed off to their own engines below
 #[path = "genx/mod.rs"]
 pub mod engine;
 
+pub mod gen1;
+pub mod gen2;
+pub mod gen3;
+
+pub mod analyze;
+pub mod arena;
 pub mod choices;
+pub mod control;
+pub mod determinize;
+pub mod evaluator;
 pub mod instruction;
 pub mod io;
 pub mod mcts;
 pub mod mcts_threaded;
+pub mod nash;
+pub mod pokedex;
 pub mod pokemon;
+pub mod protocol;
 pub mod search;
+pub mod simulate;
 pub mod state;
-
-#[macro_export]
-macro_rules! assert_unique_feature {
-    () => {};
-    ($first:tt $(,$rest:tt)*) => {
-        $(
-            #[cfg(all(feature = $first, feature = $rest))]
-            compile_error!(concat!("features \"", $first, "\" and \"", $rest, "\" cannot be used together"));
-        )*
-        assert_unique_feature!($($rest),*);
-    }
-}
+pub mod zobrist;
 
 #[macro_export]
 macro_rules! define_enum_with_from_str {
@@ -49,12 +39,13 @@ macro_rules! define_enum_with_from_str {
     ) => {
         #[repr($repr)]
         $(#[$meta])*
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum $name {
             $($variant),+
         }
 
         impl std::str::FromStr for $name {
-            type Err = ();
+            type Err = String;
 
             fn from_str(input: &str) -> Result<Self, Self::Err> {
                 match input.to_uppercase().as_str() {
@@ -99,19 +90,20 @@ macro_rules! define_enum_with_from_str {
     ) => {
         #[repr($repr)]
         $(#[$meta])*
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum $name {
             $($variant),+
         }
 
         impl std::str::FromStr for $name {
-            type Err = ();
+            type Err = String;
 
             fn from_str(input: &str) -> Result<Self, Self::Err> {
                 match input.to_uppercase().as_str() {
                     $(
                         stringify!($variant) => Ok($name::$variant),
                     )+
-                    _ => panic!("Invalid {}: {}", stringify!($name), input.to_uppercase().as_str()),
+                    _ => Err(format!("Invalid {}: {}", stringify!($name), input)),
                 }
             }
         }
@@ -139,5 +131,3 @@ macro_rules! define_enum_with_from_str {
         }
     };
 }
-
-assert_unique_feature!("gen2", "gen3", "gen4", "gen5", "gen6", "gen7", "gen8", "gen9");
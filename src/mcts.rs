@@ -1,16 +1,192 @@
-use crate::engine::evaluate::evaluate;
+use crate::control::{SearchControl, SearchProgress};
 use crate::engine::generate_instructions::generate_instructions_from_move_pair;
 use crate::engine::state::MoveChoice;
-use crate::instruction::StateInstructions;
-use crate::state::State;
+use crate::evaluator::Evaluator;
+use crate::instruction::{BranchPruning, Instruction, StateInstructions};
+use crate::state::{SideReference, State};
 use rand::prelude::*;
 use rand::rng;
 use std::collections::HashMap;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-fn sigmoid(x: f32) -> f32 {
-    // Tuned so that ~200 points is very close to 1.0
-    1.0 / (1.0 + (-0.0125 * x).exp())
+/// Gives a prior probability for each of `options`, the moves `side_ref` can make in `state`
+pub type PriorFn = fn(&State, &SideReference, &[MoveChoice]) -> Vec<f32>;
+
+/// How each side picks its move when the search walks down the tree.
+/// Both sides pick independently of each other
+#[derive(Debug, Clone, Copy)]
+pub enum SelectionPolicy {
+    /// The move with the highest `average score + sqrt(exploration * ln(parent visits) / visits)`
+    Ucb1 { exploration: f32 },
+
+    /// The move with the highest `average score + exploration * prior * sqrt(parent visits) / (1 + visits)`.
+    /// Every move has the same prior when `priors` is `None`
+    Puct {
+        exploration: f32,
+        priors: Option<PriorFn>,
+    },
+
+    /// Samples a move from the exponential weights of its estimated total score,
+    /// mixed with `exploration` of a uniformly random move
+    Exp3 { exploration: f32 },
+
+    /// Samples a move in proportion to its positive regret,
+    /// mixed with `exploration` of a uniformly random move
+    RegretMatching { exploration: f32 },
+}
+
+impl SelectionPolicy {
+    pub(crate) fn is_sampled(&self) -> bool {
+        matches!(
+            self,
+            SelectionPolicy::Exp3 { .. } | SelectionPolicy::RegretMatching { .. }
+        )
+    }
+
+    pub(crate) fn priors(
+        &self,
+        state: &State,
+        side_ref: &SideReference,
+        options: &[MoveChoice],
+    ) -> Vec<f32> {
+        if let SelectionPolicy::Puct {
+            priors: Some(prior_fn),
+            ..
+        } = self
+        {
+            let priors = prior_fn(state, side_ref, options);
+            if priors.len() == options.len() {
+                return priors;
+            }
+        }
+        vec![1.0 / options.len().max(1) as f32; options.len()]
+    }
+
+    /// The probability of picking each move for the sampled policies,
+    /// where `cumulative` is each move's estimated total score for Exp3 or its regret
+    pub(crate) fn probabilities(&self, cumulative: &[f32]) -> Vec<f32> {
+        let count = cumulative.len() as f32;
+        match self {
+            SelectionPolicy::Exp3 { exploration } => {
+                let learning_rate = exploration / count;
+                let max = cumulative.iter().cloned().fold(f32::MIN, f32::max);
+                let weights: Vec<f32> = cumulative
+                    .iter()
+                    .map(|c| (learning_rate * (c - max)).exp())
+                    .collect();
+                let total: f32 = weights.iter().sum();
+                weights
+                    .iter()
+                    .map(|w| (1.0 - exploration) * w / total + exploration / count)
+                    .collect()
+            }
+            SelectionPolicy::RegretMatching { exploration } => {
+                let total: f32 = cumulative.iter().map(|c| c.max(0.0)).sum();
+                cumulative
+                    .iter()
+                    .map(|c| {
+                        let strategy = if total > 0.0 {
+                            c.max(0.0) / total
+                        } else {
+                            1.0 / count
+                        };
+                        (1.0 - exploration) * strategy + exploration / count
+                    })
+                    .collect()
+            }
+            SelectionPolicy::Ucb1 { .. } | SelectionPolicy::Puct { .. } => {
+                vec![1.0 / count; cumulative.len()]
+            }
+        }
+    }
+
+    /// How much the cumulative value of move `index` changes after move `chosen`,
+    /// picked with `probability`, scored `score`
+    pub(crate) fn cumulative_update(
+        &self,
+        index: usize,
+        chosen: usize,
+        score: f32,
+        probability: f32,
+    ) -> f32 {
+        let estimate = if index == chosen {
+            score / probability
+        } else {
+            0.0
+        };
+        match self {
+            SelectionPolicy::Exp3 { .. } => estimate,
+            SelectionPolicy::RegretMatching { .. } => estimate - score,
+            SelectionPolicy::Ucb1 { .. } | SelectionPolicy::Puct { .. } => 0.0,
+        }
+    }
+}
+
+pub(crate) fn sample_probabilities(probabilities: &[f32], rng: &mut (impl Rng + ?Sized)) -> usize {
+    let total: f32 = probabilities.iter().sum();
+    let mut threshold = rng.random_range(0.0..total);
+    for (index, probability) in probabilities.iter().enumerate() {
+        threshold -= probability;
+        if threshold <= 0.0 {
+            return index;
+        }
+    }
+    probabilities.len() - 1
+}
+
+/// Settings for a monte-carlo tree search
+#[derive(Debug, Clone, Copy)]
+pub struct MctsConfig {
+    pub selection: SelectionPolicy,
+
+    /// How quickly a rollout's score goes from 0 to 1 as its evaluation moves away
+    /// from the root's evaluation. At 0.0125 a difference of ~200 points is very close to 1
+    pub value_scale: f32,
+
+    /// How many visits each thread adds to a move it is searching,
+    /// steering other threads away from it. Only used by the multi-threaded search
+    pub virtual_loss_visits: u32,
+
+    /// How many moves below the root the search branches on damage rolls
+    pub damage_branch_depth: u16,
+
+    /// Which unlikely outcomes of a move pair are left out of the tree
+    pub pruning: BranchPruning,
+
+    /// How many threads search the tree at once. Only used by the multi-threaded search
+    pub threads: usize,
+
+    /// Seeds the search's random choices so that a search limited by iterations can be
+    /// reproduced. Each worker of the multi-threaded search gets its own seed derived from it
+    pub seed: Option<u64>,
+}
+
+impl Default for MctsConfig {
+    fn default() -> MctsConfig {
+        MctsConfig {
+            selection: SelectionPolicy::Ucb1 { exploration: 2.0 },
+            value_scale: 0.0125,
+            virtual_loss_visits: 3,
+            damage_branch_depth: 2,
+            pruning: BranchPruning::default(),
+            threads: 1,
+            seed: None,
+        }
+    }
+}
+
+impl MctsConfig {
+    pub(crate) fn sigmoid(&self, x: f32) -> f32 {
+        1.0 / (1.0 + (-self.value_scale * x).exp())
+    }
+
+    /// The random number generator for one worker of a search
+    pub(crate) fn rng(&self, worker: u64) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed.wrapping_add(worker)),
+            None => StdRng::from_rng(&mut rng()),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -43,33 +219,39 @@ impl Node {
             s2_options: None,
         }
     }
-    unsafe fn populate(&mut self, s1_options: Vec<MoveChoice>, s2_options: Vec<MoveChoice>) {
+    unsafe fn populate(
+        &mut self,
+        state: &State,
+        s1_options: Vec<MoveChoice>,
+        s2_options: Vec<MoveChoice>,
+        config: &MctsConfig,
+    ) {
+        let s1_priors = config
+            .selection
+            .priors(state, &SideReference::SideOne, &s1_options);
+        let s2_priors = config
+            .selection
+            .priors(state, &SideReference::SideTwo, &s2_options);
         let s1_options_vec: Vec<MoveNode> = s1_options
             .iter()
-            .map(|x| MoveNode {
-                move_choice: x.clone(),
-                total_score: 0.0,
-                visits: 0,
-            })
+            .zip(s1_priors)
+            .map(|(x, prior)| MoveNode::new(*x, prior))
             .collect();
         let s2_options_vec: Vec<MoveNode> = s2_options
             .iter()
-            .map(|x| MoveNode {
-                move_choice: x.clone(),
-                total_score: 0.0,
-                visits: 0,
-            })
+            .zip(s2_priors)
+            .map(|(x, prior)| MoveNode::new(*x, prior))
             .collect();
 
         self.s1_options = Some(s1_options_vec);
         self.s2_options = Some(s2_options_vec);
     }
 
-    pub fn maximize_ucb_for_side(&self, side_map: &[MoveNode]) -> usize {
+    pub fn maximize_ucb_for_side(&self, side_map: &[MoveNode], exploration: f32) -> usize {
         let mut choice = 0;
         let mut best_ucb1 = f32::MIN;
         for (index, node) in side_map.iter().enumerate() {
-            let this_ucb1 = node.ucb1(self.times_visited);
+            let this_ucb1 = node.ucb1(self.times_visited, exploration);
             if this_ucb1 > best_ucb1 {
                 best_ucb1 = this_ucb1;
                 choice = index;
@@ -78,26 +260,68 @@ impl Node {
         choice
     }
 
+    fn maximize_puct_for_side(&self, side_map: &[MoveNode], exploration: f32) -> usize {
+        let mut choice = 0;
+        let mut best_puct = f32::MIN;
+        for (index, node) in side_map.iter().enumerate() {
+            let this_puct = node.puct(self.times_visited, exploration);
+            if this_puct > best_puct {
+                best_puct = this_puct;
+                choice = index;
+            }
+        }
+        choice
+    }
+
+    fn select_for_side(
+        &self,
+        side_map: &mut [MoveNode],
+        config: &MctsConfig,
+        rng: &mut impl Rng,
+    ) -> usize {
+        match config.selection {
+            SelectionPolicy::Ucb1 { exploration } => {
+                self.maximize_ucb_for_side(side_map, exploration)
+            }
+            SelectionPolicy::Puct { exploration, .. } => {
+                self.maximize_puct_for_side(side_map, exploration)
+            }
+            SelectionPolicy::Exp3 { .. } | SelectionPolicy::RegretMatching { .. } => {
+                let cumulative: Vec<f32> = side_map.iter().map(|n| n.cumulative).collect();
+                let probabilities = config.selection.probabilities(&cumulative);
+                let choice = sample_probabilities(&probabilities, rng);
+                side_map[choice].last_probability = probabilities[choice];
+                choice
+            }
+        }
+    }
+
     pub unsafe fn selection(
         &mut self,
         state: &mut State,
         children: &mut HashMap<(usize, usize, usize), Box<[Node]>>,
         rng: &mut impl Rng,
+        config: &MctsConfig,
     ) -> (*mut Node, usize, usize) {
         if self.s1_options.is_none() {
             let (s1_options, s2_options) = state.get_all_options();
-            self.populate(s1_options, s2_options);
+            self.populate(state, s1_options, s2_options, config);
         }
 
-        let s1_mc_index = self.maximize_ucb_for_side(self.s1_options.as_ref().unwrap());
-        let s2_mc_index = self.maximize_ucb_for_side(self.s2_options.as_ref().unwrap());
+        let mut s1_options = self.s1_options.take().unwrap();
+        let mut s2_options = self.s2_options.take().unwrap();
+        let s1_mc_index = self.select_for_side(&mut s1_options, config, rng);
+        let s2_mc_index = self.select_for_side(&mut s2_options, config, rng);
+        self.s1_options = Some(s1_options);
+        self.s2_options = Some(s2_options);
+
         let key = (self as *mut Node as usize, s1_mc_index, s2_mc_index);
         match children.get_mut(&key) {
             Some(child_vector) => {
                 let child_vec_ptr = child_vector as *mut Box<[Node]>;
                 let chosen_child = self.sample_node(child_vec_ptr, rng);
                 state.apply_instructions(&(*chosen_child).instructions.instruction_list);
-                (*chosen_child).selection(state, children, rng)
+                (*chosen_child).selection(state, children, rng, config)
             }
             None => (self as *mut Node, s1_mc_index, s2_mc_index),
         }
@@ -124,6 +348,18 @@ impl Node {
         &mut nodes[nodes.len() - 1] as *mut Node
     }
 
+    // true if this node is fewer than `depth` moves below the root
+    unsafe fn is_within_depth_of_root(&self, depth: u16) -> bool {
+        let mut node = self as *const Node;
+        for _ in 0..depth {
+            if (*node).root {
+                return true;
+            }
+            node = (*node).parent;
+        }
+        false
+    }
+
     pub unsafe fn expand(
         &mut self,
         state: &mut State,
@@ -131,6 +367,7 @@ impl Node {
         s2_move_index: usize,
         children: &mut HashMap<(usize, usize, usize), Box<[Node]>>,
         rng: &mut impl Rng,
+        config: &MctsConfig,
     ) -> *mut Node {
         let s1_move = &self.s1_options.as_ref().unwrap()[s1_move_index].move_choice;
         let s2_move = &self.s2_options.as_ref().unwrap()[s2_move_index].move_choice;
@@ -140,9 +377,10 @@ impl Node {
         {
             return self as *mut Node;
         }
-        let should_branch_on_damage = self.root || (*self.parent).root;
+        let should_branch_on_damage = self.is_within_depth_of_root(config.damage_branch_depth);
         let mut new_instructions =
             generate_instructions_from_move_pair(state, s1_move, s2_move, should_branch_on_damage);
+        config.pruning.prune(&mut new_instructions);
         let mut this_pair_vec = Vec::with_capacity(new_instructions.len());
         for state_instructions in new_instructions.drain(..) {
             let mut new_node = Node::new();
@@ -167,31 +405,44 @@ impl Node {
         new_node_ptr
     }
 
-    pub unsafe fn backpropagate(&mut self, score: f32, state: &mut State) {
+    /// Adds `score`, from side one's point of view, to the options that led to this node and
+    /// to each of its ancestors, reversing their instructions on `state` on the way to the root.
+    ///
+    /// # Safety
+    ///
+    /// Every ancestor of this node up to the root must still be alive and expanded, and `state`
+    /// must be the state reached at this node
+    pub unsafe fn backpropagate(&mut self, score: f32, state: &mut State, config: &MctsConfig) {
         self.times_visited += 1;
         if self.root {
             return;
         }
 
-        let parent_s1_movenode =
-            &mut (*self.parent).s1_options.as_mut().unwrap()[self.s1_choice as usize];
-        parent_s1_movenode.total_score += score;
-        parent_s1_movenode.visits += 1;
-
-        let parent_s2_movenode =
-            &mut (*self.parent).s2_options.as_mut().unwrap()[self.s2_choice as usize];
-        parent_s2_movenode.total_score += 1.0 - score;
-        parent_s2_movenode.visits += 1;
+        let parent_s1_options = (*self.parent).s1_options.as_mut().unwrap();
+        MoveNode::add_result(parent_s1_options, self.s1_choice as usize, score, config);
+        let parent_s2_options = (*self.parent).s2_options.as_mut().unwrap();
+        MoveNode::add_result(
+            parent_s2_options,
+            self.s2_choice as usize,
+            1.0 - score,
+            config,
+        );
 
         state.reverse_instructions(&self.instructions.instruction_list);
-        (*self.parent).backpropagate(score, state);
+        (*self.parent).backpropagate(score, state, config);
     }
 
-    pub fn rollout(&mut self, state: &mut State, root_eval: &f32) -> f32 {
+    pub fn rollout(
+        &mut self,
+        state: &mut State,
+        root_eval: &f32,
+        evaluator: &dyn Evaluator,
+        config: &MctsConfig,
+    ) -> f32 {
         let battle_is_over = state.battle_is_over();
         if battle_is_over == 0.0 {
-            let eval = evaluate(state);
-            sigmoid(eval - root_eval)
+            let eval = evaluator.evaluate(state);
+            config.sigmoid(eval - root_eval)
         } else {
             if battle_is_over == -1.0 {
                 0.0
@@ -207,28 +458,72 @@ pub struct MoveNode {
     pub move_choice: MoveChoice,
     pub total_score: f32,
     pub visits: u32,
+
+    // only used by some selection policies
+    pub prior: f32,
+    pub cumulative: f32,
+    pub last_probability: f32,
 }
 
 impl MoveNode {
-    pub fn ucb1(&self, parent_visits: u32) -> f32 {
+    fn new(move_choice: MoveChoice, prior: f32) -> MoveNode {
+        MoveNode {
+            move_choice,
+            total_score: 0.0,
+            visits: 0,
+            prior,
+            cumulative: 0.0,
+            last_probability: 1.0,
+        }
+    }
+    pub fn ucb1(&self, parent_visits: u32, exploration: f32) -> f32 {
         if self.visits == 0 {
             return f32::INFINITY;
         }
         let score = (self.total_score / self.visits as f32)
-            + (2.0 * (parent_visits as f32).ln() / self.visits as f32).sqrt();
+            + (exploration * (parent_visits as f32).ln() / self.visits as f32).sqrt();
         score
     }
+    pub fn puct(&self, parent_visits: u32, exploration: f32) -> f32 {
+        // an unvisited move is assumed to be even
+        let average_score = if self.visits == 0 {
+            0.5
+        } else {
+            self.average_score()
+        };
+        average_score
+            + exploration * self.prior * (parent_visits as f32).sqrt() / (1 + self.visits) as f32
+    }
     pub fn average_score(&self) -> f32 {
         let score = self.total_score / self.visits as f32;
         score
     }
+
+    fn add_result(side_map: &mut [MoveNode], chosen: usize, score: f32, config: &MctsConfig) {
+        side_map[chosen].total_score += score;
+        side_map[chosen].visits += 1;
+        if config.selection.is_sampled() {
+            let probability = side_map[chosen].last_probability;
+            for (index, node) in side_map.iter_mut().enumerate() {
+                node.cumulative +=
+                    config
+                        .selection
+                        .cumulative_update(index, chosen, score, probability);
+            }
+        }
+    }
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MctsSideResult {
     pub move_choice: MoveChoice,
     pub total_score: f32,
     pub visits: u32,
+
+    /// The probability of playing this move in the side's mixed strategy.
+    /// The policies of one side add up to 1
+    pub policy: f32,
 }
 
 impl MctsSideResult {
@@ -241,58 +536,377 @@ impl MctsSideResult {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MctsResult {
     pub s1: Vec<MctsSideResult>,
     pub s2: Vec<MctsSideResult>,
     pub iteration_count: u32,
 }
 
+impl MctsResult {
+    fn side(&self, side_ref: &SideReference) -> &[MctsSideResult] {
+        match side_ref {
+            SideReference::SideOne => &self.s1,
+            SideReference::SideTwo => &self.s2,
+        }
+    }
+
+    /// Each move's visits raised to `1 / temperature`, normalized to add up to 1.
+    /// A temperature of 1 is the share of visits, and 0 always plays the most visited move
+    pub fn visit_policy(&self, side_ref: &SideReference, temperature: f32) -> Vec<f32> {
+        let side_results = self.side(side_ref);
+        let most_visits = side_results.iter().map(|r| r.visits).max().unwrap_or(0);
+        if most_visits == 0 {
+            return vec![1.0 / side_results.len() as f32; side_results.len()];
+        }
+        if temperature <= 0.0 {
+            let best = side_results
+                .iter()
+                .position(|r| r.visits == most_visits)
+                .unwrap();
+            let mut policy = vec![0.0; side_results.len()];
+            policy[best] = 1.0;
+            return policy;
+        }
+
+        // relative to the most visited move so large visit counts cannot overflow
+        let weights: Vec<f32> = side_results
+            .iter()
+            .map(|r| (r.visits as f32 / most_visits as f32).powf(1.0 / temperature))
+            .collect();
+        let total: f32 = weights.iter().sum();
+        weights.iter().map(|w| w / total).collect()
+    }
+
+    /// Picks a move for `side_ref` with the probabilities in its `policy`
+    pub fn sample_move(&self, side_ref: &SideReference, rng: &mut impl Rng) -> MoveChoice {
+        let side_results = self.side(side_ref);
+        let policy: Vec<f32> = side_results.iter().map(|r| r.policy).collect();
+        side_results[sample_probabilities(&policy, rng)].move_choice
+    }
+}
+
+/// Sets each move's `policy` to its share of the visits. The sampled selection policies
+/// mix in uniformly random moves while searching, so those visits are taken out again
+pub(crate) fn set_policies(side_results: &mut [MctsSideResult], config: &MctsConfig) {
+    let exploration = match config.selection {
+        SelectionPolicy::Exp3 { exploration } | SelectionPolicy::RegretMatching { exploration } => {
+            exploration
+        }
+        SelectionPolicy::Ucb1 { .. } | SelectionPolicy::Puct { .. } => 0.0,
+    };
+    let total_visits: u32 = side_results.iter().map(|r| r.visits).sum();
+    let uniform_visits = exploration * total_visits as f32 / side_results.len() as f32;
+    let mut weights: Vec<f32> = side_results
+        .iter()
+        .map(|r| (r.visits as f32 - uniform_visits).max(0.0))
+        .collect();
+    if weights.iter().sum::<f32>() <= 0.0 {
+        weights = side_results.iter().map(|r| r.visits as f32).collect();
+    }
+    let total: f32 = weights.iter().sum();
+    let move_count = side_results.len() as f32;
+    for (side_result, weight) in side_results.iter_mut().zip(weights) {
+        side_result.policy = if total > 0.0 {
+            weight / total
+        } else {
+            1.0 / move_count
+        };
+    }
+}
+
 fn mcts_iteration(
     root_node: &mut Node,
     state: &mut State,
     root_eval: &f32,
     children: &mut HashMap<(usize, usize, usize), Box<[Node]>>,
     rng: &mut impl Rng,
+    evaluator: &dyn Evaluator,
+    config: &MctsConfig,
 ) {
-    let (mut new_node, s1_move, s2_move) = unsafe { root_node.selection(state, children, rng) };
-    new_node = unsafe { (*new_node).expand(state, s1_move, s2_move, children, rng) };
-    let rollout_result = unsafe { (*new_node).rollout(state, root_eval) };
-    unsafe { (*new_node).backpropagate(rollout_result, state) }
+    let (mut new_node, s1_move, s2_move) =
+        unsafe { root_node.selection(state, children, rng, config) };
+    new_node = unsafe { (*new_node).expand(state, s1_move, s2_move, children, rng, config) };
+    let rollout_result = unsafe { (*new_node).rollout(state, root_eval, evaluator, config) };
+    unsafe { (*new_node).backpropagate(rollout_result, state, config) }
 }
 
-enum SearchLimit {
+/// When a search stops
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchLimit {
     Time(Duration),
+    /// Counts only the iterations of this search, not the visits kept in the tree
     Iterations(u32),
 }
 
-fn run_mcts_loop(
-    root_node: &mut Node,
-    state: &mut State,
-    root_eval: &f32,
-    children: &mut HashMap<(usize, usize, usize), Box<[Node]>>,
-    limit: SearchLimit,
-) {
-    let mut rng = rng();
-    let start_time = std::time::Instant::now();
-    loop {
-        for _ in 0..1000 {
-            mcts_iteration(root_node, state, root_eval, children, &mut rng);
-        }
-        if root_node.times_visited >= 10_000_000 {
-            break;
-        }
-        match limit {
-            SearchLimit::Time(max_time) => {
-                if start_time.elapsed() >= max_time {
-                    break;
+impl SearchLimit {
+    /// Limits the search to `max_iterations` if it is above 0, otherwise to `max_time`
+    pub fn new(max_time: Duration, max_iterations: u32) -> SearchLimit {
+        if max_iterations > 0 {
+            SearchLimit::Iterations(max_iterations)
+        } else {
+            SearchLimit::Time(max_time)
+        }
+    }
+}
+
+/// Reports the root of a search, with side one's most visited move as its best move
+pub(crate) fn report_mcts_progress(control: &SearchControl, result: &MctsResult) {
+    let best_move = result
+        .s1
+        .iter()
+        .max_by_key(|r| r.visits)
+        .map(|r| r.move_choice)
+        .unwrap_or(MoveChoice::None);
+    control.report(SearchProgress::Iterations { best_move, result });
+}
+
+fn root_result(root_node: &Node, config: &MctsConfig) -> MctsResult {
+    let side_results = |options: &Option<Vec<MoveNode>>| {
+        let mut side_results: Vec<MctsSideResult> = options
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|v| MctsSideResult {
+                move_choice: v.move_choice.clone(),
+                total_score: v.total_score,
+                visits: v.visits,
+                policy: 0.0,
+            })
+            .collect();
+        set_policies(&mut side_results, config);
+        side_results
+    };
+    MctsResult {
+        s1: side_results(&root_node.s1_options),
+        s2: side_results(&root_node.s2_options),
+        iteration_count: root_node.times_visited,
+    }
+}
+
+/// A search tree that can be kept from one turn to the next.
+///
+/// After a turn is played, `advance` keeps the subtree of the outcome that happened
+/// so the next `search` starts with the visits that were already spent on it
+pub struct MctsTree {
+    // the root is kept in the branch it was expanded in so that its address,
+    // which the children map is keyed on, does not change when the tree is advanced
+    root_branch: Box<[Node]>,
+    root_index: usize,
+    children: HashMap<(usize, usize, usize), Box<[Node]>>,
+    config: MctsConfig,
+    // the evaluation of the state the tree was first searched from. rollouts are scored
+    // against it, so it is kept when the tree is advanced to match the kept statistics
+    root_eval: Option<f32>,
+}
+
+impl Default for MctsTree {
+    fn default() -> MctsTree {
+        MctsTree::new()
+    }
+}
+
+impl MctsTree {
+    pub fn new() -> MctsTree {
+        MctsTree::with_config(MctsConfig::default())
+    }
+
+    pub fn with_config(config: MctsConfig) -> MctsTree {
+        let mut root_node = Node::new();
+        root_node.root = true;
+        MctsTree {
+            root_branch: vec![root_node].into_boxed_slice(),
+            root_index: 0,
+            children: HashMap::new(),
+            config,
+            root_eval: None,
+        }
+    }
+
+    fn root_options_match(
+        &self,
+        side_one_options: &[MoveChoice],
+        side_two_options: &[MoveChoice],
+    ) -> bool {
+        let root_node = &self.root_branch[self.root_index];
+        match (&root_node.s1_options, &root_node.s2_options) {
+            (Some(s1), Some(s2)) => {
+                s1.iter()
+                    .map(|m| m.move_choice)
+                    .eq(side_one_options.iter().copied())
+                    && s2
+                        .iter()
+                        .map(|m| m.move_choice)
+                        .eq(side_two_options.iter().copied())
+            }
+            _ => false,
+        }
+    }
+
+    /// Searches from `state`, which must be the state the tree is rooted at.
+    ///
+    /// The kept subtree is only used if its options are the same as the options given,
+    /// otherwise the search starts over. `max_iterations` counts only the iterations
+    /// run by this call, but the result includes the visits kept from earlier searches
+    pub fn search(
+        &mut self,
+        state: &mut State,
+        side_one_options: Vec<MoveChoice>,
+        side_two_options: Vec<MoveChoice>,
+        max_time: Duration,
+        max_iterations: u32,
+        evaluator: &dyn Evaluator,
+    ) -> MctsResult {
+        self.search_with_control(
+            state,
+            side_one_options,
+            side_two_options,
+            SearchLimit::new(max_time, max_iterations),
+            evaluator,
+            &SearchControl::default(),
+        )
+    }
+
+    /// `search` that also stops when `control` is cancelled and reports its progress
+    pub fn search_with_control(
+        &mut self,
+        state: &mut State,
+        side_one_options: Vec<MoveChoice>,
+        side_two_options: Vec<MoveChoice>,
+        limit: SearchLimit,
+        evaluator: &dyn Evaluator,
+        control: &SearchControl,
+    ) -> MctsResult {
+        if !self.root_options_match(&side_one_options, &side_two_options) {
+            *self = MctsTree::with_config(self.config);
+            unsafe {
+                self.root_branch[self.root_index].populate(
+                    state,
+                    side_one_options,
+                    side_two_options,
+                    &self.config,
+                );
+            }
+        }
+
+        let root_eval = *self
+            .root_eval
+            .get_or_insert_with(|| evaluator.evaluate(state));
+        self.run_mcts_loop(state, &root_eval, limit, evaluator, control);
+
+        root_result(&self.root_branch[self.root_index], &self.config)
+    }
+
+    fn run_mcts_loop(
+        &mut self,
+        state: &mut State,
+        root_eval: &f32,
+        limit: SearchLimit,
+        evaluator: &dyn Evaluator,
+        control: &SearchControl,
+    ) {
+        let root_node = &mut self.root_branch[self.root_index];
+        let children = &mut self.children;
+        let config = &self.config;
+        let mut rng = config.rng(0);
+        let start_time = Instant::now();
+        let mut last_report = start_time;
+        let start_visits = root_node.times_visited;
+        loop {
+            for _ in 0..1000 {
+                mcts_iteration(
+                    root_node, state, root_eval, children, &mut rng, evaluator, config,
+                );
+            }
+            if root_node.times_visited >= 10_000_000 || control.cancellation.is_cancelled() {
+                break;
+            }
+            if control.on_progress.is_some() && last_report.elapsed() >= control.progress_interval {
+                report_mcts_progress(control, &root_result(root_node, config));
+                last_report = Instant::now();
+            }
+            match limit {
+                SearchLimit::Time(max_time) => {
+                    if start_time.elapsed() >= max_time {
+                        break;
+                    }
+                }
+                SearchLimit::Iterations(n) => {
+                    if root_node.times_visited - start_visits >= n {
+                        break;
+                    }
                 }
             }
-            SearchLimit::Iterations(n) => {
-                if root_node.times_visited >= n {
-                    break;
+        }
+    }
+
+    /// Re-roots the tree at the node reached when side one used `s1_choice`, side two used
+    /// `s2_choice` and `instructions` were applied, dropping every other part of the tree.
+    ///
+    /// Returns false and clears the tree if that node was never expanded
+    pub fn advance(
+        &mut self,
+        s1_choice: &MoveChoice,
+        s2_choice: &MoveChoice,
+        instructions: &[Instruction],
+    ) -> bool {
+        let root_node = &self.root_branch[self.root_index];
+        let key = match (&root_node.s1_options, &root_node.s2_options) {
+            (Some(s1), Some(s2)) => {
+                match (
+                    s1.iter().position(|m| &m.move_choice == s1_choice),
+                    s2.iter().position(|m| &m.move_choice == s2_choice),
+                ) {
+                    (Some(s1_index), Some(s2_index)) => {
+                        Some((root_node as *const Node as usize, s1_index, s2_index))
+                    }
+                    _ => None,
                 }
             }
+            _ => None,
+        };
+        let branch = match key.and_then(|k| self.children.remove(&k)) {
+            Some(branch) => branch,
+            None => {
+                *self = MctsTree::with_config(self.config);
+                return false;
+            }
+        };
+        let root_index = match branch
+            .iter()
+            .position(|n| n.instructions.instruction_list == instructions)
+        {
+            Some(index) => index,
+            None => {
+                *self = MctsTree::with_config(self.config);
+                return false;
+            }
+        };
+
+        // keep only the branches that hang off of the new root. the rest are dropped
+        // so their addresses cannot be reused by new nodes while still being keys
+        let mut keys_by_parent: HashMap<usize, Vec<(usize, usize, usize)>> = HashMap::new();
+        for key in self.children.keys() {
+            keys_by_parent.entry(key.0).or_default().push(*key);
+        }
+        let mut kept_children = HashMap::new();
+        let mut to_visit = vec![&branch[root_index] as *const Node as usize];
+        while let Some(parent) = to_visit.pop() {
+            for key in keys_by_parent.remove(&parent).unwrap_or_default() {
+                let child_branch = self.children.remove(&key).unwrap();
+                to_visit.extend(child_branch.iter().map(|n| n as *const Node as usize));
+                kept_children.insert(key, child_branch);
+            }
         }
+
+        self.root_branch = branch;
+        self.root_index = root_index;
+        self.children = kept_children;
+        let root_node = &mut self.root_branch[self.root_index];
+        root_node.root = true;
+        root_node.parent = std::ptr::null_mut();
+        root_node.instructions = StateInstructions::default();
+        true
     }
 }
 
@@ -302,53 +916,294 @@ pub fn perform_mcts(
     side_two_options: Vec<MoveChoice>,
     max_time: Duration,
     max_iterations: u32,
+    evaluator: &dyn Evaluator,
 ) -> MctsResult {
-    let mut root_node = Node::new();
-    unsafe {
-        root_node.populate(side_one_options, side_two_options);
-    }
-    root_node.root = true;
-    let mut children: HashMap<(usize, usize, usize), Box<[Node]>> = HashMap::new();
-
-    let root_eval = evaluate(state);
-    let search_limit = if max_iterations > 0 {
-        SearchLimit::Iterations(max_iterations)
-    } else {
-        SearchLimit::Time(max_time)
-    };
-    run_mcts_loop(
-        &mut root_node,
+    MctsTree::new().search(
         state,
-        &root_eval,
-        &mut children,
-        search_limit,
-    );
-
-    let result = MctsResult {
-        s1: root_node
-            .s1_options
-            .as_ref()
-            .unwrap()
+        side_one_options,
+        side_two_options,
+        max_time,
+        max_iterations,
+        evaluator,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::DefaultEvaluator;
+
+    #[test]
+    fn test_advancing_the_tree_keeps_the_visits_of_the_outcome_that_happened() {
+        let mut state = State::default();
+        let (s1_options, s2_options) = state.root_get_all_options();
+        let mut tree = MctsTree::new();
+        tree.search(
+            &mut state,
+            s1_options,
+            s2_options,
+            Duration::from_millis(0),
+            1000,
+            &DefaultEvaluator,
+        );
+
+        let root_eval = tree.root_eval;
+        assert!(root_eval.is_some());
+
+        // the most visited outcome of any move pair from the root
+        let root_node = &tree.root_branch[tree.root_index];
+        let root_key = root_node as *const Node as usize;
+        let (key, node) = tree
+            .children
             .iter()
-            .map(|v| MctsSideResult {
-                move_choice: v.move_choice.clone(),
-                total_score: v.total_score,
-                visits: v.visits,
-            })
-            .collect(),
-        s2: root_node
-            .s2_options
-            .as_ref()
-            .unwrap()
+            .filter(|(key, _)| key.0 == root_key)
+            .flat_map(|(key, branch)| branch.iter().map(move |node| (key, node)))
+            .max_by_key(|(_, node)| node.times_visited)
+            .unwrap();
+        let s1_choice = root_node.s1_options.as_ref().unwrap()[key.1].move_choice;
+        let s2_choice = root_node.s2_options.as_ref().unwrap()[key.2].move_choice;
+        let instructions = node.instructions.instruction_list.clone();
+        let kept_visits = node.times_visited;
+        assert!(kept_visits > 0);
+
+        assert!(tree.advance(&s1_choice, &s2_choice, &instructions));
+        state.apply_instructions(&instructions);
+        let (s1_options, s2_options) = state.root_get_all_options();
+        let result = tree.search(
+            &mut state,
+            s1_options,
+            s2_options,
+            Duration::from_millis(0),
+            1000,
+            &DefaultEvaluator,
+        );
+        assert_eq!(kept_visits + 1000, result.iteration_count);
+        // the kept visits were scored against the first root's evaluation
+        assert_eq!(root_eval, tree.root_eval);
+    }
+
+    #[test]
+    fn test_every_selection_policy_searches_the_root_options() {
+        for selection in [
+            SelectionPolicy::Ucb1 { exploration: 1.0 },
+            SelectionPolicy::Puct {
+                exploration: 1.5,
+                priors: None,
+            },
+            SelectionPolicy::Exp3 { exploration: 0.2 },
+            SelectionPolicy::RegretMatching { exploration: 0.2 },
+        ] {
+            let mut state = State::default();
+            let (s1_options, s2_options) = state.root_get_all_options();
+            let config = MctsConfig {
+                selection,
+                ..Default::default()
+            };
+            let result = MctsTree::with_config(config).search(
+                &mut state,
+                s1_options,
+                s2_options,
+                Duration::from_millis(0),
+                1000,
+                &DefaultEvaluator,
+            );
+
+            assert_eq!(1000, result.iteration_count);
+            assert_eq!(1000, result.s1.iter().map(|r| r.visits).sum::<u32>());
+            assert_eq!(1000, result.s2.iter().map(|r| r.visits).sum::<u32>());
+        }
+    }
+
+    #[test]
+    fn test_regret_matching_favours_the_move_with_the_most_regret() {
+        let selection = SelectionPolicy::RegretMatching { exploration: 0.0 };
+        assert_eq!(
+            vec![0.25, 0.75, 0.0],
+            selection.probabilities(&[1.0, 3.0, -2.0])
+        );
+        // no positive regret is a uniform strategy
+        assert_eq!(vec![0.5, 0.5], selection.probabilities(&[-1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_advancing_to_an_unexpanded_outcome_clears_the_tree() {
+        let mut state = State::default();
+        let (s1_options, s2_options) = state.root_get_all_options();
+        let mut tree = MctsTree::new();
+        tree.search(
+            &mut state,
+            s1_options.clone(),
+            s2_options.clone(),
+            Duration::from_millis(0),
+            1000,
+            &DefaultEvaluator,
+        );
+
+        assert!(!tree.advance(
+            &s1_options[0],
+            &s2_options[0],
+            &[Instruction::DecrementTrickRoomTurnsRemaining],
+        ));
+        assert!(tree.children.is_empty());
+        assert!(tree.root_eval.is_none());
+    }
+
+    fn side_results(visits: &[u32]) -> Vec<MctsSideResult> {
+        visits
             .iter()
-            .map(|v| MctsSideResult {
-                move_choice: v.move_choice.clone(),
-                total_score: v.total_score,
-                visits: v.visits,
+            .map(|visits| MctsSideResult {
+                move_choice: MoveChoice::None,
+                total_score: 0.0,
+                visits: *visits,
+                policy: 0.0,
             })
-            .collect(),
-        iteration_count: root_node.times_visited,
-    };
+            .collect()
+    }
+
+    #[test]
+    fn test_policies_are_visit_proportions_without_the_mixed_in_exploration() {
+        let mut ucb1 = side_results(&[8, 16, 32, 8]);
+        set_policies(&mut ucb1, &MctsConfig::default());
+        assert_eq!(
+            vec![0.125, 0.25, 0.5, 0.125],
+            ucb1.iter().map(|r| r.policy).collect::<Vec<f32>>()
+        );
+
+        // half of the 64 visits were spread uniformly by the exploration
+        let mut exp3 = side_results(&[8, 16, 32, 8]);
+        let config = MctsConfig {
+            selection: SelectionPolicy::Exp3 { exploration: 0.5 },
+            ..MctsConfig::default()
+        };
+        set_policies(&mut exp3, &config);
+        assert_eq!(
+            vec![0.0, 0.25, 0.75, 0.0],
+            exp3.iter().map(|r| r.policy).collect::<Vec<f32>>()
+        );
+
+        let mut unvisited = side_results(&[0, 0]);
+        set_policies(&mut unvisited, &config);
+        assert_eq!(
+            vec![0.5, 0.5],
+            unvisited.iter().map(|r| r.policy).collect::<Vec<f32>>()
+        );
+    }
+
+    #[test]
+    fn test_visit_policy_temperature() {
+        let result = MctsResult {
+            s1: side_results(&[8, 16, 32, 8]),
+            s2: side_results(&[0, 0]),
+            iteration_count: 64,
+        };
+        assert_eq!(
+            vec![0.125, 0.25, 0.5, 0.125],
+            result.visit_policy(&SideReference::SideOne, 1.0)
+        );
+        assert_eq!(
+            vec![0.0, 0.0, 1.0, 0.0],
+            result.visit_policy(&SideReference::SideOne, 0.0)
+        );
+        assert_eq!(
+            vec![0.5, 0.5],
+            result.visit_policy(&SideReference::SideTwo, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_sample_move_only_plays_moves_in_the_policy() {
+        let mut state = State::default();
+        let (s1_options, s2_options) = state.root_get_all_options();
+        let mut result = perform_mcts(
+            &mut state,
+            s1_options,
+            s2_options,
+            Duration::from_millis(0),
+            1000,
+            &DefaultEvaluator,
+        );
+        for (index, side_result) in result.s1.iter_mut().enumerate() {
+            side_result.policy = if index == 1 { 1.0 } else { 0.0 };
+        }
+
+        let mut rng = rand::rng();
+        for _ in 0..20 {
+            assert_eq!(
+                result.s1[1].move_choice,
+                result.sample_move(&SideReference::SideOne, &mut rng)
+            );
+        }
+        let policy_total: f32 = result.s2.iter().map(|r| r.policy).sum();
+        assert!((policy_total - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_searches_with_the_same_seed_are_identical() {
+        let search = |seed| {
+            let mut state = State::default();
+            let (s1_options, s2_options) = state.root_get_all_options();
+            let config = MctsConfig {
+                selection: SelectionPolicy::Exp3 { exploration: 0.1 },
+                seed: Some(seed),
+                ..MctsConfig::default()
+            };
+            MctsTree::with_config(config).search(
+                &mut state,
+                s1_options,
+                s2_options,
+                Duration::from_millis(0),
+                2000,
+                &DefaultEvaluator,
+            )
+        };
+        let scores = |result: &MctsResult| {
+            result
+                .s1
+                .iter()
+                .chain(result.s2.iter())
+                .map(|r| (r.visits, r.total_score.to_bits()))
+                .collect::<Vec<(u32, u32)>>()
+        };
+
+        assert_eq!(scores(&search(7)), scores(&search(7)));
+        assert_ne!(scores(&search(7)), scores(&search(8)));
+    }
 
-    result
+    #[test]
+    fn test_search_reports_progress_and_stops_when_cancelled() {
+        let mut state = State::default();
+        let (s1_options, s2_options) = state.root_get_all_options();
+        let reports = std::cell::Cell::new(0);
+        let on_progress = |progress: &SearchProgress| {
+            if let SearchProgress::Iterations { result, .. } = progress {
+                assert!(result.iteration_count > 0);
+                reports.set(reports.get() + 1);
+            }
+        };
+        let control = SearchControl {
+            on_progress: Some(&on_progress),
+            progress_interval: Duration::ZERO,
+            ..SearchControl::default()
+        };
+        MctsTree::new().search_with_control(
+            &mut state,
+            s1_options.clone(),
+            s2_options.clone(),
+            SearchLimit::Iterations(5000),
+            &DefaultEvaluator,
+            &control,
+        );
+        assert_eq!(5, reports.get());
+
+        control.cancellation.cancel();
+        let result = MctsTree::new().search_with_control(
+            &mut state,
+            s1_options,
+            s2_options,
+            SearchLimit::Time(Duration::from_secs(60)),
+            &DefaultEvaluator,
+            &control,
+        );
+        assert_eq!(1000, result.iteration_count);
+    }
 }
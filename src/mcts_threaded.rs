@@ -1,53 +1,61 @@
-use crate::engine::evaluate::evaluate;
+use crate::control::{CancellationToken, SearchControl};
 use crate::engine::generate_instructions::generate_instructions_from_move_pair;
 use crate::engine::state::MoveChoice;
-use crate::instruction::StateInstructions;
-use crate::mcts::{MctsResult, MctsSideResult};
-use crate::state::State;
+use crate::evaluator::Evaluator;
+use crate::instruction::{Instruction, StateInstructions};
+use crate::mcts::{
+    report_mcts_progress, sample_probabilities, set_policies, MctsConfig, MctsResult,
+    MctsSideResult, SearchLimit, SelectionPolicy,
+};
+use crate::state::{SideReference, State};
 use dashmap::DashMap;
 use rand::prelude::*;
-use rand::rng;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicI8, AtomicU32, Ordering};
 use std::sync::{Arc, OnceLock};
 use std::thread;
 use std::time::{Duration, Instant};
 
 const MCTS_MAX_ITERATIONS_PER_TREE: u32 = 10_000_000;
-const MCTS_DAMAGE_BRANCH_DEPTH: u8 = 2;
 const SCORE_SCALE: f32 = 400.0;
-const VIRTUAL_LOSS_VISITS: u32 = 3;
+// how often the calling thread checks whether progress should be reported
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_millis(5);
 
 // Node map type alias for clarity.
 // key: (parent node address, s1_move_index, s2_move_index)
 // value: the branch (weighted list of outcome nodes for that move pair)
 type ChildMap = DashMap<(usize, usize, usize), SharedBranch>;
 
-fn sigmoid(x: f32) -> f32 {
-    // Tuned so that ~200 points is very close to 1.0
-    1.0 / (1.0 + (-0.0125 * x).exp())
-}
-
 pub struct MoveNode {
     move_choice: MoveChoice,
     total_score: AtomicU32,
     visits: AtomicU32,
+
+    // only used by some selection policies
+    prior: f32,
+    // the bits of an f32 so it can be updated atomically
+    cumulative: AtomicU32,
 }
 
 impl MoveNode {
-    fn new(move_choice: MoveChoice) -> Self {
+    fn new(move_choice: MoveChoice, prior: f32) -> Self {
         Self {
             move_choice,
             total_score: AtomicU32::new(0),
             visits: AtomicU32::new(0),
+            prior,
+            cumulative: AtomicU32::new(0f32.to_bits()),
         }
     }
 
-    fn add_virtual_loss(&self) {
-        self.visits.fetch_add(VIRTUAL_LOSS_VISITS, Ordering::AcqRel);
+    fn add_virtual_loss(&self, config: &MctsConfig) {
+        self.visits
+            .fetch_add(config.virtual_loss_visits, Ordering::AcqRel);
     }
 
-    fn remove_virtual_loss(&self) {
-        self.visits.fetch_sub(VIRTUAL_LOSS_VISITS, Ordering::AcqRel);
+    fn remove_virtual_loss(&self, config: &MctsConfig) {
+        self.visits
+            .fetch_sub(config.virtual_loss_visits, Ordering::AcqRel);
     }
 
     fn add_result(&self, score: f32) {
@@ -60,15 +68,42 @@ impl MoveNode {
         self.total_score.load(Ordering::Acquire) as f32 / SCORE_SCALE
     }
 
-    fn ucb1(&self, parent_visits: u32) -> f32 {
+    fn cumulative_f32(&self) -> f32 {
+        f32::from_bits(self.cumulative.load(Ordering::Acquire))
+    }
+
+    fn add_cumulative(&self, amount: f32) {
+        if amount == 0.0 {
+            return;
+        }
+        let _ = self
+            .cumulative
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |bits| {
+                Some((f32::from_bits(bits) + amount).to_bits())
+            });
+    }
+
+    fn ucb1(&self, parent_visits: u32, exploration: f32) -> f32 {
         let visits = self.visits.load(Ordering::Acquire);
         if visits == 0 {
             return f32::INFINITY;
         }
         let average_score = self.total_score_f32() / visits as f32;
-        let exploration = 2.0 * (parent_visits as f32).ln().max(0.0) / visits as f32;
+        let exploration = exploration * (parent_visits as f32).ln().max(0.0) / visits as f32;
         average_score + exploration.sqrt()
     }
+
+    fn puct(&self, parent_visits: u32, exploration: f32) -> f32 {
+        let visits = self.visits.load(Ordering::Acquire);
+        // an unvisited move is assumed to be even
+        let average_score = if visits == 0 {
+            0.5
+        } else {
+            self.total_score_f32() / visits as f32
+        };
+        average_score
+            + exploration * self.prior * (parent_visits as f32).sqrt() / (1 + visits) as f32
+    }
 }
 
 pub struct SharedNodeOptions {
@@ -77,10 +112,51 @@ pub struct SharedNodeOptions {
 }
 
 impl SharedNodeOptions {
-    fn new(s1_options: Vec<MoveChoice>, s2_options: Vec<MoveChoice>) -> Self {
+    fn new(
+        state: &State,
+        s1_options: Vec<MoveChoice>,
+        s2_options: Vec<MoveChoice>,
+        config: &MctsConfig,
+    ) -> Self {
+        let s1_priors = config
+            .selection
+            .priors(state, &SideReference::SideOne, &s1_options);
+        let s2_priors = config
+            .selection
+            .priors(state, &SideReference::SideTwo, &s2_options);
         Self {
-            s1: s1_options.into_iter().map(MoveNode::new).collect(),
-            s2: s2_options.into_iter().map(MoveNode::new).collect(),
+            s1: s1_options
+                .into_iter()
+                .zip(s1_priors)
+                .map(|(m, prior)| MoveNode::new(m, prior))
+                .collect(),
+            s2: s2_options
+                .into_iter()
+                .zip(s2_priors)
+                .map(|(m, prior)| MoveNode::new(m, prior))
+                .collect(),
+        }
+    }
+}
+
+// adds a result for the move at `chosen`, which was picked with `probability`
+fn add_result_for_side(
+    side_options: &[MoveNode],
+    chosen: usize,
+    probability: f32,
+    score: f32,
+    config: &MctsConfig,
+) {
+    side_options[chosen].add_result(score);
+    side_options[chosen].remove_virtual_loss(config);
+    if config.selection.is_sampled() {
+        for (index, node) in side_options.iter().enumerate() {
+            node.add_cumulative(config.selection.cumulative_update(
+                index,
+                chosen,
+                score,
+                probability,
+            ));
         }
     }
 }
@@ -111,36 +187,41 @@ struct PathStep {
     child: *const Node,
     s1_index: usize,
     s2_index: usize,
+    s1_probability: f32,
+    s2_probability: f32,
 }
 
 pub struct Node {
-    root: bool,
     instructions: StateInstructions,
-    depth: u8,
+    // counted from the first root, so it keeps growing when a tree is advanced
+    depth: u16,
     times_visited: AtomicU32,
     virtual_losses: AtomicI8,
     options: OnceLock<SharedNodeOptions>,
 }
 
 impl Node {
-    fn new_root(s1_options: Vec<MoveChoice>, s2_options: Vec<MoveChoice>) -> Arc<Self> {
-        let node = Arc::new(Self {
-            root: true,
+    fn new_root(
+        state: &State,
+        s1_options: Vec<MoveChoice>,
+        s2_options: Vec<MoveChoice>,
+        config: &MctsConfig,
+    ) -> Self {
+        let node = Self {
             instructions: StateInstructions::default(),
             depth: 0,
             times_visited: AtomicU32::new(0),
             virtual_losses: AtomicI8::new(0),
             options: OnceLock::new(),
-        });
-        let _ = node
-            .options
-            .set(SharedNodeOptions::new(s1_options, s2_options));
+        };
+        let _ = node.options.set(SharedNodeOptions::new(
+            state, s1_options, s2_options, config,
+        ));
         node
     }
 
-    fn new_child(instructions: StateInstructions, depth: u8) -> Self {
+    fn new_child(instructions: StateInstructions, depth: u16) -> Self {
         Self {
-            root: false,
             instructions,
             depth,
             times_visited: AtomicU32::new(0),
@@ -153,41 +234,75 @@ impl Node {
         self as *const Node as usize
     }
 
-    fn ensure_options(&self, state: &State) -> &SharedNodeOptions {
+    fn ensure_options(&self, state: &State, config: &MctsConfig) -> &SharedNodeOptions {
         self.options.get_or_init(|| {
             let (s1, s2) = state.get_all_options();
-            SharedNodeOptions::new(s1, s2)
+            SharedNodeOptions::new(state, s1, s2, config)
         })
     }
 
-    fn select_move_pair(&self, state: &State) -> (usize, usize) {
-        let options = self.ensure_options(state);
+    // the index of each side's move and the probability it was picked with
+    fn select_move_pair<R: Rng + ?Sized>(
+        &self,
+        state: &State,
+        rng: &mut R,
+        config: &MctsConfig,
+    ) -> ((usize, f32), (usize, f32)) {
+        let options = self.ensure_options(state, config);
         let parent_visits = self
             .times_visited
             .load(Ordering::Acquire)
             .saturating_add(self.virtual_losses.load(Ordering::Acquire).max(0) as u32)
             .max(1);
         (
-            self.maximize_ucb_for_side(&options.s1, parent_visits),
-            self.maximize_ucb_for_side(&options.s2, parent_visits),
+            self.select_for_side(&options.s1, parent_visits, rng, config),
+            self.select_for_side(&options.s2, parent_visits, rng, config),
         )
     }
 
+    fn select_for_side<R: Rng + ?Sized>(
+        &self,
+        side_options: &[MoveNode],
+        parent_visits: u32,
+        rng: &mut R,
+        config: &MctsConfig,
+    ) -> (usize, f32) {
+        match config.selection {
+            SelectionPolicy::Ucb1 { exploration } => (
+                self.maximize_for_side(side_options, |m| m.ucb1(parent_visits, exploration)),
+                1.0,
+            ),
+            SelectionPolicy::Puct { exploration, .. } => (
+                self.maximize_for_side(side_options, |m| m.puct(parent_visits, exploration)),
+                1.0,
+            ),
+            SelectionPolicy::Exp3 { .. } | SelectionPolicy::RegretMatching { .. } => {
+                let cumulative: Vec<f32> =
+                    side_options.iter().map(|m| m.cumulative_f32()).collect();
+                let probabilities = config.selection.probabilities(&cumulative);
+                let choice = sample_probabilities(&probabilities, rng);
+                (choice, probabilities[choice])
+            }
+        }
+    }
+
     fn selection<R: Rng + ?Sized>(
-        root: &Arc<Node>,
+        root: &Node,
         state: &mut State,
         rng: &mut R,
         children: &ChildMap,
         path: &mut Vec<PathStep>,
-    ) -> (*const Node, usize, usize) {
-        // raw pointers walk both the root (a standalone Arc<Node>) and children
-        // (Nodes living inside a branch's Arc<[Node]>) uniformly. every node is
-        // owned by children/root for the whole search, so the pointers stay
-        // valid
-        let mut current: *const Node = Arc::as_ptr(root);
+        config: &MctsConfig,
+    ) -> (*const Node, (usize, f32), (usize, f32)) {
+        // raw pointers walk the nodes living inside each branch's Arc<[Node]>.
+        // every node is owned by children or the tree's root branch for the
+        // whole search, so the pointers stay valid
+        let mut current: *const Node = root;
         loop {
             let node = unsafe { &*current };
-            let (s1_index, s2_index) = node.select_move_pair(state);
+            let (s1_selection, s2_selection) = node.select_move_pair(state, rng, config);
+            let (s1_index, s1_probability) = s1_selection;
+            let (s2_index, s2_probability) = s2_selection;
             let options = node.options.get().expect("options set during selection");
 
             let key = (node.as_key(), s1_index, s2_index);
@@ -202,8 +317,8 @@ impl Node {
                     drop(branch);
 
                     let child_ref = unsafe { &*child };
-                    options.s1[s1_index].add_virtual_loss();
-                    options.s2[s2_index].add_virtual_loss();
+                    options.s1[s1_index].add_virtual_loss(config);
+                    options.s2[s2_index].add_virtual_loss(config);
                     child_ref.virtual_losses.fetch_add(1, Ordering::AcqRel);
                     state.apply_instructions(&child_ref.instructions.instruction_list);
                     path.push(PathStep {
@@ -211,24 +326,30 @@ impl Node {
                         child,
                         s1_index,
                         s2_index,
+                        s1_probability,
+                        s2_probability,
                     });
                     current = child;
                 }
                 None => {
                     // this is the leaf, stop selection
-                    return (current, s1_index, s2_index);
+                    return (current, s1_selection, s2_selection);
                 }
             }
         }
     }
 
-    fn maximize_ucb_for_side(&self, side_options: &[MoveNode], parent_visits: u32) -> usize {
+    fn maximize_for_side(
+        &self,
+        side_options: &[MoveNode],
+        value: impl Fn(&MoveNode) -> f32,
+    ) -> usize {
         side_options
             .iter()
             .enumerate()
             .max_by(|(_, a), (_, b)| {
-                a.ucb1(parent_visits)
-                    .partial_cmp(&b.ucb1(parent_visits))
+                value(a)
+                    .partial_cmp(&value(b))
                     .unwrap_or(std::cmp::Ordering::Equal)
             })
             .map(|(i, _)| i)
@@ -240,12 +361,13 @@ impl Node {
     /// `None` when the node should not be expanded (battle over, both-None).
     fn expand<R: Rng + ?Sized>(
         &self,
+        search: &SearchContext,
         state: &mut State,
         s1_index: usize,
         s2_index: usize,
         rng: &mut R,
-        children: &ChildMap,
     ) -> Option<*const Node> {
+        let config = search.config;
         let options = self
             .options
             .get()
@@ -253,15 +375,16 @@ impl Node {
         let s1_move = &options.s1[s1_index].move_choice;
         let s2_move = &options.s2[s2_index].move_choice;
 
-        if (state.battle_is_over() != 0.0 && !self.root)
+        if (state.battle_is_over() != 0.0 && !std::ptr::eq(self, search.root))
             || (s1_move == &MoveChoice::None && s2_move == &MoveChoice::None)
         {
             return None;
         }
 
-        let should_branch_on_damage = self.depth < MCTS_DAMAGE_BRANCH_DEPTH;
-        let instructions =
+        let should_branch_on_damage = self.depth - search.root.depth < config.damage_branch_depth;
+        let mut instructions =
             generate_instructions_from_move_pair(state, s1_move, s2_move, should_branch_on_damage);
+        config.pruning.prune(&mut instructions);
 
         let mut total_weight = 0.0f32;
         let nodes = instructions
@@ -279,15 +402,21 @@ impl Node {
         let key = (self.as_key(), s1_index, s2_index);
         // entry() on DashMap is atomic per-shard: only one thread will
         // construct the branch; all others get the winner's branch.
-        let branch_ref = children.entry(key).or_insert(branch);
+        let branch_ref = search.children.entry(key).or_insert(branch);
 
         Some(branch_ref.sample(rng))
     }
 
-    fn rollout(&self, state: &State, root_eval: f32) -> f32 {
+    fn rollout(
+        &self,
+        state: &State,
+        root_eval: f32,
+        evaluator: &dyn Evaluator,
+        config: &MctsConfig,
+    ) -> f32 {
         let battle_is_over = state.battle_is_over();
         if battle_is_over == 0.0 {
-            sigmoid(evaluate(state) - root_eval)
+            config.sigmoid(evaluator.evaluate(state) - root_eval)
         } else if battle_is_over == -1.0 {
             0.0
         } else {
@@ -298,16 +427,32 @@ impl Node {
     // walk `path` in reverse, updating visit counts and scores,
     // removes virtual losses, and reverse-applying instructions to restore `state` to how it
     // was in the root
-    fn backpropagate(path: &[PathStep], leaf: &Node, score: f32, state: &mut State) {
+    fn backpropagate(
+        path: &[PathStep],
+        leaf: &Node,
+        score: f32,
+        state: &mut State,
+        config: &MctsConfig,
+    ) {
         leaf.times_visited.fetch_add(1, Ordering::AcqRel);
 
         for step in path.iter().rev() {
             let (parent, child) = unsafe { (&*step.parent, &*step.child) };
             let options = parent.options.get().expect("path parent has options");
-            options.s1[step.s1_index].add_result(score);
-            options.s1[step.s1_index].remove_virtual_loss();
-            options.s2[step.s2_index].add_result(1.0 - score);
-            options.s2[step.s2_index].remove_virtual_loss();
+            add_result_for_side(
+                &options.s1,
+                step.s1_index,
+                step.s1_probability,
+                score,
+                config,
+            );
+            add_result_for_side(
+                &options.s2,
+                step.s2_index,
+                step.s2_probability,
+                1.0 - score,
+                config,
+            );
             parent.times_visited.fetch_add(1, Ordering::AcqRel);
             child.virtual_losses.fetch_sub(1, Ordering::AcqRel);
             state.reverse_instructions(&child.instructions.instruction_list);
@@ -315,23 +460,33 @@ impl Node {
     }
 }
 
+// what every worker of one search shares
+struct SearchContext<'a> {
+    root: &'a Node,
+    root_eval: f32,
+    children: &'a ChildMap,
+    evaluator: &'a dyn Evaluator,
+    config: &'a MctsConfig,
+    cancellation: &'a CancellationToken,
+}
+
 fn mcts_iteration<R: Rng + ?Sized>(
-    root: &Arc<Node>,
+    search: &SearchContext,
     state: &mut State,
-    root_eval: f32,
     rng: &mut R,
-    children: &ChildMap,
     path: &mut Vec<PathStep>,
 ) {
+    let config = search.config;
     path.clear();
 
-    let (leaf, s1_index, s2_index) = Node::selection(root, state, rng, children, path);
+    let (leaf, (s1_index, s1_probability), (s2_index, s2_probability)) =
+        Node::selection(search.root, state, rng, search.children, path, config);
     let leaf = unsafe { &*leaf };
 
     let options = leaf.options.get().expect("options set during selection");
-    options.s1[s1_index].add_virtual_loss();
-    options.s2[s2_index].add_virtual_loss();
-    let expanded = leaf.expand(state, s1_index, s2_index, rng, children);
+    options.s1[s1_index].add_virtual_loss(config);
+    options.s2[s2_index].add_virtual_loss(config);
+    let expanded = leaf.expand(search, state, s1_index, s2_index, rng);
     match expanded {
         Some(child) => {
             let child = unsafe { &*child };
@@ -342,11 +497,13 @@ fn mcts_iteration<R: Rng + ?Sized>(
                 child,
                 s1_index,
                 s2_index,
+                s1_probability,
+                s2_probability,
             });
 
-            let score = child.rollout(state, root_eval);
+            let score = child.rollout(state, search.root_eval, search.evaluator, config);
 
-            Node::backpropagate(path, child, score, state);
+            Node::backpropagate(path, child, score, state, config);
         }
 
         // if expansion returns None,
@@ -355,55 +512,48 @@ fn mcts_iteration<R: Rng + ?Sized>(
         // we do a rollout on the leaf and backpropagate without adding a child to the tree
         None => {
             // remove the virtual loss we added before expansion, since we're not actually expanding
-            options.s1[s1_index].remove_virtual_loss();
-            options.s2[s2_index].remove_virtual_loss();
+            options.s1[s1_index].remove_virtual_loss(config);
+            options.s2[s2_index].remove_virtual_loss(config);
 
-            let score = leaf.rollout(state, root_eval);
+            let score = leaf.rollout(state, search.root_eval, search.evaluator, config);
 
-            Node::backpropagate(path, leaf, score, state);
+            Node::backpropagate(path, leaf, score, state, config);
         }
     }
 }
 
-enum SearchLimit {
-    Time,
+// when a worker stops, with the time limit turned into the instant it runs out
+#[derive(Clone, Copy)]
+enum WorkerLimit {
+    Deadline(Instant),
     Iterations(u32),
 }
 
 fn run_mcts_loop(
-    root: &Arc<Node>,
-    root_eval: f32,
-    children: Arc<ChildMap>,
+    search: &SearchContext,
     worker_state: &mut State,
     started_iterations: Arc<AtomicU32>,
-    deadline: Instant,
-    search_limit: SearchLimit,
+    limit: WorkerLimit,
+    mut rng: StdRng,
 ) {
-    let mut rng = rng();
     let mut path = Vec::with_capacity(16);
     let mut current_iterations = started_iterations.load(Ordering::Acquire);
     loop {
         for _ in 0..1000 {
-            mcts_iteration(
-                &root,
-                worker_state,
-                root_eval,
-                &mut rng,
-                &children,
-                &mut path,
-            );
+            mcts_iteration(search, worker_state, &mut rng, &mut path);
             current_iterations = started_iterations.fetch_add(1, Ordering::AcqRel);
         }
-        if current_iterations >= MCTS_MAX_ITERATIONS_PER_TREE {
+        if current_iterations >= MCTS_MAX_ITERATIONS_PER_TREE || search.cancellation.is_cancelled()
+        {
             break;
         }
-        match search_limit {
-            SearchLimit::Time => {
+        match limit {
+            WorkerLimit::Deadline(deadline) => {
                 if Instant::now() >= deadline {
                     break;
                 }
             }
-            SearchLimit::Iterations(max_iterations) => {
+            WorkerLimit::Iterations(max_iterations) => {
                 if current_iterations >= max_iterations {
                     break;
                 }
@@ -412,6 +562,242 @@ fn run_mcts_loop(
     }
 }
 
+// while the search is running the visits include the virtual losses of unfinished iterations
+fn root_result(root: &Node, config: &MctsConfig) -> MctsResult {
+    let options = root.options.get().expect("root options initialized");
+    let side_results = |move_nodes: &[MoveNode]| {
+        let mut side_results: Vec<MctsSideResult> = move_nodes
+            .iter()
+            .map(|v| MctsSideResult {
+                move_choice: v.move_choice,
+                total_score: v.total_score_f32(),
+                visits: v.visits.load(Ordering::Acquire),
+                policy: 0.0,
+            })
+            .collect();
+        set_policies(&mut side_results, config);
+        side_results
+    };
+    MctsResult {
+        s1: side_results(&options.s1),
+        s2: side_results(&options.s2),
+        iteration_count: root.times_visited.load(Ordering::Acquire),
+    }
+}
+
+/// A search tree shared by several threads that can be kept from one turn to the next.
+///
+/// After a turn is played, `advance` keeps the subtree of the outcome that happened
+/// so the next `search` starts with the visits that were already spent on it
+pub struct SharedMctsTree {
+    // the root is kept in the branch it was expanded in so that its address,
+    // which the children map is keyed on, does not change when the tree is advanced
+    root_branch: Arc<[Node]>,
+    root_index: usize,
+    children: ChildMap,
+    config: MctsConfig,
+}
+
+impl Default for SharedMctsTree {
+    fn default() -> SharedMctsTree {
+        SharedMctsTree::new()
+    }
+}
+
+impl SharedMctsTree {
+    pub fn new() -> SharedMctsTree {
+        SharedMctsTree::with_config(MctsConfig::default())
+    }
+
+    pub fn with_config(config: MctsConfig) -> SharedMctsTree {
+        SharedMctsTree {
+            root_branch: Arc::from(vec![Node::new_child(StateInstructions::default(), 0)]),
+            root_index: 0,
+            children: DashMap::new(),
+            config,
+        }
+    }
+
+    fn root_options_match(
+        &self,
+        side_one_options: &[MoveChoice],
+        side_two_options: &[MoveChoice],
+    ) -> bool {
+        match self.root_branch[self.root_index].options.get() {
+            Some(options) => {
+                options
+                    .s1
+                    .iter()
+                    .map(|m| m.move_choice)
+                    .eq(side_one_options.iter().copied())
+                    && options
+                        .s2
+                        .iter()
+                        .map(|m| m.move_choice)
+                        .eq(side_two_options.iter().copied())
+            }
+            None => false,
+        }
+    }
+
+    /// Searches from `state`, which must be the state the tree is rooted at.
+    ///
+    /// The kept subtree is only used if its options are the same as the options given,
+    /// otherwise the search starts over. `max_iterations` counts only the iterations
+    /// run by this call, but the result includes the visits kept from earlier searches
+    pub fn search(
+        &mut self,
+        state: &mut State,
+        side_one_options: Vec<MoveChoice>,
+        side_two_options: Vec<MoveChoice>,
+        max_time: Duration,
+        max_iterations: u32,
+        evaluator: &dyn Evaluator,
+    ) -> MctsResult {
+        self.search_with_control(
+            state,
+            side_one_options,
+            side_two_options,
+            SearchLimit::new(max_time, max_iterations),
+            evaluator,
+            &SearchControl::default(),
+        )
+    }
+
+    /// `search` that also stops when `control` is cancelled and reports its progress.
+    /// Progress is reported from the calling thread while the workers search
+    pub fn search_with_control(
+        &mut self,
+        state: &mut State,
+        side_one_options: Vec<MoveChoice>,
+        side_two_options: Vec<MoveChoice>,
+        limit: SearchLimit,
+        evaluator: &dyn Evaluator,
+        control: &SearchControl,
+    ) -> MctsResult {
+        if !self.root_options_match(&side_one_options, &side_two_options) {
+            self.root_branch = Arc::from(vec![Node::new_root(
+                state,
+                side_one_options,
+                side_two_options,
+                &self.config,
+            )]);
+            self.root_index = 0;
+            self.children = DashMap::with_capacity(1 << 16);
+        }
+
+        let worker_limit = match limit {
+            SearchLimit::Time(max_time) => WorkerLimit::Deadline(Instant::now() + max_time),
+            SearchLimit::Iterations(max_iterations) => WorkerLimit::Iterations(max_iterations),
+        };
+        let root = &self.root_branch[self.root_index];
+        let config = &self.config;
+        let search = SearchContext {
+            root,
+            root_eval: evaluator.evaluate(state),
+            children: &self.children,
+            evaluator,
+            config,
+            cancellation: &control.cancellation,
+        };
+        let started_iterations = Arc::new(AtomicU32::new(0));
+
+        thread::scope(|scope| {
+            let mut workers = Vec::with_capacity(config.threads);
+            for worker in 0..config.threads {
+                let rng = config.rng(worker as u64);
+                let started_iterations = started_iterations.clone();
+                let mut worker_state = state.clone();
+                let search = &search;
+                workers.push(scope.spawn(move || {
+                    run_mcts_loop(
+                        search,
+                        &mut worker_state,
+                        started_iterations,
+                        worker_limit,
+                        rng,
+                    );
+                }));
+            }
+
+            if control.on_progress.is_some() {
+                let mut last_report = Instant::now();
+                while !workers.iter().all(|worker| worker.is_finished()) {
+                    thread::sleep(PROGRESS_POLL_INTERVAL);
+                    if last_report.elapsed() >= control.progress_interval {
+                        report_mcts_progress(control, &root_result(root, config));
+                        last_report = Instant::now();
+                    }
+                }
+            }
+        });
+
+        root_result(root, &self.config)
+    }
+
+    /// Re-roots the tree at the node reached when side one used `s1_choice`, side two used
+    /// `s2_choice` and `instructions` were applied, dropping every other part of the tree.
+    ///
+    /// Returns false and clears the tree if that node was never expanded
+    pub fn advance(
+        &mut self,
+        s1_choice: &MoveChoice,
+        s2_choice: &MoveChoice,
+        instructions: &[Instruction],
+    ) -> bool {
+        let root = &self.root_branch[self.root_index];
+        let key = root.options.get().and_then(|options| {
+            match (
+                options.s1.iter().position(|m| &m.move_choice == s1_choice),
+                options.s2.iter().position(|m| &m.move_choice == s2_choice),
+            ) {
+                (Some(s1_index), Some(s2_index)) => Some((root.as_key(), s1_index, s2_index)),
+                _ => None,
+            }
+        });
+        let branch = match key.and_then(|k| self.children.remove(&k)) {
+            Some((_, branch)) => branch,
+            None => {
+                *self = SharedMctsTree::with_config(self.config);
+                return false;
+            }
+        };
+        let root_index = match branch
+            .nodes
+            .iter()
+            .position(|n| n.instructions.instruction_list == instructions)
+        {
+            Some(index) => index,
+            None => {
+                *self = SharedMctsTree::with_config(self.config);
+                return false;
+            }
+        };
+
+        // keep only the branches that hang off of the new root. the rest are dropped
+        // so their addresses cannot be reused by new nodes while still being keys
+        let mut keys_by_parent: HashMap<usize, Vec<(usize, usize, usize)>> = HashMap::new();
+        for entry in self.children.iter() {
+            let key = *entry.key();
+            keys_by_parent.entry(key.0).or_default().push(key);
+        }
+        let kept_children = DashMap::with_capacity(self.children.len());
+        let mut to_visit = vec![branch.nodes[root_index].as_key()];
+        while let Some(parent) = to_visit.pop() {
+            for key in keys_by_parent.remove(&parent).unwrap_or_default() {
+                let (_, child_branch) = self.children.remove(&key).unwrap();
+                to_visit.extend(child_branch.nodes.iter().map(|n| n.as_key()));
+                kept_children.insert(key, child_branch);
+            }
+        }
+
+        self.root_branch = branch.nodes;
+        self.root_index = root_index;
+        self.children = kept_children;
+        true
+    }
+}
+
 pub fn perform_mcts_shared_tree(
     state: &mut State,
     side_one_options: Vec<MoveChoice>,
@@ -419,60 +805,167 @@ pub fn perform_mcts_shared_tree(
     max_time: Duration,
     max_iterations: u32,
     worker_count: usize,
+    evaluator: &dyn Evaluator,
 ) -> MctsResult {
-    let root_eval = evaluate(state);
-    let deadline = Instant::now() + max_time;
-    let root = Node::new_root(side_one_options, side_two_options);
-    let started_iterations = Arc::new(AtomicU32::new(0));
-
-    // global map shared by all threads.
-    let children: Arc<ChildMap> = Arc::new(DashMap::with_capacity(1 << 16));
-
-    thread::scope(|scope| {
-        for _ in 0..worker_count {
-            let root = root.clone();
-            let started_iterations = started_iterations.clone();
-            let children = children.clone();
-            let mut worker_state = state.clone();
-            let search_limit = if max_iterations > 0 {
-                SearchLimit::Iterations(max_iterations)
-            } else {
-                SearchLimit::Time
+    SharedMctsTree::with_config(MctsConfig {
+        threads: worker_count,
+        ..MctsConfig::default()
+    })
+    .search(
+        state,
+        side_one_options,
+        side_two_options,
+        max_time,
+        max_iterations,
+        evaluator,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::DefaultEvaluator;
+
+    #[test]
+    fn test_sampled_selection_policies_search_with_several_threads() {
+        for selection in [
+            SelectionPolicy::Exp3 { exploration: 0.2 },
+            SelectionPolicy::RegretMatching { exploration: 0.2 },
+        ] {
+            let mut state = State::default();
+            let (s1_options, s2_options) = state.root_get_all_options();
+            let config = MctsConfig {
+                selection,
+                virtual_loss_visits: 1,
+                threads: 2,
+                ..Default::default()
             };
-            scope.spawn(move || {
-                run_mcts_loop(
-                    &root,
-                    root_eval,
-                    children,
-                    &mut worker_state,
-                    started_iterations,
-                    deadline,
-                    search_limit,
-                );
-            });
+            let result = SharedMctsTree::with_config(config).search(
+                &mut state,
+                s1_options,
+                s2_options,
+                Duration::from_millis(0),
+                1000,
+                &DefaultEvaluator,
+            );
+
+            // every virtual loss was removed
+            let s1_visits = result.s1.iter().map(|r| r.visits).sum::<u32>();
+            assert_eq!(result.iteration_count, s1_visits);
         }
-    });
+    }
 
-    let options = root.options.get().expect("root options initialized");
-    MctsResult {
-        s1: options
-            .s1
+    #[test]
+    fn test_advancing_the_tree_keeps_the_visits_of_the_outcome_that_happened() {
+        let mut state = State::default();
+        let (s1_options, s2_options) = state.root_get_all_options();
+        let mut tree = SharedMctsTree::with_config(MctsConfig {
+            threads: 2,
+            ..Default::default()
+        });
+        tree.search(
+            &mut state,
+            s1_options,
+            s2_options,
+            Duration::from_millis(0),
+            1000,
+            &DefaultEvaluator,
+        );
+
+        // the most visited outcome of any move pair from the root
+        let root = &tree.root_branch[tree.root_index];
+        let (key, node_index) = tree
+            .children
             .iter()
-            .map(|v| MctsSideResult {
-                move_choice: v.move_choice,
-                total_score: v.total_score_f32(),
-                visits: v.visits.load(Ordering::Acquire),
+            .filter(|entry| entry.key().0 == root.as_key())
+            .flat_map(|entry| {
+                let key = *entry.key();
+                entry
+                    .nodes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, node)| (key, i, node.times_visited.load(Ordering::Acquire)))
+                    .collect::<Vec<_>>()
             })
-            .collect(),
-        s2: options
-            .s2
-            .iter()
-            .map(|v| MctsSideResult {
-                move_choice: v.move_choice,
-                total_score: v.total_score_f32(),
-                visits: v.visits.load(Ordering::Acquire),
-            })
-            .collect(),
-        iteration_count: root.times_visited.load(Ordering::Acquire),
+            .max_by_key(|(_, _, visits)| *visits)
+            .map(|(key, i, _)| (key, i))
+            .unwrap();
+        let options = root.options.get().unwrap();
+        let s1_choice = options.s1[key.1].move_choice;
+        let s2_choice = options.s2[key.2].move_choice;
+        let (instructions, kept_visits) = {
+            let branch = tree.children.get(&key).unwrap();
+            let node = &branch.nodes[node_index];
+            (
+                node.instructions.instruction_list.clone(),
+                node.times_visited.load(Ordering::Acquire),
+            )
+        };
+        assert!(kept_visits > 0);
+
+        assert!(tree.advance(&s1_choice, &s2_choice, &instructions));
+        let new_root = &tree.root_branch[tree.root_index];
+        assert_eq!(kept_visits, new_root.times_visited.load(Ordering::Acquire));
+
+        state.apply_instructions(&instructions);
+        let (s1_options, s2_options) = state.root_get_all_options();
+        let result = tree.search(
+            &mut state,
+            s1_options,
+            s2_options,
+            Duration::from_millis(0),
+            1000,
+            &DefaultEvaluator,
+        );
+        assert!(result.iteration_count > kept_visits + 1000);
+    }
+
+    #[test]
+    fn test_a_seeded_worker_searches_the_same_way_every_time() {
+        let search = || {
+            let mut state = State::default();
+            let (s1_options, s2_options) = state.root_get_all_options();
+            let config = MctsConfig {
+                seed: Some(7),
+                ..Default::default()
+            };
+            SharedMctsTree::with_config(config).search(
+                &mut state,
+                s1_options,
+                s2_options,
+                Duration::from_millis(0),
+                2000,
+                &DefaultEvaluator,
+            )
+        };
+        let first = search();
+        let second = search();
+
+        for (a, b) in first.s1.iter().zip(second.s1.iter()) {
+            assert_eq!(a.visits, b.visits);
+            assert_eq!(a.total_score.to_bits(), b.total_score.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_cancelled_workers_stop_searching() {
+        let mut state = State::default();
+        let (s1_options, s2_options) = state.root_get_all_options();
+        let control = SearchControl::default();
+        control.cancellation.cancel();
+        let config = MctsConfig {
+            threads: 2,
+            ..Default::default()
+        };
+
+        let result = SharedMctsTree::with_config(config).search_with_control(
+            &mut state,
+            s1_options,
+            s2_options,
+            SearchLimit::Time(Duration::from_secs(60)),
+            &DefaultEvaluator,
+            &control,
+        );
+        assert_eq!(2000, result.iteration_count);
     }
 }
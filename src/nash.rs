@@ -0,0 +1,253 @@
+// Solves the simultaneous-move sub-games produced by `expectiminimax_search`.
+//
+// The payoff matrix is the flattened `Vec<f32>` returned by the search where
+// side one picks a row and side two picks a column. Side one maximizes and
+// side two minimizes, so this is a two-player zero-sum matrix game which can be
+// solved exactly with a small linear program.
+
+const EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NashEquilibrium {
+    pub s1_strategy: Vec<f32>,
+    pub s2_strategy: Vec<f32>,
+    pub value: f32,
+}
+
+impl NashEquilibrium {
+    /// index of the side one move with the highest weight in the mixed strategy
+    pub fn s1_best_index(&self) -> usize {
+        best_index(&self.s1_strategy)
+    }
+
+    /// index of the side two move with the highest weight in the mixed strategy
+    pub fn s2_best_index(&self) -> usize {
+        best_index(&self.s2_strategy)
+    }
+}
+
+fn best_index(strategy: &[f32]) -> usize {
+    let mut best = 0;
+    for (index, weight) in strategy.iter().enumerate() {
+        if *weight > strategy[best] {
+            best = index;
+        }
+    }
+    best
+}
+
+// Pruned entries in the matrix are NaN and their real values are unknown. Filling them with
+// the smallest known score in their row leaves that row's worst case unchanged, so the
+// maximin value and choice are the same as with the full matrix. The mixed strategy is only
+// an approximation: searches that value sub-games with Nash do not prune them
+fn fill_pruned_entries(score_lookup: &[f32], num_s1_moves: usize, num_s2_moves: usize) -> Vec<f64> {
+    let mut matrix = Vec::with_capacity(num_s1_moves * num_s2_moves);
+    for s1_index in 0..num_s1_moves {
+        let row = &score_lookup[s1_index * num_s2_moves..(s1_index + 1) * num_s2_moves];
+        let row_min = row
+            .iter()
+            .filter(|x| !x.is_nan())
+            .fold(f32::MAX, |acc, x| acc.min(*x));
+        let row_min = if row_min == f32::MAX { 0.0 } else { row_min };
+        for score in row {
+            if score.is_nan() {
+                matrix.push(row_min as f64);
+            } else {
+                matrix.push(*score as f64);
+            }
+        }
+    }
+    matrix
+}
+
+/// Solves the zero-sum game described by `score_lookup` (row-major, side one on the rows)
+/// and returns the mixed strategy of both sides along with the value of the game.
+///
+/// Uses the standard reduction to a linear program: after shifting every payoff to be
+/// positive, side two's optimal strategy maximizes `sum(y)` subject to `A * y <= 1`,
+/// and side one's strategy is read from the dual values of the final simplex tableau.
+pub fn solve_nash_equilibrium(
+    score_lookup: &[f32],
+    num_s1_moves: usize,
+    num_s2_moves: usize,
+) -> NashEquilibrium {
+    if num_s1_moves == 0 || num_s2_moves == 0 {
+        return NashEquilibrium {
+            s1_strategy: vec![1.0; num_s1_moves],
+            s2_strategy: vec![1.0; num_s2_moves],
+            value: 0.0,
+        };
+    }
+
+    let matrix = fill_pruned_entries(score_lookup, num_s1_moves, num_s2_moves);
+    let min_score = matrix.iter().fold(f64::MAX, |acc, x| acc.min(*x));
+    let shift = 1.0 - min_score;
+
+    // tableau layout:
+    //   columns 0..n are side two's variables, n..n+m are the slack variables,
+    //   the last column is the right-hand side
+    //   rows 0..m are the constraints, row m is the objective
+    let m = num_s1_moves;
+    let n = num_s2_moves;
+    let width = n + m + 1;
+    let mut tableau = vec![0.0f64; (m + 1) * width];
+    for i in 0..m {
+        for j in 0..n {
+            tableau[i * width + j] = matrix[i * n + j] + shift;
+        }
+        tableau[i * width + n + i] = 1.0;
+        tableau[i * width + width - 1] = 1.0;
+    }
+    for j in 0..n {
+        tableau[m * width + j] = -1.0;
+    }
+    let mut basis: Vec<usize> = (n..n + m).collect();
+
+    // Bland's rule for both the entering and leaving variable guarantees termination
+    while let Some(entering) = (0..n + m).find(|&j| tableau[m * width + j] < -EPSILON) {
+        let mut leaving: Option<usize> = None;
+        let mut best_ratio = f64::MAX;
+        for i in 0..m {
+            let coefficient = tableau[i * width + entering];
+            if coefficient > EPSILON {
+                let ratio = tableau[i * width + width - 1] / coefficient;
+                let is_better = match leaving {
+                    None => true,
+                    Some(l) => {
+                        ratio < best_ratio - EPSILON
+                            || (ratio < best_ratio + EPSILON && basis[i] < basis[l])
+                    }
+                };
+                if is_better {
+                    best_ratio = ratio;
+                    leaving = Some(i);
+                }
+            }
+        }
+
+        // every payoff is positive so the program is bounded and a leaving row always exists
+        let pivot_row = match leaving {
+            Some(i) => i,
+            None => break,
+        };
+
+        let pivot = tableau[pivot_row * width + entering];
+        for k in 0..width {
+            tableau[pivot_row * width + k] /= pivot;
+        }
+        for i in 0..=m {
+            if i == pivot_row {
+                continue;
+            }
+            let factor = tableau[i * width + entering];
+            if factor.abs() > EPSILON {
+                for k in 0..width {
+                    tableau[i * width + k] -= factor * tableau[pivot_row * width + k];
+                }
+            }
+        }
+        basis[pivot_row] = entering;
+    }
+
+    let objective = tableau[m * width + width - 1];
+    let shifted_value = 1.0 / objective;
+
+    let mut s2_strategy = vec![0.0f32; n];
+    for (row, basic_variable) in basis.iter().enumerate() {
+        if *basic_variable < n {
+            s2_strategy[*basic_variable] =
+                (tableau[row * width + width - 1] * shifted_value) as f32;
+        }
+    }
+    let s1_strategy: Vec<f32> = (0..m)
+        .map(|i| (tableau[m * width + n + i] * shifted_value).max(0.0) as f32)
+        .collect();
+
+    NashEquilibrium {
+        s1_strategy: normalize(s1_strategy),
+        s2_strategy: normalize(s2_strategy),
+        value: (shifted_value - shift) as f32,
+    }
+}
+
+// removes floating point drift so that each strategy sums to exactly 1.0
+fn normalize(mut strategy: Vec<f32>) -> Vec<f32> {
+    let total: f32 = strategy.iter().sum();
+    if total > 0.0 {
+        for weight in strategy.iter_mut() {
+            *weight /= total;
+        }
+    } else {
+        let uniform = 1.0 / strategy.len() as f32;
+        for weight in strategy.iter_mut() {
+            *weight = uniform;
+        }
+    }
+    strategy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::solve_nash_equilibrium;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} is not close to {}", a, b);
+    }
+
+    #[test]
+    fn test_matching_pennies_is_an_even_mix() {
+        let result = solve_nash_equilibrium(&[1.0, -1.0, -1.0, 1.0], 2, 2);
+        assert_close(result.s1_strategy[0], 0.5);
+        assert_close(result.s1_strategy[1], 0.5);
+        assert_close(result.s2_strategy[0], 0.5);
+        assert_close(result.s2_strategy[1], 0.5);
+        assert_close(result.value, 0.0);
+    }
+
+    #[test]
+    fn test_rock_paper_scissors() {
+        let result =
+            solve_nash_equilibrium(&[0.0, -1.0, 1.0, 1.0, 0.0, -1.0, -1.0, 1.0, 0.0], 3, 3);
+        for weight in result.s1_strategy.iter().chain(result.s2_strategy.iter()) {
+            assert_close(*weight, 1.0 / 3.0);
+        }
+        assert_close(result.value, 0.0);
+    }
+
+    #[test]
+    fn test_saddle_point_gives_pure_strategies() {
+        // row 1 dominates row 0, column 0 is best for side two against it
+        let result = solve_nash_equilibrium(&[1.0, 2.0, 3.0, 4.0], 2, 2);
+        assert_close(result.s1_strategy[1], 1.0);
+        assert_close(result.s2_strategy[0], 1.0);
+        assert_close(result.value, 3.0);
+        assert_eq!(result.s1_best_index(), 1);
+        assert_eq!(result.s2_best_index(), 0);
+    }
+
+    #[test]
+    fn test_mixed_strategy_with_uneven_payoffs() {
+        // side one: p * 3 + (1 - p) * 0 == p * 0 + (1 - p) * 1  =>  p = 0.25, value = 0.75
+        let result = solve_nash_equilibrium(&[3.0, 0.0, 0.0, 1.0], 2, 2);
+        assert_close(result.s1_strategy[0], 0.25);
+        assert_close(result.s1_strategy[1], 0.75);
+        assert_close(result.s2_strategy[0], 0.25);
+        assert_close(result.s2_strategy[1], 0.75);
+        assert_close(result.value, 0.75);
+    }
+
+    #[test]
+    fn test_non_square_matrix() {
+        let result = solve_nash_equilibrium(&[10.0, -5.0, 2.0], 1, 3);
+        assert_close(result.s1_strategy[0], 1.0);
+        assert_close(result.s2_strategy[1], 1.0);
+        assert_close(result.value, -5.0);
+    }
+
+    #[test]
+    fn test_pruned_entries_do_not_make_a_row_attractive() {
+        let result = solve_nash_equilibrium(&[5.0, 5.0, -10.0, f32::NAN], 2, 2);
+        assert_close(result.s1_strategy[0], 1.0);
+        assert_close(result.value, 5.0);
+    }
+}
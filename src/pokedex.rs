@@ -0,0 +1,7437 @@
+use crate::gen1;
+use crate::pokemon::PokemonName;
+use crate::state::{Generation, PokemonType};
+
+/// Species data shared by every generation's engine.
+///
+/// `pokedex_entry` has the values from the most recent generation the species appeared in.
+/// `pokedex_entry_in` has the values as they were in a given generation, e.g. base stats from
+/// before they were raised in gen6 and gen7 or types from before the Fairy type existed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PokedexEntry {
+    pub base_stats: (i16, i16, i16, i16, i16, i16),
+    pub types: (PokemonType, PokemonType),
+    pub weight_kg: f32,
+}
+
+impl PokemonName {
+    pub fn pokedex_entry(&self) -> PokedexEntry {
+        match self {
+            PokemonName::NONE => PokedexEntry {
+                base_stats: (0, 0, 0, 0, 0, 0),
+                types: (PokemonType::TYPELESS, PokemonType::TYPELESS),
+                weight_kg: 0.0,
+            },
+            PokemonName::BULBASAUR => PokedexEntry {
+                base_stats: (45, 49, 49, 65, 65, 45),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 6.9,
+            },
+            PokemonName::IVYSAUR => PokedexEntry {
+                base_stats: (60, 62, 63, 80, 80, 60),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 13.0,
+            },
+            PokemonName::VENUSAUR => PokedexEntry {
+                base_stats: (80, 82, 83, 100, 100, 80),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 100.0,
+            },
+            PokemonName::VENUSAURMEGA => PokedexEntry {
+                base_stats: (80, 100, 123, 122, 120, 80),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 155.5,
+            },
+            PokemonName::VENUSAURGMAX => PokedexEntry {
+                base_stats: (80, 82, 83, 100, 100, 80),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 100.0,
+            },
+            PokemonName::CHARMANDER => PokedexEntry {
+                base_stats: (39, 52, 43, 60, 50, 65),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 8.5,
+            },
+            PokemonName::CHARMELEON => PokedexEntry {
+                base_stats: (58, 64, 58, 80, 65, 80),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 19.0,
+            },
+            PokemonName::CHARIZARD => PokedexEntry {
+                base_stats: (78, 84, 78, 109, 85, 100),
+                types: (PokemonType::FIRE, PokemonType::FLYING),
+                weight_kg: 90.5,
+            },
+            PokemonName::CHARIZARDMEGAX => PokedexEntry {
+                base_stats: (78, 130, 111, 130, 85, 100),
+                types: (PokemonType::FIRE, PokemonType::DRAGON),
+                weight_kg: 110.5,
+            },
+            PokemonName::CHARIZARDMEGAY => PokedexEntry {
+                base_stats: (78, 104, 78, 159, 115, 100),
+                types: (PokemonType::FIRE, PokemonType::FLYING),
+                weight_kg: 100.5,
+            },
+            PokemonName::CHARIZARDGMAX => PokedexEntry {
+                base_stats: (78, 84, 78, 109, 85, 100),
+                types: (PokemonType::FIRE, PokemonType::FLYING),
+                weight_kg: 90.5,
+            },
+            PokemonName::SQUIRTLE => PokedexEntry {
+                base_stats: (44, 48, 65, 50, 64, 43),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 9.0,
+            },
+            PokemonName::WARTORTLE => PokedexEntry {
+                base_stats: (59, 63, 80, 65, 80, 58),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 22.5,
+            },
+            PokemonName::BLASTOISE => PokedexEntry {
+                base_stats: (79, 83, 100, 85, 105, 78),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 85.5,
+            },
+            PokemonName::BLASTOISEMEGA => PokedexEntry {
+                base_stats: (79, 103, 120, 135, 115, 78),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 101.1,
+            },
+            PokemonName::BLASTOISEGMAX => PokedexEntry {
+                base_stats: (79, 83, 100, 85, 105, 78),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 85.5,
+            },
+            PokemonName::CATERPIE => PokedexEntry {
+                base_stats: (45, 30, 35, 20, 20, 45),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 2.9,
+            },
+            PokemonName::METAPOD => PokedexEntry {
+                base_stats: (50, 20, 55, 25, 25, 30),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 9.9,
+            },
+            PokemonName::BUTTERFREE => PokedexEntry {
+                base_stats: (60, 45, 50, 90, 80, 70),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 32.0,
+            },
+            PokemonName::BUTTERFREEGMAX => PokedexEntry {
+                base_stats: (60, 45, 50, 90, 80, 70),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 32.0,
+            },
+            PokemonName::WEEDLE => PokedexEntry {
+                base_stats: (40, 35, 30, 20, 20, 50),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 3.2,
+            },
+            PokemonName::KAKUNA => PokedexEntry {
+                base_stats: (45, 25, 50, 25, 25, 35),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 10.0,
+            },
+            PokemonName::BEEDRILL => PokedexEntry {
+                base_stats: (65, 90, 40, 45, 80, 75),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 29.5,
+            },
+            PokemonName::BEEDRILLMEGA => PokedexEntry {
+                base_stats: (65, 150, 40, 15, 80, 145),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 40.5,
+            },
+            PokemonName::PIDGEY => PokedexEntry {
+                base_stats: (40, 45, 40, 35, 35, 56),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 1.8,
+            },
+            PokemonName::PIDGEOTTO => PokedexEntry {
+                base_stats: (63, 60, 55, 50, 50, 71),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 30.0,
+            },
+            PokemonName::PIDGEOT => PokedexEntry {
+                base_stats: (83, 80, 75, 70, 70, 101),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 39.5,
+            },
+            PokemonName::PIDGEOTMEGA => PokedexEntry {
+                base_stats: (83, 80, 80, 135, 80, 121),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 50.5,
+            },
+            PokemonName::RATTATA => PokedexEntry {
+                base_stats: (30, 56, 35, 25, 35, 72),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 3.5,
+            },
+            PokemonName::RATTATAALOLA => PokedexEntry {
+                base_stats: (30, 56, 35, 25, 35, 72),
+                types: (PokemonType::DARK, PokemonType::NORMAL),
+                weight_kg: 3.8,
+            },
+            PokemonName::RATICATE => PokedexEntry {
+                base_stats: (55, 81, 60, 50, 70, 97),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 18.5,
+            },
+            PokemonName::RATICATEALOLA => PokedexEntry {
+                base_stats: (75, 71, 70, 40, 80, 77),
+                types: (PokemonType::DARK, PokemonType::NORMAL),
+                weight_kg: 25.5,
+            },
+            PokemonName::RATICATEALOLATOTEM => PokedexEntry {
+                base_stats: (75, 71, 70, 40, 80, 77),
+                types: (PokemonType::DARK, PokemonType::NORMAL),
+                weight_kg: 105.0,
+            },
+            PokemonName::SPEAROW => PokedexEntry {
+                base_stats: (40, 60, 30, 31, 31, 70),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 2.0,
+            },
+            PokemonName::FEAROW => PokedexEntry {
+                base_stats: (65, 90, 65, 61, 61, 100),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 38.0,
+            },
+            PokemonName::EKANS => PokedexEntry {
+                base_stats: (35, 60, 44, 40, 54, 55),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 6.9,
+            },
+            PokemonName::ARBOK => PokedexEntry {
+                base_stats: (60, 95, 69, 65, 79, 80),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 65.0,
+            },
+            PokemonName::PIKACHU => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUCOSPLAY => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUROCKSTAR => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUBELLE => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUPOPSTAR => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUPHD => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHULIBRE => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUORIGINAL => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUHOENN => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUSINNOH => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUUNOVA => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUKALOS => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUALOLA => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUPARTNER => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUSTARTER => PokedexEntry {
+                base_stats: (45, 80, 50, 75, 60, 120),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUGMAX => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::PIKACHUWORLD => PokedexEntry {
+                base_stats: (35, 55, 40, 50, 50, 90),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::RAICHU => PokedexEntry {
+                base_stats: (60, 90, 55, 90, 80, 110),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 30.0,
+            },
+            PokemonName::RAICHUALOLA => PokedexEntry {
+                base_stats: (60, 85, 50, 95, 85, 110),
+                types: (PokemonType::ELECTRIC, PokemonType::PSYCHIC),
+                weight_kg: 21.0,
+            },
+            PokemonName::SANDSHREW => PokedexEntry {
+                base_stats: (50, 75, 85, 20, 30, 40),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 12.0,
+            },
+            PokemonName::SANDSHREWALOLA => PokedexEntry {
+                base_stats: (50, 75, 90, 10, 35, 40),
+                types: (PokemonType::ICE, PokemonType::STEEL),
+                weight_kg: 40.0,
+            },
+            PokemonName::SANDSLASH => PokedexEntry {
+                base_stats: (75, 100, 110, 45, 55, 65),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 29.5,
+            },
+            PokemonName::SANDSLASHALOLA => PokedexEntry {
+                base_stats: (75, 100, 120, 25, 65, 65),
+                types: (PokemonType::ICE, PokemonType::STEEL),
+                weight_kg: 55.0,
+            },
+            PokemonName::NIDORANF => PokedexEntry {
+                base_stats: (55, 47, 52, 40, 40, 41),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 7.0,
+            },
+            PokemonName::NIDORINA => PokedexEntry {
+                base_stats: (70, 62, 67, 55, 55, 56),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 20.0,
+            },
+            PokemonName::NIDOQUEEN => PokedexEntry {
+                base_stats: (90, 92, 87, 75, 85, 76),
+                types: (PokemonType::POISON, PokemonType::GROUND),
+                weight_kg: 60.0,
+            },
+            PokemonName::NIDORANM => PokedexEntry {
+                base_stats: (46, 57, 40, 40, 40, 50),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 9.0,
+            },
+            PokemonName::NIDORINO => PokedexEntry {
+                base_stats: (61, 72, 57, 55, 55, 65),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 19.5,
+            },
+            PokemonName::NIDOKING => PokedexEntry {
+                base_stats: (81, 102, 77, 85, 75, 85),
+                types: (PokemonType::POISON, PokemonType::GROUND),
+                weight_kg: 62.0,
+            },
+            PokemonName::CLEFAIRY => PokedexEntry {
+                base_stats: (70, 45, 48, 60, 65, 35),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 7.5,
+            },
+            PokemonName::CLEFABLE => PokedexEntry {
+                base_stats: (95, 70, 73, 95, 90, 60),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 40.0,
+            },
+            PokemonName::VULPIX => PokedexEntry {
+                base_stats: (38, 41, 40, 50, 65, 65),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 9.9,
+            },
+            PokemonName::VULPIXALOLA => PokedexEntry {
+                base_stats: (38, 41, 40, 50, 65, 65),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 9.9,
+            },
+            PokemonName::NINETALES => PokedexEntry {
+                base_stats: (73, 76, 75, 81, 100, 100),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 19.9,
+            },
+            PokemonName::NINETALESALOLA => PokedexEntry {
+                base_stats: (73, 67, 75, 81, 100, 109),
+                types: (PokemonType::ICE, PokemonType::FAIRY),
+                weight_kg: 19.9,
+            },
+            PokemonName::JIGGLYPUFF => PokedexEntry {
+                base_stats: (115, 45, 20, 45, 25, 20),
+                types: (PokemonType::NORMAL, PokemonType::FAIRY),
+                weight_kg: 5.5,
+            },
+            PokemonName::WIGGLYTUFF => PokedexEntry {
+                base_stats: (140, 70, 45, 85, 50, 45),
+                types: (PokemonType::NORMAL, PokemonType::FAIRY),
+                weight_kg: 12.0,
+            },
+            PokemonName::ZUBAT => PokedexEntry {
+                base_stats: (40, 45, 35, 30, 40, 55),
+                types: (PokemonType::POISON, PokemonType::FLYING),
+                weight_kg: 7.5,
+            },
+            PokemonName::GOLBAT => PokedexEntry {
+                base_stats: (75, 80, 70, 65, 75, 90),
+                types: (PokemonType::POISON, PokemonType::FLYING),
+                weight_kg: 55.0,
+            },
+            PokemonName::ODDISH => PokedexEntry {
+                base_stats: (45, 50, 55, 75, 65, 30),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 5.4,
+            },
+            PokemonName::GLOOM => PokedexEntry {
+                base_stats: (60, 65, 70, 85, 75, 40),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 8.6,
+            },
+            PokemonName::VILEPLUME => PokedexEntry {
+                base_stats: (75, 80, 85, 110, 90, 50),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 18.6,
+            },
+            PokemonName::PARAS => PokedexEntry {
+                base_stats: (35, 70, 55, 45, 55, 25),
+                types: (PokemonType::BUG, PokemonType::GRASS),
+                weight_kg: 5.4,
+            },
+            PokemonName::PARASECT => PokedexEntry {
+                base_stats: (60, 95, 80, 60, 80, 30),
+                types: (PokemonType::BUG, PokemonType::GRASS),
+                weight_kg: 29.5,
+            },
+            PokemonName::VENONAT => PokedexEntry {
+                base_stats: (60, 55, 50, 40, 55, 45),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 30.0,
+            },
+            PokemonName::VENOMOTH => PokedexEntry {
+                base_stats: (70, 65, 60, 90, 75, 90),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 12.5,
+            },
+            PokemonName::DIGLETT => PokedexEntry {
+                base_stats: (10, 55, 25, 35, 45, 95),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 0.8,
+            },
+            PokemonName::DIGLETTALOLA => PokedexEntry {
+                base_stats: (10, 55, 30, 35, 45, 90),
+                types: (PokemonType::GROUND, PokemonType::STEEL),
+                weight_kg: 1.0,
+            },
+            PokemonName::DUGTRIO => PokedexEntry {
+                base_stats: (35, 100, 50, 50, 70, 120),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 33.3,
+            },
+            PokemonName::DUGTRIOALOLA => PokedexEntry {
+                base_stats: (35, 100, 60, 50, 70, 110),
+                types: (PokemonType::GROUND, PokemonType::STEEL),
+                weight_kg: 66.6,
+            },
+            PokemonName::MEOWTH => PokedexEntry {
+                base_stats: (40, 45, 35, 40, 40, 90),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 4.2,
+            },
+            PokemonName::MEOWTHALOLA => PokedexEntry {
+                base_stats: (40, 35, 35, 50, 40, 90),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 4.2,
+            },
+            PokemonName::MEOWTHGALAR => PokedexEntry {
+                base_stats: (50, 65, 55, 40, 40, 40),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 7.5,
+            },
+            PokemonName::MEOWTHGMAX => PokedexEntry {
+                base_stats: (40, 45, 35, 40, 40, 90),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 4.2,
+            },
+            PokemonName::PERSIAN => PokedexEntry {
+                base_stats: (65, 70, 60, 65, 65, 115),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 32.0,
+            },
+            PokemonName::PERSIANALOLA => PokedexEntry {
+                base_stats: (65, 60, 60, 75, 65, 115),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 33.0,
+            },
+            PokemonName::PSYDUCK => PokedexEntry {
+                base_stats: (50, 52, 48, 65, 50, 55),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 19.6,
+            },
+            PokemonName::GOLDUCK => PokedexEntry {
+                base_stats: (80, 82, 78, 95, 80, 85),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 76.6,
+            },
+            PokemonName::MANKEY => PokedexEntry {
+                base_stats: (40, 80, 35, 35, 45, 70),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::PRIMEAPE => PokedexEntry {
+                base_stats: (65, 105, 60, 60, 70, 95),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 32.0,
+            },
+            PokemonName::GROWLITHE => PokedexEntry {
+                base_stats: (55, 70, 45, 70, 50, 60),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 19.0,
+            },
+            PokemonName::GROWLITHEHISUI => PokedexEntry {
+                base_stats: (60, 75, 45, 65, 50, 55),
+                types: (PokemonType::FIRE, PokemonType::ROCK),
+                weight_kg: 22.7,
+            },
+            PokemonName::ARCANINE => PokedexEntry {
+                base_stats: (90, 110, 80, 100, 80, 95),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 155.0,
+            },
+            PokemonName::ARCANINEHISUI => PokedexEntry {
+                base_stats: (95, 115, 80, 95, 80, 90),
+                types: (PokemonType::FIRE, PokemonType::ROCK),
+                weight_kg: 168.0,
+            },
+            PokemonName::POLIWAG => PokedexEntry {
+                base_stats: (40, 50, 40, 40, 40, 90),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 12.4,
+            },
+            PokemonName::POLIWHIRL => PokedexEntry {
+                base_stats: (65, 65, 65, 50, 50, 90),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 20.0,
+            },
+            PokemonName::POLIWRATH => PokedexEntry {
+                base_stats: (90, 95, 95, 70, 90, 70),
+                types: (PokemonType::WATER, PokemonType::FIGHTING),
+                weight_kg: 54.0,
+            },
+            PokemonName::ABRA => PokedexEntry {
+                base_stats: (25, 20, 15, 105, 55, 90),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 19.5,
+            },
+            PokemonName::KADABRA => PokedexEntry {
+                base_stats: (40, 35, 30, 120, 70, 105),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 56.5,
+            },
+            PokemonName::ALAKAZAM => PokedexEntry {
+                base_stats: (55, 50, 45, 135, 95, 120),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 48.0,
+            },
+            PokemonName::ALAKAZAMMEGA => PokedexEntry {
+                base_stats: (55, 50, 65, 175, 105, 150),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 48.0,
+            },
+            PokemonName::MACHOP => PokedexEntry {
+                base_stats: (70, 80, 50, 35, 35, 35),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 19.5,
+            },
+            PokemonName::MACHOKE => PokedexEntry {
+                base_stats: (80, 100, 70, 50, 60, 45),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 70.5,
+            },
+            PokemonName::MACHAMP => PokedexEntry {
+                base_stats: (90, 130, 80, 65, 85, 55),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 130.0,
+            },
+            PokemonName::MACHAMPGMAX => PokedexEntry {
+                base_stats: (90, 130, 80, 65, 85, 55),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 130.0,
+            },
+            PokemonName::BELLSPROUT => PokedexEntry {
+                base_stats: (50, 75, 35, 70, 30, 40),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 4.0,
+            },
+            PokemonName::WEEPINBELL => PokedexEntry {
+                base_stats: (65, 90, 50, 85, 45, 55),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 6.4,
+            },
+            PokemonName::VICTREEBEL => PokedexEntry {
+                base_stats: (80, 105, 65, 100, 70, 70),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 15.5,
+            },
+            PokemonName::TENTACOOL => PokedexEntry {
+                base_stats: (40, 40, 35, 50, 100, 70),
+                types: (PokemonType::WATER, PokemonType::POISON),
+                weight_kg: 45.5,
+            },
+            PokemonName::TENTACRUEL => PokedexEntry {
+                base_stats: (80, 70, 65, 80, 120, 100),
+                types: (PokemonType::WATER, PokemonType::POISON),
+                weight_kg: 55.0,
+            },
+            PokemonName::GEODUDE => PokedexEntry {
+                base_stats: (40, 80, 100, 30, 30, 20),
+                types: (PokemonType::ROCK, PokemonType::GROUND),
+                weight_kg: 20.0,
+            },
+            PokemonName::GEODUDEALOLA => PokedexEntry {
+                base_stats: (40, 80, 100, 30, 30, 20),
+                types: (PokemonType::ROCK, PokemonType::ELECTRIC),
+                weight_kg: 20.3,
+            },
+            PokemonName::GRAVELER => PokedexEntry {
+                base_stats: (55, 95, 115, 45, 45, 35),
+                types: (PokemonType::ROCK, PokemonType::GROUND),
+                weight_kg: 105.0,
+            },
+            PokemonName::GRAVELERALOLA => PokedexEntry {
+                base_stats: (55, 95, 115, 45, 45, 35),
+                types: (PokemonType::ROCK, PokemonType::ELECTRIC),
+                weight_kg: 110.0,
+            },
+            PokemonName::GOLEM => PokedexEntry {
+                base_stats: (80, 120, 130, 55, 65, 45),
+                types: (PokemonType::ROCK, PokemonType::GROUND),
+                weight_kg: 300.0,
+            },
+            PokemonName::GOLEMALOLA => PokedexEntry {
+                base_stats: (80, 120, 130, 55, 65, 45),
+                types: (PokemonType::ROCK, PokemonType::ELECTRIC),
+                weight_kg: 316.0,
+            },
+            PokemonName::PONYTA => PokedexEntry {
+                base_stats: (50, 85, 55, 65, 65, 90),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 30.0,
+            },
+            PokemonName::PONYTAGALAR => PokedexEntry {
+                base_stats: (50, 85, 55, 65, 65, 90),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 24.0,
+            },
+            PokemonName::RAPIDASH => PokedexEntry {
+                base_stats: (65, 100, 70, 80, 80, 105),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 95.0,
+            },
+            PokemonName::RAPIDASHGALAR => PokedexEntry {
+                base_stats: (65, 100, 70, 80, 80, 105),
+                types: (PokemonType::PSYCHIC, PokemonType::FAIRY),
+                weight_kg: 80.0,
+            },
+            PokemonName::SLOWPOKE => PokedexEntry {
+                base_stats: (90, 65, 65, 40, 40, 15),
+                types: (PokemonType::WATER, PokemonType::PSYCHIC),
+                weight_kg: 36.0,
+            },
+            PokemonName::SLOWPOKEGALAR => PokedexEntry {
+                base_stats: (90, 65, 65, 40, 40, 15),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 36.0,
+            },
+            PokemonName::SLOWBRO => PokedexEntry {
+                base_stats: (95, 75, 110, 100, 80, 30),
+                types: (PokemonType::WATER, PokemonType::PSYCHIC),
+                weight_kg: 78.5,
+            },
+            PokemonName::SLOWBROMEGA => PokedexEntry {
+                base_stats: (95, 75, 180, 130, 80, 30),
+                types: (PokemonType::WATER, PokemonType::PSYCHIC),
+                weight_kg: 120.0,
+            },
+            PokemonName::SLOWBROGALAR => PokedexEntry {
+                base_stats: (95, 100, 95, 100, 70, 30),
+                types: (PokemonType::POISON, PokemonType::PSYCHIC),
+                weight_kg: 70.5,
+            },
+            PokemonName::MAGNEMITE => PokedexEntry {
+                base_stats: (25, 35, 70, 95, 55, 45),
+                types: (PokemonType::ELECTRIC, PokemonType::STEEL),
+                weight_kg: 6.0,
+            },
+            PokemonName::MAGNETON => PokedexEntry {
+                base_stats: (50, 60, 95, 120, 70, 70),
+                types: (PokemonType::ELECTRIC, PokemonType::STEEL),
+                weight_kg: 60.0,
+            },
+            PokemonName::FARFETCHD => PokedexEntry {
+                base_stats: (52, 90, 55, 58, 62, 60),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 15.0,
+            },
+            PokemonName::FARFETCHDGALAR => PokedexEntry {
+                base_stats: (52, 95, 55, 58, 62, 55),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 42.0,
+            },
+            PokemonName::DODUO => PokedexEntry {
+                base_stats: (35, 85, 45, 35, 35, 75),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 39.2,
+            },
+            PokemonName::DODRIO => PokedexEntry {
+                base_stats: (60, 110, 70, 60, 60, 110),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 85.2,
+            },
+            PokemonName::SEEL => PokedexEntry {
+                base_stats: (65, 45, 55, 45, 70, 45),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 90.0,
+            },
+            PokemonName::DEWGONG => PokedexEntry {
+                base_stats: (90, 70, 80, 70, 95, 70),
+                types: (PokemonType::WATER, PokemonType::ICE),
+                weight_kg: 120.0,
+            },
+            PokemonName::GRIMER => PokedexEntry {
+                base_stats: (80, 80, 50, 40, 50, 25),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 30.0,
+            },
+            PokemonName::GRIMERALOLA => PokedexEntry {
+                base_stats: (80, 80, 50, 40, 50, 25),
+                types: (PokemonType::POISON, PokemonType::DARK),
+                weight_kg: 42.0,
+            },
+            PokemonName::MUK => PokedexEntry {
+                base_stats: (105, 105, 75, 65, 100, 50),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 30.0,
+            },
+            PokemonName::MUKALOLA => PokedexEntry {
+                base_stats: (105, 105, 75, 65, 100, 50),
+                types: (PokemonType::POISON, PokemonType::DARK),
+                weight_kg: 52.0,
+            },
+            PokemonName::SHELLDER => PokedexEntry {
+                base_stats: (30, 65, 100, 45, 25, 40),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 4.0,
+            },
+            PokemonName::CLOYSTER => PokedexEntry {
+                base_stats: (50, 95, 180, 85, 45, 70),
+                types: (PokemonType::WATER, PokemonType::ICE),
+                weight_kg: 132.5,
+            },
+            PokemonName::GASTLY => PokedexEntry {
+                base_stats: (30, 35, 30, 100, 35, 80),
+                types: (PokemonType::GHOST, PokemonType::POISON),
+                weight_kg: 0.1,
+            },
+            PokemonName::HAUNTER => PokedexEntry {
+                base_stats: (45, 50, 45, 115, 55, 95),
+                types: (PokemonType::GHOST, PokemonType::POISON),
+                weight_kg: 0.1,
+            },
+            PokemonName::GENGAR => PokedexEntry {
+                base_stats: (60, 65, 60, 130, 75, 110),
+                types: (PokemonType::GHOST, PokemonType::POISON),
+                weight_kg: 40.5,
+            },
+            PokemonName::GENGARMEGA => PokedexEntry {
+                base_stats: (60, 65, 80, 170, 95, 130),
+                types: (PokemonType::GHOST, PokemonType::POISON),
+                weight_kg: 40.5,
+            },
+            PokemonName::GENGARGMAX => PokedexEntry {
+                base_stats: (60, 65, 60, 130, 75, 110),
+                types: (PokemonType::GHOST, PokemonType::POISON),
+                weight_kg: 40.5,
+            },
+            PokemonName::ONIX => PokedexEntry {
+                base_stats: (35, 45, 160, 30, 45, 70),
+                types: (PokemonType::ROCK, PokemonType::GROUND),
+                weight_kg: 210.0,
+            },
+            PokemonName::DROWZEE => PokedexEntry {
+                base_stats: (60, 48, 45, 43, 90, 42),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 32.4,
+            },
+            PokemonName::HYPNO => PokedexEntry {
+                base_stats: (85, 73, 70, 73, 115, 67),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 75.6,
+            },
+            PokemonName::KRABBY => PokedexEntry {
+                base_stats: (30, 105, 90, 25, 25, 50),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 6.5,
+            },
+            PokemonName::KINGLER => PokedexEntry {
+                base_stats: (55, 130, 115, 50, 50, 75),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 60.0,
+            },
+            PokemonName::KINGLERGMAX => PokedexEntry {
+                base_stats: (55, 130, 115, 50, 50, 75),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 60.0,
+            },
+            PokemonName::VOLTORB => PokedexEntry {
+                base_stats: (40, 30, 50, 55, 55, 100),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 10.4,
+            },
+            PokemonName::VOLTORBHISUI => PokedexEntry {
+                base_stats: (40, 30, 50, 55, 55, 100),
+                types: (PokemonType::ELECTRIC, PokemonType::GRASS),
+                weight_kg: 13.0,
+            },
+            PokemonName::ELECTRODE => PokedexEntry {
+                base_stats: (60, 50, 70, 80, 80, 150),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 66.6,
+            },
+            PokemonName::ELECTRODEHISUI => PokedexEntry {
+                base_stats: (60, 50, 70, 80, 80, 150),
+                types: (PokemonType::ELECTRIC, PokemonType::GRASS),
+                weight_kg: 71.0,
+            },
+            PokemonName::EXEGGCUTE => PokedexEntry {
+                base_stats: (60, 40, 80, 60, 45, 40),
+                types: (PokemonType::GRASS, PokemonType::PSYCHIC),
+                weight_kg: 2.5,
+            },
+            PokemonName::EXEGGUTOR => PokedexEntry {
+                base_stats: (95, 95, 85, 125, 75, 55),
+                types: (PokemonType::GRASS, PokemonType::PSYCHIC),
+                weight_kg: 120.0,
+            },
+            PokemonName::EXEGGUTORALOLA => PokedexEntry {
+                base_stats: (95, 105, 85, 125, 75, 45),
+                types: (PokemonType::GRASS, PokemonType::DRAGON),
+                weight_kg: 415.6,
+            },
+            PokemonName::CUBONE => PokedexEntry {
+                base_stats: (50, 50, 95, 40, 50, 35),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 6.5,
+            },
+            PokemonName::MAROWAK => PokedexEntry {
+                base_stats: (60, 80, 110, 50, 80, 45),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 45.0,
+            },
+            PokemonName::MAROWAKALOLA => PokedexEntry {
+                base_stats: (60, 80, 110, 50, 80, 45),
+                types: (PokemonType::FIRE, PokemonType::GHOST),
+                weight_kg: 34.0,
+            },
+            PokemonName::MAROWAKALOLATOTEM => PokedexEntry {
+                base_stats: (60, 80, 110, 50, 80, 45),
+                types: (PokemonType::FIRE, PokemonType::GHOST),
+                weight_kg: 98.0,
+            },
+            PokemonName::HITMONLEE => PokedexEntry {
+                base_stats: (50, 120, 53, 35, 110, 87),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 49.8,
+            },
+            PokemonName::HITMONCHAN => PokedexEntry {
+                base_stats: (50, 105, 79, 35, 110, 76),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 50.2,
+            },
+            PokemonName::LICKITUNG => PokedexEntry {
+                base_stats: (90, 55, 75, 60, 75, 30),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 65.5,
+            },
+            PokemonName::KOFFING => PokedexEntry {
+                base_stats: (40, 65, 95, 60, 45, 35),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 1.0,
+            },
+            PokemonName::WEEZING => PokedexEntry {
+                base_stats: (65, 90, 120, 85, 70, 60),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 9.5,
+            },
+            PokemonName::WEEZINGGALAR => PokedexEntry {
+                base_stats: (65, 90, 120, 85, 70, 60),
+                types: (PokemonType::POISON, PokemonType::FAIRY),
+                weight_kg: 16.0,
+            },
+            PokemonName::RHYHORN => PokedexEntry {
+                base_stats: (80, 85, 95, 30, 30, 25),
+                types: (PokemonType::GROUND, PokemonType::ROCK),
+                weight_kg: 115.0,
+            },
+            PokemonName::RHYDON => PokedexEntry {
+                base_stats: (105, 130, 120, 45, 45, 40),
+                types: (PokemonType::GROUND, PokemonType::ROCK),
+                weight_kg: 120.0,
+            },
+            PokemonName::CHANSEY => PokedexEntry {
+                base_stats: (250, 5, 5, 35, 105, 50),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 34.6,
+            },
+            PokemonName::TANGELA => PokedexEntry {
+                base_stats: (65, 55, 115, 100, 40, 60),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 35.0,
+            },
+            PokemonName::KANGASKHAN => PokedexEntry {
+                base_stats: (105, 95, 80, 40, 80, 90),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 80.0,
+            },
+            PokemonName::KANGASKHANMEGA => PokedexEntry {
+                base_stats: (105, 125, 100, 60, 100, 100),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 100.0,
+            },
+            PokemonName::HORSEA => PokedexEntry {
+                base_stats: (30, 40, 70, 70, 25, 60),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 8.0,
+            },
+            PokemonName::SEADRA => PokedexEntry {
+                base_stats: (55, 65, 95, 95, 45, 85),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 25.0,
+            },
+            PokemonName::GOLDEEN => PokedexEntry {
+                base_stats: (45, 67, 60, 35, 50, 63),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 15.0,
+            },
+            PokemonName::SEAKING => PokedexEntry {
+                base_stats: (80, 92, 65, 65, 80, 68),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 39.0,
+            },
+            PokemonName::STARYU => PokedexEntry {
+                base_stats: (30, 45, 55, 70, 55, 85),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 34.5,
+            },
+            PokemonName::STARMIE => PokedexEntry {
+                base_stats: (60, 75, 85, 100, 85, 115),
+                types: (PokemonType::WATER, PokemonType::PSYCHIC),
+                weight_kg: 80.0,
+            },
+            PokemonName::MRMIME => PokedexEntry {
+                base_stats: (40, 45, 65, 100, 120, 90),
+                types: (PokemonType::PSYCHIC, PokemonType::FAIRY),
+                weight_kg: 54.5,
+            },
+            PokemonName::MRMIMEGALAR => PokedexEntry {
+                base_stats: (50, 65, 65, 90, 90, 100),
+                types: (PokemonType::ICE, PokemonType::PSYCHIC),
+                weight_kg: 56.8,
+            },
+            PokemonName::SCYTHER => PokedexEntry {
+                base_stats: (70, 110, 80, 55, 80, 105),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 56.0,
+            },
+            PokemonName::JYNX => PokedexEntry {
+                base_stats: (65, 50, 35, 115, 95, 95),
+                types: (PokemonType::ICE, PokemonType::PSYCHIC),
+                weight_kg: 40.6,
+            },
+            PokemonName::ELECTABUZZ => PokedexEntry {
+                base_stats: (65, 83, 57, 95, 85, 105),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 30.0,
+            },
+            PokemonName::MAGMAR => PokedexEntry {
+                base_stats: (65, 95, 57, 100, 85, 93),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 44.5,
+            },
+            PokemonName::PINSIR => PokedexEntry {
+                base_stats: (65, 125, 100, 55, 70, 85),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 55.0,
+            },
+            PokemonName::PINSIRMEGA => PokedexEntry {
+                base_stats: (65, 155, 120, 65, 90, 105),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 59.0,
+            },
+            PokemonName::TAUROS => PokedexEntry {
+                base_stats: (75, 100, 95, 40, 70, 110),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 88.4,
+            },
+            PokemonName::TAUROSPALDEACOMBAT => PokedexEntry {
+                base_stats: (75, 110, 105, 30, 70, 100),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 115.0,
+            },
+            PokemonName::TAUROSPALDEABLAZE => PokedexEntry {
+                base_stats: (75, 110, 105, 30, 70, 100),
+                types: (PokemonType::FIGHTING, PokemonType::FIRE),
+                weight_kg: 85.0,
+            },
+            PokemonName::TAUROSPALDEAAQUA => PokedexEntry {
+                base_stats: (75, 110, 105, 30, 70, 100),
+                types: (PokemonType::FIGHTING, PokemonType::WATER),
+                weight_kg: 110.0,
+            },
+            PokemonName::MAGIKARP => PokedexEntry {
+                base_stats: (20, 10, 55, 15, 20, 80),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 10.0,
+            },
+            PokemonName::GYARADOS => PokedexEntry {
+                base_stats: (95, 125, 79, 60, 100, 81),
+                types: (PokemonType::WATER, PokemonType::FLYING),
+                weight_kg: 235.0,
+            },
+            PokemonName::GYARADOSMEGA => PokedexEntry {
+                base_stats: (95, 155, 109, 70, 130, 81),
+                types: (PokemonType::WATER, PokemonType::DARK),
+                weight_kg: 305.0,
+            },
+            PokemonName::LAPRAS => PokedexEntry {
+                base_stats: (130, 85, 80, 85, 95, 60),
+                types: (PokemonType::WATER, PokemonType::ICE),
+                weight_kg: 220.0,
+            },
+            PokemonName::LAPRASGMAX => PokedexEntry {
+                base_stats: (130, 85, 80, 85, 95, 60),
+                types: (PokemonType::WATER, PokemonType::ICE),
+                weight_kg: 220.0,
+            },
+            PokemonName::DITTO => PokedexEntry {
+                base_stats: (48, 48, 48, 48, 48, 48),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 4.0,
+            },
+            PokemonName::EEVEE => PokedexEntry {
+                base_stats: (55, 55, 50, 45, 65, 55),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 6.5,
+            },
+            PokemonName::EEVEESTARTER => PokedexEntry {
+                base_stats: (65, 75, 70, 65, 85, 75),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 6.5,
+            },
+            PokemonName::EEVEEGMAX => PokedexEntry {
+                base_stats: (55, 55, 50, 45, 65, 55),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 6.5,
+            },
+            PokemonName::VAPOREON => PokedexEntry {
+                base_stats: (130, 65, 60, 110, 95, 65),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 29.0,
+            },
+            PokemonName::JOLTEON => PokedexEntry {
+                base_stats: (65, 65, 60, 110, 95, 130),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 24.5,
+            },
+            PokemonName::FLAREON => PokedexEntry {
+                base_stats: (65, 130, 60, 95, 110, 65),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 25.0,
+            },
+            PokemonName::PORYGON => PokedexEntry {
+                base_stats: (65, 60, 70, 85, 75, 40),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 36.5,
+            },
+            PokemonName::OMANYTE => PokedexEntry {
+                base_stats: (35, 40, 100, 90, 55, 35),
+                types: (PokemonType::ROCK, PokemonType::WATER),
+                weight_kg: 7.5,
+            },
+            PokemonName::OMASTAR => PokedexEntry {
+                base_stats: (70, 60, 125, 115, 70, 55),
+                types: (PokemonType::ROCK, PokemonType::WATER),
+                weight_kg: 35.0,
+            },
+            PokemonName::KABUTO => PokedexEntry {
+                base_stats: (30, 80, 90, 55, 45, 55),
+                types: (PokemonType::ROCK, PokemonType::WATER),
+                weight_kg: 11.5,
+            },
+            PokemonName::KABUTOPS => PokedexEntry {
+                base_stats: (60, 115, 105, 65, 70, 80),
+                types: (PokemonType::ROCK, PokemonType::WATER),
+                weight_kg: 40.5,
+            },
+            PokemonName::AERODACTYL => PokedexEntry {
+                base_stats: (80, 105, 65, 60, 75, 130),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 59.0,
+            },
+            PokemonName::AERODACTYLMEGA => PokedexEntry {
+                base_stats: (80, 135, 85, 70, 95, 150),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 79.0,
+            },
+            PokemonName::SNORLAX => PokedexEntry {
+                base_stats: (160, 110, 65, 65, 110, 30),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 460.0,
+            },
+            PokemonName::SNORLAXGMAX => PokedexEntry {
+                base_stats: (160, 110, 65, 65, 110, 30),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 460.0,
+            },
+            PokemonName::ARTICUNO => PokedexEntry {
+                base_stats: (90, 85, 100, 95, 125, 85),
+                types: (PokemonType::ICE, PokemonType::FLYING),
+                weight_kg: 55.4,
+            },
+            PokemonName::ARTICUNOGALAR => PokedexEntry {
+                base_stats: (90, 85, 85, 125, 100, 95),
+                types: (PokemonType::PSYCHIC, PokemonType::FLYING),
+                weight_kg: 50.9,
+            },
+            PokemonName::ZAPDOS => PokedexEntry {
+                base_stats: (90, 90, 85, 125, 90, 100),
+                types: (PokemonType::ELECTRIC, PokemonType::FLYING),
+                weight_kg: 52.6,
+            },
+            PokemonName::ZAPDOSGALAR => PokedexEntry {
+                base_stats: (90, 125, 90, 85, 90, 100),
+                types: (PokemonType::FIGHTING, PokemonType::FLYING),
+                weight_kg: 58.2,
+            },
+            PokemonName::MOLTRES => PokedexEntry {
+                base_stats: (90, 100, 90, 125, 85, 90),
+                types: (PokemonType::FIRE, PokemonType::FLYING),
+                weight_kg: 60.0,
+            },
+            PokemonName::MOLTRESGALAR => PokedexEntry {
+                base_stats: (90, 85, 90, 100, 125, 90),
+                types: (PokemonType::DARK, PokemonType::FLYING),
+                weight_kg: 66.0,
+            },
+            PokemonName::DRATINI => PokedexEntry {
+                base_stats: (41, 64, 45, 50, 50, 50),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 3.3,
+            },
+            PokemonName::DRAGONAIR => PokedexEntry {
+                base_stats: (61, 84, 65, 70, 70, 70),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 16.5,
+            },
+            PokemonName::DRAGONITE => PokedexEntry {
+                base_stats: (91, 134, 95, 100, 100, 80),
+                types: (PokemonType::DRAGON, PokemonType::FLYING),
+                weight_kg: 210.0,
+            },
+            PokemonName::MEWTWO => PokedexEntry {
+                base_stats: (106, 110, 90, 154, 90, 130),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 122.0,
+            },
+            PokemonName::MEWTWOMEGAX => PokedexEntry {
+                base_stats: (106, 190, 100, 154, 100, 130),
+                types: (PokemonType::PSYCHIC, PokemonType::FIGHTING),
+                weight_kg: 127.0,
+            },
+            PokemonName::MEWTWOMEGAY => PokedexEntry {
+                base_stats: (106, 150, 70, 194, 120, 140),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 33.0,
+            },
+            PokemonName::MEW => PokedexEntry {
+                base_stats: (100, 100, 100, 100, 100, 100),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 4.0,
+            },
+            PokemonName::CHIKORITA => PokedexEntry {
+                base_stats: (45, 49, 65, 49, 65, 45),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 6.4,
+            },
+            PokemonName::BAYLEEF => PokedexEntry {
+                base_stats: (60, 62, 80, 63, 80, 60),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 15.8,
+            },
+            PokemonName::MEGANIUM => PokedexEntry {
+                base_stats: (80, 82, 100, 83, 100, 80),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::CYNDAQUIL => PokedexEntry {
+                base_stats: (39, 52, 43, 60, 50, 65),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 7.9,
+            },
+            PokemonName::QUILAVA => PokedexEntry {
+                base_stats: (58, 64, 58, 80, 65, 80),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 19.0,
+            },
+            PokemonName::TYPHLOSION => PokedexEntry {
+                base_stats: (78, 84, 78, 109, 85, 100),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 79.5,
+            },
+            PokemonName::TYPHLOSIONHISUI => PokedexEntry {
+                base_stats: (73, 84, 78, 119, 85, 95),
+                types: (PokemonType::FIRE, PokemonType::GHOST),
+                weight_kg: 69.8,
+            },
+            PokemonName::TOTODILE => PokedexEntry {
+                base_stats: (50, 65, 64, 44, 48, 43),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 9.5,
+            },
+            PokemonName::CROCONAW => PokedexEntry {
+                base_stats: (65, 80, 80, 59, 63, 58),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 25.0,
+            },
+            PokemonName::FERALIGATR => PokedexEntry {
+                base_stats: (85, 105, 100, 79, 83, 78),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 88.8,
+            },
+            PokemonName::SENTRET => PokedexEntry {
+                base_stats: (35, 46, 34, 35, 45, 20),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::FURRET => PokedexEntry {
+                base_stats: (85, 76, 64, 45, 55, 90),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 32.5,
+            },
+            PokemonName::HOOTHOOT => PokedexEntry {
+                base_stats: (60, 30, 30, 36, 56, 50),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 21.2,
+            },
+            PokemonName::NOCTOWL => PokedexEntry {
+                base_stats: (100, 50, 50, 86, 96, 70),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 40.8,
+            },
+            PokemonName::LEDYBA => PokedexEntry {
+                base_stats: (40, 20, 30, 40, 80, 55),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 10.8,
+            },
+            PokemonName::LEDIAN => PokedexEntry {
+                base_stats: (55, 35, 50, 55, 110, 85),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 35.6,
+            },
+            PokemonName::SPINARAK => PokedexEntry {
+                base_stats: (40, 60, 40, 40, 40, 30),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 8.5,
+            },
+            PokemonName::ARIADOS => PokedexEntry {
+                base_stats: (70, 90, 70, 60, 70, 40),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 33.5,
+            },
+            PokemonName::CROBAT => PokedexEntry {
+                base_stats: (85, 90, 80, 70, 80, 130),
+                types: (PokemonType::POISON, PokemonType::FLYING),
+                weight_kg: 75.0,
+            },
+            PokemonName::CHINCHOU => PokedexEntry {
+                base_stats: (75, 38, 38, 56, 56, 67),
+                types: (PokemonType::WATER, PokemonType::ELECTRIC),
+                weight_kg: 12.0,
+            },
+            PokemonName::LANTURN => PokedexEntry {
+                base_stats: (125, 58, 58, 76, 76, 67),
+                types: (PokemonType::WATER, PokemonType::ELECTRIC),
+                weight_kg: 22.5,
+            },
+            PokemonName::PICHU => PokedexEntry {
+                base_stats: (20, 40, 15, 35, 35, 60),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 2.0,
+            },
+            PokemonName::PICHUSPIKYEARED => PokedexEntry {
+                base_stats: (20, 40, 15, 35, 35, 60),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 2.0,
+            },
+            PokemonName::CLEFFA => PokedexEntry {
+                base_stats: (50, 25, 28, 45, 55, 15),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 3.0,
+            },
+            PokemonName::IGGLYBUFF => PokedexEntry {
+                base_stats: (90, 30, 15, 40, 20, 15),
+                types: (PokemonType::NORMAL, PokemonType::FAIRY),
+                weight_kg: 1.0,
+            },
+            PokemonName::TOGEPI => PokedexEntry {
+                base_stats: (35, 20, 65, 40, 65, 20),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 1.5,
+            },
+            PokemonName::TOGETIC => PokedexEntry {
+                base_stats: (55, 40, 85, 80, 105, 40),
+                types: (PokemonType::FAIRY, PokemonType::FLYING),
+                weight_kg: 3.2,
+            },
+            PokemonName::NATU => PokedexEntry {
+                base_stats: (40, 50, 45, 70, 45, 70),
+                types: (PokemonType::PSYCHIC, PokemonType::FLYING),
+                weight_kg: 2.0,
+            },
+            PokemonName::XATU => PokedexEntry {
+                base_stats: (65, 75, 70, 95, 70, 95),
+                types: (PokemonType::PSYCHIC, PokemonType::FLYING),
+                weight_kg: 15.0,
+            },
+            PokemonName::MAREEP => PokedexEntry {
+                base_stats: (55, 40, 40, 65, 45, 35),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 7.8,
+            },
+            PokemonName::FLAAFFY => PokedexEntry {
+                base_stats: (70, 55, 55, 80, 60, 45),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 13.3,
+            },
+            PokemonName::AMPHAROS => PokedexEntry {
+                base_stats: (90, 75, 85, 115, 90, 55),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 61.5,
+            },
+            PokemonName::AMPHAROSMEGA => PokedexEntry {
+                base_stats: (90, 95, 105, 165, 110, 45),
+                types: (PokemonType::ELECTRIC, PokemonType::DRAGON),
+                weight_kg: 61.5,
+            },
+            PokemonName::BELLOSSOM => PokedexEntry {
+                base_stats: (75, 80, 95, 90, 100, 50),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 5.8,
+            },
+            PokemonName::MARILL => PokedexEntry {
+                base_stats: (70, 20, 50, 20, 50, 40),
+                types: (PokemonType::WATER, PokemonType::FAIRY),
+                weight_kg: 8.5,
+            },
+            PokemonName::AZUMARILL => PokedexEntry {
+                base_stats: (100, 50, 80, 60, 80, 50),
+                types: (PokemonType::WATER, PokemonType::FAIRY),
+                weight_kg: 28.5,
+            },
+            PokemonName::SUDOWOODO => PokedexEntry {
+                base_stats: (70, 100, 115, 30, 65, 30),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 38.0,
+            },
+            PokemonName::POLITOED => PokedexEntry {
+                base_stats: (90, 75, 75, 90, 100, 70),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 33.9,
+            },
+            PokemonName::HOPPIP => PokedexEntry {
+                base_stats: (35, 35, 40, 35, 55, 50),
+                types: (PokemonType::GRASS, PokemonType::FLYING),
+                weight_kg: 0.5,
+            },
+            PokemonName::SKIPLOOM => PokedexEntry {
+                base_stats: (55, 45, 50, 45, 65, 80),
+                types: (PokemonType::GRASS, PokemonType::FLYING),
+                weight_kg: 1.0,
+            },
+            PokemonName::JUMPLUFF => PokedexEntry {
+                base_stats: (75, 55, 70, 55, 95, 110),
+                types: (PokemonType::GRASS, PokemonType::FLYING),
+                weight_kg: 3.0,
+            },
+            PokemonName::AIPOM => PokedexEntry {
+                base_stats: (55, 70, 55, 40, 55, 85),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 11.5,
+            },
+            PokemonName::SUNKERN => PokedexEntry {
+                base_stats: (30, 30, 30, 30, 30, 30),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 1.8,
+            },
+            PokemonName::SUNFLORA => PokedexEntry {
+                base_stats: (75, 75, 55, 105, 85, 30),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 8.5,
+            },
+            PokemonName::YANMA => PokedexEntry {
+                base_stats: (65, 65, 45, 75, 45, 95),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 38.0,
+            },
+            PokemonName::WOOPER => PokedexEntry {
+                base_stats: (55, 45, 45, 25, 25, 15),
+                types: (PokemonType::WATER, PokemonType::GROUND),
+                weight_kg: 8.5,
+            },
+            PokemonName::WOOPERPALDEA => PokedexEntry {
+                base_stats: (55, 45, 45, 25, 25, 15),
+                types: (PokemonType::POISON, PokemonType::GROUND),
+                weight_kg: 11.0,
+            },
+            PokemonName::QUAGSIRE => PokedexEntry {
+                base_stats: (95, 85, 85, 65, 65, 35),
+                types: (PokemonType::WATER, PokemonType::GROUND),
+                weight_kg: 75.0,
+            },
+            PokemonName::ESPEON => PokedexEntry {
+                base_stats: (65, 65, 60, 130, 95, 110),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 26.5,
+            },
+            PokemonName::UMBREON => PokedexEntry {
+                base_stats: (95, 65, 110, 60, 130, 65),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 27.0,
+            },
+            PokemonName::MURKROW => PokedexEntry {
+                base_stats: (60, 85, 42, 85, 42, 91),
+                types: (PokemonType::DARK, PokemonType::FLYING),
+                weight_kg: 2.1,
+            },
+            PokemonName::SLOWKING => PokedexEntry {
+                base_stats: (95, 75, 80, 100, 110, 30),
+                types: (PokemonType::WATER, PokemonType::PSYCHIC),
+                weight_kg: 79.5,
+            },
+            PokemonName::SLOWKINGGALAR => PokedexEntry {
+                base_stats: (95, 65, 80, 110, 110, 30),
+                types: (PokemonType::POISON, PokemonType::PSYCHIC),
+                weight_kg: 79.5,
+            },
+            PokemonName::MISDREAVUS => PokedexEntry {
+                base_stats: (60, 60, 60, 85, 85, 85),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 1.0,
+            },
+            PokemonName::UNOWN => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNB => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNC => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWND => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNE => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNF => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNG => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNH => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNI => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNJ => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNK => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNL => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNM => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNN => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNO => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNP => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNQ => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNR => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNS => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNT => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNU => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNV => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNW => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNX => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNY => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNZ => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNEXCLAMATION => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::UNOWNQUESTION => PokedexEntry {
+                base_stats: (48, 72, 48, 72, 48, 48),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::WOBBUFFET => PokedexEntry {
+                base_stats: (190, 33, 58, 33, 58, 33),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 28.5,
+            },
+            PokemonName::GIRAFARIG => PokedexEntry {
+                base_stats: (70, 80, 65, 90, 65, 85),
+                types: (PokemonType::NORMAL, PokemonType::PSYCHIC),
+                weight_kg: 41.5,
+            },
+            PokemonName::PINECO => PokedexEntry {
+                base_stats: (50, 65, 90, 35, 35, 15),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 7.2,
+            },
+            PokemonName::FORRETRESS => PokedexEntry {
+                base_stats: (75, 90, 140, 60, 60, 40),
+                types: (PokemonType::BUG, PokemonType::STEEL),
+                weight_kg: 125.8,
+            },
+            PokemonName::DUNSPARCE => PokedexEntry {
+                base_stats: (100, 70, 70, 65, 65, 45),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 14.0,
+            },
+            PokemonName::GLIGAR => PokedexEntry {
+                base_stats: (65, 75, 105, 35, 65, 85),
+                types: (PokemonType::GROUND, PokemonType::FLYING),
+                weight_kg: 64.8,
+            },
+            PokemonName::STEELIX => PokedexEntry {
+                base_stats: (75, 85, 200, 55, 65, 30),
+                types: (PokemonType::STEEL, PokemonType::GROUND),
+                weight_kg: 400.0,
+            },
+            PokemonName::STEELIXMEGA => PokedexEntry {
+                base_stats: (75, 125, 230, 55, 95, 30),
+                types: (PokemonType::STEEL, PokemonType::GROUND),
+                weight_kg: 740.0,
+            },
+            PokemonName::SNUBBULL => PokedexEntry {
+                base_stats: (60, 80, 50, 40, 40, 30),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 7.8,
+            },
+            PokemonName::GRANBULL => PokedexEntry {
+                base_stats: (90, 120, 75, 60, 60, 45),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 48.7,
+            },
+            PokemonName::QWILFISH => PokedexEntry {
+                base_stats: (65, 95, 85, 55, 55, 85),
+                types: (PokemonType::WATER, PokemonType::POISON),
+                weight_kg: 3.9,
+            },
+            PokemonName::QWILFISHHISUI => PokedexEntry {
+                base_stats: (65, 95, 85, 55, 55, 85),
+                types: (PokemonType::DARK, PokemonType::POISON),
+                weight_kg: 3.9,
+            },
+            PokemonName::SCIZOR => PokedexEntry {
+                base_stats: (70, 130, 100, 55, 80, 65),
+                types: (PokemonType::BUG, PokemonType::STEEL),
+                weight_kg: 118.0,
+            },
+            PokemonName::SCIZORMEGA => PokedexEntry {
+                base_stats: (70, 150, 140, 65, 100, 75),
+                types: (PokemonType::BUG, PokemonType::STEEL),
+                weight_kg: 125.0,
+            },
+            PokemonName::SHUCKLE => PokedexEntry {
+                base_stats: (20, 10, 230, 10, 230, 5),
+                types: (PokemonType::BUG, PokemonType::ROCK),
+                weight_kg: 20.5,
+            },
+            PokemonName::HERACROSS => PokedexEntry {
+                base_stats: (80, 125, 75, 40, 95, 85),
+                types: (PokemonType::BUG, PokemonType::FIGHTING),
+                weight_kg: 54.0,
+            },
+            PokemonName::HERACROSSMEGA => PokedexEntry {
+                base_stats: (80, 185, 115, 40, 105, 75),
+                types: (PokemonType::BUG, PokemonType::FIGHTING),
+                weight_kg: 62.5,
+            },
+            PokemonName::SNEASEL => PokedexEntry {
+                base_stats: (55, 95, 55, 35, 75, 115),
+                types: (PokemonType::DARK, PokemonType::ICE),
+                weight_kg: 28.0,
+            },
+            PokemonName::SNEASELHISUI => PokedexEntry {
+                base_stats: (55, 95, 55, 35, 75, 115),
+                types: (PokemonType::FIGHTING, PokemonType::POISON),
+                weight_kg: 27.0,
+            },
+            PokemonName::TEDDIURSA => PokedexEntry {
+                base_stats: (60, 80, 50, 50, 50, 40),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 8.8,
+            },
+            PokemonName::URSARING => PokedexEntry {
+                base_stats: (90, 130, 75, 75, 75, 55),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 125.8,
+            },
+            PokemonName::SLUGMA => PokedexEntry {
+                base_stats: (40, 40, 40, 70, 40, 20),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 35.0,
+            },
+            PokemonName::MAGCARGO => PokedexEntry {
+                base_stats: (60, 50, 120, 90, 80, 30),
+                types: (PokemonType::FIRE, PokemonType::ROCK),
+                weight_kg: 55.0,
+            },
+            PokemonName::SWINUB => PokedexEntry {
+                base_stats: (50, 50, 40, 30, 30, 50),
+                types: (PokemonType::ICE, PokemonType::GROUND),
+                weight_kg: 6.5,
+            },
+            PokemonName::PILOSWINE => PokedexEntry {
+                base_stats: (100, 100, 80, 60, 60, 50),
+                types: (PokemonType::ICE, PokemonType::GROUND),
+                weight_kg: 55.8,
+            },
+            PokemonName::CORSOLA => PokedexEntry {
+                base_stats: (65, 55, 95, 65, 95, 35),
+                types: (PokemonType::WATER, PokemonType::ROCK),
+                weight_kg: 5.0,
+            },
+            PokemonName::CORSOLAGALAR => PokedexEntry {
+                base_stats: (60, 55, 100, 65, 100, 30),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::REMORAID => PokedexEntry {
+                base_stats: (35, 65, 35, 65, 35, 65),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 12.0,
+            },
+            PokemonName::OCTILLERY => PokedexEntry {
+                base_stats: (75, 105, 75, 105, 75, 45),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 28.5,
+            },
+            PokemonName::DELIBIRD => PokedexEntry {
+                base_stats: (45, 55, 45, 65, 45, 75),
+                types: (PokemonType::ICE, PokemonType::FLYING),
+                weight_kg: 16.0,
+            },
+            PokemonName::MANTINE => PokedexEntry {
+                base_stats: (85, 40, 70, 80, 140, 70),
+                types: (PokemonType::WATER, PokemonType::FLYING),
+                weight_kg: 220.0,
+            },
+            PokemonName::SKARMORY => PokedexEntry {
+                base_stats: (65, 80, 140, 40, 70, 70),
+                types: (PokemonType::STEEL, PokemonType::FLYING),
+                weight_kg: 50.5,
+            },
+            PokemonName::HOUNDOUR => PokedexEntry {
+                base_stats: (45, 60, 30, 80, 50, 65),
+                types: (PokemonType::DARK, PokemonType::FIRE),
+                weight_kg: 10.8,
+            },
+            PokemonName::HOUNDOOM => PokedexEntry {
+                base_stats: (75, 90, 50, 110, 80, 95),
+                types: (PokemonType::DARK, PokemonType::FIRE),
+                weight_kg: 35.0,
+            },
+            PokemonName::HOUNDOOMMEGA => PokedexEntry {
+                base_stats: (75, 90, 90, 140, 90, 115),
+                types: (PokemonType::DARK, PokemonType::FIRE),
+                weight_kg: 49.5,
+            },
+            PokemonName::KINGDRA => PokedexEntry {
+                base_stats: (75, 95, 95, 95, 95, 85),
+                types: (PokemonType::WATER, PokemonType::DRAGON),
+                weight_kg: 152.0,
+            },
+            PokemonName::PHANPY => PokedexEntry {
+                base_stats: (90, 60, 60, 40, 40, 40),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 33.5,
+            },
+            PokemonName::DONPHAN => PokedexEntry {
+                base_stats: (90, 120, 120, 60, 60, 50),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 120.0,
+            },
+            PokemonName::PORYGON2 => PokedexEntry {
+                base_stats: (85, 80, 90, 105, 95, 60),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 32.5,
+            },
+            PokemonName::STANTLER => PokedexEntry {
+                base_stats: (73, 95, 62, 85, 65, 85),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 71.2,
+            },
+            PokemonName::SMEARGLE => PokedexEntry {
+                base_stats: (55, 20, 35, 20, 45, 75),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 58.0,
+            },
+            PokemonName::TYROGUE => PokedexEntry {
+                base_stats: (35, 35, 35, 35, 35, 35),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 21.0,
+            },
+            PokemonName::HITMONTOP => PokedexEntry {
+                base_stats: (50, 95, 95, 35, 110, 70),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 48.0,
+            },
+            PokemonName::SMOOCHUM => PokedexEntry {
+                base_stats: (45, 30, 15, 85, 65, 65),
+                types: (PokemonType::ICE, PokemonType::PSYCHIC),
+                weight_kg: 6.0,
+            },
+            PokemonName::ELEKID => PokedexEntry {
+                base_stats: (45, 63, 37, 65, 55, 95),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 23.5,
+            },
+            PokemonName::MAGBY => PokedexEntry {
+                base_stats: (45, 75, 37, 70, 55, 83),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 21.4,
+            },
+            PokemonName::MILTANK => PokedexEntry {
+                base_stats: (95, 80, 105, 40, 70, 100),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 75.5,
+            },
+            PokemonName::BLISSEY => PokedexEntry {
+                base_stats: (255, 10, 10, 75, 135, 55),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 46.8,
+            },
+            PokemonName::RAIKOU => PokedexEntry {
+                base_stats: (90, 85, 75, 115, 100, 115),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 178.0,
+            },
+            PokemonName::ENTEI => PokedexEntry {
+                base_stats: (115, 115, 85, 90, 75, 100),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 198.0,
+            },
+            PokemonName::SUICUNE => PokedexEntry {
+                base_stats: (100, 75, 115, 90, 115, 85),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 187.0,
+            },
+            PokemonName::LARVITAR => PokedexEntry {
+                base_stats: (50, 64, 50, 45, 50, 41),
+                types: (PokemonType::ROCK, PokemonType::GROUND),
+                weight_kg: 72.0,
+            },
+            PokemonName::PUPITAR => PokedexEntry {
+                base_stats: (70, 84, 70, 65, 70, 51),
+                types: (PokemonType::ROCK, PokemonType::GROUND),
+                weight_kg: 152.0,
+            },
+            PokemonName::TYRANITAR => PokedexEntry {
+                base_stats: (100, 134, 110, 95, 100, 61),
+                types: (PokemonType::ROCK, PokemonType::DARK),
+                weight_kg: 202.0,
+            },
+            PokemonName::TYRANITARMEGA => PokedexEntry {
+                base_stats: (100, 164, 150, 95, 120, 71),
+                types: (PokemonType::ROCK, PokemonType::DARK),
+                weight_kg: 255.0,
+            },
+            PokemonName::LUGIA => PokedexEntry {
+                base_stats: (106, 90, 130, 90, 154, 110),
+                types: (PokemonType::PSYCHIC, PokemonType::FLYING),
+                weight_kg: 216.0,
+            },
+            PokemonName::HOOH => PokedexEntry {
+                base_stats: (106, 130, 90, 110, 154, 90),
+                types: (PokemonType::FIRE, PokemonType::FLYING),
+                weight_kg: 199.0,
+            },
+            PokemonName::CELEBI => PokedexEntry {
+                base_stats: (100, 100, 100, 100, 100, 100),
+                types: (PokemonType::PSYCHIC, PokemonType::GRASS),
+                weight_kg: 5.0,
+            },
+            PokemonName::TREECKO => PokedexEntry {
+                base_stats: (40, 45, 35, 65, 55, 70),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::GROVYLE => PokedexEntry {
+                base_stats: (50, 65, 45, 85, 65, 95),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 21.6,
+            },
+            PokemonName::SCEPTILE => PokedexEntry {
+                base_stats: (70, 85, 65, 105, 85, 120),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 52.2,
+            },
+            PokemonName::SCEPTILEMEGA => PokedexEntry {
+                base_stats: (70, 110, 75, 145, 85, 145),
+                types: (PokemonType::GRASS, PokemonType::DRAGON),
+                weight_kg: 55.2,
+            },
+            PokemonName::TORCHIC => PokedexEntry {
+                base_stats: (45, 60, 40, 70, 50, 45),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 2.5,
+            },
+            PokemonName::COMBUSKEN => PokedexEntry {
+                base_stats: (60, 85, 60, 85, 60, 55),
+                types: (PokemonType::FIRE, PokemonType::FIGHTING),
+                weight_kg: 19.5,
+            },
+            PokemonName::BLAZIKEN => PokedexEntry {
+                base_stats: (80, 120, 70, 110, 70, 80),
+                types: (PokemonType::FIRE, PokemonType::FIGHTING),
+                weight_kg: 52.0,
+            },
+            PokemonName::BLAZIKENMEGA => PokedexEntry {
+                base_stats: (80, 160, 80, 130, 80, 100),
+                types: (PokemonType::FIRE, PokemonType::FIGHTING),
+                weight_kg: 52.0,
+            },
+            PokemonName::MUDKIP => PokedexEntry {
+                base_stats: (50, 70, 50, 50, 50, 40),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 7.6,
+            },
+            PokemonName::MARSHTOMP => PokedexEntry {
+                base_stats: (70, 85, 70, 60, 70, 50),
+                types: (PokemonType::WATER, PokemonType::GROUND),
+                weight_kg: 28.0,
+            },
+            PokemonName::SWAMPERT => PokedexEntry {
+                base_stats: (100, 110, 90, 85, 90, 60),
+                types: (PokemonType::WATER, PokemonType::GROUND),
+                weight_kg: 81.9,
+            },
+            PokemonName::SWAMPERTMEGA => PokedexEntry {
+                base_stats: (100, 150, 110, 95, 110, 70),
+                types: (PokemonType::WATER, PokemonType::GROUND),
+                weight_kg: 102.0,
+            },
+            PokemonName::POOCHYENA => PokedexEntry {
+                base_stats: (35, 55, 35, 30, 30, 35),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 13.6,
+            },
+            PokemonName::MIGHTYENA => PokedexEntry {
+                base_stats: (70, 90, 70, 60, 60, 70),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 37.0,
+            },
+            PokemonName::ZIGZAGOON => PokedexEntry {
+                base_stats: (38, 30, 41, 30, 41, 60),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 17.5,
+            },
+            PokemonName::ZIGZAGOONGALAR => PokedexEntry {
+                base_stats: (38, 30, 41, 30, 41, 60),
+                types: (PokemonType::DARK, PokemonType::NORMAL),
+                weight_kg: 17.5,
+            },
+            PokemonName::LINOONE => PokedexEntry {
+                base_stats: (78, 70, 61, 50, 61, 100),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 32.5,
+            },
+            PokemonName::LINOONEGALAR => PokedexEntry {
+                base_stats: (78, 70, 61, 50, 61, 100),
+                types: (PokemonType::DARK, PokemonType::NORMAL),
+                weight_kg: 32.5,
+            },
+            PokemonName::WURMPLE => PokedexEntry {
+                base_stats: (45, 45, 35, 20, 30, 20),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 3.6,
+            },
+            PokemonName::SILCOON => PokedexEntry {
+                base_stats: (50, 35, 55, 25, 25, 15),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 10.0,
+            },
+            PokemonName::BEAUTIFLY => PokedexEntry {
+                base_stats: (60, 70, 50, 100, 50, 65),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 28.4,
+            },
+            PokemonName::CASCOON => PokedexEntry {
+                base_stats: (50, 35, 55, 25, 25, 15),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 11.5,
+            },
+            PokemonName::DUSTOX => PokedexEntry {
+                base_stats: (60, 50, 70, 50, 90, 65),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 31.6,
+            },
+            PokemonName::LOTAD => PokedexEntry {
+                base_stats: (40, 30, 30, 40, 50, 30),
+                types: (PokemonType::WATER, PokemonType::GRASS),
+                weight_kg: 2.6,
+            },
+            PokemonName::LOMBRE => PokedexEntry {
+                base_stats: (60, 50, 50, 60, 70, 50),
+                types: (PokemonType::WATER, PokemonType::GRASS),
+                weight_kg: 32.5,
+            },
+            PokemonName::LUDICOLO => PokedexEntry {
+                base_stats: (80, 70, 70, 90, 100, 70),
+                types: (PokemonType::WATER, PokemonType::GRASS),
+                weight_kg: 55.0,
+            },
+            PokemonName::SEEDOT => PokedexEntry {
+                base_stats: (40, 40, 50, 30, 30, 30),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 4.0,
+            },
+            PokemonName::NUZLEAF => PokedexEntry {
+                base_stats: (70, 70, 40, 60, 40, 60),
+                types: (PokemonType::GRASS, PokemonType::DARK),
+                weight_kg: 28.0,
+            },
+            PokemonName::SHIFTRY => PokedexEntry {
+                base_stats: (90, 100, 60, 90, 60, 80),
+                types: (PokemonType::GRASS, PokemonType::DARK),
+                weight_kg: 59.6,
+            },
+            PokemonName::TAILLOW => PokedexEntry {
+                base_stats: (40, 55, 30, 30, 30, 85),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 2.3,
+            },
+            PokemonName::SWELLOW => PokedexEntry {
+                base_stats: (60, 85, 60, 75, 50, 125),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 19.8,
+            },
+            PokemonName::WINGULL => PokedexEntry {
+                base_stats: (40, 30, 30, 55, 30, 85),
+                types: (PokemonType::WATER, PokemonType::FLYING),
+                weight_kg: 9.5,
+            },
+            PokemonName::PELIPPER => PokedexEntry {
+                base_stats: (60, 50, 100, 95, 70, 65),
+                types: (PokemonType::WATER, PokemonType::FLYING),
+                weight_kg: 28.0,
+            },
+            PokemonName::RALTS => PokedexEntry {
+                base_stats: (28, 25, 25, 45, 35, 40),
+                types: (PokemonType::PSYCHIC, PokemonType::FAIRY),
+                weight_kg: 6.6,
+            },
+            PokemonName::KIRLIA => PokedexEntry {
+                base_stats: (38, 35, 35, 65, 55, 50),
+                types: (PokemonType::PSYCHIC, PokemonType::FAIRY),
+                weight_kg: 20.2,
+            },
+            PokemonName::GARDEVOIR => PokedexEntry {
+                base_stats: (68, 65, 65, 125, 115, 80),
+                types: (PokemonType::PSYCHIC, PokemonType::FAIRY),
+                weight_kg: 48.4,
+            },
+            PokemonName::GARDEVOIRMEGA => PokedexEntry {
+                base_stats: (68, 85, 65, 165, 135, 100),
+                types: (PokemonType::PSYCHIC, PokemonType::FAIRY),
+                weight_kg: 48.4,
+            },
+            PokemonName::SURSKIT => PokedexEntry {
+                base_stats: (40, 30, 32, 50, 52, 65),
+                types: (PokemonType::BUG, PokemonType::WATER),
+                weight_kg: 1.7,
+            },
+            PokemonName::MASQUERAIN => PokedexEntry {
+                base_stats: (70, 60, 62, 100, 82, 80),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 3.6,
+            },
+            PokemonName::SHROOMISH => PokedexEntry {
+                base_stats: (60, 40, 60, 40, 60, 35),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 4.5,
+            },
+            PokemonName::BRELOOM => PokedexEntry {
+                base_stats: (60, 130, 80, 60, 60, 70),
+                types: (PokemonType::GRASS, PokemonType::FIGHTING),
+                weight_kg: 39.2,
+            },
+            PokemonName::SLAKOTH => PokedexEntry {
+                base_stats: (60, 60, 60, 35, 35, 30),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 24.0,
+            },
+            PokemonName::VIGOROTH => PokedexEntry {
+                base_stats: (80, 80, 80, 55, 55, 90),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 46.5,
+            },
+            PokemonName::SLAKING => PokedexEntry {
+                base_stats: (150, 160, 100, 95, 65, 100),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 130.5,
+            },
+            PokemonName::NINCADA => PokedexEntry {
+                base_stats: (31, 45, 90, 30, 30, 40),
+                types: (PokemonType::BUG, PokemonType::GROUND),
+                weight_kg: 5.5,
+            },
+            PokemonName::NINJASK => PokedexEntry {
+                base_stats: (61, 90, 45, 50, 50, 160),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 12.0,
+            },
+            PokemonName::SHEDINJA => PokedexEntry {
+                base_stats: (1, 90, 45, 30, 30, 40),
+                types: (PokemonType::BUG, PokemonType::GHOST),
+                weight_kg: 1.2,
+            },
+            PokemonName::WHISMUR => PokedexEntry {
+                base_stats: (64, 51, 23, 51, 23, 28),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 16.3,
+            },
+            PokemonName::LOUDRED => PokedexEntry {
+                base_stats: (84, 71, 43, 71, 43, 48),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 40.5,
+            },
+            PokemonName::EXPLOUD => PokedexEntry {
+                base_stats: (104, 91, 63, 91, 73, 68),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 84.0,
+            },
+            PokemonName::MAKUHITA => PokedexEntry {
+                base_stats: (72, 60, 30, 20, 30, 25),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 86.4,
+            },
+            PokemonName::HARIYAMA => PokedexEntry {
+                base_stats: (144, 120, 60, 40, 60, 50),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 253.8,
+            },
+            PokemonName::AZURILL => PokedexEntry {
+                base_stats: (50, 20, 40, 20, 40, 20),
+                types: (PokemonType::NORMAL, PokemonType::FAIRY),
+                weight_kg: 2.0,
+            },
+            PokemonName::NOSEPASS => PokedexEntry {
+                base_stats: (30, 45, 135, 45, 90, 30),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 97.0,
+            },
+            PokemonName::SKITTY => PokedexEntry {
+                base_stats: (50, 45, 45, 35, 35, 50),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 11.0,
+            },
+            PokemonName::DELCATTY => PokedexEntry {
+                base_stats: (70, 65, 65, 55, 55, 90),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 32.6,
+            },
+            PokemonName::SABLEYE => PokedexEntry {
+                base_stats: (50, 75, 75, 65, 65, 50),
+                types: (PokemonType::DARK, PokemonType::GHOST),
+                weight_kg: 11.0,
+            },
+            PokemonName::SABLEYEMEGA => PokedexEntry {
+                base_stats: (50, 85, 125, 85, 115, 20),
+                types: (PokemonType::DARK, PokemonType::GHOST),
+                weight_kg: 161.0,
+            },
+            PokemonName::MAWILE => PokedexEntry {
+                base_stats: (50, 85, 85, 55, 55, 50),
+                types: (PokemonType::STEEL, PokemonType::FAIRY),
+                weight_kg: 11.5,
+            },
+            PokemonName::MAWILEMEGA => PokedexEntry {
+                base_stats: (50, 105, 125, 55, 95, 50),
+                types: (PokemonType::STEEL, PokemonType::FAIRY),
+                weight_kg: 23.5,
+            },
+            PokemonName::ARON => PokedexEntry {
+                base_stats: (50, 70, 100, 40, 40, 30),
+                types: (PokemonType::STEEL, PokemonType::ROCK),
+                weight_kg: 60.0,
+            },
+            PokemonName::LAIRON => PokedexEntry {
+                base_stats: (60, 90, 140, 50, 50, 40),
+                types: (PokemonType::STEEL, PokemonType::ROCK),
+                weight_kg: 120.0,
+            },
+            PokemonName::AGGRON => PokedexEntry {
+                base_stats: (70, 110, 180, 60, 60, 50),
+                types: (PokemonType::STEEL, PokemonType::ROCK),
+                weight_kg: 360.0,
+            },
+            PokemonName::AGGRONMEGA => PokedexEntry {
+                base_stats: (70, 140, 230, 60, 80, 50),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 395.0,
+            },
+            PokemonName::MEDITITE => PokedexEntry {
+                base_stats: (30, 40, 55, 40, 55, 60),
+                types: (PokemonType::FIGHTING, PokemonType::PSYCHIC),
+                weight_kg: 11.2,
+            },
+            PokemonName::MEDICHAM => PokedexEntry {
+                base_stats: (60, 60, 75, 60, 75, 80),
+                types: (PokemonType::FIGHTING, PokemonType::PSYCHIC),
+                weight_kg: 31.5,
+            },
+            PokemonName::MEDICHAMMEGA => PokedexEntry {
+                base_stats: (60, 100, 85, 80, 85, 100),
+                types: (PokemonType::FIGHTING, PokemonType::PSYCHIC),
+                weight_kg: 31.5,
+            },
+            PokemonName::ELECTRIKE => PokedexEntry {
+                base_stats: (40, 45, 40, 65, 40, 65),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 15.2,
+            },
+            PokemonName::MANECTRIC => PokedexEntry {
+                base_stats: (70, 75, 60, 105, 60, 105),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 40.2,
+            },
+            PokemonName::MANECTRICMEGA => PokedexEntry {
+                base_stats: (70, 75, 80, 135, 80, 135),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 44.0,
+            },
+            PokemonName::PLUSLE => PokedexEntry {
+                base_stats: (60, 50, 40, 85, 75, 95),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 4.2,
+            },
+            PokemonName::MINUN => PokedexEntry {
+                base_stats: (60, 40, 50, 75, 85, 95),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 4.2,
+            },
+            PokemonName::VOLBEAT => PokedexEntry {
+                base_stats: (65, 73, 75, 47, 85, 85),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 17.7,
+            },
+            PokemonName::ILLUMISE => PokedexEntry {
+                base_stats: (65, 47, 75, 73, 85, 85),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 17.7,
+            },
+            PokemonName::ROSELIA => PokedexEntry {
+                base_stats: (50, 60, 45, 100, 80, 65),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 2.0,
+            },
+            PokemonName::GULPIN => PokedexEntry {
+                base_stats: (70, 43, 53, 43, 53, 40),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 10.3,
+            },
+            PokemonName::SWALOT => PokedexEntry {
+                base_stats: (100, 73, 83, 73, 83, 55),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 80.0,
+            },
+            PokemonName::CARVANHA => PokedexEntry {
+                base_stats: (45, 90, 20, 65, 20, 65),
+                types: (PokemonType::WATER, PokemonType::DARK),
+                weight_kg: 20.8,
+            },
+            PokemonName::SHARPEDO => PokedexEntry {
+                base_stats: (70, 120, 40, 95, 40, 95),
+                types: (PokemonType::WATER, PokemonType::DARK),
+                weight_kg: 88.8,
+            },
+            PokemonName::SHARPEDOMEGA => PokedexEntry {
+                base_stats: (70, 140, 70, 110, 65, 105),
+                types: (PokemonType::WATER, PokemonType::DARK),
+                weight_kg: 130.3,
+            },
+            PokemonName::WAILMER => PokedexEntry {
+                base_stats: (130, 70, 35, 70, 35, 60),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 130.0,
+            },
+            PokemonName::WAILORD => PokedexEntry {
+                base_stats: (170, 90, 45, 90, 45, 60),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 398.0,
+            },
+            PokemonName::NUMEL => PokedexEntry {
+                base_stats: (60, 60, 40, 65, 45, 35),
+                types: (PokemonType::FIRE, PokemonType::GROUND),
+                weight_kg: 24.0,
+            },
+            PokemonName::CAMERUPT => PokedexEntry {
+                base_stats: (70, 100, 70, 105, 75, 40),
+                types: (PokemonType::FIRE, PokemonType::GROUND),
+                weight_kg: 220.0,
+            },
+            PokemonName::CAMERUPTMEGA => PokedexEntry {
+                base_stats: (70, 120, 100, 145, 105, 20),
+                types: (PokemonType::FIRE, PokemonType::GROUND),
+                weight_kg: 320.5,
+            },
+            PokemonName::TORKOAL => PokedexEntry {
+                base_stats: (70, 85, 140, 85, 70, 20),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 80.4,
+            },
+            PokemonName::SPOINK => PokedexEntry {
+                base_stats: (60, 25, 35, 70, 80, 60),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 30.6,
+            },
+            PokemonName::GRUMPIG => PokedexEntry {
+                base_stats: (80, 45, 65, 90, 110, 80),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 71.5,
+            },
+            PokemonName::SPINDA => PokedexEntry {
+                base_stats: (60, 60, 60, 60, 60, 60),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::TRAPINCH => PokedexEntry {
+                base_stats: (45, 100, 45, 45, 45, 10),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 15.0,
+            },
+            PokemonName::VIBRAVA => PokedexEntry {
+                base_stats: (50, 70, 50, 50, 50, 70),
+                types: (PokemonType::GROUND, PokemonType::DRAGON),
+                weight_kg: 15.3,
+            },
+            PokemonName::FLYGON => PokedexEntry {
+                base_stats: (80, 100, 80, 80, 80, 100),
+                types: (PokemonType::GROUND, PokemonType::DRAGON),
+                weight_kg: 82.0,
+            },
+            PokemonName::CACNEA => PokedexEntry {
+                base_stats: (50, 85, 40, 85, 40, 35),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 51.3,
+            },
+            PokemonName::CACTURNE => PokedexEntry {
+                base_stats: (70, 115, 60, 115, 60, 55),
+                types: (PokemonType::GRASS, PokemonType::DARK),
+                weight_kg: 77.4,
+            },
+            PokemonName::SWABLU => PokedexEntry {
+                base_stats: (45, 40, 60, 40, 75, 50),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 1.2,
+            },
+            PokemonName::ALTARIA => PokedexEntry {
+                base_stats: (75, 70, 90, 70, 105, 80),
+                types: (PokemonType::DRAGON, PokemonType::FLYING),
+                weight_kg: 20.6,
+            },
+            PokemonName::ALTARIAMEGA => PokedexEntry {
+                base_stats: (75, 110, 110, 110, 105, 80),
+                types: (PokemonType::DRAGON, PokemonType::FAIRY),
+                weight_kg: 20.6,
+            },
+            PokemonName::ZANGOOSE => PokedexEntry {
+                base_stats: (73, 115, 60, 60, 60, 90),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 40.3,
+            },
+            PokemonName::SEVIPER => PokedexEntry {
+                base_stats: (73, 100, 60, 100, 60, 65),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 52.5,
+            },
+            PokemonName::LUNATONE => PokedexEntry {
+                base_stats: (90, 55, 65, 95, 85, 70),
+                types: (PokemonType::ROCK, PokemonType::PSYCHIC),
+                weight_kg: 168.0,
+            },
+            PokemonName::SOLROCK => PokedexEntry {
+                base_stats: (90, 95, 85, 55, 65, 70),
+                types: (PokemonType::ROCK, PokemonType::PSYCHIC),
+                weight_kg: 154.0,
+            },
+            PokemonName::BARBOACH => PokedexEntry {
+                base_stats: (50, 48, 43, 46, 41, 60),
+                types: (PokemonType::WATER, PokemonType::GROUND),
+                weight_kg: 1.9,
+            },
+            PokemonName::WHISCASH => PokedexEntry {
+                base_stats: (110, 78, 73, 76, 71, 60),
+                types: (PokemonType::WATER, PokemonType::GROUND),
+                weight_kg: 23.6,
+            },
+            PokemonName::CORPHISH => PokedexEntry {
+                base_stats: (43, 80, 65, 50, 35, 35),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 11.5,
+            },
+            PokemonName::CRAWDAUNT => PokedexEntry {
+                base_stats: (63, 120, 85, 90, 55, 55),
+                types: (PokemonType::WATER, PokemonType::DARK),
+                weight_kg: 32.8,
+            },
+            PokemonName::BALTOY => PokedexEntry {
+                base_stats: (40, 40, 55, 40, 70, 55),
+                types: (PokemonType::GROUND, PokemonType::PSYCHIC),
+                weight_kg: 21.5,
+            },
+            PokemonName::CLAYDOL => PokedexEntry {
+                base_stats: (60, 70, 105, 70, 120, 75),
+                types: (PokemonType::GROUND, PokemonType::PSYCHIC),
+                weight_kg: 108.0,
+            },
+            PokemonName::LILEEP => PokedexEntry {
+                base_stats: (66, 41, 77, 61, 87, 23),
+                types: (PokemonType::ROCK, PokemonType::GRASS),
+                weight_kg: 23.8,
+            },
+            PokemonName::CRADILY => PokedexEntry {
+                base_stats: (86, 81, 97, 81, 107, 43),
+                types: (PokemonType::ROCK, PokemonType::GRASS),
+                weight_kg: 60.4,
+            },
+            PokemonName::ANORITH => PokedexEntry {
+                base_stats: (45, 95, 50, 40, 50, 75),
+                types: (PokemonType::ROCK, PokemonType::BUG),
+                weight_kg: 12.5,
+            },
+            PokemonName::ARMALDO => PokedexEntry {
+                base_stats: (75, 125, 100, 70, 80, 45),
+                types: (PokemonType::ROCK, PokemonType::BUG),
+                weight_kg: 68.2,
+            },
+            PokemonName::FEEBAS => PokedexEntry {
+                base_stats: (20, 15, 20, 10, 55, 80),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 7.4,
+            },
+            PokemonName::MILOTIC => PokedexEntry {
+                base_stats: (95, 60, 79, 100, 125, 81),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 162.0,
+            },
+            PokemonName::CASTFORM => PokedexEntry {
+                base_stats: (70, 70, 70, 70, 70, 70),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 0.8,
+            },
+            PokemonName::CASTFORMSUNNY => PokedexEntry {
+                base_stats: (70, 70, 70, 70, 70, 70),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 0.8,
+            },
+            PokemonName::CASTFORMRAINY => PokedexEntry {
+                base_stats: (70, 70, 70, 70, 70, 70),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 0.8,
+            },
+            PokemonName::CASTFORMSNOWY => PokedexEntry {
+                base_stats: (70, 70, 70, 70, 70, 70),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 0.8,
+            },
+            PokemonName::KECLEON => PokedexEntry {
+                base_stats: (60, 90, 70, 60, 120, 40),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 22.0,
+            },
+            PokemonName::SHUPPET => PokedexEntry {
+                base_stats: (44, 75, 35, 63, 33, 45),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 2.3,
+            },
+            PokemonName::BANETTE => PokedexEntry {
+                base_stats: (64, 115, 65, 83, 63, 65),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 12.5,
+            },
+            PokemonName::BANETTEMEGA => PokedexEntry {
+                base_stats: (64, 165, 75, 93, 83, 75),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 13.0,
+            },
+            PokemonName::DUSKULL => PokedexEntry {
+                base_stats: (20, 40, 90, 30, 90, 25),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 15.0,
+            },
+            PokemonName::DUSCLOPS => PokedexEntry {
+                base_stats: (40, 70, 130, 60, 130, 25),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 30.6,
+            },
+            PokemonName::TROPIUS => PokedexEntry {
+                base_stats: (99, 68, 83, 72, 87, 51),
+                types: (PokemonType::GRASS, PokemonType::FLYING),
+                weight_kg: 100.0,
+            },
+            PokemonName::CHIMECHO => PokedexEntry {
+                base_stats: (75, 50, 80, 95, 90, 65),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 1.0,
+            },
+            PokemonName::ABSOL => PokedexEntry {
+                base_stats: (65, 130, 60, 75, 60, 75),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 47.0,
+            },
+            PokemonName::ABSOLMEGA => PokedexEntry {
+                base_stats: (65, 150, 60, 115, 60, 115),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 49.0,
+            },
+            PokemonName::WYNAUT => PokedexEntry {
+                base_stats: (95, 23, 48, 23, 48, 23),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 14.0,
+            },
+            PokemonName::SNORUNT => PokedexEntry {
+                base_stats: (50, 50, 50, 50, 50, 50),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 16.8,
+            },
+            PokemonName::GLALIE => PokedexEntry {
+                base_stats: (80, 80, 80, 80, 80, 80),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 256.5,
+            },
+            PokemonName::GLALIEMEGA => PokedexEntry {
+                base_stats: (80, 120, 80, 120, 80, 100),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 350.2,
+            },
+            PokemonName::SPHEAL => PokedexEntry {
+                base_stats: (70, 40, 50, 55, 50, 25),
+                types: (PokemonType::ICE, PokemonType::WATER),
+                weight_kg: 39.5,
+            },
+            PokemonName::SEALEO => PokedexEntry {
+                base_stats: (90, 60, 70, 75, 70, 45),
+                types: (PokemonType::ICE, PokemonType::WATER),
+                weight_kg: 87.6,
+            },
+            PokemonName::WALREIN => PokedexEntry {
+                base_stats: (110, 80, 90, 95, 90, 65),
+                types: (PokemonType::ICE, PokemonType::WATER),
+                weight_kg: 150.6,
+            },
+            PokemonName::CLAMPERL => PokedexEntry {
+                base_stats: (35, 64, 85, 74, 55, 32),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 52.5,
+            },
+            PokemonName::HUNTAIL => PokedexEntry {
+                base_stats: (55, 104, 105, 94, 75, 52),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 27.0,
+            },
+            PokemonName::GOREBYSS => PokedexEntry {
+                base_stats: (55, 84, 105, 114, 75, 52),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 22.6,
+            },
+            PokemonName::RELICANTH => PokedexEntry {
+                base_stats: (100, 90, 130, 45, 65, 55),
+                types: (PokemonType::WATER, PokemonType::ROCK),
+                weight_kg: 23.4,
+            },
+            PokemonName::LUVDISC => PokedexEntry {
+                base_stats: (43, 30, 55, 40, 65, 97),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 8.7,
+            },
+            PokemonName::BAGON => PokedexEntry {
+                base_stats: (45, 75, 60, 40, 30, 50),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 42.1,
+            },
+            PokemonName::SHELGON => PokedexEntry {
+                base_stats: (65, 95, 100, 60, 50, 50),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 110.5,
+            },
+            PokemonName::SALAMENCE => PokedexEntry {
+                base_stats: (95, 135, 80, 110, 80, 100),
+                types: (PokemonType::DRAGON, PokemonType::FLYING),
+                weight_kg: 102.6,
+            },
+            PokemonName::SALAMENCEMEGA => PokedexEntry {
+                base_stats: (95, 145, 130, 120, 90, 120),
+                types: (PokemonType::DRAGON, PokemonType::FLYING),
+                weight_kg: 112.6,
+            },
+            PokemonName::BELDUM => PokedexEntry {
+                base_stats: (40, 55, 80, 35, 60, 30),
+                types: (PokemonType::STEEL, PokemonType::PSYCHIC),
+                weight_kg: 95.2,
+            },
+            PokemonName::METANG => PokedexEntry {
+                base_stats: (60, 75, 100, 55, 80, 50),
+                types: (PokemonType::STEEL, PokemonType::PSYCHIC),
+                weight_kg: 202.5,
+            },
+            PokemonName::METAGROSS => PokedexEntry {
+                base_stats: (80, 135, 130, 95, 90, 70),
+                types: (PokemonType::STEEL, PokemonType::PSYCHIC),
+                weight_kg: 550.0,
+            },
+            PokemonName::METAGROSSMEGA => PokedexEntry {
+                base_stats: (80, 145, 150, 105, 110, 110),
+                types: (PokemonType::STEEL, PokemonType::PSYCHIC),
+                weight_kg: 942.9,
+            },
+            PokemonName::REGIROCK => PokedexEntry {
+                base_stats: (80, 100, 200, 50, 100, 50),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 230.0,
+            },
+            PokemonName::REGICE => PokedexEntry {
+                base_stats: (80, 50, 100, 100, 200, 50),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 175.0,
+            },
+            PokemonName::REGISTEEL => PokedexEntry {
+                base_stats: (80, 75, 150, 75, 150, 50),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 205.0,
+            },
+            PokemonName::LATIAS => PokedexEntry {
+                base_stats: (80, 80, 90, 110, 130, 110),
+                types: (PokemonType::DRAGON, PokemonType::PSYCHIC),
+                weight_kg: 40.0,
+            },
+            PokemonName::LATIASMEGA => PokedexEntry {
+                base_stats: (80, 100, 120, 140, 150, 110),
+                types: (PokemonType::DRAGON, PokemonType::PSYCHIC),
+                weight_kg: 52.0,
+            },
+            PokemonName::LATIOS => PokedexEntry {
+                base_stats: (80, 90, 80, 130, 110, 110),
+                types: (PokemonType::DRAGON, PokemonType::PSYCHIC),
+                weight_kg: 60.0,
+            },
+            PokemonName::LATIOSMEGA => PokedexEntry {
+                base_stats: (80, 130, 100, 160, 120, 110),
+                types: (PokemonType::DRAGON, PokemonType::PSYCHIC),
+                weight_kg: 70.0,
+            },
+            PokemonName::KYOGRE => PokedexEntry {
+                base_stats: (100, 100, 90, 150, 140, 90),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 352.0,
+            },
+            PokemonName::KYOGREPRIMAL => PokedexEntry {
+                base_stats: (100, 150, 90, 180, 160, 90),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 430.0,
+            },
+            PokemonName::GROUDON => PokedexEntry {
+                base_stats: (100, 150, 140, 100, 90, 90),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 950.0,
+            },
+            PokemonName::GROUDONPRIMAL => PokedexEntry {
+                base_stats: (100, 180, 160, 150, 90, 90),
+                types: (PokemonType::GROUND, PokemonType::FIRE),
+                weight_kg: 999.7,
+            },
+            PokemonName::RAYQUAZA => PokedexEntry {
+                base_stats: (105, 150, 90, 150, 90, 95),
+                types: (PokemonType::DRAGON, PokemonType::FLYING),
+                weight_kg: 206.5,
+            },
+            PokemonName::RAYQUAZAMEGA => PokedexEntry {
+                base_stats: (105, 180, 100, 180, 100, 115),
+                types: (PokemonType::DRAGON, PokemonType::FLYING),
+                weight_kg: 392.0,
+            },
+            PokemonName::JIRACHI => PokedexEntry {
+                base_stats: (100, 100, 100, 100, 100, 100),
+                types: (PokemonType::STEEL, PokemonType::PSYCHIC),
+                weight_kg: 1.1,
+            },
+            PokemonName::DEOXYS => PokedexEntry {
+                base_stats: (50, 150, 50, 150, 50, 150),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 60.8,
+            },
+            PokemonName::DEOXYSATTACK => PokedexEntry {
+                base_stats: (50, 180, 20, 180, 20, 150),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 60.8,
+            },
+            PokemonName::DEOXYSDEFENSE => PokedexEntry {
+                base_stats: (50, 70, 160, 70, 160, 90),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 60.8,
+            },
+            PokemonName::DEOXYSSPEED => PokedexEntry {
+                base_stats: (50, 95, 90, 95, 90, 180),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 60.8,
+            },
+            PokemonName::TURTWIG => PokedexEntry {
+                base_stats: (55, 68, 64, 45, 55, 31),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 10.2,
+            },
+            PokemonName::GROTLE => PokedexEntry {
+                base_stats: (75, 89, 85, 55, 65, 36),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 97.0,
+            },
+            PokemonName::TORTERRA => PokedexEntry {
+                base_stats: (95, 109, 105, 75, 85, 56),
+                types: (PokemonType::GRASS, PokemonType::GROUND),
+                weight_kg: 310.0,
+            },
+            PokemonName::CHIMCHAR => PokedexEntry {
+                base_stats: (44, 58, 44, 58, 44, 61),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 6.2,
+            },
+            PokemonName::MONFERNO => PokedexEntry {
+                base_stats: (64, 78, 52, 78, 52, 81),
+                types: (PokemonType::FIRE, PokemonType::FIGHTING),
+                weight_kg: 22.0,
+            },
+            PokemonName::INFERNAPE => PokedexEntry {
+                base_stats: (76, 104, 71, 104, 71, 108),
+                types: (PokemonType::FIRE, PokemonType::FIGHTING),
+                weight_kg: 55.0,
+            },
+            PokemonName::PIPLUP => PokedexEntry {
+                base_stats: (53, 51, 53, 61, 56, 40),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 5.2,
+            },
+            PokemonName::PRINPLUP => PokedexEntry {
+                base_stats: (64, 66, 68, 81, 76, 50),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 23.0,
+            },
+            PokemonName::EMPOLEON => PokedexEntry {
+                base_stats: (84, 86, 88, 111, 101, 60),
+                types: (PokemonType::WATER, PokemonType::STEEL),
+                weight_kg: 84.5,
+            },
+            PokemonName::STARLY => PokedexEntry {
+                base_stats: (40, 55, 30, 30, 30, 60),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 2.0,
+            },
+            PokemonName::STARAVIA => PokedexEntry {
+                base_stats: (55, 75, 50, 40, 40, 80),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 15.5,
+            },
+            PokemonName::STARAPTOR => PokedexEntry {
+                base_stats: (85, 120, 70, 50, 60, 100),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 24.9,
+            },
+            PokemonName::BIDOOF => PokedexEntry {
+                base_stats: (59, 45, 40, 35, 40, 31),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 20.0,
+            },
+            PokemonName::BIBAREL => PokedexEntry {
+                base_stats: (79, 85, 60, 55, 60, 71),
+                types: (PokemonType::NORMAL, PokemonType::WATER),
+                weight_kg: 31.5,
+            },
+            PokemonName::KRICKETOT => PokedexEntry {
+                base_stats: (37, 25, 41, 25, 41, 25),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 2.2,
+            },
+            PokemonName::KRICKETUNE => PokedexEntry {
+                base_stats: (77, 85, 51, 55, 51, 65),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 25.5,
+            },
+            PokemonName::SHINX => PokedexEntry {
+                base_stats: (45, 65, 34, 40, 34, 45),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 9.5,
+            },
+            PokemonName::LUXIO => PokedexEntry {
+                base_stats: (60, 85, 49, 60, 49, 60),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 30.5,
+            },
+            PokemonName::LUXRAY => PokedexEntry {
+                base_stats: (80, 120, 79, 95, 79, 70),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 42.0,
+            },
+            PokemonName::BUDEW => PokedexEntry {
+                base_stats: (40, 30, 35, 50, 70, 55),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 1.2,
+            },
+            PokemonName::ROSERADE => PokedexEntry {
+                base_stats: (60, 70, 65, 125, 105, 90),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 14.5,
+            },
+            PokemonName::CRANIDOS => PokedexEntry {
+                base_stats: (67, 125, 40, 30, 30, 58),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 31.5,
+            },
+            PokemonName::RAMPARDOS => PokedexEntry {
+                base_stats: (97, 165, 60, 65, 50, 58),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 102.5,
+            },
+            PokemonName::SHIELDON => PokedexEntry {
+                base_stats: (30, 42, 118, 42, 88, 30),
+                types: (PokemonType::ROCK, PokemonType::STEEL),
+                weight_kg: 57.0,
+            },
+            PokemonName::BASTIODON => PokedexEntry {
+                base_stats: (60, 52, 168, 47, 138, 30),
+                types: (PokemonType::ROCK, PokemonType::STEEL),
+                weight_kg: 149.5,
+            },
+            PokemonName::BURMY => PokedexEntry {
+                base_stats: (40, 29, 45, 29, 45, 36),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 3.4,
+            },
+            PokemonName::BURMYSANDY => PokedexEntry {
+                base_stats: (40, 29, 45, 29, 45, 36),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 3.4,
+            },
+            PokemonName::BURMYTRASH => PokedexEntry {
+                base_stats: (40, 29, 45, 29, 45, 36),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 3.4,
+            },
+            PokemonName::WORMADAM => PokedexEntry {
+                base_stats: (60, 59, 85, 79, 105, 36),
+                types: (PokemonType::BUG, PokemonType::GRASS),
+                weight_kg: 6.5,
+            },
+            PokemonName::WORMADAMSANDY => PokedexEntry {
+                base_stats: (60, 79, 105, 59, 85, 36),
+                types: (PokemonType::BUG, PokemonType::GROUND),
+                weight_kg: 6.5,
+            },
+            PokemonName::WORMADAMTRASH => PokedexEntry {
+                base_stats: (60, 69, 95, 69, 95, 36),
+                types: (PokemonType::BUG, PokemonType::STEEL),
+                weight_kg: 6.5,
+            },
+            PokemonName::MOTHIM => PokedexEntry {
+                base_stats: (70, 94, 50, 94, 50, 66),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 23.3,
+            },
+            PokemonName::COMBEE => PokedexEntry {
+                base_stats: (30, 30, 42, 30, 42, 70),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 5.5,
+            },
+            PokemonName::VESPIQUEN => PokedexEntry {
+                base_stats: (70, 80, 102, 80, 102, 40),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 38.5,
+            },
+            PokemonName::PACHIRISU => PokedexEntry {
+                base_stats: (60, 45, 70, 45, 90, 95),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 3.9,
+            },
+            PokemonName::BUIZEL => PokedexEntry {
+                base_stats: (55, 65, 35, 60, 30, 85),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 29.5,
+            },
+            PokemonName::FLOATZEL => PokedexEntry {
+                base_stats: (85, 105, 55, 85, 50, 115),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 33.5,
+            },
+            PokemonName::CHERUBI => PokedexEntry {
+                base_stats: (45, 35, 45, 62, 53, 35),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 3.3,
+            },
+            PokemonName::CHERRIM => PokedexEntry {
+                base_stats: (70, 60, 70, 87, 78, 85),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 9.3,
+            },
+            PokemonName::CHERRIMSUNSHINE => PokedexEntry {
+                base_stats: (70, 60, 70, 87, 78, 85),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 9.3,
+            },
+            PokemonName::SHELLOS => PokedexEntry {
+                base_stats: (76, 48, 48, 57, 62, 34),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 6.3,
+            },
+            PokemonName::SHELLOSEAST => PokedexEntry {
+                base_stats: (76, 48, 48, 57, 62, 34),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 6.3,
+            },
+            PokemonName::GASTRODON => PokedexEntry {
+                base_stats: (111, 83, 68, 92, 82, 39),
+                types: (PokemonType::WATER, PokemonType::GROUND),
+                weight_kg: 29.9,
+            },
+            PokemonName::GASTRODONEAST => PokedexEntry {
+                base_stats: (111, 83, 68, 92, 82, 39),
+                types: (PokemonType::WATER, PokemonType::GROUND),
+                weight_kg: 29.9,
+            },
+            PokemonName::AMBIPOM => PokedexEntry {
+                base_stats: (75, 100, 66, 60, 66, 115),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 20.3,
+            },
+            PokemonName::DRIFLOON => PokedexEntry {
+                base_stats: (90, 50, 34, 60, 44, 70),
+                types: (PokemonType::GHOST, PokemonType::FLYING),
+                weight_kg: 1.2,
+            },
+            PokemonName::DRIFBLIM => PokedexEntry {
+                base_stats: (150, 80, 44, 90, 54, 80),
+                types: (PokemonType::GHOST, PokemonType::FLYING),
+                weight_kg: 15.0,
+            },
+            PokemonName::BUNEARY => PokedexEntry {
+                base_stats: (55, 66, 44, 44, 56, 85),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 5.5,
+            },
+            PokemonName::LOPUNNY => PokedexEntry {
+                base_stats: (65, 76, 84, 54, 96, 105),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 33.3,
+            },
+            PokemonName::LOPUNNYMEGA => PokedexEntry {
+                base_stats: (65, 136, 94, 54, 96, 135),
+                types: (PokemonType::NORMAL, PokemonType::FIGHTING),
+                weight_kg: 28.3,
+            },
+            PokemonName::MISMAGIUS => PokedexEntry {
+                base_stats: (60, 60, 60, 105, 105, 105),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 4.4,
+            },
+            PokemonName::HONCHKROW => PokedexEntry {
+                base_stats: (100, 125, 52, 105, 52, 71),
+                types: (PokemonType::DARK, PokemonType::FLYING),
+                weight_kg: 27.3,
+            },
+            PokemonName::GLAMEOW => PokedexEntry {
+                base_stats: (49, 55, 42, 42, 37, 85),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 3.9,
+            },
+            PokemonName::PURUGLY => PokedexEntry {
+                base_stats: (71, 82, 64, 64, 59, 112),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 43.8,
+            },
+            PokemonName::CHINGLING => PokedexEntry {
+                base_stats: (45, 30, 50, 65, 50, 45),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 0.6,
+            },
+            PokemonName::STUNKY => PokedexEntry {
+                base_stats: (63, 63, 47, 41, 41, 74),
+                types: (PokemonType::POISON, PokemonType::DARK),
+                weight_kg: 19.2,
+            },
+            PokemonName::SKUNTANK => PokedexEntry {
+                base_stats: (103, 93, 67, 71, 61, 84),
+                types: (PokemonType::POISON, PokemonType::DARK),
+                weight_kg: 38.0,
+            },
+            PokemonName::BRONZOR => PokedexEntry {
+                base_stats: (57, 24, 86, 24, 86, 23),
+                types: (PokemonType::STEEL, PokemonType::PSYCHIC),
+                weight_kg: 60.5,
+            },
+            PokemonName::BRONZONG => PokedexEntry {
+                base_stats: (67, 89, 116, 79, 116, 33),
+                types: (PokemonType::STEEL, PokemonType::PSYCHIC),
+                weight_kg: 187.0,
+            },
+            PokemonName::BONSLY => PokedexEntry {
+                base_stats: (50, 80, 95, 10, 45, 10),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 15.0,
+            },
+            PokemonName::MIMEJR => PokedexEntry {
+                base_stats: (20, 25, 45, 70, 90, 60),
+                types: (PokemonType::PSYCHIC, PokemonType::FAIRY),
+                weight_kg: 13.0,
+            },
+            PokemonName::HAPPINY => PokedexEntry {
+                base_stats: (100, 5, 5, 15, 65, 30),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 24.4,
+            },
+            PokemonName::CHATOT => PokedexEntry {
+                base_stats: (76, 65, 45, 92, 42, 91),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 1.9,
+            },
+            PokemonName::SPIRITOMB => PokedexEntry {
+                base_stats: (50, 92, 108, 92, 108, 35),
+                types: (PokemonType::GHOST, PokemonType::DARK),
+                weight_kg: 108.0,
+            },
+            PokemonName::GIBLE => PokedexEntry {
+                base_stats: (58, 70, 45, 40, 45, 42),
+                types: (PokemonType::DRAGON, PokemonType::GROUND),
+                weight_kg: 20.5,
+            },
+            PokemonName::GABITE => PokedexEntry {
+                base_stats: (68, 90, 65, 50, 55, 82),
+                types: (PokemonType::DRAGON, PokemonType::GROUND),
+                weight_kg: 56.0,
+            },
+            PokemonName::GARCHOMP => PokedexEntry {
+                base_stats: (108, 130, 95, 80, 85, 102),
+                types: (PokemonType::DRAGON, PokemonType::GROUND),
+                weight_kg: 95.0,
+            },
+            PokemonName::GARCHOMPMEGA => PokedexEntry {
+                base_stats: (108, 170, 115, 120, 95, 92),
+                types: (PokemonType::DRAGON, PokemonType::GROUND),
+                weight_kg: 95.0,
+            },
+            PokemonName::MUNCHLAX => PokedexEntry {
+                base_stats: (135, 85, 40, 40, 85, 5),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 105.0,
+            },
+            PokemonName::RIOLU => PokedexEntry {
+                base_stats: (40, 70, 40, 35, 40, 60),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 20.2,
+            },
+            PokemonName::LUCARIO => PokedexEntry {
+                base_stats: (70, 110, 70, 115, 70, 90),
+                types: (PokemonType::FIGHTING, PokemonType::STEEL),
+                weight_kg: 54.0,
+            },
+            PokemonName::LUCARIOMEGA => PokedexEntry {
+                base_stats: (70, 145, 88, 140, 70, 112),
+                types: (PokemonType::FIGHTING, PokemonType::STEEL),
+                weight_kg: 57.5,
+            },
+            PokemonName::HIPPOPOTAS => PokedexEntry {
+                base_stats: (68, 72, 78, 38, 42, 32),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 49.5,
+            },
+            PokemonName::HIPPOWDON => PokedexEntry {
+                base_stats: (108, 112, 118, 68, 72, 47),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 300.0,
+            },
+            PokemonName::SKORUPI => PokedexEntry {
+                base_stats: (40, 50, 90, 30, 55, 65),
+                types: (PokemonType::POISON, PokemonType::BUG),
+                weight_kg: 12.0,
+            },
+            PokemonName::DRAPION => PokedexEntry {
+                base_stats: (70, 90, 110, 60, 75, 95),
+                types: (PokemonType::POISON, PokemonType::DARK),
+                weight_kg: 61.5,
+            },
+            PokemonName::CROAGUNK => PokedexEntry {
+                base_stats: (48, 61, 40, 61, 40, 50),
+                types: (PokemonType::POISON, PokemonType::FIGHTING),
+                weight_kg: 23.0,
+            },
+            PokemonName::TOXICROAK => PokedexEntry {
+                base_stats: (83, 106, 65, 86, 65, 85),
+                types: (PokemonType::POISON, PokemonType::FIGHTING),
+                weight_kg: 44.4,
+            },
+            PokemonName::CARNIVINE => PokedexEntry {
+                base_stats: (74, 100, 72, 90, 72, 46),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 27.0,
+            },
+            PokemonName::FINNEON => PokedexEntry {
+                base_stats: (49, 49, 56, 49, 61, 66),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 7.0,
+            },
+            PokemonName::LUMINEON => PokedexEntry {
+                base_stats: (69, 69, 76, 69, 86, 91),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 24.0,
+            },
+            PokemonName::MANTYKE => PokedexEntry {
+                base_stats: (45, 20, 50, 60, 120, 50),
+                types: (PokemonType::WATER, PokemonType::FLYING),
+                weight_kg: 65.0,
+            },
+            PokemonName::SNOVER => PokedexEntry {
+                base_stats: (60, 62, 50, 62, 60, 40),
+                types: (PokemonType::GRASS, PokemonType::ICE),
+                weight_kg: 50.5,
+            },
+            PokemonName::ABOMASNOW => PokedexEntry {
+                base_stats: (90, 92, 75, 92, 85, 60),
+                types: (PokemonType::GRASS, PokemonType::ICE),
+                weight_kg: 135.5,
+            },
+            PokemonName::ABOMASNOWMEGA => PokedexEntry {
+                base_stats: (90, 132, 105, 132, 105, 30),
+                types: (PokemonType::GRASS, PokemonType::ICE),
+                weight_kg: 185.0,
+            },
+            PokemonName::WEAVILE => PokedexEntry {
+                base_stats: (70, 120, 65, 45, 85, 125),
+                types: (PokemonType::DARK, PokemonType::ICE),
+                weight_kg: 34.0,
+            },
+            PokemonName::MAGNEZONE => PokedexEntry {
+                base_stats: (70, 70, 115, 130, 90, 60),
+                types: (PokemonType::ELECTRIC, PokemonType::STEEL),
+                weight_kg: 180.0,
+            },
+            PokemonName::LICKILICKY => PokedexEntry {
+                base_stats: (110, 85, 95, 80, 95, 50),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 140.0,
+            },
+            PokemonName::RHYPERIOR => PokedexEntry {
+                base_stats: (115, 140, 130, 55, 55, 40),
+                types: (PokemonType::GROUND, PokemonType::ROCK),
+                weight_kg: 282.8,
+            },
+            PokemonName::TANGROWTH => PokedexEntry {
+                base_stats: (100, 100, 125, 110, 50, 50),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 128.6,
+            },
+            PokemonName::ELECTIVIRE => PokedexEntry {
+                base_stats: (75, 123, 67, 95, 85, 95),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 138.6,
+            },
+            PokemonName::MAGMORTAR => PokedexEntry {
+                base_stats: (75, 95, 67, 125, 95, 83),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 68.0,
+            },
+            PokemonName::TOGEKISS => PokedexEntry {
+                base_stats: (85, 50, 95, 120, 115, 80),
+                types: (PokemonType::FAIRY, PokemonType::FLYING),
+                weight_kg: 38.0,
+            },
+            PokemonName::YANMEGA => PokedexEntry {
+                base_stats: (86, 76, 86, 116, 56, 95),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 51.5,
+            },
+            PokemonName::LEAFEON => PokedexEntry {
+                base_stats: (65, 110, 130, 60, 65, 95),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 25.5,
+            },
+            PokemonName::GLACEON => PokedexEntry {
+                base_stats: (65, 60, 110, 130, 95, 65),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 25.9,
+            },
+            PokemonName::GLISCOR => PokedexEntry {
+                base_stats: (75, 95, 125, 45, 75, 95),
+                types: (PokemonType::GROUND, PokemonType::FLYING),
+                weight_kg: 42.5,
+            },
+            PokemonName::MAMOSWINE => PokedexEntry {
+                base_stats: (110, 130, 80, 70, 60, 80),
+                types: (PokemonType::ICE, PokemonType::GROUND),
+                weight_kg: 291.0,
+            },
+            PokemonName::PORYGONZ => PokedexEntry {
+                base_stats: (85, 80, 70, 135, 75, 90),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 34.0,
+            },
+            PokemonName::GALLADE => PokedexEntry {
+                base_stats: (68, 125, 65, 65, 115, 80),
+                types: (PokemonType::PSYCHIC, PokemonType::FIGHTING),
+                weight_kg: 52.0,
+            },
+            PokemonName::GALLADEMEGA => PokedexEntry {
+                base_stats: (68, 165, 95, 65, 115, 110),
+                types: (PokemonType::PSYCHIC, PokemonType::FIGHTING),
+                weight_kg: 56.4,
+            },
+            PokemonName::PROBOPASS => PokedexEntry {
+                base_stats: (60, 55, 145, 75, 150, 40),
+                types: (PokemonType::ROCK, PokemonType::STEEL),
+                weight_kg: 340.0,
+            },
+            PokemonName::DUSKNOIR => PokedexEntry {
+                base_stats: (45, 100, 135, 65, 135, 45),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 106.6,
+            },
+            PokemonName::FROSLASS => PokedexEntry {
+                base_stats: (70, 80, 70, 80, 70, 110),
+                types: (PokemonType::ICE, PokemonType::GHOST),
+                weight_kg: 26.6,
+            },
+            PokemonName::ROTOM => PokedexEntry {
+                base_stats: (50, 50, 77, 95, 77, 91),
+                types: (PokemonType::ELECTRIC, PokemonType::GHOST),
+                weight_kg: 0.3,
+            },
+            PokemonName::ROTOMHEAT => PokedexEntry {
+                base_stats: (50, 65, 107, 105, 107, 86),
+                types: (PokemonType::ELECTRIC, PokemonType::FIRE),
+                weight_kg: 0.3,
+            },
+            PokemonName::ROTOMWASH => PokedexEntry {
+                base_stats: (50, 65, 107, 105, 107, 86),
+                types: (PokemonType::ELECTRIC, PokemonType::WATER),
+                weight_kg: 0.3,
+            },
+            PokemonName::ROTOMFROST => PokedexEntry {
+                base_stats: (50, 65, 107, 105, 107, 86),
+                types: (PokemonType::ELECTRIC, PokemonType::ICE),
+                weight_kg: 0.3,
+            },
+            PokemonName::ROTOMFAN => PokedexEntry {
+                base_stats: (50, 65, 107, 105, 107, 86),
+                types: (PokemonType::ELECTRIC, PokemonType::FLYING),
+                weight_kg: 0.3,
+            },
+            PokemonName::ROTOMMOW => PokedexEntry {
+                base_stats: (50, 65, 107, 105, 107, 86),
+                types: (PokemonType::ELECTRIC, PokemonType::GRASS),
+                weight_kg: 0.3,
+            },
+            PokemonName::UXIE => PokedexEntry {
+                base_stats: (75, 75, 130, 75, 130, 95),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 0.3,
+            },
+            PokemonName::MESPRIT => PokedexEntry {
+                base_stats: (80, 105, 105, 105, 105, 80),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 0.3,
+            },
+            PokemonName::AZELF => PokedexEntry {
+                base_stats: (75, 125, 70, 125, 70, 115),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 0.3,
+            },
+            PokemonName::DIALGA => PokedexEntry {
+                base_stats: (100, 120, 120, 150, 100, 90),
+                types: (PokemonType::STEEL, PokemonType::DRAGON),
+                weight_kg: 683.0,
+            },
+            PokemonName::DIALGAORIGIN => PokedexEntry {
+                base_stats: (100, 100, 120, 150, 120, 90),
+                types: (PokemonType::STEEL, PokemonType::DRAGON),
+                weight_kg: 850.0,
+            },
+            PokemonName::PALKIA => PokedexEntry {
+                base_stats: (90, 120, 100, 150, 120, 100),
+                types: (PokemonType::WATER, PokemonType::DRAGON),
+                weight_kg: 336.0,
+            },
+            PokemonName::PALKIAORIGIN => PokedexEntry {
+                base_stats: (90, 100, 100, 150, 120, 120),
+                types: (PokemonType::WATER, PokemonType::DRAGON),
+                weight_kg: 660.0,
+            },
+            PokemonName::HEATRAN => PokedexEntry {
+                base_stats: (91, 90, 106, 130, 106, 77),
+                types: (PokemonType::FIRE, PokemonType::STEEL),
+                weight_kg: 430.0,
+            },
+            PokemonName::REGIGIGAS => PokedexEntry {
+                base_stats: (110, 160, 110, 80, 110, 100),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 420.0,
+            },
+            PokemonName::GIRATINA => PokedexEntry {
+                base_stats: (150, 100, 120, 100, 120, 90),
+                types: (PokemonType::GHOST, PokemonType::DRAGON),
+                weight_kg: 750.0,
+            },
+            PokemonName::GIRATINAORIGIN => PokedexEntry {
+                base_stats: (150, 120, 100, 120, 100, 90),
+                types: (PokemonType::GHOST, PokemonType::DRAGON),
+                weight_kg: 650.0,
+            },
+            PokemonName::CRESSELIA => PokedexEntry {
+                base_stats: (120, 70, 110, 75, 120, 85),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 85.6,
+            },
+            PokemonName::PHIONE => PokedexEntry {
+                base_stats: (80, 80, 80, 80, 80, 80),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 3.1,
+            },
+            PokemonName::MANAPHY => PokedexEntry {
+                base_stats: (100, 100, 100, 100, 100, 100),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 1.4,
+            },
+            PokemonName::DARKRAI => PokedexEntry {
+                base_stats: (70, 90, 90, 135, 90, 125),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 50.5,
+            },
+            PokemonName::SHAYMIN => PokedexEntry {
+                base_stats: (100, 100, 100, 100, 100, 100),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 2.1,
+            },
+            PokemonName::SHAYMINSKY => PokedexEntry {
+                base_stats: (100, 103, 75, 120, 75, 127),
+                types: (PokemonType::GRASS, PokemonType::FLYING),
+                weight_kg: 5.2,
+            },
+            PokemonName::ARCEUS => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSBUG => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSDARK => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSDRAGON => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSELECTRIC => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSFAIRY => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSFIGHTING => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSFIRE => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSFLYING => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::FLYING, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSGHOST => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSGRASS => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSGROUND => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSICE => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSPOISON => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSPSYCHIC => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSROCK => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSSTEEL => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::ARCEUSWATER => PokedexEntry {
+                base_stats: (120, 120, 120, 120, 120, 120),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 320.0,
+            },
+            PokemonName::VICTINI => PokedexEntry {
+                base_stats: (100, 100, 100, 100, 100, 100),
+                types: (PokemonType::PSYCHIC, PokemonType::FIRE),
+                weight_kg: 4.0,
+            },
+            PokemonName::SNIVY => PokedexEntry {
+                base_stats: (45, 45, 55, 45, 55, 63),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 8.1,
+            },
+            PokemonName::SERVINE => PokedexEntry {
+                base_stats: (60, 60, 75, 60, 75, 83),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 16.0,
+            },
+            PokemonName::SERPERIOR => PokedexEntry {
+                base_stats: (75, 75, 95, 75, 95, 113),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 63.0,
+            },
+            PokemonName::TEPIG => PokedexEntry {
+                base_stats: (65, 63, 45, 45, 45, 45),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 9.9,
+            },
+            PokemonName::PIGNITE => PokedexEntry {
+                base_stats: (90, 93, 55, 70, 55, 55),
+                types: (PokemonType::FIRE, PokemonType::FIGHTING),
+                weight_kg: 55.5,
+            },
+            PokemonName::EMBOAR => PokedexEntry {
+                base_stats: (110, 123, 65, 100, 65, 65),
+                types: (PokemonType::FIRE, PokemonType::FIGHTING),
+                weight_kg: 150.0,
+            },
+            PokemonName::OSHAWOTT => PokedexEntry {
+                base_stats: (55, 55, 45, 63, 45, 45),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 5.9,
+            },
+            PokemonName::DEWOTT => PokedexEntry {
+                base_stats: (75, 75, 60, 83, 60, 60),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 24.5,
+            },
+            PokemonName::SAMUROTT => PokedexEntry {
+                base_stats: (95, 100, 85, 108, 70, 70),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 94.6,
+            },
+            PokemonName::SAMUROTTHISUI => PokedexEntry {
+                base_stats: (90, 108, 80, 100, 65, 85),
+                types: (PokemonType::WATER, PokemonType::DARK),
+                weight_kg: 58.2,
+            },
+            PokemonName::PATRAT => PokedexEntry {
+                base_stats: (45, 55, 39, 35, 39, 42),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 11.6,
+            },
+            PokemonName::WATCHOG => PokedexEntry {
+                base_stats: (60, 85, 69, 60, 69, 77),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 27.0,
+            },
+            PokemonName::LILLIPUP => PokedexEntry {
+                base_stats: (45, 60, 45, 25, 45, 55),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 4.1,
+            },
+            PokemonName::HERDIER => PokedexEntry {
+                base_stats: (65, 80, 65, 35, 65, 60),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 14.7,
+            },
+            PokemonName::STOUTLAND => PokedexEntry {
+                base_stats: (85, 110, 90, 45, 90, 80),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 61.0,
+            },
+            PokemonName::PURRLOIN => PokedexEntry {
+                base_stats: (41, 50, 37, 50, 37, 66),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 10.1,
+            },
+            PokemonName::LIEPARD => PokedexEntry {
+                base_stats: (64, 88, 50, 88, 50, 106),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 37.5,
+            },
+            PokemonName::PANSAGE => PokedexEntry {
+                base_stats: (50, 53, 48, 53, 48, 64),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 10.5,
+            },
+            PokemonName::SIMISAGE => PokedexEntry {
+                base_stats: (75, 98, 63, 98, 63, 101),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 30.5,
+            },
+            PokemonName::PANSEAR => PokedexEntry {
+                base_stats: (50, 53, 48, 53, 48, 64),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 11.0,
+            },
+            PokemonName::SIMISEAR => PokedexEntry {
+                base_stats: (75, 98, 63, 98, 63, 101),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::PANPOUR => PokedexEntry {
+                base_stats: (50, 53, 48, 53, 48, 64),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 13.5,
+            },
+            PokemonName::SIMIPOUR => PokedexEntry {
+                base_stats: (75, 98, 63, 98, 63, 101),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 29.0,
+            },
+            PokemonName::MUNNA => PokedexEntry {
+                base_stats: (76, 25, 45, 67, 55, 24),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 23.3,
+            },
+            PokemonName::MUSHARNA => PokedexEntry {
+                base_stats: (116, 55, 85, 107, 95, 29),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 60.5,
+            },
+            PokemonName::PIDOVE => PokedexEntry {
+                base_stats: (50, 55, 50, 36, 30, 43),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 2.1,
+            },
+            PokemonName::TRANQUILL => PokedexEntry {
+                base_stats: (62, 77, 62, 50, 42, 65),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 15.0,
+            },
+            PokemonName::UNFEZANT => PokedexEntry {
+                base_stats: (80, 115, 80, 65, 55, 93),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 29.0,
+            },
+            PokemonName::BLITZLE => PokedexEntry {
+                base_stats: (45, 60, 32, 50, 32, 76),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 29.8,
+            },
+            PokemonName::ZEBSTRIKA => PokedexEntry {
+                base_stats: (75, 100, 63, 80, 63, 116),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 79.5,
+            },
+            PokemonName::ROGGENROLA => PokedexEntry {
+                base_stats: (55, 75, 85, 25, 25, 15),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 18.0,
+            },
+            PokemonName::BOLDORE => PokedexEntry {
+                base_stats: (70, 105, 105, 50, 40, 20),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 102.0,
+            },
+            PokemonName::GIGALITH => PokedexEntry {
+                base_stats: (85, 135, 130, 60, 80, 25),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 260.0,
+            },
+            PokemonName::WOOBAT => PokedexEntry {
+                base_stats: (65, 45, 43, 55, 43, 72),
+                types: (PokemonType::PSYCHIC, PokemonType::FLYING),
+                weight_kg: 2.1,
+            },
+            PokemonName::SWOOBAT => PokedexEntry {
+                base_stats: (67, 57, 55, 77, 55, 114),
+                types: (PokemonType::PSYCHIC, PokemonType::FLYING),
+                weight_kg: 10.5,
+            },
+            PokemonName::DRILBUR => PokedexEntry {
+                base_stats: (60, 85, 40, 30, 45, 68),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 8.5,
+            },
+            PokemonName::EXCADRILL => PokedexEntry {
+                base_stats: (110, 135, 60, 50, 65, 88),
+                types: (PokemonType::GROUND, PokemonType::STEEL),
+                weight_kg: 40.4,
+            },
+            PokemonName::AUDINO => PokedexEntry {
+                base_stats: (103, 60, 86, 60, 86, 50),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 31.0,
+            },
+            PokemonName::AUDINOMEGA => PokedexEntry {
+                base_stats: (103, 60, 126, 80, 126, 50),
+                types: (PokemonType::NORMAL, PokemonType::FAIRY),
+                weight_kg: 32.0,
+            },
+            PokemonName::TIMBURR => PokedexEntry {
+                base_stats: (75, 80, 55, 25, 35, 35),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 12.5,
+            },
+            PokemonName::GURDURR => PokedexEntry {
+                base_stats: (85, 105, 85, 40, 50, 40),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 40.0,
+            },
+            PokemonName::CONKELDURR => PokedexEntry {
+                base_stats: (105, 140, 95, 55, 65, 45),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 87.0,
+            },
+            PokemonName::TYMPOLE => PokedexEntry {
+                base_stats: (50, 50, 40, 50, 40, 64),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 4.5,
+            },
+            PokemonName::PALPITOAD => PokedexEntry {
+                base_stats: (75, 65, 55, 65, 55, 69),
+                types: (PokemonType::WATER, PokemonType::GROUND),
+                weight_kg: 17.0,
+            },
+            PokemonName::SEISMITOAD => PokedexEntry {
+                base_stats: (105, 95, 75, 85, 75, 74),
+                types: (PokemonType::WATER, PokemonType::GROUND),
+                weight_kg: 62.0,
+            },
+            PokemonName::THROH => PokedexEntry {
+                base_stats: (120, 100, 85, 30, 85, 45),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 55.5,
+            },
+            PokemonName::SAWK => PokedexEntry {
+                base_stats: (75, 125, 75, 30, 75, 85),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 51.0,
+            },
+            PokemonName::SEWADDLE => PokedexEntry {
+                base_stats: (45, 53, 70, 40, 60, 42),
+                types: (PokemonType::BUG, PokemonType::GRASS),
+                weight_kg: 2.5,
+            },
+            PokemonName::SWADLOON => PokedexEntry {
+                base_stats: (55, 63, 90, 50, 80, 42),
+                types: (PokemonType::BUG, PokemonType::GRASS),
+                weight_kg: 7.3,
+            },
+            PokemonName::LEAVANNY => PokedexEntry {
+                base_stats: (75, 103, 80, 70, 80, 92),
+                types: (PokemonType::BUG, PokemonType::GRASS),
+                weight_kg: 20.5,
+            },
+            PokemonName::VENIPEDE => PokedexEntry {
+                base_stats: (30, 45, 59, 30, 39, 57),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 5.3,
+            },
+            PokemonName::WHIRLIPEDE => PokedexEntry {
+                base_stats: (40, 55, 99, 40, 79, 47),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 58.5,
+            },
+            PokemonName::SCOLIPEDE => PokedexEntry {
+                base_stats: (60, 100, 89, 55, 69, 112),
+                types: (PokemonType::BUG, PokemonType::POISON),
+                weight_kg: 200.5,
+            },
+            PokemonName::COTTONEE => PokedexEntry {
+                base_stats: (40, 27, 60, 37, 50, 66),
+                types: (PokemonType::GRASS, PokemonType::FAIRY),
+                weight_kg: 0.6,
+            },
+            PokemonName::WHIMSICOTT => PokedexEntry {
+                base_stats: (60, 67, 85, 77, 75, 116),
+                types: (PokemonType::GRASS, PokemonType::FAIRY),
+                weight_kg: 6.6,
+            },
+            PokemonName::PETILIL => PokedexEntry {
+                base_stats: (45, 35, 50, 70, 50, 30),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 6.6,
+            },
+            PokemonName::LILLIGANT => PokedexEntry {
+                base_stats: (70, 60, 75, 110, 75, 90),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 16.3,
+            },
+            PokemonName::LILLIGANTHISUI => PokedexEntry {
+                base_stats: (70, 105, 75, 50, 75, 105),
+                types: (PokemonType::GRASS, PokemonType::FIGHTING),
+                weight_kg: 19.2,
+            },
+            PokemonName::BASCULIN => PokedexEntry {
+                base_stats: (70, 92, 65, 80, 55, 98),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 18.0,
+            },
+            PokemonName::BASCULINBLUESTRIPED => PokedexEntry {
+                base_stats: (70, 92, 65, 80, 55, 98),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 18.0,
+            },
+            PokemonName::BASCULINWHITESTRIPED => PokedexEntry {
+                base_stats: (70, 92, 65, 80, 55, 98),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 18.0,
+            },
+            PokemonName::SANDILE => PokedexEntry {
+                base_stats: (50, 72, 35, 35, 35, 65),
+                types: (PokemonType::GROUND, PokemonType::DARK),
+                weight_kg: 15.2,
+            },
+            PokemonName::KROKOROK => PokedexEntry {
+                base_stats: (60, 82, 45, 45, 45, 74),
+                types: (PokemonType::GROUND, PokemonType::DARK),
+                weight_kg: 33.4,
+            },
+            PokemonName::KROOKODILE => PokedexEntry {
+                base_stats: (95, 117, 80, 65, 70, 92),
+                types: (PokemonType::GROUND, PokemonType::DARK),
+                weight_kg: 96.3,
+            },
+            PokemonName::DARUMAKA => PokedexEntry {
+                base_stats: (70, 90, 45, 15, 45, 50),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 37.5,
+            },
+            PokemonName::DARUMAKAGALAR => PokedexEntry {
+                base_stats: (70, 90, 45, 15, 45, 50),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 40.0,
+            },
+            PokemonName::DARMANITAN => PokedexEntry {
+                base_stats: (105, 140, 55, 30, 55, 95),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 92.9,
+            },
+            PokemonName::DARMANITANZEN => PokedexEntry {
+                base_stats: (105, 30, 105, 140, 105, 55),
+                types: (PokemonType::FIRE, PokemonType::PSYCHIC),
+                weight_kg: 92.9,
+            },
+            PokemonName::DARMANITANGALAR => PokedexEntry {
+                base_stats: (105, 140, 55, 30, 55, 95),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 120.0,
+            },
+            PokemonName::DARMANITANGALARZEN => PokedexEntry {
+                base_stats: (105, 160, 55, 30, 55, 135),
+                types: (PokemonType::ICE, PokemonType::FIRE),
+                weight_kg: 120.0,
+            },
+            PokemonName::MARACTUS => PokedexEntry {
+                base_stats: (75, 86, 67, 106, 67, 60),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::DWEBBLE => PokedexEntry {
+                base_stats: (50, 65, 85, 35, 35, 55),
+                types: (PokemonType::BUG, PokemonType::ROCK),
+                weight_kg: 14.5,
+            },
+            PokemonName::CRUSTLE => PokedexEntry {
+                base_stats: (70, 105, 125, 65, 75, 45),
+                types: (PokemonType::BUG, PokemonType::ROCK),
+                weight_kg: 200.0,
+            },
+            PokemonName::SCRAGGY => PokedexEntry {
+                base_stats: (50, 75, 70, 35, 70, 48),
+                types: (PokemonType::DARK, PokemonType::FIGHTING),
+                weight_kg: 11.8,
+            },
+            PokemonName::SCRAFTY => PokedexEntry {
+                base_stats: (65, 90, 115, 45, 115, 58),
+                types: (PokemonType::DARK, PokemonType::FIGHTING),
+                weight_kg: 30.0,
+            },
+            PokemonName::SIGILYPH => PokedexEntry {
+                base_stats: (72, 58, 80, 103, 80, 97),
+                types: (PokemonType::PSYCHIC, PokemonType::FLYING),
+                weight_kg: 14.0,
+            },
+            PokemonName::YAMASK => PokedexEntry {
+                base_stats: (38, 30, 85, 55, 65, 30),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 1.5,
+            },
+            PokemonName::YAMASKGALAR => PokedexEntry {
+                base_stats: (38, 55, 85, 30, 65, 30),
+                types: (PokemonType::GROUND, PokemonType::GHOST),
+                weight_kg: 1.5,
+            },
+            PokemonName::COFAGRIGUS => PokedexEntry {
+                base_stats: (58, 50, 145, 95, 105, 30),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 76.5,
+            },
+            PokemonName::TIRTOUGA => PokedexEntry {
+                base_stats: (54, 78, 103, 53, 45, 22),
+                types: (PokemonType::WATER, PokemonType::ROCK),
+                weight_kg: 16.5,
+            },
+            PokemonName::CARRACOSTA => PokedexEntry {
+                base_stats: (74, 108, 133, 83, 65, 32),
+                types: (PokemonType::WATER, PokemonType::ROCK),
+                weight_kg: 81.0,
+            },
+            PokemonName::ARCHEN => PokedexEntry {
+                base_stats: (55, 112, 45, 74, 45, 70),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 9.5,
+            },
+            PokemonName::ARCHEOPS => PokedexEntry {
+                base_stats: (75, 140, 65, 112, 65, 110),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 32.0,
+            },
+            PokemonName::TRUBBISH => PokedexEntry {
+                base_stats: (50, 50, 62, 40, 62, 65),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 31.0,
+            },
+            PokemonName::GARBODOR => PokedexEntry {
+                base_stats: (80, 95, 82, 60, 82, 75),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 107.3,
+            },
+            PokemonName::GARBODORGMAX => PokedexEntry {
+                base_stats: (80, 95, 82, 60, 82, 75),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 107.3,
+            },
+            PokemonName::ZORUA => PokedexEntry {
+                base_stats: (40, 65, 40, 80, 40, 65),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 12.5,
+            },
+            PokemonName::ZORUAHISUI => PokedexEntry {
+                base_stats: (35, 60, 40, 85, 40, 70),
+                types: (PokemonType::NORMAL, PokemonType::GHOST),
+                weight_kg: 12.5,
+            },
+            PokemonName::ZOROARK => PokedexEntry {
+                base_stats: (60, 105, 60, 120, 60, 105),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 81.1,
+            },
+            PokemonName::ZOROARKHISUI => PokedexEntry {
+                base_stats: (55, 100, 60, 125, 60, 110),
+                types: (PokemonType::NORMAL, PokemonType::GHOST),
+                weight_kg: 73.0,
+            },
+            PokemonName::MINCCINO => PokedexEntry {
+                base_stats: (55, 50, 40, 40, 40, 75),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 5.8,
+            },
+            PokemonName::CINCCINO => PokedexEntry {
+                base_stats: (75, 95, 60, 65, 60, 115),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 7.5,
+            },
+            PokemonName::GOTHITA => PokedexEntry {
+                base_stats: (45, 30, 50, 55, 65, 45),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 5.8,
+            },
+            PokemonName::GOTHORITA => PokedexEntry {
+                base_stats: (60, 45, 70, 75, 85, 55),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 18.0,
+            },
+            PokemonName::GOTHITELLE => PokedexEntry {
+                base_stats: (70, 55, 95, 95, 110, 65),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 44.0,
+            },
+            PokemonName::SOLOSIS => PokedexEntry {
+                base_stats: (45, 30, 40, 105, 50, 20),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 1.0,
+            },
+            PokemonName::DUOSION => PokedexEntry {
+                base_stats: (65, 40, 50, 125, 60, 30),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 8.0,
+            },
+            PokemonName::REUNICLUS => PokedexEntry {
+                base_stats: (110, 65, 75, 125, 85, 30),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 20.1,
+            },
+            PokemonName::DUCKLETT => PokedexEntry {
+                base_stats: (62, 44, 50, 44, 50, 55),
+                types: (PokemonType::WATER, PokemonType::FLYING),
+                weight_kg: 5.5,
+            },
+            PokemonName::SWANNA => PokedexEntry {
+                base_stats: (75, 87, 63, 87, 63, 98),
+                types: (PokemonType::WATER, PokemonType::FLYING),
+                weight_kg: 24.2,
+            },
+            PokemonName::VANILLITE => PokedexEntry {
+                base_stats: (36, 50, 50, 65, 60, 44),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 5.7,
+            },
+            PokemonName::VANILLISH => PokedexEntry {
+                base_stats: (51, 65, 65, 80, 75, 59),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 41.0,
+            },
+            PokemonName::VANILLUXE => PokedexEntry {
+                base_stats: (71, 95, 85, 110, 95, 79),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 57.5,
+            },
+            PokemonName::DEERLING => PokedexEntry {
+                base_stats: (60, 60, 50, 40, 50, 75),
+                types: (PokemonType::NORMAL, PokemonType::GRASS),
+                weight_kg: 19.5,
+            },
+            PokemonName::DEERLINGSUMMER => PokedexEntry {
+                base_stats: (60, 60, 50, 40, 50, 75),
+                types: (PokemonType::NORMAL, PokemonType::GRASS),
+                weight_kg: 19.5,
+            },
+            PokemonName::DEERLINGAUTUMN => PokedexEntry {
+                base_stats: (60, 60, 50, 40, 50, 75),
+                types: (PokemonType::NORMAL, PokemonType::GRASS),
+                weight_kg: 19.5,
+            },
+            PokemonName::DEERLINGWINTER => PokedexEntry {
+                base_stats: (60, 60, 50, 40, 50, 75),
+                types: (PokemonType::NORMAL, PokemonType::GRASS),
+                weight_kg: 19.5,
+            },
+            PokemonName::SAWSBUCK => PokedexEntry {
+                base_stats: (80, 100, 70, 60, 70, 95),
+                types: (PokemonType::NORMAL, PokemonType::GRASS),
+                weight_kg: 92.5,
+            },
+            PokemonName::SAWSBUCKSUMMER => PokedexEntry {
+                base_stats: (80, 100, 70, 60, 70, 95),
+                types: (PokemonType::NORMAL, PokemonType::GRASS),
+                weight_kg: 92.5,
+            },
+            PokemonName::SAWSBUCKAUTUMN => PokedexEntry {
+                base_stats: (80, 100, 70, 60, 70, 95),
+                types: (PokemonType::NORMAL, PokemonType::GRASS),
+                weight_kg: 92.5,
+            },
+            PokemonName::SAWSBUCKWINTER => PokedexEntry {
+                base_stats: (80, 100, 70, 60, 70, 95),
+                types: (PokemonType::NORMAL, PokemonType::GRASS),
+                weight_kg: 92.5,
+            },
+            PokemonName::EMOLGA => PokedexEntry {
+                base_stats: (55, 75, 60, 75, 60, 103),
+                types: (PokemonType::ELECTRIC, PokemonType::FLYING),
+                weight_kg: 5.0,
+            },
+            PokemonName::KARRABLAST => PokedexEntry {
+                base_stats: (50, 75, 45, 40, 45, 60),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 5.9,
+            },
+            PokemonName::ESCAVALIER => PokedexEntry {
+                base_stats: (70, 135, 105, 60, 105, 20),
+                types: (PokemonType::BUG, PokemonType::STEEL),
+                weight_kg: 33.0,
+            },
+            PokemonName::FOONGUS => PokedexEntry {
+                base_stats: (69, 55, 45, 55, 55, 15),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 1.0,
+            },
+            PokemonName::AMOONGUSS => PokedexEntry {
+                base_stats: (114, 85, 70, 85, 80, 30),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 10.5,
+            },
+            PokemonName::FRILLISH => PokedexEntry {
+                base_stats: (55, 40, 50, 65, 85, 40),
+                types: (PokemonType::WATER, PokemonType::GHOST),
+                weight_kg: 33.0,
+            },
+            PokemonName::JELLICENT => PokedexEntry {
+                base_stats: (100, 60, 70, 85, 105, 60),
+                types: (PokemonType::WATER, PokemonType::GHOST),
+                weight_kg: 135.0,
+            },
+            PokemonName::ALOMOMOLA => PokedexEntry {
+                base_stats: (165, 75, 80, 40, 45, 65),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 31.6,
+            },
+            PokemonName::JOLTIK => PokedexEntry {
+                base_stats: (50, 47, 50, 57, 50, 65),
+                types: (PokemonType::BUG, PokemonType::ELECTRIC),
+                weight_kg: 0.6,
+            },
+            PokemonName::GALVANTULA => PokedexEntry {
+                base_stats: (70, 77, 60, 97, 60, 108),
+                types: (PokemonType::BUG, PokemonType::ELECTRIC),
+                weight_kg: 14.3,
+            },
+            PokemonName::FERROSEED => PokedexEntry {
+                base_stats: (44, 50, 91, 24, 86, 10),
+                types: (PokemonType::GRASS, PokemonType::STEEL),
+                weight_kg: 18.8,
+            },
+            PokemonName::FERROTHORN => PokedexEntry {
+                base_stats: (74, 94, 131, 54, 116, 20),
+                types: (PokemonType::GRASS, PokemonType::STEEL),
+                weight_kg: 110.0,
+            },
+            PokemonName::KLINK => PokedexEntry {
+                base_stats: (40, 55, 70, 45, 60, 30),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 21.0,
+            },
+            PokemonName::KLANG => PokedexEntry {
+                base_stats: (60, 80, 95, 70, 85, 50),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 51.0,
+            },
+            PokemonName::KLINKLANG => PokedexEntry {
+                base_stats: (60, 100, 115, 70, 85, 90),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 81.0,
+            },
+            PokemonName::TYNAMO => PokedexEntry {
+                base_stats: (35, 55, 40, 45, 40, 60),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 0.3,
+            },
+            PokemonName::EELEKTRIK => PokedexEntry {
+                base_stats: (65, 85, 70, 75, 70, 40),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 22.0,
+            },
+            PokemonName::EELEKTROSS => PokedexEntry {
+                base_stats: (85, 115, 80, 105, 80, 50),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 80.5,
+            },
+            PokemonName::ELGYEM => PokedexEntry {
+                base_stats: (55, 55, 55, 85, 55, 30),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 9.0,
+            },
+            PokemonName::BEHEEYEM => PokedexEntry {
+                base_stats: (75, 75, 75, 125, 95, 40),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 34.5,
+            },
+            PokemonName::LITWICK => PokedexEntry {
+                base_stats: (50, 30, 55, 65, 55, 20),
+                types: (PokemonType::GHOST, PokemonType::FIRE),
+                weight_kg: 3.1,
+            },
+            PokemonName::LAMPENT => PokedexEntry {
+                base_stats: (60, 40, 60, 95, 60, 55),
+                types: (PokemonType::GHOST, PokemonType::FIRE),
+                weight_kg: 13.0,
+            },
+            PokemonName::CHANDELURE => PokedexEntry {
+                base_stats: (60, 55, 90, 145, 90, 80),
+                types: (PokemonType::GHOST, PokemonType::FIRE),
+                weight_kg: 34.3,
+            },
+            PokemonName::AXEW => PokedexEntry {
+                base_stats: (46, 87, 60, 30, 40, 57),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 18.0,
+            },
+            PokemonName::FRAXURE => PokedexEntry {
+                base_stats: (66, 117, 70, 40, 50, 67),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 36.0,
+            },
+            PokemonName::HAXORUS => PokedexEntry {
+                base_stats: (76, 147, 90, 60, 70, 97),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 105.5,
+            },
+            PokemonName::CUBCHOO => PokedexEntry {
+                base_stats: (55, 70, 40, 60, 40, 40),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 8.5,
+            },
+            PokemonName::BEARTIC => PokedexEntry {
+                base_stats: (95, 130, 80, 70, 80, 50),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 260.0,
+            },
+            PokemonName::CRYOGONAL => PokedexEntry {
+                base_stats: (80, 50, 50, 95, 135, 105),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 148.0,
+            },
+            PokemonName::SHELMET => PokedexEntry {
+                base_stats: (50, 40, 85, 40, 65, 25),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 7.7,
+            },
+            PokemonName::ACCELGOR => PokedexEntry {
+                base_stats: (80, 70, 40, 100, 60, 145),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 25.3,
+            },
+            PokemonName::STUNFISK => PokedexEntry {
+                base_stats: (109, 66, 84, 81, 99, 32),
+                types: (PokemonType::GROUND, PokemonType::ELECTRIC),
+                weight_kg: 11.0,
+            },
+            PokemonName::STUNFISKGALAR => PokedexEntry {
+                base_stats: (109, 81, 99, 66, 84, 32),
+                types: (PokemonType::GROUND, PokemonType::STEEL),
+                weight_kg: 20.5,
+            },
+            PokemonName::MIENFOO => PokedexEntry {
+                base_stats: (45, 85, 50, 55, 50, 65),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 20.0,
+            },
+            PokemonName::MIENSHAO => PokedexEntry {
+                base_stats: (65, 125, 60, 95, 60, 105),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 35.5,
+            },
+            PokemonName::DRUDDIGON => PokedexEntry {
+                base_stats: (77, 120, 90, 60, 90, 48),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 139.0,
+            },
+            PokemonName::GOLETT => PokedexEntry {
+                base_stats: (59, 74, 50, 35, 50, 35),
+                types: (PokemonType::GROUND, PokemonType::GHOST),
+                weight_kg: 92.0,
+            },
+            PokemonName::GOLURK => PokedexEntry {
+                base_stats: (89, 124, 80, 55, 80, 55),
+                types: (PokemonType::GROUND, PokemonType::GHOST),
+                weight_kg: 330.0,
+            },
+            PokemonName::PAWNIARD => PokedexEntry {
+                base_stats: (45, 85, 70, 40, 40, 60),
+                types: (PokemonType::DARK, PokemonType::STEEL),
+                weight_kg: 10.2,
+            },
+            PokemonName::BISHARP => PokedexEntry {
+                base_stats: (65, 125, 100, 60, 70, 70),
+                types: (PokemonType::DARK, PokemonType::STEEL),
+                weight_kg: 70.0,
+            },
+            PokemonName::BOUFFALANT => PokedexEntry {
+                base_stats: (95, 110, 95, 40, 95, 55),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 94.6,
+            },
+            PokemonName::RUFFLET => PokedexEntry {
+                base_stats: (70, 83, 50, 37, 50, 60),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 10.5,
+            },
+            PokemonName::BRAVIARY => PokedexEntry {
+                base_stats: (100, 123, 75, 57, 75, 80),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 41.0,
+            },
+            PokemonName::BRAVIARYHISUI => PokedexEntry {
+                base_stats: (110, 83, 70, 112, 70, 65),
+                types: (PokemonType::PSYCHIC, PokemonType::FLYING),
+                weight_kg: 43.4,
+            },
+            PokemonName::VULLABY => PokedexEntry {
+                base_stats: (70, 55, 75, 45, 65, 60),
+                types: (PokemonType::DARK, PokemonType::FLYING),
+                weight_kg: 9.0,
+            },
+            PokemonName::MANDIBUZZ => PokedexEntry {
+                base_stats: (110, 65, 105, 55, 95, 80),
+                types: (PokemonType::DARK, PokemonType::FLYING),
+                weight_kg: 39.5,
+            },
+            PokemonName::HEATMOR => PokedexEntry {
+                base_stats: (85, 97, 66, 105, 66, 65),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 58.0,
+            },
+            PokemonName::DURANT => PokedexEntry {
+                base_stats: (58, 109, 112, 48, 48, 109),
+                types: (PokemonType::BUG, PokemonType::STEEL),
+                weight_kg: 33.0,
+            },
+            PokemonName::DEINO => PokedexEntry {
+                base_stats: (52, 65, 50, 45, 50, 38),
+                types: (PokemonType::DARK, PokemonType::DRAGON),
+                weight_kg: 17.3,
+            },
+            PokemonName::ZWEILOUS => PokedexEntry {
+                base_stats: (72, 85, 70, 65, 70, 58),
+                types: (PokemonType::DARK, PokemonType::DRAGON),
+                weight_kg: 50.0,
+            },
+            PokemonName::HYDREIGON => PokedexEntry {
+                base_stats: (92, 105, 90, 125, 90, 98),
+                types: (PokemonType::DARK, PokemonType::DRAGON),
+                weight_kg: 160.0,
+            },
+            PokemonName::LARVESTA => PokedexEntry {
+                base_stats: (55, 85, 55, 50, 55, 60),
+                types: (PokemonType::BUG, PokemonType::FIRE),
+                weight_kg: 28.8,
+            },
+            PokemonName::VOLCARONA => PokedexEntry {
+                base_stats: (85, 60, 65, 135, 105, 100),
+                types: (PokemonType::BUG, PokemonType::FIRE),
+                weight_kg: 46.0,
+            },
+            PokemonName::COBALION => PokedexEntry {
+                base_stats: (91, 90, 129, 90, 72, 108),
+                types: (PokemonType::STEEL, PokemonType::FIGHTING),
+                weight_kg: 250.0,
+            },
+            PokemonName::TERRAKION => PokedexEntry {
+                base_stats: (91, 129, 90, 72, 90, 108),
+                types: (PokemonType::ROCK, PokemonType::FIGHTING),
+                weight_kg: 260.0,
+            },
+            PokemonName::VIRIZION => PokedexEntry {
+                base_stats: (91, 90, 72, 90, 129, 108),
+                types: (PokemonType::GRASS, PokemonType::FIGHTING),
+                weight_kg: 200.0,
+            },
+            PokemonName::TORNADUS => PokedexEntry {
+                base_stats: (79, 115, 70, 125, 80, 111),
+                types: (PokemonType::FLYING, PokemonType::TYPELESS),
+                weight_kg: 63.0,
+            },
+            PokemonName::TORNADUSTHERIAN => PokedexEntry {
+                base_stats: (79, 100, 80, 110, 90, 121),
+                types: (PokemonType::FLYING, PokemonType::TYPELESS),
+                weight_kg: 63.0,
+            },
+            PokemonName::THUNDURUS => PokedexEntry {
+                base_stats: (79, 115, 70, 125, 80, 111),
+                types: (PokemonType::ELECTRIC, PokemonType::FLYING),
+                weight_kg: 61.0,
+            },
+            PokemonName::THUNDURUSTHERIAN => PokedexEntry {
+                base_stats: (79, 105, 70, 145, 80, 101),
+                types: (PokemonType::ELECTRIC, PokemonType::FLYING),
+                weight_kg: 61.0,
+            },
+            PokemonName::RESHIRAM => PokedexEntry {
+                base_stats: (100, 120, 100, 150, 120, 90),
+                types: (PokemonType::DRAGON, PokemonType::FIRE),
+                weight_kg: 330.0,
+            },
+            PokemonName::ZEKROM => PokedexEntry {
+                base_stats: (100, 150, 120, 120, 100, 90),
+                types: (PokemonType::DRAGON, PokemonType::ELECTRIC),
+                weight_kg: 345.0,
+            },
+            PokemonName::LANDORUS => PokedexEntry {
+                base_stats: (89, 125, 90, 115, 80, 101),
+                types: (PokemonType::GROUND, PokemonType::FLYING),
+                weight_kg: 68.0,
+            },
+            PokemonName::LANDORUSTHERIAN => PokedexEntry {
+                base_stats: (89, 145, 90, 105, 80, 91),
+                types: (PokemonType::GROUND, PokemonType::FLYING),
+                weight_kg: 68.0,
+            },
+            PokemonName::KYUREM => PokedexEntry {
+                base_stats: (125, 130, 90, 130, 90, 95),
+                types: (PokemonType::DRAGON, PokemonType::ICE),
+                weight_kg: 325.0,
+            },
+            PokemonName::KYUREMBLACK => PokedexEntry {
+                base_stats: (125, 170, 100, 120, 90, 95),
+                types: (PokemonType::DRAGON, PokemonType::ICE),
+                weight_kg: 325.0,
+            },
+            PokemonName::KYUREMWHITE => PokedexEntry {
+                base_stats: (125, 120, 90, 170, 100, 95),
+                types: (PokemonType::DRAGON, PokemonType::ICE),
+                weight_kg: 325.0,
+            },
+            PokemonName::KELDEO => PokedexEntry {
+                base_stats: (91, 72, 90, 129, 90, 108),
+                types: (PokemonType::WATER, PokemonType::FIGHTING),
+                weight_kg: 48.5,
+            },
+            PokemonName::KELDEORESOLUTE => PokedexEntry {
+                base_stats: (91, 72, 90, 129, 90, 108),
+                types: (PokemonType::WATER, PokemonType::FIGHTING),
+                weight_kg: 48.5,
+            },
+            PokemonName::MELOETTA => PokedexEntry {
+                base_stats: (100, 77, 77, 128, 128, 90),
+                types: (PokemonType::NORMAL, PokemonType::PSYCHIC),
+                weight_kg: 6.5,
+            },
+            PokemonName::MELOETTAPIROUETTE => PokedexEntry {
+                base_stats: (100, 128, 90, 77, 77, 128),
+                types: (PokemonType::NORMAL, PokemonType::FIGHTING),
+                weight_kg: 6.5,
+            },
+            PokemonName::GENESECT => PokedexEntry {
+                base_stats: (71, 120, 95, 120, 95, 99),
+                types: (PokemonType::BUG, PokemonType::STEEL),
+                weight_kg: 82.5,
+            },
+            PokemonName::GENESECTDOUSE => PokedexEntry {
+                base_stats: (71, 120, 95, 120, 95, 99),
+                types: (PokemonType::BUG, PokemonType::STEEL),
+                weight_kg: 82.5,
+            },
+            PokemonName::GENESECTSHOCK => PokedexEntry {
+                base_stats: (71, 120, 95, 120, 95, 99),
+                types: (PokemonType::BUG, PokemonType::STEEL),
+                weight_kg: 82.5,
+            },
+            PokemonName::GENESECTBURN => PokedexEntry {
+                base_stats: (71, 120, 95, 120, 95, 99),
+                types: (PokemonType::BUG, PokemonType::STEEL),
+                weight_kg: 82.5,
+            },
+            PokemonName::GENESECTCHILL => PokedexEntry {
+                base_stats: (71, 120, 95, 120, 95, 99),
+                types: (PokemonType::BUG, PokemonType::STEEL),
+                weight_kg: 82.5,
+            },
+            PokemonName::CHESPIN => PokedexEntry {
+                base_stats: (56, 61, 65, 48, 45, 38),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 9.0,
+            },
+            PokemonName::QUILLADIN => PokedexEntry {
+                base_stats: (61, 78, 95, 56, 58, 57),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 29.0,
+            },
+            PokemonName::CHESNAUGHT => PokedexEntry {
+                base_stats: (88, 107, 122, 74, 75, 64),
+                types: (PokemonType::GRASS, PokemonType::FIGHTING),
+                weight_kg: 90.0,
+            },
+            PokemonName::FENNEKIN => PokedexEntry {
+                base_stats: (40, 45, 40, 62, 60, 60),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 9.4,
+            },
+            PokemonName::BRAIXEN => PokedexEntry {
+                base_stats: (59, 59, 58, 90, 70, 73),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 14.5,
+            },
+            PokemonName::DELPHOX => PokedexEntry {
+                base_stats: (75, 69, 72, 114, 100, 104),
+                types: (PokemonType::FIRE, PokemonType::PSYCHIC),
+                weight_kg: 39.0,
+            },
+            PokemonName::FROAKIE => PokedexEntry {
+                base_stats: (41, 56, 40, 62, 44, 71),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 7.0,
+            },
+            PokemonName::FROGADIER => PokedexEntry {
+                base_stats: (54, 63, 52, 83, 56, 97),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 10.9,
+            },
+            PokemonName::GRENINJA => PokedexEntry {
+                base_stats: (72, 95, 67, 103, 71, 122),
+                types: (PokemonType::WATER, PokemonType::DARK),
+                weight_kg: 40.0,
+            },
+            PokemonName::GRENINJABOND => PokedexEntry {
+                base_stats: (72, 95, 67, 103, 71, 122),
+                types: (PokemonType::WATER, PokemonType::DARK),
+                weight_kg: 40.0,
+            },
+            PokemonName::GRENINJAASH => PokedexEntry {
+                base_stats: (72, 145, 67, 153, 71, 132),
+                types: (PokemonType::WATER, PokemonType::DARK),
+                weight_kg: 40.0,
+            },
+            PokemonName::BUNNELBY => PokedexEntry {
+                base_stats: (38, 36, 38, 32, 36, 57),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::DIGGERSBY => PokedexEntry {
+                base_stats: (85, 56, 77, 50, 77, 78),
+                types: (PokemonType::NORMAL, PokemonType::GROUND),
+                weight_kg: 42.4,
+            },
+            PokemonName::FLETCHLING => PokedexEntry {
+                base_stats: (45, 50, 43, 40, 38, 62),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 1.7,
+            },
+            PokemonName::FLETCHINDER => PokedexEntry {
+                base_stats: (62, 73, 55, 56, 52, 84),
+                types: (PokemonType::FIRE, PokemonType::FLYING),
+                weight_kg: 16.0,
+            },
+            PokemonName::TALONFLAME => PokedexEntry {
+                base_stats: (78, 81, 71, 74, 69, 126),
+                types: (PokemonType::FIRE, PokemonType::FLYING),
+                weight_kg: 24.5,
+            },
+            PokemonName::SCATTERBUG => PokedexEntry {
+                base_stats: (38, 35, 40, 27, 25, 35),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 2.5,
+            },
+            PokemonName::SPEWPA => PokedexEntry {
+                base_stats: (45, 22, 60, 27, 30, 29),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 8.4,
+            },
+            PokemonName::VIVILLON => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONARCHIPELAGO => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONCONTINENTAL => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONELEGANT => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONGARDEN => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONHIGHPLAINS => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONICYSNOW => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONJUNGLE => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONMARINE => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONMODERN => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONMONSOON => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONOCEAN => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONPOLAR => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONRIVER => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONSANDSTORM => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONSAVANNA => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONSUN => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONTUNDRA => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONFANCY => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::VIVILLONPOKEBALL => PokedexEntry {
+                base_stats: (80, 52, 50, 90, 50, 89),
+                types: (PokemonType::BUG, PokemonType::FLYING),
+                weight_kg: 17.0,
+            },
+            PokemonName::LITLEO => PokedexEntry {
+                base_stats: (62, 50, 58, 73, 54, 72),
+                types: (PokemonType::FIRE, PokemonType::NORMAL),
+                weight_kg: 13.5,
+            },
+            PokemonName::PYROAR => PokedexEntry {
+                base_stats: (86, 68, 72, 109, 66, 106),
+                types: (PokemonType::FIRE, PokemonType::NORMAL),
+                weight_kg: 81.5,
+            },
+            PokemonName::FLABEBE => PokedexEntry {
+                base_stats: (44, 38, 39, 61, 79, 42),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.1,
+            },
+            PokemonName::FLABEBEBLUE => PokedexEntry {
+                base_stats: (44, 38, 39, 61, 79, 42),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.1,
+            },
+            PokemonName::FLABEBEORANGE => PokedexEntry {
+                base_stats: (44, 38, 39, 61, 79, 42),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.1,
+            },
+            PokemonName::FLABEBEWHITE => PokedexEntry {
+                base_stats: (44, 38, 39, 61, 79, 42),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.1,
+            },
+            PokemonName::FLABEBEYELLOW => PokedexEntry {
+                base_stats: (44, 38, 39, 61, 79, 42),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.1,
+            },
+            PokemonName::FLOETTE => PokedexEntry {
+                base_stats: (54, 45, 47, 75, 98, 52),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.9,
+            },
+            PokemonName::FLOETTEBLUE => PokedexEntry {
+                base_stats: (54, 45, 47, 75, 98, 52),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.9,
+            },
+            PokemonName::FLOETTEORANGE => PokedexEntry {
+                base_stats: (54, 45, 47, 75, 98, 52),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.9,
+            },
+            PokemonName::FLOETTEWHITE => PokedexEntry {
+                base_stats: (54, 45, 47, 75, 98, 52),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.9,
+            },
+            PokemonName::FLOETTEYELLOW => PokedexEntry {
+                base_stats: (54, 45, 47, 75, 98, 52),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.9,
+            },
+            PokemonName::FLOETTEETERNAL => PokedexEntry {
+                base_stats: (74, 65, 67, 125, 128, 92),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.9,
+            },
+            PokemonName::FLORGES => PokedexEntry {
+                base_stats: (78, 65, 68, 112, 154, 75),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 10.0,
+            },
+            PokemonName::FLORGESBLUE => PokedexEntry {
+                base_stats: (78, 65, 68, 112, 154, 75),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 10.0,
+            },
+            PokemonName::FLORGESORANGE => PokedexEntry {
+                base_stats: (78, 65, 68, 112, 154, 75),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 10.0,
+            },
+            PokemonName::FLORGESWHITE => PokedexEntry {
+                base_stats: (78, 65, 68, 112, 154, 75),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 10.0,
+            },
+            PokemonName::FLORGESYELLOW => PokedexEntry {
+                base_stats: (78, 65, 68, 112, 154, 75),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 10.0,
+            },
+            PokemonName::SKIDDO => PokedexEntry {
+                base_stats: (66, 65, 48, 62, 57, 52),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 31.0,
+            },
+            PokemonName::GOGOAT => PokedexEntry {
+                base_stats: (123, 100, 62, 97, 81, 68),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 91.0,
+            },
+            PokemonName::PANCHAM => PokedexEntry {
+                base_stats: (67, 82, 62, 46, 48, 43),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 8.0,
+            },
+            PokemonName::PANGORO => PokedexEntry {
+                base_stats: (95, 124, 78, 69, 71, 58),
+                types: (PokemonType::FIGHTING, PokemonType::DARK),
+                weight_kg: 136.0,
+            },
+            PokemonName::FURFROU => PokedexEntry {
+                base_stats: (75, 80, 60, 65, 90, 102),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::FURFROUDANDY => PokedexEntry {
+                base_stats: (75, 80, 60, 65, 90, 102),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::FURFROUDEBUTANTE => PokedexEntry {
+                base_stats: (75, 80, 60, 65, 90, 102),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::FURFROUDIAMOND => PokedexEntry {
+                base_stats: (75, 80, 60, 65, 90, 102),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::FURFROUHEART => PokedexEntry {
+                base_stats: (75, 80, 60, 65, 90, 102),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::FURFROUKABUKI => PokedexEntry {
+                base_stats: (75, 80, 60, 65, 90, 102),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::FURFROULAREINE => PokedexEntry {
+                base_stats: (75, 80, 60, 65, 90, 102),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::FURFROUMATRON => PokedexEntry {
+                base_stats: (75, 80, 60, 65, 90, 102),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::FURFROUPHARAOH => PokedexEntry {
+                base_stats: (75, 80, 60, 65, 90, 102),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::FURFROUSTAR => PokedexEntry {
+                base_stats: (75, 80, 60, 65, 90, 102),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::ESPURR => PokedexEntry {
+                base_stats: (62, 48, 54, 63, 60, 68),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 3.5,
+            },
+            PokemonName::MEOWSTIC => PokedexEntry {
+                base_stats: (74, 48, 76, 83, 81, 104),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 8.5,
+            },
+            PokemonName::MEOWSTICF => PokedexEntry {
+                base_stats: (74, 48, 76, 83, 81, 104),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 8.5,
+            },
+            PokemonName::HONEDGE => PokedexEntry {
+                base_stats: (45, 80, 100, 35, 37, 28),
+                types: (PokemonType::STEEL, PokemonType::GHOST),
+                weight_kg: 2.0,
+            },
+            PokemonName::DOUBLADE => PokedexEntry {
+                base_stats: (59, 110, 150, 45, 49, 35),
+                types: (PokemonType::STEEL, PokemonType::GHOST),
+                weight_kg: 4.5,
+            },
+            PokemonName::AEGISLASH => PokedexEntry {
+                base_stats: (60, 50, 140, 50, 140, 60),
+                types: (PokemonType::STEEL, PokemonType::GHOST),
+                weight_kg: 53.0,
+            },
+            PokemonName::AEGISLASHBLADE => PokedexEntry {
+                base_stats: (60, 140, 50, 140, 50, 60),
+                types: (PokemonType::STEEL, PokemonType::GHOST),
+                weight_kg: 53.0,
+            },
+            PokemonName::SPRITZEE => PokedexEntry {
+                base_stats: (78, 52, 60, 63, 65, 23),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::AROMATISSE => PokedexEntry {
+                base_stats: (101, 72, 72, 99, 89, 29),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 15.5,
+            },
+            PokemonName::SWIRLIX => PokedexEntry {
+                base_stats: (62, 48, 66, 59, 57, 49),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 3.5,
+            },
+            PokemonName::SLURPUFF => PokedexEntry {
+                base_stats: (82, 80, 86, 85, 75, 72),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::INKAY => PokedexEntry {
+                base_stats: (53, 54, 53, 37, 46, 45),
+                types: (PokemonType::DARK, PokemonType::PSYCHIC),
+                weight_kg: 3.5,
+            },
+            PokemonName::MALAMAR => PokedexEntry {
+                base_stats: (86, 92, 88, 68, 75, 73),
+                types: (PokemonType::DARK, PokemonType::PSYCHIC),
+                weight_kg: 47.0,
+            },
+            PokemonName::BINACLE => PokedexEntry {
+                base_stats: (42, 52, 67, 39, 56, 50),
+                types: (PokemonType::ROCK, PokemonType::WATER),
+                weight_kg: 31.0,
+            },
+            PokemonName::BARBARACLE => PokedexEntry {
+                base_stats: (72, 105, 115, 54, 86, 68),
+                types: (PokemonType::ROCK, PokemonType::WATER),
+                weight_kg: 96.0,
+            },
+            PokemonName::SKRELP => PokedexEntry {
+                base_stats: (50, 60, 60, 60, 60, 30),
+                types: (PokemonType::POISON, PokemonType::WATER),
+                weight_kg: 7.3,
+            },
+            PokemonName::DRAGALGE => PokedexEntry {
+                base_stats: (65, 75, 90, 97, 123, 44),
+                types: (PokemonType::POISON, PokemonType::DRAGON),
+                weight_kg: 81.5,
+            },
+            PokemonName::CLAUNCHER => PokedexEntry {
+                base_stats: (50, 53, 62, 58, 63, 44),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 8.3,
+            },
+            PokemonName::CLAWITZER => PokedexEntry {
+                base_stats: (71, 73, 88, 120, 89, 59),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 35.3,
+            },
+            PokemonName::HELIOPTILE => PokedexEntry {
+                base_stats: (44, 38, 33, 61, 43, 70),
+                types: (PokemonType::ELECTRIC, PokemonType::NORMAL),
+                weight_kg: 6.0,
+            },
+            PokemonName::HELIOLISK => PokedexEntry {
+                base_stats: (62, 55, 52, 109, 94, 109),
+                types: (PokemonType::ELECTRIC, PokemonType::NORMAL),
+                weight_kg: 21.0,
+            },
+            PokemonName::TYRUNT => PokedexEntry {
+                base_stats: (58, 89, 77, 45, 45, 48),
+                types: (PokemonType::ROCK, PokemonType::DRAGON),
+                weight_kg: 26.0,
+            },
+            PokemonName::TYRANTRUM => PokedexEntry {
+                base_stats: (82, 121, 119, 69, 59, 71),
+                types: (PokemonType::ROCK, PokemonType::DRAGON),
+                weight_kg: 270.0,
+            },
+            PokemonName::AMAURA => PokedexEntry {
+                base_stats: (77, 59, 50, 67, 63, 46),
+                types: (PokemonType::ROCK, PokemonType::ICE),
+                weight_kg: 25.2,
+            },
+            PokemonName::AURORUS => PokedexEntry {
+                base_stats: (123, 77, 72, 99, 92, 58),
+                types: (PokemonType::ROCK, PokemonType::ICE),
+                weight_kg: 225.0,
+            },
+            PokemonName::SYLVEON => PokedexEntry {
+                base_stats: (95, 65, 65, 110, 130, 60),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 23.5,
+            },
+            PokemonName::HAWLUCHA => PokedexEntry {
+                base_stats: (78, 92, 75, 74, 63, 118),
+                types: (PokemonType::FIGHTING, PokemonType::FLYING),
+                weight_kg: 21.5,
+            },
+            PokemonName::DEDENNE => PokedexEntry {
+                base_stats: (67, 58, 57, 81, 67, 101),
+                types: (PokemonType::ELECTRIC, PokemonType::FAIRY),
+                weight_kg: 2.2,
+            },
+            PokemonName::CARBINK => PokedexEntry {
+                base_stats: (50, 50, 150, 50, 150, 50),
+                types: (PokemonType::ROCK, PokemonType::FAIRY),
+                weight_kg: 5.7,
+            },
+            PokemonName::GOOMY => PokedexEntry {
+                base_stats: (45, 50, 35, 55, 75, 40),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 2.8,
+            },
+            PokemonName::SLIGGOO => PokedexEntry {
+                base_stats: (68, 75, 53, 83, 113, 60),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 17.5,
+            },
+            PokemonName::SLIGGOOHISUI => PokedexEntry {
+                base_stats: (58, 75, 83, 83, 113, 40),
+                types: (PokemonType::STEEL, PokemonType::DRAGON),
+                weight_kg: 68.5,
+            },
+            PokemonName::GOODRA => PokedexEntry {
+                base_stats: (90, 100, 70, 110, 150, 80),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 150.5,
+            },
+            PokemonName::GOODRAHISUI => PokedexEntry {
+                base_stats: (80, 100, 100, 110, 150, 60),
+                types: (PokemonType::STEEL, PokemonType::DRAGON),
+                weight_kg: 334.1,
+            },
+            PokemonName::KLEFKI => PokedexEntry {
+                base_stats: (57, 80, 91, 80, 87, 75),
+                types: (PokemonType::STEEL, PokemonType::FAIRY),
+                weight_kg: 3.0,
+            },
+            PokemonName::PHANTUMP => PokedexEntry {
+                base_stats: (43, 70, 48, 50, 60, 38),
+                types: (PokemonType::GHOST, PokemonType::GRASS),
+                weight_kg: 7.0,
+            },
+            PokemonName::TREVENANT => PokedexEntry {
+                base_stats: (85, 110, 76, 65, 82, 56),
+                types: (PokemonType::GHOST, PokemonType::GRASS),
+                weight_kg: 71.0,
+            },
+            PokemonName::PUMPKABOO => PokedexEntry {
+                base_stats: (49, 66, 70, 44, 55, 51),
+                types: (PokemonType::GHOST, PokemonType::GRASS),
+                weight_kg: 5.0,
+            },
+            PokemonName::PUMPKABOOSMALL => PokedexEntry {
+                base_stats: (44, 66, 70, 44, 55, 56),
+                types: (PokemonType::GHOST, PokemonType::GRASS),
+                weight_kg: 3.5,
+            },
+            PokemonName::PUMPKABOOLARGE => PokedexEntry {
+                base_stats: (54, 66, 70, 44, 55, 46),
+                types: (PokemonType::GHOST, PokemonType::GRASS),
+                weight_kg: 7.5,
+            },
+            PokemonName::PUMPKABOOSUPER => PokedexEntry {
+                base_stats: (59, 66, 70, 44, 55, 41),
+                types: (PokemonType::GHOST, PokemonType::GRASS),
+                weight_kg: 15.0,
+            },
+            PokemonName::GOURGEIST => PokedexEntry {
+                base_stats: (65, 90, 122, 58, 75, 84),
+                types: (PokemonType::GHOST, PokemonType::GRASS),
+                weight_kg: 12.5,
+            },
+            PokemonName::GOURGEISTSMALL => PokedexEntry {
+                base_stats: (55, 85, 122, 58, 75, 99),
+                types: (PokemonType::GHOST, PokemonType::GRASS),
+                weight_kg: 9.5,
+            },
+            PokemonName::GOURGEISTLARGE => PokedexEntry {
+                base_stats: (75, 95, 122, 58, 75, 69),
+                types: (PokemonType::GHOST, PokemonType::GRASS),
+                weight_kg: 14.0,
+            },
+            PokemonName::GOURGEISTSUPER => PokedexEntry {
+                base_stats: (85, 100, 122, 58, 75, 54),
+                types: (PokemonType::GHOST, PokemonType::GRASS),
+                weight_kg: 39.0,
+            },
+            PokemonName::BERGMITE => PokedexEntry {
+                base_stats: (55, 69, 85, 32, 35, 28),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 99.5,
+            },
+            PokemonName::AVALUGG => PokedexEntry {
+                base_stats: (95, 117, 184, 44, 46, 28),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 505.0,
+            },
+            PokemonName::AVALUGGHISUI => PokedexEntry {
+                base_stats: (95, 127, 184, 34, 36, 38),
+                types: (PokemonType::ICE, PokemonType::ROCK),
+                weight_kg: 262.4,
+            },
+            PokemonName::NOIBAT => PokedexEntry {
+                base_stats: (40, 30, 35, 45, 40, 55),
+                types: (PokemonType::FLYING, PokemonType::DRAGON),
+                weight_kg: 8.0,
+            },
+            PokemonName::NOIVERN => PokedexEntry {
+                base_stats: (85, 70, 80, 97, 80, 123),
+                types: (PokemonType::FLYING, PokemonType::DRAGON),
+                weight_kg: 85.0,
+            },
+            PokemonName::XERNEAS => PokedexEntry {
+                base_stats: (126, 131, 95, 131, 98, 99),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 215.0,
+            },
+            PokemonName::XERNEASNEUTRAL => PokedexEntry {
+                base_stats: (126, 131, 95, 131, 98, 99),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 215.0,
+            },
+            PokemonName::YVELTAL => PokedexEntry {
+                base_stats: (126, 131, 95, 131, 98, 99),
+                types: (PokemonType::DARK, PokemonType::FLYING),
+                weight_kg: 203.0,
+            },
+            PokemonName::ZYGARDE => PokedexEntry {
+                base_stats: (108, 100, 121, 81, 95, 95),
+                types: (PokemonType::DRAGON, PokemonType::GROUND),
+                weight_kg: 305.0,
+            },
+            PokemonName::ZYGARDE10 => PokedexEntry {
+                base_stats: (54, 100, 71, 61, 85, 115),
+                types: (PokemonType::DRAGON, PokemonType::GROUND),
+                weight_kg: 33.5,
+            },
+            PokemonName::ZYGARDECOMPLETE => PokedexEntry {
+                base_stats: (216, 100, 121, 91, 95, 85),
+                types: (PokemonType::DRAGON, PokemonType::GROUND),
+                weight_kg: 610.0,
+            },
+            PokemonName::DIANCIE => PokedexEntry {
+                base_stats: (50, 100, 150, 100, 150, 50),
+                types: (PokemonType::ROCK, PokemonType::FAIRY),
+                weight_kg: 8.8,
+            },
+            PokemonName::DIANCIEMEGA => PokedexEntry {
+                base_stats: (50, 160, 110, 160, 110, 110),
+                types: (PokemonType::ROCK, PokemonType::FAIRY),
+                weight_kg: 27.8,
+            },
+            PokemonName::HOOPA => PokedexEntry {
+                base_stats: (80, 110, 60, 150, 130, 70),
+                types: (PokemonType::PSYCHIC, PokemonType::GHOST),
+                weight_kg: 9.0,
+            },
+            PokemonName::HOOPAUNBOUND => PokedexEntry {
+                base_stats: (80, 160, 60, 170, 130, 80),
+                types: (PokemonType::PSYCHIC, PokemonType::DARK),
+                weight_kg: 490.0,
+            },
+            PokemonName::VOLCANION => PokedexEntry {
+                base_stats: (80, 110, 120, 130, 90, 70),
+                types: (PokemonType::FIRE, PokemonType::WATER),
+                weight_kg: 195.0,
+            },
+            PokemonName::ROWLET => PokedexEntry {
+                base_stats: (68, 55, 55, 50, 50, 42),
+                types: (PokemonType::GRASS, PokemonType::FLYING),
+                weight_kg: 1.5,
+            },
+            PokemonName::DARTRIX => PokedexEntry {
+                base_stats: (78, 75, 75, 70, 70, 52),
+                types: (PokemonType::GRASS, PokemonType::FLYING),
+                weight_kg: 16.0,
+            },
+            PokemonName::DECIDUEYE => PokedexEntry {
+                base_stats: (78, 107, 75, 100, 100, 70),
+                types: (PokemonType::GRASS, PokemonType::GHOST),
+                weight_kg: 36.6,
+            },
+            PokemonName::DECIDUEYEHISUI => PokedexEntry {
+                base_stats: (88, 112, 80, 95, 95, 60),
+                types: (PokemonType::GRASS, PokemonType::FIGHTING),
+                weight_kg: 37.0,
+            },
+            PokemonName::LITTEN => PokedexEntry {
+                base_stats: (45, 65, 40, 60, 40, 70),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 4.3,
+            },
+            PokemonName::TORRACAT => PokedexEntry {
+                base_stats: (65, 85, 50, 80, 50, 90),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 25.0,
+            },
+            PokemonName::INCINEROAR => PokedexEntry {
+                base_stats: (95, 115, 90, 80, 90, 60),
+                types: (PokemonType::FIRE, PokemonType::DARK),
+                weight_kg: 83.0,
+            },
+            PokemonName::POPPLIO => PokedexEntry {
+                base_stats: (50, 54, 54, 66, 56, 40),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 7.5,
+            },
+            PokemonName::BRIONNE => PokedexEntry {
+                base_stats: (60, 69, 69, 91, 81, 50),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 17.5,
+            },
+            PokemonName::PRIMARINA => PokedexEntry {
+                base_stats: (80, 74, 74, 126, 116, 60),
+                types: (PokemonType::WATER, PokemonType::FAIRY),
+                weight_kg: 44.0,
+            },
+            PokemonName::PIKIPEK => PokedexEntry {
+                base_stats: (35, 75, 30, 30, 30, 65),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 1.2,
+            },
+            PokemonName::TRUMBEAK => PokedexEntry {
+                base_stats: (55, 85, 50, 40, 50, 75),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 14.8,
+            },
+            PokemonName::TOUCANNON => PokedexEntry {
+                base_stats: (80, 120, 75, 75, 75, 60),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 26.0,
+            },
+            PokemonName::YUNGOOS => PokedexEntry {
+                base_stats: (48, 70, 30, 30, 30, 45),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::GUMSHOOS => PokedexEntry {
+                base_stats: (88, 110, 60, 55, 60, 45),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 14.2,
+            },
+            PokemonName::GUMSHOOSTOTEM => PokedexEntry {
+                base_stats: (88, 110, 60, 55, 60, 45),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 60.0,
+            },
+            PokemonName::GRUBBIN => PokedexEntry {
+                base_stats: (47, 62, 45, 55, 45, 46),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 4.4,
+            },
+            PokemonName::CHARJABUG => PokedexEntry {
+                base_stats: (57, 82, 95, 55, 75, 36),
+                types: (PokemonType::BUG, PokemonType::ELECTRIC),
+                weight_kg: 10.5,
+            },
+            PokemonName::VIKAVOLT => PokedexEntry {
+                base_stats: (77, 70, 90, 145, 75, 43),
+                types: (PokemonType::BUG, PokemonType::ELECTRIC),
+                weight_kg: 45.0,
+            },
+            PokemonName::VIKAVOLTTOTEM => PokedexEntry {
+                base_stats: (77, 70, 90, 145, 75, 43),
+                types: (PokemonType::BUG, PokemonType::ELECTRIC),
+                weight_kg: 147.5,
+            },
+            PokemonName::CRABRAWLER => PokedexEntry {
+                base_stats: (47, 82, 57, 42, 47, 63),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 7.0,
+            },
+            PokemonName::CRABOMINABLE => PokedexEntry {
+                base_stats: (97, 132, 77, 62, 67, 43),
+                types: (PokemonType::FIGHTING, PokemonType::ICE),
+                weight_kg: 180.0,
+            },
+            PokemonName::ORICORIO => PokedexEntry {
+                base_stats: (75, 70, 70, 98, 70, 93),
+                types: (PokemonType::FIRE, PokemonType::FLYING),
+                weight_kg: 3.4,
+            },
+            PokemonName::ORICORIOPOMPOM => PokedexEntry {
+                base_stats: (75, 70, 70, 98, 70, 93),
+                types: (PokemonType::ELECTRIC, PokemonType::FLYING),
+                weight_kg: 3.4,
+            },
+            PokemonName::ORICORIOPAU => PokedexEntry {
+                base_stats: (75, 70, 70, 98, 70, 93),
+                types: (PokemonType::PSYCHIC, PokemonType::FLYING),
+                weight_kg: 3.4,
+            },
+            PokemonName::ORICORIOSENSU => PokedexEntry {
+                base_stats: (75, 70, 70, 98, 70, 93),
+                types: (PokemonType::GHOST, PokemonType::FLYING),
+                weight_kg: 3.4,
+            },
+            PokemonName::CUTIEFLY => PokedexEntry {
+                base_stats: (40, 45, 40, 55, 40, 84),
+                types: (PokemonType::BUG, PokemonType::FAIRY),
+                weight_kg: 0.2,
+            },
+            PokemonName::RIBOMBEE => PokedexEntry {
+                base_stats: (60, 55, 60, 95, 70, 124),
+                types: (PokemonType::BUG, PokemonType::FAIRY),
+                weight_kg: 0.5,
+            },
+            PokemonName::RIBOMBEETOTEM => PokedexEntry {
+                base_stats: (60, 55, 60, 95, 70, 124),
+                types: (PokemonType::BUG, PokemonType::FAIRY),
+                weight_kg: 2.0,
+            },
+            PokemonName::ROCKRUFF => PokedexEntry {
+                base_stats: (45, 65, 40, 30, 40, 60),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 9.2,
+            },
+            PokemonName::LYCANROC => PokedexEntry {
+                base_stats: (75, 115, 65, 55, 65, 112),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 25.0,
+            },
+            PokemonName::LYCANROCMIDNIGHT => PokedexEntry {
+                base_stats: (85, 115, 75, 55, 75, 82),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 25.0,
+            },
+            PokemonName::LYCANROCDUSK => PokedexEntry {
+                base_stats: (75, 117, 65, 55, 65, 110),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 25.0,
+            },
+            PokemonName::WISHIWASHI => PokedexEntry {
+                base_stats: (45, 20, 20, 25, 25, 40),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 0.3,
+            },
+            PokemonName::WISHIWASHISCHOOL => PokedexEntry {
+                base_stats: (45, 140, 130, 140, 135, 30),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 78.6,
+            },
+            PokemonName::MAREANIE => PokedexEntry {
+                base_stats: (50, 53, 62, 43, 52, 45),
+                types: (PokemonType::POISON, PokemonType::WATER),
+                weight_kg: 8.0,
+            },
+            PokemonName::TOXAPEX => PokedexEntry {
+                base_stats: (50, 63, 152, 53, 142, 35),
+                types: (PokemonType::POISON, PokemonType::WATER),
+                weight_kg: 14.5,
+            },
+            PokemonName::MUDBRAY => PokedexEntry {
+                base_stats: (70, 100, 70, 45, 55, 45),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 110.0,
+            },
+            PokemonName::MUDSDALE => PokedexEntry {
+                base_stats: (100, 125, 100, 55, 85, 35),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 920.0,
+            },
+            PokemonName::DEWPIDER => PokedexEntry {
+                base_stats: (38, 40, 52, 40, 72, 27),
+                types: (PokemonType::WATER, PokemonType::BUG),
+                weight_kg: 4.0,
+            },
+            PokemonName::ARAQUANID => PokedexEntry {
+                base_stats: (68, 70, 92, 50, 132, 42),
+                types: (PokemonType::WATER, PokemonType::BUG),
+                weight_kg: 82.0,
+            },
+            PokemonName::ARAQUANIDTOTEM => PokedexEntry {
+                base_stats: (68, 70, 92, 50, 132, 42),
+                types: (PokemonType::WATER, PokemonType::BUG),
+                weight_kg: 217.5,
+            },
+            PokemonName::FOMANTIS => PokedexEntry {
+                base_stats: (40, 55, 35, 50, 35, 35),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 1.5,
+            },
+            PokemonName::LURANTIS => PokedexEntry {
+                base_stats: (70, 105, 90, 80, 90, 45),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 18.5,
+            },
+            PokemonName::LURANTISTOTEM => PokedexEntry {
+                base_stats: (70, 105, 90, 80, 90, 45),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 58.0,
+            },
+            PokemonName::MORELULL => PokedexEntry {
+                base_stats: (40, 35, 55, 65, 75, 15),
+                types: (PokemonType::GRASS, PokemonType::FAIRY),
+                weight_kg: 1.5,
+            },
+            PokemonName::SHIINOTIC => PokedexEntry {
+                base_stats: (60, 45, 80, 90, 100, 30),
+                types: (PokemonType::GRASS, PokemonType::FAIRY),
+                weight_kg: 11.5,
+            },
+            PokemonName::SALANDIT => PokedexEntry {
+                base_stats: (48, 44, 40, 71, 40, 77),
+                types: (PokemonType::POISON, PokemonType::FIRE),
+                weight_kg: 4.8,
+            },
+            PokemonName::SALAZZLE => PokedexEntry {
+                base_stats: (68, 64, 60, 111, 60, 117),
+                types: (PokemonType::POISON, PokemonType::FIRE),
+                weight_kg: 22.2,
+            },
+            PokemonName::SALAZZLETOTEM => PokedexEntry {
+                base_stats: (68, 64, 60, 111, 60, 117),
+                types: (PokemonType::POISON, PokemonType::FIRE),
+                weight_kg: 81.0,
+            },
+            PokemonName::STUFFUL => PokedexEntry {
+                base_stats: (70, 75, 50, 45, 50, 50),
+                types: (PokemonType::NORMAL, PokemonType::FIGHTING),
+                weight_kg: 6.8,
+            },
+            PokemonName::BEWEAR => PokedexEntry {
+                base_stats: (120, 125, 80, 55, 60, 60),
+                types: (PokemonType::NORMAL, PokemonType::FIGHTING),
+                weight_kg: 135.0,
+            },
+            PokemonName::BOUNSWEET => PokedexEntry {
+                base_stats: (42, 30, 38, 30, 38, 32),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 3.2,
+            },
+            PokemonName::STEENEE => PokedexEntry {
+                base_stats: (52, 40, 48, 40, 48, 62),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 8.2,
+            },
+            PokemonName::TSAREENA => PokedexEntry {
+                base_stats: (72, 120, 98, 50, 98, 72),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 21.4,
+            },
+            PokemonName::COMFEY => PokedexEntry {
+                base_stats: (51, 52, 90, 82, 110, 100),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.3,
+            },
+            PokemonName::ORANGURU => PokedexEntry {
+                base_stats: (90, 60, 80, 90, 110, 60),
+                types: (PokemonType::NORMAL, PokemonType::PSYCHIC),
+                weight_kg: 76.0,
+            },
+            PokemonName::PASSIMIAN => PokedexEntry {
+                base_stats: (100, 120, 90, 40, 60, 80),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 82.8,
+            },
+            PokemonName::WIMPOD => PokedexEntry {
+                base_stats: (25, 35, 40, 20, 30, 80),
+                types: (PokemonType::BUG, PokemonType::WATER),
+                weight_kg: 12.0,
+            },
+            PokemonName::GOLISOPOD => PokedexEntry {
+                base_stats: (75, 125, 140, 60, 90, 40),
+                types: (PokemonType::BUG, PokemonType::WATER),
+                weight_kg: 108.0,
+            },
+            PokemonName::SANDYGAST => PokedexEntry {
+                base_stats: (55, 55, 80, 70, 45, 15),
+                types: (PokemonType::GHOST, PokemonType::GROUND),
+                weight_kg: 70.0,
+            },
+            PokemonName::PALOSSAND => PokedexEntry {
+                base_stats: (85, 75, 110, 100, 75, 35),
+                types: (PokemonType::GHOST, PokemonType::GROUND),
+                weight_kg: 250.0,
+            },
+            PokemonName::PYUKUMUKU => PokedexEntry {
+                base_stats: (55, 60, 130, 30, 130, 5),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 1.2,
+            },
+            PokemonName::TYPENULL => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 59),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 120.5,
+            },
+            PokemonName::SILVALLY => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYBUG => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYDARK => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYDRAGON => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYELECTRIC => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYFAIRY => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYFIGHTING => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYFIRE => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYFLYING => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::FLYING, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYGHOST => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYGRASS => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYGROUND => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYICE => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYPOISON => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYPSYCHIC => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYROCK => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYSTEEL => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::SILVALLYWATER => PokedexEntry {
+                base_stats: (95, 95, 95, 95, 95, 95),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 100.5,
+            },
+            PokemonName::MINIOR => PokedexEntry {
+                base_stats: (60, 100, 60, 100, 60, 120),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 0.3,
+            },
+            PokemonName::MINIORORANGE => PokedexEntry {
+                base_stats: (60, 100, 60, 100, 60, 120),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 0.3,
+            },
+            PokemonName::MINIORYELLOW => PokedexEntry {
+                base_stats: (60, 100, 60, 100, 60, 120),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 0.3,
+            },
+            PokemonName::MINIORGREEN => PokedexEntry {
+                base_stats: (60, 100, 60, 100, 60, 120),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 0.3,
+            },
+            PokemonName::MINIORBLUE => PokedexEntry {
+                base_stats: (60, 100, 60, 100, 60, 120),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 0.3,
+            },
+            PokemonName::MINIORINDIGO => PokedexEntry {
+                base_stats: (60, 100, 60, 100, 60, 120),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 0.3,
+            },
+            PokemonName::MINIORVIOLET => PokedexEntry {
+                base_stats: (60, 100, 60, 100, 60, 120),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 0.3,
+            },
+            PokemonName::MINIORMETEOR => PokedexEntry {
+                base_stats: (60, 60, 100, 60, 100, 60),
+                types: (PokemonType::ROCK, PokemonType::FLYING),
+                weight_kg: 40.0,
+            },
+            PokemonName::KOMALA => PokedexEntry {
+                base_stats: (65, 115, 65, 75, 95, 65),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 19.9,
+            },
+            PokemonName::TURTONATOR => PokedexEntry {
+                base_stats: (60, 78, 135, 91, 85, 36),
+                types: (PokemonType::FIRE, PokemonType::DRAGON),
+                weight_kg: 212.0,
+            },
+            PokemonName::TOGEDEMARU => PokedexEntry {
+                base_stats: (65, 98, 63, 40, 73, 96),
+                types: (PokemonType::ELECTRIC, PokemonType::STEEL),
+                weight_kg: 3.3,
+            },
+            PokemonName::TOGEDEMARUTOTEM => PokedexEntry {
+                base_stats: (65, 98, 63, 40, 73, 96),
+                types: (PokemonType::ELECTRIC, PokemonType::STEEL),
+                weight_kg: 13.0,
+            },
+            PokemonName::MIMIKYU => PokedexEntry {
+                base_stats: (55, 90, 80, 50, 105, 96),
+                types: (PokemonType::GHOST, PokemonType::FAIRY),
+                weight_kg: 0.7,
+            },
+            PokemonName::MIMIKYUBUSTED => PokedexEntry {
+                base_stats: (55, 90, 80, 50, 105, 96),
+                types: (PokemonType::GHOST, PokemonType::FAIRY),
+                weight_kg: 0.7,
+            },
+            PokemonName::MIMIKYUTOTEM => PokedexEntry {
+                base_stats: (55, 90, 80, 50, 105, 96),
+                types: (PokemonType::GHOST, PokemonType::FAIRY),
+                weight_kg: 2.8,
+            },
+            PokemonName::MIMIKYUBUSTEDTOTEM => PokedexEntry {
+                base_stats: (55, 90, 80, 50, 105, 96),
+                types: (PokemonType::GHOST, PokemonType::FAIRY),
+                weight_kg: 2.8,
+            },
+            PokemonName::BRUXISH => PokedexEntry {
+                base_stats: (68, 105, 70, 70, 70, 92),
+                types: (PokemonType::WATER, PokemonType::PSYCHIC),
+                weight_kg: 19.0,
+            },
+            PokemonName::DRAMPA => PokedexEntry {
+                base_stats: (78, 60, 85, 135, 91, 36),
+                types: (PokemonType::NORMAL, PokemonType::DRAGON),
+                weight_kg: 185.0,
+            },
+            PokemonName::DHELMISE => PokedexEntry {
+                base_stats: (70, 131, 100, 86, 90, 40),
+                types: (PokemonType::GHOST, PokemonType::GRASS),
+                weight_kg: 210.0,
+            },
+            PokemonName::JANGMOO => PokedexEntry {
+                base_stats: (45, 55, 65, 45, 45, 45),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 29.7,
+            },
+            PokemonName::HAKAMOO => PokedexEntry {
+                base_stats: (55, 75, 90, 65, 70, 65),
+                types: (PokemonType::DRAGON, PokemonType::FIGHTING),
+                weight_kg: 47.0,
+            },
+            PokemonName::KOMMOO => PokedexEntry {
+                base_stats: (75, 110, 125, 100, 105, 85),
+                types: (PokemonType::DRAGON, PokemonType::FIGHTING),
+                weight_kg: 78.2,
+            },
+            PokemonName::KOMMOOTOTEM => PokedexEntry {
+                base_stats: (75, 110, 125, 100, 105, 85),
+                types: (PokemonType::DRAGON, PokemonType::FIGHTING),
+                weight_kg: 207.5,
+            },
+            PokemonName::TAPUKOKO => PokedexEntry {
+                base_stats: (70, 115, 85, 95, 75, 130),
+                types: (PokemonType::ELECTRIC, PokemonType::FAIRY),
+                weight_kg: 20.5,
+            },
+            PokemonName::TAPULELE => PokedexEntry {
+                base_stats: (70, 85, 75, 130, 115, 95),
+                types: (PokemonType::PSYCHIC, PokemonType::FAIRY),
+                weight_kg: 18.6,
+            },
+            PokemonName::TAPUBULU => PokedexEntry {
+                base_stats: (70, 130, 115, 85, 95, 75),
+                types: (PokemonType::GRASS, PokemonType::FAIRY),
+                weight_kg: 45.5,
+            },
+            PokemonName::TAPUFINI => PokedexEntry {
+                base_stats: (70, 75, 115, 95, 130, 85),
+                types: (PokemonType::WATER, PokemonType::FAIRY),
+                weight_kg: 21.2,
+            },
+            PokemonName::COSMOG => PokedexEntry {
+                base_stats: (43, 29, 31, 29, 31, 37),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 0.1,
+            },
+            PokemonName::COSMOEM => PokedexEntry {
+                base_stats: (43, 29, 131, 29, 131, 37),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 999.9,
+            },
+            PokemonName::SOLGALEO => PokedexEntry {
+                base_stats: (137, 137, 107, 113, 89, 97),
+                types: (PokemonType::PSYCHIC, PokemonType::STEEL),
+                weight_kg: 230.0,
+            },
+            PokemonName::LUNALA => PokedexEntry {
+                base_stats: (137, 113, 89, 137, 107, 97),
+                types: (PokemonType::PSYCHIC, PokemonType::GHOST),
+                weight_kg: 120.0,
+            },
+            PokemonName::NIHILEGO => PokedexEntry {
+                base_stats: (109, 53, 47, 127, 131, 103),
+                types: (PokemonType::ROCK, PokemonType::POISON),
+                weight_kg: 55.5,
+            },
+            PokemonName::BUZZWOLE => PokedexEntry {
+                base_stats: (107, 139, 139, 53, 53, 79),
+                types: (PokemonType::BUG, PokemonType::FIGHTING),
+                weight_kg: 333.6,
+            },
+            PokemonName::PHEROMOSA => PokedexEntry {
+                base_stats: (71, 137, 37, 137, 37, 151),
+                types: (PokemonType::BUG, PokemonType::FIGHTING),
+                weight_kg: 25.0,
+            },
+            PokemonName::XURKITREE => PokedexEntry {
+                base_stats: (83, 89, 71, 173, 71, 83),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 100.0,
+            },
+            PokemonName::CELESTEELA => PokedexEntry {
+                base_stats: (97, 101, 103, 107, 101, 61),
+                types: (PokemonType::STEEL, PokemonType::FLYING),
+                weight_kg: 999.9,
+            },
+            PokemonName::KARTANA => PokedexEntry {
+                base_stats: (59, 181, 131, 59, 31, 109),
+                types: (PokemonType::GRASS, PokemonType::STEEL),
+                weight_kg: 0.1,
+            },
+            PokemonName::GUZZLORD => PokedexEntry {
+                base_stats: (223, 101, 53, 97, 53, 43),
+                types: (PokemonType::DARK, PokemonType::DRAGON),
+                weight_kg: 888.0,
+            },
+            PokemonName::NECROZMA => PokedexEntry {
+                base_stats: (97, 107, 101, 127, 89, 79),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 230.0,
+            },
+            PokemonName::NECROZMADUSKMANE => PokedexEntry {
+                base_stats: (97, 157, 127, 113, 109, 77),
+                types: (PokemonType::PSYCHIC, PokemonType::STEEL),
+                weight_kg: 460.0,
+            },
+            PokemonName::NECROZMADAWNWINGS => PokedexEntry {
+                base_stats: (97, 113, 109, 157, 127, 77),
+                types: (PokemonType::PSYCHIC, PokemonType::GHOST),
+                weight_kg: 350.0,
+            },
+            PokemonName::NECROZMAULTRA => PokedexEntry {
+                base_stats: (97, 167, 97, 167, 97, 129),
+                types: (PokemonType::PSYCHIC, PokemonType::DRAGON),
+                weight_kg: 230.0,
+            },
+            PokemonName::MAGEARNA => PokedexEntry {
+                base_stats: (80, 95, 115, 130, 115, 65),
+                types: (PokemonType::STEEL, PokemonType::FAIRY),
+                weight_kg: 80.5,
+            },
+            PokemonName::MAGEARNAORIGINAL => PokedexEntry {
+                base_stats: (80, 95, 115, 130, 115, 65),
+                types: (PokemonType::STEEL, PokemonType::FAIRY),
+                weight_kg: 80.5,
+            },
+            PokemonName::MARSHADOW => PokedexEntry {
+                base_stats: (90, 125, 80, 90, 90, 125),
+                types: (PokemonType::FIGHTING, PokemonType::GHOST),
+                weight_kg: 22.2,
+            },
+            PokemonName::POIPOLE => PokedexEntry {
+                base_stats: (67, 73, 67, 73, 67, 73),
+                types: (PokemonType::POISON, PokemonType::TYPELESS),
+                weight_kg: 1.8,
+            },
+            PokemonName::NAGANADEL => PokedexEntry {
+                base_stats: (73, 73, 73, 127, 73, 121),
+                types: (PokemonType::POISON, PokemonType::DRAGON),
+                weight_kg: 150.0,
+            },
+            PokemonName::STAKATAKA => PokedexEntry {
+                base_stats: (61, 131, 211, 53, 101, 13),
+                types: (PokemonType::ROCK, PokemonType::STEEL),
+                weight_kg: 820.0,
+            },
+            PokemonName::BLACEPHALON => PokedexEntry {
+                base_stats: (53, 127, 53, 151, 79, 107),
+                types: (PokemonType::FIRE, PokemonType::GHOST),
+                weight_kg: 13.0,
+            },
+            PokemonName::ZERAORA => PokedexEntry {
+                base_stats: (88, 112, 75, 102, 80, 143),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 44.5,
+            },
+            PokemonName::MELTAN => PokedexEntry {
+                base_stats: (46, 65, 65, 55, 35, 34),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 8.0,
+            },
+            PokemonName::MELMETAL => PokedexEntry {
+                base_stats: (135, 143, 143, 80, 65, 34),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 800.0,
+            },
+            PokemonName::MELMETALGMAX => PokedexEntry {
+                base_stats: (135, 143, 143, 80, 65, 34),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 800.0,
+            },
+            PokemonName::GROOKEY => PokedexEntry {
+                base_stats: (50, 65, 50, 40, 40, 65),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::THWACKEY => PokedexEntry {
+                base_stats: (70, 85, 70, 55, 60, 80),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 14.0,
+            },
+            PokemonName::RILLABOOM => PokedexEntry {
+                base_stats: (100, 125, 90, 60, 70, 85),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 90.0,
+            },
+            PokemonName::RILLABOOMGMAX => PokedexEntry {
+                base_stats: (100, 125, 90, 60, 70, 85),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 90.0,
+            },
+            PokemonName::SCORBUNNY => PokedexEntry {
+                base_stats: (50, 71, 40, 40, 40, 69),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 4.5,
+            },
+            PokemonName::RABOOT => PokedexEntry {
+                base_stats: (65, 86, 60, 55, 60, 94),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 9.0,
+            },
+            PokemonName::CINDERACE => PokedexEntry {
+                base_stats: (80, 116, 75, 65, 75, 119),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 33.0,
+            },
+            PokemonName::CINDERACEGMAX => PokedexEntry {
+                base_stats: (80, 116, 75, 65, 75, 119),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 33.0,
+            },
+            PokemonName::SOBBLE => PokedexEntry {
+                base_stats: (50, 40, 40, 70, 40, 70),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 4.0,
+            },
+            PokemonName::DRIZZILE => PokedexEntry {
+                base_stats: (65, 60, 55, 95, 55, 90),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 11.5,
+            },
+            PokemonName::INTELEON => PokedexEntry {
+                base_stats: (70, 85, 65, 125, 65, 120),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 45.2,
+            },
+            PokemonName::INTELEONGMAX => PokedexEntry {
+                base_stats: (70, 85, 65, 125, 65, 120),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 45.2,
+            },
+            PokemonName::SKWOVET => PokedexEntry {
+                base_stats: (70, 55, 55, 35, 35, 25),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 2.5,
+            },
+            PokemonName::GREEDENT => PokedexEntry {
+                base_stats: (120, 95, 95, 55, 75, 20),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::ROOKIDEE => PokedexEntry {
+                base_stats: (38, 47, 35, 33, 35, 57),
+                types: (PokemonType::FLYING, PokemonType::TYPELESS),
+                weight_kg: 1.8,
+            },
+            PokemonName::CORVISQUIRE => PokedexEntry {
+                base_stats: (68, 67, 55, 43, 55, 77),
+                types: (PokemonType::FLYING, PokemonType::TYPELESS),
+                weight_kg: 16.0,
+            },
+            PokemonName::CORVIKNIGHT => PokedexEntry {
+                base_stats: (98, 87, 105, 53, 85, 67),
+                types: (PokemonType::FLYING, PokemonType::STEEL),
+                weight_kg: 75.0,
+            },
+            PokemonName::CORVIKNIGHTGMAX => PokedexEntry {
+                base_stats: (98, 87, 105, 53, 85, 67),
+                types: (PokemonType::FLYING, PokemonType::STEEL),
+                weight_kg: 75.0,
+            },
+            PokemonName::BLIPBUG => PokedexEntry {
+                base_stats: (25, 20, 20, 25, 45, 45),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 8.0,
+            },
+            PokemonName::DOTTLER => PokedexEntry {
+                base_stats: (50, 35, 80, 50, 90, 30),
+                types: (PokemonType::BUG, PokemonType::PSYCHIC),
+                weight_kg: 19.5,
+            },
+            PokemonName::ORBEETLE => PokedexEntry {
+                base_stats: (60, 45, 110, 80, 120, 90),
+                types: (PokemonType::BUG, PokemonType::PSYCHIC),
+                weight_kg: 40.8,
+            },
+            PokemonName::ORBEETLEGMAX => PokedexEntry {
+                base_stats: (60, 45, 110, 80, 120, 90),
+                types: (PokemonType::BUG, PokemonType::PSYCHIC),
+                weight_kg: 40.8,
+            },
+            PokemonName::NICKIT => PokedexEntry {
+                base_stats: (40, 28, 28, 47, 52, 50),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 8.9,
+            },
+            PokemonName::THIEVUL => PokedexEntry {
+                base_stats: (70, 58, 58, 87, 92, 90),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 19.9,
+            },
+            PokemonName::GOSSIFLEUR => PokedexEntry {
+                base_stats: (40, 40, 60, 40, 60, 10),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 2.2,
+            },
+            PokemonName::ELDEGOSS => PokedexEntry {
+                base_stats: (60, 50, 90, 80, 120, 60),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 2.5,
+            },
+            PokemonName::WOOLOO => PokedexEntry {
+                base_stats: (42, 40, 55, 40, 45, 48),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 6.0,
+            },
+            PokemonName::DUBWOOL => PokedexEntry {
+                base_stats: (72, 80, 100, 60, 90, 88),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 43.0,
+            },
+            PokemonName::CHEWTLE => PokedexEntry {
+                base_stats: (50, 64, 50, 38, 38, 44),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 8.5,
+            },
+            PokemonName::DREDNAW => PokedexEntry {
+                base_stats: (90, 115, 90, 48, 68, 74),
+                types: (PokemonType::WATER, PokemonType::ROCK),
+                weight_kg: 115.5,
+            },
+            PokemonName::DREDNAWGMAX => PokedexEntry {
+                base_stats: (90, 115, 90, 48, 68, 74),
+                types: (PokemonType::WATER, PokemonType::ROCK),
+                weight_kg: 115.5,
+            },
+            PokemonName::YAMPER => PokedexEntry {
+                base_stats: (59, 45, 50, 40, 50, 26),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 13.5,
+            },
+            PokemonName::BOLTUND => PokedexEntry {
+                base_stats: (69, 90, 60, 90, 60, 121),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 34.0,
+            },
+            PokemonName::ROLYCOLY => PokedexEntry {
+                base_stats: (30, 40, 50, 40, 50, 30),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 12.0,
+            },
+            PokemonName::CARKOL => PokedexEntry {
+                base_stats: (80, 60, 90, 60, 70, 50),
+                types: (PokemonType::ROCK, PokemonType::FIRE),
+                weight_kg: 78.0,
+            },
+            PokemonName::COALOSSAL => PokedexEntry {
+                base_stats: (110, 80, 120, 80, 90, 30),
+                types: (PokemonType::ROCK, PokemonType::FIRE),
+                weight_kg: 310.5,
+            },
+            PokemonName::COALOSSALGMAX => PokedexEntry {
+                base_stats: (110, 80, 120, 80, 90, 30),
+                types: (PokemonType::ROCK, PokemonType::FIRE),
+                weight_kg: 310.5,
+            },
+            PokemonName::APPLIN => PokedexEntry {
+                base_stats: (40, 40, 80, 40, 40, 20),
+                types: (PokemonType::GRASS, PokemonType::DRAGON),
+                weight_kg: 0.5,
+            },
+            PokemonName::FLAPPLE => PokedexEntry {
+                base_stats: (70, 110, 80, 95, 60, 70),
+                types: (PokemonType::GRASS, PokemonType::DRAGON),
+                weight_kg: 1.0,
+            },
+            PokemonName::FLAPPLEGMAX => PokedexEntry {
+                base_stats: (70, 110, 80, 95, 60, 70),
+                types: (PokemonType::GRASS, PokemonType::DRAGON),
+                weight_kg: 1.0,
+            },
+            PokemonName::APPLETUN => PokedexEntry {
+                base_stats: (110, 85, 80, 100, 80, 30),
+                types: (PokemonType::GRASS, PokemonType::DRAGON),
+                weight_kg: 13.0,
+            },
+            PokemonName::APPLETUNGMAX => PokedexEntry {
+                base_stats: (110, 85, 80, 100, 80, 30),
+                types: (PokemonType::GRASS, PokemonType::DRAGON),
+                weight_kg: 13.0,
+            },
+            PokemonName::SILICOBRA => PokedexEntry {
+                base_stats: (52, 57, 75, 35, 50, 46),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 7.6,
+            },
+            PokemonName::SANDACONDA => PokedexEntry {
+                base_stats: (72, 107, 125, 65, 70, 71),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 65.5,
+            },
+            PokemonName::SANDACONDAGMAX => PokedexEntry {
+                base_stats: (72, 107, 125, 65, 70, 71),
+                types: (PokemonType::GROUND, PokemonType::TYPELESS),
+                weight_kg: 65.5,
+            },
+            PokemonName::CRAMORANT => PokedexEntry {
+                base_stats: (70, 85, 55, 85, 95, 85),
+                types: (PokemonType::FLYING, PokemonType::WATER),
+                weight_kg: 18.0,
+            },
+            PokemonName::CRAMORANTGULPING => PokedexEntry {
+                base_stats: (70, 85, 55, 85, 95, 85),
+                types: (PokemonType::FLYING, PokemonType::WATER),
+                weight_kg: 18.0,
+            },
+            PokemonName::CRAMORANTGORGING => PokedexEntry {
+                base_stats: (70, 85, 55, 85, 95, 85),
+                types: (PokemonType::FLYING, PokemonType::WATER),
+                weight_kg: 18.0,
+            },
+            PokemonName::ARROKUDA => PokedexEntry {
+                base_stats: (41, 63, 40, 40, 30, 66),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 1.0,
+            },
+            PokemonName::BARRASKEWDA => PokedexEntry {
+                base_stats: (61, 123, 60, 60, 50, 136),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 30.0,
+            },
+            PokemonName::TOXEL => PokedexEntry {
+                base_stats: (40, 38, 35, 54, 35, 40),
+                types: (PokemonType::ELECTRIC, PokemonType::POISON),
+                weight_kg: 11.0,
+            },
+            PokemonName::TOXTRICITY => PokedexEntry {
+                base_stats: (75, 98, 70, 114, 70, 75),
+                types: (PokemonType::ELECTRIC, PokemonType::POISON),
+                weight_kg: 40.0,
+            },
+            PokemonName::TOXTRICITYLOWKEY => PokedexEntry {
+                base_stats: (75, 98, 70, 114, 70, 75),
+                types: (PokemonType::ELECTRIC, PokemonType::POISON),
+                weight_kg: 40.0,
+            },
+            PokemonName::TOXTRICITYGMAX => PokedexEntry {
+                base_stats: (75, 98, 70, 114, 70, 75),
+                types: (PokemonType::ELECTRIC, PokemonType::POISON),
+                weight_kg: 40.0,
+            },
+            PokemonName::TOXTRICITYLOWKEYGMAX => PokedexEntry {
+                base_stats: (75, 98, 70, 114, 70, 75),
+                types: (PokemonType::ELECTRIC, PokemonType::POISON),
+                weight_kg: 40.0,
+            },
+            PokemonName::SIZZLIPEDE => PokedexEntry {
+                base_stats: (50, 65, 45, 50, 50, 45),
+                types: (PokemonType::FIRE, PokemonType::BUG),
+                weight_kg: 1.0,
+            },
+            PokemonName::CENTISKORCH => PokedexEntry {
+                base_stats: (100, 115, 65, 90, 90, 65),
+                types: (PokemonType::FIRE, PokemonType::BUG),
+                weight_kg: 120.0,
+            },
+            PokemonName::CENTISKORCHGMAX => PokedexEntry {
+                base_stats: (100, 115, 65, 90, 90, 65),
+                types: (PokemonType::FIRE, PokemonType::BUG),
+                weight_kg: 120.0,
+            },
+            PokemonName::CLOBBOPUS => PokedexEntry {
+                base_stats: (50, 68, 60, 50, 50, 32),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 4.0,
+            },
+            PokemonName::GRAPPLOCT => PokedexEntry {
+                base_stats: (80, 118, 90, 70, 80, 42),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 39.0,
+            },
+            PokemonName::SINISTEA => PokedexEntry {
+                base_stats: (40, 45, 45, 74, 54, 50),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 0.2,
+            },
+            PokemonName::SINISTEAANTIQUE => PokedexEntry {
+                base_stats: (40, 45, 45, 74, 54, 50),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 0.2,
+            },
+            PokemonName::POLTEAGEIST => PokedexEntry {
+                base_stats: (60, 65, 65, 134, 114, 70),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 0.4,
+            },
+            PokemonName::POLTEAGEISTANTIQUE => PokedexEntry {
+                base_stats: (60, 65, 65, 134, 114, 70),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 0.4,
+            },
+            PokemonName::HATENNA => PokedexEntry {
+                base_stats: (42, 30, 45, 56, 53, 39),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 3.4,
+            },
+            PokemonName::HATTREM => PokedexEntry {
+                base_stats: (57, 40, 65, 86, 73, 49),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 4.8,
+            },
+            PokemonName::HATTERENE => PokedexEntry {
+                base_stats: (57, 90, 95, 136, 103, 29),
+                types: (PokemonType::PSYCHIC, PokemonType::FAIRY),
+                weight_kg: 5.1,
+            },
+            PokemonName::HATTERENEGMAX => PokedexEntry {
+                base_stats: (57, 90, 95, 136, 103, 29),
+                types: (PokemonType::PSYCHIC, PokemonType::FAIRY),
+                weight_kg: 5.1,
+            },
+            PokemonName::IMPIDIMP => PokedexEntry {
+                base_stats: (45, 45, 30, 55, 40, 50),
+                types: (PokemonType::DARK, PokemonType::FAIRY),
+                weight_kg: 5.5,
+            },
+            PokemonName::MORGREM => PokedexEntry {
+                base_stats: (65, 60, 45, 75, 55, 70),
+                types: (PokemonType::DARK, PokemonType::FAIRY),
+                weight_kg: 12.5,
+            },
+            PokemonName::GRIMMSNARL => PokedexEntry {
+                base_stats: (95, 120, 65, 95, 75, 60),
+                types: (PokemonType::DARK, PokemonType::FAIRY),
+                weight_kg: 61.0,
+            },
+            PokemonName::GRIMMSNARLGMAX => PokedexEntry {
+                base_stats: (95, 120, 65, 95, 75, 60),
+                types: (PokemonType::DARK, PokemonType::FAIRY),
+                weight_kg: 61.0,
+            },
+            PokemonName::OBSTAGOON => PokedexEntry {
+                base_stats: (93, 90, 101, 60, 81, 95),
+                types: (PokemonType::DARK, PokemonType::NORMAL),
+                weight_kg: 46.0,
+            },
+            PokemonName::PERRSERKER => PokedexEntry {
+                base_stats: (70, 110, 100, 50, 60, 50),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 28.0,
+            },
+            PokemonName::CURSOLA => PokedexEntry {
+                base_stats: (60, 95, 50, 145, 130, 30),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 0.4,
+            },
+            PokemonName::SIRFETCHD => PokedexEntry {
+                base_stats: (62, 135, 95, 68, 82, 65),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 117.0,
+            },
+            PokemonName::MRRIME => PokedexEntry {
+                base_stats: (80, 85, 75, 110, 100, 70),
+                types: (PokemonType::ICE, PokemonType::PSYCHIC),
+                weight_kg: 58.2,
+            },
+            PokemonName::RUNERIGUS => PokedexEntry {
+                base_stats: (58, 95, 145, 50, 105, 30),
+                types: (PokemonType::GROUND, PokemonType::GHOST),
+                weight_kg: 66.6,
+            },
+            PokemonName::MILCERY => PokedexEntry {
+                base_stats: (45, 40, 40, 50, 61, 34),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.3,
+            },
+            PokemonName::ALCREMIE => PokedexEntry {
+                base_stats: (65, 60, 75, 110, 121, 64),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::ALCREMIERUBYCREAM => PokedexEntry {
+                base_stats: (65, 60, 75, 110, 121, 64),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::ALCREMIEMATCHACREAM => PokedexEntry {
+                base_stats: (65, 60, 75, 110, 121, 64),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::ALCREMIEMINTCREAM => PokedexEntry {
+                base_stats: (65, 60, 75, 110, 121, 64),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::ALCREMIELEMONCREAM => PokedexEntry {
+                base_stats: (65, 60, 75, 110, 121, 64),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::ALCREMIESALTEDCREAM => PokedexEntry {
+                base_stats: (65, 60, 75, 110, 121, 64),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::ALCREMIERUBYSWIRL => PokedexEntry {
+                base_stats: (65, 60, 75, 110, 121, 64),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::ALCREMIECARAMELSWIRL => PokedexEntry {
+                base_stats: (65, 60, 75, 110, 121, 64),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::ALCREMIERAINBOWSWIRL => PokedexEntry {
+                base_stats: (65, 60, 75, 110, 121, 64),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::ALCREMIEGMAX => PokedexEntry {
+                base_stats: (65, 60, 75, 110, 121, 64),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.5,
+            },
+            PokemonName::FALINKS => PokedexEntry {
+                base_stats: (65, 100, 100, 70, 60, 75),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 62.0,
+            },
+            PokemonName::PINCURCHIN => PokedexEntry {
+                base_stats: (48, 101, 95, 91, 85, 15),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 1.0,
+            },
+            PokemonName::SNOM => PokedexEntry {
+                base_stats: (30, 25, 35, 45, 30, 20),
+                types: (PokemonType::ICE, PokemonType::BUG),
+                weight_kg: 3.8,
+            },
+            PokemonName::FROSMOTH => PokedexEntry {
+                base_stats: (70, 65, 60, 125, 90, 65),
+                types: (PokemonType::ICE, PokemonType::BUG),
+                weight_kg: 42.0,
+            },
+            PokemonName::STONJOURNER => PokedexEntry {
+                base_stats: (100, 125, 135, 20, 20, 70),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 520.0,
+            },
+            PokemonName::EISCUE => PokedexEntry {
+                base_stats: (75, 80, 110, 65, 90, 50),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 89.0,
+            },
+            PokemonName::EISCUENOICE => PokedexEntry {
+                base_stats: (75, 80, 70, 65, 50, 130),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 89.0,
+            },
+            PokemonName::INDEEDEE => PokedexEntry {
+                base_stats: (60, 65, 55, 105, 95, 95),
+                types: (PokemonType::PSYCHIC, PokemonType::NORMAL),
+                weight_kg: 28.0,
+            },
+            PokemonName::INDEEDEEF => PokedexEntry {
+                base_stats: (70, 55, 65, 95, 105, 85),
+                types: (PokemonType::PSYCHIC, PokemonType::NORMAL),
+                weight_kg: 28.0,
+            },
+            PokemonName::MORPEKO => PokedexEntry {
+                base_stats: (58, 95, 58, 70, 58, 97),
+                types: (PokemonType::ELECTRIC, PokemonType::DARK),
+                weight_kg: 3.0,
+            },
+            PokemonName::MORPEKOHANGRY => PokedexEntry {
+                base_stats: (58, 95, 58, 70, 58, 97),
+                types: (PokemonType::ELECTRIC, PokemonType::DARK),
+                weight_kg: 3.0,
+            },
+            PokemonName::CUFANT => PokedexEntry {
+                base_stats: (72, 80, 49, 40, 49, 40),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 100.0,
+            },
+            PokemonName::COPPERAJAH => PokedexEntry {
+                base_stats: (122, 130, 69, 80, 69, 30),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 650.0,
+            },
+            PokemonName::COPPERAJAHGMAX => PokedexEntry {
+                base_stats: (122, 130, 69, 80, 69, 30),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 650.0,
+            },
+            PokemonName::DRACOZOLT => PokedexEntry {
+                base_stats: (90, 100, 90, 80, 70, 75),
+                types: (PokemonType::ELECTRIC, PokemonType::DRAGON),
+                weight_kg: 190.0,
+            },
+            PokemonName::ARCTOZOLT => PokedexEntry {
+                base_stats: (90, 100, 90, 90, 80, 55),
+                types: (PokemonType::ELECTRIC, PokemonType::ICE),
+                weight_kg: 150.0,
+            },
+            PokemonName::DRACOVISH => PokedexEntry {
+                base_stats: (90, 90, 100, 70, 80, 75),
+                types: (PokemonType::WATER, PokemonType::DRAGON),
+                weight_kg: 215.0,
+            },
+            PokemonName::ARCTOVISH => PokedexEntry {
+                base_stats: (90, 90, 100, 80, 90, 55),
+                types: (PokemonType::WATER, PokemonType::ICE),
+                weight_kg: 175.0,
+            },
+            PokemonName::DURALUDON => PokedexEntry {
+                base_stats: (70, 95, 115, 120, 50, 85),
+                types: (PokemonType::STEEL, PokemonType::DRAGON),
+                weight_kg: 40.0,
+            },
+            PokemonName::DURALUDONGMAX => PokedexEntry {
+                base_stats: (70, 95, 115, 120, 50, 85),
+                types: (PokemonType::STEEL, PokemonType::DRAGON),
+                weight_kg: 40.0,
+            },
+            PokemonName::DREEPY => PokedexEntry {
+                base_stats: (28, 60, 30, 40, 30, 82),
+                types: (PokemonType::DRAGON, PokemonType::GHOST),
+                weight_kg: 2.0,
+            },
+            PokemonName::DRAKLOAK => PokedexEntry {
+                base_stats: (68, 80, 50, 60, 50, 102),
+                types: (PokemonType::DRAGON, PokemonType::GHOST),
+                weight_kg: 11.0,
+            },
+            PokemonName::DRAGAPULT => PokedexEntry {
+                base_stats: (88, 120, 75, 100, 75, 142),
+                types: (PokemonType::DRAGON, PokemonType::GHOST),
+                weight_kg: 50.0,
+            },
+            PokemonName::ZACIAN => PokedexEntry {
+                base_stats: (92, 120, 115, 80, 115, 138),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 110.0,
+            },
+            PokemonName::ZACIANCROWNED => PokedexEntry {
+                base_stats: (92, 150, 115, 80, 115, 148),
+                types: (PokemonType::FAIRY, PokemonType::STEEL),
+                weight_kg: 355.0,
+            },
+            PokemonName::ZAMAZENTA => PokedexEntry {
+                base_stats: (92, 120, 115, 80, 115, 138),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 210.0,
+            },
+            PokemonName::ZAMAZENTACROWNED => PokedexEntry {
+                base_stats: (92, 120, 140, 80, 140, 128),
+                types: (PokemonType::FIGHTING, PokemonType::STEEL),
+                weight_kg: 785.0,
+            },
+            PokemonName::ETERNATUS => PokedexEntry {
+                base_stats: (140, 85, 95, 145, 95, 130),
+                types: (PokemonType::POISON, PokemonType::DRAGON),
+                weight_kg: 950.0,
+            },
+            PokemonName::ETERNATUSETERNAMAX => PokedexEntry {
+                base_stats: (255, 115, 250, 125, 250, 130),
+                types: (PokemonType::POISON, PokemonType::DRAGON),
+                weight_kg: 950.0,
+            },
+            PokemonName::KUBFU => PokedexEntry {
+                base_stats: (60, 90, 60, 53, 50, 72),
+                types: (PokemonType::FIGHTING, PokemonType::TYPELESS),
+                weight_kg: 12.0,
+            },
+            PokemonName::URSHIFU => PokedexEntry {
+                base_stats: (100, 130, 100, 63, 60, 97),
+                types: (PokemonType::FIGHTING, PokemonType::DARK),
+                weight_kg: 105.0,
+            },
+            PokemonName::URSHIFURAPIDSTRIKE => PokedexEntry {
+                base_stats: (100, 130, 100, 63, 60, 97),
+                types: (PokemonType::FIGHTING, PokemonType::WATER),
+                weight_kg: 105.0,
+            },
+            PokemonName::URSHIFUGMAX => PokedexEntry {
+                base_stats: (100, 130, 100, 63, 60, 97),
+                types: (PokemonType::FIGHTING, PokemonType::DARK),
+                weight_kg: 105.0,
+            },
+            PokemonName::URSHIFURAPIDSTRIKEGMAX => PokedexEntry {
+                base_stats: (100, 130, 100, 63, 60, 97),
+                types: (PokemonType::FIGHTING, PokemonType::WATER),
+                weight_kg: 105.0,
+            },
+            PokemonName::ZARUDE => PokedexEntry {
+                base_stats: (105, 120, 105, 70, 95, 105),
+                types: (PokemonType::DARK, PokemonType::GRASS),
+                weight_kg: 70.0,
+            },
+            PokemonName::ZARUDEDADA => PokedexEntry {
+                base_stats: (105, 120, 105, 70, 95, 105),
+                types: (PokemonType::DARK, PokemonType::GRASS),
+                weight_kg: 70.0,
+            },
+            PokemonName::REGIELEKI => PokedexEntry {
+                base_stats: (80, 100, 50, 100, 50, 200),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 145.0,
+            },
+            PokemonName::REGIDRAGO => PokedexEntry {
+                base_stats: (200, 100, 50, 100, 50, 80),
+                types: (PokemonType::DRAGON, PokemonType::TYPELESS),
+                weight_kg: 200.0,
+            },
+            PokemonName::GLASTRIER => PokedexEntry {
+                base_stats: (100, 145, 130, 65, 110, 30),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 800.0,
+            },
+            PokemonName::SPECTRIER => PokedexEntry {
+                base_stats: (100, 65, 60, 145, 80, 130),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 44.5,
+            },
+            PokemonName::CALYREX => PokedexEntry {
+                base_stats: (100, 80, 80, 80, 80, 80),
+                types: (PokemonType::PSYCHIC, PokemonType::GRASS),
+                weight_kg: 7.7,
+            },
+            PokemonName::CALYREXICE => PokedexEntry {
+                base_stats: (100, 165, 150, 85, 130, 50),
+                types: (PokemonType::PSYCHIC, PokemonType::ICE),
+                weight_kg: 809.1,
+            },
+            PokemonName::CALYREXSHADOW => PokedexEntry {
+                base_stats: (100, 85, 80, 165, 100, 150),
+                types: (PokemonType::PSYCHIC, PokemonType::GHOST),
+                weight_kg: 53.6,
+            },
+            PokemonName::WYRDEER => PokedexEntry {
+                base_stats: (103, 105, 72, 105, 75, 65),
+                types: (PokemonType::NORMAL, PokemonType::PSYCHIC),
+                weight_kg: 95.1,
+            },
+            PokemonName::KLEAVOR => PokedexEntry {
+                base_stats: (70, 135, 95, 45, 70, 85),
+                types: (PokemonType::BUG, PokemonType::ROCK),
+                weight_kg: 89.0,
+            },
+            PokemonName::URSALUNA => PokedexEntry {
+                base_stats: (130, 140, 105, 45, 80, 50),
+                types: (PokemonType::GROUND, PokemonType::NORMAL),
+                weight_kg: 290.0,
+            },
+            PokemonName::URSALUNABLOODMOON => PokedexEntry {
+                base_stats: (113, 70, 120, 135, 65, 52),
+                types: (PokemonType::GROUND, PokemonType::NORMAL),
+                weight_kg: 333.0,
+            },
+            PokemonName::BASCULEGION => PokedexEntry {
+                base_stats: (120, 112, 65, 80, 75, 78),
+                types: (PokemonType::WATER, PokemonType::GHOST),
+                weight_kg: 110.0,
+            },
+            PokemonName::BASCULEGIONF => PokedexEntry {
+                base_stats: (120, 92, 65, 100, 75, 78),
+                types: (PokemonType::WATER, PokemonType::GHOST),
+                weight_kg: 110.0,
+            },
+            PokemonName::SNEASLER => PokedexEntry {
+                base_stats: (80, 130, 60, 40, 80, 120),
+                types: (PokemonType::FIGHTING, PokemonType::POISON),
+                weight_kg: 43.0,
+            },
+            PokemonName::OVERQWIL => PokedexEntry {
+                base_stats: (85, 115, 95, 65, 65, 85),
+                types: (PokemonType::DARK, PokemonType::POISON),
+                weight_kg: 60.5,
+            },
+            PokemonName::ENAMORUS => PokedexEntry {
+                base_stats: (74, 115, 70, 135, 80, 106),
+                types: (PokemonType::FAIRY, PokemonType::FLYING),
+                weight_kg: 48.0,
+            },
+            PokemonName::ENAMORUSTHERIAN => PokedexEntry {
+                base_stats: (74, 115, 110, 135, 100, 46),
+                types: (PokemonType::FAIRY, PokemonType::FLYING),
+                weight_kg: 48.0,
+            },
+            PokemonName::SPRIGATITO => PokedexEntry {
+                base_stats: (40, 61, 54, 45, 45, 65),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 4.1,
+            },
+            PokemonName::FLORAGATO => PokedexEntry {
+                base_stats: (61, 80, 63, 60, 63, 83),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 12.2,
+            },
+            PokemonName::MEOWSCARADA => PokedexEntry {
+                base_stats: (76, 110, 70, 81, 70, 123),
+                types: (PokemonType::GRASS, PokemonType::DARK),
+                weight_kg: 31.2,
+            },
+            PokemonName::FUECOCO => PokedexEntry {
+                base_stats: (67, 45, 59, 63, 40, 36),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 9.8,
+            },
+            PokemonName::CROCALOR => PokedexEntry {
+                base_stats: (81, 55, 78, 90, 58, 49),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 30.7,
+            },
+            PokemonName::SKELEDIRGE => PokedexEntry {
+                base_stats: (104, 75, 100, 110, 75, 66),
+                types: (PokemonType::FIRE, PokemonType::GHOST),
+                weight_kg: 326.5,
+            },
+            PokemonName::QUAXLY => PokedexEntry {
+                base_stats: (55, 65, 45, 50, 45, 50),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 6.1,
+            },
+            PokemonName::QUAXWELL => PokedexEntry {
+                base_stats: (70, 85, 65, 65, 60, 65),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 21.5,
+            },
+            PokemonName::QUAQUAVAL => PokedexEntry {
+                base_stats: (85, 120, 80, 85, 75, 85),
+                types: (PokemonType::WATER, PokemonType::FIGHTING),
+                weight_kg: 61.9,
+            },
+            PokemonName::LECHONK => PokedexEntry {
+                base_stats: (54, 45, 40, 35, 45, 35),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 10.2,
+            },
+            PokemonName::OINKOLOGNE => PokedexEntry {
+                base_stats: (110, 100, 75, 59, 80, 65),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 120.0,
+            },
+            PokemonName::OINKOLOGNEF => PokedexEntry {
+                base_stats: (115, 90, 70, 59, 90, 65),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 120.0,
+            },
+            PokemonName::TAROUNTULA => PokedexEntry {
+                base_stats: (35, 41, 45, 29, 40, 20),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 4.0,
+            },
+            PokemonName::SPIDOPS => PokedexEntry {
+                base_stats: (60, 79, 92, 52, 86, 35),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 16.5,
+            },
+            PokemonName::NYMBLE => PokedexEntry {
+                base_stats: (33, 46, 40, 21, 25, 45),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 1.0,
+            },
+            PokemonName::LOKIX => PokedexEntry {
+                base_stats: (71, 102, 78, 52, 55, 92),
+                types: (PokemonType::BUG, PokemonType::DARK),
+                weight_kg: 17.5,
+            },
+            PokemonName::PAWMI => PokedexEntry {
+                base_stats: (45, 50, 20, 40, 25, 60),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 2.5,
+            },
+            PokemonName::PAWMO => PokedexEntry {
+                base_stats: (60, 75, 40, 50, 40, 85),
+                types: (PokemonType::ELECTRIC, PokemonType::FIGHTING),
+                weight_kg: 6.5,
+            },
+            PokemonName::PAWMOT => PokedexEntry {
+                base_stats: (70, 115, 70, 70, 60, 105),
+                types: (PokemonType::ELECTRIC, PokemonType::FIGHTING),
+                weight_kg: 41.0,
+            },
+            PokemonName::TANDEMAUS => PokedexEntry {
+                base_stats: (50, 50, 45, 40, 45, 75),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 1.8,
+            },
+            PokemonName::MAUSHOLD => PokedexEntry {
+                base_stats: (74, 75, 70, 65, 75, 111),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 2.3,
+            },
+            PokemonName::MAUSHOLDFOUR => PokedexEntry {
+                base_stats: (74, 75, 70, 65, 75, 111),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 2.8,
+            },
+            PokemonName::FIDOUGH => PokedexEntry {
+                base_stats: (37, 55, 70, 30, 55, 65),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 10.9,
+            },
+            PokemonName::DACHSBUN => PokedexEntry {
+                base_stats: (57, 80, 115, 50, 80, 95),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 14.9,
+            },
+            PokemonName::SMOLIV => PokedexEntry {
+                base_stats: (41, 35, 45, 58, 51, 30),
+                types: (PokemonType::GRASS, PokemonType::NORMAL),
+                weight_kg: 6.5,
+            },
+            PokemonName::DOLLIV => PokedexEntry {
+                base_stats: (52, 53, 60, 78, 78, 33),
+                types: (PokemonType::GRASS, PokemonType::NORMAL),
+                weight_kg: 11.9,
+            },
+            PokemonName::ARBOLIVA => PokedexEntry {
+                base_stats: (78, 69, 90, 125, 109, 39),
+                types: (PokemonType::GRASS, PokemonType::NORMAL),
+                weight_kg: 48.2,
+            },
+            PokemonName::SQUAWKABILLY => PokedexEntry {
+                base_stats: (82, 96, 51, 45, 51, 92),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 2.4,
+            },
+            PokemonName::SQUAWKABILLYBLUE => PokedexEntry {
+                base_stats: (82, 96, 51, 45, 51, 92),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 2.4,
+            },
+            PokemonName::SQUAWKABILLYYELLOW => PokedexEntry {
+                base_stats: (82, 96, 51, 45, 51, 92),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 2.4,
+            },
+            PokemonName::SQUAWKABILLYWHITE => PokedexEntry {
+                base_stats: (82, 96, 51, 45, 51, 92),
+                types: (PokemonType::NORMAL, PokemonType::FLYING),
+                weight_kg: 2.4,
+            },
+            PokemonName::NACLI => PokedexEntry {
+                base_stats: (55, 55, 75, 35, 35, 25),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 16.0,
+            },
+            PokemonName::NACLSTACK => PokedexEntry {
+                base_stats: (60, 60, 100, 35, 65, 35),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 105.0,
+            },
+            PokemonName::GARGANACL => PokedexEntry {
+                base_stats: (100, 100, 130, 45, 90, 35),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 240.0,
+            },
+            PokemonName::CHARCADET => PokedexEntry {
+                base_stats: (40, 50, 40, 50, 40, 35),
+                types: (PokemonType::FIRE, PokemonType::TYPELESS),
+                weight_kg: 10.5,
+            },
+            PokemonName::ARMAROUGE => PokedexEntry {
+                base_stats: (85, 60, 100, 125, 80, 75),
+                types: (PokemonType::FIRE, PokemonType::PSYCHIC),
+                weight_kg: 85.0,
+            },
+            PokemonName::CERULEDGE => PokedexEntry {
+                base_stats: (75, 125, 80, 60, 100, 85),
+                types: (PokemonType::FIRE, PokemonType::GHOST),
+                weight_kg: 62.0,
+            },
+            PokemonName::TADBULB => PokedexEntry {
+                base_stats: (61, 31, 41, 59, 35, 45),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 0.4,
+            },
+            PokemonName::BELLIBOLT => PokedexEntry {
+                base_stats: (109, 64, 91, 103, 83, 45),
+                types: (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+                weight_kg: 113.0,
+            },
+            PokemonName::WATTREL => PokedexEntry {
+                base_stats: (40, 40, 35, 55, 40, 70),
+                types: (PokemonType::ELECTRIC, PokemonType::FLYING),
+                weight_kg: 3.6,
+            },
+            PokemonName::KILOWATTREL => PokedexEntry {
+                base_stats: (70, 70, 60, 105, 60, 125),
+                types: (PokemonType::ELECTRIC, PokemonType::FLYING),
+                weight_kg: 38.6,
+            },
+            PokemonName::MASCHIFF => PokedexEntry {
+                base_stats: (60, 78, 60, 40, 51, 51),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 16.0,
+            },
+            PokemonName::MABOSSTIFF => PokedexEntry {
+                base_stats: (80, 120, 90, 60, 70, 85),
+                types: (PokemonType::DARK, PokemonType::TYPELESS),
+                weight_kg: 61.0,
+            },
+            PokemonName::SHROODLE => PokedexEntry {
+                base_stats: (40, 65, 35, 40, 35, 75),
+                types: (PokemonType::POISON, PokemonType::NORMAL),
+                weight_kg: 0.7,
+            },
+            PokemonName::GRAFAIAI => PokedexEntry {
+                base_stats: (63, 95, 65, 80, 72, 110),
+                types: (PokemonType::POISON, PokemonType::NORMAL),
+                weight_kg: 27.2,
+            },
+            PokemonName::BRAMBLIN => PokedexEntry {
+                base_stats: (40, 65, 30, 45, 35, 60),
+                types: (PokemonType::GRASS, PokemonType::GHOST),
+                weight_kg: 0.6,
+            },
+            PokemonName::BRAMBLEGHAST => PokedexEntry {
+                base_stats: (55, 115, 70, 80, 70, 90),
+                types: (PokemonType::GRASS, PokemonType::GHOST),
+                weight_kg: 6.0,
+            },
+            PokemonName::TOEDSCOOL => PokedexEntry {
+                base_stats: (40, 40, 35, 50, 100, 70),
+                types: (PokemonType::GROUND, PokemonType::GRASS),
+                weight_kg: 33.0,
+            },
+            PokemonName::TOEDSCRUEL => PokedexEntry {
+                base_stats: (80, 70, 65, 80, 120, 100),
+                types: (PokemonType::GROUND, PokemonType::GRASS),
+                weight_kg: 58.0,
+            },
+            PokemonName::KLAWF => PokedexEntry {
+                base_stats: (70, 100, 115, 35, 55, 75),
+                types: (PokemonType::ROCK, PokemonType::TYPELESS),
+                weight_kg: 79.0,
+            },
+            PokemonName::CAPSAKID => PokedexEntry {
+                base_stats: (50, 62, 40, 62, 40, 50),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 3.0,
+            },
+            PokemonName::SCOVILLAIN => PokedexEntry {
+                base_stats: (65, 108, 65, 108, 65, 75),
+                types: (PokemonType::GRASS, PokemonType::FIRE),
+                weight_kg: 15.0,
+            },
+            PokemonName::RELLOR => PokedexEntry {
+                base_stats: (41, 50, 60, 31, 58, 30),
+                types: (PokemonType::BUG, PokemonType::TYPELESS),
+                weight_kg: 1.0,
+            },
+            PokemonName::RABSCA => PokedexEntry {
+                base_stats: (75, 50, 85, 115, 100, 45),
+                types: (PokemonType::BUG, PokemonType::PSYCHIC),
+                weight_kg: 3.5,
+            },
+            PokemonName::FLITTLE => PokedexEntry {
+                base_stats: (30, 35, 30, 55, 30, 75),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 1.5,
+            },
+            PokemonName::ESPATHRA => PokedexEntry {
+                base_stats: (95, 60, 60, 101, 60, 105),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 90.0,
+            },
+            PokemonName::TINKATINK => PokedexEntry {
+                base_stats: (50, 45, 45, 35, 64, 58),
+                types: (PokemonType::FAIRY, PokemonType::STEEL),
+                weight_kg: 8.9,
+            },
+            PokemonName::TINKATUFF => PokedexEntry {
+                base_stats: (65, 55, 55, 45, 82, 78),
+                types: (PokemonType::FAIRY, PokemonType::STEEL),
+                weight_kg: 59.1,
+            },
+            PokemonName::TINKATON => PokedexEntry {
+                base_stats: (85, 75, 77, 70, 105, 94),
+                types: (PokemonType::FAIRY, PokemonType::STEEL),
+                weight_kg: 112.8,
+            },
+            PokemonName::WIGLETT => PokedexEntry {
+                base_stats: (10, 55, 25, 35, 25, 95),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 1.8,
+            },
+            PokemonName::WUGTRIO => PokedexEntry {
+                base_stats: (35, 100, 50, 50, 70, 120),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 5.4,
+            },
+            PokemonName::BOMBIRDIER => PokedexEntry {
+                base_stats: (70, 103, 85, 60, 85, 82),
+                types: (PokemonType::FLYING, PokemonType::DARK),
+                weight_kg: 42.9,
+            },
+            PokemonName::FINIZEN => PokedexEntry {
+                base_stats: (70, 45, 40, 45, 40, 75),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 60.2,
+            },
+            PokemonName::PALAFIN => PokedexEntry {
+                base_stats: (100, 70, 72, 53, 62, 100),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 60.2,
+            },
+            PokemonName::PALAFINHERO => PokedexEntry {
+                base_stats: (100, 160, 97, 106, 87, 100),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 97.4,
+            },
+            PokemonName::VAROOM => PokedexEntry {
+                base_stats: (45, 70, 63, 30, 45, 47),
+                types: (PokemonType::STEEL, PokemonType::POISON),
+                weight_kg: 35.0,
+            },
+            PokemonName::REVAVROOM => PokedexEntry {
+                base_stats: (80, 119, 90, 54, 67, 90),
+                types: (PokemonType::STEEL, PokemonType::POISON),
+                weight_kg: 120.0,
+            },
+            PokemonName::CYCLIZAR => PokedexEntry {
+                base_stats: (70, 95, 65, 85, 65, 121),
+                types: (PokemonType::DRAGON, PokemonType::NORMAL),
+                weight_kg: 63.0,
+            },
+            PokemonName::ORTHWORM => PokedexEntry {
+                base_stats: (70, 85, 145, 60, 55, 65),
+                types: (PokemonType::STEEL, PokemonType::TYPELESS),
+                weight_kg: 310.0,
+            },
+            PokemonName::GLIMMET => PokedexEntry {
+                base_stats: (48, 35, 42, 105, 60, 60),
+                types: (PokemonType::ROCK, PokemonType::POISON),
+                weight_kg: 8.0,
+            },
+            PokemonName::GLIMMORA => PokedexEntry {
+                base_stats: (83, 55, 90, 130, 81, 86),
+                types: (PokemonType::ROCK, PokemonType::POISON),
+                weight_kg: 45.0,
+            },
+            PokemonName::GREAVARD => PokedexEntry {
+                base_stats: (50, 61, 60, 30, 55, 34),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 35.0,
+            },
+            PokemonName::HOUNDSTONE => PokedexEntry {
+                base_stats: (72, 101, 100, 50, 97, 68),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 15.0,
+            },
+            PokemonName::FLAMIGO => PokedexEntry {
+                base_stats: (82, 115, 74, 75, 64, 90),
+                types: (PokemonType::FLYING, PokemonType::FIGHTING),
+                weight_kg: 37.0,
+            },
+            PokemonName::CETODDLE => PokedexEntry {
+                base_stats: (108, 68, 45, 30, 40, 43),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 45.0,
+            },
+            PokemonName::CETITAN => PokedexEntry {
+                base_stats: (170, 113, 65, 45, 55, 73),
+                types: (PokemonType::ICE, PokemonType::TYPELESS),
+                weight_kg: 700.0,
+            },
+            PokemonName::VELUZA => PokedexEntry {
+                base_stats: (90, 102, 73, 78, 65, 70),
+                types: (PokemonType::WATER, PokemonType::PSYCHIC),
+                weight_kg: 90.0,
+            },
+            PokemonName::DONDOZO => PokedexEntry {
+                base_stats: (150, 100, 115, 65, 65, 35),
+                types: (PokemonType::WATER, PokemonType::TYPELESS),
+                weight_kg: 220.0,
+            },
+            PokemonName::TATSUGIRI => PokedexEntry {
+                base_stats: (68, 50, 60, 120, 95, 82),
+                types: (PokemonType::DRAGON, PokemonType::WATER),
+                weight_kg: 8.0,
+            },
+            PokemonName::TATSUGIRIDROOPY => PokedexEntry {
+                base_stats: (68, 50, 60, 120, 95, 82),
+                types: (PokemonType::DRAGON, PokemonType::WATER),
+                weight_kg: 8.0,
+            },
+            PokemonName::TATSUGIRISTRETCHY => PokedexEntry {
+                base_stats: (68, 50, 60, 120, 95, 82),
+                types: (PokemonType::DRAGON, PokemonType::WATER),
+                weight_kg: 8.0,
+            },
+            PokemonName::ANNIHILAPE => PokedexEntry {
+                base_stats: (110, 115, 80, 50, 90, 90),
+                types: (PokemonType::FIGHTING, PokemonType::GHOST),
+                weight_kg: 56.0,
+            },
+            PokemonName::CLODSIRE => PokedexEntry {
+                base_stats: (130, 75, 60, 45, 100, 20),
+                types: (PokemonType::POISON, PokemonType::GROUND),
+                weight_kg: 223.0,
+            },
+            PokemonName::FARIGIRAF => PokedexEntry {
+                base_stats: (120, 90, 70, 110, 70, 60),
+                types: (PokemonType::NORMAL, PokemonType::PSYCHIC),
+                weight_kg: 160.0,
+            },
+            PokemonName::DUDUNSPARCE => PokedexEntry {
+                base_stats: (125, 100, 80, 85, 75, 55),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 39.2,
+            },
+            PokemonName::DUDUNSPARCETHREESEGMENT => PokedexEntry {
+                base_stats: (125, 100, 80, 85, 75, 55),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 47.4,
+            },
+            PokemonName::KINGAMBIT => PokedexEntry {
+                base_stats: (100, 135, 120, 60, 85, 50),
+                types: (PokemonType::DARK, PokemonType::STEEL),
+                weight_kg: 120.0,
+            },
+            PokemonName::GREATTUSK => PokedexEntry {
+                base_stats: (115, 131, 131, 53, 53, 87),
+                types: (PokemonType::GROUND, PokemonType::FIGHTING),
+                weight_kg: 320.0,
+            },
+            PokemonName::SCREAMTAIL => PokedexEntry {
+                base_stats: (115, 65, 99, 65, 115, 111),
+                types: (PokemonType::FAIRY, PokemonType::PSYCHIC),
+                weight_kg: 8.0,
+            },
+            PokemonName::BRUTEBONNET => PokedexEntry {
+                base_stats: (111, 127, 99, 79, 99, 55),
+                types: (PokemonType::GRASS, PokemonType::DARK),
+                weight_kg: 21.0,
+            },
+            PokemonName::FLUTTERMANE => PokedexEntry {
+                base_stats: (55, 55, 55, 135, 135, 135),
+                types: (PokemonType::GHOST, PokemonType::FAIRY),
+                weight_kg: 4.0,
+            },
+            PokemonName::SLITHERWING => PokedexEntry {
+                base_stats: (85, 135, 79, 85, 105, 81),
+                types: (PokemonType::BUG, PokemonType::FIGHTING),
+                weight_kg: 92.0,
+            },
+            PokemonName::SANDYSHOCKS => PokedexEntry {
+                base_stats: (85, 81, 97, 121, 85, 101),
+                types: (PokemonType::ELECTRIC, PokemonType::GROUND),
+                weight_kg: 60.0,
+            },
+            PokemonName::IRONTREADS => PokedexEntry {
+                base_stats: (90, 112, 120, 72, 70, 106),
+                types: (PokemonType::GROUND, PokemonType::STEEL),
+                weight_kg: 240.0,
+            },
+            PokemonName::IRONBUNDLE => PokedexEntry {
+                base_stats: (56, 80, 114, 124, 60, 136),
+                types: (PokemonType::ICE, PokemonType::WATER),
+                weight_kg: 11.0,
+            },
+            PokemonName::IRONHANDS => PokedexEntry {
+                base_stats: (154, 140, 108, 50, 68, 50),
+                types: (PokemonType::FIGHTING, PokemonType::ELECTRIC),
+                weight_kg: 380.7,
+            },
+            PokemonName::IRONJUGULIS => PokedexEntry {
+                base_stats: (94, 80, 86, 122, 80, 108),
+                types: (PokemonType::DARK, PokemonType::FLYING),
+                weight_kg: 111.0,
+            },
+            PokemonName::IRONMOTH => PokedexEntry {
+                base_stats: (80, 70, 60, 140, 110, 110),
+                types: (PokemonType::FIRE, PokemonType::POISON),
+                weight_kg: 36.0,
+            },
+            PokemonName::IRONTHORNS => PokedexEntry {
+                base_stats: (100, 134, 110, 70, 84, 72),
+                types: (PokemonType::ROCK, PokemonType::ELECTRIC),
+                weight_kg: 303.0,
+            },
+            PokemonName::FRIGIBAX => PokedexEntry {
+                base_stats: (65, 75, 45, 35, 45, 55),
+                types: (PokemonType::DRAGON, PokemonType::ICE),
+                weight_kg: 17.0,
+            },
+            PokemonName::ARCTIBAX => PokedexEntry {
+                base_stats: (90, 95, 66, 45, 65, 62),
+                types: (PokemonType::DRAGON, PokemonType::ICE),
+                weight_kg: 30.0,
+            },
+            PokemonName::BAXCALIBUR => PokedexEntry {
+                base_stats: (115, 145, 92, 75, 86, 87),
+                types: (PokemonType::DRAGON, PokemonType::ICE),
+                weight_kg: 210.0,
+            },
+            PokemonName::GIMMIGHOUL => PokedexEntry {
+                base_stats: (45, 30, 70, 75, 70, 10),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 5.0,
+            },
+            PokemonName::GIMMIGHOULROAMING => PokedexEntry {
+                base_stats: (45, 30, 25, 75, 45, 80),
+                types: (PokemonType::GHOST, PokemonType::TYPELESS),
+                weight_kg: 0.1,
+            },
+            PokemonName::GHOLDENGO => PokedexEntry {
+                base_stats: (87, 60, 95, 133, 91, 84),
+                types: (PokemonType::STEEL, PokemonType::GHOST),
+                weight_kg: 30.0,
+            },
+            PokemonName::WOCHIEN => PokedexEntry {
+                base_stats: (85, 85, 100, 95, 135, 70),
+                types: (PokemonType::DARK, PokemonType::GRASS),
+                weight_kg: 74.2,
+            },
+            PokemonName::CHIENPAO => PokedexEntry {
+                base_stats: (80, 120, 80, 90, 65, 135),
+                types: (PokemonType::DARK, PokemonType::ICE),
+                weight_kg: 152.2,
+            },
+            PokemonName::TINGLU => PokedexEntry {
+                base_stats: (155, 110, 125, 55, 80, 45),
+                types: (PokemonType::DARK, PokemonType::GROUND),
+                weight_kg: 699.7,
+            },
+            PokemonName::CHIYU => PokedexEntry {
+                base_stats: (55, 80, 80, 135, 120, 100),
+                types: (PokemonType::DARK, PokemonType::FIRE),
+                weight_kg: 4.9,
+            },
+            PokemonName::ROARINGMOON => PokedexEntry {
+                base_stats: (105, 139, 71, 55, 101, 119),
+                types: (PokemonType::DRAGON, PokemonType::DARK),
+                weight_kg: 380.0,
+            },
+            PokemonName::IRONVALIANT => PokedexEntry {
+                base_stats: (74, 130, 90, 120, 60, 116),
+                types: (PokemonType::FAIRY, PokemonType::FIGHTING),
+                weight_kg: 35.0,
+            },
+            PokemonName::KORAIDON => PokedexEntry {
+                base_stats: (100, 135, 115, 85, 100, 135),
+                types: (PokemonType::FIGHTING, PokemonType::DRAGON),
+                weight_kg: 303.0,
+            },
+            PokemonName::MIRAIDON => PokedexEntry {
+                base_stats: (100, 85, 100, 135, 115, 135),
+                types: (PokemonType::ELECTRIC, PokemonType::DRAGON),
+                weight_kg: 240.0,
+            },
+            PokemonName::WALKINGWAKE => PokedexEntry {
+                base_stats: (99, 83, 91, 125, 83, 109),
+                types: (PokemonType::WATER, PokemonType::DRAGON),
+                weight_kg: 280.0,
+            },
+            PokemonName::IRONLEAVES => PokedexEntry {
+                base_stats: (90, 130, 88, 70, 108, 104),
+                types: (PokemonType::GRASS, PokemonType::PSYCHIC),
+                weight_kg: 125.0,
+            },
+            PokemonName::DIPPLIN => PokedexEntry {
+                base_stats: (80, 80, 110, 95, 80, 40),
+                types: (PokemonType::GRASS, PokemonType::DRAGON),
+                weight_kg: 4.4,
+            },
+            PokemonName::POLTCHAGEIST => PokedexEntry {
+                base_stats: (40, 45, 45, 74, 54, 50),
+                types: (PokemonType::GRASS, PokemonType::GHOST),
+                weight_kg: 1.1,
+            },
+            PokemonName::POLTCHAGEISTARTISAN => PokedexEntry {
+                base_stats: (40, 45, 45, 74, 54, 50),
+                types: (PokemonType::GRASS, PokemonType::GHOST),
+                weight_kg: 1.1,
+            },
+            PokemonName::SINISTCHA => PokedexEntry {
+                base_stats: (71, 60, 106, 121, 80, 70),
+                types: (PokemonType::GRASS, PokemonType::GHOST),
+                weight_kg: 2.2,
+            },
+            PokemonName::SINISTCHAMASTERPIECE => PokedexEntry {
+                base_stats: (71, 60, 106, 121, 80, 70),
+                types: (PokemonType::GRASS, PokemonType::GHOST),
+                weight_kg: 2.2,
+            },
+            PokemonName::OKIDOGI => PokedexEntry {
+                base_stats: (88, 128, 115, 58, 86, 80),
+                types: (PokemonType::POISON, PokemonType::FIGHTING),
+                weight_kg: 92.0,
+            },
+            PokemonName::MUNKIDORI => PokedexEntry {
+                base_stats: (88, 75, 66, 130, 90, 106),
+                types: (PokemonType::POISON, PokemonType::PSYCHIC),
+                weight_kg: 12.2,
+            },
+            PokemonName::FEZANDIPITI => PokedexEntry {
+                base_stats: (88, 91, 82, 70, 125, 99),
+                types: (PokemonType::POISON, PokemonType::FAIRY),
+                weight_kg: 30.1,
+            },
+            PokemonName::OGERPON => PokedexEntry {
+                base_stats: (80, 120, 84, 60, 96, 110),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 39.8,
+            },
+            PokemonName::OGERPONWELLSPRING => PokedexEntry {
+                base_stats: (80, 120, 84, 60, 96, 110),
+                types: (PokemonType::GRASS, PokemonType::WATER),
+                weight_kg: 39.8,
+            },
+            PokemonName::OGERPONHEARTHFLAME => PokedexEntry {
+                base_stats: (80, 120, 84, 60, 96, 110),
+                types: (PokemonType::GRASS, PokemonType::FIRE),
+                weight_kg: 39.8,
+            },
+            PokemonName::OGERPONCORNERSTONE => PokedexEntry {
+                base_stats: (80, 120, 84, 60, 96, 110),
+                types: (PokemonType::GRASS, PokemonType::ROCK),
+                weight_kg: 39.8,
+            },
+            PokemonName::OGERPONTEALTERA => PokedexEntry {
+                base_stats: (80, 120, 84, 60, 96, 110),
+                types: (PokemonType::GRASS, PokemonType::TYPELESS),
+                weight_kg: 39.8,
+            },
+            PokemonName::OGERPONWELLSPRINGTERA => PokedexEntry {
+                base_stats: (80, 120, 84, 60, 96, 110),
+                types: (PokemonType::GRASS, PokemonType::WATER),
+                weight_kg: 39.8,
+            },
+            PokemonName::OGERPONHEARTHFLAMETERA => PokedexEntry {
+                base_stats: (80, 120, 84, 60, 96, 110),
+                types: (PokemonType::GRASS, PokemonType::FIRE),
+                weight_kg: 39.8,
+            },
+            PokemonName::OGERPONCORNERSTONETERA => PokedexEntry {
+                base_stats: (80, 120, 84, 60, 96, 110),
+                types: (PokemonType::GRASS, PokemonType::ROCK),
+                weight_kg: 39.8,
+            },
+            PokemonName::ARCHALUDON => PokedexEntry {
+                base_stats: (90, 105, 130, 125, 65, 85),
+                types: (PokemonType::STEEL, PokemonType::DRAGON),
+                weight_kg: 60.0,
+            },
+            PokemonName::HYDRAPPLE => PokedexEntry {
+                base_stats: (106, 80, 110, 120, 80, 44),
+                types: (PokemonType::GRASS, PokemonType::DRAGON),
+                weight_kg: 93.0,
+            },
+            PokemonName::GOUGINGFIRE => PokedexEntry {
+                base_stats: (105, 115, 121, 65, 93, 91),
+                types: (PokemonType::FIRE, PokemonType::DRAGON),
+                weight_kg: 590.0,
+            },
+            PokemonName::RAGINGBOLT => PokedexEntry {
+                base_stats: (125, 73, 91, 137, 89, 75),
+                types: (PokemonType::ELECTRIC, PokemonType::DRAGON),
+                weight_kg: 480.0,
+            },
+            PokemonName::IRONBOULDER => PokedexEntry {
+                base_stats: (90, 120, 80, 68, 108, 124),
+                types: (PokemonType::ROCK, PokemonType::PSYCHIC),
+                weight_kg: 162.5,
+            },
+            PokemonName::IRONCROWN => PokedexEntry {
+                base_stats: (90, 72, 100, 122, 108, 98),
+                types: (PokemonType::STEEL, PokemonType::PSYCHIC),
+                weight_kg: 156.0,
+            },
+            PokemonName::TERAPAGOS => PokedexEntry {
+                base_stats: (90, 65, 85, 65, 85, 60),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 6.5,
+            },
+            PokemonName::TERAPAGOSTERASTAL => PokedexEntry {
+                base_stats: (95, 95, 110, 105, 110, 85),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 16.0,
+            },
+            PokemonName::TERAPAGOSSTELLAR => PokedexEntry {
+                base_stats: (160, 105, 110, 130, 110, 85),
+                types: (PokemonType::NORMAL, PokemonType::TYPELESS),
+                weight_kg: 77.0,
+            },
+            PokemonName::PECHARUNT => PokedexEntry {
+                base_stats: (88, 88, 160, 88, 88, 88),
+                types: (PokemonType::POISON, PokemonType::GHOST),
+                weight_kg: 0.3,
+            },
+            PokemonName::DRAGONITEMEGA => PokedexEntry {
+                base_stats: (91, 124, 115, 145, 125, 100),
+                types: (PokemonType::DRAGON, PokemonType::FLYING),
+                weight_kg: 210.0,
+            },
+            PokemonName::CLEFABLEMEGA => PokedexEntry {
+                base_stats: (95, 80, 93, 135, 110, 70),
+                types: (PokemonType::FAIRY, PokemonType::FLYING),
+                weight_kg: 40.0,
+            },
+            PokemonName::MEGANIUMMEGA => PokedexEntry {
+                base_stats: (80, 92, 115, 143, 115, 80),
+                types: (PokemonType::GRASS, PokemonType::FAIRY),
+                weight_kg: 100.5,
+            },
+            PokemonName::FERALIGATRMEGA => PokedexEntry {
+                base_stats: (85, 160, 125, 89, 93, 78),
+                types: (PokemonType::WATER, PokemonType::DRAGON),
+                weight_kg: 88.8,
+            },
+            PokemonName::EMBOARMEGA => PokedexEntry {
+                base_stats: (110, 148, 75, 110, 110, 75),
+                types: (PokemonType::FIRE, PokemonType::FIGHTING),
+                weight_kg: 150.0,
+            },
+            PokemonName::CHESNAUGHTMEGA => PokedexEntry {
+                base_stats: (88, 137, 172, 74, 115, 44),
+                types: (PokemonType::GRASS, PokemonType::FIGHTING),
+                weight_kg: 90.0,
+            },
+            PokemonName::DELPHOXMEGA => PokedexEntry {
+                base_stats: (75, 69, 72, 159, 125, 134),
+                types: (PokemonType::FIRE, PokemonType::PSYCHIC),
+                weight_kg: 39.0,
+            },
+            PokemonName::GRENINJAMEGA => PokedexEntry {
+                base_stats: (72, 125, 77, 133, 81, 142),
+                types: (PokemonType::WATER, PokemonType::DARK),
+                weight_kg: 40.0,
+            },
+            PokemonName::CRABOMINABLEMEGA => PokedexEntry {
+                base_stats: (97, 157, 122, 62, 107, 33),
+                types: (PokemonType::FIGHTING, PokemonType::ICE),
+                weight_kg: 180.0,
+            },
+            PokemonName::GOLURKMEGA => PokedexEntry {
+                base_stats: (89, 159, 105, 70, 105, 55),
+                types: (PokemonType::GROUND, PokemonType::GHOST),
+                weight_kg: 330.0,
+            },
+            PokemonName::SCOVILLAINMEGA => PokedexEntry {
+                base_stats: (65, 138, 85, 138, 85, 75),
+                types: (PokemonType::GRASS, PokemonType::FIRE),
+                weight_kg: 15.0,
+            },
+            PokemonName::GLIMMORAMEGA => PokedexEntry {
+                base_stats: (83, 90, 105, 150, 96, 101),
+                types: (PokemonType::ROCK, PokemonType::POISON),
+                weight_kg: 45.0,
+            },
+            PokemonName::FLOETTEMEGA => PokedexEntry {
+                base_stats: (74, 85, 87, 155, 148, 102),
+                types: (PokemonType::FAIRY, PokemonType::TYPELESS),
+                weight_kg: 0.9,
+            },
+            PokemonName::VICTREEBELMEGA => PokedexEntry {
+                base_stats: (80, 125, 85, 135, 95, 70),
+                types: (PokemonType::GRASS, PokemonType::POISON),
+                weight_kg: 15.5,
+            },
+            PokemonName::STARMIEMEGA => PokedexEntry {
+                base_stats: (60, 140, 105, 130, 105, 120),
+                types: (PokemonType::WATER, PokemonType::PSYCHIC),
+                weight_kg: 80.0,
+            },
+            PokemonName::HAWLUCHAMEGA => PokedexEntry {
+                base_stats: (78, 137, 100, 74, 93, 118),
+                types: (PokemonType::FIGHTING, PokemonType::FLYING),
+                weight_kg: 21.5,
+            },
+            PokemonName::SKARMORYMEGA => PokedexEntry {
+                base_stats: (65, 140, 110, 40, 100, 110),
+                types: (PokemonType::STEEL, PokemonType::FLYING),
+                weight_kg: 50.5,
+            },
+            PokemonName::MEOWSTICMEGA => PokedexEntry {
+                base_stats: (74, 48, 76, 143, 101, 124),
+                types: (PokemonType::PSYCHIC, PokemonType::TYPELESS),
+                weight_kg: 8.5,
+            },
+            PokemonName::FROSLASSMEGA => PokedexEntry {
+                base_stats: (70, 80, 70, 140, 100, 120),
+                types: (PokemonType::ICE, PokemonType::GHOST),
+                weight_kg: 26.6,
+            },
+            PokemonName::EXCADRILLMEGA => PokedexEntry {
+                base_stats: (110, 165, 100, 65, 65, 103),
+                types: (PokemonType::GROUND, PokemonType::STEEL),
+                weight_kg: 40.4,
+            },
+            PokemonName::DRAMPAMEGA => PokedexEntry {
+                base_stats: (78, 85, 110, 160, 114, 36),
+                types: (PokemonType::NORMAL, PokemonType::DRAGON),
+                weight_kg: 185.0,
+            },
+            PokemonName::CHIMECHOMEGA => PokedexEntry {
+                base_stats: (75, 50, 110, 135, 120, 65),
+                types: (PokemonType::PSYCHIC, PokemonType::STEEL),
+                weight_kg: 1.0,
+            },
+            PokemonName::CHANDELUREMEGA => PokedexEntry {
+                base_stats: (60, 75, 110, 175, 110, 90),
+                types: (PokemonType::GHOST, PokemonType::FIRE),
+                weight_kg: 34.3,
+            },
+        }
+    }
+}
+
+impl PokemonName {
+    pub fn pokedex_entry_in(&self, generation: Generation) -> PokedexEntry {
+        let mut entry = self.pokedex_entry();
+        let base_stats = match generation {
+            Generation::GEN1 => gen1::base_stats::base_stats(self),
+            Generation::GEN6 => self.base_stats_before_gen7(),
+            _ if generation <= Generation::GEN5 => self
+                .base_stats_before_gen6()
+                .or_else(|| self.base_stats_before_gen7()),
+            _ => None,
+        };
+        if let Some(base_stats) = base_stats {
+            entry.base_stats = base_stats;
+        }
+        if generation <= Generation::GEN5 {
+            if let Some(types) = self.types_before_gen6(generation) {
+                entry.types = types;
+            }
+        }
+        entry
+    }
+
+    fn base_stats_before_gen6(&self) -> Option<(i16, i16, i16, i16, i16, i16)> {
+        match self {
+            PokemonName::BUTTERFREE => Some((60, 45, 50, 80, 80, 70)),
+            PokemonName::BEEDRILL => Some((65, 80, 40, 45, 80, 75)),
+            PokemonName::PIDGEOT => Some((83, 80, 75, 70, 70, 91)),
+            PokemonName::PIKACHU => Some((35, 55, 30, 50, 40, 90)),
+            PokemonName::RAICHU => Some((60, 90, 55, 90, 80, 100)),
+            PokemonName::NIDOQUEEN => Some((90, 82, 87, 75, 85, 76)),
+            PokemonName::NIDOKING => Some((81, 92, 77, 85, 75, 85)),
+            PokemonName::CLEFABLE => Some((95, 70, 73, 85, 90, 60)),
+            PokemonName::WIGGLYTUFF => Some((140, 70, 45, 75, 50, 45)),
+            PokemonName::VILEPLUME => Some((75, 80, 85, 100, 90, 50)),
+            PokemonName::POLIWRATH => Some((90, 85, 95, 70, 90, 70)),
+            PokemonName::ALAKAZAM => Some((55, 50, 45, 135, 85, 120)),
+            PokemonName::VICTREEBEL => Some((80, 105, 65, 100, 60, 70)),
+            PokemonName::GOLEM => Some((80, 110, 130, 55, 65, 45)),
+            PokemonName::AMPHAROS => Some((90, 75, 75, 115, 90, 55)),
+            PokemonName::BELLOSSOM => Some((75, 80, 85, 90, 100, 50)),
+            PokemonName::AZUMARILL => Some((100, 50, 80, 50, 80, 50)),
+            PokemonName::JUMPLUFF => Some((75, 55, 70, 55, 85, 110)),
+            PokemonName::BEAUTIFLY => Some((60, 70, 50, 90, 50, 65)),
+            PokemonName::EXPLOUD => Some((104, 91, 63, 91, 63, 68)),
+            PokemonName::STARAPTOR => Some((85, 120, 70, 50, 50, 100)),
+            PokemonName::ROSERADE => Some((60, 70, 55, 125, 105, 90)),
+            PokemonName::STOUTLAND => Some((85, 100, 90, 45, 90, 80)),
+            PokemonName::UNFEZANT => Some((80, 105, 80, 65, 55, 93)),
+            PokemonName::GIGALITH => Some((85, 135, 130, 60, 70, 25)),
+            PokemonName::SEISMITOAD => Some((105, 85, 75, 85, 75, 74)),
+            PokemonName::LEAVANNY => Some((75, 103, 80, 70, 70, 92)),
+            PokemonName::SCOLIPEDE => Some((60, 90, 89, 55, 69, 112)),
+            PokemonName::KROOKODILE => Some((95, 117, 70, 65, 70, 92)),
+            _ => None,
+        }
+    }
+
+    fn base_stats_before_gen7(&self) -> Option<(i16, i16, i16, i16, i16, i16)> {
+        match self {
+            PokemonName::ARBOK => Some((60, 85, 69, 65, 79, 80)),
+            PokemonName::DUGTRIO => Some((35, 80, 50, 50, 70, 120)),
+            PokemonName::FARFETCHD => Some((52, 65, 55, 58, 62, 60)),
+            PokemonName::DODRIO => Some((60, 110, 70, 60, 60, 100)),
+            PokemonName::ELECTRODE => Some((60, 50, 70, 80, 80, 140)),
+            PokemonName::EXEGGUTOR => Some((95, 95, 85, 125, 65, 55)),
+            PokemonName::NOCTOWL => Some((100, 50, 50, 76, 96, 70)),
+            PokemonName::ARIADOS => Some((70, 90, 70, 60, 60, 40)),
+            PokemonName::QWILFISH => Some((65, 95, 75, 55, 55, 85)),
+            PokemonName::MAGCARGO => Some((50, 50, 120, 80, 80, 30)),
+            PokemonName::CORSOLA => Some((55, 55, 85, 65, 85, 35)),
+            PokemonName::MANTINE => Some((65, 40, 70, 80, 140, 70)),
+            PokemonName::SWELLOW => Some((60, 85, 60, 50, 50, 125)),
+            PokemonName::PELIPPER => Some((60, 50, 100, 85, 70, 65)),
+            PokemonName::MASQUERAIN => Some((70, 60, 62, 80, 82, 60)),
+            PokemonName::DELCATTY => Some((70, 65, 65, 55, 55, 70)),
+            PokemonName::VOLBEAT => Some((65, 73, 55, 47, 75, 85)),
+            PokemonName::ILLUMISE => Some((65, 47, 55, 73, 75, 85)),
+            PokemonName::LUNATONE => Some((70, 55, 65, 95, 85, 70)),
+            PokemonName::SOLROCK => Some((70, 95, 85, 55, 65, 70)),
+            PokemonName::CHIMECHO => Some((65, 50, 70, 95, 80, 65)),
+            PokemonName::WOOBAT => Some((55, 45, 43, 55, 43, 72)),
+            PokemonName::CRUSTLE => Some((70, 95, 125, 65, 75, 45)),
+            PokemonName::BEARTIC => Some((95, 110, 80, 70, 80, 50)),
+            PokemonName::CRYOGONAL => Some((70, 50, 30, 95, 135, 105)),
+            _ => None,
+        }
+    }
+
+    fn types_before_gen6(&self, generation: Generation) -> Option<(PokemonType, PokemonType)> {
+        match self {
+            PokemonName::CLEFFA
+            | PokemonName::CLEFAIRY
+            | PokemonName::CLEFABLE
+            | PokemonName::IGGLYBUFF
+            | PokemonName::JIGGLYPUFF
+            | PokemonName::WIGGLYTUFF
+            | PokemonName::SNUBBULL
+            | PokemonName::GRANBULL
+            | PokemonName::TOGEPI
+            | PokemonName::AZURILL => Some((PokemonType::NORMAL, PokemonType::TYPELESS)),
+            PokemonName::TOGETIC | PokemonName::TOGEKISS => {
+                Some((PokemonType::NORMAL, PokemonType::FLYING))
+            }
+            PokemonName::MARILL | PokemonName::AZUMARILL => {
+                Some((PokemonType::WATER, PokemonType::TYPELESS))
+            }
+            PokemonName::MIMEJR
+            | PokemonName::MRMIME
+            | PokemonName::RALTS
+            | PokemonName::KIRLIA
+            | PokemonName::GARDEVOIR => Some((PokemonType::PSYCHIC, PokemonType::TYPELESS)),
+            PokemonName::MAWILE => Some((PokemonType::STEEL, PokemonType::TYPELESS)),
+            // the steel type was added in gen2
+            PokemonName::MAGNEMITE | PokemonName::MAGNETON if generation == Generation::GEN1 => {
+                Some((PokemonType::ELECTRIC, PokemonType::TYPELESS))
+            }
+            // the appliance formes only kept their ghost type in gen4
+            PokemonName::ROTOMHEAT
+            | PokemonName::ROTOMWASH
+            | PokemonName::ROTOMFROST
+            | PokemonName::ROTOMFAN
+            | PokemonName::ROTOMMOW
+                if generation == Generation::GEN4 =>
+            {
+                Some((PokemonType::ELECTRIC, PokemonType::GHOST))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pokedex_entry_has_the_latest_values() {
+        let entry = PokemonName::CLEFABLE.pokedex_entry();
+        assert_eq!((95, 70, 73, 95, 90, 60), entry.base_stats);
+        assert_eq!((PokemonType::FAIRY, PokemonType::TYPELESS), entry.types);
+        assert_eq!(
+            entry,
+            PokemonName::CLEFABLE.pokedex_entry_in(Generation::GEN9)
+        );
+    }
+
+    #[test]
+    fn test_types_and_base_stats_before_gen6() {
+        let entry = PokemonName::CLEFABLE.pokedex_entry_in(Generation::GEN5);
+        assert_eq!((95, 70, 73, 85, 90, 60), entry.base_stats);
+        assert_eq!((PokemonType::NORMAL, PokemonType::TYPELESS), entry.types);
+        assert_eq!(40.0, entry.weight_kg);
+
+        let entry = PokemonName::AZUMARILL.pokedex_entry_in(Generation::GEN4);
+        assert_eq!((100, 50, 80, 50, 80, 50), entry.base_stats);
+        assert_eq!((PokemonType::WATER, PokemonType::TYPELESS), entry.types);
+    }
+
+    #[test]
+    fn test_gen6_has_the_gen6_raises_but_not_the_gen7_raises() {
+        let clefable = PokemonName::CLEFABLE.pokedex_entry_in(Generation::GEN6);
+        assert_eq!(PokemonName::CLEFABLE.pokedex_entry(), clefable);
+
+        let arbok = PokemonName::ARBOK.pokedex_entry_in(Generation::GEN6);
+        assert_eq!((60, 85, 69, 65, 79, 80), arbok.base_stats);
+        let arbok = PokemonName::ARBOK.pokedex_entry_in(Generation::GEN7);
+        assert_eq!((60, 95, 69, 65, 79, 80), arbok.base_stats);
+    }
+
+    #[test]
+    fn test_species_that_did_not_change_are_the_same_in_every_generation() {
+        let latest = PokemonName::GARCHOMP.pokedex_entry();
+        for generation in [Generation::GEN4, Generation::GEN5, Generation::GEN6] {
+            assert_eq!(latest, PokemonName::GARCHOMP.pokedex_entry_in(generation));
+        }
+    }
+
+    #[test]
+    fn test_gen1_uses_the_special_stat_and_types() {
+        let alakazam = PokemonName::ALAKAZAM.pokedex_entry_in(Generation::GEN1);
+        assert_eq!((55, 50, 45, 135, 135, 120), alakazam.base_stats);
+
+        let magneton = PokemonName::MAGNETON.pokedex_entry_in(Generation::GEN1);
+        assert_eq!(
+            (PokemonType::ELECTRIC, PokemonType::TYPELESS),
+            magneton.types
+        );
+        let magneton = PokemonName::MAGNETON.pokedex_entry_in(Generation::GEN2);
+        assert_eq!((PokemonType::ELECTRIC, PokemonType::STEEL), magneton.types);
+    }
+
+    #[test]
+    fn test_rotom_formes_were_ghost_type_in_gen4() {
+        let gen4 = PokemonName::ROTOMWASH.pokedex_entry_in(Generation::GEN4);
+        assert_eq!((PokemonType::ELECTRIC, PokemonType::GHOST), gen4.types);
+        let gen5 = PokemonName::ROTOMWASH.pokedex_entry_in(Generation::GEN5);
+        assert_eq!((PokemonType::ELECTRIC, PokemonType::WATER), gen5.types);
+    }
+}
@@ -0,0 +1,547 @@
+use crate::control::{CancellationToken, SearchControl, SearchProgress};
+use crate::engine::generate_instructions::generate_instructions_from_move_pair;
+use crate::engine::state::MoveChoice;
+use crate::evaluator::{EvaluationWeights, Evaluator};
+use crate::instruction::{BranchPruning, StateInstructions};
+use crate::io::deserialize_state;
+use crate::mcts::{MctsConfig, MctsResult, MctsTree, SearchLimit};
+use crate::mcts_threaded::SharedMctsTree;
+use crate::search::{
+    iterative_deepen_expectiminimax_with_control, ExpectiminimaxOptions, SubGameSolver,
+};
+use crate::state::{Generation, State};
+use std::io::{BufRead, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+// a search without a time or iteration limit runs until it is stopped
+const UNLIMITED_SEARCH_TIME: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Where responses are written. It is shared with the thread of a running search
+pub type ProtocolOutput = Arc<Mutex<dyn Write + Send>>;
+
+fn send(output: &ProtocolOutput, line: &str) {
+    let mut output = output.lock().unwrap();
+    let _ = writeln!(output, "{}", line);
+    let _ = output.flush();
+}
+
+struct RunningSearch {
+    cancellation: CancellationToken,
+    handle: JoinHandle<()>,
+}
+
+/// A line-oriented protocol for programs that keep one engine process for a whole battle.
+///
+/// Commands:
+/// - `isready`: answered with `readyok` once every earlier command has been handled
+/// - `position <state>`: sets the state
+/// - `go mcts [time=<ms>] [iterations=<n>] [threads=<n>] [prune=<percent>]`: starts a monte-carlo tree search
+/// - `go id [time=<ms>] [prune=<percent>]`: starts an iterative deepening search
+/// - `stop`: stops the running search
+/// - `branches s1=<move> s2=<move>`: lists the outcomes of a pair of moves as
+///   `branch <index> <percentage> <instructions>`
+/// - `apply s1=<move> s2=<move> [branch=<index>]`: applies one of the outcomes listed by `branches`
+/// - `eval`: evaluates the state
+/// - `quit`
+///
+/// A search runs in the background and writes `info` lines while it runs and a
+/// `bestmove <move>` line when it finishes. A search without a time or iteration limit runs
+/// until `stop`. Any command that changes the state or starts a search stops the running search first.
+/// Errors are written as `error <message>`
+pub struct Protocol {
+    state: State,
+    generation: Option<Generation>,
+    weights: EvaluationWeights,
+    seed: Option<u64>,
+    pruning: BranchPruning,
+    output: ProtocolOutput,
+    search: Option<RunningSearch>,
+}
+
+impl Protocol {
+    pub fn new(
+        state: State,
+        generation: Option<Generation>,
+        weights: EvaluationWeights,
+        seed: Option<u64>,
+        pruning: BranchPruning,
+        output: ProtocolOutput,
+    ) -> Protocol {
+        Protocol {
+            state,
+            generation,
+            weights,
+            seed,
+            pruning,
+            output,
+            search: None,
+        }
+    }
+
+    /// Handles commands from `input` until `quit` or the end of the input
+    pub fn run(&mut self, input: impl BufRead) {
+        for line in input.lines() {
+            match line {
+                Ok(line) => {
+                    if !self.handle(&line) {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    self.send(&format!("error {}", e));
+                    break;
+                }
+            }
+        }
+        self.wait_for_search();
+    }
+
+    /// Handles one command, returning false for `quit`
+    pub fn handle(&mut self, line: &str) -> bool {
+        let mut parts = line.split_whitespace();
+        let command = parts.next().unwrap_or("");
+        let args: Vec<&str> = parts.collect();
+        let result = match command {
+            "" => Ok(()),
+            "isready" => {
+                self.send("readyok");
+                Ok(())
+            }
+            "position" => self.position(&args),
+            "go" => self.go(&args),
+            "stop" => {
+                self.stop();
+                Ok(())
+            }
+            "branches" => self.branches(&args),
+            "apply" => self.apply(&args),
+            "eval" => {
+                self.send(&format!("eval {}", self.weights.evaluate(&self.state)));
+                Ok(())
+            }
+            "quit" => {
+                self.stop();
+                return false;
+            }
+            command => Err(format!("unknown command {}", command)),
+        };
+        if let Err(e) = result {
+            self.send(&format!("error {}", e));
+        }
+        true
+    }
+
+    fn send(&self, line: &str) {
+        send(&self.output, line);
+    }
+
+    fn stop(&mut self) {
+        if let Some(search) = &self.search {
+            search.cancellation.cancel();
+        }
+        self.wait_for_search();
+    }
+
+    fn wait_for_search(&mut self) {
+        if let Some(search) = self.search.take() {
+            let _ = search.handle.join();
+        }
+    }
+
+    fn position(&mut self, args: &[&str]) -> Result<(), String> {
+        let state_string = match args {
+            [state_string] => state_string,
+            _ => return Err("usage: position <state>".to_string()),
+        };
+        self.stop();
+        self.state = deserialize_state(state_string, self.generation)
+            .map_err(|e| format!("invalid state: {}", e))?;
+        Ok(())
+    }
+
+    // the outcomes of the `s1` and `s2` moves, in the order `branches` lists them
+    fn move_pair_branches(
+        &mut self,
+        args: &[(&str, &str)],
+        usage: &str,
+    ) -> Result<Vec<StateInstructions>, String> {
+        let (s1, s2) = match (get_argument(args, "s1"), get_argument(args, "s2")) {
+            (Some(s1), Some(s2)) => (s1, s2),
+            _ => return Err(format!("usage: {}", usage)),
+        };
+        let s1_move = MoveChoice::from_string(s1, &self.state.side_one)
+            .ok_or_else(|| format!("invalid move for side one: {}", s1))?;
+        let s2_move = MoveChoice::from_string(s2, &self.state.side_two)
+            .ok_or_else(|| format!("invalid move for side two: {}", s2))?;
+        self.stop();
+        Ok(generate_instructions_from_move_pair(
+            &mut self.state,
+            &s1_move,
+            &s2_move,
+            false,
+        ))
+    }
+
+    fn branches(&mut self, args: &[&str]) -> Result<(), String> {
+        let args = parse_arguments(args)?;
+        let branches = self.move_pair_branches(&args, "branches s1=<move> s2=<move>")?;
+        for (index, branch) in branches.iter().enumerate() {
+            self.send(&format!(
+                "branch {} {} {:?}",
+                index, branch.percentage, branch.instruction_list
+            ));
+        }
+        Ok(())
+    }
+
+    fn apply(&mut self, args: &[&str]) -> Result<(), String> {
+        let args = parse_arguments(args)?;
+        let branch = parse_argument::<usize>(&args, "branch")?.unwrap_or(0);
+        let mut branches =
+            self.move_pair_branches(&args, "apply s1=<move> s2=<move> [branch=<index>]")?;
+        if branch >= branches.len() {
+            return Err(format!(
+                "branch {} does not exist, there are {} branches",
+                branch,
+                branches.len()
+            ));
+        }
+        let instructions = branches.swap_remove(branch);
+        self.state
+            .apply_instructions(&instructions.instruction_list);
+        Ok(())
+    }
+
+    fn go(&mut self, args: &[&str]) -> Result<(), String> {
+        let (search, args) = match args.split_first() {
+            Some((search, args)) => (*search, parse_arguments(args)?),
+            None => return Err("usage: go mcts|id [time=<ms>] ...".to_string()),
+        };
+        let time = parse_argument::<u64>(&args, "time")?;
+        let iterations = parse_argument::<u32>(&args, "iterations")?.unwrap_or(0);
+        let threads = parse_argument::<usize>(&args, "threads")?.unwrap_or(1);
+        let pruning = match parse_argument::<f32>(&args, "prune")? {
+            Some(min_percentage) => BranchPruning {
+                min_percentage,
+                ..self.pruning
+            },
+            None => self.pruning,
+        };
+        let use_mcts = match search {
+            "mcts" => true,
+            "id" => false,
+            search => return Err(format!("unknown search {}", search)),
+        };
+        // iterative deepening searches one more depth at a time and has no iterations to count
+        if !use_mcts && iterations > 0 {
+            return Err("iterations is only supported by go mcts".to_string());
+        }
+        let max_time = match time {
+            Some(time) => Duration::from_millis(time),
+            None if iterations > 0 => Duration::ZERO,
+            None => UNLIMITED_SEARCH_TIME,
+        };
+        self.stop();
+
+        let state = self.state.clone();
+        let weights = self.weights.clone();
+        let config = MctsConfig {
+            pruning,
+            threads,
+            seed: self.seed,
+            ..Default::default()
+        };
+        let output = Arc::clone(&self.output);
+        let cancellation = CancellationToken::new();
+        let search_cancellation = cancellation.clone();
+        let handle = thread::spawn(move || {
+            let start_time = Instant::now();
+            let on_progress = |progress: &SearchProgress| {
+                send(&output, &progress_info(&state, progress, start_time));
+            };
+            let control = SearchControl {
+                cancellation: search_cancellation,
+                on_progress: Some(&on_progress),
+                ..Default::default()
+            };
+            let (side_one_options, side_two_options) = state.root_get_all_options();
+            let mut search_state = state.clone();
+            let best_move = if use_mcts {
+                let result = if threads > 1 {
+                    SharedMctsTree::with_config(config).search_with_control(
+                        &mut search_state,
+                        side_one_options,
+                        side_two_options,
+                        SearchLimit::new(max_time, iterations),
+                        &weights,
+                        &control,
+                    )
+                } else {
+                    MctsTree::with_config(config).search_with_control(
+                        &mut search_state,
+                        side_one_options,
+                        side_two_options,
+                        SearchLimit::new(max_time, iterations),
+                        &weights,
+                        &control,
+                    )
+                };
+                send(&output, &mcts_info(&state, &result, start_time));
+                best_mcts_move(&result)
+            } else {
+                let (side_one_options, side_two_options, scores, _) =
+                    iterative_deepen_expectiminimax_with_control(
+                        &mut search_state,
+                        side_one_options,
+                        side_two_options,
+                        max_time,
+                        ExpectiminimaxOptions {
+                            pruning,
+                            evaluator: &weights,
+                            ..Default::default()
+                        },
+                        &control,
+                    );
+                let (best_index, _) = SubGameSolver::Safest.best_choice(
+                    &scores,
+                    side_one_options.len(),
+                    side_two_options.len(),
+                );
+                side_one_options[best_index]
+            };
+            send(
+                &output,
+                &format!("bestmove {}", best_move.to_string(&state.side_one)),
+            );
+        });
+        self.search = Some(RunningSearch {
+            cancellation,
+            handle,
+        });
+        Ok(())
+    }
+}
+
+// `key=value` arguments
+fn parse_arguments<'a>(args: &[&'a str]) -> Result<Vec<(&'a str, &'a str)>, String> {
+    args.iter()
+        .map(|arg| {
+            arg.split_once('=')
+                .ok_or_else(|| format!("expected key=value, got {}", arg))
+        })
+        .collect()
+}
+
+fn get_argument<'a>(args: &[(&str, &'a str)], key: &str) -> Option<&'a str> {
+    args.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+}
+
+fn parse_argument<T: std::str::FromStr>(
+    args: &[(&str, &str)],
+    key: &str,
+) -> Result<Option<T>, String> {
+    match get_argument(args, key) {
+        Some(value) => value
+            .parse::<T>()
+            .map(Some)
+            .map_err(|_| format!("invalid {}: {}", key, value)),
+        None => Ok(None),
+    }
+}
+
+fn best_mcts_move(result: &MctsResult) -> MoveChoice {
+    result
+        .s1
+        .iter()
+        .max_by_key(|r| r.visits)
+        .map(|r| r.move_choice)
+        .unwrap_or(MoveChoice::None)
+}
+
+fn mcts_info(state: &State, result: &MctsResult, start_time: Instant) -> String {
+    let best_move = best_mcts_move(result);
+    let score = result
+        .s1
+        .iter()
+        .find(|r| r.move_choice == best_move)
+        .map(|r| r.total_score / r.visits.max(1) as f32)
+        .unwrap_or(0.0);
+    format!(
+        "info time {} iterations {} score {:.4} bestmove {}",
+        start_time.elapsed().as_millis(),
+        result.iteration_count,
+        score,
+        best_move.to_string(&state.side_one)
+    )
+}
+
+fn progress_info(state: &State, progress: &SearchProgress, start_time: Instant) -> String {
+    match progress {
+        SearchProgress::Depth {
+            depth,
+            best_move,
+            side_one_options,
+            side_two_options,
+            scores,
+        } => {
+            let (_, score) = SubGameSolver::Safest.best_choice(
+                scores,
+                side_one_options.len(),
+                side_two_options.len(),
+            );
+            format!(
+                "info time {} depth {} score {:.4} bestmove {}",
+                start_time.elapsed().as_millis(),
+                depth,
+                score,
+                best_move.to_string(&state.side_one)
+            )
+        }
+        SearchProgress::Row {
+            depth,
+            finished_rows,
+            best_move,
+            side_one_options,
+            side_two_options,
+            scores,
+        } => {
+            let (_, score) =
+                SubGameSolver::Safest.best_choice(scores, *finished_rows, side_two_options.len());
+            format!(
+                "info time {} depth {} rows {}/{} score {:.4} bestmove {}",
+                start_time.elapsed().as_millis(),
+                depth,
+                finished_rows,
+                side_one_options.len(),
+                score,
+                best_move.to_string(&state.side_one)
+            )
+        }
+        SearchProgress::Iterations { result, .. } => mcts_info(state, result, start_time),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::Choices;
+    use crate::state::{PokemonMoveIndex, SideReference};
+
+    fn protocol() -> (Protocol, Arc<Mutex<Vec<u8>>>) {
+        let mut state = State::default();
+        let generation = state.generation;
+        for side_ref in [SideReference::SideOne, SideReference::SideTwo] {
+            state.get_side(&side_ref).get_active().replace_move(
+                PokemonMoveIndex::M0,
+                Choices::TACKLE,
+                generation,
+            );
+        }
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let protocol = Protocol::new(
+            state,
+            None,
+            EvaluationWeights::default(),
+            Some(1),
+            BranchPruning::default(),
+            output.clone(),
+        );
+        (protocol, output)
+    }
+
+    fn lines(output: &Arc<Mutex<Vec<u8>>>) -> Vec<String> {
+        String::from_utf8(output.lock().unwrap().clone())
+            .unwrap()
+            .lines()
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_search_with_iterations_ends_with_bestmove() {
+        let (mut protocol, output) = protocol();
+        protocol.run("go mcts iterations=2000\nisready\n".as_bytes());
+
+        let lines = lines(&output);
+        assert!(lines
+            .iter()
+            .any(|line| line.starts_with("info ") && line.contains(" iterations 2000 ")));
+        assert!(lines.iter().any(|line| line.starts_with("bestmove ")));
+    }
+
+    #[test]
+    fn test_stop_ends_an_unlimited_search() {
+        let (mut protocol, output) = protocol();
+        protocol.handle("go id");
+        protocol.handle("stop");
+
+        let lines = lines(&output);
+        assert!(lines.last().unwrap().starts_with("bestmove "));
+    }
+
+    #[test]
+    fn test_apply_changes_the_state() {
+        let (mut protocol, output) = protocol();
+        let before = protocol.state.serialize();
+        protocol.run("apply s1=tackle s2=tackle branch=0\n".as_bytes());
+
+        assert!(lines(&output).is_empty());
+        assert_ne!(before, protocol.state.serialize());
+    }
+
+    #[test]
+    fn test_branches_lists_the_outcomes_that_apply_indexes() {
+        let (mut protocol, output) = protocol();
+        protocol.state.side_one.get_active().replace_move(
+            PokemonMoveIndex::M1,
+            Choices::ZAPCANNON,
+            protocol.state.generation,
+        );
+        let before = protocol.state.clone();
+        protocol.run("branches s1=zapcannon s2=tackle\n".as_bytes());
+
+        let lines = lines(&output);
+        // zap cannon can miss
+        assert!(lines.len() > 1);
+        let total: f32 = lines
+            .iter()
+            .map(|line| {
+                line.split_whitespace()
+                    .nth(2)
+                    .unwrap()
+                    .parse::<f32>()
+                    .unwrap()
+            })
+            .sum();
+        assert!((total - 100.0).abs() < 1e-3);
+
+        let last = lines.len() - 1;
+        assert!(lines[last].starts_with(&format!("branch {} ", last)));
+        protocol.handle(&format!("apply s1=zapcannon s2=tackle branch={}", last));
+        let mut expected = before.clone();
+        let mut branches = generate_instructions_from_move_pair(
+            &mut expected,
+            &MoveChoice::Move(PokemonMoveIndex::M1),
+            &MoveChoice::Move(PokemonMoveIndex::M0),
+            false,
+        );
+        expected.apply_instructions(&branches.swap_remove(last).instruction_list);
+        assert_eq!(expected.serialize(), protocol.state.serialize());
+    }
+
+    #[test]
+    fn test_bad_commands_are_errors() {
+        let (mut protocol, output) = protocol();
+        protocol.run(
+            "apply s1=tackle\nposition\ngo nothing\ngo id iterations=100\nfly\nquit\neval\n"
+                .as_bytes(),
+        );
+
+        let lines = lines(&output);
+        assert_eq!(5, lines.len());
+        assert!(lines.iter().all(|line| line.starts_with("error ")));
+    }
+}
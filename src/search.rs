@@ -1,30 +1,209 @@
-use crate::engine::evaluate::evaluate;
+use crate::control::{CancellationToken, SearchControl, SearchProgress};
 use crate::engine::generate_instructions::generate_instructions_from_move_pair;
 use crate::engine::state::MoveChoice;
+use crate::evaluator::{DefaultEvaluator, Evaluator};
+use crate::instruction::BranchPruning;
+use crate::nash::solve_nash_equilibrium;
 use crate::state::State;
-use std::sync::mpsc::{channel, Receiver, Sender};
-use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+// how often iterative deepening checks whether it was cancelled
+const CANCELLATION_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// How the value of a sub-game (the payoff matrix of a future turn) is determined
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubGameSolver {
+    /// The pure-strategy maximin value for side one. Allows alpha-beta pruning
+    Safest,
+    /// The value of the mixed-strategy Nash equilibrium. Sub-games cannot be pruned
+    Nash,
+}
+
+impl SubGameSolver {
+    pub fn sub_game_value(
+        &self,
+        score_lookup: &[f32],
+        num_s1_moves: usize,
+        num_s2_moves: usize,
+    ) -> f32 {
+        match self {
+            SubGameSolver::Safest => pick_safest(score_lookup, num_s1_moves, num_s2_moves).1,
+            SubGameSolver::Nash => {
+                solve_nash_equilibrium(score_lookup, num_s1_moves, num_s2_moves).value
+            }
+        }
+    }
+
+    /// The index of side one's best move and the value of the sub-game
+    pub fn best_choice(
+        &self,
+        score_lookup: &[f32],
+        num_s1_moves: usize,
+        num_s2_moves: usize,
+    ) -> (usize, f32) {
+        match self {
+            SubGameSolver::Safest => pick_safest(score_lookup, num_s1_moves, num_s2_moves),
+            SubGameSolver::Nash => {
+                let equilibrium = solve_nash_equilibrium(score_lookup, num_s1_moves, num_s2_moves);
+                (equilibrium.s1_best_index(), equilibrium.value)
+            }
+        }
+    }
+}
+
+/// Default number of entries in a `TranspositionTable`
+pub const TRANSPOSITION_TABLE_SIZE: usize = 1 << 18;
+
+#[derive(Debug, Clone, Copy)]
+struct TranspositionTableEntry {
+    hash: u64,
+    depth: i8,
+    value: f32,
+}
+
+/// A fixed-size table of sub-game values keyed by `State::zobrist_hash`
+///
+/// Each hash maps to a single slot. An entry is only replaced by one that was searched
+/// at least as deep, and a lookup only succeeds if the stored entry was searched to exactly
+/// the depth requested, since the score of a finished battle grows with the remaining depth
+pub struct TranspositionTable {
+    entries: Vec<Option<TranspositionTableEntry>>,
+    mask: usize,
+}
+
+impl TranspositionTable {
+    /// `size` is rounded up to the next power of two
+    pub fn new(size: usize) -> TranspositionTable {
+        let size = size.max(1).next_power_of_two();
+        TranspositionTable {
+            entries: vec![None; size],
+            mask: size - 1,
+        }
+    }
+
+    pub fn get(&self, hash: u64, depth: i8) -> Option<f32> {
+        match self.entries[hash as usize & self.mask] {
+            Some(entry) if entry.hash == hash && entry.depth == depth => Some(entry.value),
+            _ => None,
+        }
+    }
+
+    pub fn insert(&mut self, hash: u64, depth: i8, value: f32) {
+        let slot = &mut self.entries[hash as usize & self.mask];
+        match slot {
+            Some(entry) if entry.hash == hash && entry.depth > depth => {}
+            _ => *slot = Some(TranspositionTableEntry { hash, depth, value }),
+        }
+    }
+}
+
+/// How an expectiminimax search values and prunes the tree
+pub struct ExpectiminimaxOptions<'a> {
+    /// Skips the rest of a row once it cannot beat an earlier row.
+    /// Only used with `SubGameSolver::Safest`
+    pub ab_prune: bool,
+    pub solver: SubGameSolver,
+    pub pruning: BranchPruning,
+    /// Re-uses the values of sub-games that were already searched.
+    /// Iterative deepening uses a table of its own when this is `None`
+    pub transposition_table: Option<&'a mut TranspositionTable>,
+    pub evaluator: &'a dyn Evaluator,
+}
+
+impl Default for ExpectiminimaxOptions<'_> {
+    fn default() -> Self {
+        ExpectiminimaxOptions {
+            ab_prune: true,
+            solver: SubGameSolver::Safest,
+            pruning: BranchPruning::default(),
+            transposition_table: None,
+            evaluator: &DefaultEvaluator,
+        }
+    }
+}
+
+impl ExpectiminimaxOptions<'_> {
+    // the options of the sub-games below the root, which are pruned whenever that is sound
+    fn sub_game(&mut self) -> ExpectiminimaxOptions<'_> {
+        ExpectiminimaxOptions {
+            ab_prune: self.solver == SubGameSolver::Safest,
+            solver: self.solver,
+            pruning: self.pruning,
+            transposition_table: self.transposition_table.as_deref_mut(),
+            evaluator: self.evaluator,
+        }
+    }
+}
 
 enum IterativeDeependingThreadMessage {
+    Depth((Vec<MoveChoice>, Vec<MoveChoice>, Vec<f32>, i8)),
     Stop((Vec<MoveChoice>, Vec<MoveChoice>, Vec<f32>, i8)),
 }
 
+/// Searches `depth` turns ahead and returns the score of every pair of root moves,
+/// row by row for each of side one's moves.
+/// Stops early when `control` is cancelled, and reports the scores so far after every row
 pub fn expectiminimax_search(
+    state: &mut State,
+    depth: i8,
+    side_one_options: Vec<MoveChoice>,
+    side_two_options: Vec<MoveChoice>,
+    mut options: ExpectiminimaxOptions,
+    control: &SearchControl,
+) -> Vec<f32> {
+    let solver = options.solver;
+    let num_s2_moves = side_two_options.len();
+    let on_row_finished = |scores: &[f32]| {
+        let finished_rows = scores.len() / num_s2_moves;
+        let (best_index, _) = solver.best_choice(scores, finished_rows, num_s2_moves);
+        control.report(SearchProgress::Row {
+            depth,
+            finished_rows,
+            best_move: side_one_options[best_index],
+            side_one_options: &side_one_options,
+            side_two_options: &side_two_options,
+            scores,
+        });
+    };
+    let on_row_finished: Option<OnRowFinished> = match control.on_progress {
+        Some(_) if num_s2_moves > 0 => Some(&on_row_finished),
+        _ => None,
+    };
+    expectiminimax(
+        state,
+        depth,
+        side_one_options.clone(),
+        side_two_options.clone(),
+        &mut options,
+        &control.cancellation,
+        on_row_finished,
+    )
+}
+
+// called with the scores so far whenever a row of the root matrix is finished
+type OnRowFinished<'a> = &'a dyn Fn(&[f32]);
+
+fn expectiminimax(
     state: &mut State,
     mut depth: i8,
     side_one_options: Vec<MoveChoice>,
     side_two_options: Vec<MoveChoice>,
-    ab_prune: bool,
-    mtx: &Arc<Mutex<bool>>,
+    options: &mut ExpectiminimaxOptions,
+    cancellation: &CancellationToken,
+    on_row_finished: Option<OnRowFinished>,
 ) -> Vec<f32> {
+    let solver = options.solver;
+    let evaluator = options.evaluator;
+    // pruning is only sound when the matrix is valued by its maximin
+    let ab_prune = options.ab_prune && solver == SubGameSolver::Safest;
     depth -= 1;
     let num_s1_moves = side_one_options.len();
     let num_s2_moves = side_two_options.len();
     let mut score_lookup: Vec<f32> = Vec::with_capacity(num_s1_moves * num_s2_moves);
 
-    if *mtx.lock().unwrap() == false {
+    if cancellation.is_cancelled() {
         for _ in 0..(num_s1_moves * num_s2_moves) {
             score_lookup.push(0.0);
         }
@@ -34,7 +213,8 @@ pub fn expectiminimax_search(
     let battle_is_over = state.battle_is_over();
     if battle_is_over != 0.0 {
         for _ in 0..(num_s1_moves * num_s2_moves) {
-            score_lookup.push(((100.0 * depth as f32) * battle_is_over) + evaluate(state));
+            score_lookup
+                .push(((100.0 * depth as f32) * battle_is_over) + evaluator.evaluate(state));
         }
         return score_lookup;
     }
@@ -52,35 +232,53 @@ pub fn expectiminimax_search(
             }
 
             let mut score = 0.0;
-            let instructions =
+            let mut instructions =
                 generate_instructions_from_move_pair(state, &side_one_move, &side_two_move, false);
+            options.pruning.prune(&mut instructions);
             if depth == 0 {
                 for instruction in instructions.iter() {
                     state.apply_instructions(&instruction.instruction_list);
-                    score += instruction.percentage * evaluate(state) / 100.0;
+                    score += instruction.percentage * evaluator.evaluate(state) / 100.0;
                     state.reverse_instructions(&instruction.instruction_list);
                 }
             } else {
                 for instruction in instructions.iter() {
                     state.apply_instructions(&instruction.instruction_list);
-                    let (next_turn_side_one_options, next_turn_side_two_options) =
-                        state.get_all_options();
-
-                    let next_turn_side_one_options_len = next_turn_side_one_options.len();
-                    let next_turn_side_two_options_len = next_turn_side_two_options.len();
-                    let (_, safest) = pick_safest(
-                        &expectiminimax_search(
-                            state,
-                            depth,
-                            next_turn_side_one_options,
-                            next_turn_side_two_options,
-                            true, // until there is something better than `pick_safest` for evaluating a sub-game, there is no point in this being anything other than `true`
-                            &mtx,
-                        ),
-                        next_turn_side_one_options_len,
-                        next_turn_side_two_options_len,
-                    );
-                    score += instruction.percentage * safest / 100.0;
+                    let cached_value = match options.transposition_table.as_deref() {
+                        Some(table) => table.get(state.zobrist_hash, depth),
+                        None => None,
+                    };
+                    let sub_game_value = match cached_value {
+                        Some(value) => value,
+                        None => {
+                            let (next_turn_side_one_options, next_turn_side_two_options) =
+                                state.get_all_options();
+
+                            let next_turn_side_one_options_len = next_turn_side_one_options.len();
+                            let next_turn_side_two_options_len = next_turn_side_two_options.len();
+                            let value = solver.sub_game_value(
+                                &expectiminimax(
+                                    state,
+                                    depth,
+                                    next_turn_side_one_options,
+                                    next_turn_side_two_options,
+                                    &mut options.sub_game(),
+                                    cancellation,
+                                    None,
+                                ),
+                                next_turn_side_one_options_len,
+                                next_turn_side_two_options_len,
+                            );
+                            // a search that was stopped early returns meaningless values
+                            if let Some(table) = options.transposition_table.as_deref_mut() {
+                                if !cancellation.is_cancelled() {
+                                    table.insert(state.zobrist_hash, depth, value);
+                                }
+                            }
+                            value
+                        }
+                    };
+                    score += instruction.percentage * sub_game_value / 100.0;
 
                     state.reverse_instructions(&instruction.instruction_list);
                 }
@@ -99,15 +297,16 @@ pub fn expectiminimax_search(
         if beta > alpha {
             alpha = beta;
         }
+        if let Some(on_row_finished) = on_row_finished {
+            if !cancellation.is_cancelled() {
+                on_row_finished(&score_lookup);
+            }
+        }
     }
     score_lookup
 }
 
-pub fn pick_safest(
-    score_lookup: &Vec<f32>,
-    num_s1_moves: usize,
-    num_s2_moves: usize,
-) -> (usize, f32) {
+pub fn pick_safest(score_lookup: &[f32], num_s1_moves: usize, num_s2_moves: usize) -> (usize, f32) {
     let mut best_worst_case = f32::MIN;
     let mut best_worst_case_s1_index = 0;
     let mut vec_index = 0;
@@ -163,73 +362,326 @@ pub fn iterative_deepen_expectiminimax(
     side_one_options: Vec<MoveChoice>,
     side_two_options: Vec<MoveChoice>,
     max_time: Duration,
+    options: ExpectiminimaxOptions,
+) -> (Vec<MoveChoice>, Vec<MoveChoice>, Vec<f32>, i8) {
+    iterative_deepen_expectiminimax_with_control(
+        state,
+        side_one_options,
+        side_two_options,
+        max_time,
+        options,
+        &SearchControl::default(),
+    )
+}
+
+fn report_depth(
+    control: &SearchControl,
+    solver: SubGameSolver,
+    (side_one_options, side_two_options, scores, depth): &(
+        Vec<MoveChoice>,
+        Vec<MoveChoice>,
+        Vec<f32>,
+        i8,
+    ),
+) {
+    if control.on_progress.is_none() {
+        return;
+    }
+    let (best_index, _) =
+        solver.best_choice(scores, side_one_options.len(), side_two_options.len());
+    control.report(SearchProgress::Depth {
+        depth: *depth,
+        best_move: side_one_options[best_index],
+        side_one_options,
+        side_two_options,
+        scores,
+    });
+}
+
+/// Iterative deepening that stops at `max_time` or when `control` is cancelled,
+/// whichever is first, and reports the result of every depth it finishes
+pub fn iterative_deepen_expectiminimax_with_control(
+    state: &mut State,
+    side_one_options: Vec<MoveChoice>,
+    side_two_options: Vec<MoveChoice>,
+    max_time: Duration,
+    mut options: ExpectiminimaxOptions,
+    control: &SearchControl,
 ) -> (Vec<MoveChoice>, Vec<MoveChoice>, Vec<f32>, i8) {
+    let deadline = Instant::now() + max_time;
+    let solver = options.solver;
+    state.update_zobrist_hash();
     let mut state_clone = state.clone();
 
-    let mut result = expectiminimax_search(
+    let mut result = expectiminimax(
         state,
         1,
         side_one_options.clone(),
         side_two_options.clone(),
-        true,
-        &Arc::new(Mutex::new(true)),
+        &mut options,
+        &CancellationToken::new(),
+        None,
     );
     let (mut re_ordered_s1_options, mut re_ordered_s2_options) =
         re_order_moves_for_iterative_deepening(&result, side_one_options, side_two_options);
+    report_depth(
+        control,
+        solver,
+        &(
+            re_ordered_s1_options.clone(),
+            re_ordered_s2_options.clone(),
+            result.clone(),
+            1,
+        ),
+    );
     let mut i = 1;
-    let running = Arc::new(Mutex::new(true));
-    let running_clone = Arc::clone(&running);
+    // kept separate from the caller's token so that running out of time does not cancel it
+    let running = CancellationToken::new();
+    let running_clone = running.clone();
 
     let (sender, receiver): (
         Sender<IterativeDeependingThreadMessage>,
         Receiver<IterativeDeependingThreadMessage>,
     ) = channel();
 
-    let handle = thread::spawn(move || {
-        let mut previous_turn_s1_options = re_ordered_s1_options.clone();
-        let mut previous_turn_s2_options = re_ordered_s2_options.clone();
+    // a scoped thread can borrow the evaluator
+    thread::scope(|scope| {
+        let handle = scope.spawn(move || {
+            // values found at one depth are re-used by every deeper search
+            let mut own_table = None;
+            let mut options = ExpectiminimaxOptions {
+                transposition_table: Some(match options.transposition_table {
+                    Some(table) => table,
+                    None => own_table.insert(TranspositionTable::new(TRANSPOSITION_TABLE_SIZE)),
+                }),
+                ..options
+            };
+            let mut previous_turn_s1_options = re_ordered_s1_options.clone();
+            let mut previous_turn_s2_options = re_ordered_s2_options.clone();
+            loop {
+                let previous_result = result;
+                i += 1;
+                result = expectiminimax(
+                    &mut state_clone,
+                    i,
+                    re_ordered_s1_options.clone(),
+                    re_ordered_s2_options.clone(),
+                    &mut options,
+                    &running_clone,
+                    None,
+                );
+
+                // when we are told to stop, return the *previous* result.
+                // the current result will be invalid
+                if running_clone.is_cancelled() {
+                    sender
+                        .send(IterativeDeependingThreadMessage::Stop((
+                            previous_turn_s1_options,
+                            previous_turn_s2_options,
+                            previous_result,
+                            i - 1,
+                        )))
+                        .unwrap();
+                    break;
+                }
+                // the receiver is gone once the search has stopped
+                let _ = sender.send(IterativeDeependingThreadMessage::Depth((
+                    re_ordered_s1_options.clone(),
+                    re_ordered_s2_options.clone(),
+                    result.clone(),
+                    i,
+                )));
+                previous_turn_s1_options = re_ordered_s1_options.clone();
+                previous_turn_s2_options = re_ordered_s2_options.clone();
+                (re_ordered_s1_options, re_ordered_s2_options) =
+                    re_order_moves_for_iterative_deepening(
+                        &result,
+                        re_ordered_s1_options,
+                        re_ordered_s2_options,
+                    );
+            }
+        });
+
         loop {
-            let previous_result = result;
-            i += 1;
-            result = expectiminimax_search(
-                &mut state_clone,
-                i,
-                re_ordered_s1_options.clone(),
-                re_ordered_s2_options.clone(),
-                true,
-                &running_clone,
-            );
-
-            // when we are told to stop, return the *previous* result.
-            // the current result will be invalid
-            if *running_clone.lock().unwrap() == false {
-                sender
-                    .send(IterativeDeependingThreadMessage::Stop((
-                        previous_turn_s1_options,
-                        previous_turn_s2_options,
-                        previous_result,
-                        i - 1,
-                    )))
-                    .unwrap();
+            let now = Instant::now();
+            if now >= deadline || control.cancellation.is_cancelled() {
                 break;
             }
-            previous_turn_s1_options = re_ordered_s1_options.clone();
-            previous_turn_s2_options = re_ordered_s2_options.clone();
-            (re_ordered_s1_options, re_ordered_s2_options) = re_order_moves_for_iterative_deepening(
-                &result,
-                re_ordered_s1_options,
-                re_ordered_s2_options,
-            );
+            match receiver.recv_timeout((deadline - now).min(CANCELLATION_POLL_INTERVAL)) {
+                Ok(IterativeDeependingThreadMessage::Depth(depth_result)) => {
+                    report_depth(control, solver, &depth_result)
+                }
+                Ok(IterativeDeependingThreadMessage::Stop(_)) => {
+                    panic!("Search stopped before it was told to")
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => panic!("Search thread stopped"),
+            }
         }
-    });
+        running.cancel();
+        loop {
+            match receiver.recv() {
+                Ok(IterativeDeependingThreadMessage::Stop(result)) => {
+                    handle.join().unwrap();
+                    return result;
+                }
+                // depths finished before the search thread saw it was stopped
+                Ok(IterativeDeependingThreadMessage::Depth(depth_result)) => {
+                    report_depth(control, solver, &depth_result)
+                }
+                Err(_) => panic!("Failed to receive stop message"),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::Choices;
+    use crate::evaluator::DefaultEvaluator;
+    use crate::instruction::PrunedBranches;
+    use crate::state::PokemonMoveIndex;
+    use std::cell::{Cell, RefCell};
+
+    #[test]
+    fn test_transposition_table_only_returns_values_searched_to_the_same_depth() {
+        let mut table = TranspositionTable::new(16);
+        table.insert(12345, 2, 1.5);
+        assert_eq!(None, table.get(12345, 1));
+        assert_eq!(Some(1.5), table.get(12345, 2));
+        assert_eq!(None, table.get(12345, 3));
+        assert_eq!(None, table.get(54321, 1));
+    }
+
+    #[test]
+    fn test_transposition_table_keeps_the_deeper_entry_for_the_same_state() {
+        let mut table = TranspositionTable::new(16);
+        table.insert(12345, 3, 1.5);
+        table.insert(12345, 1, 2.5);
+        assert_eq!(Some(1.5), table.get(12345, 3));
+        assert_eq!(None, table.get(12345, 1));
 
-    thread::sleep(max_time);
-    *running.lock().unwrap() = false;
-    match receiver.recv() {
-        Ok(IterativeDeependingThreadMessage::Stop(result)) => {
-            handle.join().unwrap();
-            result
+        table.insert(12345, 4, 3.5);
+        assert_eq!(Some(3.5), table.get(12345, 4));
+    }
+
+    #[test]
+    fn test_transposition_table_replaces_colliding_entries() {
+        let mut table = TranspositionTable::new(16);
+        table.insert(1, 3, 1.5);
+        table.insert(17, 1, 2.5);
+        assert_eq!(None, table.get(1, 1));
+        assert_eq!(Some(2.5), table.get(17, 1));
+    }
+
+    #[test]
+    fn test_cancelling_iterative_deepening_returns_the_last_finished_depth() {
+        let mut state = State::default();
+        let (s1_options, s2_options) = state.root_get_all_options();
+        let cancellation = CancellationToken::new();
+        let deepest_reported = Cell::new(0);
+        let on_progress = |progress: &SearchProgress| {
+            if let SearchProgress::Depth { depth, .. } = progress {
+                deepest_reported.set(*depth);
+                if *depth >= 2 {
+                    cancellation.cancel();
+                }
+            }
+        };
+        let control = SearchControl {
+            cancellation: cancellation.clone(),
+            on_progress: Some(&on_progress),
+            ..SearchControl::default()
+        };
+
+        let start = Instant::now();
+        let (_, _, _, depth) = iterative_deepen_expectiminimax_with_control(
+            &mut state,
+            s1_options,
+            s2_options,
+            Duration::from_secs(60),
+            ExpectiminimaxOptions::default(),
+            &control,
+        );
+
+        assert!(start.elapsed() < Duration::from_secs(30));
+        assert_eq!(deepest_reported.get(), depth);
+    }
+
+    #[test]
+    fn test_expectiminimax_reports_every_finished_root_row() {
+        let mut state = State::default();
+        let (s1_options, s2_options) = state.root_get_all_options();
+        let reported_rows = RefCell::new(vec![]);
+        let on_progress = |progress: &SearchProgress| {
+            if let SearchProgress::Row {
+                finished_rows,
+                scores,
+                side_two_options,
+                ..
+            } = progress
+            {
+                assert_eq!(finished_rows * side_two_options.len(), scores.len());
+                reported_rows.borrow_mut().push(*finished_rows);
+            }
+        };
+        let control = SearchControl {
+            on_progress: Some(&on_progress),
+            ..SearchControl::default()
+        };
+
+        let result = expectiminimax_search(
+            &mut state,
+            2,
+            s1_options.clone(),
+            s2_options.clone(),
+            ExpectiminimaxOptions {
+                ab_prune: false,
+                ..Default::default()
+            },
+            &control,
+        );
+
+        assert_eq!(s1_options.len() * s2_options.len(), result.len());
+        let expected: Vec<usize> = (1..=s1_options.len()).collect();
+        assert_eq!(expected, *reported_rows.borrow());
+    }
+
+    #[test]
+    fn test_expectiminimax_only_searches_branches_that_survive_pruning() {
+        let mut state = State::default();
+        for side in [&mut state.side_one, &mut state.side_two] {
+            side.get_active()
+                .replace_move(PokemonMoveIndex::M0, Choices::TACKLE, state.generation);
         }
-        _ => panic!("Failed to receive stop message"),
+        let tackle = MoveChoice::Move(PokemonMoveIndex::M0);
+        let pruning = BranchPruning {
+            min_percentage: 10.0,
+            pruned_branches: PrunedBranches::Renormalize,
+        };
+
+        let mut instructions =
+            generate_instructions_from_move_pair(&mut state, &tackle, &tackle, false);
+        pruning.prune(&mut instructions);
+        let mut expected = 0.0;
+        for instruction in instructions.iter() {
+            state.apply_instructions(&instruction.instruction_list);
+            expected += instruction.percentage * DefaultEvaluator.evaluate(&state) / 100.0;
+            state.reverse_instructions(&instruction.instruction_list);
+        }
+
+        let result = expectiminimax_search(
+            &mut state,
+            1,
+            vec![tackle],
+            vec![tackle],
+            ExpectiminimaxOptions {
+                pruning,
+                ..Default::default()
+            },
+            &SearchControl::default(),
+        );
+        assert!((expected - result[0]).abs() < 1e-3);
     }
 }
@@ -0,0 +1,283 @@
+use crate::engine::generate_instructions::generate_instructions_from_move_pair;
+use crate::engine::state::MoveChoice;
+use crate::instruction::StateInstructions;
+use crate::state::{SideReference, State};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Picks a move for one side of a simulated battle
+pub trait Policy {
+    /// `options` are the legal moves for `side_ref` and always has at least one element
+    fn choose(
+        &mut self,
+        state: &State,
+        side_ref: &SideReference,
+        options: &[MoveChoice],
+        rng: &mut StdRng,
+    ) -> MoveChoice;
+}
+
+impl<F> Policy for F
+where
+    F: FnMut(&State, &SideReference, &[MoveChoice], &mut StdRng) -> MoveChoice,
+{
+    fn choose(
+        &mut self,
+        state: &State,
+        side_ref: &SideReference,
+        options: &[MoveChoice],
+        rng: &mut StdRng,
+    ) -> MoveChoice {
+        self(state, side_ref, options, rng)
+    }
+}
+
+/// Picks uniformly at random from the legal moves
+pub struct RandomPolicy;
+
+impl Policy for RandomPolicy {
+    fn choose(
+        &mut self,
+        _state: &State,
+        _side_ref: &SideReference,
+        options: &[MoveChoice],
+        rng: &mut StdRng,
+    ) -> MoveChoice {
+        options[rng.random_range(0..options.len())]
+    }
+}
+
+/// One turn of a simulated battle: the moves both sides chose
+/// and the outcome that was sampled and applied to the state
+#[derive(Debug, Clone)]
+pub struct SimulatedTurn {
+    pub s1_choice: MoveChoice,
+    pub s2_choice: MoveChoice,
+    pub instructions: StateInstructions,
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulationResult {
+    pub turns: Vec<SimulatedTurn>,
+    /// `None` if `max_turns` was reached before either side won
+    pub winner: Option<SideReference>,
+}
+
+/// Picks one of `instructions` with a probability proportional to its percentage
+pub fn sample_instructions(instructions: &[StateInstructions], rng: &mut impl Rng) -> usize {
+    let total_weight: f32 = instructions.iter().map(|i| i.percentage.max(0.0)).sum();
+    if total_weight <= 0.0 {
+        return 0;
+    }
+    // the threshold is in [0, total_weight), so an outcome is picked once the threshold
+    // falls below zero. Outcomes that cannot happen are skipped even if it is exactly zero
+    let mut threshold = rng.random_range(0.0..total_weight);
+    for (index, state_instructions) in instructions.iter().enumerate() {
+        if state_instructions.percentage <= 0.0 {
+            continue;
+        }
+        threshold -= state_instructions.percentage;
+        if threshold < 0.0 {
+            return index;
+        }
+    }
+
+    // float rounding can leave a tiny bit of the threshold
+    instructions
+        .iter()
+        .rposition(|i| i.percentage > 0.0)
+        .unwrap_or(0)
+}
+
+/// Plays the battle in `state` until one side has no pokemon left or `max_turns` turns
+/// have been played. Each turn both policies pick a move, one of the possible outcomes
+/// is sampled by its percentage and applied to `state`.
+///
+/// Forced switches are turns like any other: the options given to the policies are
+/// only switches. The same seed and policies always play out the same battle.
+///
+/// `state` is left at the end of the battle. Reversing the instructions of every turn
+/// in reverse order restores it, except for `team_preview` which is cleared after the first turn
+pub fn simulate(
+    state: &mut State,
+    side_one_policy: &mut dyn Policy,
+    side_two_policy: &mut dyn Policy,
+    seed: u64,
+    max_turns: usize,
+) -> SimulationResult {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut turns = Vec::new();
+
+    while state.battle_is_over() == 0.0 && turns.len() < max_turns {
+        let (s1_options, s2_options) = state.root_get_all_options();
+        let s1_choice =
+            side_one_policy.choose(state, &SideReference::SideOne, &s1_options, &mut rng);
+        let s2_choice =
+            side_two_policy.choose(state, &SideReference::SideTwo, &s2_options, &mut rng);
+
+        let mut instructions =
+            generate_instructions_from_move_pair(state, &s1_choice, &s2_choice, true);
+        let index = sample_instructions(&instructions, &mut rng);
+        let chosen = instructions.swap_remove(index);
+        state.apply_instructions(&chosen.instruction_list);
+        state.team_preview = false;
+
+        turns.push(SimulatedTurn {
+            s1_choice,
+            s2_choice,
+            instructions: chosen,
+        });
+    }
+
+    let winner = match state.battle_is_over() {
+        x if x > 0.0 => Some(SideReference::SideOne),
+        x if x < 0.0 => Some(SideReference::SideTwo),
+        _ => None,
+    };
+    SimulationResult { turns, winner }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choices::Choices;
+    use crate::state::{PokemonIndex, PokemonMoveIndex};
+
+    fn state_where_every_pokemon_knows_tackle() -> State {
+        let mut state = State::default();
+        for side in [&mut state.side_one, &mut state.side_two] {
+            for index in [
+                PokemonIndex::P0,
+                PokemonIndex::P1,
+                PokemonIndex::P2,
+                PokemonIndex::P3,
+                PokemonIndex::P4,
+                PokemonIndex::P5,
+            ] {
+                side.pokemon[index].replace_move(
+                    PokemonMoveIndex::M0,
+                    Choices::TACKLE,
+                    state.generation,
+                );
+            }
+        }
+        state
+    }
+
+    fn always_attack(
+        _state: &State,
+        _side_ref: &SideReference,
+        options: &[MoveChoice],
+        _rng: &mut StdRng,
+    ) -> MoveChoice {
+        match options.iter().find(|o| matches!(o, MoveChoice::Move(_))) {
+            Some(choice) => *choice,
+            None => options[0],
+        }
+    }
+
+    #[test]
+    fn test_simulation_plays_until_one_side_wins() {
+        let mut state = state_where_every_pokemon_knows_tackle();
+        let result = simulate(&mut state, &mut always_attack, &mut always_attack, 1, 1000);
+
+        assert!(result.winner.is_some());
+        assert_ne!(0.0, state.battle_is_over());
+    }
+
+    #[test]
+    fn test_same_seed_plays_the_same_battle() {
+        let mut first_state = state_where_every_pokemon_knows_tackle();
+        let mut second_state = state_where_every_pokemon_knows_tackle();
+        let first = simulate(
+            &mut first_state,
+            &mut RandomPolicy,
+            &mut RandomPolicy,
+            42,
+            200,
+        );
+        let second = simulate(
+            &mut second_state,
+            &mut RandomPolicy,
+            &mut RandomPolicy,
+            42,
+            200,
+        );
+
+        assert_eq!(first.turns.len(), second.turns.len());
+        for (a, b) in first.turns.iter().zip(second.turns.iter()) {
+            assert_eq!(a.s1_choice, b.s1_choice);
+            assert_eq!(a.s2_choice, b.s2_choice);
+            assert_eq!(a.instructions, b.instructions);
+        }
+        assert_eq!(first_state.serialize(), second_state.serialize());
+    }
+
+    #[test]
+    fn test_reversing_every_turn_restores_the_state() {
+        let mut state = state_where_every_pokemon_knows_tackle();
+        let original = state.serialize();
+        let result = simulate(&mut state, &mut RandomPolicy, &mut RandomPolicy, 7, 50);
+
+        for turn in result.turns.iter().rev() {
+            state.reverse_instructions(&turn.instructions.instruction_list);
+        }
+        assert_eq!(original, state.serialize());
+    }
+
+    #[test]
+    fn test_sample_instructions_never_picks_zero_percent() {
+        let instructions = vec![
+            StateInstructions {
+                percentage: 0.0,
+                instruction_list: vec![],
+            },
+            StateInstructions {
+                percentage: 100.0,
+                instruction_list: vec![],
+            },
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            assert_eq!(1, sample_instructions(&instructions, &mut rng));
+        }
+        // the smallest threshold that can be drawn
+        assert_eq!(1, sample_instructions(&instructions, &mut ZeroRng));
+    }
+
+    #[test]
+    fn test_sample_instructions_never_picks_zero_percent_after_the_last_outcome() {
+        let instructions = vec![
+            StateInstructions {
+                percentage: 100.0,
+                instruction_list: vec![],
+            },
+            StateInstructions {
+                percentage: 0.0,
+                instruction_list: vec![],
+            },
+        ];
+        assert_eq!(0, sample_instructions(&instructions, &mut ZeroRng));
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            assert_eq!(0, sample_instructions(&instructions, &mut rng));
+        }
+    }
+
+    // an rng whose every draw is zero, so `random_range` returns the start of its range
+    struct ZeroRng;
+
+    impl rand::RngCore for ZeroRng {
+        fn next_u32(&mut self) -> u32 {
+            0
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            0
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            dst.fill(0);
+        }
+    }
+}
@@ -1,14 +1,89 @@
-use crate::choices::{Choice, Choices, MoveCategory, MOVES};
+use crate::choices::{moves, Choice, Choices, MoveCategory, MOVES};
 use crate::define_enum_with_from_str;
 use crate::engine::abilities::Abilities;
 use crate::engine::items::Items;
 use crate::engine::state::{PokemonVolatileStatus, Terrain, Weather};
 use crate::instruction::{BoostInstruction, EnableMoveInstruction, Instruction};
 use crate::pokemon::PokemonName;
+use std::fmt;
 use std::ops::{Index, IndexMut};
 use std::str::FromStr;
 
+/// Error returned by the `try_deserialize` functions when a serialized state cannot be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateParseError {
+    /// the side that was being parsed, `None` for fields belonging to the state itself
+    pub side: Option<SideReference>,
+    /// the index of the pokemon on that side that was being parsed, if any
+    pub pokemon_index: Option<usize>,
+    /// index of the field that could not be parsed.
+    /// Counted within the pokemon, side or state that was being parsed
+    pub field_index: usize,
+    /// the text of the field that could not be parsed
+    pub token: String,
+    pub message: String,
+}
+
+impl StateParseError {
+    fn new<T: fmt::Display>(field_index: usize, token: &str, message: T) -> StateParseError {
+        StateParseError {
+            side: None,
+            pokemon_index: None,
+            field_index,
+            token: token.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for StateParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(side) = self.side {
+            write!(f, "{:?} ", side)?;
+        }
+        if let Some(pokemon_index) = self.pokemon_index {
+            write!(f, "pokemon {} ", pokemon_index)?;
+        }
+        write!(
+            f,
+            "field {} \"{}\": {}",
+            self.field_index, self.token, self.message
+        )
+    }
+}
+
+impl std::error::Error for StateParseError {}
+
+fn get_field<'a>(split: &[&'a str], index: usize) -> Result<&'a str, StateParseError> {
+    match split.get(index) {
+        Some(token) => Ok(token),
+        None => Err(StateParseError::new(index, "", "missing field")),
+    }
+}
+
+fn parse_field<T>(split: &[&str], index: usize) -> Result<T, StateParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let token = get_field(split, index)?;
+    token
+        .parse::<T>()
+        .map_err(|e| StateParseError::new(index, token, e))
+}
+
+// parses a field that has its own serialized format, e.g. the side conditions of a side
+fn parse_nested_field<T>(
+    split: &[&str],
+    index: usize,
+    try_deserialize: fn(&str) -> Result<T, StateParseError>,
+) -> Result<T, StateParseError> {
+    let token = get_field(split, index)?;
+    try_deserialize(token).map_err(|e| StateParseError::new(index, token, e))
+}
+
 #[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SideReference {
     SideOne,
     SideTwo,
@@ -23,6 +98,7 @@ impl SideReference {
 }
 
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PokemonSideCondition {
     AuroraVeil,
     CraftyShield,
@@ -46,6 +122,7 @@ pub enum PokemonSideCondition {
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LastUsedMove {
     Move(PokemonMoveIndex),
     Switch(PokemonIndex),
@@ -60,22 +137,34 @@ impl LastUsedMove {
         }
     }
     pub fn deserialize(serialized: &str) -> LastUsedMove {
+        LastUsedMove::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_deserialize(serialized: &str) -> Result<LastUsedMove, StateParseError> {
         let split: Vec<&str> = serialized.split(":").collect();
         match split[0] {
             "move" => {
-                if split[1] == "none" {
-                    LastUsedMove::None
+                if get_field(&split, 1)? == "none" {
+                    Ok(LastUsedMove::None)
                 } else {
-                    LastUsedMove::Move(PokemonMoveIndex::deserialize(split[1]))
+                    Ok(LastUsedMove::Move(parse_nested_field(
+                        &split,
+                        1,
+                        PokemonMoveIndex::try_deserialize,
+                    )?))
                 }
             }
-            "switch" => LastUsedMove::Switch(PokemonIndex::deserialize(split[1])),
-            _ => panic!("Invalid LastUsedMove: {}", serialized),
+            "switch" => Ok(LastUsedMove::Switch(parse_nested_field(
+                &split,
+                1,
+                PokemonIndex::try_deserialize,
+            )?)),
+            _ => Err(StateParseError::new(0, split[0], "Invalid LastUsedMove")),
         }
     }
 }
 
 #[derive(Debug, Copy, PartialEq, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PokemonMoveIndex {
     M0,
     M1,
@@ -92,17 +181,25 @@ impl PokemonMoveIndex {
         }
     }
     pub fn deserialize(serialized: &str) -> PokemonMoveIndex {
+        PokemonMoveIndex::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_deserialize(serialized: &str) -> Result<PokemonMoveIndex, StateParseError> {
         match serialized {
-            "0" => PokemonMoveIndex::M0,
-            "1" => PokemonMoveIndex::M1,
-            "2" => PokemonMoveIndex::M2,
-            "3" => PokemonMoveIndex::M3,
-            _ => panic!("Invalid PokemonMoveIndex: {}", serialized),
+            "0" => Ok(PokemonMoveIndex::M0),
+            "1" => Ok(PokemonMoveIndex::M1),
+            "2" => Ok(PokemonMoveIndex::M2),
+            "3" => Ok(PokemonMoveIndex::M3),
+            _ => Err(StateParseError::new(
+                0,
+                serialized,
+                "Invalid PokemonMoveIndex",
+            )),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PokemonBoostableStat {
     Attack,
     Defense,
@@ -169,6 +266,49 @@ define_enum_with_from_str! {
     }
 }
 
+impl PokemonNature {
+    /// The stat raised and the stat lowered by this nature, or `None` for a neutral nature
+    pub fn stat_changes(&self) -> Option<(PokemonBoostableStat, PokemonBoostableStat)> {
+        use PokemonBoostableStat::{Attack, Defense, SpecialAttack, SpecialDefense, Speed};
+        match self {
+            PokemonNature::LONELY => Some((Attack, Defense)),
+            PokemonNature::ADAMANT => Some((Attack, SpecialAttack)),
+            PokemonNature::NAUGHTY => Some((Attack, SpecialDefense)),
+            PokemonNature::BRAVE => Some((Attack, Speed)),
+            PokemonNature::BOLD => Some((Defense, Attack)),
+            PokemonNature::IMPISH => Some((Defense, SpecialAttack)),
+            PokemonNature::LAX => Some((Defense, SpecialDefense)),
+            PokemonNature::RELAXED => Some((Defense, Speed)),
+            PokemonNature::MODEST => Some((SpecialAttack, Attack)),
+            PokemonNature::MILD => Some((SpecialAttack, Defense)),
+            PokemonNature::RASH => Some((SpecialAttack, SpecialDefense)),
+            PokemonNature::QUIET => Some((SpecialAttack, Speed)),
+            PokemonNature::CALM => Some((SpecialDefense, Attack)),
+            PokemonNature::GENTLE => Some((SpecialDefense, Defense)),
+            PokemonNature::CAREFUL => Some((SpecialDefense, SpecialAttack)),
+            PokemonNature::SASSY => Some((SpecialDefense, Speed)),
+            PokemonNature::TIMID => Some((Speed, Attack)),
+            PokemonNature::HASTY => Some((Speed, Defense)),
+            PokemonNature::JOLLY => Some((Speed, SpecialAttack)),
+            PokemonNature::NAIVE => Some((Speed, SpecialDefense)),
+            PokemonNature::HARDY
+            | PokemonNature::DOCILE
+            | PokemonNature::BASHFUL
+            | PokemonNature::QUIRKY
+            | PokemonNature::SERIOUS => None,
+        }
+    }
+
+    /// Applies this nature's 10% change to `stat_value`, if it changes `stat`
+    pub fn modify_stat(&self, stat: PokemonBoostableStat, stat_value: i16) -> i16 {
+        match self.stat_changes() {
+            Some((raised, _)) if raised == stat => stat_value * 11 / 10,
+            Some((_, lowered)) if lowered == stat => stat_value * 9 / 10,
+            _ => stat_value,
+        }
+    }
+}
+
 impl PokemonVolatileStatus {
     #[inline(always)]
     pub fn bit(self) -> u128 {
@@ -181,6 +321,7 @@ impl PokemonVolatileStatus {
 }
 
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VolatileStatusBitset(pub u128);
 
 impl VolatileStatusBitset {
@@ -255,6 +396,38 @@ define_enum_with_from_str! {
     default = TYPELESS
 }
 
+define_enum_with_from_str! {
+    #[repr(u8)]
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+    Generation {
+        GEN1,
+        GEN2,
+        GEN3,
+        GEN4,
+        GEN5,
+        GEN6,
+        GEN7,
+        GEN8,
+        GEN9,
+    }
+}
+
+/// The generation a state uses unless another one is chosen
+pub const DEFAULT_GENERATION: Generation = Generation::GEN4;
+
+impl Default for Generation {
+    fn default() -> Generation {
+        DEFAULT_GENERATION
+    }
+}
+
+impl Generation {
+    /// Whether pokemon can terastallize in this generation
+    pub fn has_terastallization(&self) -> bool {
+        *self == Generation::GEN9
+    }
+}
+
 impl Index<&PokemonMoveIndex> for PokemonMoves {
     type Output = Move;
 
@@ -329,6 +502,7 @@ impl<'a> IntoIterator for &'a PokemonMoves {
 }
 
 #[derive(Debug, Copy, PartialEq, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum PokemonIndex {
     P0,
@@ -350,14 +524,17 @@ impl PokemonIndex {
         }
     }
     pub fn deserialize(serialized: &str) -> PokemonIndex {
+        PokemonIndex::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_deserialize(serialized: &str) -> Result<PokemonIndex, StateParseError> {
         match serialized {
-            "0" => PokemonIndex::P0,
-            "1" => PokemonIndex::P1,
-            "2" => PokemonIndex::P2,
-            "3" => PokemonIndex::P3,
-            "4" => PokemonIndex::P4,
-            "5" => PokemonIndex::P5,
-            _ => panic!("Invalid PokemonIndex: {}", serialized),
+            "0" => Ok(PokemonIndex::P0),
+            "1" => Ok(PokemonIndex::P1),
+            "2" => Ok(PokemonIndex::P2),
+            "3" => Ok(PokemonIndex::P3),
+            "4" => Ok(PokemonIndex::P4),
+            "5" => Ok(PokemonIndex::P5),
+            _ => Err(StateParseError::new(0, serialized, "Invalid PokemonIndex")),
         }
     }
 }
@@ -405,6 +582,7 @@ impl Iterator for PokemonIndexIterator {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SidePokemon {
     pub pkmn: [Pokemon; 6],
 }
@@ -532,27 +710,57 @@ impl Default for Side {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "SerializedMove"))]
 pub struct Move {
     pub id: Choices,
     pub disabled: bool,
     pub pp: i8,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     pub choice: Choice,
 }
+
+// only the id is stored when serializing a Move, the choice is loaded from the move data
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerializedMove {
+    id: Choices,
+    disabled: bool,
+    pp: i8,
+}
+
+#[cfg(feature = "serde")]
+impl From<SerializedMove> for Move {
+    fn from(serialized: SerializedMove) -> Move {
+        Move {
+            id: serialized.id,
+            disabled: serialized.disabled,
+            pp: serialized.pp,
+            choice: MOVES.get(&serialized.id).unwrap().to_owned(),
+        }
+    }
+}
+
 impl Move {
     pub fn serialize(&self) -> String {
         format!("{:?};{};{}", self.id, self.disabled, self.pp)
     }
     pub fn deserialize(serialized: &str) -> Move {
+        Move::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_deserialize(serialized: &str) -> Result<Move, StateParseError> {
         let split: Vec<&str> = serialized.split(";").collect();
-        Move {
-            id: Choices::from_str(split[0]).unwrap(),
-            disabled: split[1].parse::<bool>().unwrap(),
-            pp: split[2].parse::<i8>().unwrap(),
-            choice: MOVES
-                .get(&Choices::from_str(split[0]).unwrap())
-                .unwrap()
-                .to_owned(),
-        }
+        let id = parse_field::<Choices>(&split, 0)?;
+        let choice = match MOVES.get(&id) {
+            Some(choice) => choice.to_owned(),
+            None => return Err(StateParseError::new(0, split[0], "no move data")),
+        };
+        Ok(Move {
+            id,
+            disabled: parse_field(&split, 1)?,
+            pp: parse_field(&split, 2)?,
+            choice,
+        })
     }
 }
 impl Default for Move {
@@ -567,6 +775,7 @@ impl Default for Move {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DamageDealt {
     pub damage: i16,
     pub move_category: MoveCategory,
@@ -584,6 +793,7 @@ impl Default for DamageDealt {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PokemonMoves {
     pub m0: Move,
     pub m1: Move,
@@ -592,6 +802,7 @@ pub struct PokemonMoves {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SideConditions {
     pub aurora_veil: i8,
     pub crafty_shield: i8,
@@ -673,28 +884,31 @@ impl SideConditions {
         )
     }
     pub fn deserialize(serialized: &str) -> SideConditions {
+        SideConditions::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_deserialize(serialized: &str) -> Result<SideConditions, StateParseError> {
         let split: Vec<&str> = serialized.split(";").collect();
-        SideConditions {
-            aurora_veil: split[0].parse::<i8>().unwrap(),
-            crafty_shield: split[1].parse::<i8>().unwrap(),
-            healing_wish: split[2].parse::<i8>().unwrap(),
-            light_screen: split[3].parse::<i8>().unwrap(),
-            lucky_chant: split[4].parse::<i8>().unwrap(),
-            lunar_dance: split[5].parse::<i8>().unwrap(),
-            mat_block: split[6].parse::<i8>().unwrap(),
-            mist: split[7].parse::<i8>().unwrap(),
-            protect: split[8].parse::<i8>().unwrap(),
-            quick_guard: split[9].parse::<i8>().unwrap(),
-            reflect: split[10].parse::<i8>().unwrap(),
-            safeguard: split[11].parse::<i8>().unwrap(),
-            spikes: split[12].parse::<i8>().unwrap(),
-            stealth_rock: split[13].parse::<i8>().unwrap(),
-            sticky_web: split[14].parse::<i8>().unwrap(),
-            tailwind: split[15].parse::<i8>().unwrap(),
-            toxic_count: split[16].parse::<i8>().unwrap(),
-            toxic_spikes: split[17].parse::<i8>().unwrap(),
-            wide_guard: split[18].parse::<i8>().unwrap(),
-        }
+        Ok(SideConditions {
+            aurora_veil: parse_field(&split, 0)?,
+            crafty_shield: parse_field(&split, 1)?,
+            healing_wish: parse_field(&split, 2)?,
+            light_screen: parse_field(&split, 3)?,
+            lucky_chant: parse_field(&split, 4)?,
+            lunar_dance: parse_field(&split, 5)?,
+            mat_block: parse_field(&split, 6)?,
+            mist: parse_field(&split, 7)?,
+            protect: parse_field(&split, 8)?,
+            quick_guard: parse_field(&split, 9)?,
+            reflect: parse_field(&split, 10)?,
+            safeguard: parse_field(&split, 11)?,
+            spikes: parse_field(&split, 12)?,
+            stealth_rock: parse_field(&split, 13)?,
+            sticky_web: parse_field(&split, 14)?,
+            tailwind: parse_field(&split, 15)?,
+            toxic_count: parse_field(&split, 16)?,
+            toxic_spikes: parse_field(&split, 17)?,
+            wide_guard: parse_field(&split, 18)?,
+        })
     }
 }
 impl Default for SideConditions {
@@ -724,6 +938,7 @@ impl Default for SideConditions {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateWeather {
     pub weather_type: Weather,
     pub turns_remaining: i8,
@@ -733,15 +948,19 @@ impl StateWeather {
         format!("{:?};{}", self.weather_type, self.turns_remaining)
     }
     pub fn deserialize(serialized: &str) -> StateWeather {
+        StateWeather::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_deserialize(serialized: &str) -> Result<StateWeather, StateParseError> {
         let split: Vec<&str> = serialized.split(";").collect();
-        StateWeather {
-            weather_type: Weather::from_str(split[0]).unwrap(),
-            turns_remaining: split[1].parse::<i8>().unwrap(),
-        }
+        Ok(StateWeather {
+            weather_type: parse_field(&split, 0)?,
+            turns_remaining: parse_field(&split, 1)?,
+        })
     }
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateTerrain {
     pub terrain_type: Terrain,
     pub turns_remaining: i8,
@@ -751,15 +970,19 @@ impl StateTerrain {
         format!("{:?};{}", self.terrain_type, self.turns_remaining)
     }
     pub fn deserialize(serialized: &str) -> StateTerrain {
+        StateTerrain::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_deserialize(serialized: &str) -> Result<StateTerrain, StateParseError> {
         let split: Vec<&str> = serialized.split(";").collect();
-        StateTerrain {
-            terrain_type: Terrain::from_str(split[0]).unwrap(),
-            turns_remaining: split[1].parse::<i8>().unwrap(),
-        }
+        Ok(StateTerrain {
+            terrain_type: parse_field(&split, 0)?,
+            turns_remaining: parse_field(&split, 1)?,
+        })
     }
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateTrickRoom {
     pub active: bool,
     pub turns_remaining: i8,
@@ -769,15 +992,19 @@ impl StateTrickRoom {
         format!("{};{}", self.active, self.turns_remaining)
     }
     pub fn deserialize(serialized: &str) -> StateTrickRoom {
+        StateTrickRoom::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_deserialize(serialized: &str) -> Result<StateTrickRoom, StateParseError> {
         let split: Vec<&str> = serialized.split(";").collect();
-        StateTrickRoom {
-            active: split[0].parse::<bool>().unwrap(),
-            turns_remaining: split[1].parse::<i8>().unwrap(),
-        }
+        Ok(StateTrickRoom {
+            active: parse_field(&split, 0)?,
+            turns_remaining: parse_field(&split, 1)?,
+        })
     }
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VolatileStatusDurations {
     pub confusion: i8,
     pub encore: i8,
@@ -830,19 +1057,51 @@ impl VolatileStatusDurations {
         )
     }
     pub fn deserialize(serialized: &str) -> VolatileStatusDurations {
+        VolatileStatusDurations::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_deserialize(serialized: &str) -> Result<VolatileStatusDurations, StateParseError> {
         let split: Vec<&str> = serialized.split(";").collect();
-        VolatileStatusDurations {
-            confusion: split[0].parse::<i8>().unwrap(),
-            encore: split[1].parse::<i8>().unwrap(),
-            lockedmove: split[2].parse::<i8>().unwrap(),
-            slowstart: split[3].parse::<i8>().unwrap(),
-            taunt: split[4].parse::<i8>().unwrap(),
-            yawn: split[5].parse::<i8>().unwrap(),
-        }
+        Ok(VolatileStatusDurations {
+            confusion: parse_field(&split, 0)?,
+            encore: parse_field(&split, 1)?,
+            lockedmove: parse_field(&split, 2)?,
+            slowstart: parse_field(&split, 3)?,
+            taunt: parse_field(&split, 4)?,
+            yawn: parse_field(&split, 5)?,
+        })
     }
 }
 
+// an empty string means the default evs
+fn deserialize_evs(serialized: &str) -> Result<(u8, u8, u8, u8, u8, u8), StateParseError> {
+    deserialize_stat_spread(serialized, 85)
+}
+
+// an empty string means 31 in every stat
+fn deserialize_ivs(serialized: &str) -> Result<(u8, u8, u8, u8, u8, u8), StateParseError> {
+    deserialize_stat_spread(serialized, 31)
+}
+
+fn deserialize_stat_spread(
+    serialized: &str,
+    default: u8,
+) -> Result<(u8, u8, u8, u8, u8, u8), StateParseError> {
+    if serialized == "" {
+        return Ok((default, default, default, default, default, default));
+    }
+    let split: Vec<&str> = serialized.split(";").collect();
+    Ok((
+        parse_field(&split, 0)?,
+        parse_field(&split, 1)?,
+        parse_field(&split, 2)?,
+        parse_field(&split, 3)?,
+        parse_field(&split, 4)?,
+        parse_field(&split, 5)?,
+    ))
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pokemon {
     pub id: PokemonName,
     pub level: i8,
@@ -855,6 +1114,7 @@ pub struct Pokemon {
     pub item: Items,
     pub nature: PokemonNature,
     pub evs: (u8, u8, u8, u8, u8, u8),
+    pub ivs: (u8, u8, u8, u8, u8, u8),
     pub attack: i16,
     pub defense: i16,
     pub special_attack: i16,
@@ -883,6 +1143,7 @@ impl Default for Pokemon {
             item: Items::NONE,
             nature: PokemonNature::SERIOUS,
             evs: (85, 85, 85, 85, 85, 85),
+            ivs: (31, 31, 31, 31, 31, 31),
             attack: 100,
             defense: 100,
             special_attack: 100,
@@ -905,8 +1166,13 @@ impl Default for Pokemon {
 }
 
 impl Pokemon {
-    pub fn replace_move(&mut self, move_index: PokemonMoveIndex, new_move_name: Choices) {
-        self.moves[&move_index].choice = MOVES.get(&new_move_name).unwrap().to_owned();
+    pub fn replace_move(
+        &mut self,
+        move_index: PokemonMoveIndex,
+        new_move_name: Choices,
+        generation: Generation,
+    ) {
+        self.moves[&move_index].choice = moves(generation).get(&new_move_name).unwrap().to_owned();
         self.moves[&move_index].id = new_move_name;
     }
     pub fn get_sleep_talk_choices(&self) -> Vec<Choice> {
@@ -955,8 +1221,12 @@ impl Pokemon {
             "{};{};{};{};{};{}",
             self.evs.0, self.evs.1, self.evs.2, self.evs.3, self.evs.4, self.evs.5
         );
+        let ivs_str = format!(
+            "{};{};{};{};{};{}",
+            self.ivs.0, self.ivs.1, self.ivs.2, self.ivs.3, self.ivs.4, self.ivs.5
+        );
         format!(
-            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
             self.id,
             self.level,
             self.types.0.to_string(),
@@ -970,6 +1240,7 @@ impl Pokemon {
             self.item.to_string(),
             self.nature.to_string(),
             evs_str,
+            ivs_str,
             self.attack,
             self.defense,
             self.special_attack,
@@ -989,60 +1260,46 @@ impl Pokemon {
     }
 
     pub fn deserialize(serialized: &str) -> Pokemon {
+        Pokemon::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_deserialize(serialized: &str) -> Result<Pokemon, StateParseError> {
         let split: Vec<&str> = serialized.split(",").collect();
-        let evs = if split[12] != "" {
-            let mut ev_iter = split[12].split(";");
-            (
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-                ev_iter.next().unwrap().parse::<u8>().unwrap(),
-            )
-        } else {
-            (85, 85, 85, 85, 85, 85)
-        };
-        Pokemon {
-            id: PokemonName::from_str(split[0]).unwrap(),
-            level: split[1].parse::<i8>().unwrap(),
-            types: (
-                PokemonType::from_str(split[2]).unwrap(),
-                PokemonType::from_str(split[3]).unwrap(),
-            ),
-            base_types: (
-                PokemonType::from_str(split[4]).unwrap(),
-                PokemonType::from_str(split[5]).unwrap(),
-            ),
-            hp: split[6].parse::<i16>().unwrap(),
-            maxhp: split[7].parse::<i16>().unwrap(),
-            ability: Abilities::from_str(split[8]).unwrap(),
-            base_ability: Abilities::from_str(split[9]).unwrap(),
-            item: Items::from_str(split[10]).unwrap(),
-            nature: PokemonNature::from_str(split[11]).unwrap(),
-            evs,
-            attack: split[13].parse::<i16>().unwrap(),
-            defense: split[14].parse::<i16>().unwrap(),
-            special_attack: split[15].parse::<i16>().unwrap(),
-            special_defense: split[16].parse::<i16>().unwrap(),
-            speed: split[17].parse::<i16>().unwrap(),
-            status: PokemonStatus::from_str(split[18]).unwrap(),
-            rest_turns: split[19].parse::<i8>().unwrap(),
-            sleep_turns: split[20].parse::<i8>().unwrap(),
-            weight_kg: split[21].parse::<f32>().unwrap(),
+        Ok(Pokemon {
+            id: parse_field(&split, 0)?,
+            level: parse_field(&split, 1)?,
+            types: (parse_field(&split, 2)?, parse_field(&split, 3)?),
+            base_types: (parse_field(&split, 4)?, parse_field(&split, 5)?),
+            hp: parse_field(&split, 6)?,
+            maxhp: parse_field(&split, 7)?,
+            ability: parse_field(&split, 8)?,
+            base_ability: parse_field(&split, 9)?,
+            item: parse_field(&split, 10)?,
+            nature: parse_field(&split, 11)?,
+            evs: parse_nested_field(&split, 12, deserialize_evs)?,
+            ivs: parse_nested_field(&split, 13, deserialize_ivs)?,
+            attack: parse_field(&split, 14)?,
+            defense: parse_field(&split, 15)?,
+            special_attack: parse_field(&split, 16)?,
+            special_defense: parse_field(&split, 17)?,
+            speed: parse_field(&split, 18)?,
+            status: parse_field(&split, 19)?,
+            rest_turns: parse_field(&split, 20)?,
+            sleep_turns: parse_field(&split, 21)?,
+            weight_kg: parse_field(&split, 22)?,
             moves: PokemonMoves {
-                m0: Move::deserialize(split[22]),
-                m1: Move::deserialize(split[23]),
-                m2: Move::deserialize(split[24]),
-                m3: Move::deserialize(split[25]),
+                m0: parse_nested_field(&split, 23, Move::try_deserialize)?,
+                m1: parse_nested_field(&split, 24, Move::try_deserialize)?,
+                m2: parse_nested_field(&split, 25, Move::try_deserialize)?,
+                m3: parse_nested_field(&split, 26, Move::try_deserialize)?,
             },
-            terastallized: split[26].parse::<bool>().unwrap(),
-            tera_type: PokemonType::from_str(split[27]).unwrap(),
-        }
+            terastallized: parse_field(&split, 27)?,
+            tera_type: parse_field(&split, 28)?,
+        })
     }
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Side {
     pub active_index: PokemonIndex,
     pub baton_passing: bool,
@@ -1178,54 +1435,69 @@ impl Side {
         )
     }
     pub fn deserialize(serialized: &str) -> Side {
+        Side::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_deserialize(serialized: &str) -> Result<Side, StateParseError> {
         let split: Vec<&str> = serialized.split("=").collect();
 
+        // errors inside of a pokemon keep the field index within that pokemon
+        let pokemon_at = |index: usize| {
+            Pokemon::try_deserialize(get_field(&split, index)?).map_err(|mut e| {
+                e.pokemon_index = Some(index);
+                e
+            })
+        };
+        let pkmn = [
+            pokemon_at(0)?,
+            pokemon_at(1)?,
+            pokemon_at(2)?,
+            pokemon_at(3)?,
+            pokemon_at(4)?,
+            pokemon_at(5)?,
+        ];
         let mut vs_bitset = VolatileStatusBitset::default();
-        if split[8] != "" {
-            for item in split[8].split(":") {
-                vs_bitset.insert(PokemonVolatileStatus::from_str(item).unwrap());
+        let volatile_statuses_token = get_field(&split, 8)?;
+        if volatile_statuses_token != "" {
+            for item in volatile_statuses_token.split(":") {
+                vs_bitset.insert(
+                    PokemonVolatileStatus::from_str(item)
+                        .map_err(|e| StateParseError::new(8, volatile_statuses_token, e))?,
+                );
             }
         }
-        Side {
-            pokemon: SidePokemon {
-                pkmn: [
-                    Pokemon::deserialize(split[0]),
-                    Pokemon::deserialize(split[1]),
-                    Pokemon::deserialize(split[2]),
-                    Pokemon::deserialize(split[3]),
-                    Pokemon::deserialize(split[4]),
-                    Pokemon::deserialize(split[5]),
-                ],
-            },
-            active_index: PokemonIndex::deserialize(split[6]),
-            side_conditions: SideConditions::deserialize(split[7]),
+
+        Ok(Side {
+            pokemon: SidePokemon { pkmn },
+            active_index: parse_nested_field(&split, 6, PokemonIndex::try_deserialize)?,
+            side_conditions: parse_nested_field(&split, 7, SideConditions::try_deserialize)?,
             volatile_statuses: vs_bitset,
-            volatile_status_durations: VolatileStatusDurations::deserialize(split[9]),
-            substitute_health: split[10].parse::<i16>().unwrap(),
-            attack_boost: split[11].parse::<i8>().unwrap(),
-            defense_boost: split[12].parse::<i8>().unwrap(),
-            special_attack_boost: split[13].parse::<i8>().unwrap(),
-            special_defense_boost: split[14].parse::<i8>().unwrap(),
-            speed_boost: split[15].parse::<i8>().unwrap(),
-            accuracy_boost: split[16].parse::<i8>().unwrap(),
-            evasion_boost: split[17].parse::<i8>().unwrap(),
-            wish: (
-                split[18].parse::<i8>().unwrap(),
-                split[19].parse::<i16>().unwrap(),
-            ),
+            volatile_status_durations: parse_nested_field(
+                &split,
+                9,
+                VolatileStatusDurations::try_deserialize,
+            )?,
+            substitute_health: parse_field(&split, 10)?,
+            attack_boost: parse_field(&split, 11)?,
+            defense_boost: parse_field(&split, 12)?,
+            special_attack_boost: parse_field(&split, 13)?,
+            special_defense_boost: parse_field(&split, 14)?,
+            speed_boost: parse_field(&split, 15)?,
+            accuracy_boost: parse_field(&split, 16)?,
+            evasion_boost: parse_field(&split, 17)?,
+            wish: (parse_field(&split, 18)?, parse_field(&split, 19)?),
             future_sight: (
-                split[20].parse::<i8>().unwrap(),
-                PokemonIndex::deserialize(split[21]),
+                parse_field(&split, 20)?,
+                parse_nested_field(&split, 21, PokemonIndex::try_deserialize)?,
             ),
-            force_switch: split[22].parse::<bool>().unwrap(),
-            switch_out_move_second_saved_move: Choices::from_str(split[23]).unwrap(),
-            baton_passing: split[24].parse::<bool>().unwrap(),
-            shed_tailing: split[25].parse::<bool>().unwrap(),
-            force_trapped: split[26].parse::<bool>().unwrap(),
-            last_used_move: LastUsedMove::deserialize(split[27]),
+            force_switch: parse_field(&split, 22)?,
+            switch_out_move_second_saved_move: parse_field(&split, 23)?,
+            baton_passing: parse_field(&split, 24)?,
+            shed_tailing: parse_field(&split, 25)?,
+            force_trapped: parse_field(&split, 26)?,
+            last_used_move: parse_nested_field(&split, 27, LastUsedMove::try_deserialize)?,
             damage_dealt: DamageDealt::default(),
-            slow_uturn_move: split[28].parse::<bool>().unwrap(),
-        }
+            slow_uturn_move: parse_field(&split, 28)?,
+        })
     }
 }
 impl Side {
@@ -1307,7 +1579,110 @@ impl Side {
     }
 }
 
+/// Version of the layout written by `State::serialize`.
+/// Bump this and add a migration to `migrate_state_string` when the layout changes
+pub const STATE_STRING_VERSION: u8 = 3;
+
+const POKEMON_FIELD_COUNT: usize = 29;
+const SIDE_FIELD_COUNT: usize = 29;
+
+// Fields that were added to the layout since the first version, in the order they were added.
+// A string that is missing the last N of them was written before they were added.
+// Each entry is the index of the field in the current layout and the value to fill in
+fn legacy_pokemon_fields(split: &[String]) -> Vec<(usize, String)> {
+    vec![
+        // base_ability was the same as the ability
+        (9, split.get(8).cloned().unwrap_or_default()),
+        (11, "SERIOUS".to_string()), // nature
+        (12, "".to_string()),        // evs, empty is the default evs
+        (13, "".to_string()),        // ivs, added in version 3. Empty is 31 in every stat
+    ]
+}
+fn legacy_side_fields() -> Vec<(usize, String)> {
+    vec![
+        (25, "false".to_string()), // shed_tailing
+        (28, "false".to_string()), // slow_uturn_move
+    ]
+}
+
+fn insert_missing_fields(
+    fields: &mut Vec<String>,
+    field_count: usize,
+    legacy_fields: Vec<(usize, String)>,
+) {
+    if fields.len() >= field_count {
+        return;
+    }
+    let missing_count = (field_count - fields.len()).min(legacy_fields.len());
+    let already_present = legacy_fields.len() - missing_count;
+    for (index, default) in legacy_fields.into_iter().skip(already_present) {
+        fields.insert(index.min(fields.len()), default);
+    }
+}
+
+fn migrate_side_string(serialized: &str) -> String {
+    let mut fields: Vec<String> = serialized.split("=").map(|s| s.to_string()).collect();
+    for pkmn in fields.iter_mut().take(6) {
+        let mut pkmn_fields: Vec<String> = pkmn.split(",").map(|s| s.to_string()).collect();
+        let legacy_fields = legacy_pokemon_fields(&pkmn_fields);
+        insert_missing_fields(&mut pkmn_fields, POKEMON_FIELD_COUNT, legacy_fields);
+        *pkmn = pkmn_fields.join(",");
+    }
+    insert_missing_fields(&mut fields, SIDE_FIELD_COUNT, legacy_side_fields());
+    fields.join("=")
+}
+
+/// Converts a serialized state of any supported version to the current layout.
+///
+/// Unversioned strings (version 1) are from before the version prefix was added.
+/// Fields that were added to pokemon and sides since then are filled in with the values
+/// an older engine would have assumed for them
+pub fn migrate_state_string(serialized: &str) -> Result<String, StateParseError> {
+    // a version tag is "v" followed by digits. Anything else, such as a pokemon named
+    // "volcarona" at the start of a legacy string, is the first field of version 1
+    let version_tag = serialized.split_once("/").and_then(|(first, rest)| {
+        first
+            .strip_prefix("v")
+            .filter(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+            .map(|digits| (first, digits, rest))
+    });
+    let (version, body) = match version_tag {
+        Some((first, digits, rest)) => match digits.parse::<u8>() {
+            Ok(version) => (version, rest),
+            Err(_) => {
+                return Err(StateParseError::new(
+                    0,
+                    first,
+                    "Invalid state string version",
+                ))
+            }
+        },
+        None => (1, serialized),
+    };
+
+    match version {
+        // version 2 strings are only missing the ivs of each pokemon
+        1 | 2 => {
+            let mut split: Vec<String> = body.split("/").map(|s| s.to_string()).collect();
+            for side in split.iter_mut().take(2) {
+                *side = migrate_side_string(side);
+            }
+            Ok(split.join("/"))
+        }
+        STATE_STRING_VERSION => Ok(body.to_string()),
+        _ => Err(StateParseError::new(
+            0,
+            &format!("v{}", version),
+            format!(
+                "state string version is newer than this engine supports (v{})",
+                STATE_STRING_VERSION
+            ),
+        )),
+    }
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct State {
     pub side_one: Side,
     pub side_two: Side,
@@ -1317,6 +1692,54 @@ pub struct State {
     pub team_preview: bool,
     pub use_last_used_move: bool,
     pub use_damage_dealt: bool,
+    pub generation: Generation,
+    pub terastallization: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub zobrist_hash: u64,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerializedState {
+    side_one: Side,
+    side_two: Side,
+    weather: StateWeather,
+    terrain: StateTerrain,
+    trick_room: StateTrickRoom,
+    team_preview: bool,
+    use_last_used_move: bool,
+    use_damage_dealt: bool,
+    generation: Generation,
+    terastallization: bool,
+}
+
+// the move data and the zobrist hash are not serialized.
+// They are rebuilt for the generation of the state
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for State {
+    fn deserialize<D>(deserializer: D) -> Result<State, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let serialized = <SerializedState as serde::Deserialize>::deserialize(deserializer)?;
+        let mut state = State {
+            side_one: serialized.side_one,
+            side_two: serialized.side_two,
+            weather: serialized.weather,
+            terrain: serialized.terrain,
+            trick_room: serialized.trick_room,
+            team_preview: serialized.team_preview,
+            use_last_used_move: serialized.use_last_used_move,
+            use_damage_dealt: serialized.use_damage_dealt,
+            generation: serialized.generation,
+            terastallization: serialized.terastallization,
+            zobrist_hash: 0,
+        };
+        state.set_generation(serialized.generation);
+        state.terastallization = serialized.terastallization;
+        state.update_zobrist_hash();
+        Ok(state)
+    }
 }
 impl Default for State {
     fn default() -> State {
@@ -1338,14 +1761,44 @@ impl Default for State {
             team_preview: false,
             use_damage_dealt: false,
             use_last_used_move: false,
+            generation: Generation::default(),
+            terastallization: Generation::default().has_terastallization(),
+            zobrist_hash: 0,
         };
 
         // many tests rely on the speed of side 2's active pokemon being greater than side_one's
         s.side_two.get_active().speed += 1;
+        s.update_zobrist_hash();
         s
     }
 }
 impl State {
+    /// Use the rules of `generation` for this state
+    ///
+    /// The move data stored on every pokemon is re-loaded from the moves of that generation,
+    /// and terastallization is allowed only if that generation has it
+    pub fn set_generation(&mut self, generation: Generation) {
+        self.generation = generation;
+        self.terastallization = generation.has_terastallization();
+        let generation_moves = moves(generation);
+        for side in [&mut self.side_one, &mut self.side_two].iter_mut() {
+            for pkmn in side.pokemon.pkmn.iter_mut() {
+                for move_index in [
+                    PokemonMoveIndex::M0,
+                    PokemonMoveIndex::M1,
+                    PokemonMoveIndex::M2,
+                    PokemonMoveIndex::M3,
+                ]
+                .iter()
+                {
+                    let pkmn_move = &mut pkmn.moves[move_index];
+                    pkmn_move.choice = generation_moves.get(&pkmn_move.id).unwrap().to_owned();
+                }
+            }
+        }
+        self.update_zobrist_hash();
+    }
+
     pub fn battle_is_over(&self) -> f32 {
         //  0 if battle is not over
         //  1 if side one has won
@@ -1782,6 +2235,7 @@ impl State {
     }
 
     pub fn apply_one_instruction(&mut self, instruction: &Instruction) {
+        self.zobrist_hash ^= self.instruction_zobrist_key(instruction);
         match instruction {
             Instruction::Damage(instruction) => {
                 self.damage(&instruction.side_ref, instruction.damage_amount)
@@ -1960,6 +2414,7 @@ impl State {
                 active.id = PokemonName::from(active.id as i16 + instruction.name_change);
             }
         }
+        self.zobrist_hash ^= self.instruction_zobrist_key(instruction);
     }
 
     pub fn reverse_instructions(&mut self, instructions: &Vec<Instruction>) {
@@ -1969,6 +2424,7 @@ impl State {
     }
 
     pub fn reverse_one_instruction(&mut self, instruction: &Instruction) {
+        self.zobrist_hash ^= self.instruction_zobrist_key(instruction);
         match instruction {
             Instruction::Damage(instruction) => {
                 self.heal(&instruction.side_ref, instruction.damage_amount)
@@ -2147,6 +2603,7 @@ impl State {
                 active.id = PokemonName::from(active.id as i16 - instruction.name_change);
             }
         }
+        self.zobrist_hash ^= self.instruction_zobrist_key(instruction);
     }
 }
 impl State {
@@ -2178,7 +2635,8 @@ impl State {
 
     pub fn serialize(&self) -> String {
         format!(
-            "{}/{}/{}/{}/{}/{}",
+            "v{}/{}/{}/{}/{}/{}/{}",
+            STATE_STRING_VERSION,
             self.side_one.serialize(),
             self.side_two.serialize(),
             self.weather.serialize(),
@@ -2194,21 +2652,20 @@ impl State {
     /// This doctest does its best to show the format of the serialized state.
     ///
     /// Roughly, the format for a state is:
-    ///     side1/side2/weather/terrain/trick_room/team_preview
+    ///     v3/side1/side2/weather/terrain/trick_room/team_preview
+    ///
+    /// The leading version is optional. Strings without it are from before the version was
+    /// added and may be missing fields that were added since, see `migrate_state_string`
     ///
     /// Where the format for a side is:
-    ///     p0=p1=p2=p3=p4=p5=active_index=side_conditions=wish0=wish1=force_switch=switch_out_move_second_saved_move=baton_passing=shed_tailing=force_trapped=last_used_move=slow_uturn_move
+    ///     p0=p1=p2=p3=p4=p5=active_index=side_conditions=volatile_statuses=volatile_status_durations=substitute_health=attack_boost=defense_boost=special_attack_boost=special_defense_boost=speed_boost=accuracy_boost=evasion_boost=wish0=wish1=future_sight0=future_sight1=force_switch=switch_out_move_second_saved_move=baton_passing=shed_tailing=force_trapped=last_used_move=slow_uturn_move
     ///
     /// And the format for a pokemon is:
-    ///    id,level,type1,type2,hp,maxhp,ability,item,attack,defense,special_attack,special_defense,speed,attack_boost,defense_boost,special_attack_boost,special_defense_boost,speed_boost,accuracy_boost,evasion_boost,status,substitute_health,rest_turns,weight_kg,volatile_statuses,m0,m1,m2,m3
+    ///    id,level,type1,type2,base_type1,base_type2,hp,maxhp,ability,base_ability,item,nature,evs,ivs,attack,defense,special_attack,special_defense,speed,status,rest_turns,sleep_turns,weight_kg,m0,m1,m2,m3,terastallized,tera_type
     ///
     /// There's more to it, follow the code below to see a full example of a serialized state.
     /// */
     ///
-    /// if cfg!(feature = "gen2") {
-    ///    return;
-    /// }
-    ///
     /// use poke_engine::engine::abilities::Abilities;
     /// use poke_engine::engine::items::Items;
     /// use poke_engine::pokemon::PokemonName;
@@ -2216,6 +2673,9 @@ impl State {
     ///
     /// let serialized_state = concat!(
     ///
+    /// // the version of the layout
+    /// "v3/",
+    ///
     /// // SIDE 1
     ///
     /// // POKEMON 1
@@ -2258,6 +2718,10 @@ impl State {
     /// "252;0;252;0;4;0,",
     /// // ",", left blank for default EVs
     ///
+    /// // IVs split by `;`. Leave blank for default IVs (31 in all)
+    /// "31;0;31;31;31;31,",
+    /// // ",", left blank for default IVs
+    ///
     /// // attack,defense,special attack,special defense,speed
     /// // note these are final stats, not base stats
     /// "121,148,353,206,365,",
@@ -2284,11 +2748,11 @@ impl State {
     /// "Normal=",
     ///
     /// // all remaining Pokémon shown in 1 line for brevity
-    /// "skarmory,100,Steel,Flying,Steel,Flying,271,271,STURDY,STURDY,CUSTAPBERRY,SERIOUS,,259,316,104,177,262,None,0,0,25.5,STEALTHROCK;false;32,SPIKES;false;32,BRAVEBIRD;false;24,THIEF;false;40,false,Normal=",
-    /// "tyranitar,100,Rock,Dark,Rock,Dark,404,404,SANDSTREAM,SANDSTREAM,CHOPLEBERRY,SERIOUS,,305,256,203,327,159,None,0,0,25.5,CRUNCH;false;24,SUPERPOWER;false;8,THUNDERWAVE;false;32,PURSUIT;false;32,false,Normal=",
-    /// "mamoswine,100,Ice,Ground,Ice,Ground,362,362,THICKFAT,THICKFAT,NEVERMELTICE,SERIOUS,,392,196,158,176,241,None,0,0,25.5,ICESHARD;false;48,EARTHQUAKE;false;16,SUPERPOWER;false;8,ICICLECRASH;false;16,false,Normal=",
-    /// "jellicent,100,Water,Ghost,Water,Ghost,404,404,WATERABSORB,WATERABSORB,AIRBALLOON,SERIOUS,,140,237,206,246,180,None,0,0,25.5,TAUNT;false;32,NIGHTSHADE;false;24,WILLOWISP;false;24,RECOVER;false;16,false,Normal=",
-    /// "excadrill,100,Ground,Steel,Ground,Steel,362,362,SANDFORCE,SANDFORCE,CHOICESCARF,SERIOUS,,367,156,122,168,302,None,0,0,25.5,EARTHQUAKE;false;16,IRONHEAD;false;24,ROCKSLIDE;false;16,RAPIDSPIN;false;64,false,Normal=",
+    /// "skarmory,100,Steel,Flying,Steel,Flying,271,271,STURDY,STURDY,CUSTAPBERRY,SERIOUS,,,259,316,104,177,262,None,0,0,25.5,STEALTHROCK;false;32,SPIKES;false;32,BRAVEBIRD;false;24,THIEF;false;40,false,Normal=",
+    /// "tyranitar,100,Rock,Dark,Rock,Dark,404,404,SANDSTREAM,SANDSTREAM,CHOPLEBERRY,SERIOUS,,,305,256,203,327,159,None,0,0,25.5,CRUNCH;false;24,SUPERPOWER;false;8,THUNDERWAVE;false;32,PURSUIT;false;32,false,Normal=",
+    /// "mamoswine,100,Ice,Ground,Ice,Ground,362,362,THICKFAT,THICKFAT,NEVERMELTICE,SERIOUS,,,392,196,158,176,241,None,0,0,25.5,ICESHARD;false;48,EARTHQUAKE;false;16,SUPERPOWER;false;8,ICICLECRASH;false;16,false,Normal=",
+    /// "jellicent,100,Water,Ghost,Water,Ghost,404,404,WATERABSORB,WATERABSORB,AIRBALLOON,SERIOUS,,,140,237,206,246,180,None,0,0,25.5,TAUNT;false;32,NIGHTSHADE;false;24,WILLOWISP;false;24,RECOVER;false;16,false,Normal=",
+    /// "excadrill,100,Ground,Steel,Ground,Steel,362,362,SANDFORCE,SANDFORCE,CHOICESCARF,SERIOUS,,,367,156,122,168,302,None,0,0,25.5,EARTHQUAKE;false;16,IRONHEAD;false;24,ROCKSLIDE;false;16,RAPIDSPIN;false;64,false,Normal=",
     ///
     /// // active-index. This is the index of the active Pokémon in the side's Pokémon array
     /// "0=",
@@ -2340,7 +2804,7 @@ impl State {
     /// "false/",
     ///
     /// // SIDE 2, all in one line for brevity
-    /// "terrakion,100,Rock,Fighting,Rock,Fighting,323,323,NONE,NONE,FOCUSSASH,SERIOUS,,357,216,163,217,346,None,0,0,25.5,CLOSECOMBAT;false;8,STONEEDGE;false;8,STEALTHROCK;false;32,TAUNT;false;32,false,Normal=lucario,100,Fighting,Steel,Fighting,Steel,281,281,NONE,NONE,LIFEORB,SERIOUS,,350,176,241,177,279,None,0,0,25.5,CLOSECOMBAT;false;8,EXTREMESPEED;false;8,SWORDSDANCE;false;32,CRUNCH;false;24,false,Normal=breloom,100,Grass,Fighting,Grass,Fighting,262,262,TECHNICIAN,TECHNICIAN,LIFEORB,SERIOUS,,394,196,141,156,239,None,0,0,25.5,MACHPUNCH;false;48,BULLETSEED;false;48,SWORDSDANCE;false;32,LOWSWEEP;false;32,false,Normal=keldeo,100,Water,Fighting,Water,Fighting,323,323,NONE,NONE,LEFTOVERS,SERIOUS,,163,216,357,217,346,None,0,0,25.5,SECRETSWORD;false;16,HYDROPUMP;false;8,SCALD;false;24,SURF;false;24,false,Normal=conkeldurr,100,Fighting,Typeless,Fighting,Typeless,414,414,GUTS,GUTS,LEFTOVERS,SERIOUS,,416,226,132,167,126,None,0,0,25.5,MACHPUNCH;false;48,DRAINPUNCH;false;16,ICEPUNCH;false;24,THUNDERPUNCH;false;24,false,Normal=toxicroak,100,Poison,Fighting,Poison,Fighting,307,307,DRYSKIN,DRYSKIN,LIFEORB,SERIOUS,,311,166,189,167,295,None,0,0,25.5,DRAINPUNCH;false;16,SUCKERPUNCH;false;8,SWORDSDANCE;false;32,ICEPUNCH;false;24,false,Normal=0=0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;==0;0;0;0;0;0=0=0=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:0=false/",
+    /// "terrakion,100,Rock,Fighting,Rock,Fighting,323,323,NONE,NONE,FOCUSSASH,SERIOUS,,,357,216,163,217,346,None,0,0,25.5,CLOSECOMBAT;false;8,STONEEDGE;false;8,STEALTHROCK;false;32,TAUNT;false;32,false,Normal=lucario,100,Fighting,Steel,Fighting,Steel,281,281,NONE,NONE,LIFEORB,SERIOUS,,,350,176,241,177,279,None,0,0,25.5,CLOSECOMBAT;false;8,EXTREMESPEED;false;8,SWORDSDANCE;false;32,CRUNCH;false;24,false,Normal=breloom,100,Grass,Fighting,Grass,Fighting,262,262,TECHNICIAN,TECHNICIAN,LIFEORB,SERIOUS,,,394,196,141,156,239,None,0,0,25.5,MACHPUNCH;false;48,BULLETSEED;false;48,SWORDSDANCE;false;32,LOWSWEEP;false;32,false,Normal=keldeo,100,Water,Fighting,Water,Fighting,323,323,NONE,NONE,LEFTOVERS,SERIOUS,,,163,216,357,217,346,None,0,0,25.5,SECRETSWORD;false;16,HYDROPUMP;false;8,SCALD;false;24,SURF;false;24,false,Normal=conkeldurr,100,Fighting,Typeless,Fighting,Typeless,414,414,GUTS,GUTS,LEFTOVERS,SERIOUS,,,416,226,132,167,126,None,0,0,25.5,MACHPUNCH;false;48,DRAINPUNCH;false;16,ICEPUNCH;false;24,THUNDERPUNCH;false;24,false,Normal=toxicroak,100,Poison,Fighting,Poison,Fighting,307,307,DRYSKIN,DRYSKIN,LIFEORB,SERIOUS,,,311,166,189,167,295,None,0,0,25.5,DRAINPUNCH;false;16,SUCKERPUNCH;false;8,SWORDSDANCE;false;32,ICEPUNCH;false;24,false,Normal=0=0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;==0;0;0;0;0;0=0=0=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:0=false/",
     ///
     /// // weather is a string representing the weather type and the number of turns remaining
     /// "none;5/",
@@ -2360,6 +2824,7 @@ impl State {
     ///
     /// assert_eq!(state.side_one.get_active_immutable().id, PokemonName::ALAKAZAM);
     /// assert_eq!(state.side_one.get_active_immutable().weight_kg, 25.5);
+    /// assert_eq!(state.side_one.get_active_immutable().ivs, (31, 0, 31, 31, 31, 31));
     /// assert_eq!(state.side_one.substitute_health, 0);
     /// assert_eq!(state.side_two.get_active_immutable().id, PokemonName::TERRAKION);
     /// assert_eq!(state.trick_room.active, false);
@@ -2367,24 +2832,54 @@ impl State {
     ///
     ///
     /// // the same state, but all in one line
-    /// let serialized_state = "alakazam,100,Psychic,Typeless,Psychic,Typeless,251,251,NONE,NONE,LIFEORB,SERIOUS,252;0;252;0;4;0,121,148,353,206,365,None,0,0,25.5,PSYCHIC;false;16,GRASSKNOT;false;32,SHADOWBALL;false;24,HIDDENPOWERFIRE70;false;24,false,Normal=skarmory,100,Steel,Flying,Steel,Flying,271,271,STURDY,STURDY,CUSTAPBERRY,SERIOUS,,259,316,104,177,262,None,0,0,25.5,STEALTHROCK;false;32,SPIKES;false;32,BRAVEBIRD;false;24,THIEF;false;40,false,Normal=tyranitar,100,Rock,Dark,Rock,Dark,404,404,SANDSTREAM,SANDSTREAM,CHOPLEBERRY,SERIOUS,,305,256,203,327,159,None,0,0,25.5,CRUNCH;false;24,SUPERPOWER;false;8,THUNDERWAVE;false;32,PURSUIT;false;32,false,Normal=mamoswine,100,Ice,Ground,Ice,Ground,362,362,THICKFAT,THICKFAT,NEVERMELTICE,SERIOUS,,392,196,158,176,241,None,0,0,25.5,ICESHARD;false;48,EARTHQUAKE;false;16,SUPERPOWER;false;8,ICICLECRASH;false;16,false,Normal=jellicent,100,Water,Ghost,Water,Ghost,404,404,WATERABSORB,WATERABSORB,AIRBALLOON,SERIOUS,,140,237,206,246,180,None,0,0,25.5,TAUNT;false;32,NIGHTSHADE;false;24,WILLOWISP;false;24,RECOVER;false;16,false,Normal=excadrill,100,Ground,Steel,Ground,Steel,362,362,SANDFORCE,SANDFORCE,CHOICESCARF,SERIOUS,,367,156,122,168,302,None,0,0,25.5,EARTHQUAKE;false;16,IRONHEAD;false;24,ROCKSLIDE;false;16,RAPIDSPIN;false;64,false,Normal=0=0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;==0;0;0;0;0;0=0=0=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:0=false/terrakion,100,Rock,Fighting,Rock,Fighting,323,323,NONE,NONE,FOCUSSASH,SERIOUS,,357,216,163,217,346,None,0,0,25.5,CLOSECOMBAT;false;8,STONEEDGE;false;8,STEALTHROCK;false;32,TAUNT;false;32,false,Normal=lucario,100,Fighting,Steel,Fighting,Steel,281,281,NONE,NONE,LIFEORB,SERIOUS,,350,176,241,177,279,None,0,0,25.5,CLOSECOMBAT;false;8,EXTREMESPEED;false;8,SWORDSDANCE;false;32,CRUNCH;false;24,false,Normal=breloom,100,Grass,Fighting,Grass,Fighting,262,262,TECHNICIAN,TECHNICIAN,LIFEORB,SERIOUS,,394,196,141,156,239,None,0,0,25.5,MACHPUNCH;false;48,BULLETSEED;false;48,SWORDSDANCE;false;32,LOWSWEEP;false;32,false,Normal=keldeo,100,Water,Fighting,Water,Fighting,323,323,NONE,NONE,LEFTOVERS,SERIOUS,,163,216,357,217,346,None,0,0,25.5,SECRETSWORD;false;16,HYDROPUMP;false;8,SCALD;false;24,SURF;false;24,false,Normal=conkeldurr,100,Fighting,Typeless,Fighting,Typeless,414,414,GUTS,GUTS,LEFTOVERS,SERIOUS,,416,226,132,167,126,None,0,0,25.5,MACHPUNCH;false;48,DRAINPUNCH;false;16,ICEPUNCH;false;24,THUNDERPUNCH;false;24,false,Normal=toxicroak,100,Poison,Fighting,Poison,Fighting,307,307,DRYSKIN,DRYSKIN,LIFEORB,SERIOUS,,311,166,189,167,295,None,0,0,25.5,DRAINPUNCH;false;16,SUCKERPUNCH;false;8,SWORDSDANCE;false;32,ICEPUNCH;false;24,false,Normal=0=0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;==0;0;0;0;0;0=0=0=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:0=false/none;5/none;5/false;5/false";
+    /// let serialized_state = "v3/alakazam,100,Psychic,Typeless,Psychic,Typeless,251,251,NONE,NONE,LIFEORB,SERIOUS,252;0;252;0;4;0,31;0;31;31;31;31,121,148,353,206,365,None,0,0,25.5,PSYCHIC;false;16,GRASSKNOT;false;32,SHADOWBALL;false;24,HIDDENPOWERFIRE70;false;24,false,Normal=skarmory,100,Steel,Flying,Steel,Flying,271,271,STURDY,STURDY,CUSTAPBERRY,SERIOUS,,,259,316,104,177,262,None,0,0,25.5,STEALTHROCK;false;32,SPIKES;false;32,BRAVEBIRD;false;24,THIEF;false;40,false,Normal=tyranitar,100,Rock,Dark,Rock,Dark,404,404,SANDSTREAM,SANDSTREAM,CHOPLEBERRY,SERIOUS,,,305,256,203,327,159,None,0,0,25.5,CRUNCH;false;24,SUPERPOWER;false;8,THUNDERWAVE;false;32,PURSUIT;false;32,false,Normal=mamoswine,100,Ice,Ground,Ice,Ground,362,362,THICKFAT,THICKFAT,NEVERMELTICE,SERIOUS,,,392,196,158,176,241,None,0,0,25.5,ICESHARD;false;48,EARTHQUAKE;false;16,SUPERPOWER;false;8,ICICLECRASH;false;16,false,Normal=jellicent,100,Water,Ghost,Water,Ghost,404,404,WATERABSORB,WATERABSORB,AIRBALLOON,SERIOUS,,,140,237,206,246,180,None,0,0,25.5,TAUNT;false;32,NIGHTSHADE;false;24,WILLOWISP;false;24,RECOVER;false;16,false,Normal=excadrill,100,Ground,Steel,Ground,Steel,362,362,SANDFORCE,SANDFORCE,CHOICESCARF,SERIOUS,,,367,156,122,168,302,None,0,0,25.5,EARTHQUAKE;false;16,IRONHEAD;false;24,ROCKSLIDE;false;16,RAPIDSPIN;false;64,false,Normal=0=0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;==0;0;0;0;0;0=0=0=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:0=false/terrakion,100,Rock,Fighting,Rock,Fighting,323,323,NONE,NONE,FOCUSSASH,SERIOUS,,,357,216,163,217,346,None,0,0,25.5,CLOSECOMBAT;false;8,STONEEDGE;false;8,STEALTHROCK;false;32,TAUNT;false;32,false,Normal=lucario,100,Fighting,Steel,Fighting,Steel,281,281,NONE,NONE,LIFEORB,SERIOUS,,,350,176,241,177,279,None,0,0,25.5,CLOSECOMBAT;false;8,EXTREMESPEED;false;8,SWORDSDANCE;false;32,CRUNCH;false;24,false,Normal=breloom,100,Grass,Fighting,Grass,Fighting,262,262,TECHNICIAN,TECHNICIAN,LIFEORB,SERIOUS,,,394,196,141,156,239,None,0,0,25.5,MACHPUNCH;false;48,BULLETSEED;false;48,SWORDSDANCE;false;32,LOWSWEEP;false;32,false,Normal=keldeo,100,Water,Fighting,Water,Fighting,323,323,NONE,NONE,LEFTOVERS,SERIOUS,,,163,216,357,217,346,None,0,0,25.5,SECRETSWORD;false;16,HYDROPUMP;false;8,SCALD;false;24,SURF;false;24,false,Normal=conkeldurr,100,Fighting,Typeless,Fighting,Typeless,414,414,GUTS,GUTS,LEFTOVERS,SERIOUS,,,416,226,132,167,126,None,0,0,25.5,MACHPUNCH;false;48,DRAINPUNCH;false;16,ICEPUNCH;false;24,THUNDERPUNCH;false;24,false,Normal=toxicroak,100,Poison,Fighting,Poison,Fighting,307,307,DRYSKIN,DRYSKIN,LIFEORB,SERIOUS,,,311,166,189,167,295,None,0,0,25.5,DRAINPUNCH;false;16,SUCKERPUNCH;false;8,SWORDSDANCE;false;32,ICEPUNCH;false;24,false,Normal=0=0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;0;==0;0;0;0;0;0=0=0=0=0=0=0=0=0=0=0=0=0=false=NONE=false=false=false=switch:0=false/none;5/none;5/false;5/false";
     /// let state2 = State::deserialize(serialized_state);
     /// assert_eq!(state.serialize(), state2.serialize());
     ///
     /// ```
     pub fn deserialize(serialized: &str) -> State {
-        let split: Vec<&str> = serialized.split("/").collect();
+        State::try_deserialize(serialized).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as `State::deserialize`, but returns an error describing where the
+    /// serialized state is malformed instead of panicking
+    ///
+    /// ```
+    /// use poke_engine::state::{SideReference, State};
+    ///
+    /// let mut serialized = State::default().serialize();
+    /// serialized = serialized.replacen(",100,", ",not-a-level,", 1);
+    ///
+    /// let error = State::try_deserialize(&serialized).unwrap_err();
+    /// assert_eq!(error.side, Some(SideReference::SideOne));
+    /// assert_eq!(error.pokemon_index, Some(0));
+    /// assert_eq!(error.field_index, 1);
+    /// assert_eq!(error.token, "not-a-level");
+    /// ```
+    pub fn try_deserialize(serialized: &str) -> Result<State, StateParseError> {
+        let migrated = migrate_state_string(serialized)?;
+        let split: Vec<&str> = migrated.split("/").collect();
+        let side_at = |index: usize, side_ref: SideReference| {
+            Side::try_deserialize(get_field(&split, index)?).map_err(|mut e| {
+                e.side = Some(side_ref);
+                e
+            })
+        };
         let mut state = State {
-            side_one: Side::deserialize(split[0]),
-            side_two: Side::deserialize(split[1]),
-            weather: StateWeather::deserialize(split[2]),
-            terrain: StateTerrain::deserialize(split[3]),
-            trick_room: StateTrickRoom::deserialize(split[4]),
-            team_preview: split[5].parse::<bool>().unwrap(),
+            side_one: side_at(0, SideReference::SideOne)?,
+            side_two: side_at(1, SideReference::SideTwo)?,
+            weather: parse_nested_field(&split, 2, StateWeather::try_deserialize)?,
+            terrain: parse_nested_field(&split, 3, StateTerrain::try_deserialize)?,
+            trick_room: parse_nested_field(&split, 4, StateTrickRoom::try_deserialize)?,
+            team_preview: parse_field(&split, 5)?,
             use_damage_dealt: false,
             use_last_used_move: false,
+            generation: Generation::default(),
+            terastallization: Generation::default().has_terastallization(),
+            zobrist_hash: 0,
         };
         state.set_conditional_mechanics();
-        state
+        state.update_zobrist_hash();
+        Ok(state)
     }
 }
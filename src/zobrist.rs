@@ -0,0 +1,753 @@
+// Zobrist-style hashing of `State`.
+//
+// Every feature of the state is mapped to a pseudo-random 64-bit key and the hash of a
+// state is the XOR of the keys of all of its features. XOR is its own inverse, so
+// `apply_one_instruction` and `reverse_one_instruction` keep `State::zobrist_hash` up to
+// date by XOR-ing the keys of the features touched by an instruction once before and
+// once after the state is modified.
+
+use crate::instruction::Instruction;
+use crate::state::{
+    pokemon_index_iter, LastUsedMove, Pokemon, PokemonIndex, PokemonMoveIndex,
+    PokemonSideCondition, PokemonType, Side, SideReference, State,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[repr(u64)]
+enum Feature {
+    Hp = 1,
+    Status,
+    Attack,
+    Defense,
+    SpecialAttack,
+    SpecialDefense,
+    Speed,
+    Types,
+    Ability,
+    Item,
+    Id,
+    RestTurns,
+    SleepTurns,
+    Terastallized,
+    PokemonStatic,
+    MovePp,
+    MoveDisabled,
+    MoveId,
+    ActiveIndex,
+    Boosts,
+    SideCondition,
+    VolatileStatuses,
+    VolatileStatusDurations,
+    Wish,
+    FutureSight,
+    SubstituteHealth,
+    ForceSwitch,
+    SwitchOutMoveSecondSavedMove,
+    BatonPassing,
+    ShedTailing,
+    LastUsedMove,
+    DamageDealtDamage,
+    DamageDealtMoveCategory,
+    DamageDealtHitSubstitute,
+    SideStatic,
+    Weather,
+    Terrain,
+    TrickRoom,
+    FieldStatic,
+}
+
+const SIDE_CONDITIONS: [PokemonSideCondition; 19] = [
+    PokemonSideCondition::AuroraVeil,
+    PokemonSideCondition::CraftyShield,
+    PokemonSideCondition::HealingWish,
+    PokemonSideCondition::LightScreen,
+    PokemonSideCondition::LuckyChant,
+    PokemonSideCondition::LunarDance,
+    PokemonSideCondition::MatBlock,
+    PokemonSideCondition::Mist,
+    PokemonSideCondition::Protect,
+    PokemonSideCondition::QuickGuard,
+    PokemonSideCondition::Reflect,
+    PokemonSideCondition::Safeguard,
+    PokemonSideCondition::Spikes,
+    PokemonSideCondition::Stealthrock,
+    PokemonSideCondition::StickyWeb,
+    PokemonSideCondition::Tailwind,
+    PokemonSideCondition::ToxicCount,
+    PokemonSideCondition::ToxicSpikes,
+    PokemonSideCondition::WideGuard,
+];
+
+const MOVE_INDICES: [PokemonMoveIndex; 4] = [
+    PokemonMoveIndex::M0,
+    PokemonMoveIndex::M1,
+    PokemonMoveIndex::M2,
+    PokemonMoveIndex::M3,
+];
+
+#[inline(always)]
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E3779B97F4A7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+    x ^ (x >> 31)
+}
+
+// `side` is 0 or 1 for features that belong to a side, 2 for the field.
+// `index` distinguishes features of the same kind on one side, e.g. which pokemon
+#[inline(always)]
+fn key(feature: Feature, side: u64, index: u64, value: u64) -> u64 {
+    splitmix64(splitmix64((feature as u64) << 32 | side << 16 | index) ^ value)
+}
+
+fn side_number(side_ref: &SideReference) -> u64 {
+    match side_ref {
+        SideReference::SideOne => 0,
+        SideReference::SideTwo => 1,
+    }
+}
+
+fn move_number(pokemon_index: PokemonIndex, move_index: &PokemonMoveIndex) -> u64 {
+    pokemon_index as u64 * 4 + *move_index as u64
+}
+
+fn pack_i8s(values: &[i8]) -> u64 {
+    values
+        .iter()
+        .fold(0, |acc, value| acc << 8 | *value as u8 as u64)
+}
+
+fn types_value(types: &(PokemonType, PokemonType)) -> u64 {
+    (types.0 as u64) << 8 | types.1 as u64
+}
+
+fn last_used_move_value(last_used_move: &LastUsedMove) -> u64 {
+    match last_used_move {
+        LastUsedMove::Move(move_index) => 1 << 8 | *move_index as u64,
+        LastUsedMove::Switch(pokemon_index) => 2 << 8 | *pokemon_index as u64,
+        LastUsedMove::None => 0,
+    }
+}
+
+impl Pokemon {
+    fn hp_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(Feature::Hp, side, index as u64, self.hp as u64)
+    }
+    fn status_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(Feature::Status, side, index as u64, self.status as u64)
+    }
+    fn attack_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(Feature::Attack, side, index as u64, self.attack as u64)
+    }
+    fn defense_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(Feature::Defense, side, index as u64, self.defense as u64)
+    }
+    fn special_attack_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(
+            Feature::SpecialAttack,
+            side,
+            index as u64,
+            self.special_attack as u64,
+        )
+    }
+    fn special_defense_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(
+            Feature::SpecialDefense,
+            side,
+            index as u64,
+            self.special_defense as u64,
+        )
+    }
+    fn speed_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(Feature::Speed, side, index as u64, self.speed as u64)
+    }
+    fn types_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(Feature::Types, side, index as u64, types_value(&self.types))
+    }
+    fn ability_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(Feature::Ability, side, index as u64, self.ability as u64)
+    }
+    fn item_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(Feature::Item, side, index as u64, self.item as u64)
+    }
+    fn id_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(Feature::Id, side, index as u64, self.id as u64)
+    }
+    fn rest_turns_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(
+            Feature::RestTurns,
+            side,
+            index as u64,
+            self.rest_turns as u64,
+        )
+    }
+    fn sleep_turns_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(
+            Feature::SleepTurns,
+            side,
+            index as u64,
+            self.sleep_turns as u64,
+        )
+    }
+    fn terastallized_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        key(
+            Feature::Terastallized,
+            side,
+            index as u64,
+            self.terastallized as u64,
+        )
+    }
+    fn move_pp_key(&self, side: u64, index: PokemonIndex, move_index: &PokemonMoveIndex) -> u64 {
+        key(
+            Feature::MovePp,
+            side,
+            move_number(index, move_index),
+            self.moves[move_index].pp as u64,
+        )
+    }
+    fn move_disabled_key(
+        &self,
+        side: u64,
+        index: PokemonIndex,
+        move_index: &PokemonMoveIndex,
+    ) -> u64 {
+        key(
+            Feature::MoveDisabled,
+            side,
+            move_number(index, move_index),
+            self.moves[move_index].disabled as u64,
+        )
+    }
+
+    // fields that no instruction modifies
+    fn static_key(&self, side: u64, index: PokemonIndex) -> u64 {
+        let mut hash = key(
+            Feature::PokemonStatic,
+            side,
+            index as u64,
+            (self.maxhp as u64) << 32
+                | (self.level as u8 as u64) << 16
+                | (self.tera_type as u64) << 8
+                | self.base_ability as u64,
+        );
+        hash ^= key(
+            Feature::PokemonStatic,
+            side,
+            index as u64 | 1 << 8,
+            self.weight_kg.to_bits() as u64,
+        );
+        for move_index in MOVE_INDICES.iter() {
+            hash ^= key(
+                Feature::MoveId,
+                side,
+                move_number(index, move_index),
+                self.moves[move_index].id as u64,
+            );
+        }
+        hash
+    }
+
+    fn zobrist_hash(&self, side: u64, index: PokemonIndex) -> u64 {
+        let mut hash = self.hp_key(side, index)
+            ^ self.status_key(side, index)
+            ^ self.attack_key(side, index)
+            ^ self.defense_key(side, index)
+            ^ self.special_attack_key(side, index)
+            ^ self.special_defense_key(side, index)
+            ^ self.speed_key(side, index)
+            ^ self.types_key(side, index)
+            ^ self.ability_key(side, index)
+            ^ self.item_key(side, index)
+            ^ self.id_key(side, index)
+            ^ self.rest_turns_key(side, index)
+            ^ self.sleep_turns_key(side, index)
+            ^ self.terastallized_key(side, index)
+            ^ self.static_key(side, index);
+        for move_index in MOVE_INDICES.iter() {
+            hash ^= self.move_pp_key(side, index, move_index)
+                ^ self.move_disabled_key(side, index, move_index);
+        }
+        hash
+    }
+}
+
+impl Side {
+    fn active_index_key(&self, side: u64) -> u64 {
+        key(Feature::ActiveIndex, side, 0, self.active_index as u64)
+    }
+    fn boosts_key(&self, side: u64) -> u64 {
+        key(
+            Feature::Boosts,
+            side,
+            0,
+            pack_i8s(&[
+                self.attack_boost,
+                self.defense_boost,
+                self.special_attack_boost,
+                self.special_defense_boost,
+                self.speed_boost,
+                self.accuracy_boost,
+                self.evasion_boost,
+            ]),
+        )
+    }
+    fn side_condition_key(&self, side: u64, side_condition: PokemonSideCondition) -> u64 {
+        key(
+            Feature::SideCondition,
+            side,
+            side_condition as u64,
+            self.get_side_condition(side_condition) as u64,
+        )
+    }
+    fn volatile_statuses_key(&self, side: u64) -> u64 {
+        let bits = self.volatile_statuses.0;
+        key(
+            Feature::VolatileStatuses,
+            side,
+            0,
+            bits as u64 ^ splitmix64((bits >> 64) as u64),
+        )
+    }
+    fn volatile_status_durations_key(&self, side: u64) -> u64 {
+        let durations = &self.volatile_status_durations;
+        key(
+            Feature::VolatileStatusDurations,
+            side,
+            0,
+            pack_i8s(&[
+                durations.confusion,
+                durations.encore,
+                durations.lockedmove,
+                durations.slowstart,
+                durations.taunt,
+                durations.yawn,
+            ]),
+        )
+    }
+    fn wish_key(&self, side: u64) -> u64 {
+        key(
+            Feature::Wish,
+            side,
+            0,
+            (self.wish.1 as u16 as u64) << 8 | self.wish.0 as u8 as u64,
+        )
+    }
+    fn future_sight_key(&self, side: u64) -> u64 {
+        key(
+            Feature::FutureSight,
+            side,
+            0,
+            (self.future_sight.1 as u64) << 8 | self.future_sight.0 as u8 as u64,
+        )
+    }
+    fn substitute_health_key(&self, side: u64) -> u64 {
+        key(
+            Feature::SubstituteHealth,
+            side,
+            0,
+            self.substitute_health as u64,
+        )
+    }
+    fn force_switch_key(&self, side: u64) -> u64 {
+        key(Feature::ForceSwitch, side, 0, self.force_switch as u64)
+    }
+    fn switch_out_move_second_saved_move_key(&self, side: u64) -> u64 {
+        key(
+            Feature::SwitchOutMoveSecondSavedMove,
+            side,
+            0,
+            self.switch_out_move_second_saved_move as u64,
+        )
+    }
+    fn baton_passing_key(&self, side: u64) -> u64 {
+        key(Feature::BatonPassing, side, 0, self.baton_passing as u64)
+    }
+    fn shed_tailing_key(&self, side: u64) -> u64 {
+        key(Feature::ShedTailing, side, 0, self.shed_tailing as u64)
+    }
+    fn last_used_move_key(&self, side: u64) -> u64 {
+        key(
+            Feature::LastUsedMove,
+            side,
+            0,
+            last_used_move_value(&self.last_used_move),
+        )
+    }
+    fn damage_dealt_damage_key(&self, side: u64) -> u64 {
+        key(
+            Feature::DamageDealtDamage,
+            side,
+            0,
+            self.damage_dealt.damage as u64,
+        )
+    }
+    fn damage_dealt_move_category_key(&self, side: u64) -> u64 {
+        key(
+            Feature::DamageDealtMoveCategory,
+            side,
+            0,
+            self.damage_dealt.move_category as u64,
+        )
+    }
+    fn damage_dealt_hit_substitute_key(&self, side: u64) -> u64 {
+        key(
+            Feature::DamageDealtHitSubstitute,
+            side,
+            0,
+            self.damage_dealt.hit_substitute as u64,
+        )
+    }
+
+    fn zobrist_hash(&self, side: u64) -> u64 {
+        let mut hash = self.active_index_key(side)
+            ^ self.boosts_key(side)
+            ^ self.volatile_statuses_key(side)
+            ^ self.volatile_status_durations_key(side)
+            ^ self.wish_key(side)
+            ^ self.future_sight_key(side)
+            ^ self.substitute_health_key(side)
+            ^ self.force_switch_key(side)
+            ^ self.switch_out_move_second_saved_move_key(side)
+            ^ self.baton_passing_key(side)
+            ^ self.shed_tailing_key(side)
+            ^ self.last_used_move_key(side)
+            ^ self.damage_dealt_damage_key(side)
+            ^ self.damage_dealt_move_category_key(side)
+            ^ self.damage_dealt_hit_substitute_key(side)
+            ^ key(
+                Feature::SideStatic,
+                side,
+                0,
+                (self.force_trapped as u64) << 1 | self.slow_uturn_move as u64,
+            );
+        for side_condition in SIDE_CONDITIONS {
+            hash ^= self.side_condition_key(side, side_condition);
+        }
+        for pokemon_index in pokemon_index_iter() {
+            hash ^= self.pokemon[pokemon_index].zobrist_hash(side, pokemon_index);
+        }
+        hash
+    }
+}
+
+impl State {
+    fn weather_key(&self) -> u64 {
+        key(
+            Feature::Weather,
+            2,
+            0,
+            (self.weather.weather_type as u64) << 8 | self.weather.turns_remaining as u8 as u64,
+        )
+    }
+    fn terrain_key(&self) -> u64 {
+        key(
+            Feature::Terrain,
+            2,
+            0,
+            (self.terrain.terrain_type as u64) << 8 | self.terrain.turns_remaining as u8 as u64,
+        )
+    }
+    fn trick_room_key(&self) -> u64 {
+        key(
+            Feature::TrickRoom,
+            2,
+            0,
+            (self.trick_room.active as u64) << 8 | self.trick_room.turns_remaining as u8 as u64,
+        )
+    }
+
+    /// Computes the hash of the entire state from scratch
+    ///
+    /// `zobrist_hash` is only updated incrementally by applying and reversing instructions.
+    /// Use `update_zobrist_hash` after modifying the state by any other means
+    pub fn compute_zobrist_hash(&self) -> u64 {
+        self.side_one.zobrist_hash(0)
+            ^ self.side_two.zobrist_hash(1)
+            ^ self.weather_key()
+            ^ self.terrain_key()
+            ^ self.trick_room_key()
+            ^ key(
+                Feature::FieldStatic,
+                2,
+                0,
+                (self.generation as u64) << 8
+                    | (self.terastallization as u64) << 3
+                    | (self.team_preview as u64) << 2
+                    | (self.use_last_used_move as u64) << 1
+                    | self.use_damage_dealt as u64,
+            )
+    }
+
+    pub fn update_zobrist_hash(&mut self) {
+        self.zobrist_hash = self.compute_zobrist_hash();
+    }
+
+    // XOR of the keys of every feature that `instruction` modifies, given the current state.
+    // Calling this before and after applying (or reversing) the instruction and XOR-ing both
+    // results into the hash removes the old values of those features and adds the new ones
+    pub(crate) fn instruction_zobrist_key(&self, instruction: &Instruction) -> u64 {
+        match instruction {
+            Instruction::Switch(i) => self.side_key(&i.side_ref, Side::active_index_key),
+            Instruction::ApplyVolatileStatus(i) => {
+                self.side_key(&i.side_ref, Side::volatile_statuses_key)
+            }
+            Instruction::RemoveVolatileStatus(i) => {
+                self.side_key(&i.side_ref, Side::volatile_statuses_key)
+            }
+            Instruction::Boost(i) => self.side_key(&i.side_ref, Side::boosts_key),
+            Instruction::ChangeSideCondition(i) => {
+                let side_number = side_number(&i.side_ref);
+                self.get_side_immutable(&i.side_ref)
+                    .side_condition_key(side_number, i.side_condition)
+            }
+            Instruction::ChangeVolatileStatusDuration(i) => {
+                self.side_key(&i.side_ref, Side::volatile_status_durations_key)
+            }
+            Instruction::ChangeWish(i) => self.side_key(&i.side_ref, Side::wish_key),
+            Instruction::DecrementWish(i) => self.side_key(&i.side_ref, Side::wish_key),
+            Instruction::SetFutureSight(i) => self.side_key(&i.side_ref, Side::future_sight_key),
+            Instruction::DecrementFutureSight(i) => {
+                self.side_key(&i.side_ref, Side::future_sight_key)
+            }
+            Instruction::DamageSubstitute(i) => {
+                self.side_key(&i.side_ref, Side::substitute_health_key)
+            }
+            Instruction::ChangeSubstituteHealth(i) => {
+                self.side_key(&i.side_ref, Side::substitute_health_key)
+            }
+            Instruction::ToggleSideOneForceSwitch => {
+                self.side_key(&SideReference::SideOne, Side::force_switch_key)
+            }
+            Instruction::ToggleSideTwoForceSwitch => {
+                self.side_key(&SideReference::SideTwo, Side::force_switch_key)
+            }
+            Instruction::SetSideOneMoveSecondSwitchOutMove(_) => self.side_key(
+                &SideReference::SideOne,
+                Side::switch_out_move_second_saved_move_key,
+            ),
+            Instruction::SetSideTwoMoveSecondSwitchOutMove(_) => self.side_key(
+                &SideReference::SideTwo,
+                Side::switch_out_move_second_saved_move_key,
+            ),
+            Instruction::ToggleBatonPassing(i) => {
+                self.side_key(&i.side_ref, Side::baton_passing_key)
+            }
+            Instruction::ToggleShedTailing(i) => self.side_key(&i.side_ref, Side::shed_tailing_key),
+            Instruction::SetLastUsedMove(i) => self.side_key(&i.side_ref, Side::last_used_move_key),
+            Instruction::ChangeDamageDealtDamage(i) => {
+                self.side_key(&i.side_ref, Side::damage_dealt_damage_key)
+            }
+            Instruction::ChangeDamageDealtMoveCatagory(i) => {
+                self.side_key(&i.side_ref, Side::damage_dealt_move_category_key)
+            }
+            Instruction::ToggleDamageDealtHitSubstitute(i) => {
+                self.side_key(&i.side_ref, Side::damage_dealt_hit_substitute_key)
+            }
+            Instruction::ChangeWeather(_) | Instruction::DecrementWeatherTurnsRemaining => {
+                self.weather_key()
+            }
+            Instruction::ChangeTerrain(_) | Instruction::DecrementTerrainTurnsRemaining => {
+                self.terrain_key()
+            }
+            Instruction::ToggleTrickRoom(_) | Instruction::DecrementTrickRoomTurnsRemaining => {
+                self.trick_room_key()
+            }
+            Instruction::ChangeStatus(i) => {
+                self.pokemon_key(&i.side_ref, i.pokemon_index, Pokemon::status_key)
+            }
+            Instruction::SetRestTurns(i) => {
+                self.pokemon_key(&i.side_ref, i.pokemon_index, Pokemon::rest_turns_key)
+            }
+            Instruction::SetSleepTurns(i) => {
+                self.pokemon_key(&i.side_ref, i.pokemon_index, Pokemon::sleep_turns_key)
+            }
+            Instruction::Damage(i) => self.active_pokemon_key(&i.side_ref, Pokemon::hp_key),
+            Instruction::Heal(i) => self.active_pokemon_key(&i.side_ref, Pokemon::hp_key),
+            Instruction::ChangeType(i) => self.active_pokemon_key(&i.side_ref, Pokemon::types_key),
+            Instruction::ChangeAbility(i) => {
+                self.active_pokemon_key(&i.side_ref, Pokemon::ability_key)
+            }
+            Instruction::ChangeItem(i) => self.active_pokemon_key(&i.side_ref, Pokemon::item_key),
+            Instruction::ChangeAttack(i) => {
+                self.active_pokemon_key(&i.side_ref, Pokemon::attack_key)
+            }
+            Instruction::ChangeDefense(i) => {
+                self.active_pokemon_key(&i.side_ref, Pokemon::defense_key)
+            }
+            Instruction::ChangeSpecialAttack(i) => {
+                self.active_pokemon_key(&i.side_ref, Pokemon::special_attack_key)
+            }
+            Instruction::ChangeSpecialDefense(i) => {
+                self.active_pokemon_key(&i.side_ref, Pokemon::special_defense_key)
+            }
+            Instruction::ChangeSpeed(i) => self.active_pokemon_key(&i.side_ref, Pokemon::speed_key),
+            Instruction::DecrementRestTurns(i) => {
+                self.active_pokemon_key(&i.side_ref, Pokemon::rest_turns_key)
+            }
+            Instruction::ToggleTerastallized(i) => {
+                self.active_pokemon_key(&i.side_ref, Pokemon::terastallized_key)
+            }
+            Instruction::FormeChange(i) => self.active_pokemon_key(&i.side_ref, Pokemon::id_key),
+            Instruction::EnableMove(i) => {
+                let side = self.get_side_immutable(&i.side_ref);
+                side.get_active_immutable().move_disabled_key(
+                    side_number(&i.side_ref),
+                    side.active_index,
+                    &i.move_index,
+                )
+            }
+            Instruction::DisableMove(i) => {
+                let side = self.get_side_immutable(&i.side_ref);
+                side.get_active_immutable().move_disabled_key(
+                    side_number(&i.side_ref),
+                    side.active_index,
+                    &i.move_index,
+                )
+            }
+            Instruction::DecrementPP(i) => {
+                let side = self.get_side_immutable(&i.side_ref);
+                side.get_active_immutable().move_pp_key(
+                    side_number(&i.side_ref),
+                    side.active_index,
+                    &i.move_index,
+                )
+            }
+        }
+    }
+
+    fn side_key(&self, side_ref: &SideReference, side_key: fn(&Side, u64) -> u64) -> u64 {
+        side_key(self.get_side_immutable(side_ref), side_number(side_ref))
+    }
+
+    fn pokemon_key(
+        &self,
+        side_ref: &SideReference,
+        pokemon_index: PokemonIndex,
+        pokemon_key: fn(&Pokemon, u64, PokemonIndex) -> u64,
+    ) -> u64 {
+        pokemon_key(
+            &self.get_side_immutable(side_ref).pokemon[pokemon_index],
+            side_number(side_ref),
+            pokemon_index,
+        )
+    }
+
+    fn active_pokemon_key(
+        &self,
+        side_ref: &SideReference,
+        pokemon_key: fn(&Pokemon, u64, PokemonIndex) -> u64,
+    ) -> u64 {
+        let active_index = self.get_side_immutable(side_ref).active_index;
+        self.pokemon_key(side_ref, active_index, pokemon_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::choices::Choices;
+    use crate::engine::generate_instructions::generate_instructions_from_move_pair;
+    use crate::instruction::{BoostInstruction, DamageInstruction, Instruction, SwitchInstruction};
+    use crate::state::{
+        PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, SideReference, State,
+    };
+
+    fn state_with_moves() -> State {
+        let mut state = State::default();
+        let side_one_active = state.side_one.get_active();
+        side_one_active.replace_move(PokemonMoveIndex::M0, Choices::TACKLE, state.generation);
+        side_one_active.replace_move(PokemonMoveIndex::M1, Choices::SWORDSDANCE, state.generation);
+        side_one_active.replace_move(PokemonMoveIndex::M2, Choices::TOXIC, state.generation);
+        side_one_active.replace_move(PokemonMoveIndex::M3, Choices::SUBSTITUTE, state.generation);
+        let side_two_active = state.side_two.get_active();
+        side_two_active.replace_move(PokemonMoveIndex::M0, Choices::WATERGUN, state.generation);
+        side_two_active.replace_move(PokemonMoveIndex::M1, Choices::REFLECT, state.generation);
+        side_two_active.replace_move(PokemonMoveIndex::M2, Choices::LEECHSEED, state.generation);
+        state.update_zobrist_hash();
+        state
+    }
+
+    #[test]
+    fn test_hash_is_kept_up_to_date_by_applying_and_reversing_instructions() {
+        let mut state = state_with_moves();
+        for _ in 0..10 {
+            let (side_one_options, side_two_options) = state.get_all_options();
+            let hash_before = state.zobrist_hash;
+            let mut next_turn = None;
+            for side_one_move in side_one_options.iter() {
+                for side_two_move in side_two_options.iter() {
+                    let instructions = generate_instructions_from_move_pair(
+                        &mut state,
+                        side_one_move,
+                        side_two_move,
+                        true,
+                    );
+                    assert_eq!(hash_before, state.zobrist_hash);
+                    for instruction in instructions.iter() {
+                        state.apply_instructions(&instruction.instruction_list);
+                        assert_eq!(state.compute_zobrist_hash(), state.zobrist_hash);
+                        state.reverse_instructions(&instruction.instruction_list);
+                        assert_eq!(hash_before, state.zobrist_hash);
+                    }
+                    if next_turn.is_none() {
+                        next_turn = instructions.into_iter().last();
+                    }
+                }
+            }
+            state.apply_instructions(&next_turn.unwrap().instruction_list);
+            if state.battle_is_over() != 0.0 {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_state_reached_by_different_instruction_orders_has_the_same_hash() {
+        let boost = Instruction::Boost(BoostInstruction {
+            side_ref: SideReference::SideOne,
+            stat: PokemonBoostableStat::Attack,
+            amount: 1,
+        });
+        let damage = Instruction::Damage(DamageInstruction {
+            side_ref: SideReference::SideTwo,
+            damage_amount: 5,
+        });
+
+        let mut state_one = State::default();
+        state_one.apply_instructions(&vec![boost.clone(), damage.clone()]);
+        let mut state_two = State::default();
+        state_two.apply_instructions(&vec![damage, boost]);
+
+        assert_eq!(state_one.zobrist_hash, state_two.zobrist_hash);
+        assert_ne!(State::default().zobrist_hash, state_one.zobrist_hash);
+    }
+
+    #[test]
+    fn test_switching_out_and_back_in_restores_the_hash() {
+        let mut state = State::default();
+        let original_hash = state.zobrist_hash;
+        state.apply_instructions(&vec![
+            Instruction::Switch(SwitchInstruction {
+                side_ref: SideReference::SideOne,
+                previous_index: PokemonIndex::P0,
+                next_index: PokemonIndex::P1,
+            }),
+            Instruction::Switch(SwitchInstruction {
+                side_ref: SideReference::SideOne,
+                previous_index: PokemonIndex::P1,
+                next_index: PokemonIndex::P0,
+            }),
+        ]);
+        assert_eq!(original_hash, state.zobrist_hash);
+    }
+
+    #[test]
+    fn test_states_on_opposite_sides_have_different_hashes() {
+        let mut state_one = State::default();
+        state_one.side_one.get_active().hp -= 1;
+        state_one.update_zobrist_hash();
+        let mut state_two = State::default();
+        state_two.side_two.get_active().hp -= 1;
+        state_two.update_zobrist_hash();
+        assert_ne!(state_one.zobrist_hash, state_two.zobrist_hash);
+    }
+}
@@ -1,11 +1,9 @@
-#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
-
-use poke_engine::choices::{Choices, MoveCategory, MOVES};
-use poke_engine::engine::abilities::{Abilities, WEATHER_ABILITY_TURNS};
-use poke_engine::engine::damage_calc::CRIT_MULTIPLIER;
+use poke_engine::choices::{moves, Choices, MoveCategory, MOVES};
+use poke_engine::engine::abilities::{weather_ability_turns, Abilities};
+use poke_engine::engine::damage_calc::crit_multiplier;
 use poke_engine::engine::generate_instructions::{
-    generate_instructions_from_move_pair, BASE_CRIT_CHANCE, CONSECUTIVE_PROTECT_CHANCE,
-    MAX_SLEEP_TURNS,
+    base_crit_chance, consecutive_protect_chance, generate_instructions_from_move_pair,
+    max_sleep_turns,
 };
 use poke_engine::engine::items::Items;
 use poke_engine::engine::state::{MoveChoice, PokemonVolatileStatus, Terrain, Weather};
@@ -26,11 +24,11 @@ use poke_engine::instruction::{
 };
 use poke_engine::pokemon::PokemonName;
 use poke_engine::state::{
-    pokemon_index_iter, LastUsedMove, Move, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex,
-    PokemonSideCondition, PokemonStatus, PokemonType, SideReference, State, StateWeather,
+    pokemon_index_iter, Generation, LastUsedMove, Move, PokemonBoostableStat, PokemonIndex,
+    PokemonMoveIndex, PokemonNature, PokemonSideCondition, PokemonStatus, PokemonType,
+    SideReference, State, StateWeather, STATE_STRING_VERSION,
 };
 
-#[cfg(feature = "terastallization")]
 use poke_engine::instruction::ToggleTerastallizedInstruction;
 
 pub fn generate_instructions_with_state_assertion(
@@ -54,11 +52,11 @@ fn set_moves_on_pkmn_and_call_generate_instructions(
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let instructions = generate_instructions_with_state_assertion(
         state,
@@ -100,11 +98,11 @@ fn test_branch_on_crit() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -116,17 +114,18 @@ fn test_branch_on_crit() {
     let expected_damage = 32;
     let expected_instructions = vec![
         StateInstructions {
-            percentage: 100.0 * (1.0 - BASE_CRIT_CHANCE),
+            percentage: 100.0 * (1.0 - base_crit_chance(Generation::default())),
             instruction_list: vec![Instruction::Damage(DamageInstruction {
                 side_ref: SideReference::SideTwo,
                 damage_amount: expected_damage,
             })],
         },
         StateInstructions {
-            percentage: 100.0 * BASE_CRIT_CHANCE,
+            percentage: 100.0 * base_crit_chance(Generation::default()),
             instruction_list: vec![Instruction::Damage(DamageInstruction {
                 side_ref: SideReference::SideTwo,
-                damage_amount: (CRIT_MULTIPLIER * expected_damage as f32).floor() as i16,
+                damage_amount: (crit_multiplier(Generation::default()) * expected_damage as f32)
+                    .floor() as i16,
             })],
         },
     ];
@@ -143,11 +142,11 @@ fn test_highcrit_move() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -173,7 +172,8 @@ fn test_highcrit_move() {
             percentage: 11.875,
             instruction_list: vec![Instruction::Damage(DamageInstruction {
                 side_ref: SideReference::SideTwo,
-                damage_amount: (CRIT_MULTIPLIER * expected_damage as f32).round() as i16,
+                damage_amount: (crit_multiplier(Generation::default()) * expected_damage as f32)
+                    .round() as i16,
             })],
         },
     ];
@@ -191,11 +191,11 @@ fn test_wickedblow_always_crits_without_a_branch() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -219,11 +219,11 @@ fn test_wickedblow_always_ignores_defensive_boost_on_opponent_because_of_crit()
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -247,11 +247,11 @@ fn test_wickedblow_cannot_crit_on_shellarmor() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -274,11 +274,11 @@ fn test_surgingstrikes_always_crits_without_a_branch() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -300,11 +300,11 @@ fn test_crit_does_not_overkill() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -316,14 +316,14 @@ fn test_crit_does_not_overkill() {
     let expected_damage = 48;
     let expected_instructions = vec![
         StateInstructions {
-            percentage: (1.0 - BASE_CRIT_CHANCE) * 100.0,
+            percentage: (1.0 - base_crit_chance(Generation::default())) * 100.0,
             instruction_list: vec![Instruction::Damage(DamageInstruction {
                 side_ref: SideReference::SideTwo,
                 damage_amount: expected_damage,
             })],
         },
         StateInstructions {
-            percentage: BASE_CRIT_CHANCE * 100.0,
+            percentage: base_crit_chance(Generation::default()) * 100.0,
             instruction_list: vec![Instruction::Damage(DamageInstruction {
                 side_ref: SideReference::SideTwo,
                 damage_amount: 65,
@@ -366,6 +366,25 @@ fn test_same_speed_branch() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
+#[test]
+fn test_same_speed_branches_with_the_same_outcome_are_combined() {
+    let mut state = State::default();
+    state.side_one.get_active().speed = 100;
+    state.side_two.get_active().speed = 100;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::SPLASH,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
 #[test]
 fn test_same_speed_branch_with_residuals() {
     let mut state = State::default();
@@ -477,9 +496,9 @@ fn test_same_speed_branch_with_residuals_for_both_sides() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_branch_when_a_roll_can_kill() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().hp = 50;
 
     let move_one = Choices::TACKLE;
@@ -487,11 +506,11 @@ fn test_branch_when_a_roll_can_kill() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -523,9 +542,9 @@ fn test_branch_when_a_roll_can_kill() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_branch_when_a_roll_can_kill_on_the_low_side() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().hp = 45;
 
     let move_one = Choices::TACKLE;
@@ -533,11 +552,11 @@ fn test_branch_when_a_roll_can_kill_on_the_low_side() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -577,11 +596,11 @@ fn test_min_damage_killing_does_not_branch() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -1288,10 +1307,10 @@ fn test_protect_stops_after_damage_hit_callback() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
-#[cfg(feature = "gen8")]
 #[test]
 fn test_knockoff_removing_item() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN8);
     state.side_one.get_active().item = Items::LEFTOVERS;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -1317,10 +1336,10 @@ fn test_knockoff_removing_item() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
-#[cfg(feature = "gen8")]
 #[test]
 fn test_knockoff_cannot_remove_arceus_plate() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN8);
     state.side_one.get_active().id = PokemonName::ARCEUSGHOST;
     state.side_one.get_active().item = Items::SPOOKYPLATE;
 
@@ -1341,9 +1360,9 @@ fn test_knockoff_cannot_remove_arceus_plate() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_knockoff_cannot_remove_ogerpon_mask_and_does_not_give_boost() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().id = PokemonName::OGERPONCORNERSTONE;
     state.side_one.get_active().item = Items::CORNERSTONEMASK;
 
@@ -1363,10 +1382,10 @@ fn test_knockoff_cannot_remove_ogerpon_mask_and_does_not_give_boost() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
-#[cfg(feature = "gen8")]
 #[test]
 fn test_knockoff_boosts_damage_but_cannot_remove_if_sub_is_hit() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN8);
     state.side_one.get_active().item = Items::LEFTOVERS;
     state
         .side_one
@@ -1390,10 +1409,10 @@ fn test_knockoff_boosts_damage_but_cannot_remove_if_sub_is_hit() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
-#[cfg(any(feature = "gen9", feature = "gen8", feature = "gen7", feature = "gen6"))]
 #[test]
 fn test_knockoff_boosts_damage_but_cannot_remove_if_stickyhold() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().item = Items::LEFTOVERS;
     state.side_one.get_active().ability = Abilities::STICKYHOLD;
 
@@ -1420,9 +1439,9 @@ fn test_knockoff_boosts_damage_but_cannot_remove_if_stickyhold() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_move_that_goes_through_protect() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -1662,9 +1681,9 @@ fn test_moxie_boost() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_gen9_battlebond_boost() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().hp = 1;
     state.side_one.get_active().ability = Abilities::BATTLEBOND;
 
@@ -1702,9 +1721,9 @@ fn test_gen9_battlebond_boost() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_battlebond_gen9_does_not_overboost() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().hp = 1;
     state.side_one.attack_boost = 6;
     state.side_one.get_active().ability = Abilities::BATTLEBOND;
@@ -2393,9 +2412,9 @@ fn test_banefulbunker_poisons() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_burning_bulwark_burns() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -2435,9 +2454,9 @@ fn test_burning_bulwark_burns() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_bypassing_protect_does_not_inflict_burn_against_burning_bulwark() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -2602,7 +2621,7 @@ fn test_protect_side_condition_is_removed() {
 fn test_protect_for_second_turn_in_a_row() {
     let mut state = State::default();
     state.side_one.side_conditions.protect = 1;
-    let success_chance = CONSECUTIVE_PROTECT_CHANCE.powi(1);
+    let success_chance = consecutive_protect_chance(Generation::default()).powi(1);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -2683,7 +2702,7 @@ fn test_regular_move_with_protect_side_condition() {
 fn test_protect_for_third_turn_in_a_row() {
     let mut state = State::default();
     state.side_one.side_conditions.protect = 2;
-    let success_chance = CONSECUTIVE_PROTECT_CHANCE.powi(2);
+    let success_chance = consecutive_protect_chance(Generation::default()).powi(2);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -2735,7 +2754,7 @@ fn test_consecutive_protect_while_paralyzed() {
     state.side_one.side_conditions.protect = 1;
 
     // chance to move is chance to not be fully paralyzed (0.75) * chance to double-protect
-    let chance_to_move = 0.75 * CONSECUTIVE_PROTECT_CHANCE.powi(1);
+    let chance_to_move = 0.75 * consecutive_protect_chance(Generation::default()).powi(1);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -3230,9 +3249,9 @@ fn test_destinybond_kills_on_knockout() {
 }
 
 #[test]
-#[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5", feature = "gen6"))]
 fn test_earlier_gen_nothing_happens_if_destinybond_is_used_while_already_having_destinybond() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN6);
     state.side_one.get_active().speed = 150;
     state
         .side_one
@@ -3253,9 +3272,9 @@ fn test_earlier_gen_nothing_happens_if_destinybond_is_used_while_already_having_
 }
 
 #[test]
-#[cfg(any(feature = "gen7", feature = "gen8", feature = "gen9",))]
 fn test_later_gen_destinybond_cannot_be_used_twice_in_a_row() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().speed = 150;
     state
         .side_one
@@ -3558,6 +3577,29 @@ fn test_minior_formechange() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
+#[test]
+fn test_minior_formechange_uses_the_pokemons_ivs() {
+    let mut state = State::default();
+    state.side_one.get_active().hp = 60;
+    state.side_one.get_active().ability = Abilities::SHIELDSDOWN;
+    state.side_one.get_active().id = PokemonName::MINIORMETEOR;
+    state.side_one.get_active().ivs = (31, 0, 31, 31, 31, 31);
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::SPLASH,
+        Choices::TACKLE,
+    );
+
+    // 0 attack IVs give minior-core 31 less attack than test_minior_formechange
+    assert!(vec_of_instructions[0]
+        .instruction_list
+        .contains(&Instruction::ChangeAttack(ChangeStatInstruction {
+            side_ref: SideReference::SideOne,
+            amount: 126,
+        })));
+}
+
 #[test]
 fn test_palafin_formechange_on_switchout() {
     let mut state = State::default();
@@ -4456,9 +4498,9 @@ fn test_suckerpunch_versus_non_attacking_move() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_suckerpunch_versus_attacking_move() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -4848,7 +4890,6 @@ fn test_roughskin_damage_taken_on_multihit_move() {
 }
 
 #[test]
-#[cfg(not(feature = "gen5"))]
 fn test_flyinggem_and_acrobatics_together() {
     let mut state = State::default();
     state.side_one.get_active().item = Items::FLYINGGEM;
@@ -4883,9 +4924,9 @@ fn test_flyinggem_and_acrobatics_together() {
 }
 
 #[test]
-#[cfg(feature = "gen5")]
 fn test_flyinggem_and_acrobatics_together_gen5() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN5);
     state.side_one.get_active().item = Items::FLYINGGEM;
     state.side_two.get_active().hp = 400;
     state.side_two.get_active().maxhp = 400;
@@ -4918,7 +4959,6 @@ fn test_flyinggem_and_acrobatics_together_gen5() {
 }
 
 #[test]
-#[cfg(not(feature = "gen5"))]
 fn test_normalgem_boosting_tackle() {
     let mut state = State::default();
     state.side_one.get_active().item = Items::NORMALGEM;
@@ -5567,9 +5607,9 @@ fn test_rockyhelmet_does_not_overkill() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_choiceband_locking() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().item = Items::CHOICEBAND;
     state.side_one.get_active().moves[&PokemonMoveIndex::M0] = Move {
         id: Choices::WILLOWISP,
@@ -5764,10 +5804,11 @@ fn test_locked_moves_unlock_on_switchout() {
     state.side_one.get_active().moves[&PokemonMoveIndex::M2].disabled = true;
     state.side_one.get_active().moves[&PokemonMoveIndex::M3].disabled = true;
 
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SPLASH,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
         &mut state,
@@ -5801,9 +5842,9 @@ fn test_locked_moves_unlock_on_switchout() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_fighting_move_with_blackbelt() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().hp = 300;
     state.side_two.get_active().maxhp = 300;
     state.side_one.get_active().item = Items::BLACKBELT;
@@ -5825,9 +5866,9 @@ fn test_fighting_move_with_blackbelt() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_expert_belt_boost() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().hp = 300;
     state.side_two.get_active().maxhp = 300;
     state.side_one.get_active().item = Items::EXPERTBELT;
@@ -5893,9 +5934,9 @@ fn test_weatherball_in_sun() {
 }
 
 #[test]
-#[cfg(any(feature = "gen9"))]
 fn test_terrainpulse_gen9() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.terrain.terrain_type = Terrain::ELECTRICTERRAIN;
     state.side_two.get_active().maxhp = 300;
     state.side_two.get_active().hp = 300;
@@ -5936,9 +5977,9 @@ fn test_terablast_into_ghost_makes_normal_immune() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_tera_blast_stellar_into_ghost_type_does_damage() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().terastallized = true;
     state.side_one.get_active().tera_type = PokemonType::STELLAR;
     state.side_two.get_active().tera_type = PokemonType::GHOST;
@@ -5972,9 +6013,9 @@ fn test_tera_blast_stellar_into_ghost_type_does_damage() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_tera_blast_stellar_into_terastallized_does_double_damage() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().terastallized = true;
     state.side_one.get_active().tera_type = PokemonType::STELLAR;
     state.side_two.get_active().hp = 500;
@@ -6010,9 +6051,9 @@ fn test_tera_blast_stellar_into_terastallized_does_double_damage() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_tera_blast_stellar_with_contrary_increases_offensive_stats() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().terastallized = true;
     state.side_one.get_active().tera_type = PokemonType::STELLAR;
     state.side_two.get_active().hp = 500;
@@ -6074,9 +6115,9 @@ fn test_terablast_becomes_physical() {
 }
 
 #[test]
-#[cfg(any(feature = "gen7"))]
 fn test_terrainpulse_gen7() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN7);
     state.terrain.terrain_type = Terrain::ELECTRICTERRAIN;
     state.side_two.get_active().maxhp = 300;
     state.side_two.get_active().hp = 300;
@@ -6154,9 +6195,9 @@ fn test_batonpass_with_boosts() {
 }
 
 #[test]
-#[cfg(feature = "gen4")]
 fn test_simple_in_gen4_doubles_effective_boost() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN4);
     state.side_one.attack_boost = 1; // should behave as +2 in gen4
 
     let regular_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -7761,28 +7802,28 @@ fn test_noretreat_traps_pokemon() {
         .insert(PokemonVolatileStatus::NORETREAT);
 
     let (side_one_moves, _side_two_moves) = state.get_all_options();
-
-    #[cfg(feature = "terastallization")]
     assert_eq!(
         vec![
             MoveChoice::Move(PokemonMoveIndex::M0),
-            MoveChoice::MoveTera(PokemonMoveIndex::M0),
             MoveChoice::Move(PokemonMoveIndex::M1),
-            MoveChoice::MoveTera(PokemonMoveIndex::M1),
             MoveChoice::Move(PokemonMoveIndex::M2),
-            MoveChoice::MoveTera(PokemonMoveIndex::M2),
             MoveChoice::Move(PokemonMoveIndex::M3),
-            MoveChoice::MoveTera(PokemonMoveIndex::M3),
         ],
         side_one_moves
     );
-    #[cfg(not(feature = "terastallization"))]
+
+    state.set_generation(Generation::GEN9);
+    let (side_one_moves, _side_two_moves) = state.get_all_options();
     assert_eq!(
         vec![
             MoveChoice::Move(PokemonMoveIndex::M0),
+            MoveChoice::MoveTera(PokemonMoveIndex::M0),
             MoveChoice::Move(PokemonMoveIndex::M1),
+            MoveChoice::MoveTera(PokemonMoveIndex::M1),
             MoveChoice::Move(PokemonMoveIndex::M2),
+            MoveChoice::MoveTera(PokemonMoveIndex::M2),
             MoveChoice::Move(PokemonMoveIndex::M3),
+            MoveChoice::MoveTera(PokemonMoveIndex::M3),
         ],
         side_one_moves
     );
@@ -7807,7 +7848,6 @@ fn test_meteorbeam_volatile_only_allows_meteor_beam_to_be_used() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_assaultvest_prevents_status_move() {
     let mut state = State::default();
     state.side_one.pokemon[PokemonIndex::P1].hp = 0;
@@ -7821,28 +7861,40 @@ fn test_assaultvest_prevents_status_move() {
         id: Choices::TOXIC,
         disabled: false,
         pp: 35,
-        choice: MOVES.get(&Choices::TOXIC).unwrap().clone(),
+        choice: moves(state.generation)
+            .get(&Choices::TOXIC)
+            .unwrap()
+            .clone(),
     };
 
     state.side_one.get_active().moves[&PokemonMoveIndex::M1] = Move {
         id: Choices::TACKLE,
         disabled: false,
         pp: 35,
-        choice: MOVES.get(&Choices::TACKLE).unwrap().clone(),
+        choice: moves(state.generation)
+            .get(&Choices::TACKLE)
+            .unwrap()
+            .clone(),
     };
 
     state.side_one.get_active().moves[&PokemonMoveIndex::M2] = Move {
         id: Choices::WATERGUN,
         disabled: false,
         pp: 35,
-        choice: MOVES.get(&Choices::TACKLE).unwrap().clone(),
+        choice: moves(state.generation)
+            .get(&Choices::TACKLE)
+            .unwrap()
+            .clone(),
     };
 
     state.side_one.get_active().moves[&PokemonMoveIndex::M3] = Move {
         id: Choices::EMBER,
         disabled: false,
         pp: 35,
-        choice: MOVES.get(&Choices::TACKLE).unwrap().clone(),
+        choice: moves(state.generation)
+            .get(&Choices::TACKLE)
+            .unwrap()
+            .clone(),
     };
 
     let (side_one_moves, _) = state.get_all_options();
@@ -7910,7 +7962,6 @@ fn test_taunt_prevents_status_move() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_cannot_use_bloodmoon_after_using_bloodmoon() {
     let mut state = State::default();
 
@@ -7955,9 +8006,9 @@ fn test_cannot_use_bloodmoon_after_using_bloodmoon() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_terastallization_side_one() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.pokemon[PokemonIndex::P0].terastallized = true; // s2 cannot tera
 
     let (side_one_moves, side_two_moves) = state.get_all_options();
@@ -8000,7 +8051,6 @@ fn test_terastallization_side_one() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_mega_evolve_options_side_one() {
     let mut state = State::default();
     state.side_one.get_active().id = PokemonName::VENUSAUR;
@@ -8044,7 +8094,6 @@ fn test_mega_evolve_options_side_one() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_mega_evolve_options_side_two() {
     let mut state = State::default();
     state.side_two.get_active().id = PokemonName::VENUSAUR;
@@ -8088,9 +8137,9 @@ fn test_mega_evolve_options_side_two() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_terastallization_side_two() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.pokemon[PokemonIndex::P0].terastallized = true; // s1 cannot tera
 
     let (side_one_moves, side_two_moves) = state.get_all_options();
@@ -8133,7 +8182,6 @@ fn test_terastallization_side_two() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_cannot_use_gigatonhammer_after_using_gigatonhammer() {
     let mut state = State::default();
 
@@ -8178,7 +8226,6 @@ fn test_cannot_use_gigatonhammer_after_using_gigatonhammer() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_can_use_gigatonhammer_after_using_switch() {
     let mut state = State::default();
 
@@ -8223,7 +8270,6 @@ fn test_can_use_gigatonhammer_after_using_switch() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_can_use_bloodmoon_after_using_switch() {
     let mut state = State::default();
 
@@ -8268,7 +8314,6 @@ fn test_can_use_bloodmoon_after_using_switch() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_arenatrap_traps_opponent() {
     let mut state = State::default();
     state.side_one.get_active().ability = Abilities::ARENATRAP;
@@ -8303,7 +8348,6 @@ fn test_arenatrap_traps_opponent() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_arenatrap_does_not_trap_flying() {
     let mut state = State::default();
     state.side_one.get_active().ability = Abilities::ARENATRAP;
@@ -8343,7 +8387,6 @@ fn test_arenatrap_does_not_trap_flying() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_arenatrap_does_not_trap_ghost() {
     let mut state = State::default();
     state.side_one.get_active().ability = Abilities::ARENATRAP;
@@ -8383,7 +8426,6 @@ fn test_arenatrap_does_not_trap_ghost() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_arenatrap_does_not_trap_shedshell() {
     let mut state = State::default();
     state.side_one.get_active().ability = Abilities::ARENATRAP;
@@ -8445,7 +8487,6 @@ fn test_turn_after_switch_out_move_other_side_does_nothing() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_lockedmove_prevents_switches() {
     let mut state = State::default();
     state
@@ -8466,7 +8507,6 @@ fn test_lockedmove_prevents_switches() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_zero_pp_move_cannot_be_used() {
     let mut state = State::default();
     state.side_one.get_active().moves[&PokemonMoveIndex::M0].pp = 0;
@@ -8671,9 +8711,9 @@ fn test_rock_spdef_in_sand() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_rock_does_not_get_spdef_when_terastallized_out_of_rock() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.weather.weather_type = Weather::SAND;
     state.side_one.get_active().types.0 = PokemonType::ROCK;
     state.side_one.get_active().terastallized = true;
@@ -8706,9 +8746,9 @@ fn test_rock_does_not_get_spdef_when_terastallized_out_of_rock() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_low_bp_move_boost_when_terastallizing() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().terastallized = true;
     state.side_one.get_active().tera_type = PokemonType::WATER;
 
@@ -8754,15 +8794,17 @@ fn test_ice_def_in_snow() {
 fn test_pressure_caused_double_pp_decrement() {
     let mut state = State::default();
     state.side_two.get_active().ability = Abilities::PRESSURE;
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
     state.side_one.get_active().moves[&PokemonMoveIndex::M0].pp = 1;
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
     state.side_two.get_active().moves[&PokemonMoveIndex::M0].pp = 1;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -8801,15 +8843,17 @@ fn test_pressure_caused_double_pp_decrement() {
 fn test_pressure_does_not_cause_pp_decrement_if_move_targets_self() {
     let mut state = State::default();
     state.side_two.get_active().ability = Abilities::PRESSURE;
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::RECOVER);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::RECOVER,
+        state.generation,
+    );
     state.side_one.get_active().moves[&PokemonMoveIndex::M0].pp = 5;
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SPLASH,
+        state.generation,
+    );
     state.side_two.get_active().moves[&PokemonMoveIndex::M0].pp = 1;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -8839,15 +8883,17 @@ fn test_pressure_does_not_cause_pp_decrement_if_move_targets_self() {
 #[test]
 fn test_pp_decremented() {
     let mut state = State::default();
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
     state.side_one.get_active().moves[&PokemonMoveIndex::M0].pp = 1;
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
     state.side_two.get_active().moves[&PokemonMoveIndex::M0].pp = 1;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -8885,15 +8931,17 @@ fn test_pp_decremented() {
 #[test]
 fn test_pp_not_decremented_when_flinched() {
     let mut state = State::default();
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
     state.side_one.get_active().moves[&PokemonMoveIndex::M0].pp = 1;
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::IRONHEAD);
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::IRONHEAD,
+        state.generation,
+    );
     state.side_two.get_active().moves[&PokemonMoveIndex::M0].pp = 1;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -9624,9 +9672,9 @@ fn test_healing_wish_activates_if_only_statused() {
 }
 
 #[test]
-#[cfg(any(feature = "gen8", feature = "gen9"))]
 fn test_healing_wish_does_not_consume_on_healthy_pkmn() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.side_conditions.healing_wish = 1;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -9647,9 +9695,9 @@ fn test_healing_wish_does_not_consume_on_healthy_pkmn() {
 }
 
 #[test]
-#[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6", feature = "gen7"))]
 fn test_healing_wish_is_consumed_even_if_it_does_nothing() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN7);
     state.side_one.side_conditions.healing_wish = 1;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -9748,9 +9796,9 @@ fn test_cannot_use_wish_when_already_active() {
 }
 
 #[test]
-#[cfg(not(feature = "gen4"))]
 fn test_wish_healing_end_of_turn() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.wish = (1, 50);
     state.side_one.get_active().hp = 1;
     state.side_one.get_active().maxhp = 400;
@@ -9845,9 +9893,9 @@ fn test_wish_does_not_produce_heal_instruction_when_at_maxhp() {
 }
 
 #[test]
-#[cfg(feature = "gen4")]
 fn test_wish_in_gen4_uses_target_pkmn_maxhp() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN4);
     state.side_one.wish = (1, 100);
     state.side_one.get_active().hp = 1;
     state.side_one.get_active().maxhp = 100;
@@ -10561,22 +10609,25 @@ fn test_guaranteed_to_stay_asleep_sleeptalk_move_when_not_rested() {
     let mut state = State::default();
     state.side_one.get_active().hp = 50;
 
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SLEEPTALK,
+        state.generation,
+    );
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SLEEPTALK);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::REST);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M2, Choices::TACKLE);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M3, Choices::CURSE);
+        .replace_move(PokemonMoveIndex::M1, Choices::REST, state.generation);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M2,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M3,
+        Choices::CURSE,
+        state.generation,
+    );
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -10664,29 +10715,32 @@ fn test_guaranteed_to_stay_asleep_sleeptalk_move_when_not_rested() {
 }
 
 #[test]
-#[cfg(feature = "gen4")]
 fn test_small_chance_to_awaken_sleeptalk_move_when_not_rested() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN4);
     state.side_one.get_active().hp = 50;
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().sleep_turns = 1;
 
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SLEEPTALK,
+        state.generation,
+    );
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SLEEPTALK);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::REST);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M2, Choices::TACKLE);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M3, Choices::CURSE);
+        .replace_move(PokemonMoveIndex::M1, Choices::REST, state.generation);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M2,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M3,
+        Choices::CURSE,
+        state.generation,
+    );
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -10771,29 +10825,32 @@ fn test_small_chance_to_awaken_sleeptalk_move_when_not_rested() {
 }
 
 #[test]
-#[cfg(feature = "gen4")]
 fn test_large_chance_to_awaken_sleeptalk_move_when_not_rested() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN4);
     state.side_one.get_active().hp = 50;
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().sleep_turns = 3;
 
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SLEEPTALK,
+        state.generation,
+    );
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SLEEPTALK);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::REST);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M2, Choices::TACKLE);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M3, Choices::CURSE);
+        .replace_move(PokemonMoveIndex::M1, Choices::REST, state.generation);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M2,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M3,
+        Choices::CURSE,
+        state.generation,
+    );
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -11055,9 +11112,9 @@ fn test_using_sleeppowder_as_faster_pkmn() {
 }
 
 #[test]
-#[cfg(feature = "gen4")]
 fn test_gen4_one_turn_asleep_trying_to_wake_up() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN4);
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().sleep_turns = 1;
 
@@ -11103,9 +11160,9 @@ fn test_gen4_one_turn_asleep_trying_to_wake_up() {
 }
 
 #[test]
-#[cfg(feature = "gen5")]
 fn test_gen5_switchout_while_sleep_resets_rest_turns() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN5);
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().rest_turns = 1;
 
@@ -11135,9 +11192,9 @@ fn test_gen5_switchout_while_sleep_resets_rest_turns() {
 }
 
 #[test]
-#[cfg(feature = "gen5")]
 fn test_gen5_switchout_while_sleep_resets_sleep_turns() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN5);
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().sleep_turns = 1;
 
@@ -11167,9 +11224,9 @@ fn test_gen5_switchout_while_sleep_resets_sleep_turns() {
 }
 
 #[test]
-#[cfg(feature = "gen5")]
 fn test_gen5_one_turn_asleep_trying_to_wake_up() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN5);
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().sleep_turns = 1;
 
@@ -11215,9 +11272,9 @@ fn test_gen5_one_turn_asleep_trying_to_wake_up() {
 }
 
 #[test]
-#[cfg(feature = "gen5")]
 fn test_gen5_guaranteed_wake_up() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN5);
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().sleep_turns = 3;
 
@@ -11375,9 +11432,9 @@ fn test_doubleshock_fails_when_not_electric_type() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_tera_electric_always_allows_doubleshock_with_no_typechange_volatile() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().types = (PokemonType::NORMAL, PokemonType::TYPELESS);
     state.side_one.get_active().tera_type = PokemonType::ELECTRIC;
     state.side_one.get_active().terastallized = true;
@@ -11399,9 +11456,9 @@ fn test_tera_electric_always_allows_doubleshock_with_no_typechange_volatile() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_basic_protean() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().types = (PokemonType::WATER, PokemonType::DARK);
     state.side_one.get_active().ability = Abilities::PROTEAN;
 
@@ -11433,9 +11490,9 @@ fn test_basic_protean() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_gen9_protean_does_not_activate_when_already_typechanged() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().types = (PokemonType::NORMAL, PokemonType::TYPELESS);
     state.side_one.get_active().base_types = (PokemonType::WATER, PokemonType::DARK);
     state
@@ -11461,9 +11518,9 @@ fn test_gen9_protean_does_not_activate_when_already_typechanged() {
 }
 
 #[test]
-#[cfg(any(feature = "gen8", feature = "gen7", feature = "gen6"))]
 fn test_gen6_gen7_gen8_protean_does_activate_when_already_typechanged() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN8);
     state.side_one.get_active().types = (PokemonType::NORMAL, PokemonType::TYPELESS);
     state.side_one.get_active().base_types = (PokemonType::WATER, PokemonType::DARK);
     state
@@ -11497,9 +11554,9 @@ fn test_gen6_gen7_gen8_protean_does_activate_when_already_typechanged() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_protean_does_not_change_type_if_already_has_type() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().types = (PokemonType::WATER, PokemonType::DARK);
     state.side_one.get_active().ability = Abilities::PROTEAN;
 
@@ -11748,15 +11805,9 @@ fn test_burnup_removes_type_1_fire() {
 }
 
 #[test]
-#[cfg(any(
-    feature = "gen5",
-    feature = "gen6",
-    feature = "gen7",
-    feature = "gen8",
-    feature = "gen9"
-))]
 fn test_fast_taunt_gets_applied_and_duration_increments() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.pokemon.pkmn[0].speed = 150;
     state.side_two.pokemon.pkmn[0].speed = 100;
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -11783,15 +11834,9 @@ fn test_fast_taunt_gets_applied_and_duration_increments() {
 }
 
 #[test]
-#[cfg(any(
-    feature = "gen5",
-    feature = "gen6",
-    feature = "gen7",
-    feature = "gen8",
-    feature = "gen9"
-))]
 fn test_slow_taunt_gets_applied_and_duration_does_not_increment() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.pokemon.pkmn[0].speed = 100;
     state.side_two.pokemon.pkmn[0].speed = 150;
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -11813,15 +11858,9 @@ fn test_slow_taunt_gets_applied_and_duration_does_not_increment() {
 }
 
 #[test]
-#[cfg(any(
-    feature = "gen5",
-    feature = "gen6",
-    feature = "gen7",
-    feature = "gen8",
-    feature = "gen9"
-))]
 fn test_taunt_volatile_is_removed_end_of_turn_when_it_would_reach_3() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.volatile_status_durations.taunt = 2;
     state
         .side_one
@@ -11852,15 +11891,9 @@ fn test_taunt_volatile_is_removed_end_of_turn_when_it_would_reach_3() {
 }
 
 #[test]
-#[cfg(any(
-    feature = "gen5",
-    feature = "gen6",
-    feature = "gen7",
-    feature = "gen8",
-    feature = "gen9"
-))]
 fn test_taunt_re_enables_disabled_moves_when_being_removed() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.volatile_status_durations.taunt = 2;
     state.side_one.get_active().moves.m1.disabled = true;
     state
@@ -11896,15 +11929,9 @@ fn test_taunt_re_enables_disabled_moves_when_being_removed() {
 }
 
 #[test]
-#[cfg(any(
-    feature = "gen5",
-    feature = "gen6",
-    feature = "gen7",
-    feature = "gen8",
-    feature = "gen9"
-))]
 fn test_switching_out_with_taunt_resets_duration_to_0() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.volatile_status_durations.taunt = 1;
     state
         .side_one
@@ -13090,9 +13117,9 @@ fn test_cannot_use_futuresight_when_it_is_already_active() {
 }
 
 #[test]
-#[cfg(any(feature = "gen9", feature = "gen8", feature = "gen7", feature = "gen6"))] // just so that the damage is correct
 fn test_futuresight_activating() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.future_sight.0 = 1;
     state.side_one.future_sight.1 = PokemonIndex::P0;
 
@@ -13118,9 +13145,9 @@ fn test_futuresight_activating() {
 }
 
 #[test]
-#[cfg(any(feature = "gen9", feature = "gen8", feature = "gen7", feature = "gen6"))] // just so that the damage is correct
 fn test_futuresight_activating_on_reserve_pkmn() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.future_sight.0 = 1;
     state.side_one.future_sight.1 = PokemonIndex::P1;
     state.side_one.pokemon[PokemonIndex::P1].special_attack = 10; // very weak
@@ -13514,10 +13541,11 @@ fn test_toxic_count_is_reset_even_if_toxic_is_reapplied_the_same_turn() {
     state.side_two.get_active().speed = 150;
     state.side_two.get_active().status = PokemonStatus::TOXIC;
     state.side_two.side_conditions.toxic_count = 2;
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::REFRESH);
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::REFRESH,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
         &mut state,
@@ -13669,9 +13697,9 @@ fn test_solarbeam_in_sun() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_thief() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().item = Items::NONE;
     state.side_two.get_active().item = Items::EXPERTBELT;
 
@@ -13704,9 +13732,9 @@ fn test_thief() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_thief_does_not_steal_if_user_has_item() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().item = Items::LEFTOVERS;
     state.side_two.get_active().item = Items::EXPERTBELT;
 
@@ -13727,9 +13755,9 @@ fn test_thief_does_not_steal_if_user_has_item() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_thief_does_not_steal_if_opponent_has_no_item() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().item = Items::NONE;
     state.side_two.get_active().item = Items::NONE;
 
@@ -13750,9 +13778,9 @@ fn test_thief_does_not_steal_if_opponent_has_no_item() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_thief_does_not_steal_if_hit_sub() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().item = Items::NONE;
     state.side_two.get_active().item = Items::EXPERTBELT;
     state
@@ -14322,7 +14350,7 @@ fn test_mimikyu_with_disguise_formechange_on_damaging_move() {
     }];
 
     // Gen8 onwards mimikyu takes 1/8th of its health in damage when busting
-    if cfg!(feature = "gen8") || cfg!(feature = "gen9") {
+    if state.generation >= Generation::GEN8 {
         expected_instructions[0]
             .instruction_list
             .push(Instruction::Damage(DamageInstruction {
@@ -14356,7 +14384,7 @@ fn test_mimikyu_busting_does_not_overkill() {
     }];
 
     // Gen8 onwards mimikyu takes up to 1/8th of its health in damage when busting
-    if cfg!(feature = "gen8") || cfg!(feature = "gen9") {
+    if state.generation >= Generation::GEN8 {
         expected_instructions[0]
             .instruction_list
             .push(Instruction::Damage(DamageInstruction {
@@ -14442,9 +14470,9 @@ fn test_snowscape() {
 }
 
 #[test]
-#[cfg(any(feature = "gen8", feature = "gen9"))]
 fn test_sandspit() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().ability = Abilities::SANDSPIT;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -14531,9 +14559,9 @@ fn test_toxicdebris_when_max_kayers_already_hit() {
 }
 
 #[test]
-#[cfg(any(feature = "gen8", feature = "gen9"))]
 fn test_sandspit_does_not_activate_on_miss() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().ability = Abilities::SANDSPIT;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -14812,9 +14840,9 @@ fn test_heatcrash_highest_base_power() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_blizzard_in_hail() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.weather.weather_type = Weather::HAIL;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -15014,13 +15042,13 @@ fn test_cannot_toxic_steel_pokemon() {
 }
 
 #[test]
-#[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8"))]
 fn test_scenario_where_choice_gets_updated_on_second_move_that_has_branched_on_first_turn() {
     /*
     There was a bug that caused the choice to get incorrectly updated twice when
     the first move branched into a second move that caused the choice to update.
     */
     let mut state = State::default();
+    state.set_generation(Generation::GEN8);
     state.side_two.get_active().speed = 150;
     state.side_one.get_active().item = Items::LIFEORB;
 
@@ -15213,9 +15241,9 @@ fn test_poltergeist_missing() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_expert_belt_does_not_boost() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().hp = 300;
     state.side_two.get_active().maxhp = 300;
     state.side_two.get_active().types = (PokemonType::FIRE, PokemonType::DRAGON);
@@ -15334,9 +15362,9 @@ fn test_contact_multi_hit_move_versus_rockyhelmet() {
 }
 
 #[test]
-#[cfg(any(feature = "gen7", feature = "gen8", feature = "gen9"))]
 fn test_souldew_20_percent_boost_on_dragon_move() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().id = PokemonName::LATIOS;
     state.side_one.get_active().item = Items::SOULDEW;
 
@@ -15357,9 +15385,9 @@ fn test_souldew_20_percent_boost_on_dragon_move() {
 }
 
 #[test]
-#[cfg(any(feature = "gen4", feature = "gen5", feature = "gen6"))]
 fn test_earlier_gen_souldew_50_percent_boost_on_any_special_move() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN6);
     state.side_one.get_active().id = PokemonName::LATIOS;
     state.side_one.get_active().item = Items::SOULDEW;
 
@@ -15380,9 +15408,9 @@ fn test_earlier_gen_souldew_50_percent_boost_on_any_special_move() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_skilllink_always_has_5_hits() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().item = Items::ROCKYHELMET;
     state.side_one.get_active().ability = Abilities::SKILLLINK;
 
@@ -15427,9 +15455,9 @@ fn test_skilllink_always_has_5_hits() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_population_bomb_with_widelens() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().item = Items::WIDELENS;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -15578,8 +15606,7 @@ fn test_lifeorb_hitting_sub() {
         Choices::SPLASH,
     );
 
-    #[cfg(feature = "gen4")]
-    let expected_instructions = vec![StateInstructions {
+    let mut expected_instructions = vec![StateInstructions {
         percentage: 100.0,
         instruction_list: vec![
             Instruction::DamageSubstitute(DamageInstruction {
@@ -15592,24 +15619,15 @@ fn test_lifeorb_hitting_sub() {
             }),
         ],
     }];
-    #[cfg(not(feature = "gen4"))]
-    let expected_instructions = vec![StateInstructions {
-        percentage: 100.0,
-        instruction_list: vec![
-            Instruction::DamageSubstitute(DamageInstruction {
-                side_ref: SideReference::SideTwo,
-                damage_amount: 10,
-            }),
-            Instruction::RemoveVolatileStatus(RemoveVolatileStatusInstruction {
-                side_ref: SideReference::SideTwo,
-                volatile_status: PokemonVolatileStatus::SUBSTITUTE,
-            }),
-            Instruction::Heal(HealInstruction {
+    // Life Orb recoil is not taken when hitting a substitute in gen4
+    if state.generation != Generation::GEN4 {
+        expected_instructions[0]
+            .instruction_list
+            .push(Instruction::Heal(HealInstruction {
                 side_ref: SideReference::SideOne,
                 heal_amount: -10,
-            }),
-        ],
-    }];
+            }));
+    }
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
@@ -16082,9 +16100,9 @@ fn test_lightning_rod_versus_status_move() {
 }
 
 #[test]
-#[cfg(any(feature = "gen9", feature = "gen8", feature = "gen7"))]
 fn test_prankster_into_dark_type() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().ability = Abilities::PRANKSTER;
     state.side_two.get_active().types.0 = PokemonType::DARK;
 
@@ -16102,7 +16120,6 @@ fn test_prankster_into_dark_type() {
 }
 
 #[test]
-#[cfg(not(any(feature = "gen9", feature = "gen8", feature = "gen7")))]
 fn test_prankster_into_dark_type_earlier_gens() {
     let mut state = State::default();
     state.side_one.get_active().ability = Abilities::PRANKSTER;
@@ -16481,9 +16498,9 @@ fn test_magicbounce_with_side_condition_that_is_already_up() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_magicbounce_with_status() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().ability = Abilities::MAGICBOUNCE;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -16517,9 +16534,9 @@ fn test_magicbounce_with_status() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_magicbounce_with_leechseed() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().ability = Abilities::MAGICBOUNCE;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -17195,9 +17212,9 @@ fn test_oblivious_versus_intimidate() {
 }
 
 #[test]
-#[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
 fn test_drizzle() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.pokemon[PokemonIndex::P1].ability = Abilities::DRIZZLE;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -17216,7 +17233,7 @@ fn test_drizzle() {
             }),
             Instruction::ChangeWeather(ChangeWeather {
                 new_weather: Weather::RAIN,
-                new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                new_weather_turns_remaining: weather_ability_turns(state.generation),
                 previous_weather: Weather::NONE,
                 previous_weather_turns_remaining: -1,
             }),
@@ -17227,9 +17244,9 @@ fn test_drizzle() {
 }
 
 #[test]
-#[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5"))]
-fn test_drizzle() {
+fn test_earlier_gen_drizzle() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN5);
     state.side_one.pokemon[PokemonIndex::P1].ability = Abilities::DRIZZLE;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -17248,7 +17265,7 @@ fn test_drizzle() {
             }),
             Instruction::ChangeWeather(ChangeWeather {
                 new_weather: Weather::RAIN,
-                new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                new_weather_turns_remaining: weather_ability_turns(state.generation),
                 previous_weather: Weather::NONE,
                 previous_weather_turns_remaining: -1,
             }),
@@ -17450,9 +17467,9 @@ fn test_hadronenegine_terrain_application() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_orichalcumpulse_weather_application() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.pokemon[PokemonIndex::P1].ability = Abilities::ORICHALCUMPULSE;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -17471,7 +17488,7 @@ fn test_orichalcumpulse_weather_application() {
             }),
             Instruction::ChangeWeather(ChangeWeather {
                 new_weather: Weather::SUN,
-                new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                new_weather_turns_remaining: weather_ability_turns(state.generation),
                 previous_weather: Weather::NONE,
                 previous_weather_turns_remaining: -1,
             }),
@@ -17684,9 +17701,9 @@ fn test_raging_bull_removes_screens() {
 }
 
 #[test]
-#[cfg(any(feature = "gen3", feature = "gen4", feature = "gen5"))]
-fn test_drought() {
+fn test_earlier_gen_drought() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN5);
     state.side_one.pokemon[PokemonIndex::P1].ability = Abilities::DROUGHT;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -17705,7 +17722,7 @@ fn test_drought() {
             }),
             Instruction::ChangeWeather(ChangeWeather {
                 new_weather: Weather::SUN,
-                new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                new_weather_turns_remaining: weather_ability_turns(state.generation),
                 previous_weather: Weather::NONE,
                 previous_weather_turns_remaining: -1,
             }),
@@ -17715,9 +17732,9 @@ fn test_drought() {
 }
 
 #[test]
-#[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
 fn test_drought() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.pokemon[PokemonIndex::P1].ability = Abilities::DROUGHT;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -17736,7 +17753,7 @@ fn test_drought() {
             }),
             Instruction::ChangeWeather(ChangeWeather {
                 new_weather: Weather::SUN,
-                new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                new_weather_turns_remaining: weather_ability_turns(state.generation),
                 previous_weather: Weather::NONE,
                 previous_weather_turns_remaining: -1,
             }),
@@ -17747,9 +17764,9 @@ fn test_drought() {
 }
 
 #[test]
-#[cfg(feature = "gen8")]
 fn test_pre_gen9_snowwarning() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN8);
     state.side_one.pokemon[PokemonIndex::P1].ability = Abilities::SNOWWARNING;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -17768,7 +17785,7 @@ fn test_pre_gen9_snowwarning() {
             }),
             Instruction::ChangeWeather(ChangeWeather {
                 new_weather: Weather::HAIL,
-                new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                new_weather_turns_remaining: weather_ability_turns(state.generation),
                 previous_weather: Weather::NONE,
                 previous_weather_turns_remaining: -1,
             }),
@@ -17787,9 +17804,9 @@ fn test_pre_gen9_snowwarning() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_gen9_snowwarning() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.pokemon[PokemonIndex::P1].ability = Abilities::SNOWWARNING;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -17808,7 +17825,7 @@ fn test_gen9_snowwarning() {
             }),
             Instruction::ChangeWeather(ChangeWeather {
                 new_weather: Weather::SNOW,
-                new_weather_turns_remaining: WEATHER_ABILITY_TURNS,
+                new_weather_turns_remaining: weather_ability_turns(state.generation),
                 previous_weather: Weather::NONE,
                 previous_weather_turns_remaining: -1,
             }),
@@ -18059,9 +18076,9 @@ fn test_scrappy_fighting_move_becomes_supereffective_against_ghost_normal() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_terastallizing() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().tera_type = PokemonType::GRASS;
     state.side_two.get_active().tera_type = PokemonType::FIRE;
     state.side_one.get_active().terastallized = false;
@@ -18755,10 +18772,11 @@ fn test_switching_in_with_grassyseed_in_grassy_terrain() {
     state.terrain.terrain_type = Terrain::GRASSYTERRAIN;
     state.terrain.turns_remaining = 3;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SPLASH,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
         &mut state,
@@ -18798,10 +18816,11 @@ fn test_contrary_with_seed() {
     state.terrain.terrain_type = Terrain::PSYCHICTERRAIN;
     state.terrain.turns_remaining = 3;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SPLASH,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
         &mut state,
@@ -19000,7 +19019,7 @@ fn test_endure_with_protect_side_condition_not_fully_accurate() {
     let mut state = State::default();
     state.side_two.get_active().hp = 5;
     state.side_two.side_conditions.protect = 1;
-    let success_chance = CONSECUTIVE_PROTECT_CHANCE.powi(1);
+    let success_chance = consecutive_protect_chance(Generation::default()).powi(1);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -19106,9 +19125,9 @@ fn test_steamengine() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_thermal_exchange() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().ability = Abilities::THERMALEXCHANGE;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -19241,9 +19260,9 @@ fn test_adaptability() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_adaptability_with_tera_stab_is_225_percent() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().ability = Abilities::ADAPTABILITY;
     state.side_one.get_active().terastallized = true;
     state.side_one.get_active().tera_type = PokemonType::NORMAL;
@@ -19265,9 +19284,9 @@ fn test_adaptability_with_tera_stab_is_225_percent() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_adaptability_with_tera_but_no_regular_stab_is_200_percent() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().ability = Abilities::ADAPTABILITY;
     state.side_one.get_active().terastallized = true;
     state.side_one.get_active().types = (PokemonType::GHOST, PokemonType::FIGHTING);
@@ -19839,7 +19858,7 @@ fn test_taunt_into_glare() {
             },
         )],
     }];
-    if cfg!(not(feature = "gen4")) {
+    if state.generation != Generation::GEN4 {
         expected_instructions[0]
             .instruction_list
             .push(Instruction::ChangeVolatileStatusDuration(
@@ -19854,9 +19873,9 @@ fn test_taunt_into_glare() {
 }
 
 #[test]
-#[cfg(any(feature = "gen6", feature = "gen7", feature = "gen8", feature = "gen9"))]
 fn test_glare_into_electric_type() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().types.1 = PokemonType::ELECTRIC;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -19873,9 +19892,9 @@ fn test_glare_into_electric_type() {
 }
 
 #[test]
-#[cfg(any(feature = "gen4"))]
 fn test_gen4_glare_into_electric_type() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN4);
     state.side_two.get_active().types.1 = PokemonType::ELECTRIC;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -20174,9 +20193,9 @@ fn test_watercompaction() {
 }
 
 #[test]
-#[cfg(any(feature = "gen4", feature = "gen5"))]
 fn test_gen5_or_earlier_ghost_versus_steel() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN5);
     state.side_two.get_active().types = (PokemonType::STEEL, PokemonType::TYPELESS);
 
     let vec_of_instructions =
@@ -20193,9 +20212,9 @@ fn test_gen5_or_earlier_ghost_versus_steel() {
 }
 
 #[test]
-#[cfg(any(feature = "gen7", feature = "gen8"))]
 fn test_pixilate() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN8);
     state.side_one.get_active().types = (PokemonType::FAIRY, PokemonType::TYPELESS);
     state.side_one.get_active().ability = Abilities::PIXILATE;
 
@@ -20216,9 +20235,9 @@ fn test_pixilate() {
 }
 
 #[test]
-#[cfg(feature = "gen6")]
 fn test_pixilate_gen6() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN6);
     state.side_one.get_active().types = (PokemonType::FAIRY, PokemonType::TYPELESS);
     state.side_one.get_active().ability = Abilities::PIXILATE;
 
@@ -20239,9 +20258,9 @@ fn test_pixilate_gen6() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_transistor() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_one.get_active().ability = Abilities::TRANSISTOR;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -20278,15 +20297,9 @@ fn test_transistor() {
 }
 
 #[test]
-#[cfg(any(
-    feature = "gen8",
-    feature = "gen7",
-    feature = "gen6",
-    feature = "gen5",
-    feature = "gen4"
-))]
 fn test_transistor_higher_boost_before_gen8() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN8);
     state.side_one.get_active().ability = Abilities::TRANSISTOR;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -20322,10 +20335,10 @@ fn test_transistor_higher_boost_before_gen8() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
-#[cfg(any(feature = "gen9"))]
 #[test]
 fn test_wickedblow_gen9() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -20344,9 +20357,9 @@ fn test_wickedblow_gen9() {
 }
 
 #[test]
-#[cfg(feature = "gen8")]
 fn test_wickedblow_gen8() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN8);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -20403,9 +20416,9 @@ fn test_mortalspin_poison_and_remove_hazards() {
 }
 
 #[test]
-#[cfg(feature = "gen7")]
 fn test_gen7_rapidspin_does_not_boost_speed() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN7);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -20424,9 +20437,9 @@ fn test_gen7_rapidspin_does_not_boost_speed() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_gen9_rapidspin_boosts_speed() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -20452,9 +20465,9 @@ fn test_gen9_rapidspin_boosts_speed() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_weakarmor() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().ability = Abilities::WEAKARMOR;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -20527,9 +20540,9 @@ fn test_wonderguard_against_spore() {
 }
 
 #[test]
-#[cfg(feature = "gen9")]
 fn test_wonderguard_against_willowisp() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().ability = Abilities::WONDERGUARD;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -20563,9 +20576,9 @@ fn test_wonderguard_against_willowisp() {
 }
 
 #[test]
-#[cfg(feature = "gen4")]
 fn test_wonderguard_1_hp_against_willowisp() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN4);
     state.side_two.get_active().hp = 1;
     state.side_two.get_active().maxhp = 1;
     state.side_two.get_active().ability = Abilities::WONDERGUARD;
@@ -21047,7 +21060,7 @@ fn test_baddreams() {
     let mut state = State::default();
     state.side_one.get_active().ability = Abilities::BADDREAMS;
     state.side_two.get_active().status = PokemonStatus::SLEEP;
-    state.side_two.get_active().sleep_turns = MAX_SLEEP_TURNS - 2;
+    state.side_two.get_active().sleep_turns = max_sleep_turns(Generation::default()) - 2;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -21062,8 +21075,8 @@ fn test_baddreams() {
                 Instruction::SetSleepTurns(SetSleepTurnsInstruction {
                     side_ref: SideReference::SideTwo,
                     pokemon_index: PokemonIndex::P0,
-                    new_turns: MAX_SLEEP_TURNS - 1,
-                    previous_turns: MAX_SLEEP_TURNS - 2,
+                    new_turns: max_sleep_turns(Generation::default()) - 1,
+                    previous_turns: max_sleep_turns(Generation::default()) - 2,
                 }),
                 Instruction::Damage(DamageInstruction {
                     side_ref: SideReference::SideTwo,
@@ -21084,7 +21097,7 @@ fn test_baddreams() {
                     side_ref: SideReference::SideTwo,
                     pokemon_index: PokemonIndex::P0,
                     new_turns: 0,
-                    previous_turns: MAX_SLEEP_TURNS - 2,
+                    previous_turns: max_sleep_turns(Generation::default()) - 2,
                 }),
             ],
         },
@@ -21156,26 +21169,30 @@ fn test_sleeptalk_when_asleep_and_rest_turns_active() {
     let mut state = State::default();
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().rest_turns = 3;
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::REST);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::SLEEPTALK);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M2, Choices::TACKLE);
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M3, Choices::CURSE);
+        .replace_move(PokemonMoveIndex::M0, Choices::REST, state.generation);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M1,
+        Choices::SLEEPTALK,
+        state.generation,
+    );
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M2,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M3,
+        Choices::CURSE,
+        state.generation,
+    );
     let vec_of_instructions = generate_instructions_with_state_assertion(
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M1),
@@ -21251,7 +21268,7 @@ fn test_baddreams_does_not_overkill() {
     let mut state = State::default();
     state.side_one.get_active().ability = Abilities::BADDREAMS;
     state.side_two.get_active().status = PokemonStatus::SLEEP;
-    state.side_two.get_active().sleep_turns = MAX_SLEEP_TURNS - 2;
+    state.side_two.get_active().sleep_turns = max_sleep_turns(Generation::default()) - 2;
     state.side_two.get_active().hp = 5;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -21267,8 +21284,8 @@ fn test_baddreams_does_not_overkill() {
                 Instruction::SetSleepTurns(SetSleepTurnsInstruction {
                     side_ref: SideReference::SideTwo,
                     pokemon_index: PokemonIndex::P0,
-                    new_turns: MAX_SLEEP_TURNS - 1,
-                    previous_turns: MAX_SLEEP_TURNS - 2,
+                    new_turns: max_sleep_turns(Generation::default()) - 1,
+                    previous_turns: max_sleep_turns(Generation::default()) - 2,
                 }),
                 Instruction::Damage(DamageInstruction {
                     side_ref: SideReference::SideTwo,
@@ -21289,7 +21306,7 @@ fn test_baddreams_does_not_overkill() {
                     side_ref: SideReference::SideTwo,
                     pokemon_index: PokemonIndex::P0,
                     new_turns: 0,
-                    previous_turns: MAX_SLEEP_TURNS - 2,
+                    previous_turns: max_sleep_turns(Generation::default()) - 2,
                 }),
             ],
         },
@@ -21342,9 +21359,9 @@ fn test_prismarmor() {
 }
 
 #[test]
-#[cfg(feature = "terastallization")]
 fn test_prismarmor_respects_tera_type() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.side_two.get_active().ability = Abilities::PRISMARMOR;
     state.side_two.get_active().types = (PokemonType::FIRE, PokemonType::NORMAL);
     state.side_two.get_active().terastallized = true;
@@ -21711,7 +21728,6 @@ fn test_using_none_with_mustrecharge_removes_volatile() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_mustrecharge_move_only_allows_none() {
     let mut state = State::default();
     state
@@ -21737,3 +21753,266 @@ fn test_mustrecharge_move_only_allows_none() {
     );
     assert_eq!(expected_options, options);
 }
+
+#[test]
+fn test_set_generation_reloads_move_data() {
+    let mut state = State::default();
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::THUNDERBOLT,
+        state.generation,
+    );
+
+    state.set_generation(Generation::GEN5);
+    assert_eq!(
+        95.0,
+        state.side_one.get_active().moves[&PokemonMoveIndex::M0]
+            .choice
+            .base_power
+    );
+
+    state.set_generation(Generation::GEN6);
+    assert_eq!(
+        90.0,
+        state.side_one.get_active().moves[&PokemonMoveIndex::M0]
+            .choice
+            .base_power
+    );
+}
+
+#[test]
+fn test_set_generation_allows_terastallization_only_in_gen9() {
+    let mut state = State::default();
+    state.set_generation(Generation::GEN8);
+    assert!(!state.terastallization);
+
+    state.set_generation(Generation::GEN9);
+    assert!(state.terastallization);
+}
+
+#[test]
+fn test_replace_move_uses_the_move_data_of_the_given_generation() {
+    let mut state = State::default();
+    state.set_generation(Generation::GEN5);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::THUNDERBOLT,
+        state.generation,
+    );
+    assert_eq!(
+        95.0,
+        state.side_one.get_active().moves[&PokemonMoveIndex::M0]
+            .choice
+            .base_power
+    );
+}
+
+#[test]
+fn test_burn_damage_uses_the_state_generation() {
+    let mut state = State::default();
+    state.side_one.get_active().status = PokemonStatus::BURN;
+    state.set_generation(Generation::GEN6);
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::SPLASH,
+        Choices::SPLASH,
+    );
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![Instruction::Damage(DamageInstruction {
+            side_ref: SideReference::SideOne,
+            damage_amount: 12,
+        })],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+
+    state.set_generation(Generation::GEN7);
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::SPLASH,
+        Choices::SPLASH,
+    );
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![Instruction::Damage(DamageInstruction {
+            side_ref: SideReference::SideOne,
+            damage_amount: 6,
+        })],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
+#[test]
+fn test_gen1_state_is_handed_to_the_gen1_engine() {
+    let mut state = State::default();
+    state.side_one.get_active().status = PokemonStatus::BURN;
+    state.set_generation(Generation::GEN1);
+
+    // burn does 1/16th of max hp in gen1 and 1/8th from gen2 through gen6
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::SPLASH,
+        Choices::SPLASH,
+    );
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![Instruction::Damage(DamageInstruction {
+            side_ref: SideReference::SideOne,
+            damage_amount: 6,
+        })],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+
+    state.set_generation(Generation::GEN2);
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::SPLASH,
+        Choices::SPLASH,
+    );
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![Instruction::Damage(DamageInstruction {
+            side_ref: SideReference::SideOne,
+            damage_amount: 12,
+        })],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
+#[test]
+fn test_try_deserialize_reports_the_side_field_that_failed() {
+    let serialized = State::default().serialize();
+    let mut sides: Vec<String> = serialized.split("/").map(|s| s.to_string()).collect();
+    let mut side_two_fields: Vec<&str> = sides[2].split("=").collect();
+    side_two_fields[10] = "lots";
+    sides[2] = side_two_fields.join("=");
+
+    let error = State::try_deserialize(&sides.join("/")).unwrap_err();
+    assert_eq!(Some(SideReference::SideTwo), error.side);
+    assert_eq!(None, error.pokemon_index);
+    assert_eq!(10, error.field_index);
+    assert_eq!("lots", error.token);
+}
+
+#[test]
+fn test_try_deserialize_reports_missing_fields() {
+    let serialized = State::default().serialize();
+    let truncated: Vec<&str> = serialized.split("/").take(6).collect();
+
+    let error = State::try_deserialize(&truncated.join("/")).unwrap_err();
+    assert_eq!(None, error.side);
+    assert_eq!(5, error.field_index);
+    assert_eq!("", error.token);
+}
+
+#[test]
+fn test_try_deserialize_round_trips_a_valid_state() {
+    let state = State::default();
+    let deserialized = State::try_deserialize(&state.serialize()).unwrap();
+    assert_eq!(state.serialize(), deserialized.serialize());
+}
+
+// removes the fields that were added to pokemon and sides before the state string was versioned
+fn legacy_state_string(state: &State) -> String {
+    let serialized = state.serialize();
+    let mut sides: Vec<String> = serialized
+        .split("/")
+        .skip(1)
+        .map(|s| s.to_string())
+        .collect();
+    for side in sides.iter_mut().take(2) {
+        let mut side_fields: Vec<String> = side.split("=").map(|s| s.to_string()).collect();
+        for pkmn in side_fields.iter_mut().take(6) {
+            let mut pkmn_fields: Vec<&str> = pkmn.split(",").collect();
+            pkmn_fields.remove(13);
+            pkmn_fields.remove(12);
+            pkmn_fields.remove(11);
+            pkmn_fields.remove(9);
+            *pkmn = pkmn_fields.join(",");
+        }
+        side_fields.remove(28);
+        side_fields.remove(25);
+        *side = side_fields.join("=");
+    }
+    sides.join("/")
+}
+
+#[test]
+fn test_state_string_starts_with_its_version() {
+    let serialized = State::default().serialize();
+    assert!(serialized.starts_with(&format!("v{}/", STATE_STRING_VERSION)));
+}
+
+#[test]
+fn test_legacy_state_string_is_migrated() {
+    let mut state = State::default();
+    state.side_one.get_active().ability = Abilities::INTIMIDATE;
+    state.side_one.get_active().base_ability = Abilities::INTIMIDATE;
+
+    let migrated = State::deserialize(&legacy_state_string(&state));
+
+    assert_eq!(state.serialize(), migrated.serialize());
+}
+
+#[test]
+fn test_legacy_state_string_fills_in_defaults_for_new_fields() {
+    let mut state = State::default();
+    state.side_one.get_active().ability = Abilities::INTIMIDATE;
+    state.side_one.get_active().nature = PokemonNature::ADAMANT;
+    state.side_one.shed_tailing = true;
+
+    let migrated = State::deserialize(&legacy_state_string(&state));
+
+    let active = migrated.side_one.get_active_immutable();
+    assert_eq!(Abilities::INTIMIDATE, active.base_ability);
+    assert_eq!(PokemonNature::SERIOUS, active.nature);
+    assert_eq!((85, 85, 85, 85, 85, 85), active.evs);
+    assert_eq!((31, 31, 31, 31, 31, 31), active.ivs);
+    assert!(!migrated.side_one.shed_tailing);
+}
+
+#[test]
+fn test_version_2_state_string_is_migrated_with_31_ivs() {
+    let mut state = State::default();
+    state.side_one.get_active().evs = (4, 252, 0, 0, 0, 252);
+    let current = state.serialize();
+
+    // version 2 had no ivs field after the evs
+    let version_2 = current
+        .replacen(&format!("v{}/", STATE_STRING_VERSION), "v2/", 1)
+        .replace(",4;252;0;0;0;252,31;31;31;31;31;31,", ",4;252;0;0;0;252,")
+        .replace(
+            ",85;85;85;85;85;85,31;31;31;31;31;31,",
+            ",85;85;85;85;85;85,",
+        );
+    let migrated = State::try_deserialize(&version_2).unwrap();
+
+    assert_eq!(current, migrated.serialize());
+}
+
+#[test]
+fn test_legacy_state_string_starting_with_a_v_pokemon_is_migrated() {
+    let mut state = State::default();
+    state.side_one.pokemon[PokemonIndex::P0].id = PokemonName::VOLCARONA;
+
+    // names are parsed case-insensitively and are often written in lowercase
+    let legacy = legacy_state_string(&state).replacen("VOLCARONA", "volcarona", 1);
+    assert!(legacy.starts_with("volcarona,"));
+    let migrated = State::try_deserialize(&legacy).unwrap();
+
+    assert_eq!(state.serialize(), migrated.serialize());
+}
+
+#[test]
+fn test_newer_state_string_version_is_an_error() {
+    let serialized = State::default().serialize();
+    let newer = serialized.replacen(
+        &format!("v{}/", STATE_STRING_VERSION),
+        &format!("v{}/", STATE_STRING_VERSION + 1),
+        1,
+    );
+
+    let error = State::try_deserialize(&newer).unwrap_err();
+    assert_eq!(0, error.field_index);
+}
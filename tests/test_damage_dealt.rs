@@ -1,5 +1,3 @@
-#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
-
 use poke_engine::choices::{Choices, MoveCategory};
 use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
 use poke_engine::engine::state::{MoveChoice, PokemonVolatileStatus, Weather};
@@ -8,7 +6,7 @@ use poke_engine::instruction::{
     DamageInstruction, Instruction, RemoveVolatileStatusInstruction, StateInstructions,
     ToggleDamageDealtHitSubstituteInstruction,
 };
-use poke_engine::state::{PokemonMoveIndex, PokemonType, SideReference, State};
+use poke_engine::state::{Generation, PokemonMoveIndex, PokemonType, SideReference, State};
 
 #[test]
 fn test_previous_damage_dealt_resets_and_then_goes_to_a_new_value() {
@@ -16,14 +14,16 @@ fn test_previous_damage_dealt_resets_and_then_goes_to_a_new_value() {
     state.use_damage_dealt = true;
     state.side_two.damage_dealt.damage = 10;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::COUNTER);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::COUNTER,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -61,14 +61,16 @@ fn test_counter_after_physical_hit() {
     let mut state = State::default();
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::COUNTER);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::COUNTER,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -103,14 +105,16 @@ fn test_counter_cannot_hit_ghost_type() {
     state.use_damage_dealt = true;
     state.side_two.get_active().types.0 = PokemonType::GHOST;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::COUNTER);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::COUNTER,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -136,19 +140,21 @@ fn test_counter_cannot_hit_ghost_type() {
 }
 
 #[test]
-#[cfg(feature = "gen3")]
 fn test_counter_reflects_special_hiddenpower() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::COUNTER);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::HIDDENPOWERWATER70);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::COUNTER,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::HIDDENPOWERWATER70,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -164,11 +170,14 @@ fn test_counter_reflects_special_hiddenpower() {
                 side_ref: SideReference::SideOne,
                 damage_amount: 55,
             }),
-            Instruction::SetDamageDealtSideTwo(SetDamageDealtSideTwoInstruction {
+            Instruction::ChangeDamageDealtDamage(ChangeDamageDealtDamageInstruction {
+                side_ref: SideReference::SideTwo,
                 damage_change: 55,
+            }),
+            Instruction::ChangeDamageDealtMoveCatagory(ChangeDamageDealtMoveCategoryInstruction {
+                side_ref: SideReference::SideTwo,
                 move_category: MoveCategory::Special,
                 previous_move_category: MoveCategory::Physical,
-                toggle_hit_substitute: false,
             }),
             Instruction::Damage(DamageInstruction {
                 side_ref: SideReference::SideTwo,
@@ -180,19 +189,21 @@ fn test_counter_reflects_special_hiddenpower() {
 }
 
 #[test]
-#[cfg(feature = "gen3")]
 fn test_mirrorcoat_does_not_reflect_special_hiddenpower() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::MIRRORCOAT);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::HIDDENPOWERWATER70);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::MIRRORCOAT,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::HIDDENPOWERWATER70,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -208,11 +219,14 @@ fn test_mirrorcoat_does_not_reflect_special_hiddenpower() {
                 side_ref: SideReference::SideOne,
                 damage_amount: 55,
             }),
-            Instruction::SetDamageDealtSideTwo(SetDamageDealtSideTwoInstruction {
+            Instruction::ChangeDamageDealtDamage(ChangeDamageDealtDamageInstruction {
+                side_ref: SideReference::SideTwo,
                 damage_change: 55,
+            }),
+            Instruction::ChangeDamageDealtMoveCatagory(ChangeDamageDealtMoveCategoryInstruction {
+                side_ref: SideReference::SideTwo,
                 move_category: MoveCategory::Special,
                 previous_move_category: MoveCategory::Physical,
-                toggle_hit_substitute: false,
             }),
         ],
     }];
@@ -224,14 +238,16 @@ fn test_metalburst_after_physical_move() {
     let mut state = State::default();
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::METALBURST);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::METALBURST,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -265,14 +281,16 @@ fn test_comeuppance_after_physical_move() {
     let mut state = State::default();
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::COMEUPPANCE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::COMEUPPANCE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -306,14 +324,16 @@ fn test_metalburst_after_special_move() {
     let mut state = State::default();
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::METALBURST);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::WATERGUN);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::METALBURST,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::WATERGUN,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -352,14 +372,16 @@ fn test_metalburst_after_substitute_being_hit() {
     let mut state = State::default();
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::METALBURST);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::METALBURST,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
     state
         .side_one
         .volatile_statuses
@@ -403,14 +425,16 @@ fn test_metalburst_fails_moving_first() {
     let mut state = State::default();
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::METALBURST);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::METALBURST,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
     state.side_one.get_active().speed = 100;
     state.side_two.get_active().speed = 50;
 
@@ -442,14 +466,16 @@ fn test_metalburst_after_status_move() {
     let mut state = State::default();
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::METALBURST);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::METALBURST,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SPLASH,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -470,14 +496,16 @@ fn test_counter_after_special_hit() {
     let mut state = State::default();
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::COUNTER);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::WATERGUN);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::COUNTER,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::WATERGUN,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -512,14 +540,16 @@ fn test_mirrorcoat_after_special_hit() {
     let mut state = State::default();
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::MIRRORCOAT);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::WATERGUN);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::MIRRORCOAT,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::WATERGUN,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -558,14 +588,16 @@ fn test_mirrorcoat_after_physical_hit() {
     let mut state = State::default();
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::MIRRORCOAT);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::MIRRORCOAT,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -596,14 +628,16 @@ fn test_focuspunch_after_getting_hit() {
     state.use_damage_dealt = true;
     state.weather.weather_type = Weather::SUN;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::FOCUSPUNCH);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::FOCUSPUNCH,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -638,14 +672,16 @@ fn test_focuspunch_after_substitute_getting_hit() {
         .insert(PokemonVolatileStatus::SUBSTITUTE);
     state.side_one.substitute_health = 1;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::FOCUSPUNCH);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::FOCUSPUNCH,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -692,14 +728,16 @@ fn test_focuspunch_after_status_move() {
     let mut state = State::default();
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::FOCUSPUNCH);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::FOCUSPUNCH,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SPLASH,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -1,10 +1,8 @@
-#![cfg(feature = "gen1")]
-
-use poke_engine::choices::{Choices, MOVES};
-use poke_engine::engine::generate_instructions::{
+use poke_engine::choices::{moves, Choices};
+use poke_engine::engine::state::{MoveChoice, PokemonVolatileStatus};
+use poke_engine::gen1::generate_instructions::{
     generate_instructions_from_move_pair, moves_first, MAX_SLEEP_TURNS,
 };
-use poke_engine::engine::state::{MoveChoice, PokemonVolatileStatus};
 use poke_engine::instruction::{
     ApplyVolatileStatusInstruction, BoostInstruction, ChangeDamageDealtDamageInstruction,
     ChangeStatusInstruction, DamageInstruction, DecrementRestTurnsInstruction, HealInstruction,
@@ -13,7 +11,7 @@ use poke_engine::instruction::{
 };
 use poke_engine::pokemon::PokemonName;
 use poke_engine::state::{
-    PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, PokemonStatus, PokemonType,
+    Generation, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, PokemonStatus, PokemonType,
     SideReference, State,
 };
 
@@ -38,11 +36,11 @@ fn set_moves_on_pkmn_and_call_generate_instructions(
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let instructions = generate_instructions_with_state_assertion(
         state,
@@ -55,6 +53,7 @@ fn set_moves_on_pkmn_and_call_generate_instructions(
 #[test]
 fn test_bodyslam_cannot_paralyze_normal_type() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_two.get_active().types.0 = PokemonType::NORMAL;
     state.side_two.get_active().hp = 500;
     state.side_two.get_active().maxhp = 500;
@@ -78,6 +77,7 @@ fn test_bodyslam_cannot_paralyze_normal_type() {
 #[test]
 fn test_same_speed_branch() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().speed = 100;
     state.side_one.get_active().hp = 1;
     state.side_two.get_active().speed = 100;
@@ -108,9 +108,30 @@ fn test_same_speed_branch() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
+#[test]
+fn test_same_speed_branches_with_the_same_outcome_are_combined() {
+    let mut state = State::default();
+    state.set_generation(Generation::GEN1);
+    state.side_one.get_active().speed = 100;
+    state.side_two.get_active().speed = 100;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::SPLASH,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
 #[test]
 fn test_same_speed_branch_with_residuals() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().speed = 100;
     state.side_one.get_active().hp = 1;
     state.side_one.get_active().status = PokemonStatus::BURN;
@@ -158,6 +179,7 @@ fn test_same_speed_branch_with_residuals() {
 #[test]
 fn test_same_speed_branch_with_residuals_for_both_sides() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().speed = 100;
     state.side_one.get_active().hp = 100;
     state.side_one.get_active().status = PokemonStatus::BURN;
@@ -221,6 +243,7 @@ fn test_same_speed_branch_with_residuals_for_both_sides() {
 #[test]
 fn test_thunderbolt_cannot_paralyze_electric_type() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_two.get_active().types.0 = PokemonType::ELECTRIC;
     state.side_two.get_active().hp = 500;
     state.side_two.get_active().maxhp = 500;
@@ -244,6 +267,7 @@ fn test_thunderbolt_cannot_paralyze_electric_type() {
 #[test]
 fn test_thunderwave_can_paralyze_electric_type() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_two.get_active().types.0 = PokemonType::ELECTRIC;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -269,6 +293,7 @@ fn test_thunderwave_can_paralyze_electric_type() {
 #[test]
 fn test_thunderwave_can_paralyze_normal_type() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_two.get_active().types.0 = PokemonType::NORMAL;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -294,13 +319,20 @@ fn test_thunderwave_can_paralyze_normal_type() {
 #[test]
 fn test_paralysis_nullify_ignores_paralysis() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().speed = 100;
     state.side_one.speed_boost = 2;
     state.side_one.get_active().status = PokemonStatus::PARALYZE;
     state.side_two.get_active().speed = 195;
 
-    let s1_choice = MOVES.get(&Choices::TACKLE).unwrap().clone();
-    let s2_choice = MOVES.get(&Choices::TACKLE).unwrap().clone();
+    let s1_choice = moves(state.generation)
+        .get(&Choices::TACKLE)
+        .unwrap()
+        .clone();
+    let s2_choice = moves(state.generation)
+        .get(&Choices::TACKLE)
+        .unwrap()
+        .clone();
 
     let moves_first_before = moves_first(&state, &s1_choice, &s2_choice);
     state
@@ -316,6 +348,7 @@ fn test_paralysis_nullify_ignores_paralysis() {
 #[test]
 fn test_gen1_swordsdance_while_burned_volatile_increases_damage() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state
         .side_one
         .volatile_statuses
@@ -350,6 +383,7 @@ fn test_gen1_swordsdance_while_burned_volatile_increases_damage() {
 #[test]
 fn test_gen1_swordsdance_while_burned_sets_burn_nullify_volatile() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().status = PokemonStatus::BURN;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -382,6 +416,7 @@ fn test_gen1_swordsdance_while_burned_sets_burn_nullify_volatile() {
 #[test]
 fn test_does_not_set_burn_nullify_if_already_exists() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().status = PokemonStatus::BURN;
     state
         .side_one
@@ -414,6 +449,7 @@ fn test_does_not_set_burn_nullify_if_already_exists() {
 #[test]
 fn test_gen1_agility_while_paralyzed_sets_paralysis_nullify_volatile() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().status = PokemonStatus::PARALYZE;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -448,6 +484,7 @@ fn test_gen1_agility_while_paralyzed_sets_paralysis_nullify_volatile() {
 #[test]
 fn test_does_not_set_paralysisnullify_if_already_exists() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().status = PokemonStatus::PARALYZE;
     state
         .side_one
@@ -480,6 +517,7 @@ fn test_does_not_set_paralysisnullify_if_already_exists() {
 #[test]
 fn test_gen1_agility_while_burned_does_not_set_burn_nullify_volatile() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().status = PokemonStatus::BURN;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -510,6 +548,7 @@ fn test_gen1_agility_while_burned_does_not_set_burn_nullify_volatile() {
 #[test]
 fn test_reflect_halves_physical_damage_as_volatile() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state
         .side_two
         .volatile_statuses
@@ -534,6 +573,7 @@ fn test_reflect_halves_physical_damage_as_volatile() {
 #[test]
 fn test_reflect_and_lightscreen_set_volatiles_instead_of_sideconditions() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -560,6 +600,7 @@ fn test_reflect_and_lightscreen_set_volatiles_instead_of_sideconditions() {
 #[test]
 fn test_lightscreen_halves_special_damage_as_volatile() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state
         .side_two
         .volatile_statuses
@@ -584,6 +625,7 @@ fn test_lightscreen_halves_special_damage_as_volatile() {
 #[test]
 fn test_thunderwave_into_substitute() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state
         .side_two
         .volatile_statuses
@@ -606,6 +648,7 @@ fn test_thunderwave_into_substitute() {
 #[test]
 fn test_confuseray_into_substitute() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state
         .side_two
         .volatile_statuses
@@ -628,6 +671,7 @@ fn test_confuseray_into_substitute() {
 #[test]
 fn test_counter_into_normal_move() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.use_damage_dealt = true;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -659,6 +703,7 @@ fn test_counter_into_normal_move() {
 #[test]
 fn test_counter_into_fighting_move() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.use_damage_dealt = true;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -690,6 +735,7 @@ fn test_counter_into_fighting_move() {
 #[test]
 fn test_counter_into_flying_move_does_not_set_damage_dealt() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.use_damage_dealt = true;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -711,6 +757,7 @@ fn test_counter_into_flying_move_does_not_set_damage_dealt() {
 #[test]
 fn test_toxic_turns_into_poison_when_switching() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().status = PokemonStatus::TOXIC;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -741,6 +788,7 @@ fn test_toxic_turns_into_poison_when_switching() {
 #[test]
 fn test_gen1_bite_flinch_with_counter() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -794,6 +842,7 @@ fn test_gen1_bite_flinch_with_counter() {
 #[test]
 fn test_special_attack_boosts_defense() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
 
     let un_boosted_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -816,6 +865,7 @@ fn test_special_attack_boosts_defense() {
 #[test]
 fn test_cannot_use_move_when_waking_from_sleep() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().sleep_turns = MAX_SLEEP_TURNS; // guaranteed to wake up
 
@@ -848,6 +898,7 @@ fn test_cannot_use_move_when_waking_from_sleep() {
 #[test]
 fn test_cannot_use_move_after_waking_when_only_a_chance_to_wake_up() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().sleep_turns = 5;
 
@@ -891,6 +942,7 @@ fn test_cannot_use_move_after_waking_when_only_a_chance_to_wake_up() {
 #[test]
 fn test_rest_wake_up_cannot_use_move() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().rest_turns = 1; // guaranteed to wake up
 
@@ -920,6 +972,7 @@ fn test_rest_wake_up_cannot_use_move() {
 #[test]
 fn test_using_rest_sets_rest_turns_to_2() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().hp = 1;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -955,6 +1008,7 @@ fn test_using_rest_sets_rest_turns_to_2() {
 #[test]
 fn test_persion_using_slash_guaranteed_crit() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().id = PokemonName::PERSIAN;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -976,6 +1030,7 @@ fn test_persion_using_slash_guaranteed_crit() {
 #[test]
 fn test_persion_using_tackle_rolls_crit() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().id = PokemonName::PERSIAN;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -1006,6 +1061,7 @@ fn test_persion_using_tackle_rolls_crit() {
 #[test]
 fn test_using_reflect() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -1028,6 +1084,7 @@ fn test_using_reflect() {
 #[test]
 fn test_freeze_clause() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_two.pokemon[PokemonIndex::P1].status = PokemonStatus::FREEZE;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -1049,17 +1106,20 @@ fn test_freeze_clause() {
 #[test]
 fn test_counter_hits_ghost_type() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.use_damage_dealt = true;
     state.side_two.get_active().types.0 = PokemonType::GHOST;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::COUNTER);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::COUNTER,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -1091,6 +1151,7 @@ fn test_counter_hits_ghost_type() {
 #[test]
 fn test_crit_roll_ignores_reflect() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().id = PokemonName::PERSIAN;
     state
         .side_two
@@ -1125,6 +1186,7 @@ fn test_crit_roll_ignores_reflect() {
 #[test]
 fn test_crit_roll_ignores_own_boost() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().id = PokemonName::PERSIAN;
     state.side_one.attack_boost = 1;
 
@@ -1156,6 +1218,7 @@ fn test_crit_roll_ignores_own_boost() {
 #[test]
 fn test_crit_roll_ignores_other_boost() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().id = PokemonName::PERSIAN;
     state.side_two.defense_boost = 1;
 
@@ -1187,6 +1250,7 @@ fn test_crit_roll_ignores_other_boost() {
 #[test]
 fn test_crit_roll_ignores_other_boost_negative_boost() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_one.get_active().id = PokemonName::PERSIAN;
     state.side_two.defense_boost = -1;
 
@@ -1218,6 +1282,7 @@ fn test_crit_roll_ignores_other_boost_negative_boost() {
 #[test]
 fn test_hyperbeam_sets_mustrecharge() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state.side_two.get_active().hp = 500;
     state.side_two.get_active().maxhp = 500;
 
@@ -1252,6 +1317,7 @@ fn test_hyperbeam_sets_mustrecharge() {
 #[test]
 fn test_hyperbeam_does_not_set_mustrecharge_on_ko() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -1278,6 +1344,7 @@ fn test_hyperbeam_does_not_set_mustrecharge_on_ko() {
 #[test]
 fn test_using_none_with_mustrecharge_removes_volatile() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state
         .side_one
         .volatile_statuses
@@ -1304,6 +1371,7 @@ fn test_using_none_with_mustrecharge_removes_volatile() {
 #[test]
 fn test_mustrecharge_move_only_allows_none() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN1);
     state
         .side_one
         .volatile_statuses
@@ -1,5 +1,3 @@
-#![cfg(feature = "gen2")]
-
 use poke_engine::choices::{Choices, MoveCategory};
 use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
 use poke_engine::engine::items::Items;
@@ -12,7 +10,7 @@ use poke_engine::instruction::{
     SwitchInstruction,
 };
 use poke_engine::state::{
-    PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, PokemonStatus, PokemonType,
+    Generation, PokemonBoostableStat, PokemonIndex, PokemonMoveIndex, PokemonStatus, PokemonType,
     SideReference, State,
 };
 
@@ -37,11 +35,11 @@ fn set_moves_on_pkmn_and_call_generate_instructions(
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let instructions = generate_instructions_with_state_assertion(
         state,
@@ -54,6 +52,7 @@ fn set_moves_on_pkmn_and_call_generate_instructions(
 #[test]
 fn test_branch_when_a_roll_can_kill() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_two.get_active().hp = 50;
 
     let move_one = Choices::TACKLE;
@@ -61,11 +60,11 @@ fn test_branch_when_a_roll_can_kill() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -98,6 +97,7 @@ fn test_branch_when_a_roll_can_kill() {
 #[test]
 fn test_branch_on_crit() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_two.get_active().hp = 100;
 
     let move_one = Choices::TACKLE;
@@ -105,11 +105,11 @@ fn test_branch_on_crit() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -140,6 +140,7 @@ fn test_branch_on_crit() {
 #[test]
 fn test_highcrit_move() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_two.get_active().hp = 100;
 
     let move_one = Choices::RAZORLEAF;
@@ -147,11 +148,11 @@ fn test_highcrit_move() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -186,6 +187,7 @@ fn test_highcrit_move() {
 #[test]
 fn test_crit_does_not_overkill() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_two.get_active().hp = 85;
 
     let move_one = Choices::TACKLE;
@@ -193,11 +195,11 @@ fn test_crit_does_not_overkill() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -228,6 +230,7 @@ fn test_crit_does_not_overkill() {
 #[test]
 fn test_branch_when_a_roll_can_kill_on_the_low_side() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_two.get_active().hp = 45;
 
     let move_one = Choices::TACKLE;
@@ -235,11 +238,11 @@ fn test_branch_when_a_roll_can_kill_on_the_low_side() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -274,6 +277,7 @@ fn test_branch_when_a_roll_can_kill_on_the_low_side() {
 #[test]
 fn test_min_damage_killing_does_not_branch() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_two.get_active().hp = 44;
 
     let move_one = Choices::TACKLE;
@@ -281,11 +285,11 @@ fn test_min_damage_killing_does_not_branch() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -307,6 +311,7 @@ fn test_min_damage_killing_does_not_branch() {
 #[test]
 fn test_toxic_turns_into_poison_when_switching() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().status = PokemonStatus::TOXIC;
 
     let vec_of_instructions = generate_instructions_with_state_assertion(
@@ -337,28 +342,33 @@ fn test_toxic_turns_into_poison_when_switching() {
 #[test]
 fn test_sleeptalk_rest_has_no_effect_at_full_hp() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().rest_turns = 3;
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SPLASH);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::REST);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::SLEEPTALK);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M2, Choices::TACKLE);
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SPLASH,
+        state.generation,
+    );
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M3, Choices::CURSE);
+        .replace_move(PokemonMoveIndex::M0, Choices::REST, state.generation);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M1,
+        Choices::SLEEPTALK,
+        state.generation,
+    );
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M2,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M3,
+        Choices::CURSE,
+        state.generation,
+    );
     let vec_of_instructions = generate_instructions_with_state_assertion(
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M1),
@@ -420,24 +430,28 @@ fn test_sleeptalk_rest_has_no_effect_at_full_hp() {
 #[test]
 fn test_guaranteed_to_stay_asleep_sleeptalk_move_when_not_rested() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().hp = 50;
 
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SLEEPTALK,
+        state.generation,
+    );
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SLEEPTALK);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::REST);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M2, Choices::TACKLE);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M3, Choices::CURSE);
+        .replace_move(PokemonMoveIndex::M1, Choices::REST, state.generation);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M2,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M3,
+        Choices::CURSE,
+        state.generation,
+    );
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -537,26 +551,30 @@ fn test_guaranteed_to_stay_asleep_sleeptalk_move_when_not_rested() {
 #[test]
 fn test_small_chance_to_awaken_sleeptalk_move_when_not_rested() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().hp = 50;
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().sleep_turns = 1;
 
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SLEEPTALK,
+        state.generation,
+    );
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SLEEPTALK);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::REST);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M2, Choices::TACKLE);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M3, Choices::CURSE);
+        .replace_move(PokemonMoveIndex::M1, Choices::REST, state.generation);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M2,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M3,
+        Choices::CURSE,
+        state.generation,
+    );
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -655,6 +673,7 @@ fn test_small_chance_to_awaken_sleeptalk_move_when_not_rested() {
 #[test]
 fn test_destinybond_is_removed_if_non_destinybond_is_used() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().speed = 150;
     state
         .side_one
@@ -686,6 +705,7 @@ fn test_destinybond_is_removed_if_non_destinybond_is_used() {
 #[test]
 fn test_switching_out_while_other_side_is_partiallytrapped() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state
         .side_two
         .volatile_statuses
@@ -717,6 +737,7 @@ fn test_switching_out_while_other_side_is_partiallytrapped() {
 #[test]
 fn test_same_speed_branch() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().speed = 100;
     state.side_one.get_active().hp = 1;
     state.side_two.get_active().speed = 100;
@@ -747,9 +768,30 @@ fn test_same_speed_branch() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
+#[test]
+fn test_same_speed_branches_with_the_same_outcome_are_combined() {
+    let mut state = State::default();
+    state.set_generation(Generation::GEN2);
+    state.side_one.get_active().speed = 100;
+    state.side_two.get_active().speed = 100;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::SPLASH,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
 #[test]
 fn test_same_speed_branch_with_residuals() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().speed = 100;
     state.side_one.get_active().hp = 1;
     state.side_one.get_active().item = Items::LEFTOVERS;
@@ -797,6 +839,7 @@ fn test_same_speed_branch_with_residuals() {
 #[test]
 fn test_same_speed_branch_with_residuals_for_both_sides() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().speed = 100;
     state.side_one.get_active().hp = 100;
     state.side_one.get_active().item = Items::LEFTOVERS;
@@ -860,6 +903,7 @@ fn test_same_speed_branch_with_residuals_for_both_sides() {
 #[test]
 fn test_nothing_happens_if_destinybond_is_used_while_already_having_destinybond() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().speed = 150;
     state
         .side_one
@@ -882,28 +926,33 @@ fn test_nothing_happens_if_destinybond_is_used_while_already_having_destinybond(
 #[test]
 fn test_sleeptalk_can_call_rest() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().status = PokemonStatus::SLEEP;
     state.side_one.get_active().rest_turns = 2;
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::REST);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::SLEEPTALK);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M2, Choices::TACKLE);
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M3, Choices::CURSE);
+        .replace_move(PokemonMoveIndex::M0, Choices::REST, state.generation);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M1,
+        Choices::SLEEPTALK,
+        state.generation,
+    );
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M2,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M3,
+        Choices::CURSE,
+        state.generation,
+    );
     let vec_of_instructions = generate_instructions_with_state_assertion(
         &mut state,
         &MoveChoice::Move(PokemonMoveIndex::M1),
@@ -987,6 +1036,7 @@ fn test_sleeptalk_can_call_rest() {
 #[test]
 fn test_hyperbeam_sets_mustrecharge() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_two.get_active().hp = 500;
     state.side_two.get_active().maxhp = 500;
 
@@ -1021,6 +1071,7 @@ fn test_hyperbeam_sets_mustrecharge() {
 #[test]
 fn test_mintberry_cures_rest() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().hp = 50;
     state.side_one.get_active().item = Items::MINTBERRY;
 
@@ -1068,6 +1119,7 @@ fn test_mintberry_cures_rest() {
 #[test]
 fn test_miracleberry_cures_rest() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().hp = 50;
     state.side_one.get_active().item = Items::MIRACLEBERRY;
 
@@ -1115,6 +1167,7 @@ fn test_miracleberry_cures_rest() {
 #[test]
 fn test_miracleberry_cures_paralysis_and_attack_does_not_branch() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().hp = 50;
     state.side_one.get_active().item = Items::MIRACLEBERRY;
 
@@ -1144,6 +1197,7 @@ fn test_miracleberry_cures_paralysis_and_attack_does_not_branch() {
 #[test]
 fn test_bellydrum() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
@@ -1171,6 +1225,7 @@ fn test_bellydrum() {
 #[test]
 fn test_bellydrum_with_negative_prior_boost() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.attack_boost = -1;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -1199,6 +1254,7 @@ fn test_bellydrum_with_negative_prior_boost() {
 #[test]
 fn test_bellydrum_below_50_percent_boosts_by_2_bug() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().hp = 49;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -1221,6 +1277,7 @@ fn test_bellydrum_below_50_percent_boosts_by_2_bug() {
 #[test]
 fn test_bellydrum_below_50_percent_boosts_by_2_bug_does_not_overboost() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().hp = 49;
     state.side_one.attack_boost = 5;
 
@@ -1244,6 +1301,7 @@ fn test_bellydrum_below_50_percent_boosts_by_2_bug_does_not_overboost() {
 #[test]
 fn test_bellydrum_at_75_percent() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().hp = 75;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -1272,6 +1330,7 @@ fn test_bellydrum_at_75_percent() {
 #[test]
 fn test_bellydrum_at_exactly_50_percent() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().hp = 50;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -1294,6 +1353,7 @@ fn test_bellydrum_at_exactly_50_percent() {
 #[test]
 fn test_freeze_clause() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_two.pokemon[PokemonIndex::P1].status = PokemonStatus::FREEZE;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -1315,17 +1375,20 @@ fn test_freeze_clause() {
 #[test]
 fn test_counter_cannot_hit_ghost_type() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.use_damage_dealt = true;
     state.side_two.get_active().types.0 = PokemonType::GHOST;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::COUNTER);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::COUNTER,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -1353,16 +1416,19 @@ fn test_counter_cannot_hit_ghost_type() {
 #[test]
 fn test_counter_reflects_special_hiddenpower() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::COUNTER);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::HIDDENPOWERWATER70);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::COUNTER,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::HIDDENPOWERWATER70,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -1399,16 +1465,19 @@ fn test_counter_reflects_special_hiddenpower() {
 #[test]
 fn test_mirrorcoat_does_not_reflect_special_hiddenpower() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.use_damage_dealt = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::MIRRORCOAT);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::HIDDENPOWERWATER70);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::MIRRORCOAT,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::HIDDENPOWERWATER70,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -1441,6 +1510,7 @@ fn test_mirrorcoat_does_not_reflect_special_hiddenpower() {
 #[test]
 fn test_confuseray_into_substitute() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state
         .side_two
         .volatile_statuses
@@ -1463,6 +1533,7 @@ fn test_confuseray_into_substitute() {
 #[test]
 fn test_mint_berry_does_not_cure_paralysis() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state.side_one.get_active().hp = 50;
     state.side_one.get_active().item = Items::MINTBERRY;
 
@@ -1487,6 +1558,7 @@ fn test_mint_berry_does_not_cure_paralysis() {
 #[test]
 fn test_using_none_with_mustrecharge_removes_volatile() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state
         .side_one
         .volatile_statuses
@@ -1513,6 +1585,7 @@ fn test_using_none_with_mustrecharge_removes_volatile() {
 #[test]
 fn test_mustrecharge_move_only_allows_none() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN2);
     state
         .side_one
         .volatile_statuses
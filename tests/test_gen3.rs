@@ -1,6 +1,4 @@
-#![cfg(feature = "gen3")]
-
-use poke_engine::choices::{Choices, MOVES};
+use poke_engine::choices::{moves, Choices};
 use poke_engine::engine::abilities::Abilities;
 use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
 use poke_engine::engine::items::Items;
@@ -14,7 +12,8 @@ use poke_engine::instruction::{
 };
 use poke_engine::state::PokemonSideCondition;
 use poke_engine::state::{
-    Move, PokemonIndex, PokemonMoveIndex, PokemonStatus, PokemonType, SideReference, State,
+    Generation, Move, PokemonIndex, PokemonMoveIndex, PokemonStatus, PokemonType, SideReference,
+    State,
 };
 
 pub fn generate_instructions_with_state_assertion(
@@ -38,11 +37,11 @@ fn set_moves_on_pkmn_and_call_generate_instructions(
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let instructions = generate_instructions_with_state_assertion(
         state,
@@ -55,6 +54,7 @@ fn set_moves_on_pkmn_and_call_generate_instructions(
 #[test]
 fn test_regular_move_with_protect_side_condition() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_one.side_conditions.protect = 1;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -87,6 +87,7 @@ fn test_regular_move_with_protect_side_condition() {
 #[test]
 fn test_chestoberry_activates_when_being_put_to_sleep() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_one.get_active().item = Items::CHESTOBERRY;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -109,6 +110,7 @@ fn test_chestoberry_activates_when_being_put_to_sleep() {
 #[test]
 fn test_chestoberry_activates_when_using_rest() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_one.get_active().item = Items::CHESTOBERRY;
 
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
@@ -165,6 +167,7 @@ fn test_chestoberry_activates_when_using_rest() {
 #[test]
 fn test_taunt_gets_applied_and_duration_increments_end_of_turn() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
         &mut state,
         Choices::TAUNT,
@@ -191,6 +194,7 @@ fn test_taunt_gets_applied_and_duration_increments_end_of_turn() {
 #[test]
 fn test_taunt_volatile_is_removed_end_of_turn_when_it_would_reach_2() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_one.volatile_status_durations.taunt = 1;
     state
         .side_one
@@ -222,6 +226,7 @@ fn test_taunt_volatile_is_removed_end_of_turn_when_it_would_reach_2() {
 #[test]
 fn test_taunt_re_enables_disabled_moves_when_being_removed() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_one.volatile_status_durations.taunt = 1;
     state.side_one.get_active().moves.m1.disabled = true;
     state
@@ -258,6 +263,7 @@ fn test_taunt_re_enables_disabled_moves_when_being_removed() {
 #[test]
 fn test_switching_out_with_taunt_resets_duration_to_0() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_one.volatile_status_durations.taunt = 1;
     state
         .side_one
@@ -294,6 +300,7 @@ fn test_switching_out_with_taunt_resets_duration_to_0() {
 #[test]
 fn test_taunt_prevents_status_move() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_one.pokemon[PokemonIndex::P1].hp = 0;
     state.side_one.pokemon[PokemonIndex::P2].hp = 0;
     state.side_one.pokemon[PokemonIndex::P3].hp = 0;
@@ -308,28 +315,40 @@ fn test_taunt_prevents_status_move() {
         id: Choices::TOXIC,
         disabled: false,
         pp: 35,
-        choice: MOVES.get(&Choices::TOXIC).unwrap().clone(),
+        choice: moves(state.generation)
+            .get(&Choices::TOXIC)
+            .unwrap()
+            .clone(),
     };
 
     state.side_one.get_active().moves[&PokemonMoveIndex::M1] = Move {
         id: Choices::TACKLE,
         disabled: false,
         pp: 35,
-        choice: MOVES.get(&Choices::TACKLE).unwrap().clone(),
+        choice: moves(state.generation)
+            .get(&Choices::TACKLE)
+            .unwrap()
+            .clone(),
     };
 
     state.side_one.get_active().moves[&PokemonMoveIndex::M2] = Move {
         id: Choices::WATERGUN,
         disabled: false,
         pp: 35,
-        choice: MOVES.get(&Choices::TACKLE).unwrap().clone(),
+        choice: moves(state.generation)
+            .get(&Choices::TACKLE)
+            .unwrap()
+            .clone(),
     };
 
     state.side_one.get_active().moves[&PokemonMoveIndex::M3] = Move {
         id: Choices::EMBER,
         disabled: false,
         pp: 35,
-        choice: MOVES.get(&Choices::TACKLE).unwrap().clone(),
+        choice: moves(state.generation)
+            .get(&Choices::TACKLE)
+            .unwrap()
+            .clone(),
     };
 
     let (side_one_moves, _) = state.get_all_options();
@@ -346,6 +365,7 @@ fn test_taunt_prevents_status_move() {
 #[test]
 fn test_rest_does_not_activate_when_fainted() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_one.get_active().item = Items::CHESTOBERRY;
     state.side_one.get_active().hp = 1;
 
@@ -368,6 +388,7 @@ fn test_rest_does_not_activate_when_fainted() {
 #[test]
 fn test_branch_when_a_roll_can_kill() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_two.get_active().hp = 50;
 
     let move_one = Choices::TACKLE;
@@ -375,11 +396,11 @@ fn test_branch_when_a_roll_can_kill() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -412,6 +433,7 @@ fn test_branch_when_a_roll_can_kill() {
 #[test]
 fn test_fast_explosion_makes_other_side_unable_to_move() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_one.get_active().hp = 500;
     state.side_one.get_active().maxhp = 500;
     state.side_one.get_active().types = (PokemonType::STEEL, PokemonType::FLYING);
@@ -443,6 +465,7 @@ fn test_fast_explosion_makes_other_side_unable_to_move() {
 #[test]
 fn test_end_of_turn_sand_kos_before_leftovers() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.weather.weather_type = Weather::SAND;
     state.weather.turns_remaining = -1;
 
@@ -483,6 +506,7 @@ fn test_end_of_turn_sand_kos_before_leftovers() {
 #[test]
 fn test_intimidate_blocked_by_clearbody() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_one.pokemon[PokemonIndex::P1].ability = Abilities::INTIMIDATE;
     state.side_two.get_active().ability = Abilities::CLEARBODY;
 
@@ -506,6 +530,7 @@ fn test_intimidate_blocked_by_clearbody() {
 #[test]
 fn test_same_speed_branch() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_one.get_active().speed = 100;
     state.side_one.get_active().hp = 1;
     state.side_two.get_active().speed = 100;
@@ -536,9 +561,30 @@ fn test_same_speed_branch() {
     assert_eq!(expected_instructions, vec_of_instructions);
 }
 
+#[test]
+fn test_same_speed_branches_with_the_same_outcome_are_combined() {
+    let mut state = State::default();
+    state.set_generation(Generation::GEN3);
+    state.side_one.get_active().speed = 100;
+    state.side_two.get_active().speed = 100;
+
+    let vec_of_instructions = set_moves_on_pkmn_and_call_generate_instructions(
+        &mut state,
+        Choices::SPLASH,
+        Choices::SPLASH,
+    );
+
+    let expected_instructions = vec![StateInstructions {
+        percentage: 100.0,
+        instruction_list: vec![],
+    }];
+    assert_eq!(expected_instructions, vec_of_instructions);
+}
+
 #[test]
 fn test_gen3_branch_when_a_roll_can_kill() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN3);
     state.side_two.get_active().hp = 50;
 
     let move_one = Choices::TACKLE;
@@ -546,11 +592,11 @@ fn test_gen3_branch_when_a_roll_can_kill() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_one);
+        .replace_move(PokemonMoveIndex::M0, move_one, state.generation);
     state
         .side_two
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, move_two);
+        .replace_move(PokemonMoveIndex::M0, move_two, state.generation);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
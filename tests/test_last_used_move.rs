@@ -1,5 +1,3 @@
-#![cfg(not(any(feature = "gen1", feature = "gen2", feature = "gen3")))]
-
 use poke_engine::choices::Choices;
 use poke_engine::engine::generate_instructions::generate_instructions_from_move_pair;
 use poke_engine::instruction::{
@@ -8,21 +6,19 @@ use poke_engine::instruction::{
     SwitchInstruction,
 };
 
-#[cfg(not(feature = "terastallization"))]
 use poke_engine::instruction::ChangeSubsituteHealthInstruction;
 
-#[cfg(not(feature = "terastallization"))]
 use poke_engine::engine::abilities::Abilities;
 
-#[cfg(not(feature = "gen4"))]
 use poke_engine::state::PokemonBoostableStat;
 
-#[cfg(not(feature = "gen4"))]
 use poke_engine::instruction::{BoostInstruction, ChangeVolatileStatusDurationInstruction};
 
 use poke_engine::engine::state::{MoveChoice, PokemonVolatileStatus};
 
-use poke_engine::state::{LastUsedMove, PokemonIndex, PokemonMoveIndex, SideReference, State};
+use poke_engine::state::{
+    Generation, LastUsedMove, PokemonIndex, PokemonMoveIndex, SideReference, State,
+};
 
 #[test]
 fn test_last_used_move_is_set_on_switch() {
@@ -57,14 +53,16 @@ fn test_last_used_move_is_set_on_switch() {
 fn test_last_used_move_is_set_on_move() {
     let mut state = State::default();
     state.use_last_used_move = true;
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -110,14 +108,16 @@ fn test_last_used_move_overwritten_when_dragged_out() {
     state.side_one.pokemon.pkmn[4].hp = 0;
     state.side_one.pokemon.pkmn[5].hp = 0;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::WHIRLWIND);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::WHIRLWIND,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -159,7 +159,6 @@ fn test_last_used_move_overwritten_when_dragged_out() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_encore_causes_get_all_options_to_only_allow_last_used_move() {
     let mut state = State::default();
     state.use_last_used_move = true;
@@ -168,10 +167,11 @@ fn test_encore_causes_get_all_options_to_only_allow_last_used_move() {
         .volatile_statuses
         .insert(PokemonVolatileStatus::ENCORE);
     state.side_one.last_used_move = LastUsedMove::Move(PokemonMoveIndex::M0);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let (side_one_moves, _side_two_moves) = state.get_all_options();
 
@@ -189,7 +189,6 @@ fn test_encore_causes_get_all_options_to_only_allow_last_used_move() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_encore_and_arenatrapped_together() {
     let mut state = State::default();
     state.use_last_used_move = true;
@@ -199,10 +198,11 @@ fn test_encore_and_arenatrapped_together() {
         .volatile_statuses
         .insert(PokemonVolatileStatus::ENCORE);
     state.side_one.last_used_move = LastUsedMove::Move(PokemonMoveIndex::M0);
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     let (side_one_moves, _side_two_moves) = state.get_all_options();
 
@@ -213,23 +213,25 @@ fn test_encore_and_arenatrapped_together() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_encore_slow() {
     let mut state = State::default();
     state.use_last_used_move = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::ENCORE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::WATERGUN);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::ENCORE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M1,
+        Choices::WATERGUN,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -281,23 +283,25 @@ fn test_encore_slow() {
 }
 
 #[test]
-#[cfg(not(feature = "terastallization"))]
 fn test_encore_slow_into_substitute() {
     let mut state = State::default();
     state.use_last_used_move = true;
 
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::ENCORE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::SUBSTITUTE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::WATERGUN);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::ENCORE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::SUBSTITUTE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M1,
+        Choices::WATERGUN,
+        state.generation,
+    );
 
     let vec_of_instructions = generate_instructions_from_move_pair(
         &mut state,
@@ -362,18 +366,21 @@ fn test_encore_fast_fails_with_lastusedmove_equal_to_switch() {
     state.use_last_used_move = true;
     state.side_one.get_active().speed = 200;
     state.side_two.get_active().speed = 100;
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::ENCORE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::WATERGUN);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::ENCORE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M1,
+        Choices::WATERGUN,
+        state.generation,
+    );
     state.side_two.last_used_move = LastUsedMove::Switch(PokemonIndex::P0);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
@@ -411,18 +418,21 @@ fn test_encore_fast_fails_with_lastusedmove_equal_to_none() {
     state.use_last_used_move = true;
     state.side_one.get_active().speed = 200;
     state.side_two.get_active().speed = 100;
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::ENCORE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::WATERGUN);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::ENCORE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M1,
+        Choices::WATERGUN,
+        state.generation,
+    );
     state.side_two.last_used_move = LastUsedMove::None;
 
     let vec_of_instructions = generate_instructions_from_move_pair(
@@ -460,10 +470,11 @@ fn test_encore_second_fails_when_opponent_switches() {
     state.use_last_used_move = true;
     state.side_one.get_active().speed = 200;
     state.side_two.pokemon[PokemonIndex::P1].speed = 100;
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::ENCORE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::ENCORE,
+        state.generation,
+    );
     state.side_two.last_used_move = LastUsedMove::Move(PokemonMoveIndex::M0);
 
     let vec_of_instructions = generate_instructions_from_move_pair(
@@ -497,24 +508,27 @@ fn test_encore_second_fails_when_opponent_switches() {
 }
 
 #[test]
-#[cfg(not(feature = "gen4"))]
 fn test_fast_encore_into_using_a_different_move_from_lum() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.use_last_used_move = true;
     state.side_one.get_active().speed = 200;
     state.side_two.get_active().speed = 100;
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::ENCORE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::SWORDSDANCE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::ENCORE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M1,
+        Choices::SWORDSDANCE,
+        state.generation,
+    );
     state.side_two.last_used_move = LastUsedMove::Move(PokemonMoveIndex::M1);
 
     // side_two will try to use tackle, but will encored into watergun from last turn
@@ -554,9 +568,9 @@ fn test_fast_encore_into_using_a_different_move_from_lum() {
 }
 
 #[test]
-#[cfg(not(feature = "gen4"))]
 fn test_encore_expires_at_2_turns() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.use_last_used_move = true;
     state.side_one.get_active().speed = 200;
     state.side_two.get_active().speed = 100;
@@ -568,15 +582,17 @@ fn test_encore_expires_at_2_turns() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::NONE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::SWORDSDANCE);
+        .replace_move(PokemonMoveIndex::M0, Choices::NONE, state.generation);
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M1,
+        Choices::SWORDSDANCE,
+        state.generation,
+    );
     state.side_two.last_used_move = LastUsedMove::Move(PokemonMoveIndex::M1);
 
     // side_two will try to use tackle, but will encored into watergun from last turn
@@ -611,9 +627,9 @@ fn test_encore_expires_at_2_turns() {
 }
 
 #[test]
-#[cfg(not(feature = "gen4"))]
 fn test_encore_counter_increment() {
     let mut state = State::default();
+    state.set_generation(Generation::GEN9);
     state.use_last_used_move = true;
     state.side_one.get_active().speed = 200;
     state.side_two.get_active().speed = 100;
@@ -625,15 +641,17 @@ fn test_encore_counter_increment() {
     state
         .side_one
         .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::NONE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M1, Choices::SWORDSDANCE);
+        .replace_move(PokemonMoveIndex::M0, Choices::NONE, state.generation);
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M1,
+        Choices::SWORDSDANCE,
+        state.generation,
+    );
     state.side_two.last_used_move = LastUsedMove::Move(PokemonMoveIndex::M1);
 
     // side_two will try to use tackle, but will encored into watergun from last turn
@@ -669,14 +687,16 @@ fn test_fakeout_first_turn_switched_in() {
     state.use_last_used_move = true;
     state.side_one.get_active().speed = 200;
     state.side_two.get_active().speed = 100;
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::FAKEOUT);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::FAKEOUT,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     state.side_one.last_used_move = LastUsedMove::Switch(PokemonIndex::P0);
 
@@ -719,14 +739,16 @@ fn test_fakeout_with_last_used_move_of_non_switch() {
     state.use_last_used_move = true;
     state.side_one.get_active().speed = 200;
     state.side_two.get_active().speed = 100;
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::FAKEOUT);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::FAKEOUT,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     state.side_one.last_used_move = LastUsedMove::Move(PokemonMoveIndex::M0);
 
@@ -760,14 +782,16 @@ fn test_firstimpression_first_turn_switched_in() {
     state.use_last_used_move = true;
     state.side_one.get_active().speed = 200;
     state.side_two.get_active().speed = 100;
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::FIRSTIMPRESSION);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::FIRSTIMPRESSION,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     state.side_one.last_used_move = LastUsedMove::Switch(PokemonIndex::P0);
 
@@ -810,14 +834,16 @@ fn test_firstimpression_with_last_used_move_of_non_switch() {
     state.use_last_used_move = true;
     state.side_one.get_active().speed = 200;
     state.side_two.get_active().speed = 100;
-    state
-        .side_one
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::FIRSTIMPRESSION);
-    state
-        .side_two
-        .get_active()
-        .replace_move(PokemonMoveIndex::M0, Choices::TACKLE);
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::FIRSTIMPRESSION,
+        state.generation,
+    );
+    state.side_two.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
 
     state.side_one.last_used_move = LastUsedMove::Move(PokemonMoveIndex::M0);
 
@@ -0,0 +1,91 @@
+#![cfg(feature = "serde")]
+
+use poke_engine::choices::Choices;
+use poke_engine::engine::state::PokemonVolatileStatus;
+use poke_engine::instruction::{
+    BoostInstruction, DamageInstruction, Instruction, StateInstructions,
+};
+use poke_engine::state::{
+    PokemonBoostableStat, PokemonMoveIndex, PokemonStatus, SideReference, State,
+};
+
+#[test]
+fn test_state_json_round_trip() {
+    let mut state = State::default();
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
+    state.side_one.get_active().status = PokemonStatus::BURN;
+    state.side_two.side_conditions.stealth_rock = 1;
+    state
+        .side_two
+        .volatile_statuses
+        .insert(PokemonVolatileStatus::CONFUSION);
+    state.update_zobrist_hash();
+
+    let json = serde_json::to_string(&state).unwrap();
+    let deserialized: State = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(state.serialize(), deserialized.serialize());
+    assert_eq!(state.zobrist_hash, deserialized.zobrist_hash);
+}
+
+#[test]
+fn test_deserialized_moves_have_their_move_data() {
+    let mut state = State::default();
+    state.side_one.get_active().replace_move(
+        PokemonMoveIndex::M0,
+        Choices::TACKLE,
+        state.generation,
+    );
+
+    let json = serde_json::to_string(&state).unwrap();
+    let mut deserialized: State = serde_json::from_str(&json).unwrap();
+
+    let choice = &deserialized.side_one.get_active().moves[&PokemonMoveIndex::M0].choice;
+    assert_eq!(Choices::TACKLE, choice.move_id);
+    assert_eq!(
+        state.side_one.get_active().moves[&PokemonMoveIndex::M0]
+            .choice
+            .base_power,
+        choice.base_power
+    );
+}
+
+#[test]
+fn test_enums_serialize_as_their_variant_name() {
+    assert_eq!(
+        "\"BURN\"",
+        serde_json::to_string(&PokemonStatus::BURN).unwrap()
+    );
+    assert_eq!(
+        "\"TACKLE\"",
+        serde_json::to_string(&Choices::TACKLE).unwrap()
+    );
+}
+
+#[test]
+fn test_state_instructions_json_round_trip() {
+    let state_instructions = StateInstructions {
+        percentage: 50.0,
+        instruction_list: vec![
+            Instruction::Damage(DamageInstruction {
+                side_ref: SideReference::SideTwo,
+                damage_amount: 25,
+            }),
+            Instruction::Boost(BoostInstruction {
+                side_ref: SideReference::SideOne,
+                stat: PokemonBoostableStat::Attack,
+                amount: 2,
+            }),
+            Instruction::DecrementWeatherTurnsRemaining,
+        ],
+    };
+
+    let json = serde_json::to_string(&state_instructions).unwrap();
+    let deserialized: StateInstructions = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(state_instructions, deserialized);
+}